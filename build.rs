@@ -1,5 +1,46 @@
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 fn main() {
     println!("cargo::rerun-if-env-changed=protontool_DEFAULT_STEAM_DIR");
     println!("cargo::rerun-if-env-changed=protontool_DEFAULT_GUI_PROVIDER");
     println!("cargo::rerun-if-env-changed=protontool_STEAM_RUNTIME_PATH");
+    println!("cargo::rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    // Build provenance for `protontool version`
+    for file in [".git/HEAD", ".git/index"] {
+        if Path::new(file).exists() {
+            println!("cargo::rerun-if-changed={}", file);
+        }
+    }
+    if let Some(commit) = git(&["rev-parse", "--short=12", "HEAD"]) {
+        let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+            .is_some_and(|status| !status.is_empty());
+        let suffix = if dirty { "-dirty" } else { "" };
+        println!(
+            "cargo::rustc-env=PROTONTOOL_GIT_COMMIT={}{}",
+            commit, suffix
+        );
+    }
+    // SOURCE_DATE_EPOCH keeps reproducible builds reproducible
+    let build_time = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0)
+        });
+    println!("cargo::rustc-env=PROTONTOOL_BUILD_TIME={}", build_time);
+}
+
+/// Trimmed output of a successful git command.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
@@ -0,0 +1,315 @@
+//! Offline analysis of existing Wine/Proton logs.
+//!
+//! Large logs (hundreds of MB) are split into newline-aligned chunks that are
+//! scanned for `KNOWN_ERRORS` patterns in parallel. The per-chunk results are
+//! merged into a ranked summary: most frequent codes first, with the first
+//! and last occurrence of each.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::log::extract_dll_name;
+use crate::wine_data::KNOWN_ERRORS;
+
+/// Files smaller than this are scanned on a single thread.
+const MIN_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+/// A single occurrence position within the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    /// 1-based line number.
+    pub line: usize,
+    /// Timestamp found on the line, if the log format has one.
+    pub timestamp: Option<String>,
+}
+
+impl Occurrence {
+    /// Timestamp if known, otherwise the line number.
+    pub fn describe(&self) -> String {
+        match &self.timestamp {
+            Some(ts) => ts.clone(),
+            None => format!("line {}", self.line),
+        }
+    }
+}
+
+/// Aggregated occurrences of one known error code.
+#[derive(Debug, Clone)]
+pub struct ErrorSummary {
+    pub code: String,
+    pub description: String,
+    pub count: usize,
+    pub first: Occurrence,
+    pub last: Occurrence,
+    /// DLL names mentioned on matching lines (for missing-DLL style errors).
+    pub dlls: Vec<String>,
+}
+
+/// Result of analyzing a log file.
+#[derive(Debug, Clone)]
+pub struct LogAnalysis {
+    pub path: PathBuf,
+    pub total_lines: usize,
+    /// Summaries ranked by count (descending), then by first occurrence.
+    pub summaries: Vec<ErrorSummary>,
+}
+
+/// Per-chunk scan result. Line numbers are relative to the chunk.
+struct ChunkResult {
+    lines: usize,
+    summaries: HashMap<usize, ErrorSummary>,
+}
+
+/// Extract a timestamp from a log line.
+///
+/// Recognizes protontool's `[YYYY-MM-DD HH:MM:SS]` prefix and Wine's
+/// `+timestamp` channel format (`1234.567:0024:0028:err:...`).
+pub fn extract_timestamp(line: &str) -> Option<String> {
+    if let Some(rest) = line.strip_prefix('[') {
+        let end = rest.find(']')?;
+        let ts = &rest[..end];
+        if ts.starts_with(|c: char| c.is_ascii_digit()) {
+            return Some(ts.to_string());
+        }
+        return None;
+    }
+
+    let first = line.split(':').next()?;
+    if first.contains('.') && first.parse::<f64>().is_ok() {
+        return Some(first.to_string());
+    }
+    None
+}
+
+/// Does this known error code refer to a missing/broken DLL?
+fn is_dll_error(code: &str) -> bool {
+    code.contains("NODLL")
+        || code.contains("MODULE")
+        || code.contains("DLL")
+        || code.contains("ORDINAL")
+        || code.contains("ENTRYPT")
+}
+
+/// Scan one byte range of the file. `start` must be at a line boundary;
+/// the chunk covers every line that starts before `end`.
+fn scan_chunk(
+    path: &Path,
+    start: u64,
+    end: u64,
+    patterns: &[String],
+) -> Result<ChunkResult, String> {
+    let mut file =
+        File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to seek in {}: {}", path.display(), e))?;
+    let mut reader = BufReader::new(file.take(end - start));
+
+    let mut result = ChunkResult {
+        lines: 0,
+        summaries: HashMap::new(),
+    };
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+        let read = reader
+            .read_until(b'\n', &mut buf)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        result.lines += 1;
+
+        let line = String::from_utf8_lossy(&buf);
+        let line = line.trim_end();
+        let lower = line.to_lowercase();
+
+        for (idx, pattern) in patterns.iter().enumerate() {
+            if !lower.contains(pattern.as_str()) {
+                continue;
+            }
+            let (_, code, description) = KNOWN_ERRORS[idx];
+            let occurrence = Occurrence {
+                line: result.lines,
+                timestamp: extract_timestamp(line),
+            };
+            let summary = result.summaries.entry(idx).or_insert_with(|| ErrorSummary {
+                code: code.to_string(),
+                description: description.to_string(),
+                count: 0,
+                first: occurrence.clone(),
+                last: occurrence.clone(),
+                dlls: Vec::new(),
+            });
+            summary.count += 1;
+            summary.last = occurrence;
+
+            if is_dll_error(code) {
+                if let Some(dll) = extract_dll_name(line) {
+                    if !summary.dlls.contains(&dll) {
+                        summary.dlls.push(dll);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Split the file into newline-aligned byte ranges, one per worker.
+fn chunk_ranges(
+    path: &Path,
+    len: u64,
+    workers: usize,
+    min_chunk: u64,
+) -> Result<Vec<(u64, u64)>, String> {
+    let workers = workers.max(1) as u64;
+    let chunk_size = (len / workers).max(min_chunk).max(1);
+
+    let mut file =
+        File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut ranges = Vec::new();
+    let mut start = 0u64;
+
+    while start < len {
+        let mut end = (start + chunk_size).min(len);
+        if end < len {
+            // Move the boundary forward to just after the next newline
+            file.seek(SeekFrom::Start(end))
+                .map_err(|e| format!("Failed to seek in {}: {}", path.display(), e))?;
+            let mut reader = BufReader::new(&mut file);
+            let mut skipped = Vec::new();
+            let n = reader
+                .read_until(b'\n', &mut skipped)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            end = (end + n as u64).min(len);
+        }
+        ranges.push((start, end));
+        start = end;
+    }
+
+    Ok(ranges)
+}
+
+/// Analyze a log file for known errors using up to `workers` threads
+/// (0 picks the number of available CPUs).
+pub fn analyze_log(path: &Path, workers: usize) -> Result<LogAnalysis, String> {
+    analyze_log_chunked(path, workers, MIN_CHUNK_SIZE)
+}
+
+fn analyze_log_chunked(path: &Path, workers: usize, min_chunk: u64) -> Result<LogAnalysis, String> {
+    let len = std::fs::metadata(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+        .len();
+
+    let workers = if workers == 0 {
+        thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    } else {
+        workers
+    };
+
+    let patterns: Vec<String> = KNOWN_ERRORS
+        .iter()
+        .map(|(pattern, _, _)| pattern.to_lowercase())
+        .collect();
+    let ranges = chunk_ranges(path, len, workers, min_chunk)?;
+
+    let chunk_results: Vec<Result<ChunkResult, String>> = thread::scope(|scope| {
+        let handles: Vec<_> = ranges
+            .iter()
+            .map(|&(start, end)| {
+                let patterns = &patterns;
+                scope.spawn(move || scan_chunk(path, start, end, patterns))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| {
+                h.join()
+                    .unwrap_or_else(|_| Err("Log scanner thread panicked".to_string()))
+            })
+            .collect()
+    });
+
+    // Merge chunks in file order, shifting line numbers by preceding chunks
+    let mut merged: HashMap<usize, ErrorSummary> = HashMap::new();
+    let mut line_offset = 0;
+    for chunk in chunk_results {
+        let chunk = chunk?;
+        for (idx, mut summary) in chunk.summaries {
+            summary.first.line += line_offset;
+            summary.last.line += line_offset;
+            match merged.get_mut(&idx) {
+                Some(existing) => {
+                    existing.count += summary.count;
+                    existing.last = summary.last;
+                    for dll in summary.dlls {
+                        if !existing.dlls.contains(&dll) {
+                            existing.dlls.push(dll);
+                        }
+                    }
+                }
+                None => {
+                    merged.insert(idx, summary);
+                }
+            }
+        }
+        line_offset += chunk.lines;
+    }
+
+    let mut summaries: Vec<ErrorSummary> = merged.into_values().collect();
+    summaries.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.first.line.cmp(&b.first.line))
+    });
+
+    Ok(LogAnalysis {
+        path: path.to_path_buf(),
+        total_lines: line_offset,
+        summaries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze_log_ranks_and_tracks_occurrences() {
+        let dir = std::env::temp_dir().join(format!("protontool-analyze-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("steam-123.log");
+
+        let mut content = String::new();
+        content.push_str("1.000:0024:0028:err:module:import_dll Library MSVCP140.dll (which is needed by L\"game.exe\") not found\n");
+        for i in 0..3 {
+            content.push_str(&format!(
+                "{}.500:0024:0028:err:seh:NtRaiseException Unhandled exception code c0000005\n",
+                i + 2
+            ));
+        }
+        std::fs::write(&log, content).unwrap();
+
+        // Tiny chunks force the multi-threaded merge path
+        let analysis = analyze_log_chunked(&log, 4, 16).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(analysis.total_lines, 4);
+        let top = &analysis.summaries[0];
+        assert_eq!(top.count, 3);
+        assert_eq!(top.first.line, 2);
+        assert_eq!(top.last.line, 4);
+        assert_eq!(top.last.timestamp.as_deref(), Some("4.500"));
+        assert!(analysis
+            .summaries
+            .iter()
+            .any(|s| s.dlls.contains(&"MSVCP140.dll".to_string())));
+    }
+}
@@ -0,0 +1,302 @@
+//! Before/after audit records of prefix changes (`--audit`).
+//!
+//! `protontool --audit ...` captures the state of the prefix an operation
+//! works on, runs the operation, captures the state again and saves what
+//! changed as a JSON record in the state store (`audit/`), answering "what
+//! did that verb actually do" for debugging and for users who have to
+//! account for changes. A capture holds:
+//!
+//! - a hash of each registry hive and of every key in it (key timestamps
+//!   are left out, so only real value changes show),
+//! - a manifest (size and modification time) of the files in the system
+//!   directories, `Program Files` and `ProgramData`,
+//! - the prefix's environment profile.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use crate::json::JsonValue;
+use crate::wine::profile::EnvProfile;
+
+/// Registry hives of a prefix.
+const HIVES: &[&str] = &["system.reg", "user.reg", "userdef.reg"];
+
+/// Directories below the prefix whose files are listed in the manifest.
+const MANIFEST_DIRS: &[&str] = &[
+    "drive_c/windows/system32",
+    "drive_c/windows/syswow64",
+    "drive_c/Program Files",
+    "drive_c/Program Files (x86)",
+    "drive_c/ProgramData",
+];
+
+/// State store directory holding the audit records.
+const RECORD_DIR: &str = "audit";
+
+/// 64-bit FNV-1a hash, enough to tell whether content changed.
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Hash of each key's values in a registry hive. The timestamps on key
+/// lines and `#time` lines are skipped.
+pub fn hive_keys(content: &str) -> BTreeMap<String, u64> {
+    let mut keys = BTreeMap::new();
+    let mut current: Option<(String, String)> = None;
+    for line in content.lines() {
+        if let Some(rest) = line.strip_prefix('[') {
+            if let Some((name, values)) = current.take() {
+                keys.insert(name, fnv1a(values.as_bytes()));
+            }
+            let name = rest.rsplit_once(']').map_or(rest, |(name, _)| name);
+            current = Some((name.to_string(), String::new()));
+        } else if let Some((_, values)) = current.as_mut() {
+            if !line.starts_with('#') {
+                values.push_str(line);
+                values.push('\n');
+            }
+        }
+    }
+    if let Some((name, values)) = current {
+        keys.insert(name, fnv1a(values.as_bytes()));
+    }
+    keys
+}
+
+/// The audited state of a prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixState {
+    /// Hive file name and hash of its content.
+    pub hives: Vec<(String, u64)>,
+    /// `hive:key` and hash of the key's values.
+    pub keys: BTreeMap<String, u64>,
+    /// Path relative to the prefix, size and modification time.
+    pub files: BTreeMap<String, (u64, u64)>,
+    pub env: Vec<(String, String)>,
+}
+
+impl PrefixState {
+    /// Capture a prefix's state. A missing prefix captures as empty.
+    pub fn capture(prefix_path: &Path) -> Self {
+        let mut state = PrefixState::default();
+        for hive in HIVES {
+            let Ok(content) = fs::read(prefix_path.join(hive)) else {
+                continue;
+            };
+            state.hives.push((hive.to_string(), fnv1a(&content)));
+            for (key, hash) in hive_keys(&String::from_utf8_lossy(&content)) {
+                state.keys.insert(format!("{}:{}", hive, key), hash);
+            }
+        }
+        for dir in MANIFEST_DIRS {
+            add_files(&mut state.files, prefix_path, &prefix_path.join(dir));
+        }
+        state.env = EnvProfile::load(prefix_path).vars;
+        state
+    }
+}
+
+/// Add the files below `dir` to a manifest. Symlinks are listed, not
+/// followed.
+fn add_files(files: &mut BTreeMap<String, (u64, u64)>, prefix_path: &Path, dir: &Path) {
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|e| e.path()));
+            }
+            continue;
+        }
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        if let Ok(relative) = path.strip_prefix(prefix_path) {
+            files.insert(
+                relative.to_string_lossy().into_owned(),
+                (metadata.len(), modified),
+            );
+        }
+    }
+}
+
+fn strings(items: Vec<String>) -> JsonValue {
+    JsonValue::Array(items.into_iter().map(JsonValue::String).collect())
+}
+
+/// Names added, removed and changed between two maps.
+fn compare_maps<V: PartialEq>(
+    before: &BTreeMap<String, V>,
+    after: &BTreeMap<String, V>,
+) -> JsonValue {
+    let added = after.keys().filter(|k| !before.contains_key(*k)).cloned();
+    let removed = before.keys().filter(|k| !after.contains_key(*k)).cloned();
+    let changed = after
+        .iter()
+        .filter(|(k, v)| before.get(*k).is_some_and(|old| old != *v))
+        .map(|(k, _)| k.clone());
+    JsonValue::Object(vec![
+        ("added".to_string(), strings(added.collect())),
+        ("removed".to_string(), strings(removed.collect())),
+        ("changed".to_string(), strings(changed.collect())),
+    ])
+}
+
+/// What changed between two captures.
+pub fn diff(before: &PrefixState, after: &PrefixState) -> JsonValue {
+    let hashes = |state: &PrefixState| {
+        JsonValue::Object(
+            state
+                .hives
+                .iter()
+                .map(|(hive, hash)| (hive.clone(), JsonValue::String(format!("{:016x}", hash))))
+                .collect(),
+        )
+    };
+    let env = |state: &PrefixState| state.env.iter().cloned().collect::<BTreeMap<_, _>>();
+    let (env_before, env_after) = (env(before), env(after));
+    let env_values = |values: &BTreeMap<String, String>| {
+        JsonValue::Object(
+            values
+                .iter()
+                .map(|(k, v)| (k.clone(), JsonValue::String(v.clone())))
+                .collect(),
+        )
+    };
+    JsonValue::Object(vec![
+        (
+            "registry".to_string(),
+            JsonValue::Object(vec![
+                ("before".to_string(), hashes(before)),
+                ("after".to_string(), hashes(after)),
+                ("keys".to_string(), compare_maps(&before.keys, &after.keys)),
+            ]),
+        ),
+        (
+            "files".to_string(),
+            compare_maps(&before.files, &after.files),
+        ),
+        (
+            "env".to_string(),
+            JsonValue::Object(vec![
+                ("before".to_string(), env_values(&env_before)),
+                ("after".to_string(), env_values(&env_after)),
+                ("changes".to_string(), compare_maps(&env_before, &env_after)),
+            ]),
+        ),
+    ])
+}
+
+/// A finished audited operation.
+pub struct AuditRecord<'a> {
+    pub command: &'a [String],
+    pub prefix_path: &'a Path,
+    /// Start and end as seconds since the epoch.
+    pub started: u64,
+    pub finished: u64,
+    pub exit_code: i32,
+}
+
+impl AuditRecord<'_> {
+    /// The record as JSON, with the changes between two captures.
+    pub fn to_json(&self, before: &PrefixState, after: &PrefixState) -> JsonValue {
+        JsonValue::Object(vec![
+            ("version".to_string(), JsonValue::Number(1.0)),
+            ("command".to_string(), strings(self.command.to_vec())),
+            (
+                "prefix".to_string(),
+                JsonValue::String(self.prefix_path.display().to_string()),
+            ),
+            (
+                "started".to_string(),
+                JsonValue::Number(self.started as f64),
+            ),
+            (
+                "finished".to_string(),
+                JsonValue::Number(self.finished as f64),
+            ),
+            (
+                "exit_code".to_string(),
+                JsonValue::Number(self.exit_code as f64),
+            ),
+            ("changes".to_string(), diff(before, after)),
+        ])
+    }
+
+    /// Save the record to the state store. Returns its path.
+    pub fn save(
+        &self,
+        before: &PrefixState,
+        after: &PrefixState,
+    ) -> Result<std::path::PathBuf, String> {
+        let name = format!(
+            "{}/{}-{}.json",
+            RECORD_DIR,
+            self.started,
+            std::process::id()
+        );
+        crate::state::write(&name, &format!("{}\n", self.to_json(before, after)))?;
+        Ok(crate::state::state_path(&name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_ignores_key_timestamps() {
+        let hive = |timestamp: &str, value: &str| {
+            format!(
+                "WINE REGISTRY Version 2\n\n[Software\\\\Wine] {}\n#time=1d9\n\"Version\"=\"{}\"\n\n\
+                 [Software\\\\Other] {}\n\"A\"=\"1\"\n",
+                timestamp, value, timestamp
+            )
+        };
+        let before_keys = hive_keys(&hive("1700000000", "win10"));
+        assert_eq!(before_keys.len(), 2);
+        assert_eq!(before_keys, hive_keys(&hive("1800000000", "win10")));
+
+        let mut before = PrefixState {
+            keys: before_keys,
+            ..Default::default()
+        };
+        before
+            .files
+            .insert("drive_c/windows/system32/d3d11.dll".into(), (10, 1));
+        let mut after = PrefixState {
+            keys: hive_keys(&hive("1800000000", "win7")),
+            ..Default::default()
+        };
+        after
+            .files
+            .insert("drive_c/windows/system32/d3d11.dll".into(), (12, 2));
+        after.env.push(("DXVK_HUD".into(), "fps".into()));
+
+        let changes = diff(&before, &after);
+        let list = |path: &[&str]| {
+            let mut value = &changes;
+            for key in path {
+                value = value.get(key).unwrap();
+            }
+            value.to_string()
+        };
+        assert_eq!(
+            list(&["registry", "keys", "changed"]),
+            "[\"Software\\\\\\\\Wine\"]"
+        );
+        assert_eq!(
+            list(&["files", "changed"]),
+            "[\"drive_c/windows/system32/d3d11.dll\"]"
+        );
+        assert_eq!(list(&["env", "changes", "added"]), "[\"DXVK_HUD\"]");
+    }
+}
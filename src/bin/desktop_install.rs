@@ -1,8 +1,10 @@
 //! protontool-desktop-install - Install .desktop entries for protontool.
 //!
-//! Creates application shortcuts in ~/.local/share/applications/ for:
+//! Installs protontool's icon and creates application shortcuts in
+//! ~/.local/share/applications/ for:
 //! - protontool: Main GUI for managing prefixes
 //! - protontool-launch: Quick launcher for .exe files
+//! - protontool run: Run .exe files in the default prefix
 
 use std::env;
 use std::fs;
@@ -20,36 +22,40 @@ fn install_desktop_entries() -> Result<PathBuf, String> {
     fs::create_dir_all(&applications_dir)
         .map_err(|e| format!("Failed to create applications dir: {}", e))?;
 
-    let desktop_content = r#"[Desktop Entry]
-Type=Application
-Name=Protontool
-Comment=Manage Wine/Proton prefixes and install Windows components
-Exec=protontool --gui --no-term
-Icon=wine
-Terminal=false
-Categories=Utility;Game;
-"#;
-
     let launch_content = r#"[Desktop Entry]
 Type=Application
 Name=Protontool Launch
 Comment=Launch Windows executables using Proton
 Exec=protontool-launch --no-term %f
-Icon=wine
+Icon=protontool
 Terminal=false
 Categories=Utility;Game;
 MimeType=application/x-ms-dos-executable;application/x-msdos-program;
 "#;
 
-    let desktop_path = applications_dir.join("protontool.desktop");
+    let run_content = r#"[Desktop Entry]
+Type=Application
+Name=Run with Protontool
+Comment=Run Windows programs in the default Proton prefix
+Exec=protontool --no-term run %f
+Icon=protontool
+Terminal=false
+NoDisplay=true
+Categories=Utility;
+MimeType=application/x-ms-dos-executable;
+"#;
+
     let launch_path = applications_dir.join("protontool-launch.desktop");
+    let run_path = applications_dir.join("protontool-run.desktop");
 
-    fs::write(&desktop_path, desktop_content)
-        .map_err(|e| format!("Failed to write desktop file: {}", e))?;
+    protontool::desktop::install_desktop_entry()?;
 
     fs::write(&launch_path, launch_content)
         .map_err(|e| format!("Failed to write launch desktop file: {}", e))?;
 
+    fs::write(&run_path, run_content)
+        .map_err(|e| format!("Failed to write run desktop file: {}", e))?;
+
     let _ = Command::new("update-desktop-database")
         .arg(&applications_dir)
         .status();
@@ -91,7 +97,7 @@ fn main() {
                 install_dir.display()
             );
             println!(
-                "The protontool shortcut and protontool-launch desktop entries should now work."
+                "The protontool shortcut, protontool-launch and protontool-run desktop entries should now work."
             );
         }
         Err(e) => {
@@ -5,12 +5,12 @@
 
 use std::env;
 use std::path::{Path, PathBuf};
-use std::process::{self, Command};
+use std::process;
 
 use protontool::cli::util::{enable_logging, exit_with_error, ArgParser};
 use protontool::gui::{select_steam_installation, select_steam_library_paths};
 use protontool::steam::{find_steam_installations, get_steam_apps, get_steam_lib_paths, SteamApp};
-use protontool::util::{output_to_string, shell_quote, which};
+use protontool::util::{output_to_string, which};
 
 /// Target environment for launching the executable.
 #[derive(Debug)]
@@ -173,14 +173,9 @@ fn main() {
         cli_args.push("--cwd-app".to_string());
     }
 
-    let quoted_exec = shell_quote(&executable_path.to_string_lossy());
-    let quoted_args: Vec<String> = exec_args.iter().map(|a| shell_quote(a)).collect();
-
     // Don't include "wine" - the command mode runs through wine already
-    let inner_args = format!("{} {}", quoted_exec, quoted_args.join(" "));
-
     cli_args.push("-c".to_string());
-    cli_args.push(inner_args);
+    cli_args.push(executable_path.to_string_lossy().to_string());
 
     match target {
         LaunchTarget::SteamApp(appid) => {
@@ -192,6 +187,12 @@ fn main() {
         }
     }
 
+    // Program arguments go after `--`, passed to the program as they are
+    if !exec_args.is_empty() {
+        cli_args.push("--".to_string());
+        cli_args.extend(exec_args.iter().cloned());
+    }
+
     protontool::cli::main_cli(Some(cli_args));
 }
 
@@ -205,16 +206,19 @@ fn select_launch_target_gui(
     // Find zenity or yad
     let gui_tool = which("zenity").or_else(|| which("yad"))?;
 
-    // Collect custom prefixes
-    let custom_prefixes: Vec<_> = std::fs::read_dir(prefixes_dir)
+    // Collect custom prefixes, most recently used first
+    let mut custom_prefixes: Vec<PathBuf> = std::fs::read_dir(prefixes_dir)
         .ok()
         .map(|entries| {
             entries
                 .filter_map(|e| e.ok())
-                .filter(|e| e.path().is_dir())
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
                 .collect()
         })
         .unwrap_or_default();
+    custom_prefixes.sort();
+    protontool::wine::usage::sort_by_last_used(&mut custom_prefixes);
 
     if steam_apps.is_empty() && custom_prefixes.is_empty() {
         return None;
@@ -224,6 +228,8 @@ fn select_launch_target_gui(
         "--list".to_string(),
         "--title".to_string(),
         "Select target to run executable".to_string(),
+        "--text".to_string(),
+        "Select target to run executable".to_string(),
         "--column".to_string(),
         "Type".to_string(),
         "--column".to_string(),
@@ -240,8 +246,11 @@ fn select_launch_target_gui(
 
     // Add custom prefixes first
     for entry in &custom_prefixes {
-        let name = entry.file_name().to_string_lossy().to_string();
-        let path = entry.path().to_string_lossy().to_string();
+        let name = entry
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let path = entry.to_string_lossy().to_string();
         args.push("[Custom]".to_string());
         args.push(name);
         args.push(path);
@@ -254,7 +263,10 @@ fn select_launch_target_gui(
         args.push(app.appid.to_string());
     }
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+    let output = protontool::gui::dialog(&gui_tool)
+        .args(&args)
+        .output()
+        .ok()?;
 
     if !output.status.success() {
         return None;
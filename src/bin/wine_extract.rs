@@ -16,6 +16,8 @@ struct Args {
     wine_path: Option<PathBuf>,
     proton_path: Option<PathBuf>,
     output: Option<PathBuf>,
+    /// Also generate typed `NtStatus`/`HResult` enums
+    enums: bool,
     command: Command,
 }
 
@@ -40,6 +42,7 @@ OPTIONS:
     -w, --wine-path <PATH>     Path to Wine source directory (auto-detects Proton layout)
     -p, --proton-path <PATH>   Path to Proton repository (uses wine/ subdirectory)
     -o, --output <FILE>        Output file (stdout if not specified)
+    -e, --enums                Also generate NtStatus/HResult enums (ntstatus, winerror, all)
     -h, --help                 Print help information
 
 COMMANDS:
@@ -58,6 +61,7 @@ fn parse_args() -> Result<Args, String> {
     let mut wine_path = None;
     let mut proton_path = None;
     let mut output = None;
+    let mut enums = false;
     let mut command = None;
 
     let mut i = 1;
@@ -68,6 +72,7 @@ fn parse_args() -> Result<Args, String> {
                     wine_path: None,
                     proton_path: None,
                     output: None,
+                    enums: false,
                     command: Command::Help,
                 });
             }
@@ -92,6 +97,7 @@ fn parse_args() -> Result<Args, String> {
                 }
                 output = Some(PathBuf::from(&args[i]));
             }
+            "-e" | "--enums" => enums = true,
             "channels" => command = Some(Command::Channels),
             "ntstatus" => command = Some(Command::Ntstatus),
             "winerror" => command = Some(Command::Winerror),
@@ -113,6 +119,7 @@ fn parse_args() -> Result<Args, String> {
         wine_path,
         proton_path,
         output,
+        enums,
         command,
     })
 }
@@ -138,9 +145,9 @@ fn main() -> io::Result<()> {
 
     let output = match &args.command {
         Command::Channels => extract_channels(&wine_path)?,
-        Command::Ntstatus => extract_ntstatus(&wine_path)?,
-        Command::Winerror => extract_winerror(&wine_path)?,
-        Command::All => generate_all(&wine_path)?,
+        Command::Ntstatus => extract_ntstatus(&wine_path, args.enums)?,
+        Command::Winerror => extract_winerror(&wine_path, args.enums)?,
+        Command::All => generate_all(&wine_path, args.enums)?,
         Command::Protontool => generate_protontool(&wine_path)?,
         Command::Help => unreachable!(),
     };
@@ -215,13 +222,16 @@ fn resolve_wine_path(args: &Args) -> io::Result<PathBuf> {
     ))
 }
 
-/// Find pattern "WINE_DEFAULT_DEBUG_CHANNEL(name)" and extract name
+/// Find "WINE_DEFAULT_DEBUG_CHANNEL(name)" and "WINE_DECLARE_DEBUG_CHANNEL(name)" and extract name
 fn extract_debug_channel(content: &str) -> Vec<String> {
     let mut channels = Vec::new();
-    let pattern = "WINE_DEFAULT_DEBUG_CHANNEL";
+    let patterns = ["WINE_DEFAULT_DEBUG_CHANNEL", "WINE_DECLARE_DEBUG_CHANNEL"];
 
     for line in content.lines() {
-        if let Some(pos) = line.find(pattern) {
+        for pattern in patterns {
+            let Some(pos) = line.find(pattern) else {
+                continue;
+            };
             let rest = &line[pos + pattern.len()..];
             if let Some(start) = rest.find('(') {
                 let after_paren = &rest[start + 1..];
@@ -366,8 +376,8 @@ fn extract_channels(wine_path: &Path) -> io::Result<String> {
     Ok(output)
 }
 
-/// Extract NTSTATUS codes from ntstatus.h
-fn extract_ntstatus(wine_path: &Path) -> io::Result<String> {
+/// Read NTSTATUS codes from ntstatus.h, keyed by code
+fn ntstatus_codes(wine_path: &Path) -> io::Result<BTreeMap<u32, (String, String)>> {
     let ntstatus_path = wine_path.join("include/ntstatus.h");
     if !ntstatus_path.exists() {
         return Err(io::Error::new(
@@ -386,6 +396,12 @@ fn extract_ntstatus(wine_path: &Path) -> io::Result<String> {
     }
 
     eprintln!("Found {} NTSTATUS error/warning codes", codes.len());
+    Ok(codes)
+}
+
+/// Extract NTSTATUS codes from ntstatus.h
+fn extract_ntstatus(wine_path: &Path, enums: bool) -> io::Result<String> {
+    let codes = ntstatus_codes(wine_path)?;
 
     let mut output = String::new();
     output.push_str("/// NTSTATUS codes extracted from Wine ntstatus.h\n");
@@ -401,11 +417,25 @@ fn extract_ntstatus(wine_path: &Path) -> io::Result<String> {
 
     output.push_str("];\n");
 
+    if enums {
+        let entries: Vec<(u32, &str)> = codes
+            .iter()
+            .map(|(code, (name, _))| (*code, name.as_str()))
+            .collect();
+        output.push('\n');
+        output.push_str(&generate_code_enum(
+            "NtStatus",
+            "NTSTATUS_CODES",
+            "STATUS_",
+            &entries,
+        ));
+    }
+
     Ok(output)
 }
 
 /// Extract error codes from winerror.h
-fn extract_winerror(wine_path: &Path) -> io::Result<String> {
+fn extract_winerror(wine_path: &Path, enums: bool) -> io::Result<String> {
     let winerror_path = wine_path.join("include/winerror.h");
     if !winerror_path.exists() {
         return Err(io::Error::new(
@@ -437,7 +467,7 @@ fn extract_winerror(wine_path: &Path) -> io::Result<String> {
 
     output.push_str("/// HRESULT codes extracted from Wine winerror.h\n");
     output.push_str("pub const HRESULT_CODES: &[(u32, &str, &str)] = &[\n");
-    for (code, (name, desc)) in hresults.iter().take(200) {
+    for (code, (name, desc)) in hresults.iter().take(HRESULT_TABLE_LIMIT) {
         output.push_str(&format!(
             "    (0x{:08X}, \"{}\", \"{}\"),\n",
             code, name, desc
@@ -452,11 +482,29 @@ fn extract_winerror(wine_path: &Path) -> io::Result<String> {
     }
     output.push_str("];\n");
 
+    if enums {
+        let entries: Vec<(u32, &str)> = hresults
+            .iter()
+            .take(HRESULT_TABLE_LIMIT)
+            .map(|(code, (name, _))| (*code, name.as_str()))
+            .collect();
+        output.push('\n');
+        output.push_str(&generate_code_enum(
+            "HResult",
+            "HRESULT_CODES",
+            "E_",
+            &entries,
+        ));
+    }
+
     Ok(output)
 }
 
+/// Number of HRESULT codes kept in `HRESULT_CODES`
+const HRESULT_TABLE_LIMIT: usize = 200;
+
 /// Generate complete Rust module with all extracted data
-fn generate_all(wine_path: &Path) -> io::Result<String> {
+fn generate_all(wine_path: &Path, enums: bool) -> io::Result<String> {
     let mut output = String::new();
 
     output.push_str("//! Wine debug information extracted from Wine source code\n");
@@ -465,9 +513,9 @@ fn generate_all(wine_path: &Path) -> io::Result<String> {
 
     output.push_str(&extract_channels(wine_path)?);
     output.push_str("\n");
-    output.push_str(&extract_ntstatus(wine_path)?);
+    output.push_str(&extract_ntstatus(wine_path, enums)?);
     output.push_str("\n");
-    output.push_str(&extract_winerror(wine_path)?);
+    output.push_str(&extract_winerror(wine_path, enums)?);
 
     output.push_str(
         r#"
@@ -505,6 +553,114 @@ pub fn is_valid_channel(channel: &str) -> bool {
     Ok(output)
 }
 
+/// Rust variant name for a code: `STATUS_ACCESS_VIOLATION` -> `AccessViolation`
+fn variant_name(name: &str, strip_prefix: &str) -> String {
+    let stripped = name.strip_prefix(strip_prefix).unwrap_or(name);
+    let mut variant: String = stripped
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let lower = word.to_lowercase();
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if !variant.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        variant.insert(0, 'X');
+    }
+    variant
+}
+
+/// Generate a `#[repr(u32)]` enum over `codes` (sorted by code, as in the
+/// table), with `from_u32`, name/description lookups into `table` and a
+/// Display impl.
+fn generate_code_enum(
+    type_name: &str,
+    table: &str,
+    strip_prefix: &str,
+    codes: &[(u32, &str)],
+) -> String {
+    let mut used = BTreeSet::new();
+    let variants: Vec<(u32, String)> = codes
+        .iter()
+        .map(|(code, name)| {
+            let mut variant = variant_name(name, strip_prefix);
+            if !used.insert(variant.clone()) {
+                variant = format!("{}{:08X}", variant, code);
+                used.insert(variant.clone());
+            }
+            (*code, variant)
+        })
+        .collect();
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "/// Typed codes from `{}`, for matching without string comparisons\n",
+        table
+    ));
+    output.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    output.push_str("#[repr(u32)]\n");
+    output.push_str(&format!("pub enum {} {{\n", type_name));
+    for ((code, variant), (_, name)) in variants.iter().zip(codes) {
+        output.push_str(&format!("    /// {}\n", name));
+        output.push_str(&format!("    {} = 0x{:08X},\n", variant, code));
+    }
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl {} {{\n", type_name));
+    output.push_str(&format!(
+        "    /// Every code, in the same order as `{}`\n",
+        table
+    ));
+    output.push_str("    pub const ALL: &'static [Self] = &[\n");
+    for (_, variant) in &variants {
+        output.push_str(&format!("        Self::{},\n", variant));
+    }
+    output.push_str("    ];\n\n");
+    output.push_str(&format!(
+        r#"    /// Convert a raw code, None if it isn't in `{table}`
+    pub fn from_u32(code: u32) -> Option<Self> {{
+        {table}
+            .binary_search_by_key(&code, |(c, _, _)| *c)
+            .ok()
+            .map(|index| Self::ALL[index])
+    }}
+
+    /// Raw code value
+    pub fn code(self) -> u32 {{
+        self as u32
+    }}
+
+    /// Name as written in the Wine headers
+    pub fn name(self) -> &'static str {{
+        {table}[self.index()].1
+    }}
+
+    /// Human-readable description
+    pub fn description(self) -> &'static str {{
+        {table}[self.index()].2
+    }}
+
+    fn index(self) -> usize {{
+        {table}.partition_point(|(c, _, _)| *c < self.code())
+    }}
+}}
+
+impl std::fmt::Display for {type_name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        write!(f, "{{}} (0x{{:08X}})", self.name(), self.code())
+    }}
+}}
+"#,
+        table = table,
+        type_name = type_name
+    ));
+    output
+}
+
 /// Convert STATUS_NAME to human-readable description
 fn status_to_description(name: &str) -> String {
     let name = name.strip_prefix("STATUS_").unwrap_or(name);
@@ -523,6 +679,21 @@ fn error_to_description(name: &str) -> String {
     name.replace('_', " ").to_lowercase()
 }
 
+/// Wine version of a source tree, from its `VERSION` file ("Wine version
+/// 9.0") or the `AC_INIT` line of `configure.ac`.
+fn wine_source_version(wine_path: &Path) -> Option<String> {
+    if let Ok(content) = fs::read_to_string(wine_path.join("VERSION")) {
+        let version = content.trim().trim_start_matches("Wine version").trim();
+        if !version.is_empty() {
+            return Some(version.to_string());
+        }
+    }
+    let configure = fs::read_to_string(wine_path.join("configure.ac")).ok()?;
+    let init = configure.lines().find(|l| l.starts_with("AC_INIT("))?;
+    let version = init.split(',').nth(1)?;
+    Some(version.trim().trim_matches(['[', ']']).to_string())
+}
+
 /// Generate wine_data.rs module for protontool
 fn generate_protontool(wine_path: &Path) -> io::Result<String> {
     let dlls_path = wine_path.join("dlls");
@@ -559,6 +730,16 @@ fn generate_protontool(wine_path: &Path) -> io::Result<String> {
 "#,
     );
 
+    output.push_str("/// Wine version of the source tree the data was extracted from, if it was\n");
+    output.push_str("/// recorded\n");
+    match wine_source_version(wine_path) {
+        Some(version) => output.push_str(&format!(
+            "pub const WINE_SOURCE_VERSION: Option<&str> = Some(\"{}\");\n\n",
+            version
+        )),
+        None => output.push_str("pub const WINE_SOURCE_VERSION: Option<&str> = None;\n\n"),
+    }
+
     output.push_str("/// All Wine debug channels extracted from Wine source\n");
     output.push_str("/// Use with WINEDEBUG=+channel to enable tracing\n");
     output.push_str("pub const WINE_DEBUG_CHANNELS: &[&str] = &[\n");
@@ -750,5 +931,9 @@ pub const KNOWN_ERRORS: &[(&str, &str, &str)] = &[
     ("connection refused", "NET-REFUSED", "Network connection refused"),
     ("connection timed out", "NET-TIMEOUT", "Network connection timed out"),
     ("certificate", "NET-CERT", "SSL/TLS certificate issue"),
+    // Media playback (winegstreamer)
+    ("missing a plug-in", "MEDIA-GST-PLUGIN", "GStreamer plugin missing - a container or codec can't be played"),
+    ("no decoder available for type", "MEDIA-GST-DECODER", "No GStreamer decoder for a media type - codec plugin missing"),
+    ("failed to initialize gstreamer", "MEDIA-GST-INIT", "GStreamer failed to initialize - media playback unavailable"),
 ];
 "#;
@@ -0,0 +1,560 @@
+//! `protontool serve`: JSON-RPC requests over a Unix socket.
+//!
+//! Desktop widgets and other front ends talk to one long-running protontool
+//! instead of starting it for every operation. Every line a client sends is
+//! a request, `{"id": 1, "method": "games.list", "params": {...}}`, answered
+//! by one line, `{"id": 1, "result": ...}` or `{"id": 1, "error": "..."}`.
+//!
+//! Operations that change a prefix (`verbs.run`, `prefix.create`,
+//! `prefix.delete`) are queued on a [`JobScheduler`] and answered with the
+//! job's id right away; `jobs.list`, `jobs.status` and `jobs.cancel` follow
+//! them up. Operations on different prefixes run side by side, up to
+//! `[daemon] max_jobs` (default 2) at once.
+//!
+//! Every request is checked against the [`PermissionPolicy`] of
+//! `config.toml`, which grants only `read` unless configured.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use crate::jobs::{JobId, JobScheduler, JobState, JobStatus};
+use crate::json::{self, JsonValue};
+use crate::permissions::{OperationClass, PermissionPolicy, Target};
+use crate::steam::{find_proton_app, find_proton_by_name, get_steam_apps, ProtonApp, SteamApp};
+use crate::wine::{Wine, WineArch};
+
+/// Jobs run at once unless `[daemon] max_jobs` says otherwise.
+const DEFAULT_MAX_JOBS: usize = 2;
+
+/// The socket `serve` listens on without an explicit path.
+pub fn default_socket_path() -> PathBuf {
+    match std::env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir).join("protontool.sock"),
+        _ => crate::config::get_state_dir().join("protontool.sock"),
+    }
+}
+
+/// Request handler of the daemon, for one Steam installation.
+pub struct Daemon {
+    steam_path: PathBuf,
+    steam_root: PathBuf,
+    steam_lib_paths: Vec<PathBuf>,
+    jobs: JobScheduler,
+    policy: PermissionPolicy,
+}
+
+impl Daemon {
+    pub fn new(
+        steam_path: PathBuf,
+        steam_root: PathBuf,
+        steam_lib_paths: Vec<PathBuf>,
+        config: &crate::config::UserConfig,
+    ) -> Result<Self, String> {
+        let policy = PermissionPolicy::from_config(config)?;
+        let max_jobs = config
+            .get("daemon", "max_jobs")
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(DEFAULT_MAX_JOBS);
+        Ok(Self {
+            steam_path,
+            steam_root,
+            steam_lib_paths,
+            jobs: JobScheduler::new(max_jobs),
+            policy,
+        })
+    }
+
+    /// Answer one request line with one response line (without newline).
+    pub fn handle_line(&self, line: &str) -> String {
+        let request = match json::parse(line) {
+            Ok(request) => request,
+            Err(e) => return response(JsonValue::Null, Err(format!("Invalid request: {}", e))),
+        };
+        let id = request.get("id").cloned().unwrap_or(JsonValue::Null);
+        let method = match request.get("method").and_then(|m| m.as_str()) {
+            Some(method) => method,
+            None => return response(id, Err("The request has no method".to_string())),
+        };
+        let params = request
+            .get("params")
+            .cloned()
+            .unwrap_or(JsonValue::Object(Vec::new()));
+        response(id, self.call(method, &params))
+    }
+
+    fn call(&self, method: &str, params: &JsonValue) -> Result<JsonValue, String> {
+        let (op, target) = self.scope(method, params)?;
+        self.policy.check(op, &target)?;
+
+        match method {
+            "games.list" => Ok(self.list_games()),
+            "prefixes.list" => Ok(self.list_prefixes()),
+            "verbs.run" => self.run_verbs(params),
+            "prefix.create" => self.create_prefix(params),
+            "prefix.delete" => self.delete_prefix(params),
+            "game.launch" => {
+                let appid = appid_param(params)?;
+                crate::play::launch_via_steam(appid, &[])?;
+                Ok(JsonValue::Null)
+            }
+            "jobs.list" => Ok(JsonValue::Array(
+                self.jobs
+                    .list()
+                    .iter()
+                    .filter(|status| self.permits(OperationClass::Read, &job_target(status)))
+                    .map(job_json)
+                    .collect(),
+            )),
+            "jobs.status" => match self.jobs.status(job_param(params)?) {
+                Some(status) => Ok(job_json(&status)),
+                None => Err("Unknown job".to_string()),
+            },
+            "jobs.cancel" => Ok(JsonValue::Bool(self.jobs.cancel(job_param(params)?))),
+            _ => Err(format!("Unknown method '{}'", method)),
+        }
+    }
+
+    /// The operation class of a request and what it acts on, for the policy.
+    fn scope(&self, method: &str, params: &JsonValue) -> Result<(OperationClass, Target), String> {
+        let job_target = |params: &JsonValue| -> Result<Target, String> {
+            Ok(self
+                .jobs
+                .status(job_param(params)?)
+                .map_or(Target::None, |status| job_target(&status)))
+        };
+        Ok(match method {
+            "games.list" | "prefixes.list" | "jobs.list" => (OperationClass::Read, Target::None),
+            "jobs.status" => (OperationClass::Read, job_target(params)?),
+            "jobs.cancel" => (OperationClass::Modify, job_target(params)?),
+            "game.launch" => (OperationClass::Launch, Target::App(appid_param(params)?)),
+            "verbs.run" => match str_param(params, "prefix") {
+                Some(prefix) => (
+                    OperationClass::Modify,
+                    Target::Prefix(resolve_prefix(prefix)?),
+                ),
+                None => (OperationClass::Modify, Target::App(appid_param(params)?)),
+            },
+            "prefix.create" => (OperationClass::Create, Target::Prefix(path_param(params)?)),
+            "prefix.delete" => (OperationClass::Delete, Target::Prefix(path_param(params)?)),
+            _ => return Err(format!("Unknown method '{}'", method)),
+        })
+    }
+
+    fn permits(&self, op: OperationClass, target: &Target) -> bool {
+        self.policy.check(op, target).is_ok()
+    }
+
+    fn steam_apps(&self) -> Vec<SteamApp> {
+        get_steam_apps(&self.steam_root, &self.steam_path, &self.steam_lib_paths)
+    }
+
+    fn list_games(&self) -> JsonValue {
+        let games = self
+            .steam_apps()
+            .into_iter()
+            .filter(|app| app.is_windows_app())
+            .filter(|app| self.permits(OperationClass::Read, &Target::App(app.appid)))
+            .map(|app| {
+                JsonValue::Object(vec![
+                    ("appid".into(), JsonValue::Number(app.appid as f64)),
+                    ("name".into(), JsonValue::String(app.name)),
+                    ("prefix".into(), path_json(app.prefix_path.as_deref())),
+                ])
+            })
+            .collect();
+        JsonValue::Array(games)
+    }
+
+    fn list_prefixes(&self) -> JsonValue {
+        let prefixes: Vec<PathBuf> = custom_prefixes()
+            .into_iter()
+            .filter(|path| self.permits(OperationClass::Read, &Target::Prefix(path.clone())))
+            .collect();
+        prefixes_json(&prefixes)
+    }
+
+    /// The prefix and runner a request works on: a game (`appid`) or a
+    /// custom prefix (`prefix`) with the Proton it was created with.
+    fn target(&self, params: &JsonValue) -> Result<(PathBuf, ProtonApp, WineArch), String> {
+        let steam_apps = self.steam_apps();
+        let (prefix_path, proton_app, arch) = if let Some(prefix) = str_param(params, "prefix") {
+            let prefix_path = resolve_prefix(prefix)?;
+            if !prefix_path.exists() {
+                return Err(format!("Prefix path does not exist: {}", prefix));
+            }
+            let metadata = crate::wine::metadata::PrefixMetadata::load(&prefix_path);
+            let proton_name = str_param(params, "proton")
+                .or(metadata.get("proton_name"))
+                .ok_or("The prefix has no saved Proton version; pass \"proton\"")?;
+            let proton_app = find_proton_by_name(&steam_apps, proton_name)
+                .ok_or(format!("Proton version '{}' not found", proton_name))?;
+            let arch = metadata
+                .get("arch")
+                .and_then(WineArch::from_str)
+                .unwrap_or(WineArch::Win64);
+            (prefix_path, proton_app, arch)
+        } else {
+            let appid = appid_param(params)?;
+            let steam_app = steam_apps
+                .iter()
+                .find(|app| app.appid == appid && app.is_windows_app())
+                .ok_or(format!("App {} is not installed or has no prefix", appid))?;
+            let prefix_path = steam_app.prefix_path.clone().unwrap_or_default();
+            let proton_app = find_proton_app(&self.steam_path, &steam_apps, appid)
+                .ok_or("Proton installation could not be found")?;
+            (prefix_path, proton_app, WineArch::Win64)
+        };
+        if !proton_app.is_proton_ready {
+            return Err(format!("{} is not ready", proton_app.name));
+        }
+        if crate::wine::image::has_image(&prefix_path) {
+            crate::wine::image::mount(&prefix_path)?;
+        }
+        Ok((prefix_path, proton_app, arch))
+    }
+
+    /// Queue `verbs` for the prefix of `appid` or `prefix`. A cancelled job
+    /// stops before its next verb.
+    fn run_verbs(&self, params: &JsonValue) -> Result<JsonValue, String> {
+        let verbs = params
+            .get("verbs")
+            .and_then(|v| v.as_array())
+            .map(|verbs| verbs.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>())
+            .filter(|verbs| !verbs.is_empty())
+            .ok_or("\"verbs\" must be a list of verb names")?;
+        let registry = crate::wine::VerbRegistry::new();
+        for verb in &verbs {
+            registry.resolve(verb)?;
+        }
+        let verbs: Vec<String> = verbs.into_iter().map(String::from).collect();
+        let (prefix_path, proton_app, arch) = self.target(params)?;
+
+        let name = format!("{} in {}", verbs.join(" "), prefix_path.display());
+        let resource = prefix_path.to_string_lossy().into_owned();
+        let id = self.jobs.submit(&name, Some(&resource), move |cancel| {
+            let wine = Wine::new_with_arch(&proton_app, &prefix_path, arch);
+            for verb in &verbs {
+                if cancel.is_cancelled() {
+                    return Err(format!("Cancelled before {}", verb));
+                }
+                wine.run_verb(verb)?;
+            }
+            Ok(())
+        });
+        Ok(job_id_json(id))
+    }
+
+    /// Queue the creation of a custom prefix at `path` with the Proton
+    /// version `proton`.
+    fn create_prefix(&self, params: &JsonValue) -> Result<JsonValue, String> {
+        let prefix_path = path_param(params)?;
+        if prefix_path.exists() {
+            return Err(format!("{} already exists", prefix_path.display()));
+        }
+        let proton_name = str_param(params, "proton").ok_or("\"proton\" is required")?;
+        let proton_app = find_proton_by_name(&self.steam_apps(), proton_name)
+            .filter(|app| app.is_proton_ready)
+            .ok_or(format!(
+                "Proton version '{}' not found or not ready",
+                proton_name
+            ))?;
+        let arch = match str_param(params, "arch") {
+            Some(arch) => WineArch::from_str(arch).ok_or(format!("Unknown arch '{}'", arch))?,
+            None => WineArch::Win64,
+        };
+
+        let name = format!("create {}", prefix_path.display());
+        let resource = prefix_path.to_string_lossy().into_owned();
+        let id = self.jobs.submit(&name, Some(&resource), move |_| {
+            super::create_prefix(&prefix_path, &proton_app, arch)
+        });
+        Ok(job_id_json(id))
+    }
+
+    /// Queue the deletion of the custom prefix at `path`, after the jobs
+    /// already queued for it.
+    fn delete_prefix(&self, params: &JsonValue) -> Result<JsonValue, String> {
+        let prefix_path = path_param(params)?;
+        if !is_prefix(&prefix_path) {
+            return Err(format!("{} is not a Wine prefix", prefix_path.display()));
+        }
+
+        let name = format!("delete {}", prefix_path.display());
+        let resource = prefix_path.to_string_lossy().into_owned();
+        let id = self.jobs.submit(&name, Some(&resource), move |_| {
+            super::delete_prefix_files(&prefix_path)
+        });
+        Ok(job_id_json(id))
+    }
+}
+
+/// Listen on `socket_path` and answer clients until the process ends. The
+/// socket is only accessible to the current user.
+pub fn serve(daemon: Daemon, socket_path: &Path) -> Result<(), String> {
+    if socket_path.exists() {
+        if UnixStream::connect(socket_path).is_ok() {
+            return Err(format!(
+                "Another protontool is already serving on {}",
+                socket_path.display()
+            ));
+        }
+        std::fs::remove_file(socket_path)
+            .map_err(|e| format!("Failed to remove {}: {}", socket_path.display(), e))?;
+    }
+    if let Some(parent) = socket_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let listener = UnixListener::bind(socket_path)
+        .map_err(|e| format!("Failed to listen on {}: {}", socket_path.display(), e))?;
+    std::fs::set_permissions(socket_path, std::fs::Permissions::from_mode(0o600))
+        .map_err(|e| format!("Failed to restrict {}: {}", socket_path.display(), e))?;
+
+    let daemon = Arc::new(daemon);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                crate::log::warn(&format!("Failed to accept a client: {}", e));
+                continue;
+            }
+        };
+        let daemon = Arc::clone(&daemon);
+        std::thread::spawn(move || serve_client(&daemon, stream));
+    }
+    Ok(())
+}
+
+/// Answer the requests of one client until it disconnects.
+fn serve_client(daemon: &Daemon, stream: UnixStream) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(e) => {
+            crate::log::warn(&format!("Failed to answer a client: {}", e));
+            return;
+        }
+    };
+    for line in BufReader::new(stream).lines() {
+        let line = match line {
+            Ok(line) if line.trim().is_empty() => continue,
+            Ok(line) => line,
+            Err(_) => return,
+        };
+        let answer = daemon.handle_line(&line);
+        if writeln!(writer, "{}", answer).is_err() {
+            return;
+        }
+    }
+}
+
+fn response(id: JsonValue, result: Result<JsonValue, String>) -> String {
+    let body = match result {
+        Ok(result) => ("result".to_string(), result),
+        Err(error) => ("error".to_string(), JsonValue::String(error)),
+    };
+    JsonValue::Object(vec![("id".to_string(), id), body]).to_string()
+}
+
+/// A prefix path from a request, absolute and with symlinks resolved, so
+/// the policy's prefix check can't be sidestepped.
+fn resolve_prefix(path: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(crate::wine::custom::expand_path(path));
+    if !path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+        return Err(format!(
+            "Prefix paths must be absolute, without '..': {}",
+            path.display()
+        ));
+    }
+    Ok(path.canonicalize().unwrap_or(path))
+}
+
+fn path_param(params: &JsonValue) -> Result<PathBuf, String> {
+    resolve_prefix(str_param(params, "path").ok_or("\"path\" is required")?)
+}
+
+fn str_param<'a>(params: &'a JsonValue, key: &str) -> Option<&'a str> {
+    params.get(key).and_then(|v| v.as_str())
+}
+
+fn appid_param(params: &JsonValue) -> Result<u32, String> {
+    params
+        .get("appid")
+        .and_then(|v| v.as_f64())
+        .filter(|n| n.fract() == 0.0 && *n > 0.0 && *n <= u32::MAX as f64)
+        .map(|n| n as u32)
+        .ok_or("\"appid\" must be a Steam app ID".to_string())
+}
+
+fn job_param(params: &JsonValue) -> Result<JobId, String> {
+    params
+        .get("job")
+        .and_then(|v| v.as_f64())
+        .filter(|n| n.fract() == 0.0 && *n >= 0.0)
+        .map(|n| n as JobId)
+        .ok_or("\"job\" must be a job id".to_string())
+}
+
+/// Whether `path` holds a Wine prefix (or the disk image of one), so
+/// `prefix.delete` can't remove arbitrary directories.
+fn is_prefix(path: &Path) -> bool {
+    crate::wine::image::has_image(path) || path.join("system.reg").is_file()
+}
+
+/// The custom prefixes in `~/.protontool/pfx`.
+fn custom_prefixes() -> Vec<PathBuf> {
+    let mut prefixes: Vec<PathBuf> = std::fs::read_dir(crate::config::get_prefixes_dir())
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    prefixes.retain(|path| is_prefix(path));
+    prefixes.sort();
+    prefixes
+}
+
+fn prefixes_json(prefixes: &[PathBuf]) -> JsonValue {
+    JsonValue::Array(
+        prefixes
+            .iter()
+            .map(|path| {
+                let metadata = crate::wine::metadata::PrefixMetadata::load(path);
+                JsonValue::Object(vec![
+                    ("path".into(), path_json(Some(path))),
+                    (
+                        "proton".into(),
+                        metadata
+                            .get("proton_name")
+                            .map_or(JsonValue::Null, |n| JsonValue::String(n.into())),
+                    ),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn path_json(path: Option<&Path>) -> JsonValue {
+    path.map_or(JsonValue::Null, |p| {
+        JsonValue::String(p.to_string_lossy().into_owned())
+    })
+}
+
+/// What a job works on: the prefix it was queued for.
+fn job_target(status: &JobStatus) -> Target {
+    status
+        .resource
+        .as_ref()
+        .map_or(Target::None, |resource| Target::Prefix(resource.into()))
+}
+
+fn job_id_json(id: JobId) -> JsonValue {
+    JsonValue::Object(vec![("job".into(), JsonValue::Number(id as f64))])
+}
+
+fn job_json(status: &JobStatus) -> JsonValue {
+    let (state, error) = match &status.state {
+        JobState::Queued => ("queued", None),
+        JobState::Running => ("running", None),
+        JobState::Succeeded => ("succeeded", None),
+        JobState::Failed(e) => ("failed", Some(e)),
+        JobState::Cancelled => ("cancelled", None),
+    };
+    let mut entries = vec![
+        ("id".to_string(), JsonValue::Number(status.id as f64)),
+        ("name".to_string(), JsonValue::String(status.name.clone())),
+        ("state".to_string(), JsonValue::String(state.to_string())),
+    ];
+    if let Some(error) = error {
+        entries.push(("error".to_string(), JsonValue::String(error.clone())));
+    }
+    JsonValue::Object(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daemon_requests() {
+        let steam = std::env::temp_dir().join(format!("pt-daemon-{}", std::process::id()));
+        let steamapps = steam.join("steamapps");
+        std::fs::create_dir_all(steamapps.join("compatdata/620/pfx")).unwrap();
+        std::fs::write(
+            steamapps.join("appmanifest_620.acf"),
+            "\"AppState\"\n{\n\t\"appid\"\t\"620\"\n\t\"name\"\t\"Portal 2\"\n\t\"installdir\"\t\"Portal 2\"\n}\n",
+        )
+        .unwrap();
+        let daemon = |config: &str| {
+            let config = crate::config::UserConfig::parse(config);
+            Daemon::new(steam.clone(), steam.clone(), vec![steam.clone()], &config).unwrap()
+        };
+        let restricted = daemon("");
+        let daemon = daemon("[daemon]\nallow = [\"*\"]\n");
+        let call = |line: &str| json::parse(&daemon.handle_line(line)).unwrap();
+
+        let games = call(r#"{"id": 1, "method": "games.list"}"#);
+        assert_eq!(games.get("id"), Some(&JsonValue::Number(1.0)));
+        let games = games.get("result").and_then(|r| r.as_array()).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].get("appid"), Some(&JsonValue::Number(620.0)));
+
+        let error = |line: &str| {
+            call(line)
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        assert!(error("not json").starts_with("Invalid request"));
+        assert_eq!(
+            error(r#"{"id": 2, "method": "nope"}"#),
+            "Unknown method 'nope'"
+        );
+        assert!(error(
+            r#"{"method": "verbs.run", "params": {"appid": 620, "verbs": ["no-such-verb"]}}"#
+        )
+        .contains("no-such-verb"));
+        // Without a Proton installation the verbs aren't queued
+        assert!(!error(
+            r#"{"method": "verbs.run", "params": {"appid": 620, "verbs": ["vcrun2022"]}}"#
+        )
+        .is_empty());
+        assert!(
+            error(r#"{"method": "prefix.delete", "params": {"path": "/tmp"}}"#)
+                .contains("not a Wine prefix")
+        );
+        assert_eq!(
+            error(r#"{"method": "jobs.status", "params": {"job": 7}}"#),
+            "Unknown job"
+        );
+        assert_eq!(
+            call(r#"{"id": 3, "method": "jobs.list"}"#).get("result"),
+            Some(&JsonValue::Array(vec![]))
+        );
+
+        // Without a [daemon] section clients may only read
+        let error = |line: &str| {
+            json::parse(&restricted.handle_line(line))
+                .unwrap()
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        assert_eq!(error(r#"{"method": "games.list"}"#), "");
+        assert_eq!(
+            error(r#"{"method": "verbs.run", "params": {"appid": 620, "verbs": ["vcrun2022"]}}"#),
+            "Operation 'modify' is not permitted"
+        );
+        assert_eq!(
+            error(r#"{"method": "prefix.delete", "params": {"path": "/tmp/pfx"}}"#),
+            "Operation 'delete' is not permitted"
+        );
+        assert!(
+            error(r#"{"method": "prefix.create", "params": {"path": "pfx/../../etc"}}"#)
+                .contains("without '..'")
+        );
+
+        std::fs::remove_dir_all(&steam).ok();
+    }
+}
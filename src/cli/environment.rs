@@ -0,0 +1,95 @@
+//! System services used by the CLI.
+//!
+//! Steam discovery, GUI prompts and process exit go through the traits here
+//! so the argument dispatch can be exercised in tests without a Steam
+//! installation, dialogs or terminating the test process.
+
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::gui::{prompt_filesystem_access, select_steam_installation};
+use crate::steam::{find_steam_installations, SteamInstallation};
+
+/// Finds Steam installations.
+pub trait SteamDiscovery {
+    fn find_installations(&self) -> Vec<SteamInstallation>;
+}
+
+/// Interactive prompts needed before the CLI can proceed.
+pub trait GuiProvider {
+    /// Pick one of several Steam installations. None if cancelled.
+    fn select_steam_installation(
+        &self,
+        installations: &[SteamInstallation],
+    ) -> Option<SteamInstallation>;
+
+    /// Check access to paths outside a sandbox (Flatpak), telling the user
+    /// how to grant it. Returns false if a path is inaccessible.
+    fn prompt_filesystem_access(&self, paths: &[&Path], no_term: bool) -> bool;
+}
+
+/// Terminates the CLI.
+pub trait ExitHandler {
+    /// Report a fatal error and exit with status 1.
+    fn exit_with_error(&self, error: &str, no_term: bool) -> !;
+
+    /// Exit with the given status.
+    fn exit(&self, code: i32) -> !;
+}
+
+/// The services the CLI runs with.
+pub struct CliEnv {
+    pub steam: Box<dyn SteamDiscovery>,
+    pub gui: Box<dyn GuiProvider>,
+    pub exit: Box<dyn ExitHandler>,
+    /// Directory holding protontool's config and state in place of
+    /// `~/.protontool`; None for the default.
+    pub base_dir: Option<PathBuf>,
+}
+
+impl CliEnv {
+    /// The real system: Steam on disk, zenity/yad dialogs, process exit.
+    pub fn system() -> Self {
+        CliEnv {
+            steam: Box::new(SystemSteam),
+            gui: Box::new(SystemGui),
+            exit: Box::new(ProcessExit),
+            base_dir: None,
+        }
+    }
+}
+
+struct SystemSteam;
+
+impl SteamDiscovery for SystemSteam {
+    fn find_installations(&self) -> Vec<SteamInstallation> {
+        find_steam_installations()
+    }
+}
+
+struct SystemGui;
+
+impl GuiProvider for SystemGui {
+    fn select_steam_installation(
+        &self,
+        installations: &[SteamInstallation],
+    ) -> Option<SteamInstallation> {
+        select_steam_installation(installations)
+    }
+
+    fn prompt_filesystem_access(&self, paths: &[&Path], no_term: bool) -> bool {
+        prompt_filesystem_access(paths, no_term)
+    }
+}
+
+struct ProcessExit;
+
+impl ExitHandler for ProcessExit {
+    fn exit_with_error(&self, error: &str, no_term: bool) -> ! {
+        super::util::exit_with_error(error, no_term)
+    }
+
+    fn exit(&self, code: i32) -> ! {
+        process::exit(code)
+    }
+}
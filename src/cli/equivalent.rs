@@ -0,0 +1,84 @@
+//! Equivalent command lines for GUI actions.
+//!
+//! After the GUI does something that has a non-interactive form, the
+//! matching `protontool ...` invocation is printed so it can be scripted or
+//! repeated later. With `copy_commands = "true"` in the `[gui]` section of
+//! `config.toml` it is also copied to the clipboard.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::config::UserConfig;
+use crate::util::{shell_quote, which};
+
+/// The `protontool` command line for `args`, quoted for a POSIX shell.
+///
+/// ```
+/// use protontool::cli::equivalent::command_line;
+/// assert_eq!(
+///     command_line(&["1091500", "vcrun2022", "corefonts"]),
+///     "protontool 1091500 vcrun2022 corefonts"
+/// );
+/// assert_eq!(
+///     command_line(&["--prefix", "/home/me/My Prefix", "-c", "app.exe", "--", "-w"]),
+///     "protontool --prefix '/home/me/My Prefix' -c app.exe -- -w"
+/// );
+/// ```
+pub fn command_line(args: &[&str]) -> String {
+    std::iter::once("protontool".to_string())
+        .chain(args.iter().map(|arg| shell_quote(arg)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether `config.toml` asks for commands to be copied to the clipboard.
+fn copy_enabled(config: &UserConfig) -> bool {
+    config
+        .get("gui", "copy_commands")
+        .is_some_and(|v| matches!(v, "true" | "1" | "yes"))
+}
+
+/// Put text on the clipboard with wl-copy, xclip or xsel.
+fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    let candidates: [(&str, &[&str]); 3] = [
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+    let (tool, args) = candidates
+        .iter()
+        .find_map(|(name, args)| {
+            // wl-copy only works in a Wayland session
+            if *name == "wl-copy" && std::env::var_os("WAYLAND_DISPLAY").is_none() {
+                return None;
+            }
+            which(name).map(|tool| (tool, *args))
+        })
+        .ok_or("No clipboard tool found (wl-copy, xclip or xsel)")?;
+    let mut child = Command::new(&tool)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to run {}: {}", tool.display(), e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| format!("Failed to write to {}: {}", tool.display(), e))?;
+    }
+    // xclip and xsel stay around to serve the selection; don't wait for them
+    Ok(())
+}
+
+/// Print the command line equivalent to a GUI action, and copy it to the
+/// clipboard if configured.
+pub fn show(args: &[&str]) {
+    let line = command_line(args);
+    println!("Equivalent command: {}", line);
+    if copy_enabled(&UserConfig::load()) {
+        if let Err(e) = copy_to_clipboard(&line) {
+            crate::log::warn(&format!("Could not copy the command: {}", e));
+        }
+    }
+}
@@ -6,22 +6,26 @@
 //! - Prefix creation/deletion
 //! - Running commands with Wine environment
 
+pub mod daemon;
+pub mod environment;
+pub mod equivalent;
 pub mod util;
 
-use std::env;
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use crate::cli::environment::CliEnv;
 use crate::cli::util::{enable_logging, exit_with_error, ArgParser};
 use crate::gui::{
-    get_prefix_name_gui, prompt_filesystem_access, select_custom_prefix_gui,
-    select_prefix_location_gui, select_proton_with_gui, select_steam_app_with_gui,
-    select_steam_installation, select_steam_library_paths, select_verb_category_gui,
-    select_verbs_with_gui, show_main_menu_gui, GuiAction,
+    ask_question_gui, get_prefix_name_gui, select_custom_prefix_gui, select_prefix_location_gui,
+    select_proton_with_gui, select_steam_app_with_gui, select_steam_library_paths,
+    select_verb_category_gui, select_verbs_with_gui, show_main_menu_gui, GuiAction,
 };
 use crate::steam::{
-    find_proton_app, find_proton_by_name, find_steam_installations, get_proton_apps,
-    get_steam_apps, get_steam_lib_paths,
+    find_proton_app, find_proton_by_name, find_screenshot_dirs, get_proton_apps, get_steam_apps,
+    get_steam_lib_paths, SteamApp,
 };
 use crate::util::output_to_string;
 use crate::wine::Wine;
@@ -29,14 +33,402 @@ use crate::wine::Wine;
 /// Main CLI entry point. Parses arguments and dispatches to appropriate handler.
 /// If `args` is None, uses command-line arguments from env::args().
 pub fn main_cli(args: Option<Vec<String>>) {
-    let args = args.unwrap_or_else(|| env::args().skip(1).collect());
+    main_cli_with_env(args, &CliEnv::system());
+}
+
+/// Set the download limits from `--max-downloads`/`--limit-rate`, falling
+/// back to the `[downloads]` section of `config.toml`.
+fn apply_download_limits(
+    parsed: &util::ParsedArgs,
+    config: &crate::config::UserConfig,
+) -> Result<(), String> {
+    use crate::wine::download;
+
+    let (config_parallel, config_rate) = download::limits_from_config(config);
+    let max_parallel = match parsed.get_option("max_downloads") {
+        Some(value) => Some(
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or("--max-downloads must be a positive number")?,
+        ),
+        None => config_parallel,
+    };
+    let rate = match parsed.get_option("limit_rate") {
+        Some(value) => Some(
+            download::parse_rate(value)
+                .ok_or("--limit-rate must be bytes per second, like 500K or 2M")?,
+        ),
+        None => config_rate,
+    };
+    download::set_download_limits(max_parallel.unwrap_or(download::DEFAULT_MAX_PARALLEL), rate);
+    Ok(())
+}
+
+/// Like [`main_cli`], with Steam discovery, GUI prompts and process exit
+/// taken from `env`.
+pub fn main_cli_with_env(args: Option<Vec<String>>, env: &CliEnv) {
+    let args = args.unwrap_or_else(|| std::env::args().skip(1).collect());
+
+    let parser = build_parser();
+    let parsed = match parser.parse(&args) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("{}", parser.help());
+            eprintln!("protontool: error: {}", e);
+            env.exit.exit(2);
+        }
+    };
+
+    crate::util::set_plain_output(parsed.get_flag("plain"));
+    crate::config::set_base_dir(env.base_dir.clone());
+    let config = crate::config::UserConfig::load();
+    crate::wine::set_show_wine_output(
+        parsed.get_flag("show_wine_output") || crate::wine::wine_output_shown_by_config(&config),
+    );
+    if let Err(e) = apply_download_limits(&parsed, &config) {
+        eprintln!("protontool: error: {}", e);
+        env.exit.exit(2);
+    }
+    if let Err(e) = crate::log::apply_config(&config) {
+        eprintln!("protontool: error: {}", e);
+        env.exit.exit(2);
+    }
+
+    if parsed.get_flag("help") {
+        println!("{}", parser.help());
+        return;
+    }
+
+    if parsed.get_flag("version") {
+        if parsed.get_flag("json") {
+            run_version_mode(&parsed);
+        } else {
+            println!("protontool ({})", crate::VERSION);
+        }
+        return;
+    }
+
+    let no_term = parsed.get_flag("no_term");
+    let verbose = parsed.get_count("verbose");
+
+    enable_logging(verbose);
+    crate::wine::set_read_only(parsed.get_flag("read_only"));
+    if let Some(spec) = parsed.get_option("winedebug") {
+        match crate::wine::winedebug::validate(spec) {
+            Ok(spec) => crate::wine::winedebug::set_override(Some(spec)),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        }
+    }
+    ASSUME_YES.store(
+        parsed.get_flag("yes") || assume_yes_by_config(&config),
+        Ordering::SeqCst,
+    );
+    ACCEPT_LICENSES.store(parsed.get_flag("accept_licenses"), Ordering::SeqCst);
+    crate::wine::verbs::set_action_failure_prompt(if no_term {
+        Some(ask_action_failure_gui)
+    } else if io::IsTerminal::is_terminal(&io::stdin()) {
+        Some(ask_action_failure_terminal)
+    } else {
+        None
+    });
+    crate::workdir::sweep_stale();
+    if crate::wine::ownership::is_root() && !parsed.get_flag("fix_ownership") {
+        let warning = crate::wine::ownership::root_warning();
+        crate::log::warn(&warning);
+        eprintln!("WARNING: {}\n", warning);
+    }
+
+    let action = match select_action(&parsed, args.is_empty()) {
+        Ok(a) => a,
+        Err(e) => env.exit.exit_with_error(&e, no_term),
+    };
+    if let Some(prefix) = custom_prefix(&action) {
+        mount_prefix_image(env, Path::new(prefix), no_term);
+    }
+    if parsed.get_flag("audit") {
+        run_audited(env, &action, &args, &parsed, no_term);
+    }
+
+    match action {
+        CliAction::Help => println!("{}", parser.help()),
+        CliAction::Conflict => {
+            eprintln!("Only one action can be performed at a time.");
+            println!("{}", parser.help());
+        }
+        CliAction::Gui => run_gui_mode(env, no_term),
+        CliAction::ListApps => run_list_mode(env, &parsed, no_term),
+        CliAction::RunVerbs { appid, verbs } => run_verb_mode(env, appid, &verbs, &parsed, no_term),
+        CliAction::PrefixCommand { prefix, command } => {
+            run_prefix_command_mode(env, &prefix, &command, &parsed, no_term)
+        }
+        CliAction::Command { appid, command } => {
+            run_command_mode(env, appid, &command, &parsed, no_term)
+        }
+        CliAction::CreatePrefix(prefix) => run_create_prefix_mode(env, &prefix, &parsed, no_term),
+        CliAction::DeletePrefix(prefix) => run_delete_prefix_mode(&prefix, no_term),
+        CliAction::AdoptPrefix(prefix) => run_adopt_prefix_mode(env, &prefix, &parsed, no_term),
+        CliAction::PrefixCleanup(prefix) => {
+            run_custom_prefix_mode(env, &prefix, &[], &parsed, no_term)
+        }
+        CliAction::PrefixFixOwnership(prefix) => {
+            run_fix_ownership_mode(Path::new(&prefix), &parsed, no_term)
+        }
+        CliAction::ZDrive { prefix, value } => run_z_drive_mode(&prefix, &value, no_term),
+        CliAction::PrefixImage { prefix, value } => {
+            run_prefix_image_mode(Path::new(&prefix), &value, no_term)
+        }
+        CliAction::PrefixMount { prefix, spec } => {
+            run_mount_mode(Path::new(&prefix), &spec, no_term)
+        }
+        CliAction::Sandbox { prefix, value } => run_sandbox_mode(&prefix, &value, no_term),
+        CliAction::UserDirs { prefix, value } => run_user_dirs_mode(&prefix, &value, no_term),
+        CliAction::UsePrefix { prefix, verbs } => {
+            run_custom_prefix_mode(env, &prefix, &verbs, &parsed, no_term)
+        }
+        CliAction::ImportProtonfixes(source) => run_import_protonfixes_mode(&source, no_term),
+        CliAction::AnalyzeLog(log_path) => run_analyze_log_mode(&log_path, &parsed, no_term),
+        CliAction::OpenFolder { appid, target } => {
+            run_open_folder_mode(env, appid, &target, &parsed, no_term)
+        }
+        CliAction::Play(appid) => run_play_mode(env, appid, &parsed, no_term),
+        CliAction::DxvkConf { appid, args } => {
+            run_dxvk_conf_mode(env, appid, &args, &parsed, no_term)
+        }
+        CliAction::Workshop { appid, args } => {
+            run_workshop_mode(env, appid, &args, &parsed, no_term)
+        }
+        CliAction::Services { appid, args } => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_services_command(&wine_ctx, &args, no_term)
+        }
+        CliAction::Certs { appid, args } => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_certs_command(&wine_ctx, &args, no_term)
+        }
+        CliAction::Doctor { appid, args } => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_doctor_command(&wine_ctx, &args, no_term)
+        }
+        CliAction::Deferred { appid, args } => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_deferred_command(&wine_ctx, &args, no_term)
+        }
+        CliAction::LaunchOptions { appid, args } => {
+            run_launch_options_mode(env, appid, &args, &parsed, no_term)
+        }
+        CliAction::WineDebug { appid, args } => {
+            let (_, prefix_path) = find_steam_app_prefix(env, appid, &parsed, no_term);
+            run_winedebug_command(&prefix_path, &args, no_term)
+        }
+        CliAction::Notes { appid, args } => {
+            run_notes_command(&crate::notes::Subject::App(appid), &args, no_term)
+        }
+        CliAction::TestController { appid, args } => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_test_controller_command(&wine_ctx, Some(appid), &args, no_term)
+        }
+        CliAction::Snapshot { appid, args } => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_snapshot_command(&wine_ctx, &args, no_term)
+        }
+        CliAction::Compare { appid, args } => {
+            let (_, prefix_path) = find_steam_app_prefix(env, appid, &parsed, no_term);
+            run_compare_command(env, &prefix_path, &args, &parsed, no_term)
+        }
+        CliAction::Vkd3dConfig { appid, args } => {
+            run_vkd3d_config_mode(env, appid, &args, &parsed, no_term)
+        }
+        CliAction::CleanupApp(appid) => run_cleanup_app_mode(env, appid, &parsed, no_term),
+        CliAction::FixOwnershipApp(appid) => {
+            let (_, prefix_path) = find_steam_app_prefix(env, appid, &parsed, no_term);
+            run_fix_ownership_mode(&prefix_path, &parsed, no_term)
+        }
+        CliAction::RollbackApp(appid) => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_rollback_last(&wine_ctx, no_term)
+        }
+        CliAction::VerifyGraphicsApp(appid) => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_verify_graphics(&wine_ctx, &parsed, no_term)
+        }
+        CliAction::OverridesApp(appid) => {
+            let wine_ctx = app_wine_context(env, appid, &parsed, no_term);
+            run_overrides_command(&wine_ctx, &parsed, no_term)
+        }
+        CliAction::MountApp { appid, spec } => {
+            let (_, prefix_path) = find_steam_app_prefix(env, appid, &parsed, no_term);
+            run_mount_mode(&prefix_path, &spec, no_term)
+        }
+        CliAction::Prewarm(appid) => run_prewarm_mode(env, appid, &parsed, no_term),
+        CliAction::Undo => run_undo_mode(no_term),
+        CliAction::Version => run_version_mode(&parsed),
+        CliAction::Recipe(args) => run_recipe_mode(env, &args, &parsed, no_term),
+        CliAction::Migrate(args) => run_migrate_mode(env, &args, &parsed, no_term),
+        CliAction::SearchApp(query) => run_search_app_mode(&query),
+        CliAction::Verbs(args) => run_verbs_mode(&args, &parsed, no_term),
+        CliAction::Nxm(url) => run_nxm_mode(&url, no_term),
+        CliAction::Serve(socket) => run_serve_mode(env, socket.as_deref(), &parsed, no_term),
+        CliAction::RunDefault { program, args } => {
+            run_default_prefix_mode(env, &program, &args, &parsed, no_term)
+        }
+        CliAction::RegisterFileHandler => match crate::desktop::register_file_handler() {
+            Ok(path) => println!(
+                "Registered {} as the handler for .exe and .msi files.",
+                path.display()
+            ),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        },
+        CliAction::UnregisterFileHandler => match crate::desktop::unregister_file_handler() {
+            Ok(true) => println!("Removed the protontool .exe/.msi file handler."),
+            Ok(false) => println!("The protontool file handler is not registered."),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        },
+        CliAction::InstallDesktopEntry => match crate::desktop::install_desktop_entry() {
+            Ok(path) => println!("Installed {} and the protontool icon.", path.display()),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        },
+    }
+}
+
+/// Run the command line without `--audit` in a child process, capturing
+/// the prefix it works on before and after, and save the audit record.
+/// Exits with the child's exit code.
+fn run_audited(
+    env: &CliEnv,
+    action: &CliAction,
+    args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) -> ! {
+    use crate::audit::{AuditRecord, PrefixState};
+
+    let prefix_path = match parsed
+        .get_option("prefix")
+        .or(parsed.get_option("create_prefix"))
+    {
+        Some(prefix) => PathBuf::from(prefix),
+        None => match action_appid(action) {
+            Some(appid) => find_steam_app_prefix(env, appid, parsed, no_term).1,
+            None => env.exit.exit_with_error(
+                "--audit requires an APPID, --prefix or --create-prefix",
+                no_term,
+            ),
+        },
+    };
+
+    // Only the flag itself goes; arguments after `--` belong to the program
+    let end = args.iter().position(|a| a == "--").unwrap_or(args.len());
+    let child_args: Vec<&String> = args
+        .iter()
+        .enumerate()
+        .filter(|(i, a)| *i >= end || *a != "--audit")
+        .map(|(_, a)| a)
+        .collect();
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => env
+            .exit
+            .exit_with_error(&format!("Failed to find protontool: {}", e), no_term),
+    };
+
+    let before = PrefixState::capture(&prefix_path);
+    let started = crate::wine::usage::now_secs();
+    let exit_code = match process::Command::new(exe).args(&child_args).status() {
+        Ok(status) => status.code().unwrap_or(1),
+        Err(e) => env
+            .exit
+            .exit_with_error(&format!("Failed to run protontool: {}", e), no_term),
+    };
+    let after = PrefixState::capture(&prefix_path);
+
+    let record = AuditRecord {
+        command: args,
+        prefix_path: &prefix_path,
+        started,
+        finished: crate::wine::usage::now_secs(),
+        exit_code,
+    };
+    match record.save(&before, &after) {
+        Ok(path) => eprintln!("Audit record saved to {}", path.display()),
+        Err(e) => eprintln!("Error: Failed to save the audit record: {}", e),
+    }
+    env.exit.exit(exit_code)
+}
 
+/// Build the command-line parser with all options.
+fn build_parser() -> ArgParser {
     let mut parser = ArgParser::new(
         "protontool",
         "A tool for managing Wine/Proton prefixes with built-in component installation.\n\n\
          Usage:\n\n\
          Install components (DLLs, fonts, settings) for a Steam game:\n\
          $ protontool APPID <verb> [verb...]\n\n\
+         Launch a game with log capture and post-exit analysis:\n\
+         $ protontool APPID play [-- ARGS]\n\n\
+         Create a game's Proton prefix ahead of its first launch:\n\
+         $ protontool APPID --prewarm\n\n\
+         Open a game's screenshots, install, prefix or Workshop folder:\n\
+         $ protontool APPID open-screenshots|open-installdir|open-prefix|open-workshop\n\n\
+         List a game's Steam Workshop items or link them into its prefix:\n\
+         $ protontool APPID workshop [show|link|unlink]\n\n\
+         Pass an nxm:// link to the mod manager installed by the mo2 verb:\n\
+         $ protontool nxm URL|off\n\n\
+         List, start, stop or restart the Windows services of a game's or custom prefix:\n\
+         $ protontool APPID|--prefix PATH services [list|start|stop|restart NAME|startup NAME auto|manual|disabled]\n\n\
+         List or import trusted root certificates (e.g. a corporate proxy's CA) in a prefix:\n\
+         $ protontool APPID|--prefix PATH certs [list|import FILE|import-host|remove THUMBPRINT]\n\n\
+         Check a game's or custom prefix for clock and time zone problems, and fix them:\n\
+         $ protontool APPID|--prefix PATH doctor [fix]\n\n\
+         Test game controllers and report which ones a game's or custom prefix sees:\n\
+         $ protontool APPID|--prefix PATH test-controller [report]\n\n\
+         Snapshot a game's or custom prefix, or restore one (btrfs/ZFS native when possible):\n\
+         $ protontool APPID|--prefix PATH snapshot [list|create [NAME]|restore NAME|delete NAME]\n\n\
+         Compare a game's or custom prefix with a known-good prefix (a path or APPID):\n\
+         $ protontool APPID|--prefix PATH compare --with PREFIX\n\n\
+         Undo the prefix changes of the last DLL or application verb:\n\
+         $ protontool APPID|--prefix PATH --rollback-last\n\n\
+         Check that the DXVK and VKD3D-Proton DLLs installed by verbs are still in place\n\
+         (Proton updates replace them) and reinstall them if not:\n\
+         $ protontool APPID|--prefix PATH --verify-graphics [--dry-run]\n\n\
+         Export a game's or custom prefix's DLL overrides, or import a shared set:\n\
+         $ protontool APPID|--prefix PATH --export-overrides|--import-overrides FILE.toml\n\n\
+         Mount a host mod directory into a game's or custom prefix:\n\
+         $ protontool APPID|--prefix PATH --mount WINPATH=DIR|WINPATH=off|list|restore\n\n\
+         Show or edit a game's dxvk.conf:\n\
+         $ protontool APPID dxvk-conf [show|set KEY=VALUE...|unset KEY...|use game|prefix]\n\n\
+         Show a game's Steam launch options, merge options into them or replace them\n\
+         (Steam must be closed; 'protontool undo' restores the previous ones):\n\
+         $ protontool APPID launch-options [show|add OPTIONS|set OPTIONS]\n\n\
+         Show, store or clear the WINEDEBUG spec used for every run in a prefix\n\
+         (specs starting with '-' go after '--'):\n\
+         $ protontool APPID|--prefix PATH winedebug [show|set SPEC|clear]\n\n\
+         Keep notes and tags on a game or custom prefix (tags show up in listings,\n\
+         the GUI and exported recipes):\n\
+         $ protontool APPID|--prefix PATH notes [show|set TEXT|tag TAG...|untag TAG...|clear]\n\n\
+         Show or edit a game's VKD3D-Proton settings:\n\
+         $ protontool APPID vkd3d-config [show|enable FLAG...|disable FLAG...|shader-model SM|debug LEVEL]\n\n\
+         Run a Windows program in the default prefix (created on first use):\n\
+         $ protontool run PROGRAM.exe [-- ARGS]\n\n\
+         Undo the most recent settings change:\n\
+         $ protontool undo\n\n\
+         Show the build details to include in bug reports:\n\
+         $ protontool version [--json]\n\n\
+         Export a game's setup as a shareable recipe, or apply one:\n\
+         $ protontool recipe export APPID [FILE]\n\
+         $ protontool recipe apply FILE\n\
+         $ protontool recipe search APPID   (community recipes, see config.toml [recipes])\n\n\
+         Move config, custom verbs, state and game recipes (optionally custom prefixes)\n\
+         to another machine:\n\
+         $ protontool migrate export backup.tar.zst [--with-prefixes]\n\
+         $ protontool migrate import backup.tar.zst\n\n\
+         Serve JSON-RPC requests (games, prefixes, verbs, jobs) on a Unix socket:\n\
+         $ protontool serve [SOCKET]\n\n\
+         Find an application to install (verbs and community recipes):\n\
+         $ protontool search-app NAME\n\n\
+         List the available verbs, optionally of one category:\n\
+         $ protontool verbs list [apps|dlls|fonts|settings|gamefixes|custom] [--names-only]\n\n\
          Search for games to find the APPID:\n\
          $ protontool -s GAME_NAME\n\n\
          List all installed games:\n\
@@ -47,6 +439,8 @@ pub fn main_cli(args: Option<Vec<String>>) {
          $ protontool --create-prefix ~/MyPrefix --proton 'Proton 9.0'\n\n\
          Delete a custom prefix:\n\
          $ protontool --delete-prefix ~/MyPrefix\n\n\
+         Use an existing prefix made by Wine, Lutris, Bottles or CrossOver as a custom prefix:\n\
+         $ protontool --adopt-prefix ~/.wine [--proton 'Proton 9.0']\n\n\
          Environment variables:\n\n\
          PROTON_VERSION: name of the preferred Proton installation\n\
          STEAM_DIR: path to custom Steam installation\n\
@@ -69,7 +463,23 @@ pub fn main_cli(args: Option<Vec<String>>) {
     parser.add_option(
         "command",
         &["-c", "--command"],
-        "Run a command with Wine environment variables",
+        "Run a program with Wine in a game's or custom prefix. Pass its arguments \
+         after --: -c PROGRAM -- ARGS, or -c -- PROGRAM ARGS",
+    );
+    parser.add_option(
+        "workdir",
+        &["--workdir"],
+        "Working directory for -c (default: the program's directory)",
+    );
+    parser.add_flag(
+        "detach",
+        &["--detach"],
+        "With -c, start the program in the background and print its PID",
+    );
+    parser.add_flag(
+        "wait_wineserver",
+        &["--wait-wineserver"],
+        "With -c, wait until every process in the prefix has exited before returning",
     );
     parser.add_flag("gui", &["--gui"], "Launch the protontool GUI");
     parser.add_flag(
@@ -97,6 +507,12 @@ pub fn main_cli(args: Option<Vec<String>>) {
         &["--delete-prefix"],
         "Delete an existing custom prefix at the given path",
     );
+    parser.add_option(
+        "adopt_prefix",
+        &["--adopt-prefix"],
+        "Take over an existing Wine prefix (plain Wine, Lutris, Bottles, CrossOver) at the \
+         given path without recreating it",
+    );
     parser.add_option(
         "prefix",
         &["--prefix", "-p"],
@@ -112,39 +528,388 @@ pub fn main_cli(args: Option<Vec<String>>) {
         &["--arch"],
         "Prefix architecture: win32 or win64 (default: win64)",
     );
+    parser.add_option(
+        "sandbox",
+        &["--sandbox"],
+        "Sandbox a custom prefix (on/off): no links into $HOME and no Z: drive. \
+         Use with --create-prefix or --prefix",
+    );
+    parser.add_option(
+        "user_dirs",
+        &["--user-dirs"],
+        "Host folders linked into a custom prefix: all, none, or a list such as \
+         documents,downloads=~/Downloads/Games. Use with --create-prefix or --prefix",
+    );
+    parser.add_option(
+        "z_drive",
+        &["--z-drive"],
+        "Z: drive of a custom prefix: off, on (maps /) or a directory to restrict it to. \
+         Use with --prefix",
+    );
+    parser.add_option(
+        "image",
+        &["--image"],
+        "Host a custom prefix in a sparse ext4 disk image: a size (e.g. 20G) with \
+         --create-prefix; mount, unmount, status or a new size with --prefix",
+    );
+    parser.add_option(
+        "mount",
+        &["--mount"],
+        "Mount a host directory into a prefix for mod managers: WINPATH=DIR (e.g. \
+         M:=~/MO2 or C:\\Modding=~/mods), WINPATH=off, list or restore. \
+         Use with an APPID or --prefix",
+    );
+    parser.add_option(
+        "nice",
+        &["--nice"],
+        "Nice level (-20 to 19) for play, run and -c, saved for the prefix (0 to reset)",
+    );
+    parser.add_option(
+        "cpu_affinity",
+        &["--cpu-affinity"],
+        "CPUs to run on for play, run and -c, e.g. 0-7, saved for the prefix (all to reset)",
+    );
+    parser.add_option(
+        "gamemode",
+        &["--gamemode"],
+        "Request Feral GameMode (on/off) for play, run and -c, saved for the prefix",
+    );
+    parser.add_option(
+        "memory_limit",
+        &["--memory-limit"],
+        "Memory limit for play, run and -c, e.g. 6G or 75%, enforced through a systemd \
+         scope and saved for the prefix (off to reset)",
+    );
+    parser.add_option(
+        "large_address_aware",
+        &["--large-address-aware"],
+        "Treat 32-bit games as large address aware (on/off) for play, run and -c, \
+         saved for the prefix",
+    );
+    parser.add_multi_option(
+        "override",
+        &["--override"],
+        "Per-run DLL override for verbs, run, play and -c, e.g. d3d11=n or dxgi,d3d11=b \
+         (can be specified multiple times)",
+    );
+    parser.add_flag(
+        "cleanup",
+        &["--cleanup"],
+        "Remove temp files, old crash dumps and installer leftovers from a prefix. \
+         Use with APPID or --prefix",
+    );
+    parser.add_flag(
+        "fix_ownership",
+        &["--fix-ownership"],
+        "Give files in a prefix owned by another user (e.g. root, after running protontool \
+         with sudo) back to the invoking user. Use with APPID or --prefix",
+    );
+    parser.add_option(
+        "with",
+        &["--with"],
+        "Prefix path or APPID to compare with. Use with compare",
+    );
+    parser.add_flag(
+        "rollback_last",
+        &["--rollback-last"],
+        "Restore the snapshot taken before the last DLL or application verb. \
+         Use with APPID or --prefix",
+    );
+    parser.add_flag(
+        "verify_graphics",
+        &["--verify-graphics"],
+        "Check that the DXVK and VKD3D-Proton DLLs installed by verbs weren't replaced \
+         (e.g. by a Proton update) and offer to reinstall them. Use with APPID or --prefix",
+    );
+    parser.add_option(
+        "export_overrides",
+        &["--export-overrides"],
+        "Write the DLL overrides of a prefix to a TOML file. Use with APPID or --prefix",
+    );
+    parser.add_option(
+        "import_overrides",
+        &["--import-overrides"],
+        "Apply the DLL overrides of a TOML file to a prefix. Use with APPID or --prefix",
+    );
+    parser.add_flag(
+        "prewarm",
+        &["--prewarm"],
+        "Create and initialize a game's Proton prefix before its first launch. Use with APPID",
+    );
+    parser.add_flag(
+        "dry_run",
+        &["--dry-run"],
+        "With --cleanup, --fix-ownership or --verify-graphics, only report what would be changed",
+    );
+    parser.add_flag(
+        "audit",
+        &["--audit"],
+        "Capture the prefix before and after the operation and save what changed \
+         as a JSON record in the state directory. Use with APPID or --prefix",
+    );
+    parser.add_flag(
+        "read_only",
+        &["--read-only"],
+        "Only inspect: refuse actions that change a prefix and never start Wine \
+         (for backups and other users' prefixes)",
+    );
+    parser.add_option(
+        "winedebug",
+        &["--winedebug"],
+        "WINEDEBUG channel spec for everything this run starts (e.g. -all,err+module), \
+         overriding the prefix's stored spec",
+    );
+    parser.add_flag(
+        "yes",
+        &["-y", "--yes", "--assume-yes"],
+        "Answer yes to every confirmation (deleting prefixes, restoring snapshots, \
+         applying recipes, importing migration archives and registry files)",
+    );
+    parser.add_flag(
+        "accept_licenses",
+        &["--accept-licenses"],
+        "Accept the license agreements of the verbs being installed without asking \
+         (--yes does not)",
+    );
+    parser.add_option(
+        "import_protonfixes",
+        &["--import-protonfixes"],
+        "Import protonfixes gamefix modules or JSON metadata as gamefix verbs",
+    );
+    parser.add_option(
+        "analyze_log",
+        &["--analyze-log"],
+        "Scan an existing Wine/Proton log for known errors and print a ranked summary",
+    );
+    parser.add_option(
+        "report",
+        &["--report"],
+        "Emit the --analyze-log result as a report: markdown or html",
+    );
+    parser.add_option(
+        "report_output",
+        &["--report-output"],
+        "Write the report to this file instead of stdout",
+    );
+    parser.add_flag(
+        "via_steam",
+        &["--via-steam"],
+        "With 'play', launch the game through the Steam client (steam://run) so Steam Input applies",
+    );
+    parser.add_flag(
+        "with_prefixes",
+        &["--with-prefixes"],
+        "With 'migrate export', also archive the custom prefixes in ~/.protontool/pfx",
+    );
+    parser.add_flag(
+        "names_only",
+        &["--names-only"],
+        "With 'verbs list', print only verb names, one per line (for shell completion)",
+    );
+    parser.add_flag(
+        "register_file_handler",
+        &["--register-file-handler"],
+        "Make protontool the handler for .exe and .msi files (runs them in the default prefix)",
+    );
+    parser.add_flag(
+        "unregister_file_handler",
+        &["--unregister-file-handler"],
+        "Remove the .exe/.msi file handler installed by --register-file-handler",
+    );
+    parser.add_flag(
+        "install_desktop_entry",
+        &["--install-desktop-entry"],
+        "Add protontool to the application menu, with its icon",
+    );
+    parser.add_flag(
+        "confirm",
+        &["--confirm"],
+        "Ask for confirmation before running a program with 'run'",
+    );
+    parser.add_flag(
+        "plain",
+        &["--plain"],
+        "Screen-reader friendly output: key: value lines, no tables or colors",
+    );
+    parser.add_flag(
+        "show_wine_output",
+        &["--show-wine-output"],
+        "Stream installer and wine output to the console while verbs run \
+         (default: log only, with a progress spinner)",
+    );
+    parser.add_option(
+        "max_downloads",
+        &["--max-downloads"],
+        "Number of verb downloads run at once (default: 2)",
+    );
+    parser.add_option(
+        "limit_rate",
+        &["--limit-rate"],
+        "Bandwidth cap for all downloads in bytes per second, e.g. 500K or 2M",
+    );
     parser.add_flag("version", &["-V", "--version"], "Show version");
+    parser.add_flag(
+        "json",
+        &["--json"],
+        "With version, print the build details as JSON",
+    );
     parser.add_flag("help", &["-h", "--help"], "Show help");
 
-    let parsed = match parser.parse(&args) {
-        Ok(p) => p,
-        Err(e) => {
-            eprintln!("{}", parser.help());
-            eprintln!("protontool: error: {}", e);
-            process::exit(2);
-        }
-    };
-
-    if parsed.get_flag("help") {
-        println!("{}", parser.help());
-        return;
-    }
-
-    if parsed.get_flag("version") {
-        println!("protontool ({})", crate::VERSION);
-        return;
-    }
-
-    let no_term = parsed.get_flag("no_term");
-    let verbose = parsed.get_count("verbose");
+    parser
+}
 
-    enable_logging(verbose);
+/// What a command line asks protontool to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CliAction {
+    /// No action given: print the help.
+    Help,
+    /// More than one action given.
+    Conflict,
+    Gui,
+    ListApps,
+    RunVerbs {
+        appid: u32,
+        verbs: Vec<String>,
+    },
+    PrefixCommand {
+        prefix: String,
+        command: String,
+    },
+    Command {
+        appid: Option<u32>,
+        command: String,
+    },
+    CreatePrefix(String),
+    DeletePrefix(String),
+    AdoptPrefix(String),
+    PrefixCleanup(String),
+    PrefixFixOwnership(String),
+    ZDrive {
+        prefix: String,
+        value: String,
+    },
+    PrefixImage {
+        prefix: String,
+        value: String,
+    },
+    PrefixMount {
+        prefix: String,
+        spec: String,
+    },
+    Sandbox {
+        prefix: String,
+        value: String,
+    },
+    UserDirs {
+        prefix: String,
+        value: String,
+    },
+    UsePrefix {
+        prefix: String,
+        verbs: Vec<String>,
+    },
+    ImportProtonfixes(String),
+    AnalyzeLog(String),
+    OpenFolder {
+        appid: u32,
+        target: String,
+    },
+    Play(u32),
+    DxvkConf {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Vkd3dConfig {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Workshop {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Services {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Certs {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Doctor {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Deferred {
+        appid: u32,
+        args: Vec<String>,
+    },
+    LaunchOptions {
+        appid: u32,
+        args: Vec<String>,
+    },
+    WineDebug {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Notes {
+        appid: u32,
+        args: Vec<String>,
+    },
+    TestController {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Snapshot {
+        appid: u32,
+        args: Vec<String>,
+    },
+    Compare {
+        appid: u32,
+        args: Vec<String>,
+    },
+    CleanupApp(u32),
+    FixOwnershipApp(u32),
+    RollbackApp(u32),
+    VerifyGraphicsApp(u32),
+    OverridesApp(u32),
+    MountApp {
+        appid: u32,
+        spec: String,
+    },
+    Prewarm(u32),
+    Undo,
+    Version,
+    Recipe(Vec<String>),
+    Migrate(Vec<String>),
+    SearchApp(String),
+    Verbs(Vec<String>),
+    Nxm(String),
+    Serve(Option<String>),
+    RunDefault {
+        program: String,
+        args: Vec<String>,
+    },
+    RegisterFileHandler,
+    UnregisterFileHandler,
+    InstallDesktopEntry,
+}
 
+/// Work out the action from parsed arguments. `no_args` is true when
+/// protontool was started without arguments, which opens the GUI.
+/// Invalid option combinations are returned as errors.
+fn select_action(parsed: &util::ParsedArgs, no_args: bool) -> Result<CliAction, String> {
     let do_command = parsed.get_option("command").is_some();
     let do_list_apps = parsed.get_option("search").is_some() || parsed.get_flag("list");
     let do_gui = parsed.get_flag("gui");
     let do_create_prefix = parsed.get_option("create_prefix").is_some();
     let do_delete_prefix = parsed.get_option("delete_prefix").is_some();
+    let do_adopt_prefix = parsed.get_option("adopt_prefix").is_some();
     let do_use_prefix = parsed.get_option("prefix").is_some();
+    let do_import_protonfixes = parsed.get_option("import_protonfixes").is_some();
+    let do_analyze_log = parsed.get_option("analyze_log").is_some();
+    let do_register_file_handler = parsed.get_flag("register_file_handler");
+    let do_unregister_file_handler = parsed.get_flag("unregister_file_handler");
+    let do_install_desktop_entry = parsed.get_flag("install_desktop_entry");
 
     let positional = parsed.positional();
     let appid: Option<u32> = positional.first().and_then(|s| s.parse().ok());
@@ -153,7 +918,70 @@ pub fn main_cli(args: Option<Vec<String>>) {
     } else {
         vec![]
     };
-    let do_run_verbs = appid.is_some() && !verbs_to_run.is_empty();
+    let open_target = match verbs_to_run.as_slice() {
+        [target] if OPEN_TARGETS.contains(&target.as_str()) => Some(target.as_str()),
+        _ => None,
+    };
+    let do_open_folder = appid.is_some() && open_target.is_some();
+    let do_play = appid.is_some() && verbs_to_run.len() == 1 && verbs_to_run[0] == "play";
+    let do_dxvk_conf = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "dxvk-conf");
+    let do_vkd3d_config =
+        appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "vkd3d-config");
+    let do_workshop = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "workshop");
+    let do_services = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "services");
+    let do_certs = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "certs");
+    let do_doctor = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "doctor");
+    let do_deferred = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "deferred");
+    let do_launch_options =
+        appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "launch-options");
+    let do_winedebug = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "winedebug");
+    let do_notes = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "notes");
+    let do_test_controller =
+        appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "test-controller");
+    let do_snapshot = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "snapshot");
+    let do_compare = appid.is_some() && verbs_to_run.first().is_some_and(|v| v == "compare");
+    let do_run_verbs = appid.is_some()
+        && !verbs_to_run.is_empty()
+        && !do_open_folder
+        && !do_play
+        && !do_dxvk_conf
+        && !do_vkd3d_config
+        && !do_workshop
+        && !do_services
+        && !do_certs
+        && !do_doctor
+        && !do_deferred
+        && !do_launch_options
+        && !do_winedebug
+        && !do_notes
+        && !do_test_controller
+        && !do_snapshot
+        && !do_compare;
+    let do_cleanup_app = appid.is_some() && verbs_to_run.is_empty() && parsed.get_flag("cleanup");
+    let do_fix_ownership_app =
+        appid.is_some() && verbs_to_run.is_empty() && parsed.get_flag("fix_ownership");
+    let do_rollback_app =
+        appid.is_some() && verbs_to_run.is_empty() && parsed.get_flag("rollback_last");
+    let do_verify_graphics_app =
+        appid.is_some() && verbs_to_run.is_empty() && parsed.get_flag("verify_graphics");
+    let overrides_file = ["export_overrides", "import_overrides"]
+        .iter()
+        .filter(|name| parsed.get_option(name).is_some())
+        .count();
+    let do_overrides_app = appid.is_some() && verbs_to_run.is_empty() && overrides_file > 0;
+    let do_mount_app =
+        appid.is_some() && verbs_to_run.is_empty() && parsed.get_option("mount").is_some();
+    let do_prewarm = appid.is_some() && verbs_to_run.is_empty() && parsed.get_flag("prewarm");
+    let do_undo = positional.len() == 1 && positional[0] == "undo";
+    let do_version = positional.len() == 1 && positional[0] == "version";
+    let do_recipe = positional.first().is_some_and(|p| p == "recipe");
+    let do_migrate = positional.first().is_some_and(|p| p == "migrate");
+    let do_search_app = positional.len() >= 2 && positional[0] == "search-app";
+    let do_verbs = positional.first().is_some_and(|p| p == "verbs");
+    let do_nxm = positional.len() == 2 && positional[0] == "nxm";
+    let do_serve = (1..=2).contains(&positional.len()) && positional[0] == "serve";
+    let do_run_default =
+        positional.len() >= 2 && positional[0] == "run" && !do_use_prefix && !do_command;
 
     if !do_command
         && !do_list_apps
@@ -161,15 +989,134 @@ pub fn main_cli(args: Option<Vec<String>>) {
         && !do_run_verbs
         && !do_create_prefix
         && !do_delete_prefix
+        && !do_adopt_prefix
         && !do_use_prefix
+        && !do_import_protonfixes
+        && !do_analyze_log
+        && !do_open_folder
+        && !do_play
+        && !do_dxvk_conf
+        && !do_vkd3d_config
+        && !do_workshop
+        && !do_services
+        && !do_certs
+        && !do_doctor
+        && !do_deferred
+        && !do_launch_options
+        && !do_winedebug
+        && !do_notes
+        && !do_test_controller
+        && !do_snapshot
+        && !do_compare
+        && !do_cleanup_app
+        && !do_fix_ownership_app
+        && !do_rollback_app
+        && !do_verify_graphics_app
+        && !do_overrides_app
+        && !do_mount_app
+        && !do_prewarm
+        && !do_undo
+        && !do_version
+        && !do_recipe
+        && !do_migrate
+        && !do_search_app
+        && !do_verbs
+        && !do_nxm
+        && !do_serve
+        && !do_run_default
+        && !do_register_file_handler
+        && !do_unregister_file_handler
+        && !do_install_desktop_entry
     {
-        if args.is_empty() {
-            // Default to GUI mode when no args
-            run_gui_mode(no_term);
-            return;
-        }
-        println!("{}", parser.help());
-        return;
+        // Default to GUI mode when no args
+        return Ok(if no_args {
+            CliAction::Gui
+        } else {
+            CliAction::Help
+        });
+    }
+
+    if parsed.get_option("sandbox").is_some() && !do_create_prefix && !do_use_prefix {
+        return Err("--sandbox requires --create-prefix or --prefix".to_string());
+    }
+    if parsed.get_option("user_dirs").is_some() && !do_create_prefix && !do_use_prefix {
+        return Err("--user-dirs requires --create-prefix or --prefix".to_string());
+    }
+    if parsed.get_option("z_drive").is_some() && !do_use_prefix {
+        return Err("--z-drive requires --prefix".to_string());
+    }
+    if parsed.get_option("image").is_some() && !do_create_prefix && !do_use_prefix {
+        return Err("--image requires --create-prefix or --prefix".to_string());
+    }
+    if parsed.get_flag("cleanup") && !do_cleanup_app && !do_use_prefix {
+        return Err("--cleanup requires an APPID or --prefix".to_string());
+    }
+    if parsed.get_flag("fix_ownership") && !do_fix_ownership_app && !do_use_prefix {
+        return Err("--fix-ownership requires an APPID or --prefix".to_string());
+    }
+    let compares =
+        do_compare || (do_use_prefix && positional.first().is_some_and(|v| v == "compare"));
+    if parsed.get_option("with").is_some() && !compares {
+        return Err("--with requires compare".to_string());
+    }
+    if parsed.get_flag("rollback_last") && !do_rollback_app && !do_use_prefix {
+        return Err("--rollback-last requires an APPID or --prefix".to_string());
+    }
+    if parsed.get_flag("verify_graphics") && !do_verify_graphics_app && !do_use_prefix {
+        return Err("--verify-graphics requires an APPID or --prefix".to_string());
+    }
+    if overrides_file > 1 {
+        return Err("--export-overrides and --import-overrides can't be combined".to_string());
+    }
+    if overrides_file > 0 && !do_overrides_app && !do_use_prefix {
+        return Err(
+            "--export-overrides and --import-overrides require an APPID or --prefix".to_string(),
+        );
+    }
+    if parsed.get_option("mount").is_some() && !do_mount_app && !do_use_prefix {
+        return Err("--mount requires an APPID or --prefix".to_string());
+    }
+    let sets_scheduling = [
+        "nice",
+        "cpu_affinity",
+        "gamemode",
+        "memory_limit",
+        "large_address_aware",
+    ]
+    .iter()
+    .any(|name| parsed.get_option(name).is_some());
+    if sets_scheduling && !do_command && !do_play && !do_run_default {
+        return Err(
+            "--nice, --cpu-affinity, --gamemode, --memory-limit and --large-address-aware \
+             require play, run or -c"
+                .to_string(),
+        );
+    }
+    if parsed.get_flag("with_prefixes")
+        && !(do_migrate && positional.get(1).is_some_and(|c| c == "export"))
+    {
+        return Err("--with-prefixes requires migrate export".to_string());
+    }
+    if parsed.get_flag("names_only") && !do_verbs {
+        return Err("--names-only requires verbs list".to_string());
+    }
+    let controls_command = parsed.get_option("workdir").is_some()
+        || parsed.get_flag("detach")
+        || parsed.get_flag("wait_wineserver");
+    if controls_command && !do_command {
+        return Err("--workdir, --detach and --wait-wineserver require -c".to_string());
+    }
+    if parsed.get_flag("detach") && parsed.get_flag("wait_wineserver") {
+        return Err("--detach and --wait-wineserver can't be combined".to_string());
+    }
+    if parsed.get_flag("audit") && appid.is_none() && !do_use_prefix && !do_create_prefix {
+        return Err("--audit requires an APPID, --prefix or --create-prefix".to_string());
+    }
+    if parsed.get_flag("prewarm") && !do_prewarm {
+        return Err("--prewarm requires an APPID".to_string());
+    }
+    if parsed.get_flag("json") && !do_version {
+        return Err("--json only works with version".to_string());
     }
 
     // Allow combining -c with --prefix (command mode with custom prefix)
@@ -185,7 +1132,44 @@ pub fn main_cli(args: Option<Vec<String>>) {
             do_command,
             do_create_prefix,
             do_delete_prefix,
+            do_adopt_prefix,
             do_use_prefix,
+            do_import_protonfixes,
+            do_analyze_log,
+            do_open_folder,
+            do_play,
+            do_dxvk_conf,
+            do_vkd3d_config,
+            do_workshop,
+            do_services,
+            do_certs,
+            do_doctor,
+            do_deferred,
+            do_launch_options,
+            do_winedebug,
+            do_notes,
+            do_test_controller,
+            do_snapshot,
+            do_compare,
+            do_cleanup_app,
+            do_fix_ownership_app,
+            do_rollback_app,
+            do_verify_graphics_app,
+            do_overrides_app,
+            do_mount_app,
+            do_prewarm,
+            do_undo,
+            do_version,
+            do_recipe,
+            do_migrate,
+            do_search_app,
+            do_verbs,
+            do_nxm,
+            do_serve,
+            do_run_default,
+            do_register_file_handler,
+            do_unregister_file_handler,
+            do_install_desktop_entry,
         ]
         .iter()
         .filter(|&&x| x)
@@ -193,62 +1177,350 @@ pub fn main_cli(args: Option<Vec<String>>) {
     };
 
     if action_count != 1 {
-        eprintln!("Only one action can be performed at a time.");
-        println!("{}", parser.help());
-        return;
+        return Ok(CliAction::Conflict);
     }
 
-    if do_gui {
-        run_gui_mode(no_term);
+    let option = |name: &str| parsed.get_option(name).unwrap().to_string();
+    let action = if do_gui {
+        CliAction::Gui
     } else if do_list_apps {
-        run_list_mode(&parsed, no_term);
+        CliAction::ListApps
     } else if do_run_verbs {
-        run_verb_mode(appid.unwrap(), &verbs_to_run, &parsed, no_term);
+        CliAction::RunVerbs {
+            appid: appid.unwrap(),
+            verbs: verbs_to_run,
+        }
     } else if do_prefix_command {
-        let cmd = parsed.get_option("command").unwrap();
-        let prefix_path = parsed.get_option("prefix").unwrap();
-        run_prefix_command_mode(&prefix_path, &cmd, &parsed, no_term);
+        CliAction::PrefixCommand {
+            prefix: option("prefix"),
+            command: option("command"),
+        }
     } else if do_command {
-        let cmd = parsed.get_option("command").unwrap();
-        run_command_mode(appid, &cmd, &parsed, no_term);
+        CliAction::Command {
+            appid,
+            command: option("command"),
+        }
     } else if do_create_prefix {
-        let prefix_path = parsed.get_option("create_prefix").unwrap();
-        run_create_prefix_mode(&prefix_path, &parsed, no_term);
+        CliAction::CreatePrefix(option("create_prefix"))
     } else if do_delete_prefix {
-        let prefix_path = parsed.get_option("delete_prefix").unwrap();
-        run_delete_prefix_mode(&prefix_path, no_term);
+        CliAction::DeletePrefix(option("delete_prefix"))
+    } else if do_adopt_prefix {
+        CliAction::AdoptPrefix(option("adopt_prefix"))
+    } else if do_use_prefix && parsed.get_flag("cleanup") {
+        CliAction::PrefixCleanup(option("prefix"))
+    } else if do_use_prefix && parsed.get_flag("fix_ownership") {
+        CliAction::PrefixFixOwnership(option("prefix"))
+    } else if do_use_prefix && parsed.get_option("mount").is_some() {
+        CliAction::PrefixMount {
+            prefix: option("prefix"),
+            spec: option("mount"),
+        }
+    } else if do_use_prefix && parsed.get_option("image").is_some() {
+        CliAction::PrefixImage {
+            prefix: option("prefix"),
+            value: option("image"),
+        }
+    } else if do_use_prefix && parsed.get_option("z_drive").is_some() {
+        CliAction::ZDrive {
+            prefix: option("prefix"),
+            value: option("z_drive"),
+        }
+    } else if do_use_prefix && parsed.get_option("user_dirs").is_some() {
+        CliAction::UserDirs {
+            prefix: option("prefix"),
+            value: option("user_dirs"),
+        }
+    } else if do_use_prefix && parsed.get_option("sandbox").is_some() {
+        CliAction::Sandbox {
+            prefix: option("prefix"),
+            value: option("sandbox"),
+        }
     } else if do_use_prefix {
-        let prefix_path = parsed.get_option("prefix").unwrap();
-        run_custom_prefix_mode(&prefix_path, &verbs_to_run, &parsed, no_term);
-    }
-}
-
-/// Get Steam installation context (steam_path, steam_root, library_paths).
-/// Returns None if user cancels selection or no Steam found.
+        // Without an APPID, every positional argument is a verb or command
+        CliAction::UsePrefix {
+            prefix: option("prefix"),
+            verbs: positional.to_vec(),
+        }
+    } else if do_import_protonfixes {
+        CliAction::ImportProtonfixes(option("import_protonfixes"))
+    } else if do_analyze_log {
+        CliAction::AnalyzeLog(option("analyze_log"))
+    } else if do_open_folder {
+        CliAction::OpenFolder {
+            appid: appid.unwrap(),
+            target: open_target.unwrap().to_string(),
+        }
+    } else if do_play {
+        CliAction::Play(appid.unwrap())
+    } else if do_dxvk_conf {
+        CliAction::DxvkConf {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_vkd3d_config {
+        CliAction::Vkd3dConfig {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_workshop {
+        CliAction::Workshop {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_services {
+        CliAction::Services {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_certs {
+        CliAction::Certs {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_doctor {
+        CliAction::Doctor {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_deferred {
+        CliAction::Deferred {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_winedebug {
+        let mut args = verbs_to_run[1..].to_vec();
+        args.extend(parsed.trailing().iter().cloned());
+        CliAction::WineDebug {
+            appid: appid.unwrap(),
+            args,
+        }
+    } else if do_notes {
+        let mut args = verbs_to_run[1..].to_vec();
+        args.extend(parsed.trailing().iter().cloned());
+        CliAction::Notes {
+            appid: appid.unwrap(),
+            args,
+        }
+    } else if do_launch_options {
+        let mut args = verbs_to_run[1..].to_vec();
+        args.extend(parsed.trailing().iter().cloned());
+        CliAction::LaunchOptions {
+            appid: appid.unwrap(),
+            args,
+        }
+    } else if do_test_controller {
+        CliAction::TestController {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_snapshot {
+        CliAction::Snapshot {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_compare {
+        CliAction::Compare {
+            appid: appid.unwrap(),
+            args: verbs_to_run[1..].to_vec(),
+        }
+    } else if do_cleanup_app {
+        CliAction::CleanupApp(appid.unwrap())
+    } else if do_fix_ownership_app {
+        CliAction::FixOwnershipApp(appid.unwrap())
+    } else if do_rollback_app {
+        CliAction::RollbackApp(appid.unwrap())
+    } else if do_verify_graphics_app {
+        CliAction::VerifyGraphicsApp(appid.unwrap())
+    } else if do_overrides_app {
+        CliAction::OverridesApp(appid.unwrap())
+    } else if do_mount_app {
+        CliAction::MountApp {
+            appid: appid.unwrap(),
+            spec: option("mount"),
+        }
+    } else if do_prewarm {
+        CliAction::Prewarm(appid.unwrap())
+    } else if do_undo {
+        CliAction::Undo
+    } else if do_version {
+        CliAction::Version
+    } else if do_recipe {
+        CliAction::Recipe(positional[1..].to_vec())
+    } else if do_migrate {
+        CliAction::Migrate(positional[1..].to_vec())
+    } else if do_search_app {
+        CliAction::SearchApp(positional[1..].join(" "))
+    } else if do_verbs {
+        CliAction::Verbs(positional[1..].to_vec())
+    } else if do_nxm {
+        CliAction::Nxm(positional[1].clone())
+    } else if do_serve {
+        CliAction::Serve(positional.get(1).cloned())
+    } else if do_run_default {
+        let mut args = positional[2..].to_vec();
+        args.extend(parsed.trailing().iter().cloned());
+        CliAction::RunDefault {
+            program: positional[1].clone(),
+            args,
+        }
+    } else if do_register_file_handler {
+        CliAction::RegisterFileHandler
+    } else if do_install_desktop_entry {
+        CliAction::InstallDesktopEntry
+    } else {
+        CliAction::UnregisterFileHandler
+    };
+    if parsed.get_flag("read_only") && !is_inspection(&action, parsed) {
+        return Err(
+            "--read-only only works with listing and inspection actions: -l, -s, \
+             --analyze-log, search-app, verbs list, compare, doctor, the list and show forms of \
+             certs, snapshot, dxvk-conf, vkd3d-config, workshop, winedebug and notes, \
+             --export-overrides, and --cleanup, --fix-ownership or --verify-graphics with --dry-run"
+                .to_string(),
+        );
+    }
+    Ok(action)
+}
+
+/// The custom prefix an action works in, for the actions that need its
+/// image mounted. `--image`, `--create-prefix`, `--adopt-prefix` and
+/// `--delete-prefix` handle images themselves.
+fn custom_prefix(action: &CliAction) -> Option<&str> {
+    match action {
+        CliAction::PrefixCommand { prefix, .. }
+        | CliAction::ZDrive { prefix, .. }
+        | CliAction::PrefixMount { prefix, .. }
+        | CliAction::Sandbox { prefix, .. }
+        | CliAction::UserDirs { prefix, .. }
+        | CliAction::UsePrefix { prefix, .. } => Some(prefix),
+        CliAction::PrefixCleanup(prefix) | CliAction::PrefixFixOwnership(prefix) => Some(prefix),
+        _ => None,
+    }
+}
+
+/// The Steam app an action works on, if any.
+fn action_appid(action: &CliAction) -> Option<u32> {
+    match action {
+        CliAction::Command { appid, .. } => *appid,
+        CliAction::RunVerbs { appid, .. }
+        | CliAction::OpenFolder { appid, .. }
+        | CliAction::DxvkConf { appid, .. }
+        | CliAction::Vkd3dConfig { appid, .. }
+        | CliAction::Workshop { appid, .. }
+        | CliAction::Services { appid, .. }
+        | CliAction::Certs { appid, .. }
+        | CliAction::Doctor { appid, .. }
+        | CliAction::Deferred { appid, .. }
+        | CliAction::LaunchOptions { appid, .. }
+        | CliAction::WineDebug { appid, .. }
+        | CliAction::Notes { appid, .. }
+        | CliAction::TestController { appid, .. }
+        | CliAction::Snapshot { appid, .. }
+        | CliAction::Compare { appid, .. }
+        | CliAction::MountApp { appid, .. } => Some(*appid),
+        CliAction::Play(appid)
+        | CliAction::CleanupApp(appid)
+        | CliAction::FixOwnershipApp(appid)
+        | CliAction::RollbackApp(appid)
+        | CliAction::VerifyGraphicsApp(appid)
+        | CliAction::OverridesApp(appid)
+        | CliAction::Prewarm(appid) => Some(*appid),
+        _ => None,
+    }
+}
+
+/// Whether an action only reads prefixes, and so runs with `--read-only`.
+fn is_inspection(action: &CliAction, parsed: &util::ParsedArgs) -> bool {
+    let shows = |args: &[String], form: &str| match args {
+        [] => true,
+        [arg] => arg == form,
+        _ => false,
+    };
+    let exports = parsed.get_option("export_overrides").is_some();
+    match action {
+        CliAction::Help
+        | CliAction::Conflict
+        | CliAction::ListApps
+        | CliAction::AnalyzeLog(_)
+        | CliAction::SearchApp(_)
+        | CliAction::Verbs(_)
+        | CliAction::Version
+        | CliAction::Compare { .. } => true,
+        CliAction::Doctor { args, .. } | CliAction::Deferred { args, .. } => args.is_empty(),
+        CliAction::Certs { args, .. } | CliAction::Snapshot { args, .. } => shows(args, "list"),
+        CliAction::DxvkConf { args, .. }
+        | CliAction::Vkd3dConfig { args, .. }
+        | CliAction::Workshop { args, .. }
+        | CliAction::LaunchOptions { args, .. }
+        | CliAction::WineDebug { args, .. }
+        | CliAction::Notes { args, .. } => shows(args, "show"),
+        CliAction::OverridesApp(_) => exports,
+        CliAction::PrefixCleanup(_)
+        | CliAction::CleanupApp(_)
+        | CliAction::PrefixFixOwnership(_)
+        | CliAction::FixOwnershipApp(_)
+        | CliAction::VerifyGraphicsApp(_) => parsed.get_flag("dry_run"),
+        CliAction::UsePrefix { verbs, .. } => match verbs.first().map(|v| v.as_str()) {
+            _ if parsed.get_flag("rollback_last") => false,
+            _ if parsed.get_flag("verify_graphics") => parsed.get_flag("dry_run"),
+            None => exports,
+            Some("compare") => true,
+            Some("doctor") | Some("deferred") => verbs.len() == 1,
+            Some("winedebug") | Some("notes") => verbs.len() == 1 || verbs[1] == "show",
+            Some("certs") | Some("snapshot") => shows(&verbs[1..], "list"),
+            Some(_) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Get Steam installation context (steam_path, steam_root, library_paths).
+/// Returns None if user cancels selection or no Steam found.
 fn get_steam_context(
+    env: &CliEnv,
     no_term: bool,
     extra_libraries: &[String],
 ) -> Option<(PathBuf, PathBuf, Vec<PathBuf>)> {
-    let steam_installations = find_steam_installations();
+    let steam_installations = env.steam.find_installations();
     if steam_installations.is_empty() {
-        exit_with_error("Steam installation directory could not be found.", no_term);
+        if crate::flatpak::is_sandboxed() {
+            // The sandbox may simply hide the Steam directory
+            let candidates = crate::steam::steam_path_candidates();
+            let paths: Vec<&std::path::Path> = candidates.iter().map(|p| p.as_path()).collect();
+            env.gui.prompt_filesystem_access(&paths, no_term);
+        }
+        env.exit
+            .exit_with_error("Steam installation directory could not be found.", no_term);
     }
 
-    let installation = select_steam_installation(&steam_installations)?;
+    let installation = env.gui.select_steam_installation(&steam_installations)?;
     let steam_path = installation.steam_path.clone();
     let steam_root = installation.steam_root.clone();
 
+    // Check access up front rather than failing on the first write
+    let listed_libraries = crate::steam::listed_library_paths(&steam_path);
+    let mut paths: Vec<&std::path::Path> = vec![&steam_path, &steam_root];
+    paths.extend(listed_libraries.iter().map(|p| p.as_path()));
+    extra_libraries
+        .iter()
+        .for_each(|lib| paths.push(std::path::Path::new(lib)));
+    if !env.gui.prompt_filesystem_access(&paths, no_term)
+        && !crate::flatpak::is_accessible(&steam_path)
+    {
+        env.exit.exit_with_error(
+            "The Steam directory is not accessible from the Flatpak sandbox.",
+            no_term,
+        );
+    }
+
     let extra_paths: Vec<PathBuf> = extra_libraries.iter().map(PathBuf::from).collect();
     let steam_lib_paths = get_steam_lib_paths(&steam_path, &extra_paths);
 
-    let paths: Vec<&std::path::Path> = vec![&steam_path, &steam_root];
-    prompt_filesystem_access(&paths, no_term);
-
     Some((steam_path, steam_root, steam_lib_paths))
 }
 
 /// Run the interactive GUI mode with main menu loop.
-fn run_gui_mode(no_term: bool) {
+fn run_gui_mode(env: &CliEnv, no_term: bool) {
     // Show main menu to choose action
     loop {
         let action = match show_main_menu_gui() {
@@ -257,16 +1529,37 @@ fn run_gui_mode(no_term: bool) {
         };
 
         match action {
-            GuiAction::ManageGame => run_gui_manage_game(no_term),
-            GuiAction::CreatePrefix => run_gui_create_prefix(no_term),
+            GuiAction::ManageGame => run_gui_manage_game(env, no_term),
+            GuiAction::CreatePrefix => run_gui_create_prefix(env, no_term),
             GuiAction::DeletePrefix => run_gui_delete_prefix(no_term),
-            GuiAction::ManagePrefix => run_gui_manage_prefix(no_term),
+            GuiAction::ManagePrefix => run_gui_manage_prefix(env, no_term),
+            GuiAction::GameNotes => run_gui_game_notes(env, no_term),
+        }
+    }
+}
+
+/// GUI flow for editing a Steam game's notes and tags.
+fn run_gui_game_notes(env: &CliEnv, no_term: bool) {
+    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(env, no_term, &[]) {
+        Some(ctx) => ctx,
+        None => {
+            env.exit
+                .exit_with_error("No Steam installation was selected.", no_term);
         }
+    };
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    if let Some(steam_app) =
+        select_steam_app_with_gui(&steam_apps, Some("Select a game"), &steam_path)
+    {
+        run_notes_gui(
+            &crate::notes::Subject::App(steam_app.appid),
+            &steam_app.name,
+        );
     }
 }
 
 /// GUI flow for managing a Steam game's prefix.
-fn run_gui_manage_game(no_term: bool) {
+fn run_gui_manage_game(env: &CliEnv, no_term: bool) {
     // First, let user add extra Steam library paths via GUI
     let extra_lib_paths = select_steam_library_paths();
     let extra_libs: Vec<String> = extra_lib_paths
@@ -274,12 +1567,14 @@ fn run_gui_manage_game(no_term: bool) {
         .map(|p| p.to_string_lossy().to_string())
         .collect();
 
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
-        }
-    };
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
 
     let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
 
@@ -289,7 +1584,7 @@ fn run_gui_manage_game(no_term: bool) {
         .collect();
 
     if windows_apps.is_empty() {
-        exit_with_error(
+        env.exit.exit_with_error(
             "Found no games. You need to launch a game at least once before protontool can find it.",
             no_term
         );
@@ -299,16 +1594,18 @@ fn run_gui_manage_game(no_term: bool) {
         Some(app) => app,
         None => return,
     };
+    mount_app_prefix(env, &steam_app, no_term);
 
     let proton_app = match find_proton_app(&steam_path, &steam_apps, steam_app.appid) {
         Some(app) => app,
         None => {
-            exit_with_error("Proton installation could not be found!", no_term);
+            env.exit
+                .exit_with_error("Proton installation could not be found!", no_term);
         }
     };
 
     if !proton_app.is_proton_ready {
-        exit_with_error(
+        env.exit.exit_with_error(
             "Proton installation is incomplete. Have you launched a Steam app using this Proton version at least once?",
             no_term
         );
@@ -317,6 +1614,19 @@ fn run_gui_manage_game(no_term: bool) {
     let prefix_path = steam_app.prefix_path.as_ref().unwrap();
     let verb_runner = Wine::new(&proton_app, prefix_path);
 
+    // Make Workshop mods reachable for mod managers run in the prefix
+    if let Some(content) = steam_app.workshop_content_path().filter(|p| p.is_dir()) {
+        match crate::wine::workshop::link(prefix_path, &content) {
+            Ok(path) => println!(
+                "Steam Workshop content is available in the prefix at {}",
+                path
+            ),
+            Err(e) => eprintln!("Warning: {}", e),
+        }
+    }
+
+    restore_mounts(prefix_path);
+
     // Show category selection, then verb selection
     loop {
         let category = match select_verb_category_gui() {
@@ -326,6 +1636,7 @@ fn run_gui_manage_game(no_term: bool) {
 
         let verbs = verb_runner.list_verbs(Some(category));
         let selected = select_verbs_with_gui(
+            &verb_runner.verb_registry,
             &verbs,
             Some(&format!("Select {} to install", category.as_str())),
         );
@@ -333,21 +1644,38 @@ fn run_gui_manage_game(no_term: bool) {
         if selected.is_empty() {
             continue; // Go back to category selection
         }
+        if !check_prefix_before_changes(&verb_runner.wine_ctx, true) {
+            continue;
+        }
 
         // Run selected verbs
+        let appid = steam_app.appid.to_string();
+        let mut command = vec![appid.as_str()];
         for verb_name in &selected {
+            if !accept_verb_licenses(&verb_runner, verb_name, true) {
+                continue;
+            }
+            if !confirm_setting_changes(&verb_runner, verb_name, true) {
+                continue;
+            }
             println!("Running verb: {}", verb_name);
+            command.push(verb_name);
             if let Err(e) = verb_runner.run_verb(verb_name) {
                 eprintln!("Error running {}: {}", verb_name, e);
             }
         }
 
         println!("Completed running verbs.");
+
+        print_verb_summary(&verb_runner);
+        if command.len() > 1 {
+            equivalent::show(&command);
+        }
     }
 }
 
 /// GUI flow for creating a new custom prefix.
-fn run_gui_create_prefix(no_term: bool) {
+fn run_gui_create_prefix(env: &CliEnv, no_term: bool) {
     // Get prefix name from user
     let prefix_name = match get_prefix_name_gui() {
         Some(name) => name,
@@ -361,10 +1689,11 @@ fn run_gui_create_prefix(no_term: bool) {
     };
 
     // Get Steam context for Proton selection
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &[]) {
+    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(env, no_term, &[]) {
         Some(ctx) => ctx,
         None => {
-            exit_with_error("No Steam installation was selected.", no_term);
+            env.exit
+                .exit_with_error("No Steam installation was selected.", no_term);
         }
     };
 
@@ -372,7 +1701,7 @@ fn run_gui_create_prefix(no_term: bool) {
     let proton_apps = get_proton_apps(&steam_apps);
 
     if proton_apps.is_empty() {
-        exit_with_error(
+        env.exit.exit_with_error(
             "No Proton installations found. Please install Proton through Steam first.",
             no_term,
         );
@@ -385,7 +1714,7 @@ fn run_gui_create_prefix(no_term: bool) {
     };
 
     if !proton_app.is_proton_ready {
-        exit_with_error(
+        env.exit.exit_with_error(
             "Selected Proton installation is not ready. Please launch a game with this Proton version first.",
             no_term
         );
@@ -403,7 +1732,7 @@ fn run_gui_create_prefix(no_term: bool) {
     println!("Architecture: {}", arch.as_str());
 
     if let Err(e) = std::fs::create_dir_all(&prefix_path) {
-        exit_with_error(
+        env.exit.exit_with_error(
             &format!("Failed to create prefix directory: {}", e),
             no_term,
         );
@@ -424,21 +1753,22 @@ fn run_gui_create_prefix(no_term: bool) {
     println!("Initializing prefix...");
     if let Err(e) = crate::wine::prefix::init_prefix(&prefix_path, &dist_dir, true, Some(&wine_ctx))
     {
-        exit_with_error(&format!("Failed to initialize prefix: {}", e), no_term);
+        env.exit
+            .exit_with_error(&format!("Failed to initialize prefix: {}", e), no_term);
     }
 
     // Save metadata
-    let metadata_path = prefix_path.join(".protontool");
-    let metadata = format!(
-        "proton_name={}\nproton_path={}\narch={}\ncreated={}\n",
-        proton_app.name,
-        proton_app.install_path.display(),
-        arch.as_str(),
-        chrono_lite_now()
-    );
-    std::fs::write(&metadata_path, metadata).ok();
+    save_prefix_metadata(&prefix_path, &proton_app, arch).ok();
 
     println!("Prefix '{}' created successfully!", prefix_name);
+    equivalent::show(&[
+        "--create-prefix",
+        &prefix_path.to_string_lossy(),
+        "--proton",
+        &proton_app.name,
+        "--arch",
+        arch.as_str(),
+    ]);
 }
 
 /// GUI flow for deleting an existing custom prefix.
@@ -468,37 +1798,23 @@ fn run_gui_delete_prefix(no_term: bool) {
     };
 
     let confirm_text = format!(
-        "Are you sure you want to delete the prefix '{}'?\n\nThis will permanently remove:\n{}\n\nThis action cannot be undone!",
+        "This will permanently remove the prefix '{}':\n{}\n\nThis action cannot be undone!",
         prefix_name,
         prefix_path.display()
     );
-
-    let confirm = std::process::Command::new(&gui_tool)
-        .args([
-            "--question",
-            "--title",
-            "Confirm Delete",
-            "--text",
-            &confirm_text,
-            "--width",
-            "450",
-        ])
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false);
-
-    if !confirm {
+    if !confirm("Confirm Delete", &confirm_text, "Delete this prefix?", true) {
         println!("Deletion cancelled.");
         return;
     }
 
     // Delete the prefix directory
-    match std::fs::remove_dir_all(&prefix_path) {
+    match delete_prefix_files(&prefix_path) {
         Ok(()) => {
             println!("Prefix '{}' deleted successfully.", prefix_name);
+            equivalent::show(&["--delete-prefix", &prefix_path.to_string_lossy()]);
 
             // Show success message
-            let _ = std::process::Command::new(&gui_tool)
+            let _ = crate::gui::dialog(&gui_tool)
                 .args([
                     "--info",
                     "--title",
@@ -514,7 +1830,7 @@ fn run_gui_delete_prefix(no_term: bool) {
             let error_msg = format!("Failed to delete prefix: {}", e);
             eprintln!("{}", error_msg);
 
-            let _ = std::process::Command::new(&gui_tool)
+            let _ = crate::gui::dialog(&gui_tool)
                 .args([
                     "--error",
                     "--title",
@@ -530,7 +1846,7 @@ fn run_gui_delete_prefix(no_term: bool) {
 }
 
 /// GUI flow for managing an existing custom prefix.
-fn run_gui_manage_prefix(no_term: bool) {
+fn run_gui_manage_prefix(env: &CliEnv, no_term: bool) {
     // Get the default prefixes directory
     let prefixes_dir = crate::config::get_prefixes_dir();
 
@@ -542,12 +1858,14 @@ fn run_gui_manage_prefix(no_term: bool) {
         Some(path) => path,
         None => return,
     };
+    mount_prefix_image(env, &prefix_path, no_term);
 
     // Get Steam context for Proton
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &[]) {
+    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(env, no_term, &[]) {
         Some(ctx) => ctx,
         None => {
-            exit_with_error("No Steam installation was selected.", no_term);
+            env.exit
+                .exit_with_error("No Steam installation was selected.", no_term);
         }
     };
 
@@ -595,12 +1913,22 @@ fn run_gui_manage_prefix(no_term: bool) {
     };
 
     if !proton_app.is_proton_ready {
-        exit_with_error("Proton installation is not ready.", no_term);
+        env.exit
+            .exit_with_error("Proton installation is not ready.", no_term);
     }
 
     let verb_runner = Wine::new_with_arch(&proton_app, &prefix_path, saved_arch);
     let wine_ctx =
         crate::wine::WineContext::from_proton_with_arch(&proton_app, &prefix_path, saved_arch);
+    let prefix_arg = prefix_path.to_string_lossy().into_owned();
+    let show_wine_command = |args: &[&str]| {
+        let mut command = vec!["--prefix", prefix_arg.as_str(), "-c", args[0]];
+        if args.len() > 1 {
+            command.push("--");
+            command.extend_from_slice(&args[1..]);
+        }
+        equivalent::show(&command);
+    };
 
     // Interactive action selection
     loop {
@@ -608,10 +1936,17 @@ fn run_gui_manage_prefix(no_term: bool) {
         match select_prefix_action_gui() {
             Some(PrefixAction::RunApplication) => {
                 if let Some(exe_path) = select_executable_gui() {
+                    if crate::wine::sandbox::is_sandboxed(&prefix_path) {
+                        if let Some(warning) =
+                            crate::wine::sandbox::program_warning(&prefix_path, &exe_path)
+                        {
+                            eprintln!("Warning: {}", warning);
+                        }
+                    }
                     println!("Running: {}", exe_path.display());
                     // run_wine automatically changes to executable's directory
                     match wine_ctx.run_wine(&[&exe_path.to_string_lossy()]) {
-                        Ok(_) => {}
+                        Ok(_) => show_wine_command(&[&exe_path.to_string_lossy()]),
                         Err(e) => eprintln!("Error running application: {}", e),
                     }
                 }
@@ -624,6 +1959,7 @@ fn run_gui_manage_prefix(no_term: bool) {
 
                 let verb_list = verb_runner.list_verbs(Some(category));
                 let selected = select_verbs_with_gui(
+                    &verb_runner.verb_registry,
                     &verb_list,
                     Some(&format!("Select {} to install", category.as_str())),
                 );
@@ -631,22 +1967,39 @@ fn run_gui_manage_prefix(no_term: bool) {
                 if selected.is_empty() {
                     continue;
                 }
+                if !check_prefix_before_changes(&verb_runner.wine_ctx, true) {
+                    continue;
+                }
 
+                let mut command = vec!["--prefix", prefix_arg.as_str()];
                 for verb_name in &selected {
+                    if !accept_verb_licenses(&verb_runner, verb_name, true) {
+                        continue;
+                    }
+                    if !confirm_setting_changes(&verb_runner, verb_name, true) {
+                        continue;
+                    }
                     println!("Running verb: {}", verb_name);
+                    command.push(verb_name);
                     if let Err(e) = verb_runner.run_verb(verb_name) {
                         eprintln!("Error running {}: {}", verb_name, e);
                     }
                 }
 
                 println!("Completed running verbs.");
+
+                print_verb_summary(&verb_runner);
+                if command.len() > 2 {
+                    equivalent::show(&command);
+                }
             }
             Some(PrefixAction::WineTools) => {
-                if let Some(tool) = select_wine_tool_gui() {
-                    println!("Launching: {}", tool);
-                    match wine_ctx.run_wine_no_cwd(&[&tool]) {
-                        Ok(_) => {}
-                        Err(e) => eprintln!("Error launching {}: {}", tool, e),
+                if let Some(tool) = select_wine_tool_gui(&wine_ctx.proton_path) {
+                    let tool: Vec<&str> = tool.iter().map(String::as_str).collect();
+                    println!("Launching: {}", tool.join(" "));
+                    match wine_ctx.run_wine_no_cwd(&tool) {
+                        Ok(_) => show_wine_command(&tool),
+                        Err(e) => eprintln!("Error launching {}: {}", tool[0], e),
                     }
                 }
             }
@@ -666,6 +2019,10 @@ fn run_gui_manage_prefix(no_term: bool) {
                             if let Some(version) = select_windows_version_gui() {
                                 println!("Setting Windows version to: {}", version);
                                 set_windows_version(&wine_ctx, &version);
+                                // Versions with a verb can be set from the command line
+                                if verb_runner.verb_registry.get(&version).is_some() {
+                                    equivalent::show(&["--prefix", &prefix_arg, &version]);
+                                }
                             }
                         }
                         PrefixSetting::VirtualDesktop => {
@@ -677,6 +2034,15 @@ fn run_gui_manage_prefix(no_term: bool) {
                                 set_wine_theme(&wine_ctx, &theme);
                             }
                         }
+                        PrefixSetting::DxvkConfig => {
+                            run_dxvk_conf_gui(&prefix_path);
+                        }
+                        PrefixSetting::Vkd3dConfig => {
+                            run_vkd3d_config_gui(&prefix_path);
+                        }
+                        PrefixSetting::LegacyApps => {
+                            run_legacy_apps_gui(&verb_runner, &prefix_arg);
+                        }
                         PrefixSetting::RegistryImport => {
                             run_registry_import_gui(&wine_ctx);
                         }
@@ -689,6 +2055,12 @@ fn run_gui_manage_prefix(no_term: bool) {
             Some(PrefixAction::CreateVerb) => {
                 run_verb_creator_gui();
             }
+            Some(PrefixAction::Notes) => {
+                run_notes_gui(
+                    &crate::notes::Subject::Prefix(&prefix_path),
+                    &prefix_path.to_string_lossy(),
+                );
+            }
             None => return,
         }
     }
@@ -701,6 +2073,7 @@ enum PrefixAction {
     WineTools,
     Settings,
     CreateVerb,
+    Notes,
 }
 
 /// Show GUI menu to select a prefix management action.
@@ -711,6 +2084,8 @@ fn select_prefix_action_gui() -> Option<PrefixAction> {
         "--list",
         "--title",
         "Select action",
+        "--text",
+        "Select action",
         "--column",
         "Action",
         "--column",
@@ -731,12 +2106,11 @@ fn select_prefix_action_gui() -> Option<PrefixAction> {
         "Prefix settings (DPI, etc.)",
         "verb",
         "Create custom verb",
+        "notes",
+        "Notes and tags",
     ];
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -750,6 +2124,7 @@ fn select_prefix_action_gui() -> Option<PrefixAction> {
         "tools" => Some(PrefixAction::WineTools),
         "settings" => Some(PrefixAction::Settings),
         "verb" => Some(PrefixAction::CreateVerb),
+        "notes" => Some(PrefixAction::Notes),
         _ => None,
     }
 }
@@ -766,10 +2141,7 @@ fn select_executable_gui() -> Option<PathBuf> {
         "Windows Executables | *.exe *.msi *.bat",
     ];
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -791,6 +2163,8 @@ fn select_arch_gui() -> Option<crate::wine::WineArch> {
         "--list",
         "--title",
         "Select prefix architecture",
+        "--text",
+        "Select prefix architecture",
         "--column",
         "Architecture",
         "--column",
@@ -807,10 +2181,7 @@ fn select_arch_gui() -> Option<crate::wine::WineArch> {
         "32-bit Windows (for legacy apps)",
     ];
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -820,14 +2191,37 @@ fn select_arch_gui() -> Option<crate::wine::WineArch> {
     crate::wine::WineArch::from_str(&selected)
 }
 
-/// Show GUI to select a Wine tool (winecfg, regedit, etc.).
-fn select_wine_tool_gui() -> Option<String> {
+/// Wine tools offered by the tools picker, with descriptions.
+const WINE_TOOLS: &[(&str, &str)] = &[
+    ("winecfg", "Wine configuration"),
+    ("regedit", "Registry editor"),
+    ("taskmgr", "Task manager"),
+    ("explorer", "File explorer"),
+    ("winefile", "Wine file manager"),
+    ("control", "Control panel"),
+    ("cmd", "Command prompt"),
+    ("uninstaller", "Wine uninstaller"),
+    ("notepad", "Text editor"),
+    ("wordpad", "Rich text editor"),
+    ("oleview", "OLE/COM object viewer"),
+    ("winver", "Wine version"),
+];
+
+/// Picker row for entering any command.
+const CUSTOM_TOOL_ROW: &str = "custom";
+
+/// Show GUI to select a Wine tool (winecfg, regedit, etc.) or enter a
+/// command. Tools missing from the runner are hidden. Returns the program
+/// and its arguments.
+fn select_wine_tool_gui(proton_path: &Path) -> Option<Vec<String>> {
     let gui_tool = crate::gui::get_gui_tool()?;
 
-    let args = vec![
+    let mut args = vec![
         "--list",
         "--title",
         "Select Wine tool",
+        "--text",
+        "Select Wine tool",
         "--column",
         "Tool",
         "--column",
@@ -837,27 +2231,16 @@ fn select_wine_tool_gui() -> Option<String> {
         "--width",
         "500",
         "--height",
-        "350",
-        "winecfg",
-        "Wine configuration",
-        "regedit",
-        "Registry editor",
-        "taskmgr",
-        "Task manager",
-        "explorer",
-        "File explorer",
-        "control",
-        "Control panel",
-        "cmd",
-        "Command prompt",
-        "uninstaller",
-        "Wine uninstaller",
+        "420",
     ];
+    for (tool, description) in WINE_TOOLS {
+        if crate::wine::runner::has_program(proton_path, tool) {
+            args.extend([*tool, *description]);
+        }
+    }
+    args.extend([CUSTOM_TOOL_ROW, "Run a custom command..."]);
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -865,9 +2248,35 @@ fn select_wine_tool_gui() -> Option<String> {
 
     let selected = output_to_string(&output);
     if selected.is_empty() {
-        None
-    } else {
-        Some(selected)
+        return None;
+    }
+    if selected != CUSTOM_TOOL_ROW {
+        return Some(vec![selected]);
+    }
+
+    let output = crate::gui::dialog(&gui_tool)
+        .args([
+            "--entry",
+            "--title",
+            "Run a custom command",
+            "--text",
+            "Windows program and arguments, quoted where they contain spaces:\n\n\
+             e.g. winedbg --help or notepad \"C:\\users\\steamuser\\log.txt\"",
+            "--width",
+            "500",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    match crate::util::split_args(&output_to_string(&output)) {
+        Ok(command) if !command.is_empty() => Some(command),
+        Ok(_) => None,
+        Err(e) => {
+            eprintln!("Invalid command: {}", e);
+            None
+        }
     }
 }
 
@@ -878,6 +2287,9 @@ enum PrefixSetting {
     WindowsVersion,
     VirtualDesktop,
     Theme,
+    DxvkConfig,
+    Vkd3dConfig,
+    LegacyApps,
     RegistryImport,
     ViewLogs,
 }
@@ -890,6 +2302,8 @@ fn select_prefix_setting_gui() -> Option<PrefixSetting> {
         "--list",
         "--title",
         "Select setting",
+        "--text",
+        "Select setting",
         "--column",
         "Setting",
         "--column",
@@ -910,16 +2324,19 @@ fn select_prefix_setting_gui() -> Option<PrefixSetting> {
         "Virtual desktop",
         "theme",
         "Desktop theme",
+        "dxvk",
+        "DXVK configuration (dxvk.conf)",
+        "vkd3d",
+        "VKD3D-Proton (Direct3D 12) settings",
+        "legacy",
+        "Legacy apps (16-bit and 90s-era games)",
         "registry",
         "Import registry file (.reg)",
         "logs",
         "View application logs",
     ];
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -932,6 +2349,9 @@ fn select_prefix_setting_gui() -> Option<PrefixSetting> {
         "winver" => Some(PrefixSetting::WindowsVersion),
         "desktop" => Some(PrefixSetting::VirtualDesktop),
         "theme" => Some(PrefixSetting::Theme),
+        "dxvk" => Some(PrefixSetting::DxvkConfig),
+        "vkd3d" => Some(PrefixSetting::Vkd3dConfig),
+        "legacy" => Some(PrefixSetting::LegacyApps),
         "registry" => Some(PrefixSetting::RegistryImport),
         "logs" => Some(PrefixSetting::ViewLogs),
         _ => None,
@@ -947,6 +2367,8 @@ fn select_dpi_gui() -> Option<u32> {
         "--list",
         "--title",
         "Select DPI",
+        "--text",
+        "Select DPI",
         "--column",
         "DPI",
         "--column",
@@ -973,10 +2395,7 @@ fn select_dpi_gui() -> Option<u32> {
         "400%",
     ];
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -998,112 +2417,382 @@ fn set_wine_dpi(wine_ctx: &crate::wine::WineContext, dpi: u32) {
         dpi, dpi
     );
 
-    // Write to a temp .reg file
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_dpi.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, &reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, &reg_content, true) {
         return;
     }
 
-    // Import the registry file
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
+    // Import through a uniquely named temp .reg file
+    match import_reg_content(wine_ctx, "dpi", &reg_content) {
         Ok(_) => println!(
             "DPI set to {}. You may need to restart applications for changes to take effect.",
             dpi
         ),
         Err(e) => eprintln!("Failed to set DPI: {}", e),
     }
-
-    // Clean up
-    std::fs::remove_file(&reg_file).ok();
 }
 
 // ============================================================================
-// DLL OVERRIDE SETTINGS
+// DXVK AND VKD3D CONFIGURATION
 // ============================================================================
 
-/// Run the DLL override management GUI.
-fn run_dll_override_gui(wine_ctx: &crate::wine::WineContext) {
+/// Edit the common dxvk.conf options of a prefix in a form. The config is
+/// kept in the prefix and referenced through DXVK_CONFIG_FILE.
+/// Edit the notes and tags of a game or custom prefix in a form.
+fn run_notes_gui(subject: &crate::notes::Subject, name: &str) {
+    use crate::notes::{self, parse_tags};
+
     let gui_tool = match crate::gui::get_gui_tool() {
         Some(tool) => tool,
         None => return,
     };
+    let mut current = notes::get(subject);
+    let mut text = format!(
+        "Notes on {}\n\nLeave a field empty to keep its value, enter - to remove it.",
+        name
+    );
+    if !current.tags.is_empty() {
+        text.push_str(&format!("\n\nTags: {}", current.tags.join(", ")));
+    }
+    if !current.note.is_empty() {
+        text.push_str(&format!("\n\nNote: {}", current.note));
+    }
+    let args = [
+        "--forms",
+        "--title",
+        "Notes and Tags",
+        "--text",
+        &text,
+        "--add-entry",
+        "Tags (comma-separated)",
+        "--add-entry",
+        "Note",
+        "--separator",
+        "|",
+        "--width",
+        "500",
+    ];
+    let output = match crate::gui::dialog(&gui_tool).args(args).output() {
+        Ok(out) if out.status.success() => output_to_string(&out),
+        _ => return,
+    };
 
-    loop {
-        // Show action menu
-        let args = vec![
-            "--list",
-            "--title",
-            "DLL Overrides",
-            "--column",
-            "Action",
-            "--column",
-            "Description",
-            "--print-column",
-            "1",
-            "--width",
-            "500",
-            "--height",
-            "300",
-            "add",
-            "Add new DLL override",
-            "remove",
-            "Remove DLL override",
-            "list",
-            "List current overrides",
-            "back",
-            "Back to settings",
-        ];
+    let (tags, note) = output.split_once('|').unwrap_or((&output, ""));
+    match tags.trim() {
+        "" => {}
+        "-" => current.tags.clear(),
+        tags => current.tags = parse_tags(tags),
+    }
+    match note.trim() {
+        "" => {}
+        "-" => current.note.clear(),
+        note => current.note = note.to_string(),
+    }
+    if let Err(e) = notes::set(subject, &current) {
+        eprintln!("Error: {}", e);
+        crate::gui::show_text_dialog("Notes and Tags", &e);
+    }
+}
 
-        let output = match std::process::Command::new(&gui_tool).args(&args).output() {
-            Ok(out) => out,
-            Err(_) => return,
-        };
+fn run_dxvk_conf_gui(prefix_path: &Path) {
+    use crate::wine::dxvk_conf::{self, DxvkConfLocation, DxvkConfig};
 
-        if !output.status.success() {
-            return;
-        }
+    let gui_tool = match crate::gui::get_gui_tool() {
+        Some(tool) => tool,
+        None => return,
+    };
+    let location = dxvk_conf::active_location(prefix_path, None).unwrap_or_else(|| {
+        DxvkConfLocation::ConfigFile(prefix_path.join(dxvk_conf::DXVK_CONF_FILE))
+    });
+    let path = location.path();
+    let mut config = DxvkConfig::load(&path);
 
-        let selected = output_to_string(&output);
-        match selected.as_str() {
-            "add" => add_dll_override_gui(&gui_tool, wine_ctx),
-            "remove" => remove_dll_override_gui(&gui_tool, wine_ctx),
-            "list" => list_dll_overrides_gui(&gui_tool, wine_ctx),
-            _ => return,
-        }
+    let labels: Vec<String> = dxvk_conf::DXVK_OPTIONS
+        .iter()
+        .map(|option| match config.get(option.key) {
+            Some(value) => format!("{} [{}]", option.key, value),
+            None => option.key.to_string(),
+        })
+        .collect();
+    let mut args = vec![
+        "--forms".to_string(),
+        "--title".to_string(),
+        "DXVK Configuration".to_string(),
+        "--text".to_string(),
+        format!(
+            "{}\n\nLeave a field empty to keep its value, enter - to remove it.",
+            path.display()
+        ),
+        "--separator".to_string(),
+        "|".to_string(),
+        "--width".to_string(),
+        "500".to_string(),
+    ];
+    for label in &labels {
+        args.push("--add-entry".to_string());
+        args.push(label.clone());
     }
-}
-
-/// Show GUI dialogs to add a new DLL override.
-fn add_dll_override_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::WineContext) {
-    // Get DLL name
-    let output = std::process::Command::new(gui_tool)
-        .args([
-            "--entry",
-            "--title", "Add DLL Override",
-            "--text", "Enter DLL name (without .dll extension):\n\nCommon examples: d3d9, d3d11, dxgi, xinput1_3, vcrun2019",
-            "--width", "400",
-        ])
-        .output();
 
-    let dll_name = match output {
+    let output = match crate::gui::dialog(&gui_tool).args(&args).output() {
         Ok(out) if out.status.success() => output_to_string(&out),
         _ => return,
     };
 
-    if dll_name.is_empty() {
+    let mut errors = Vec::new();
+    for (option, value) in dxvk_conf::DXVK_OPTIONS.iter().zip(output.split('|')) {
+        match value.trim() {
+            "" => {}
+            "-" => {
+                config.unset(option.key);
+            }
+            value => {
+                if let Err(e) = config.set(option.key, value) {
+                    errors.push(e);
+                }
+            }
+        }
+    }
+    if !errors.is_empty() {
+        crate::gui::show_text_dialog("DXVK Configuration", &errors.join("\n"));
         return;
     }
 
-    // Get override mode
-    let title = format!("Override mode for {}", dll_name);
-    let args = vec![
-        "--list",
-        "--title",
-        &title,
-        "--column",
+    let result = config.save(&path).and_then(|_| {
+        if crate::wine::profile::EnvProfile::load(prefix_path)
+            .get(dxvk_conf::DXVK_CONFIG_FILE_VAR)
+            .is_some()
+        {
+            return Ok(());
+        }
+        crate::journal::set_profile_var(
+            prefix_path,
+            dxvk_conf::DXVK_CONFIG_FILE_VAR,
+            Some(&path.to_string_lossy()),
+        )
+    });
+    match result {
+        Ok(()) => println!("Saved {}", path.display()),
+        Err(e) => eprintln!("Failed to save DXVK configuration: {}", e),
+    }
+}
+
+/// Combo values with the current choice first, as the form preselects the
+/// first value.
+fn combo_values(options: &[&str], current: &str) -> String {
+    let mut values = vec![current];
+    values.extend(options.iter().filter(|&&o| o != current));
+    values.join("|")
+}
+
+/// Edit a prefix's VKD3D-Proton settings in a form. Flags the detected
+/// vkd3d-proton version doesn't support are left out.
+fn run_vkd3d_config_gui(prefix_path: &Path) {
+    use crate::wine::vkd3d::{self, Vkd3dSettings};
+
+    let gui_tool = match crate::gui::get_gui_tool() {
+        Some(tool) => tool,
+        None => return,
+    };
+    let version = vkd3d::detect_version(prefix_path);
+    let mut settings = Vkd3dSettings::load(prefix_path);
+    let flags: Vec<&vkd3d::Vkd3dFlag> = vkd3d::VKD3D_FLAGS
+        .iter()
+        .filter(|flag| version.is_none_or(|v| v >= flag.since))
+        .collect();
+
+    let version_text = match version {
+        Some((major, minor)) => format!("vkd3d-proton {}.{}", major, minor),
+        None => "vkd3d-proton version unknown".to_string(),
+    };
+    let mut args = vec![
+        "--forms".to_string(),
+        "--title".to_string(),
+        "VKD3D-Proton Settings".to_string(),
+        "--text".to_string(),
+        version_text,
+        "--separator".to_string(),
+        "|".to_string(),
+        "--width".to_string(),
+        "500".to_string(),
+    ];
+    for flag in &flags {
+        let current = if settings.flags.iter().any(|f| f == flag.name) {
+            "On"
+        } else {
+            "Off"
+        };
+        args.push("--add-combo".to_string());
+        args.push(format!("{} - {}", flag.name, flag.description));
+        args.push("--combo-values".to_string());
+        args.push(combo_values(&["On", "Off"], current));
+    }
+    let mut shader_models = vec!["Default"];
+    shader_models.extend_from_slice(vkd3d::SHADER_MODELS);
+    args.push("--add-combo".to_string());
+    args.push("Shader model".to_string());
+    args.push("--combo-values".to_string());
+    args.push(combo_values(
+        &shader_models,
+        settings.shader_model.as_deref().unwrap_or("Default"),
+    ));
+    let mut debug_levels = vec!["Default"];
+    debug_levels.extend_from_slice(vkd3d::DEBUG_LEVELS);
+    args.push("--add-combo".to_string());
+    args.push("Debug log level".to_string());
+    args.push("--combo-values".to_string());
+    args.push(combo_values(
+        &debug_levels,
+        settings.debug.as_deref().unwrap_or("Default"),
+    ));
+
+    let output = match crate::gui::dialog(&gui_tool).args(&args).output() {
+        Ok(out) if out.status.success() => output_to_string(&out),
+        _ => return,
+    };
+    let values: Vec<&str> = output.split('|').map(|v| v.trim()).collect();
+    if values.len() != flags.len() + 2 {
+        return;
+    }
+
+    let mut result = Ok(());
+    for (flag, value) in flags.iter().zip(&values) {
+        if *value == "On" {
+            result = result.and_then(|_| settings.enable_flag(flag.name, version));
+        } else {
+            settings.disable_flag(flag.name);
+        }
+    }
+    let optional = |v: &str| Some(v.to_string()).filter(|v| v != "Default");
+    let result = result
+        .and_then(|_| settings.set_shader_model(optional(values[flags.len()]).as_deref()))
+        .and_then(|_| settings.set_debug(optional(values[flags.len() + 1]).as_deref()))
+        .and_then(|_| settings.save(prefix_path));
+    match result {
+        Ok(()) => println!("Saved VKD3D-Proton settings"),
+        Err(e) => eprintln!("Failed to save VKD3D-Proton settings: {}", e),
+    }
+}
+
+// ============================================================================
+// LEGACY APP SETTINGS
+// ============================================================================
+
+/// Run the legacy apps settings group: pick and apply settings verbs for
+/// 16-bit and 90s-era applications.
+fn run_legacy_apps_gui(verb_runner: &Wine, prefix_arg: &str) {
+    let verbs: Vec<&crate::wine::verbs::Verb> = crate::wine::verbs::LEGACY_APP_VERBS
+        .iter()
+        .filter_map(|name| verb_runner.verb_registry.get(name))
+        .collect();
+    let selected = select_verbs_with_gui(
+        &verb_runner.verb_registry,
+        &verbs,
+        Some("Select legacy app settings"),
+    );
+    let mut command = vec!["--prefix", prefix_arg];
+    for verb_name in &selected {
+        if !confirm_setting_changes(verb_runner, verb_name, true) {
+            continue;
+        }
+        println!("Running verb: {}", verb_name);
+        command.push(verb_name);
+        if let Err(e) = verb_runner.run_verb(verb_name) {
+            eprintln!("Error running {}: {}", verb_name, e);
+        }
+    }
+    print_verb_summary(verb_runner);
+    if command.len() > 2 {
+        equivalent::show(&command);
+    }
+}
+
+// ============================================================================
+// DLL OVERRIDE SETTINGS
+// ============================================================================
+
+/// Run the DLL override management GUI.
+fn run_dll_override_gui(wine_ctx: &crate::wine::WineContext) {
+    let gui_tool = match crate::gui::get_gui_tool() {
+        Some(tool) => tool,
+        None => return,
+    };
+
+    loop {
+        // Show action menu
+        let args = vec![
+            "--list",
+            "--title",
+            "DLL Overrides",
+            "--text",
+            "DLL Overrides",
+            "--column",
+            "Action",
+            "--column",
+            "Description",
+            "--print-column",
+            "1",
+            "--width",
+            "500",
+            "--height",
+            "300",
+            "add",
+            "Add new DLL override",
+            "remove",
+            "Remove DLL override",
+            "list",
+            "List current overrides",
+            "back",
+            "Back to settings",
+        ];
+
+        let output = match crate::gui::dialog(&gui_tool).args(&args).output() {
+            Ok(out) => out,
+            Err(_) => return,
+        };
+
+        if !output.status.success() {
+            return;
+        }
+
+        let selected = output_to_string(&output);
+        match selected.as_str() {
+            "add" => add_dll_override_gui(&gui_tool, wine_ctx),
+            "remove" => remove_dll_override_gui(&gui_tool, wine_ctx),
+            "list" => list_dll_overrides_gui(&gui_tool, wine_ctx),
+            _ => return,
+        }
+    }
+}
+
+/// Show GUI dialogs to add a new DLL override.
+fn add_dll_override_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::WineContext) {
+    // Get DLL name
+    let output = crate::gui::dialog(gui_tool)
+        .args([
+            "--entry",
+            "--title", "Add DLL Override",
+            "--text", "Enter DLL name (without .dll extension):\n\nCommon examples: d3d9, d3d11, dxgi, xinput1_3, vcrun2019",
+            "--width", "400",
+        ])
+        .output();
+
+    let dll_name = match output {
+        Ok(out) if out.status.success() => output_to_string(&out),
+        _ => return,
+    };
+
+    if dll_name.is_empty() {
+        return;
+    }
+
+    // Get override mode
+    let title = format!("Override mode for {}", dll_name);
+    let args = vec![
+        "--list",
+        "--title",
+        &title,
+        "--text",
+        &title,
+        "--column",
         "Mode",
         "--column",
         "Description",
@@ -1125,7 +2814,7 @@ fn add_dll_override_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::Wine
         "Disable the DLL entirely",
     ];
 
-    let output = match std::process::Command::new(gui_tool).args(&args).output() {
+    let output = match crate::gui::dialog(gui_tool).args(&args).output() {
         Ok(out) => out,
         Err(_) => return,
     };
@@ -1147,25 +2836,19 @@ fn add_dll_override_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::Wine
         dll_name, mode
     );
 
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_dll_override.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, &reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, &reg_content, true) {
         return;
     }
 
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
+    match import_reg_content(wine_ctx, "dll_override", &reg_content) {
         Ok(_) => println!("DLL override set: {} = {}", dll_name, mode),
         Err(e) => eprintln!("Failed to set DLL override: {}", e),
     }
-
-    std::fs::remove_file(&reg_file).ok();
 }
 
 fn remove_dll_override_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::WineContext) {
     // Get DLL name to remove
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--entry",
             "--title",
@@ -1194,20 +2877,14 @@ fn remove_dll_override_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::W
         dll_name
     );
 
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_dll_override.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, &reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, &reg_content, true) {
         return;
     }
 
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
+    match import_reg_content(wine_ctx, "dll_override", &reg_content) {
         Ok(_) => println!("DLL override removed: {}", dll_name),
         Err(e) => eprintln!("Failed to remove DLL override: {}", e),
     }
-
-    std::fs::remove_file(&reg_file).ok();
 }
 
 fn list_dll_overrides_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::WineContext) {
@@ -1242,7 +2919,7 @@ fn list_dll_overrides_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::Wi
         Err(_) => "No DLL overrides configured.".to_string(),
     };
 
-    let _ = std::process::Command::new(gui_tool)
+    let _ = crate::gui::dialog(gui_tool)
         .args([
             "--info",
             "--title",
@@ -1262,10 +2939,12 @@ fn list_dll_overrides_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine::Wi
 fn select_windows_version_gui() -> Option<String> {
     let gui_tool = crate::gui::get_gui_tool()?;
 
-    let args = vec![
+    let mut args = vec![
         "--list",
         "--title",
         "Select Windows Version",
+        "--text",
+        "Select Windows Version",
         "--column",
         "Version",
         "--column",
@@ -1275,33 +2954,14 @@ fn select_windows_version_gui() -> Option<String> {
         "--width",
         "500",
         "--height",
-        "400",
-        "win11",
-        "Windows 11",
-        "win10",
-        "Windows 10",
-        "win81",
-        "Windows 8.1",
-        "win8",
-        "Windows 8",
-        "win7",
-        "Windows 7",
-        "vista",
-        "Windows Vista",
-        "winxp64",
-        "Windows XP (64-bit)",
-        "winxp",
-        "Windows XP",
-        "win2k",
-        "Windows 2000",
-        "win98",
-        "Windows 98",
+        "500",
     ];
+    for version in crate::wine::registry::WINDOWS_VERSIONS {
+        args.push(version.name);
+        args.push(version.description);
+    }
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -1316,55 +2976,23 @@ fn select_windows_version_gui() -> Option<String> {
 }
 
 fn set_windows_version(wine_ctx: &crate::wine::WineContext, version: &str) {
-    // Map version string to Windows version data
-    let (ver_str, build, sp, product) = match version {
-        "win11" => ("win11", "10.0.22000", "", "Windows 11"),
-        "win10" => ("win10", "10.0.19041", "", "Windows 10"),
-        "win81" => ("win81", "6.3.9600", "", "Windows 8.1"),
-        "win8" => ("win8", "6.2.9200", "", "Windows 8"),
-        "win7" => ("win7", "6.1.7601", "Service Pack 1", "Windows 7"),
-        "vista" => ("vista", "6.0.6002", "Service Pack 2", "Windows Vista"),
-        "winxp64" => ("winxp64", "5.2.3790", "Service Pack 2", "Windows XP"),
-        "winxp" => ("winxp", "5.1.2600", "Service Pack 3", "Windows XP"),
-        "win2k" => ("win2k", "5.0.2195", "Service Pack 4", "Windows 2000"),
-        "win98" => ("win98", "4.10.2222", "", "Windows 98"),
-        _ => return,
+    let Some(build) = crate::wine::registry::windows_build(version) else {
+        return;
     };
+    let reg_content = build.reg_content();
 
-    let parts: Vec<&str> = build.split('.').collect();
-    let major = parts.get(0).unwrap_or(&"10");
-    let minor = parts.get(1).unwrap_or(&"0");
-    let build_num = parts.get(2).unwrap_or(&"0");
-
-    let reg_content = format!(
-        "Windows Registry Editor Version 5.00\n\n\
-         [HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows NT\\CurrentVersion]\n\
-         \"ProductName\"=\"{}\"\n\
-         \"CSDVersion\"=\"{}\"\n\
-         \"CurrentBuild\"=\"{}\"\n\
-         \"CurrentBuildNumber\"=\"{}\"\n\
-         \"CurrentVersion\"=\"{}.{}\"\n\n\
-         [HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Control\\Windows]\n\
-         \"CSDVersion\"=dword:00000000\n\n\
-         [HKEY_CURRENT_USER\\Software\\Wine]\n\
-         \"Version\"=\"{}\"\n",
-        product, sp, build_num, build_num, major, minor, ver_str
-    );
-
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_winver.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, &reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, &reg_content, true) {
         return;
     }
 
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
-        Ok(_) => println!("Windows version set to: {}", product),
+    match import_reg_content(wine_ctx, "winver", &reg_content) {
+        Ok(_) => println!(
+            "Windows version set to: {} ({})",
+            build.description,
+            build.version_string()
+        ),
         Err(e) => eprintln!("Failed to set Windows version: {}", e),
     }
-
-    std::fs::remove_file(&reg_file).ok();
 }
 
 // ============================================================================
@@ -1381,6 +3009,8 @@ fn run_virtual_desktop_gui(wine_ctx: &crate::wine::WineContext) {
         "--list",
         "--title",
         "Virtual Desktop",
+        "--text",
+        "Virtual Desktop",
         "--column",
         "Action",
         "--column",
@@ -1397,7 +3027,7 @@ fn run_virtual_desktop_gui(wine_ctx: &crate::wine::WineContext) {
         "Disable virtual desktop (fullscreen)",
     ];
 
-    let output = match std::process::Command::new(&gui_tool).args(&args).output() {
+    let output = match crate::gui::dialog(&gui_tool).args(&args).output() {
         Ok(out) => out,
         Err(_) => return,
     };
@@ -1420,6 +3050,8 @@ fn enable_virtual_desktop_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine
         "--list",
         "--title",
         "Virtual Desktop Resolution",
+        "--text",
+        "Virtual Desktop Resolution",
         "--column",
         "Resolution",
         "--column",
@@ -1450,7 +3082,7 @@ fn enable_virtual_desktop_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine
         "4:3",
     ];
 
-    let output = match std::process::Command::new(gui_tool).args(&args).output() {
+    let output = match crate::gui::dialog(gui_tool).args(&args).output() {
         Ok(out) => out,
         Err(_) => return,
     };
@@ -1473,20 +3105,14 @@ fn enable_virtual_desktop_gui(gui_tool: &std::path::Path, wine_ctx: &crate::wine
         resolution
     );
 
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_desktop.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, &reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, &reg_content, true) {
         return;
     }
 
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
+    match import_reg_content(wine_ctx, "desktop", &reg_content) {
         Ok(_) => println!("Virtual desktop enabled at {}", resolution),
         Err(e) => eprintln!("Failed to enable virtual desktop: {}", e),
     }
-
-    std::fs::remove_file(&reg_file).ok();
 }
 
 fn disable_virtual_desktop(wine_ctx: &crate::wine::WineContext) {
@@ -1494,20 +3120,14 @@ fn disable_virtual_desktop(wine_ctx: &crate::wine::WineContext) {
          [HKEY_CURRENT_USER\\Software\\Wine\\Explorer]\n\
          \"Desktop\"=-\n";
 
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_desktop.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, reg_content, true) {
         return;
     }
 
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
+    match import_reg_content(wine_ctx, "desktop", reg_content) {
         Ok(_) => println!("Virtual desktop disabled"),
         Err(e) => eprintln!("Failed to disable virtual desktop: {}", e),
     }
-
-    std::fs::remove_file(&reg_file).ok();
 }
 
 // ============================================================================
@@ -1524,6 +3144,8 @@ fn select_theme_gui(wine_ctx: &crate::wine::WineContext) -> Option<String> {
         "--list".to_string(),
         "--title".to_string(),
         "Select Theme".to_string(),
+        "--text".to_string(),
+        "Select Theme".to_string(),
         "--column".to_string(),
         "Theme".to_string(),
         "--column".to_string(),
@@ -1551,10 +3173,7 @@ fn select_theme_gui(wine_ctx: &crate::wine::WineContext) -> Option<String> {
         }
     }
 
-    let output = std::process::Command::new(&gui_tool)
-        .args(&args)
-        .output()
-        .ok()?;
+    let output = crate::gui::dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -1635,15 +3254,11 @@ fn set_wine_theme(wine_ctx: &crate::wine::WineContext, theme: &str) {
         )
     };
 
-    let tmp_dir = std::env::temp_dir();
-    let reg_file = tmp_dir.join("protontool_theme.reg");
-
-    if let Err(e) = std::fs::write(&reg_file, &reg_content) {
-        eprintln!("Failed to write registry file: {}", e);
+    if !confirm_reg_content(wine_ctx, &reg_content, true) {
         return;
     }
 
-    match wine_ctx.run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()]) {
+    match import_reg_content(wine_ctx, "theme", &reg_content) {
         Ok(_) => {
             if theme == "(none)" {
                 println!("Theme disabled (classic Windows look)");
@@ -1653,8 +3268,6 @@ fn set_wine_theme(wine_ctx: &crate::wine::WineContext, theme: &str) {
         }
         Err(e) => eprintln!("Failed to set theme: {}", e),
     }
-
-    std::fs::remove_file(&reg_file).ok();
 }
 
 fn create_builtin_theme(themes_dir: &std::path::Path, name: &str) {
@@ -1737,9 +3350,7 @@ pub fn run_log_viewer_gui() {
             "400",
         ];
 
-        let filter_output = std::process::Command::new(&gui_tool)
-            .args(&filter_args)
-            .output();
+        let filter_output = crate::gui::dialog(&gui_tool).args(&filter_args).output();
 
         let filters = match filter_output {
             Ok(out) if out.status.success() => output_to_string(&out),
@@ -1775,9 +3386,13 @@ pub fn run_log_viewer_gui() {
                 "--list".to_string(),
                 "--title".to_string(),
                 "Log Viewer".to_string(),
+                "--text".to_string(),
+                "Log Viewer".to_string(),
                 "--column".to_string(),
                 "Type".to_string(),
                 "--column".to_string(),
+                "Source".to_string(),
+                "--column".to_string(),
                 "Count".to_string(),
                 "--column".to_string(),
                 "Time".to_string(),
@@ -1796,6 +3411,7 @@ pub fn run_log_viewer_gui() {
             ];
 
             if entries.is_empty() {
+                list_args.push("--".to_string());
                 list_args.push("--".to_string());
                 list_args.push("0".to_string());
                 list_args.push("--".to_string());
@@ -1803,6 +3419,7 @@ pub fn run_log_viewer_gui() {
             } else {
                 for entry in &entries {
                     list_args.push(entry.level.clone());
+                    list_args.push(entry.source.to_string());
                     list_args.push(entry.count.to_string());
                     list_args.push(entry.timestamp.clone());
                     // Truncate long messages for display
@@ -1815,9 +3432,7 @@ pub fn run_log_viewer_gui() {
                 }
             }
 
-            let list_output = std::process::Command::new(&gui_tool)
-                .args(&list_args)
-                .output();
+            let list_output = crate::gui::dialog(&gui_tool).args(&list_args).output();
 
             match list_output {
                 Ok(out) => {
@@ -1859,9 +3474,28 @@ pub fn view_logs_cli(lines: Option<usize>, level: Option<&str>, search: Option<&
 
     let limit = lines.unwrap_or(50);
 
-    println!("╔════════╦═══════╦═════════════════════╦════════════════════════════════════════════════════════════╗");
-    println!("║ Level  ║ Count ║ Time                ║ Message                                                    ║");
-    println!("╠════════╬═══════╬═════════════════════╬════════════════════════════════════════════════════════════╣");
+    if crate::util::is_plain_output() {
+        for entry in entries.iter().take(limit) {
+            println!("Level: {}", entry.level);
+            println!("Source: {}", entry.source);
+            println!("Count: {}", entry.count);
+            println!("Time: {}", entry.timestamp);
+            println!("Message: {}", entry.message);
+            println!();
+        }
+        if entries.len() > limit {
+            println!(
+                "Showing {} of {} entries. Use --lines to see more.",
+                limit,
+                entries.len()
+            );
+        }
+        return;
+    }
+
+    println!("╔════════╦════════════╦═══════╦═════════════════════╦═══════════════════════════════════════════════╗");
+    println!("║ Level  ║ Source     ║ Count ║ Time                ║ Message                                       ║");
+    println!("╠════════╬════════════╬═══════╬═════════════════════╬═══════════════════════════════════════════════╣");
 
     for entry in entries.iter().take(limit) {
         let level_colored = match entry.level.as_str() {
@@ -1872,22 +3506,23 @@ pub fn view_logs_cli(lines: Option<usize>, level: Option<&str>, search: Option<&
             _ => format!("{:6}", entry.level),
         };
 
-        let msg = if entry.message.len() > 58 {
-            format!("{}...", &entry.message[..55])
+        let msg = if entry.message.len() > 45 {
+            format!("{}...", &entry.message[..42])
         } else {
             entry.message.clone()
         };
 
         println!(
-            "║ {} ║ {:5} ║ {:19} ║ {:58} ║",
+            "║ {} ║ {:10} ║ {:5} ║ {:19} ║ {:45} ║",
             level_colored,
+            entry.source.name(),
             entry.count,
             &entry.timestamp[..std::cmp::min(19, entry.timestamp.len())],
             msg
         );
     }
 
-    println!("╚════════╩═══════╩═════════════════════╩════════════════════════════════════════════════════════════╝");
+    println!("╚════════╩════════════╩═══════╩═════════════════════╩═══════════════════════════════════════════════╝");
 
     if entries.len() > limit {
         println!(
@@ -1909,11 +3544,13 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
     };
 
     // Ask how to select the file
-    let method_output = std::process::Command::new(&gui_tool)
+    let method_output = crate::gui::dialog(&gui_tool)
         .args([
             "--list",
             "--title",
             "Registry Import",
+            "--text",
+            "Registry Import",
             "--column",
             "Method",
             "--column",
@@ -1939,7 +3576,7 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
     let reg_path = match method.as_str() {
         "browse" => {
             // File selection dialog for .reg files
-            let output = std::process::Command::new(&gui_tool)
+            let output = crate::gui::dialog(&gui_tool)
                 .args([
                     "--file-selection",
                     "--title",
@@ -1956,7 +3593,7 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
         }
         "manual" => {
             // Manual entry dialog
-            let output = std::process::Command::new(&gui_tool)
+            let output = crate::gui::dialog(&gui_tool)
                 .args([
                     "--entry",
                     "--title",
@@ -1994,28 +3631,19 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
             content
         };
 
-        let confirm_output = std::process::Command::new(&gui_tool)
-            .args([
-                "--question",
-                "--title",
-                "Confirm Registry Import",
-                "--text",
-                &format!(
-                    "Import this registry file?\n\nFile: {}\n\nPreview:\n{}",
-                    path.file_name().unwrap_or_default().to_string_lossy(),
-                    preview
-                ),
-                "--width",
-                "600",
-            ])
-            .output();
-
-        match confirm_output {
-            Ok(out) if out.status.success() => {}
-            _ => {
-                println!("Import cancelled.");
-                return;
-            }
+        let text = format!(
+            "File: {}\n\nPreview:\n{}",
+            path.file_name().unwrap_or_default().to_string_lossy(),
+            preview
+        );
+        if !confirm(
+            "Confirm Registry Import",
+            &text,
+            "Import this registry file?",
+            true,
+        ) {
+            println!("Import cancelled.");
+            return;
         }
     }
 
@@ -2026,7 +3654,7 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
                 println!("Registry file imported successfully: {}", reg_path);
 
                 // Show success dialog
-                let _ = std::process::Command::new(&gui_tool)
+                let _ = crate::gui::dialog(&gui_tool)
                     .args([
                         "--info",
                         "--title",
@@ -2039,7 +3667,7 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
                 let stderr = String::from_utf8_lossy(&output.stderr);
                 eprintln!("Registry import may have failed: {}", stderr);
 
-                let _ = std::process::Command::new(&gui_tool)
+                let _ = crate::gui::dialog(&gui_tool)
                     .args([
                         "--warning",
                         "--title",
@@ -2053,7 +3681,7 @@ fn run_registry_import_gui(wine_ctx: &crate::wine::WineContext) {
         Err(e) => {
             eprintln!("Failed to import registry file: {}", e);
 
-            let _ = std::process::Command::new(&gui_tool)
+            let _ = crate::gui::dialog(&gui_tool)
                 .args([
                     "--error",
                     "--title",
@@ -2196,11 +3824,13 @@ fn run_verb_creator_gui() {
     };
 
     // Initial dialog: Import existing or create new?
-    let output = std::process::Command::new(&gui_tool)
+    let output = crate::gui::dialog(&gui_tool)
         .args([
             "--list",
             "--title",
             "Custom Verb Creator",
+            "--text",
+            "Custom Verb Creator",
             "--column",
             "Option",
             "--column",
@@ -2238,7 +3868,7 @@ fn run_verb_creator_gui() {
     }
 
     // Show advanced options checkbox
-    let show_advanced = std::process::Command::new(&gui_tool)
+    let show_advanced = crate::gui::dialog(&gui_tool)
         .args([
             "--question",
             "--title", "Verb Creator Mode",
@@ -2267,7 +3897,7 @@ fn run_verb_creator_gui() {
 }
 
 fn import_verb_toml_gui(gui_tool: &std::path::Path) -> Option<VerbData> {
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--file-selection",
             "--title",
@@ -2295,7 +3925,7 @@ fn edit_verb_simple_gui(gui_tool: &std::path::Path, data: &mut VerbData) -> bool
     // Simple mode: just ask for title, publisher, and installer path
     // Name is derived from title, year is current year, category defaults to app
 
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--forms",
             "--title",
@@ -2332,7 +3962,7 @@ fn edit_verb_simple_gui(gui_tool: &std::path::Path, data: &mut VerbData) -> bool
     }
 
     // Select installer file
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--file-selection",
             "--title",
@@ -2359,11 +3989,13 @@ fn edit_verb_advanced_gui(gui_tool: &std::path::Path, data: &mut VerbData) -> bo
     // Advanced mode: full control over all fields
 
     // First, select category
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--list",
             "--title",
             "Select Category",
+            "--text",
+            "Select Category",
             "--column",
             "Category",
             "--column",
@@ -2398,11 +4030,13 @@ fn edit_verb_advanced_gui(gui_tool: &std::path::Path, data: &mut VerbData) -> bo
     }
 
     // Select action type
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--list",
             "--title",
             "Select Action Type",
+            "--text",
+            "Select Action Type",
             "--column",
             "Type",
             "--column",
@@ -2435,7 +4069,7 @@ fn edit_verb_advanced_gui(gui_tool: &std::path::Path, data: &mut VerbData) -> bo
     }
 
     // Form for all text fields
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--forms",
             "--title",
@@ -2500,7 +4134,7 @@ fn edit_verb_advanced_gui(gui_tool: &std::path::Path, data: &mut VerbData) -> bo
     };
 
     if data.action_type == "local_installer" || data.action_type == "script" {
-        let output = std::process::Command::new(gui_tool)
+        let output = crate::gui::dialog(gui_tool)
             .args([
                 "--file-selection",
                 "--title",
@@ -2532,11 +4166,13 @@ fn save_verb_gui(gui_tool: &std::path::Path, data: &VerbData) {
     std::fs::create_dir_all(&default_dir).ok();
 
     // Ask Save or Save As
-    let output = std::process::Command::new(gui_tool)
+    let output = crate::gui::dialog(gui_tool)
         .args([
             "--list",
             "--title",
             "Save Verb",
+            "--text",
+            "Save Verb",
             "--column",
             "Option",
             "--column",
@@ -2566,7 +4202,7 @@ fn save_verb_gui(gui_tool: &std::path::Path, data: &VerbData) {
 
         if choice == "saveas" {
             // Let user choose location
-            let output = std::process::Command::new(gui_tool)
+            let output = crate::gui::dialog(gui_tool)
                 .args([
                     "--file-selection",
                     "--save",
@@ -2605,7 +4241,7 @@ fn save_verb_gui(gui_tool: &std::path::Path, data: &VerbData) {
     match std::fs::write(&save_path, &toml_content) {
         Ok(_) => {
             println!("Verb saved to: {}", save_path.display());
-            let _ = std::process::Command::new(gui_tool)
+            let _ = crate::gui::dialog(gui_tool)
                 .args([
                     "--info",
                     "--title", "Verb Saved",
@@ -2616,7 +4252,7 @@ fn save_verb_gui(gui_tool: &std::path::Path, data: &VerbData) {
         }
         Err(e) => {
             eprintln!("Failed to save verb: {}", e);
-            let _ = std::process::Command::new(gui_tool)
+            let _ = crate::gui::dialog(gui_tool)
                 .args([
                     "--error",
                     "--title",
@@ -2631,16 +4267,18 @@ fn save_verb_gui(gui_tool: &std::path::Path, data: &VerbData) {
     }
 }
 
-fn run_list_mode(parsed: &util::ParsedArgs, no_term: bool) {
+fn run_list_mode(env: &CliEnv, parsed: &util::ParsedArgs, no_term: bool) {
     let extra_libs = parsed.get_multi_option("steam_library").to_vec();
     let verbose = parsed.get_count("verbose") > 0;
 
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
-        }
-    };
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
 
     if verbose {
         println!("Steam path: {}", steam_path.display());
@@ -2698,100 +4336,291 @@ fn run_list_mode(parsed: &util::ParsedArgs, no_term: bool) {
     if !matching_apps.is_empty() {
         println!("Found the following games:");
         for app in &matching_apps {
-            println!("{} ({})", app.name, app.appid);
+            let notes = crate::notes::get(&crate::notes::Subject::App(app.appid));
+            match notes.tags_label() {
+                tags if tags.is_empty() => println!("{} ({})", app.name, app.appid),
+                tags => println!("{} ({}) {}", app.name, app.appid, tags),
+            }
         }
         println!("\nTo run protontool for the chosen game, run:");
         println!("$ protontool APPID COMMAND");
     } else {
         println!("Found no games.");
     }
+    for report in steam_lib_paths
+        .iter()
+        .filter_map(|lib| crate::wine::filesystem::check(lib, false))
+        .filter(|report| !report.problems.is_empty())
+    {
+        eprintln!("Warning: Steam library {}", report.summary());
+    }
 
     println!("\nNOTE: A game must be launched at least once before protontool can find the game.");
 }
 
-fn run_verb_mode(appid: u32, verbs: &[String], parsed: &util::ParsedArgs, no_term: bool) {
-    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
-        }
-    };
+/// Answer yes to every confirmation (`--yes`).
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
 
-    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+/// Whether `config.toml` answers confirmations by default
+/// (`assume_yes` in the `[prompts]` section).
+fn assume_yes_by_config(config: &crate::config::UserConfig) -> bool {
+    config
+        .get("prompts", "assume_yes")
+        .is_some_and(|v| matches!(v, "true" | "1" | "yes"))
+}
 
-    let steam_app = match steam_apps
-        .iter()
-        .find(|app| app.appid == appid && app.is_windows_app())
-    {
-        Some(app) => app.clone(),
-        None => {
-            exit_with_error(
-                "Steam app with the given app ID could not be found. Is it installed and have you launched it at least once?",
-                no_term
-            );
-        }
-    };
+/// Accept verb license agreements without asking (`--accept-licenses`).
+static ACCEPT_LICENSES: AtomicBool = AtomicBool::new(false);
 
-    let proton_app = match find_proton_app(&steam_path, &steam_apps, appid) {
-        Some(app) => app,
-        None => {
-            exit_with_error("Proton installation could not be found!", no_term);
-        }
-    };
+/// Ask a yes/no question in a dialog or on the terminal. Defaults to no;
+/// `flag` is the option that answers it without a prompt.
+fn ask_yes_no(title: &str, text: &str, question: &str, use_gui: bool, flag: &str) -> bool {
+    if use_gui {
+        return ask_question_gui(title, &format!("{}\n\n{}", text, question));
+    }
+    println!("{}\n", text);
+    print!("{} [y/N] ", question);
+    io::stdout().flush().ok();
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).unwrap_or(0) == 0 {
+        println!("\nNo answer; pass {} to confirm without a prompt.", flag);
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
 
-    if !proton_app.is_proton_ready {
-        exit_with_error(
-            "Proton installation is incomplete. Have you launched a Steam app using this Proton version at least once?",
-            no_term
-        );
+/// Ask a yes/no question in a dialog or on the terminal. Defaults to no.
+/// Every change that can't be taken back asks through this, so `--yes`
+/// answers all of them.
+fn confirm(title: &str, text: &str, question: &str, use_gui: bool) -> bool {
+    if ASSUME_YES.load(Ordering::SeqCst) {
+        println!("{}\n\n{} yes (--yes)", text, question);
+        return true;
     }
+    ask_yes_no(title, text, question, use_gui, "--yes")
+}
 
-    let prefix_path = steam_app.prefix_path.as_ref().unwrap();
-    let verb_runner = Wine::new(&proton_app, prefix_path);
+/// Message describing a failed step of a verb.
+fn action_failure_message(failure: &crate::wine::verbs::ActionFailure) -> String {
+    format!(
+        "Step {} of {} of {} failed ({}):\n\n{}",
+        failure.index + 1,
+        failure.count,
+        failure.verb,
+        failure.action,
+        failure.error
+    )
+}
 
-    // Run each specified verb
-    let mut success = true;
-    for verb_name in verbs {
-        // Skip if it looks like a flag (starts with -)
-        if verb_name.starts_with('-') {
-            continue;
-        }
+/// Ask on the terminal whether to retry, skip or abort a failed verb step.
+fn ask_action_failure_terminal(
+    failure: &crate::wine::verbs::ActionFailure,
+) -> crate::wine::verbs::ActionResolution {
+    use crate::wine::verbs::ActionResolution;
 
-        println!("Running verb: {}", verb_name);
-        match verb_runner.run_verb(verb_name) {
-            Ok(()) => println!("Successfully completed: {}", verb_name),
-            Err(e) => {
-                eprintln!("Error running {}: {}", verb_name, e);
-                success = false;
-            }
+    eprintln!("{}\n", action_failure_message(failure));
+    loop {
+        eprint!("[r]etry, [s]kip this step, or [a]bort {}? ", failure.verb);
+        io::stderr().flush().ok();
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer).unwrap_or(0) == 0 {
+            return ActionResolution::Abort;
+        }
+        match answer.trim().to_lowercase().as_str() {
+            "r" | "retry" => return ActionResolution::Retry,
+            "s" | "skip" => return ActionResolution::Skip,
+            "a" | "abort" => return ActionResolution::Abort,
+            _ => {}
         }
     }
+}
 
-    if success {
-        process::exit(0);
-    } else {
-        process::exit(1);
+/// Ask with a dialog whether to retry, skip or abort a failed verb step.
+/// Closing the dialog aborts.
+fn ask_action_failure_gui(
+    failure: &crate::wine::verbs::ActionFailure,
+) -> crate::wine::verbs::ActionResolution {
+    use crate::wine::verbs::ActionResolution;
+
+    let choice = crate::gui::ask_choice_gui(
+        "Step Failed",
+        &action_failure_message(failure),
+        &["Retry", "Skip", "Abort"],
+    );
+    match choice.as_deref() {
+        Some("Retry") => ActionResolution::Retry,
+        Some("Skip") => ActionResolution::Skip,
+        _ => ActionResolution::Abort,
     }
 }
 
-fn run_command_mode(appid: Option<u32>, command: &str, parsed: &util::ParsedArgs, no_term: bool) {
-    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
+/// Check a prefix before running verbs or wineboot in it: refuse a prefix on
+/// a read-only mount, and offer to shut down a wineserver of a different
+/// Wine version still serving it. Returns false if the operation should not
+/// go ahead.
+fn check_prefix_before_changes(wine_ctx: &crate::wine::WineContext, use_gui: bool) -> bool {
+    use crate::wine::preflight;
+
+    let prefix_path = &wine_ctx.prefix_path;
+    if let Some(mount_point) = preflight::read_only_mount(prefix_path) {
+        let message = format!(
+            "The prefix {} is on {}, which is mounted read-only. Remount it read-write to change the prefix.",
+            prefix_path.display(),
+            mount_point.display()
+        );
+        if use_gui {
+            crate::gui::show_text_dialog("Read-only Prefix", &message);
         }
-    };
+        eprintln!("Error: {}", message);
+        return false;
+    }
 
-    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    for server in preflight::foreign_servers(wine_ctx) {
+        let text = format!(
+            "A wineserver from a different Wine version is still running for {}:\n\n  {}\n\n\
+             Running {} against it can trigger a prefix update or corrupt the prefix. \
+             Shutting it down closes every program still running in the prefix.",
+            prefix_path.display(),
+            server.describe(),
+            wine_ctx.wineserver_path.display()
+        );
+        if !confirm("Wineserver Running", &text, "Shut it down now?", use_gui) {
+            eprintln!("Close the programs running in the prefix and try again.");
+            return false;
+        }
+        if let Err(e) = preflight::shut_down(&server, prefix_path) {
+            eprintln!("Error: {}", e);
+            return false;
+        }
+        crate::log::info(&format!("Shut down wineserver {}", server.describe()));
+    }
+    true
+}
 
-    let appid = match appid {
-        Some(id) => id,
-        None => {
-            exit_with_error("APPID is required for -c/--command mode", no_term);
+/// Show the values registry content would change and ask before applying.
+/// Returns true without asking if nothing would change, or if there is no
+/// terminal to ask on: scripts get the preview and the change, which `undo`
+/// can take back.
+fn confirm_registry_changes(
+    changes: &[crate::wine::registry::RegistryChange],
+    use_gui: bool,
+) -> bool {
+    if changes.is_empty() {
+        return true;
+    }
+    let preview: Vec<String> = changes.iter().map(|c| c.describe()).collect();
+    let text = format!(
+        "The following registry values will change:\n\n{}",
+        preview.join("\n")
+    );
+    if !use_gui && !io::IsTerminal::is_terminal(&io::stdin()) {
+        println!("{}\n", text);
+        return true;
+    }
+    confirm("Apply Settings", &text, "Apply these changes?", use_gui)
+}
+
+/// Preview the changes of .reg content in a prefix and ask before applying.
+fn confirm_reg_content(wine_ctx: &crate::wine::WineContext, content: &str, use_gui: bool) -> bool {
+    let changes = crate::wine::registry::preview_reg_content(&wine_ctx.prefix_path, content);
+    confirm_registry_changes(&changes, use_gui)
+}
+
+/// Import .reg content into a prefix with regedit, journaling the values
+/// it replaces so `protontool undo` can restore them. The file gets a unique
+/// name in a directory of its own, so concurrent settings changes (e.g. two
+/// GUI windows on different prefixes) can't overwrite each other's imports.
+fn import_reg_content(
+    wine_ctx: &crate::wine::WineContext,
+    label: &str,
+    content: &str,
+) -> Result<(), String> {
+    let changes = crate::journal::registry_changes(wine_ctx, content);
+    let work_dir = crate::workdir::WorkDir::create(label)?;
+    let reg_file = work_dir.file(label, "reg");
+    std::fs::write(&reg_file, content)
+        .map_err(|e| format!("Failed to write registry file: {}", e))?;
+    wine_ctx
+        .run_wine_no_cwd(&["regedit", "/S", &reg_file.to_string_lossy()])
+        .map_err(|e| e.to_string())?;
+
+    let description = format!(
+        "{} setting in {}",
+        label.replace('_', " "),
+        wine_ctx.prefix_path.display()
+    );
+    if let Err(e) = crate::journal::record(&description, changes) {
+        crate::log::warn(&format!("Failed to record {} in journal: {}", label, e));
+    }
+    Ok(())
+}
+
+/// Preview the registry changes of a settings verb and ask before running it.
+fn confirm_setting_changes(verb_runner: &Wine, verb_name: &str, use_gui: bool) -> bool {
+    let changes = verb_runner
+        .verb_registry
+        .preview_settings(verb_name, &verb_runner.wine_ctx);
+    if confirm_registry_changes(&changes, use_gui) {
+        return true;
+    }
+    eprintln!("Skipping {}", verb_name);
+    false
+}
+
+/// Ask the user to accept licenses required by a verb or its dependencies.
+/// Returns false if a license was declined. `--yes` doesn't answer this;
+/// only `--accept-licenses` does.
+fn accept_verb_licenses(verb_runner: &Wine, verb_name: &str, use_gui: bool) -> bool {
+    for verb in verb_runner.verb_registry.pending_licenses(verb_name) {
+        let notice = crate::wine::license::license_notice(verb);
+        let question = "Do you accept the license agreement?";
+        let accepted = if ACCEPT_LICENSES.load(Ordering::SeqCst) {
+            println!("{}\n\n{} yes (--accept-licenses)", notice, question);
+            true
+        } else {
+            ask_yes_no(
+                "License Agreement",
+                &notice,
+                question,
+                use_gui,
+                "--accept-licenses",
+            )
+        };
+
+        if !accepted {
+            eprintln!(
+                "License for {} was not accepted, skipping {}",
+                verb.title, verb_name
+            );
+            return false;
         }
-    };
+        if let Some(eula) = &verb.eula {
+            if let Err(e) = crate::wine::license::record_acceptance(eula) {
+                crate::log::warn(&format!("Failed to record license acceptance: {}", e));
+            }
+        }
+    }
+    true
+}
+
+fn run_verb_mode(
+    env: &CliEnv,
+    appid: u32,
+    verbs: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
 
     let steam_app = match steam_apps
         .iter()
@@ -2799,317 +4628,3253 @@ fn run_command_mode(appid: Option<u32>, command: &str, parsed: &util::ParsedArgs
     {
         Some(app) => app.clone(),
         None => {
-            exit_with_error(
-                "Steam app with the given app ID could not be found.",
-                no_term,
+            env.exit.exit_with_error(
+                "Steam app with the given app ID could not be found. Is it installed and have you launched it at least once?",
+                no_term
             );
         }
     };
+    mount_app_prefix(env, &steam_app, no_term);
 
     let proton_app = match find_proton_app(&steam_path, &steam_apps, appid) {
         Some(app) => app,
         None => {
-            exit_with_error("Proton installation could not be found!", no_term);
+            env.exit
+                .exit_with_error("Proton installation could not be found!", no_term);
         }
     };
 
-    // Use built-in wine context to run the command
+    if !proton_app.is_proton_ready {
+        env.exit.exit_with_error(
+            "Proton installation is incomplete. Have you launched a Steam app using this Proton version at least once?",
+            no_term
+        );
+    }
+
     let prefix_path = steam_app.prefix_path.as_ref().unwrap();
-    let wine_ctx = crate::wine::WineContext::from_proton(&proton_app, prefix_path);
+    let mut verb_runner = Wine::new(&proton_app, prefix_path);
+    if !check_prefix_before_changes(&verb_runner.wine_ctx, no_term) {
+        env.exit.exit(1);
+    }
+    if crate::wine::provision::steam_prefix_needs_provisioning(prefix_path) {
+        crate::wine::provision::provision_prefix(&verb_runner.wine_ctx);
+    }
+    restore_mounts(prefix_path);
+    apply_override_args(env, &mut verb_runner.wine_ctx, parsed, no_term);
 
-    let cwd_app = parsed.get_flag("cwd_app");
-    let _cwd = if cwd_app {
-        Some(steam_app.install_path.to_string_lossy().to_string())
-    } else {
-        None
-    };
+    // Run each specified verb
+    let mut success = true;
+    for verb_name in verbs {
+        // Skip if it looks like a flag (starts with -)
+        if verb_name.starts_with('-') {
+            continue;
+        }
 
-    // Start background wineserver if requested
-    if parsed.get_flag("background_wineserver") {
-        if let Err(e) = wine_ctx.start_wineserver() {
-            eprintln!("Warning: Failed to start background wineserver: {}", e);
+        if !accept_verb_licenses(&verb_runner, verb_name, no_term) {
+            success = false;
+            continue;
+        }
+        if !confirm_setting_changes(&verb_runner, verb_name, no_term) {
+            success = false;
+            continue;
         }
-    }
 
-    // Run the command with wine
-    match wine_ctx.run_wine(&[command]) {
-        Ok(output) => {
-            if !output.stdout.is_empty() {
-                println!("{}", String::from_utf8_lossy(&output.stdout));
-            }
-            if !output.stderr.is_empty() {
-                eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        println!("Running verb: {}", verb_name);
+        match verb_runner.run_verb(verb_name) {
+            Ok(()) => println!("Successfully completed: {}", verb_name),
+            Err(e) => {
+                eprintln!("Error running {}: {}", verb_name, e);
+                success = false;
             }
-            process::exit(output.status.code().unwrap_or(0));
-        }
-        Err(e) => {
-            exit_with_error(&format!("Failed to run command: {}", e), no_term);
         }
     }
+    print_verb_summary(&verb_runner);
+
+    if success {
+        env.exit.exit(0);
+    } else {
+        env.exit.exit(1);
+    }
 }
 
-fn run_prefix_command_mode(
-    prefix_path: &str,
-    command: &str,
+/// Print how long each verb of a batch took and how much it downloaded, and
+/// notify the desktop when the batch took long.
+fn print_verb_summary(verb_runner: &Wine) {
+    let runs = verb_runner.take_stats();
+    if runs.is_empty() {
+        return;
+    }
+    println!("\n{}", crate::wine::stats::summary_table(&runs));
+
+    let elapsed = runs.iter().map(|r| r.download_time + r.install_time).sum();
+    let failed: Vec<&str> = runs
+        .iter()
+        .filter(|r| !r.succeeded)
+        .map(|r| r.verb.as_str())
+        .collect();
+    let summary = if failed.is_empty() {
+        let verbs: Vec<&str> = runs.iter().map(|r| r.verb.as_str()).collect();
+        format!("Installed {}", verbs.join(", "))
+    } else {
+        format!("Failed: {}", failed.join(", "))
+    };
+    let body = format!(
+        "Took {} in {}",
+        crate::wine::stats::format_duration(elapsed),
+        verb_runner.wine_ctx.prefix_path.display()
+    );
+    crate::notify::operation_finished(&summary, &body, failed.is_empty(), elapsed);
+}
+
+/// Pseudo-verbs that open a game's folders instead of running verbs.
+const OPEN_TARGETS: &[&str] = &[
+    "open-screenshots",
+    "open-installdir",
+    "open-prefix",
+    "open-workshop",
+];
+
+fn run_open_folder_mode(
+    env: &CliEnv,
+    appid: u32,
+    target: &str,
     parsed: &util::ParsedArgs,
     no_term: bool,
 ) {
-    let prefix_path = PathBuf::from(prefix_path);
-
-    if !prefix_path.exists() {
-        exit_with_error(
-            &format!("Prefix path does not exist: {}", prefix_path.display()),
-            no_term,
-        );
-    }
-
     let extra_libs = parsed.get_multi_option("steam_library").to_vec();
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
-        }
-    };
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
 
     let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
-
-    // Try to read saved Proton and arch info from prefix metadata
-    let metadata_path = prefix_path.join(".protontool");
-    let metadata_content = std::fs::read_to_string(&metadata_path).ok();
-
-    let proton_app = if let Some(ref metadata) = metadata_content {
-        let proton_name = metadata
-            .lines()
-            .find(|l| l.starts_with("proton_name="))
-            .and_then(|l| l.strip_prefix("proton_name="));
-
-        if let Some(name) = proton_name {
-            find_proton_by_name(&steam_apps, name)
-        } else {
-            None
+    let steam_app = match steam_apps.iter().find(|app| app.appid == appid) {
+        Some(app) => app,
+        None => {
+            env.exit.exit_with_error(
+                "Steam app with the given app ID could not be found.",
+                no_term,
+            );
         }
-    } else {
-        None
     };
+    mount_app_prefix(env, steam_app, no_term);
 
-    // Read saved architecture (default to win64)
-    let saved_arch = metadata_content
-        .as_ref()
-        .and_then(|m| m.lines().find(|l| l.starts_with("arch=")))
-        .and_then(|l| l.strip_prefix("arch="))
-        .and_then(crate::wine::WineArch::from_str)
-        .unwrap_or(crate::wine::WineArch::Win64);
-
-    // If no saved Proton or --proton flag specified, select one
-    let proton_app = if let Some(proton_name) = parsed.get_option("proton") {
-        match find_proton_by_name(&steam_apps, proton_name) {
-            Some(app) => app,
+    let path = match target {
+        "open-screenshots" => match find_screenshot_dirs(&steam_path, appid).into_iter().next() {
+            Some(dir) => dir,
             None => {
-                exit_with_error(
-                    &format!("Proton version '{}' not found.", proton_name),
+                env.exit.exit_with_error(
+                    &format!("No screenshots found for {} ({}).", steam_app.name, appid),
                     no_term,
                 );
             }
-        }
-    } else if let Some(app) = proton_app {
-        println!("Using saved Proton version: {}", app.name);
-        app
-    } else {
-        match select_proton_with_gui(&get_proton_apps(&steam_apps)) {
-            Some(app) => app,
+        },
+        "open-installdir" => steam_app.install_path.clone(),
+        "open-workshop" => match steam_app.workshop_content_path().filter(|p| p.is_dir()) {
+            Some(dir) => dir,
+            None => {
+                env.exit.exit_with_error(
+                    &format!(
+                        "No Workshop content found for {} ({}).",
+                        steam_app.name, appid
+                    ),
+                    no_term,
+                );
+            }
+        },
+        _ => match steam_app.prefix_path.as_ref().filter(|p| p.exists()) {
+            Some(prefix) => prefix.clone(),
             None => {
-                exit_with_error("No Proton version selected.", no_term);
+                env.exit.exit_with_error(
+                    &format!(
+                        "{} ({}) has no Proton prefix yet. Launch it once or run 'protontool {} --prewarm'.",
+                        steam_app.name, appid, appid
+                    ),
+                    no_term,
+                );
             }
-        }
+        },
     };
 
-    if !proton_app.is_proton_ready {
-        exit_with_error("Proton installation is not ready.", no_term);
+    println!("Opening {}", path.display());
+    if let Err(e) = crate::util::open_path(&path) {
+        env.exit.exit_with_error(&e, no_term);
     }
+}
 
-    let wine_ctx =
-        crate::wine::WineContext::from_proton_with_arch(&proton_app, &prefix_path, saved_arch);
+/// Find an installed Steam app and its (existing) Proton prefix, exiting
+/// with an error if either is missing.
+fn find_steam_app_prefix(
+    env: &CliEnv,
+    appid: u32,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) -> (SteamApp, PathBuf) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
 
-    // Start background wineserver if requested
-    if parsed.get_flag("background_wineserver") {
-        if let Err(e) = wine_ctx.start_wineserver() {
-            eprintln!("Warning: Failed to start background wineserver: {}", e);
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    let steam_app = match steam_apps.iter().find(|app| app.appid == appid) {
+        Some(app) => app.clone(),
+        None => {
+            env.exit.exit_with_error(
+                "Steam app with the given app ID could not be found.",
+                no_term,
+            );
         }
-    }
+    };
+    mount_app_prefix(env, &steam_app, no_term);
+    let prefix_path = match steam_app.prefix_path.as_ref().filter(|p| p.exists()) {
+        Some(prefix) => prefix.clone(),
+        None => {
+            env.exit.exit_with_error(
+                &format!(
+                    "{} ({}) has no Proton prefix yet. Launch it once or run 'protontool {} --prewarm'.",
+                    steam_app.name, appid, appid
+                ),
+                no_term,
+            );
+        }
+    };
+    (steam_app, prefix_path)
+}
 
-    // Run the command with wine
-    match wine_ctx.run_wine(&[command]) {
-        Ok(output) => {
-            if !output.stdout.is_empty() {
-                println!("{}", String::from_utf8_lossy(&output.stdout));
+/// Show or edit a game's dxvk.conf, or switch where it is kept.
+fn run_dxvk_conf_mode(
+    env: &CliEnv,
+    appid: u32,
+    args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::dxvk_conf::{self, DxvkConfLocation, DxvkConfig};
+
+    let (steam_app, prefix_path) = find_steam_app_prefix(env, appid, parsed, no_term);
+    let game_dir = steam_app.install_path.as_path();
+
+    // New configs go next to the game so they also apply to Steam launches
+    let location = dxvk_conf::active_location(&prefix_path, Some(game_dir))
+        .unwrap_or_else(|| DxvkConfLocation::GameDir(game_dir.to_path_buf()));
+
+    match args.first().map(|a| a.as_str()) {
+        None | Some("show") => {
+            let config = DxvkConfig::load(&location.path());
+            println!("dxvk.conf for {}: {}", steam_app.name, location.describe());
+            if config.is_empty() {
+                println!("\nNo options set. Common options:");
+                for option in dxvk_conf::DXVK_OPTIONS {
+                    println!("  {:<26} {}", option.key, option.description);
+                }
+            } else {
+                for (key, value) in config.options() {
+                    println!("  {} = {}", key, value);
+                }
             }
-            if !output.stderr.is_empty() {
-                eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Some("set") | Some("unset") => {
+            let path = location.path();
+            let mut config = DxvkConfig::load(&path);
+            for arg in &args[1..] {
+                if args[0] == "unset" {
+                    config.unset(arg);
+                    continue;
+                }
+                let result = match arg.split_once('=') {
+                    Some((key, value)) => config.set(key.trim(), value),
+                    None => Err(format!("Expected KEY=VALUE, got {}", arg)),
+                };
+                if let Err(e) = result {
+                    env.exit.exit_with_error(&e, no_term);
+                }
+            }
+            if let Err(e) = config.save(&path) {
+                env.exit.exit_with_error(&e, no_term);
             }
-            process::exit(output.status.code().unwrap_or(0));
+            println!("Updated {}", path.display());
         }
-        Err(e) => {
-            exit_with_error(&format!("Failed to run command: {}", e), no_term);
+        Some("use") => {
+            let target = match args.get(1).map(|a| a.as_str()) {
+                Some("game") => DxvkConfLocation::GameDir(game_dir.to_path_buf()),
+                Some("prefix") => {
+                    DxvkConfLocation::ConfigFile(prefix_path.join(dxvk_conf::DXVK_CONF_FILE))
+                }
+                _ => env
+                    .exit
+                    .exit_with_error("Usage: dxvk-conf use game|prefix", no_term),
+            };
+            if let Err(e) = dxvk_conf::switch_location(&prefix_path, Some(game_dir), &target) {
+                env.exit.exit_with_error(&e, no_term);
+            }
+            println!("DXVK now reads {}", target.describe());
         }
+        Some(other) => env.exit.exit_with_error(
+            &format!(
+                "Unknown dxvk-conf command: {} (expected show, set, unset or use)",
+                other
+            ),
+            no_term,
+        ),
     }
 }
 
-fn run_create_prefix_mode(prefix_path: &str, parsed: &util::ParsedArgs, no_term: bool) {
-    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
-        }
+/// Show or edit a game's VKD3D-Proton settings (environment profile).
+fn run_vkd3d_config_mode(
+    env: &CliEnv,
+    appid: u32,
+    args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::vkd3d::{self, Vkd3dSettings};
+
+    let (steam_app, prefix_path) = find_steam_app_prefix(env, appid, parsed, no_term);
+    let version = vkd3d::detect_version(&prefix_path);
+    let mut settings = Vkd3dSettings::load(&prefix_path);
+
+    let values = &args[1.min(args.len())..];
+    let result = match args.first().map(|a| a.as_str()) {
+        None | Some("show") => {
+            println!("VKD3D-Proton settings for {}:", steam_app.name);
+            match version {
+                Some((major, minor)) => println!("  vkd3d-proton version: {}.{}", major, minor),
+                None => println!("  vkd3d-proton version: unknown"),
+            }
+            for (name, value) in settings.env_vars() {
+                println!("  {}={}", name, value.as_deref().unwrap_or("(unset)"));
+            }
+            println!("\nAvailable flags:");
+            for flag in vkd3d::VKD3D_FLAGS {
+                let supported = version.is_none_or(|v| v >= flag.since);
+                println!(
+                    "  {:<18} {}{}",
+                    flag.name,
+                    flag.description,
+                    if supported { "" } else { " (not supported)" }
+                );
+            }
+            return;
+        }
+        Some("enable") => values
+            .iter()
+            .try_for_each(|flag| settings.enable_flag(flag, version)),
+        Some("disable") => {
+            for flag in values {
+                settings.disable_flag(flag);
+            }
+            Ok(())
+        }
+        Some("shader-model") => match values.first().map(|v| v.as_str()) {
+            None | Some("default") => settings.set_shader_model(None),
+            Some(model) => settings.set_shader_model(Some(model)),
+        },
+        Some("debug") => match values.first().map(|v| v.as_str()) {
+            None | Some("default") => settings.set_debug(None),
+            Some(level) => settings.set_debug(Some(level)),
+        },
+        Some(other) => Err(format!(
+            "Unknown vkd3d-config command: {} (expected show, enable, disable, shader-model or debug)",
+            other
+        )),
     };
 
-    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
-    let proton_apps = get_proton_apps(&steam_apps);
+    if let Err(e) = result.and_then(|_| settings.save(&prefix_path)) {
+        env.exit.exit_with_error(&e, no_term);
+    }
+    println!("Updated VKD3D-Proton settings for {}", steam_app.name);
+}
 
-    if proton_apps.is_empty() {
-        exit_with_error(
-            "No Proton installations found. Please install Proton through Steam first.",
+fn run_workshop_mode(
+    env: &CliEnv,
+    appid: u32,
+    args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::workshop;
+
+    let (steam_app, prefix_path) = find_steam_app_prefix(env, appid, parsed, no_term);
+    let content = steam_app.workshop_content_path().filter(|p| p.is_dir());
+
+    match args.first().map(|a| a.as_str()) {
+        None | Some("show") => {
+            let Some(content) = content else {
+                println!("{} has no Workshop content.", steam_app.name);
+                return;
+            };
+            println!(
+                "Workshop content for {}: {}",
+                steam_app.name,
+                content.display()
+            );
+            match workshop::linked_dir(&prefix_path) {
+                Some(_) => println!(
+                    "Linked in the prefix as {}",
+                    workshop::WORKSHOP_WINDOWS_PATH
+                ),
+                None => println!("Not linked into the prefix (use 'workshop link')"),
+            }
+            for item in workshop::items(&content) {
+                println!("  {}", item.id);
+            }
+        }
+        Some("link") => {
+            let Some(content) = content else {
+                env.exit.exit_with_error(
+                    &format!(
+                        "No Workshop content found for {} ({}).",
+                        steam_app.name, appid
+                    ),
+                    no_term,
+                );
+            };
+            match workshop::link(&prefix_path, &content) {
+                Ok(path) => println!(
+                    "Workshop content is available at {} -> {}",
+                    path,
+                    content.display()
+                ),
+                Err(e) => env.exit.exit_with_error(&e, no_term),
+            }
+        }
+        Some("unlink") => match workshop::unlink(&prefix_path) {
+            Ok(true) => println!(
+                "Removed {} from the prefix",
+                workshop::WORKSHOP_WINDOWS_PATH
+            ),
+            Ok(false) => println!("Workshop content is not linked into the prefix"),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        },
+        Some(other) => env.exit.exit_with_error(
+            &format!(
+                "Unknown workshop command: {} (expected show, link or unlink)",
+                other
+            ),
             no_term,
-        );
+        ),
     }
+}
 
-    // Find Proton version - either from --proton flag or let user select
-    let proton_app = if let Some(proton_name) = parsed.get_option("proton") {
-        match find_proton_by_name(&steam_apps, proton_name) {
-            Some(app) => app,
+/// Wine context for a Steam app's existing prefix, for commands that work
+/// on the prefix without running the game.
+fn app_wine_context(
+    env: &CliEnv,
+    appid: u32,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) -> crate::wine::WineContext {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
             None => {
-                eprintln!("Available Proton versions:");
-                for app in &proton_apps {
-                    eprintln!("  - {}", app.name);
-                }
-                exit_with_error(
-                    &format!("Proton version '{}' not found.", proton_name),
-                    no_term,
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    let steam_app = steam_apps.iter().find(|app| app.appid == appid);
+    if let Some(steam_app) = steam_app {
+        mount_app_prefix(env, steam_app, no_term);
+    }
+    let prefix_path = match steam_app
+        .and_then(|app| app.prefix_path.clone())
+        .filter(|p| p.exists())
+    {
+        Some(prefix) => prefix,
+        None => env
+            .exit
+            .exit_with_error(&format!("App {} has no Proton prefix yet.", appid), no_term),
+    };
+    let proton_app = match find_proton_app(&steam_path, &steam_apps, appid) {
+        Some(app) => app,
+        None => {
+            env.exit
+                .exit_with_error("Proton installation could not be found!", no_term);
+        }
+    };
+    crate::wine::WineContext::from_proton(&proton_app, &prefix_path)
+}
+
+/// List or control the Windows services of a prefix.
+fn run_services_command(wine_ctx: &crate::wine::WineContext, args: &[String], no_term: bool) {
+    use crate::wine::services::{self, StartType};
+
+    let prefix_path = &wine_ctx.prefix_path;
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    let find = |name: &str| {
+        services::find_service(prefix_path, name).unwrap_or_else(|e| exit_with_error(&e, no_term))
+    };
+    let result = match args.as_slice() {
+        [] | ["list"] => {
+            let all = services::list_services(prefix_path);
+            if all.is_empty() {
+                println!("No services are registered in {}", prefix_path.display());
+                return;
+            }
+            let running = services::running_services(wine_ctx).unwrap_or_else(|e| {
+                eprintln!("Warning: {}", e);
+                Vec::new()
+            });
+            println!("{:<28} {:<9} {:<8} DISPLAY NAME", "NAME", "START", "STATE");
+            for service in &all {
+                println!(
+                    "{:<28} {:<9} {:<8} {}",
+                    service.name,
+                    service.start.map_or("?", |s| s.name()),
+                    if services::is_running(service, &running) {
+                        "running"
+                    } else {
+                        "stopped"
+                    },
+                    service.display_name
                 );
             }
+            Ok(String::new())
         }
-    } else {
-        match select_proton_with_gui(&proton_apps) {
-            Some(app) => app,
-            None => {
-                exit_with_error("No Proton version selected.", no_term);
+        ["start", name] => {
+            let service = find(name);
+            services::start(wine_ctx, &service).map(|_| format!("Started {}", service.name))
+        }
+        ["stop", name] => {
+            let service = find(name);
+            services::stop(wine_ctx, &service).map(|_| format!("Stopped {}", service.name))
+        }
+        ["restart", name] => {
+            let service = find(name);
+            services::restart(wine_ctx, &service).map(|_| format!("Restarted {}", service.name))
+        }
+        ["startup", name, start] => {
+            let start = StartType::parse(start).unwrap_or_else(|| {
+                exit_with_error("Start type must be auto, manual or disabled", no_term)
+            });
+            let service = find(name);
+            services::set_start_type(wine_ctx, &service, start)
+                .map(|_| format!("{} now starts {}", service.name, start.name()))
+        }
+        _ => Err(
+            "Usage: services [list|start NAME|stop NAME|restart NAME|startup NAME auto|manual|disabled]"
+                .to_string(),
+        ),
+    };
+    match result {
+        Ok(message) if !message.is_empty() => println!("{}", message),
+        Ok(_) => {}
+        Err(e) => exit_with_error(&e, no_term),
+    }
+}
+
+/// List, import or remove trusted root certificates of a prefix.
+fn run_certs_command(wine_ctx: &crate::wine::WineContext, args: &[String], no_term: bool) {
+    use crate::wine::certs;
+
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    let import = |path: &Path| {
+        let found = certs::read_certificates(path).unwrap_or_else(|e| exit_with_error(&e, no_term));
+        certs::import(wine_ctx, &found).map(|added| {
+            format!(
+                "Imported {} of {} certificate(s) from {} ({} already trusted)",
+                added,
+                found.len(),
+                path.display(),
+                found.len() - added
+            )
+        })
+    };
+    let result = match args.as_slice() {
+        [] | ["list"] => {
+            let installed = certs::installed(&wine_ctx.prefix_path);
+            if installed.is_empty() {
+                println!(
+                    "No root certificates are stored in {}",
+                    wine_ctx.prefix_path.display()
+                );
+                return;
             }
+            println!("{:<40} SUBJECT", "THUMBPRINT");
+            for cert in &installed {
+                println!("{:<40} {}", cert.thumbprint(), cert.subject());
+            }
+            Ok(String::new())
         }
+        ["import-host"] => match certs::host_ca_bundle() {
+            Some(bundle) => import(&bundle),
+            None => Err(format!(
+                "No CA bundle found on the host (looked for SSL_CERT_FILE and {})",
+                certs::HOST_CA_BUNDLES.join(", ")
+            )),
+        },
+        ["import", file] => import(Path::new(file)),
+        ["remove", thumbprint] => certs::remove(wine_ctx, thumbprint)
+            .map(|_| format!("Removed certificate {}", thumbprint)),
+        _ => Err("Usage: certs [list|import FILE|import-host|remove THUMBPRINT]".to_string()),
     };
+    match result {
+        Ok(message) if !message.is_empty() => println!("{}", message),
+        Ok(_) => {}
+        Err(e) => exit_with_error(&e, no_term),
+    }
+}
 
-    if !proton_app.is_proton_ready {
-        exit_with_error(
-            "Selected Proton installation is not ready. Please launch a game with this Proton version first to initialize it.",
-            no_term
+/// List, take, restore or delete prefix snapshots.
+fn run_snapshot_command(wine_ctx: &crate::wine::WineContext, args: &[String], no_term: bool) {
+    use crate::wine::snapshot::{self, SnapshotBackend};
+
+    let prefix_path = &wine_ctx.prefix_path;
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    let result = match args.as_slice() {
+        [] | ["list"] => {
+            let snapshots = snapshot::list(prefix_path);
+            println!(
+                "Snapshots of {} ({}):",
+                prefix_path.display(),
+                SnapshotBackend::detect(prefix_path).label()
+            );
+            if snapshots.is_empty() {
+                println!("  (none)");
+            }
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            for s in snapshots {
+                let age = s.created.map(|created| now.saturating_sub(created));
+                println!("  {:<24} {}", s.name, crate::wine::usage::describe_age(age));
+            }
+            Ok(())
+        }
+        ["create"] | ["create", _] => {
+            snapshot::create(prefix_path, args.get(1).copied()).map(|s| {
+                println!(
+                    "Created snapshot '{}' ({})",
+                    s.name,
+                    SnapshotBackend::detect(prefix_path).label()
+                )
+            })
+        }
+        ["restore", name] => {
+            let mut text = format!(
+                "Restoring '{}' replaces the current state of {}.",
+                name,
+                prefix_path.display()
+            );
+            text.push_str(&zfs_rollback_warning(prefix_path, name));
+            if !confirm("Restore Snapshot", &text, "Restore this snapshot?", no_term) {
+                return;
+            }
+            // Programs still running would write into the replaced prefix
+            wine_ctx.kill_wineserver().ok();
+            snapshot::restore(prefix_path, name).map(|_| println!("Restored snapshot '{}'", name))
+        }
+        ["delete", name] => {
+            let text = format!("Snapshot '{}' of {}", name, prefix_path.display());
+            if !confirm("Delete Snapshot", &text, "Delete this snapshot?", no_term) {
+                return;
+            }
+            snapshot::delete(prefix_path, name).map(|_| println!("Deleted snapshot '{}'", name))
+        }
+        _ => exit_with_error(
+            "Usage: snapshot [list|create [NAME]|restore NAME|delete NAME]",
+            no_term,
+        ),
+    };
+    if let Err(e) = result {
+        exit_with_error(&e, no_term);
+    }
+}
+
+/// Compare a prefix with the one named by `--with`, a path or an APPID.
+fn run_compare_command(
+    env: &CliEnv,
+    prefix_path: &Path,
+    args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::compare::{compare, format_differences, PrefixSummary};
+
+    let with = match (args, parsed.get_option("with")) {
+        ([], Some(with)) => with,
+        _ => env
+            .exit
+            .exit_with_error("Usage: compare --with PREFIX|APPID", no_term),
+    };
+    let other_path = match with.parse::<u32>() {
+        Ok(appid) if !Path::new(with).exists() => {
+            find_steam_app_prefix(env, appid, parsed, no_term).1
+        }
+        _ => PathBuf::from(crate::wine::custom::expand_path(with)),
+    };
+    if !other_path.is_dir() {
+        env.exit.exit_with_error(
+            &format!("Prefix path does not exist: {}", other_path.display()),
+            no_term,
         );
     }
 
-    let prefix_path = PathBuf::from(prefix_path);
+    let differences = compare(
+        &PrefixSummary::read(prefix_path),
+        &PrefixSummary::read(&other_path),
+    );
+    println!(
+        "Comparing {} | {}",
+        prefix_path.display(),
+        other_path.display()
+    );
+    if differences.is_empty() {
+        println!("No differences found.");
+    } else {
+        println!("{}", format_differences(&differences));
+    }
+}
 
-    // Parse architecture option (default to win64)
-    let arch = parsed
-        .get_option("arch")
-        .and_then(|s| crate::wine::WineArch::from_str(s))
-        .unwrap_or(crate::wine::WineArch::Win64);
+/// Export a prefix's DLL overrides to the `--export-overrides` file, or
+/// import those of the `--import-overrides` file.
+fn run_overrides_command(
+    wine_ctx: &crate::wine::WineContext,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::overrides;
 
-    // Create the prefix directory structure
-    println!("Creating Wine prefix at: {}", prefix_path.display());
-    println!("Using Proton: {}", proton_app.name);
-    println!("Architecture: {}", arch.as_str());
+    let path = |name: &str| {
+        parsed
+            .get_option(name)
+            .map(|file| PathBuf::from(crate::wine::custom::expand_path(file)))
+    };
+    if let Some(file) = path("export_overrides") {
+        match overrides::export(&wine_ctx.prefix_path, &file) {
+            Ok(count) => println!("Wrote {} DLL overrides to {}", count, file.display()),
+            Err(e) => exit_with_error(&e, no_term),
+        }
+    } else if let Some(file) = path("import_overrides") {
+        match overrides::import(wine_ctx, &file) {
+            Ok(imported) => {
+                for (dll, mode) in &imported {
+                    println!(
+                        "{} = {}",
+                        dll,
+                        if mode.is_empty() { "disabled" } else { mode }
+                    );
+                }
+                println!(
+                    "Imported {} DLL overrides into {}",
+                    imported.len(),
+                    wine_ctx.prefix_path.display()
+                );
+            }
+            Err(e) => exit_with_error(&e, no_term),
+        }
+    }
+}
 
-    if let Err(e) = std::fs::create_dir_all(&prefix_path) {
+/// Confirmation text naming the ZFS snapshots a restore destroys, as
+/// `zfs rollback -r` removes every snapshot newer than the restored one.
+fn zfs_rollback_warning(prefix_path: &Path, name: &str) -> String {
+    let destroyed = crate::wine::snapshot::destroyed_by_restore(prefix_path, name);
+    if destroyed.is_empty() {
+        return String::new();
+    }
+    format!(
+        "\n\nOn ZFS this destroys every newer snapshot: {}.",
+        destroyed.join(", ")
+    )
+}
+
+/// Restore the automatic snapshot taken before the most recent component
+/// verb.
+fn run_rollback_last(wine_ctx: &crate::wine::WineContext, no_term: bool) {
+    use crate::wine::snapshot;
+
+    let prefix_path = &wine_ctx.prefix_path;
+    let Some(last) = snapshot::latest_automatic(prefix_path) else {
         exit_with_error(
-            &format!("Failed to create prefix directory: {}", e),
+            &format!("No automatic snapshots of {}", prefix_path.display()),
             no_term,
         );
+    };
+    let mut text = format!(
+        "Rolling back restores {} to snapshot '{}'.",
+        prefix_path.display(),
+        last.name
+    );
+    text.push_str(&zfs_rollback_warning(prefix_path, &last.name));
+    if !confirm("Roll Back", &text, "Roll back?", no_term) {
+        return;
+    }
+    // A running wineserver would write its registry over the restored one
+    wine_ctx.kill_wineserver().ok();
+    match snapshot::restore(prefix_path, &last.name) {
+        Ok(()) => println!(
+            "Rolled back to '{}'{}",
+            last.name,
+            if last.registry_only {
+                " (registry only; installed files were kept)"
+            } else {
+                ""
+            }
+        ),
+        Err(e) => exit_with_error(&e, no_term),
+    }
+}
+
+/// Check the graphics DLLs installed by verbs and offer to reinstall the
+/// ones that were replaced.
+fn run_verify_graphics(
+    wine_ctx: &crate::wine::WineContext,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::graphics::{self, Drift};
+
+    let prefix_path = &wine_ctx.prefix_path;
+    if graphics::read_manifest(prefix_path).is_empty() {
+        println!(
+            "No graphics verbs (DXVK, VKD3D-Proton) were installed into {} by protontool",
+            prefix_path.display()
+        );
+        return;
+    }
+    let drifted = graphics::verify(prefix_path).unwrap_or_else(|e| exit_with_error(&e, no_term));
+    if drifted.is_empty() {
+        println!("All graphics DLLs installed by verbs are in place");
+        return;
+    }
+    for (entry, drift) in &drifted {
+        println!(
+            "{}: {} ({} {})",
+            match drift {
+                Drift::Missing => "missing",
+                Drift::Replaced => "replaced",
+            },
+            entry.path,
+            entry.verb,
+            entry.version
+        );
+    }
+    if parsed.get_flag("dry_run") {
+        process::exit(1);
+    }
+    let text = format!(
+        "{} graphics DLLs installed by verbs were replaced, most likely by a Proton update.",
+        drifted.len()
+    );
+    if !confirm("Repair Graphics DLLs", &text, "Reinstall them?", no_term)
+        || !check_prefix_before_changes(wine_ctx, no_term)
+    {
+        process::exit(1);
+    }
+    match graphics::repair(wine_ctx, &drifted) {
+        Ok(()) => println!("Reinstalled the graphics DLLs"),
+        Err(e) => exit_with_error(&e, no_term),
+    }
+}
+
+/// Check a prefix for known problems and optionally fix them.
+fn run_doctor_command(wine_ctx: &crate::wine::WineContext, args: &[String], no_term: bool) {
+    use crate::doctor::{self, Severity};
+
+    let fix = match args {
+        [] => false,
+        [arg] if arg == "fix" => true,
+        _ => exit_with_error("Usage: doctor [fix]", no_term),
+    };
+    let findings = doctor::check_prefix(wine_ctx);
+    for finding in &findings {
+        println!(
+            "[{}] {}: {}",
+            finding.severity.label(),
+            finding.check,
+            finding.message
+        );
+    }
+
+    let fixes: Vec<&doctor::Fix> = findings.iter().filter_map(|f| f.fix.as_ref()).collect();
+    if fixes.is_empty() {
+        if findings.iter().all(|f| f.severity == Severity::Ok) {
+            println!("No problems found.");
+        }
+        return;
+    }
+    if !fix {
+        println!();
+        for f in &fixes {
+            println!("Fixable: {}", f.describe());
+        }
+        println!("Run 'doctor fix' to apply.");
+        return;
+    }
+    let mut failed = false;
+    for f in fixes {
+        match f.apply(wine_ctx) {
+            Ok(()) => println!("Fixed: {}", f.describe()),
+            Err(e) => {
+                eprintln!("Failed to {}: {}", f.describe(), e);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        exit_with_error("Some fixes could not be applied.", no_term);
+    }
+}
+
+/// List, apply or drop the verbs deferred until the prefix is idle. `wait`
+/// waits for the game to exit before applying them.
+fn run_deferred_command(wine_ctx: &crate::wine::WineContext, args: &[String], no_term: bool) {
+    use crate::wine::deferred;
+
+    let prefix_path = &wine_ctx.prefix_path;
+    match args.first().map(|a| a.as_str()) {
+        None => {
+            let pending = deferred::pending(prefix_path);
+            if pending.is_empty() {
+                println!("No deferred verbs for {}", prefix_path.display());
+                return;
+            }
+            let state = if deferred::is_idle(prefix_path) {
+                "idle"
+            } else {
+                "in use"
+            };
+            println!("Deferred verbs ({}): {}", state, pending.join(", "));
+        }
+        Some("clear") if args.len() == 1 => match deferred::remove(prefix_path, None) {
+            Ok(()) => println!("Cleared the deferred verbs of {}", prefix_path.display()),
+            Err(e) => exit_with_error(&e, no_term),
+        },
+        Some(command @ ("run" | "wait")) if args.len() == 1 => {
+            if deferred::pending(prefix_path).is_empty() {
+                println!("No deferred verbs for {}", prefix_path.display());
+                return;
+            }
+            if command == "wait" && !deferred::is_idle(prefix_path) {
+                println!("Waiting for the prefix to go idle...");
+                deferred::wait_until_idle(prefix_path, None);
+            }
+            if !check_prefix_before_changes(wine_ctx, no_term) {
+                process::exit(1);
+            }
+            match deferred::run_pending(wine_ctx) {
+                Ok(failed) if failed.is_empty() => println!("Applied the deferred verbs."),
+                Ok(failed) => {
+                    for (verb, error) in &failed {
+                        eprintln!("{} failed: {}", verb, error);
+                    }
+                    exit_with_error("Some deferred verbs failed; they stay queued.", no_term);
+                }
+                Err(e) => exit_with_error(&e, no_term),
+            }
+        }
+        _ => exit_with_error("Usage: deferred [run|wait|clear]", no_term),
+    }
+}
+
+/// Open Wine's game controller test and report which of the host's
+/// controllers the prefix sees. `report` skips the test window.
+fn run_test_controller_command(
+    wine_ctx: &crate::wine::WineContext,
+    appid: Option<u32>,
+    args: &[String],
+    no_term: bool,
+) {
+    use crate::controller;
+
+    let open_test = match args {
+        [] => true,
+        [arg] if arg == "report" => false,
+        _ => exit_with_error("Usage: test-controller [report]", no_term),
+    };
+    if open_test {
+        println!("Opening the game controller test; close it to see the report.");
+        if let Err(e) = wine_ctx.run_wine_no_cwd(&["control", "joy.cpl"]) {
+            exit_with_error(&format!("Failed to run joy.cpl: {}", e), no_term);
+        }
+        // The registry is written out when the wineserver exits
+        wine_ctx.wait_for_wineserver().ok();
+    }
+
+    let host = controller::connected_gamepads();
+    let seen = controller::prefix_devices(&wine_ctx.prefix_path);
+    if host.is_empty() {
+        println!("No controllers are connected to the host (see /proc/bus/input/devices).");
+    } else {
+        println!("Host controllers:");
+        for device in &host {
+            let id = device.id();
+            let state = match id {
+                Some(id) if seen.contains(&id) => "seen by the prefix",
+                Some(_) => "NOT seen by the prefix",
+                None => "unknown ID",
+            };
+            println!(
+                "  {} [{}] {}",
+                device.name,
+                id.map_or_else(|| "????:????".to_string(), |id| id.to_string()),
+                state
+            );
+        }
+    }
+    let others: Vec<String> = seen
+        .iter()
+        .filter(|id| !host.iter().any(|d| d.id() == Some(**id)))
+        .map(|id| id.to_string())
+        .collect();
+    if !others.is_empty() {
+        println!(
+            "Other devices recorded in the prefix: {}",
+            others.join(", ")
+        );
+    }
+
+    let missing: Vec<&controller::InputDevice> = host
+        .iter()
+        .filter(|d| d.id().is_some_and(|id| !seen.contains(&id)))
+        .collect();
+    let mut hints = controller::controller_hints(&wine_ctx.prefix_path, &missing);
+    if !missing.is_empty() {
+        if let Some(warning) = controller::steam_input_warning(None, appid) {
+            hints.insert(0, warning);
+        }
+    }
+    if !hints.is_empty() {
+        println!();
+        println!("Hints:");
+        for hint in hints {
+            println!("  - {}", hint);
+        }
+    }
+}
+
+fn run_play_mode(env: &CliEnv, appid: u32, parsed: &util::ParsedArgs, no_term: bool) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    let steam_app = match steam_apps
+        .iter()
+        .find(|app| app.appid == appid && app.is_windows_app())
+    {
+        Some(app) => app.clone(),
+        None => {
+            env.exit.exit_with_error(
+                "Steam app with the given app ID could not be found. Is it installed and have you launched it at least once?",
+                no_term
+            );
+        }
+    };
+    mount_app_prefix(env, &steam_app, no_term);
+
+    let proton_app = match find_proton_app(&steam_path, &steam_apps, appid) {
+        Some(app) => app,
+        None => {
+            env.exit
+                .exit_with_error("Proton installation could not be found!", no_term);
+        }
+    };
+
+    if parsed.get_flag("via_steam") {
+        println!("Launching {} ({}) through Steam", steam_app.name, appid);
+        match crate::play::launch_via_steam(appid, parsed.trailing()) {
+            Ok(session) => env.exit.exit(session.exit_code),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        }
+    }
+    if let Some(warning) = crate::controller::steam_input_warning(Some(&steam_path), Some(appid)) {
+        eprintln!("Warning: {}", warning);
+    }
+
+    if let Some(prefix_path) = steam_app.prefix_path.as_deref() {
+        if crate::wine::provision::steam_prefix_needs_provisioning(prefix_path) {
+            let wine_ctx = crate::wine::WineContext::from_proton(&proton_app, prefix_path);
+            crate::wine::provision::provision_prefix(&wine_ctx);
+        }
+        restore_mounts(prefix_path);
+        save_scheduling_args(env, prefix_path, parsed, no_term);
+        warn_missing_scheduling_tools(&crate::wine::scheduling::Scheduling::from_profile(
+            &crate::wine::profile::EnvProfile::load(prefix_path),
+        ));
+    }
+
+    println!("Launching {} ({})", steam_app.name, appid);
+    let overrides = override_args(env, parsed, no_term);
+    let session = match crate::play::play(
+        &steam_path,
+        &steam_app,
+        &proton_app,
+        parsed.trailing(),
+        &overrides,
+    ) {
+        Ok(s) => s,
+        Err(e) => env.exit.exit_with_error(&e, no_term),
+    };
+
+    match session.exception {
+        Some(status) => println!(
+            "Game crashed with {} (exit code {})",
+            crate::log::describe_exception(status),
+            session.exit_code
+        ),
+        None => println!("Game exited with code {}", session.exit_code),
+    }
+    if let Some(log_path) = session.log_path {
+        println!("Log saved to {}", log_path.display());
+        match crate::analyze::analyze_log(&log_path, 0) {
+            Ok(analysis) => print_analysis_table(&analysis),
+            Err(e) => eprintln!("Failed to analyze log: {}", e),
+        }
+    }
+    env.exit.exit(session.exit_code);
+}
+
+/// Whether a string is an absolute Windows path like `C:\Games\game.exe`.
+fn is_windows_path(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() >= 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && (bytes[2] == b'\\' || bytes[2] == b'/')
+}
+
+/// The program and arguments of `-c`/`--command`. Arguments after `--` are
+/// passed as they are (`-c PROGRAM -- ARGS`, or `-c -- PROGRAM ARGS`). A
+/// command string without them is split like a shell would, unless it is
+/// an existing file or a Windows path, which may contain spaces. A leading
+/// `wine` is dropped since the command runs through Wine already.
+fn command_argv(command: &str, trailing: &[String]) -> Result<Vec<String>, String> {
+    let argv = if command.is_empty() {
+        trailing.to_vec()
+    } else if !trailing.is_empty()
+        || Path::new(command).is_file()
+        || is_windows_path(command)
+        || !command.contains(char::is_whitespace)
+    {
+        std::iter::once(command.to_string())
+            .chain(trailing.iter().cloned())
+            .collect()
+    } else {
+        let mut split = crate::util::split_args(command)?;
+        if split.len() > 1 && split[0] == "wine" {
+            split.remove(0);
+        }
+        split
+    };
+    if argv.is_empty() {
+        return Err("no program given".to_string());
+    }
+    Ok(argv)
+}
+
+/// Run the `-c` program in `--workdir`, `default_dir` or the program's own
+/// directory, and exit with its exit code. With `--detach` the program is
+/// started in the background and its PID printed instead; with
+/// `--wait-wineserver` exiting waits until every process in the prefix has.
+fn run_wine_command(
+    env: &CliEnv,
+    wine_ctx: &crate::wine::WineContext,
+    argv: &[&str],
+    default_dir: Option<&Path>,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) -> ! {
+    let workdir = parsed
+        .get_option("workdir")
+        .map(|dir| PathBuf::from(crate::wine::custom::expand_path(dir)));
+    if let Some(dir) = workdir.as_ref().filter(|dir| !dir.is_dir()) {
+        env.exit.exit_with_error(
+            &format!("Working directory does not exist: {}", dir.display()),
+            no_term,
+        );
+    }
+    let cwd = workdir.as_deref().or(default_dir);
+
+    if parsed.get_flag("detach") {
+        let log_dir = crate::config::get_log_dir();
+        std::fs::create_dir_all(&log_dir).ok();
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let log_path = log_dir.join(format!("command-{}.log", stamp));
+        let log = std::fs::File::create(&log_path).unwrap_or_else(|e| {
+            env.exit.exit_with_error(
+                &format!("Failed to create {}: {}", log_path.display(), e),
+                no_term,
+            )
+        });
+        match wine_ctx.spawn_wine(argv, cwd, log) {
+            Ok(child) => {
+                // The PID alone on stdout, so scripts can capture it
+                println!("{}", child.id());
+                eprintln!("Started {}, output in {}", argv[0], log_path.display());
+                env.exit.exit(0);
+            }
+            Err(e) => env
+                .exit
+                .exit_with_error(&format!("Failed to run command: {}", e), no_term),
+        }
+    }
+
+    let result = match cwd {
+        Some(dir) => wine_ctx.run_wine_cwd(argv, dir),
+        None => wine_ctx.run_wine(argv),
+    };
+    match result {
+        Ok(output) => {
+            if !output.stdout.is_empty() {
+                println!("{}", String::from_utf8_lossy(&output.stdout));
+            }
+            if !output.stderr.is_empty() {
+                eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            }
+            if parsed.get_flag("wait_wineserver") {
+                if let Err(e) = wine_ctx.wait_for_wineserver() {
+                    eprintln!("Warning: Failed to wait for wineserver: {}", e);
+                }
+            }
+            env.exit.exit(output.status.code().unwrap_or(0));
+        }
+        Err(e) => {
+            env.exit
+                .exit_with_error(&format!("Failed to run command: {}", e), no_term);
+        }
+    }
+}
+
+fn run_command_mode(
+    env: &CliEnv,
+    appid: Option<u32>,
+    command: &str,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+
+    let appid = match appid {
+        Some(id) => id,
+        None => {
+            env.exit
+                .exit_with_error("APPID is required for -c/--command mode", no_term);
+        }
+    };
+
+    let steam_app = match steam_apps
+        .iter()
+        .find(|app| app.appid == appid && app.is_windows_app())
+    {
+        Some(app) => app.clone(),
+        None => {
+            env.exit.exit_with_error(
+                "Steam app with the given app ID could not be found.",
+                no_term,
+            );
+        }
+    };
+    mount_app_prefix(env, &steam_app, no_term);
+
+    let proton_app = match find_proton_app(&steam_path, &steam_apps, appid) {
+        Some(app) => app,
+        None => {
+            env.exit
+                .exit_with_error("Proton installation could not be found!", no_term);
+        }
+    };
+
+    // Use built-in wine context to run the command
+    let prefix_path = steam_app.prefix_path.as_ref().unwrap();
+    let mut wine_ctx = crate::wine::WineContext::from_proton(&proton_app, prefix_path);
+    apply_override_args(env, &mut wine_ctx, parsed, no_term);
+    apply_scheduling_args(env, &mut wine_ctx, parsed, no_term);
+
+    let argv = command_argv(command, parsed.trailing()).unwrap_or_else(|e| {
+        env.exit
+            .exit_with_error(&format!("Invalid command: {}", e), no_term);
+    });
+    let argv: Vec<&str> = argv.iter().map(|a| a.as_str()).collect();
+
+    // Start background wineserver if requested
+    if parsed.get_flag("background_wineserver") {
+        if let Err(e) = wine_ctx.start_wineserver() {
+            eprintln!("Warning: Failed to start background wineserver: {}", e);
+        }
+    }
+
+    let app_dir = parsed
+        .get_flag("cwd_app")
+        .then_some(steam_app.install_path.as_path());
+    run_wine_command(env, &wine_ctx, &argv, app_dir, parsed, no_term)
+}
+
+fn run_prefix_command_mode(
+    env: &CliEnv,
+    prefix_path: &str,
+    command: &str,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    let prefix_path = PathBuf::from(prefix_path);
+
+    if !prefix_path.exists() {
+        env.exit.exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+
+    // Try to read saved Proton and arch info from prefix metadata
+    let metadata_path = prefix_path.join(".protontool");
+    let metadata_content = std::fs::read_to_string(&metadata_path).ok();
+
+    let proton_app = if let Some(ref metadata) = metadata_content {
+        let proton_name = metadata
+            .lines()
+            .find(|l| l.starts_with("proton_name="))
+            .and_then(|l| l.strip_prefix("proton_name="));
+
+        if let Some(name) = proton_name {
+            find_proton_by_name(&steam_apps, name)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    // Read saved architecture (default to win64)
+    let saved_arch = metadata_content
+        .as_ref()
+        .and_then(|m| m.lines().find(|l| l.starts_with("arch=")))
+        .and_then(|l| l.strip_prefix("arch="))
+        .and_then(crate::wine::WineArch::from_str)
+        .unwrap_or(crate::wine::WineArch::Win64);
+
+    // If no saved Proton or --proton flag specified, select one
+    let proton_app = if let Some(proton_name) = parsed.get_option("proton") {
+        match find_proton_by_name(&steam_apps, proton_name) {
+            Some(app) => app,
+            None => {
+                env.exit.exit_with_error(
+                    &format!("Proton version '{}' not found.", proton_name),
+                    no_term,
+                );
+            }
+        }
+    } else if let Some(app) = proton_app {
+        println!("Using saved Proton version: {}", app.name);
+        app
+    } else {
+        match select_proton_with_gui(&get_proton_apps(&steam_apps)) {
+            Some(app) => app,
+            None => {
+                env.exit
+                    .exit_with_error("No Proton version selected.", no_term);
+            }
+        }
+    };
+
+    if !proton_app.is_proton_ready {
+        env.exit
+            .exit_with_error("Proton installation is not ready.", no_term);
+    }
+
+    let mut wine_ctx =
+        crate::wine::WineContext::from_proton_with_arch(&proton_app, &prefix_path, saved_arch);
+    apply_override_args(env, &mut wine_ctx, parsed, no_term);
+    apply_scheduling_args(env, &mut wine_ctx, parsed, no_term);
+    let argv = command_argv(command, parsed.trailing()).unwrap_or_else(|e| {
+        env.exit
+            .exit_with_error(&format!("Invalid command: {}", e), no_term);
+    });
+    let argv: Vec<&str> = argv.iter().map(|a| a.as_str()).collect();
+
+    // Start background wineserver if requested
+    if parsed.get_flag("background_wineserver") {
+        if let Err(e) = wine_ctx.start_wineserver() {
+            eprintln!("Warning: Failed to start background wineserver: {}", e);
+        }
+    }
+
+    run_wine_command(env, &wine_ctx, &argv, None, parsed, no_term)
+}
+
+/// Create and initialize a custom prefix, saving its Proton and arch in the
+/// prefix metadata.
+fn create_prefix(
+    prefix_path: &Path,
+    proton_app: &crate::steam::ProtonApp,
+    arch: crate::wine::WineArch,
+) -> Result<(), String> {
+    // Create the prefix directory structure
+    println!("Creating Wine prefix at: {}", prefix_path.display());
+    println!("Using Proton: {}", proton_app.name);
+    println!("Architecture: {}", arch.as_str());
+
+    crate::wine::snapshot::create_prefix_dir(prefix_path)?;
+
+    // Initialize the prefix with Proton's wine
+    let wine_ctx = crate::wine::WineContext::from_proton_with_arch(proton_app, prefix_path, arch);
+    // Proton uses "files" subdirectory, older versions may use "dist"
+    let dist_dir = {
+        let files_dir = proton_app.install_path.join("files");
+        let dist_dir = proton_app.install_path.join("dist");
+        if files_dir.exists() {
+            files_dir
+        } else {
+            dist_dir
+        }
+    };
+
+    println!("Initializing prefix...");
+    crate::wine::prefix::init_prefix(prefix_path, &dist_dir, true, Some(&wine_ctx))
+        .map_err(|e| format!("Failed to initialize prefix: {}", e))?;
+
+    // Save prefix metadata for future use
+    save_prefix_metadata(prefix_path, proton_app, arch).ok();
+    Ok(())
+}
+
+/// Record the Proton and architecture of a new prefix in its metadata,
+/// keeping keys written during initialization (e.g. `provisioned`).
+fn save_prefix_metadata(
+    prefix_path: &Path,
+    proton_app: &crate::steam::ProtonApp,
+    arch: crate::wine::WineArch,
+) -> Result<(), String> {
+    let mut metadata = crate::wine::metadata::PrefixMetadata::load(prefix_path);
+    metadata.set("proton_name", &proton_app.name);
+    metadata.set(
+        "proton_path",
+        &proton_app.install_path.display().to_string(),
+    );
+    metadata.set("arch", arch.as_str());
+    metadata.set("created", &chrono_lite_now());
+    metadata.save(prefix_path)
+}
+
+fn run_create_prefix_mode(
+    env: &CliEnv,
+    prefix_path: &str,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    let proton_apps = get_proton_apps(&steam_apps);
+
+    if proton_apps.is_empty() {
+        env.exit.exit_with_error(
+            "No Proton installations found. Please install Proton through Steam first.",
+            no_term,
+        );
+    }
+
+    // Find Proton version - either from --proton flag or let user select
+    let proton_app = if let Some(proton_name) = parsed.get_option("proton") {
+        match find_proton_by_name(&steam_apps, proton_name) {
+            Some(app) => app,
+            None => {
+                eprintln!("Available Proton versions:");
+                for app in &proton_apps {
+                    eprintln!("  - {}", app.name);
+                }
+                env.exit.exit_with_error(
+                    &format!("Proton version '{}' not found.", proton_name),
+                    no_term,
+                );
+            }
+        }
+    } else {
+        match select_proton_with_gui(&proton_apps) {
+            Some(app) => app,
+            None => {
+                env.exit
+                    .exit_with_error("No Proton version selected.", no_term);
+            }
+        }
+    };
+
+    if !proton_app.is_proton_ready {
+        env.exit.exit_with_error(
+            "Selected Proton installation is not ready. Please launch a game with this Proton version first to initialize it.",
+            no_term
+        );
+    }
+
+    let prefix_path = PathBuf::from(prefix_path);
+
+    // Parse architecture option (default to win64)
+    let arch = parsed
+        .get_option("arch")
+        .and_then(|s| crate::wine::WineArch::from_str(s))
+        .unwrap_or(crate::wine::WineArch::Win64);
+
+    let sandboxed = match parsed.get_option("sandbox").map(parse_on_off) {
+        Some(Some(on)) => on,
+        Some(None) => env
+            .exit
+            .exit_with_error("--sandbox must be 'on' or 'off'", no_term),
+        None => false,
+    };
+
+    let user_dirs = parsed.get_option("user_dirs").map(|spec| {
+        crate::wine::userdirs::UserDirPolicy::parse(spec)
+            .unwrap_or_else(|e| env.exit.exit_with_error(&e, no_term))
+    });
+
+    if let Some(size) = parsed.get_option("image") {
+        let size = crate::wine::image::parse_size(size)
+            .unwrap_or_else(|e| env.exit.exit_with_error(&e, no_term));
+        if let Err(e) = crate::wine::image::create(&prefix_path, size) {
+            env.exit.exit_with_error(&e, no_term);
+        }
+        println!(
+            "Prefix image: {} ({})",
+            crate::wine::image::image_path(&prefix_path).display(),
+            crate::util::format_size(size)
+        );
+    }
+
+    if let Err(e) = create_prefix(&prefix_path, &proton_app, arch) {
+        env.exit.exit_with_error(&e, no_term);
+    }
+    if let Some(policy) = &user_dirs {
+        match crate::wine::userdirs::set_policy(&prefix_path, policy) {
+            Ok(report) => report_kept_user_dirs(&report),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        }
+    }
+    if sandboxed {
+        if let Err(e) = crate::wine::sandbox::set_sandboxed(&prefix_path, true) {
+            env.exit.exit_with_error(&e, no_term);
+        }
+        println!("Prefix is sandboxed: no links into your home directory and no Z: drive.");
+    }
+
+    println!("\nPrefix created successfully!");
+    println!("\nTo use this prefix:");
+    println!("  protontool --prefix '{}' <verbs>", prefix_path.display());
+    println!(
+        "  protontool --prefix '{}' -c <command>",
+        prefix_path.display()
+    );
+}
+
+/// Run a Windows program in the default prefix, creating it on first use.
+fn run_default_prefix_mode(
+    env: &CliEnv,
+    program: &str,
+    program_args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    let program_path = PathBuf::from(program);
+    if !program_path.is_file() {
+        env.exit
+            .exit_with_error(&format!("Program not found: {}", program), no_term);
+    }
+    let program_path = program_path.canonicalize().unwrap_or(program_path);
+
+    if parsed.get_flag("confirm") {
+        let text = format!(
+            "{}\n\nWindows programs can access your files. Only run programs from sources you trust.",
+            program_path.display()
+        );
+        if !confirm(
+            "Run Windows Program",
+            &text,
+            "Run this program with Wine?",
+            no_term,
+        ) {
+            return;
+        }
+    }
+
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+
+    let prefix_path = crate::config::get_default_prefix_path();
+    let metadata = std::fs::read_to_string(prefix_path.join(".protontool")).unwrap_or_default();
+    let metadata_value = |key: &str| {
+        metadata
+            .lines()
+            .find_map(|l| l.strip_prefix(key)?.strip_prefix('='))
+    };
+
+    let proton_app = match parsed
+        .get_option("proton")
+        .or(metadata_value("proton_name"))
+    {
+        Some(name) => find_proton_by_name(&steam_apps, name),
+        None => crate::steam::find_default_proton(&steam_apps),
+    };
+    let proton_app = match proton_app {
+        Some(app) if app.is_proton_ready => app,
+        Some(app) => env
+            .exit
+            .exit_with_error(&format!("{} is not ready.", app.name), no_term),
+        None => env.exit.exit_with_error(
+            "No Proton installations found. Please install Proton through Steam first.",
+            no_term,
+        ),
+    };
+    let arch = metadata_value("arch")
+        .and_then(crate::wine::WineArch::from_str)
+        .unwrap_or(crate::wine::WineArch::Win64);
+
+    if !prefix_path.join(".protontool").exists() {
+        if let Err(e) = create_prefix(&prefix_path, &proton_app, arch) {
+            env.exit.exit_with_error(&e, no_term);
+        }
+    }
+
+    if crate::wine::sandbox::is_sandboxed(&prefix_path) {
+        if let Some(warning) = crate::wine::sandbox::program_warning(&prefix_path, &program_path) {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+    if let Some(warning) = crate::controller::steam_input_warning(None, None) {
+        eprintln!("Warning: {}", warning);
+    }
+
+    let mut wine_ctx =
+        crate::wine::WineContext::from_proton_with_arch(&proton_app, &prefix_path, arch);
+    apply_override_args(env, &mut wine_ctx, parsed, no_term);
+    apply_scheduling_args(env, &mut wine_ctx, parsed, no_term);
+    let is_msi = program_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("msi"));
+    let program_str = program_path.to_string_lossy().to_string();
+    let mut wine_args: Vec<&str> = if is_msi {
+        vec!["msiexec", "/i", &program_str]
+    } else {
+        vec![&program_str]
+    };
+    wine_args.extend(program_args.iter().map(|a| a.as_str()));
+    let cwd = program_path.parent().unwrap_or(&prefix_path);
+
+    match wine_ctx.run_wine_cwd(&wine_args, cwd) {
+        Ok(output) => {
+            if !output.stdout.is_empty() {
+                println!("{}", String::from_utf8_lossy(&output.stdout));
+            }
+            if !output.stderr.is_empty() {
+                eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            }
+            env.exit.exit(output.status.code().unwrap_or(0));
+        }
+        Err(e) => {
+            env.exit
+                .exit_with_error(&format!("Failed to run {}: {}", program, e), no_term);
+        }
+    }
+}
+
+/// Per-run DLL overrides from `--override DLL[,DLL]=MODE` arguments.
+fn override_args(env: &CliEnv, parsed: &util::ParsedArgs, no_term: bool) -> Vec<(String, String)> {
+    let mut overrides = Vec::new();
+    for arg in parsed.get_multi_option("override") {
+        let parsed_overrides = crate::wine::parse_dll_overrides(arg);
+        if !arg.contains('=') || parsed_overrides.is_empty() {
+            env.exit.exit_with_error(
+                &format!(
+                    "--override expects DLL=MODE (e.g. d3d11=n,b), got '{}'",
+                    arg
+                ),
+                no_term,
+            );
+        }
+        for (dll, mode) in parsed_overrides {
+            if !crate::wine::overrides::is_valid_mode(&mode) {
+                env.exit.exit_with_error(
+                    &format!(
+                        "Invalid override mode '{}' for {}: use n, b, n,b, b,n or d",
+                        mode, dll
+                    ),
+                    no_term,
+                );
+            }
+            overrides.push((dll, mode));
+        }
+    }
+    overrides
+}
+
+/// Apply `--override` arguments to a Wine context for this run only.
+fn apply_override_args(
+    env: &CliEnv,
+    wine_ctx: &mut crate::wine::WineContext,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    for (dll, mode) in override_args(env, parsed, no_term) {
+        wine_ctx.set_dll_override(&dll, &mode);
+    }
+}
+
+/// Save `--nice`, `--cpu-affinity`, `--gamemode`, `--memory-limit` and
+/// `--large-address-aware` in the prefix's environment profile, where later
+/// runs pick them up.
+fn save_scheduling_args(
+    env: &CliEnv,
+    prefix_path: &Path,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::scheduling::{
+        self, CPU_AFFINITY_VAR, GAMEMODE_VAR, LARGE_ADDRESS_AWARE_VAR, MEMORY_MAX_VAR, NICE_VAR,
+    };
+
+    let mut changes: Vec<(&str, Option<String>)> = Vec::new();
+    if let Some(value) = parsed.get_option("nice") {
+        let nice =
+            scheduling::parse_nice(value).unwrap_or_else(|e| env.exit.exit_with_error(&e, no_term));
+        changes.push((NICE_VAR, (nice != 0).then(|| nice.to_string())));
+    }
+    if let Some(value) = parsed.get_option("cpu_affinity") {
+        let cpus = if value == "all" {
+            None
+        } else {
+            Some(
+                scheduling::parse_cpu_list(value)
+                    .unwrap_or_else(|e| env.exit.exit_with_error(&e, no_term)),
+            )
+        };
+        changes.push((CPU_AFFINITY_VAR, cpus));
+    }
+    if let Some(value) = parsed.get_option("gamemode") {
+        match parse_on_off(value) {
+            Some(on) => changes.push((GAMEMODE_VAR, on.then(|| "1".to_string()))),
+            None => env
+                .exit
+                .exit_with_error("--gamemode must be 'on' or 'off'", no_term),
+        }
+    }
+    if let Some(value) = parsed.get_option("memory_limit") {
+        let limit = if value == "off" {
+            None
+        } else {
+            Some(
+                scheduling::parse_memory_size(value)
+                    .unwrap_or_else(|e| env.exit.exit_with_error(&e, no_term)),
+            )
+        };
+        changes.push((MEMORY_MAX_VAR, limit));
+    }
+    if let Some(value) = parsed.get_option("large_address_aware") {
+        match parse_on_off(value) {
+            Some(on) => changes.push((LARGE_ADDRESS_AWARE_VAR, on.then(|| "1".to_string()))),
+            None => env
+                .exit
+                .exit_with_error("--large-address-aware must be 'on' or 'off'", no_term),
+        }
+    }
+    for (name, value) in changes {
+        if let Err(e) = crate::journal::set_profile_var(prefix_path, name, value.as_deref()) {
+            env.exit.exit_with_error(&e, no_term);
+        }
+    }
+}
+
+fn warn_missing_scheduling_tools(scheduling: &crate::wine::scheduling::Scheduling) {
+    let needed = [
+        (
+            scheduling.gamemode,
+            "gamemoderun",
+            "GameMode is not requested",
+        ),
+        (
+            scheduling.cpu_affinity.is_some(),
+            "taskset",
+            "the CPU affinity is not applied",
+        ),
+        (
+            scheduling.memory_max.is_some(),
+            "systemd-run",
+            "the memory limit is not applied",
+        ),
+    ];
+    for (used, tool, effect) in needed {
+        if used && crate::util::which(tool).is_none() {
+            eprintln!("Warning: {} is not installed; {}.", tool, effect);
+        }
+    }
+}
+
+/// Save the scheduling options for the prefix and apply its settings to
+/// the programs run through `wine_ctx`.
+fn apply_scheduling_args(
+    env: &CliEnv,
+    wine_ctx: &mut crate::wine::WineContext,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    save_scheduling_args(env, &wine_ctx.prefix_path, parsed, no_term);
+    wine_ctx.scheduling = crate::wine::scheduling::Scheduling::from_profile(
+        &crate::wine::profile::EnvProfile::load(&wine_ctx.prefix_path),
+    );
+    warn_missing_scheduling_tools(&wine_ctx.scheduling);
+}
+
+/// Parse an on/off switch value.
+fn parse_on_off(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "on" | "true" | "yes" | "1" => Some(true),
+        "off" | "false" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn run_sandbox_mode(prefix_path: &str, value: &str, no_term: bool) {
+    let prefix_path = PathBuf::from(prefix_path);
+    if !prefix_path.exists() {
+        exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+    let sandboxed = match parse_on_off(value) {
+        Some(on) => on,
+        None => exit_with_error("--sandbox must be 'on' or 'off'", no_term),
+    };
+
+    if let Err(e) = crate::wine::sandbox::set_sandboxed(&prefix_path, sandboxed) {
+        exit_with_error(&e, no_term);
+    }
+    if sandboxed {
+        println!(
+            "Sandboxed {}: removed links into your home directory and the Z: drive.",
+            prefix_path.display()
+        );
+    } else {
+        println!(
+            "Sandbox disabled for {}: restored the Z: drive and links to empty user folders.",
+            prefix_path.display()
+        );
+    }
+}
+
+fn report_kept_user_dirs(report: &crate::wine::userdirs::ApplyReport) {
+    for path in &report.kept {
+        eprintln!(
+            "Warning: {} contains files and was not linked; move them out and run again.",
+            path.display()
+        );
+    }
+}
+
+fn run_user_dirs_mode(prefix_path: &str, value: &str, no_term: bool) {
+    use crate::wine::userdirs::{self, UserDirPolicy};
+
+    let prefix_path = PathBuf::from(prefix_path);
+    if !prefix_path.exists() {
+        exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+    let policy = UserDirPolicy::parse(value).unwrap_or_else(|e| exit_with_error(&e, no_term));
+
+    // A sandboxed prefix keeps its folders unlinked; the policy applies
+    // when the sandbox is turned off
+    if crate::wine::sandbox::is_sandboxed(&prefix_path) {
+        if let Err(e) = userdirs::record_policy(&prefix_path, &policy) {
+            exit_with_error(&e, no_term);
+        }
+        println!(
+            "Saved user folders '{}' for {}; they are linked when the sandbox is turned off.",
+            policy.to_spec(),
+            prefix_path.display()
+        );
+        return;
+    }
+
+    let report = match userdirs::set_policy(&prefix_path, &policy) {
+        Ok(report) => report,
+        Err(e) => exit_with_error(&e, no_term),
+    };
+    report_kept_user_dirs(&report);
+    println!(
+        "User folders of {} set to '{}' ({} changed).",
+        prefix_path.display(),
+        policy.to_spec(),
+        report.changed
+    );
+}
+
+/// Mount a custom prefix's disk image if it has one and it isn't mounted.
+fn mount_prefix_image(env: &CliEnv, prefix_path: &Path, no_term: bool) {
+    if crate::wine::image::has_image(prefix_path) {
+        if let Err(e) = crate::wine::image::mount(prefix_path) {
+            env.exit.exit_with_error(&e, no_term);
+        }
+    }
+}
+
+/// Mount the disk image of a game's prefix before a command works on it.
+fn mount_app_prefix(env: &CliEnv, steam_app: &SteamApp, no_term: bool) {
+    if let Some(prefix_path) = steam_app.prefix_path.as_deref() {
+        mount_prefix_image(env, prefix_path, no_term);
+    }
+}
+
+/// `--prefix P --image mount|unmount|status|SIZE`.
+fn run_prefix_image_mode(prefix_path: &Path, value: &str, no_term: bool) {
+    use crate::util::format_size;
+    use crate::wine::image;
+
+    let result = match value {
+        "mount" => image::mount(prefix_path).map(|_| println!("Mounted {}", prefix_path.display())),
+        "unmount" => {
+            image::unmount(prefix_path).map(|_| println!("Unmounted {}", prefix_path.display()))
+        }
+        "status" => image::status(prefix_path).map(|status| {
+            println!("Image: {}", status.image.display());
+            println!(
+                "Size: {} ({} used on disk)",
+                format_size(status.size),
+                format_size(status.allocated)
+            );
+            match status.mount_type {
+                Some(fs_type) => println!("Mounted on {} ({})", prefix_path.display(), fs_type),
+                None => println!("Not mounted"),
+            }
+        }),
+        size => image::parse_size(size)
+            .map_err(|_| {
+                format!(
+                    "--image must be mount, unmount, status or a size, got '{}'",
+                    size
+                )
+            })
+            .and_then(|bytes| image::resize(prefix_path, bytes).map(|_| bytes))
+            .map(|bytes| {
+                println!(
+                    "Resized {} to {}",
+                    prefix_path.display(),
+                    format_size(bytes)
+                )
+            }),
+    };
+    if let Err(e) = result {
+        exit_with_error(&e, no_term);
+    }
+}
+
+fn run_z_drive_mode(prefix_path: &str, value: &str, no_term: bool) {
+    use crate::wine::drives;
+
+    let prefix_path = PathBuf::from(prefix_path);
+    if !prefix_path.exists() {
+        exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+
+    let target = match parse_on_off(value) {
+        Some(false) => None,
+        Some(true) => Some(PathBuf::from("/")),
+        None => {
+            let dir = PathBuf::from(crate::wine::custom::expand_path(value));
+            if !dir.is_dir() {
+                exit_with_error(
+                    &format!("--z-drive must be on, off or a directory, got '{}'", value),
+                    no_term,
+                );
+            }
+            Some(dir.canonicalize().unwrap_or(dir))
+        }
+    };
+
+    let result = match &target {
+        Some(dir) => drives::map_drive(&prefix_path, 'z', dir),
+        None => drives::unmap_drive(&prefix_path, 'z').map(|_| ()),
+    };
+    if let Err(e) = result {
+        exit_with_error(&e, no_term);
+    }
+    match &target {
+        Some(dir) => println!("Z: now maps to {}", dir.display()),
+        None => println!("Removed the Z: drive from {}", prefix_path.display()),
+    }
+    if target.is_some() && crate::wine::sandbox::is_sandboxed(&prefix_path) {
+        eprintln!("Warning: the prefix is sandboxed; the Z: drive exposes host files to it.");
+    }
+}
+
+/// Re-create mod directory mounts a reset prefix has lost.
+fn restore_mounts(prefix_path: &Path) {
+    match crate::wine::mounts::restore(prefix_path) {
+        Ok(restored) => {
+            for mount in restored {
+                println!(
+                    "Restored mount {} -> {}",
+                    mount.windows_path,
+                    mount.host.display()
+                );
+            }
+        }
+        Err(e) => eprintln!("Warning: Failed to restore mounts: {}", e),
+    }
+}
+
+fn run_mount_mode(prefix_path: &Path, spec: &str, no_term: bool) {
+    use crate::wine::mounts;
+
+    if !prefix_path.exists() {
+        exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+
+    match spec {
+        "list" => {
+            let all = mounts::mounts(prefix_path);
+            if all.is_empty() {
+                println!("No mounts in {}", prefix_path.display());
+            }
+            for mount in all {
+                let state = if !mount.host.is_dir() {
+                    " (directory missing)"
+                } else if !mounts::is_linked(prefix_path, &mount) {
+                    " (not linked, run --mount restore)"
+                } else {
+                    ""
+                };
+                println!(
+                    "{:<24} {}{}",
+                    mount.windows_path,
+                    mount.host.display(),
+                    state
+                );
+            }
+        }
+        "restore" => match mounts::restore(prefix_path) {
+            Ok(restored) => println!("Restored {} mount(s)", restored.len()),
+            Err(e) => exit_with_error(&e, no_term),
+        },
+        _ => {
+            let Some((windows_path, value)) = spec.split_once('=') else {
+                exit_with_error(
+                    &format!(
+                        "--mount must be WINPATH=DIR, WINPATH=off, list or restore, got '{}'",
+                        spec
+                    ),
+                    no_term,
+                );
+            };
+            if parse_on_off(value) == Some(false) {
+                match mounts::unmount(prefix_path, windows_path) {
+                    Ok(true) => println!("Removed the mount at {}", windows_path),
+                    Ok(false) => println!("Nothing is mounted at {}", windows_path),
+                    Err(e) => exit_with_error(&e, no_term),
+                }
+                return;
+            }
+            let host = PathBuf::from(crate::wine::custom::expand_path(value));
+            match mounts::mount(prefix_path, windows_path, &host) {
+                Ok(mount) => println!("{} now shows {}", mount.windows_path, mount.host.display()),
+                Err(e) => exit_with_error(&e, no_term),
+            }
+            if crate::wine::sandbox::is_sandboxed(prefix_path) {
+                eprintln!("Warning: the prefix is sandboxed; the mount exposes host files to it.");
+            }
+        }
+    }
+}
+
+/// Print what cleaning up a prefix freed (or would free).
+fn print_cleanup_report(
+    prefix_path: &Path,
+    report: &crate::wine::cleanup::CleanupReport,
+    verb: &str,
+) {
+    for category in &report.categories {
+        println!(
+            "{}: {} files, {}",
+            category.name,
+            category.files,
+            crate::util::format_size(category.bytes)
+        );
+    }
+    println!(
+        "{} {} in {}",
+        verb,
+        crate::util::format_size(report.total_bytes()),
+        prefix_path.display()
+    );
+
+    let usage = crate::wine::usage::usage(prefix_path);
+    let now = crate::wine::usage::now_secs();
+    if usage.is_stale(now) {
+        println!(
+            "This prefix was last used {}; consider deleting it if you no longer need it.",
+            crate::wine::usage::describe_age(usage.age(now))
+        );
+    }
+}
+
+/// Report what cleaning up a prefix would free (`--cleanup --dry-run`).
+fn run_cleanup_dry_run(prefix_path: &Path) {
+    let report = crate::wine::cleanup::cleanup_prefix(prefix_path, true);
+    print_cleanup_report(prefix_path, &report, "Would free");
+}
+
+/// Delete the temp files and crash artifacts of a prefix after showing
+/// what would go and asking.
+fn run_cleanup_mode(env: &CliEnv, wine_ctx: &crate::wine::WineContext, no_term: bool) {
+    let prefix_path = &wine_ctx.prefix_path;
+    let preview = crate::wine::cleanup::cleanup_prefix(prefix_path, true);
+    if preview.total_files() == 0 {
+        println!("Nothing to clean up in {}", prefix_path.display());
+        return;
+    }
+    let categories: Vec<String> = preview
+        .categories
+        .iter()
+        .filter(|c| c.files > 0)
+        .map(|c| {
+            format!(
+                "  {}: {} files, {}",
+                c.name,
+                c.files,
+                crate::util::format_size(c.bytes)
+            )
+        })
+        .collect();
+    let text = format!(
+        "Cleaning up {} deletes {} files:\n\n{}",
+        prefix_path.display(),
+        preview.total_files(),
+        categories.join("\n")
+    );
+    let question = format!("Free {}?", crate::util::format_size(preview.total_bytes()));
+    if !confirm("Clean Up Prefix", &text, &question, no_term)
+        || !check_prefix_before_changes(wine_ctx, no_term)
+    {
+        env.exit.exit(1);
+    }
+
+    let report = crate::wine::cleanup::cleanup_prefix(prefix_path, false);
+    print_cleanup_report(prefix_path, &report, "Freed");
+}
+
+/// Give the files of a prefix owned by another user back to the user the
+/// prefix belongs to.
+fn run_fix_ownership_mode(prefix_path: &Path, parsed: &util::ParsedArgs, no_term: bool) {
+    use crate::wine::ownership;
+
+    if !prefix_path.exists() {
+        exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+    let Some(owner) = ownership::prefix_owner(prefix_path) else {
+        exit_with_error(
+            &format!(
+                "Can't tell which user {} belongs to. Run protontool with sudo from that user's session.",
+                prefix_path.display()
+            ),
+            no_term,
+        );
+    };
+
+    if let Err(e) = ownership::check_prefix(prefix_path) {
+        exit_with_error(&e, no_term);
+    }
+
+    let paths = ownership::foreign_owned(prefix_path, owner.uid);
+    if parsed.get_flag("dry_run") {
+        for path in &paths {
+            println!("{}", path.display());
+        }
+        println!(
+            "Would give {} files in {} to {}",
+            paths.len(),
+            prefix_path.display(),
+            owner.describe()
+        );
+        return;
+    }
+    if !paths.is_empty()
+        && !confirm(
+            "Fix Ownership",
+            &format!(
+                "{} files in {} are owned by another user.",
+                paths.len(),
+                prefix_path.display()
+            ),
+            &format!("Give them to {}?", owner.describe()),
+            no_term,
+        )
+    {
+        return;
+    }
+    match ownership::fix_ownership(prefix_path, &owner) {
+        Ok(paths) if paths.is_empty() => println!(
+            "All files in {} already belong to {}",
+            prefix_path.display(),
+            owner.describe()
+        ),
+        Ok(paths) => println!(
+            "Gave {} files in {} to {}",
+            paths.len(),
+            prefix_path.display(),
+            owner.describe()
+        ),
+        Err(e) if !ownership::is_root() => exit_with_error(
+            &format!(
+                "{}\nFiles owned by root can only be given back as root: sudo protontool --prefix {} --fix-ownership",
+                e,
+                crate::util::shell_quote(&prefix_path.to_string_lossy())
+            ),
+            no_term,
+        ),
+        Err(e) => exit_with_error(&e, no_term),
+    }
+}
+
+fn run_cleanup_app_mode(env: &CliEnv, appid: u32, parsed: &util::ParsedArgs, no_term: bool) {
+    if parsed.get_flag("dry_run") {
+        let (_, prefix_path) = find_steam_app_prefix(env, appid, parsed, no_term);
+        run_cleanup_dry_run(&prefix_path);
+    } else {
+        run_cleanup_mode(env, &app_wine_context(env, appid, parsed, no_term), no_term);
+    }
+}
+
+/// Initialize a game's prefix ahead of its first launch.
+fn run_prewarm_mode(env: &CliEnv, appid: u32, parsed: &util::ParsedArgs, no_term: bool) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    let steam_app = match steam_apps
+        .iter()
+        .find(|app| app.appid == appid && !app.is_proton)
+    {
+        Some(app) => app,
+        None => env.exit.exit_with_error(
+            "Steam app with the given app ID could not be found. Is it installed?",
+            no_term,
+        ),
+    };
+    mount_app_prefix(env, steam_app, no_term);
+    let proton_app = match find_proton_app(&steam_path, &steam_apps, appid) {
+        Some(app) if app.is_proton_ready => app,
+        _ => env
+            .exit
+            .exit_with_error("Proton installation could not be found!", no_term),
+    };
+
+    println!(
+        "Prewarming {} ({}) with {}",
+        steam_app.name, appid, proton_app.name
+    );
+    let result = match crate::prewarm::prewarm(&steam_path, steam_app, &proton_app) {
+        Ok(result) => result,
+        Err(e) => env.exit.exit_with_error(&e, no_term),
+    };
+    println!(
+        "{} {}",
+        if result.created {
+            "Created prefix"
+        } else {
+            "Updated prefix"
+        },
+        result.prefix_path.display()
+    );
+    if result.provisioned {
+        println!("Applied the [provision] baseline from config.toml");
+    }
+    if let Some(shader_cache) = &result.shader_cache {
+        println!("Shader cache: {}", shader_cache.display());
+    }
+}
+
+/// Write protontool's metadata into a prefix made by another tool, so it is
+/// used and listed like a custom prefix.
+fn run_adopt_prefix_mode(
+    env: &CliEnv,
+    prefix_path: &str,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::wine::adopt;
+
+    let prefix_path = std::fs::canonicalize(prefix_path).unwrap_or_else(|e| {
+        env.exit
+            .exit_with_error(&format!("Can't open {}: {}", prefix_path, e), no_term)
+    });
+    let mut info =
+        adopt::inspect(&prefix_path).unwrap_or_else(|e| env.exit.exit_with_error(&e, no_term));
+    if let Some(arch) = parsed.get_option("arch") {
+        info.arch = crate::wine::WineArch::from_str(arch).unwrap_or_else(|| {
+            env.exit
+                .exit_with_error("--arch must be 'win32' or 'win64'", no_term)
+        });
+    }
+    let proton_app = parsed.get_option("proton").map(|proton_name| {
+        let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+        let Some((steam_path, steam_root, steam_lib_paths)) =
+            get_steam_context(env, no_term, &extra_libs)
+        else {
+            env.exit
+                .exit_with_error("No Steam installation was selected.", no_term);
+        };
+        let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+        find_proton_by_name(&steam_apps, proton_name).unwrap_or_else(|| {
+            env.exit.exit_with_error(
+                &format!("Proton version '{}' not found.", proton_name),
+                no_term,
+            )
+        })
+    });
+
+    if let Err(e) = adopt::adopt(&prefix_path, &info, proton_app.as_ref()) {
+        env.exit.exit_with_error(&e, no_term);
+    }
+    println!("Adopted {}", prefix_path.display());
+    println!("  Created by:      {}", info.origin);
+    println!("  Architecture:    {}", info.arch.as_str());
+    println!(
+        "  Windows version: {}",
+        info.windows_version.as_deref().unwrap_or("unknown")
+    );
+    if let Some(proton_app) = &proton_app {
+        println!("  Proton:          {}", proton_app.name);
+    }
+    println!(
+        "Proton updates the prefix to its Wine version the first time it runs there. \
+         To keep a way back, snapshot it first:\n  protontool --prefix {} snapshot create",
+        crate::util::shell_quote(&prefix_path.to_string_lossy())
+    );
+}
+
+/// Delete a custom prefix: its directory, or its disk image and mount point.
+fn delete_prefix_files(prefix_path: &Path) -> Result<(), String> {
+    if crate::wine::image::has_image(prefix_path) {
+        crate::wine::image::delete(prefix_path)
+    } else {
+        std::fs::remove_dir_all(prefix_path).map_err(|e| e.to_string())
+    }
+}
+
+fn run_delete_prefix_mode(prefix_path: &str, no_term: bool) {
+    let prefix_path = PathBuf::from(prefix_path);
+
+    if !prefix_path.exists() {
+        exit_with_error(
+            &format!("Prefix path does not exist: {}", prefix_path.display()),
+            no_term,
+        );
+    }
+
+    let prefix_name = prefix_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Unknown");
+
+    let text = format!(
+        "This will permanently remove the prefix '{}':\n{}",
+        prefix_name,
+        prefix_path.display()
+    );
+    if !confirm("Confirm Delete", &text, "Delete this prefix?", no_term) {
+        println!("Deletion cancelled.");
+        return;
+    }
+
+    // Delete the prefix directory
+    match delete_prefix_files(&prefix_path) {
+        Ok(()) => {
+            println!("Prefix '{}' deleted successfully.", prefix_name);
+        }
+        Err(e) => {
+            exit_with_error(&format!("Failed to delete prefix: {}", e), no_term);
+        }
+    }
+}
+
+fn run_import_protonfixes_mode(source: &str, no_term: bool) {
+    let source_path = PathBuf::from(source);
+    if !source_path.exists() {
+        exit_with_error(&format!("Path does not exist: {}", source), no_term);
+    }
+
+    let fixes = match crate::wine::protonfixes::import_path(&source_path) {
+        Ok(fixes) => fixes,
+        Err(e) => exit_with_error(&e, no_term),
+    };
+
+    let verbs_dir = crate::config::get_verbs_dir();
+    let source_name = source_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| source.to_string());
+
+    let written =
+        match crate::wine::protonfixes::write_gamefix_verbs(&fixes, &verbs_dir, &source_name) {
+            Ok(n) => n,
+            Err(e) => exit_with_error(&e, no_term),
+        };
+
+    for fix in &fixes {
+        if fix.is_empty() {
+            println!("Skipped {} ({}): nothing to import", fix.id, fix.title);
+        } else {
+            println!(
+                "Imported {} ({}) as '{}'",
+                fix.id,
+                fix.title,
+                fix.verb_name()
+            );
+        }
+        for call in &fix.skipped {
+            println!("  not imported: {}", call);
+        }
+    }
+
+    println!(
+        "\n{} gamefix verb(s) written to {}",
+        written,
+        verbs_dir.display()
+    );
+}
+
+/// Revert the most recent journaled change.
+/// Show a game's Steam launch options, merge a template into them (`add`)
+/// or replace them (`set`).
+fn run_launch_options_mode(
+    env: &CliEnv,
+    appid: u32,
+    args: &[String],
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) {
+    use crate::launch_options;
+
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let Some((steam_path, _, _)) = get_steam_context(env, no_term, &extra_libs) else {
+        env.exit
+            .exit_with_error("No Steam installation was selected.", no_term);
+    };
+    let (command, rest) = args
+        .split_first()
+        .map_or(("show", &[][..]), |(command, rest)| {
+            (command.as_str(), rest)
+        });
+    let result = match (command, rest) {
+        ("show", []) => {
+            let configs = launch_options::user_configs(&steam_path, appid);
+            if configs.is_empty() {
+                println!("No Steam user has launch settings for {} yet.", appid);
+            }
+            for (config, options) in configs {
+                let user = config
+                    .ancestors()
+                    .nth(2)
+                    .and_then(|dir| dir.file_name())
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let options = if options.is_empty() {
+                    "(none)"
+                } else {
+                    &options
+                };
+                println!("User {}: {}", user, options);
+            }
+            return;
+        }
+        ("add", rest) if !rest.is_empty() => {
+            launch_options::add(&steam_path, appid, &rest.join(" "))
+        }
+        ("set", rest) => {
+            let options = rest.join(" ");
+            launch_options::update(
+                &steam_path,
+                appid,
+                &format!("Set launch options of {} to {}", appid, options),
+                |_| Ok(options.clone()),
+            )
+        }
+        _ => env.exit.exit_with_error(
+            "Usage: launch-options [show|add OPTIONS|set OPTIONS]",
+            no_term,
+        ),
+    };
+    match result {
+        Ok(updated) if updated.is_empty() => println!("The launch options already match."),
+        Ok(updated) => {
+            for options in updated {
+                println!("Launch options: {}", options);
+            }
+            println!("Use 'protontool undo' to restore the previous launch options.");
+        }
+        Err(e) => env.exit.exit_with_error(&e, no_term),
+    }
+}
+
+/// Show, store or clear the `WINEDEBUG` spec of a prefix.
+fn run_winedebug_command(prefix_path: &Path, args: &[String], no_term: bool) {
+    use crate::wine::winedebug;
+
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    let result = match args.as_slice() {
+        [] | ["show"] => {
+            match winedebug::stored(prefix_path) {
+                Some(spec) => println!("WINEDEBUG={}", spec),
+                None => println!("No WINEDEBUG spec stored for {}", prefix_path.display()),
+            }
+            if let Ok(exported) = std::env::var("WINEDEBUG") {
+                println!("(WINEDEBUG={} is exported and wins over it)", exported);
+            }
+            Ok(())
+        }
+        ["set", spec @ ..] if !spec.is_empty() => {
+            winedebug::store(prefix_path, Some(&spec.join(","))).map(|_| {
+                let stored = winedebug::stored(prefix_path).unwrap_or_default();
+                println!("Stored WINEDEBUG={}", stored)
+            })
+        }
+        ["clear"] => {
+            winedebug::store(prefix_path, None).map(|_| println!("Cleared the WINEDEBUG spec"))
+        }
+        _ => exit_with_error("Usage: winedebug [show|set SPEC|clear]", no_term),
+    };
+    if let Err(e) = result {
+        exit_with_error(&e, no_term);
+    }
+}
+
+/// Show or edit the notes and tags of a game or custom prefix.
+fn run_notes_command(subject: &crate::notes::Subject, args: &[String], no_term: bool) {
+    use crate::notes::{self, parse_tags};
+
+    let mut current = notes::get(subject);
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    match args.as_slice() {
+        [] | ["show"] => {
+            if current.is_empty() {
+                println!("No notes.");
+            }
+            if !current.tags.is_empty() {
+                println!("Tags: {}", current.tags.join(", "));
+            }
+            if !current.note.is_empty() {
+                println!("{}", current.note);
+            }
+            return;
+        }
+        ["set", text @ ..] if !text.is_empty() => current.note = text.join(" "),
+        ["tag", tags @ ..] if !tags.is_empty() => current.add_tags(&parse_tags(&tags.join(","))),
+        ["untag", tags @ ..] if !tags.is_empty() => {
+            let removed = parse_tags(&tags.join(","));
+            current.tags.retain(|tag| !removed.contains(tag));
+        }
+        ["clear"] => current = notes::Notes::default(),
+        _ => exit_with_error(
+            "Usage: notes [show|set TEXT|tag TAG...|untag TAG...|clear]",
+            no_term,
+        ),
+    }
+    if let Err(e) = notes::set(subject, &current) {
+        exit_with_error(&e, no_term);
+    }
+    match current.summary() {
+        summary if summary.is_empty() => println!("Cleared the notes"),
+        summary => println!("Notes: {}", summary),
+    }
+}
+
+/// Print the build details, as JSON with `--json`.
+fn run_version_mode(parsed: &util::ParsedArgs) {
+    let info = crate::version::BuildInfo::collect();
+    if parsed.get_flag("json") {
+        println!("{}", info.to_json());
+    } else {
+        print!("{}", info.describe());
+    }
+}
+
+fn run_undo_mode(no_term: bool) {
+    match crate::journal::undo_last() {
+        Ok(Some(entry)) => {
+            println!("Reverted: {}", entry.description);
+            for change in &entry.changes {
+                println!("  restored {}", change.describe());
+            }
+        }
+        Ok(None) => println!("Nothing to undo."),
+        Err(e) => exit_with_error(&e, no_term),
+    }
+}
+
+/// Export protontool's setup to a migration archive, or import one.
+fn run_migrate_mode(env: &CliEnv, args: &[String], parsed: &util::ParsedArgs, no_term: bool) {
+    use crate::migrate;
+    use crate::recipe::Recipe;
+
+    let (command, file) = match args {
+        [command, file] if command == "export" || command == "import" => {
+            (command.as_str(), Path::new(file))
+        }
+        _ => env.exit.exit_with_error(
+            "Usage: protontool migrate export FILE.tar.zst [--with-prefixes] | migrate import FILE",
+            no_term,
+        ),
+    };
+
+    if command == "export" {
+        let recipes = migration_recipes(env, parsed, no_term);
+        match migrate::export(file, &recipes, parsed.get_flag("with_prefixes")) {
+            Ok(manifest) => println!("{}\n\nWrote {}", manifest.describe(), file.display()),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        }
+        return;
+    }
+
+    let manifest = match migrate::read_manifest(file) {
+        Ok(manifest) => manifest,
+        Err(e) => env.exit.exit_with_error(&e, no_term),
+    };
+    let mut text = manifest.describe();
+    let existing = migrate::existing_entries(&manifest);
+    if !existing.is_empty() {
+        text.push_str("\n\nFiles the archive also has are overwritten in:");
+        for path in &existing {
+            text.push_str(&format!("\n  {}", path.display()));
+        }
+    }
+    if !confirm(
+        "Import protontool Setup",
+        &text,
+        "Import this archive?",
+        no_term,
+    ) {
+        return;
+    }
+    if let Err(e) = migrate::import(file, &manifest) {
+        env.exit.exit_with_error(&e, no_term);
+    }
+    println!("Imported {}", file.display());
+
+    let recipes_dir = crate::config::get_base_dir().join(migrate::RECIPES_DIR);
+    let mut recipe_files: Vec<PathBuf> = std::fs::read_dir(&recipes_dir)
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    recipe_files.sort();
+    if !recipe_files.is_empty() {
+        println!("\nOnce a game is installed and has been launched, set it up again with:");
+    }
+    for path in recipe_files {
+        if let Ok(recipe) = Recipe::load(&path) {
+            println!(
+                "$ protontool recipe apply {}   # {}",
+                crate::util::shell_quote(&path.to_string_lossy()),
+                recipe.game
+            );
+        }
+    }
+}
+
+/// Recipes of the Steam games protontool set up, for `migrate export`.
+/// Without Steam there are none.
+fn migration_recipes(
+    env: &CliEnv,
+    parsed: &util::ParsedArgs,
+    no_term: bool,
+) -> Vec<crate::recipe::Recipe> {
+    use crate::{migrate, recipe};
+
+    if env.steam.find_installations().is_empty() {
+        eprintln!("Steam was not found, so no game recipes are exported.");
+        return Vec::new();
+    }
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let Some((steam_path, steam_root, steam_lib_paths)) =
+        get_steam_context(env, no_term, &extra_libs)
+    else {
+        return Vec::new();
+    };
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    steam_apps
+        .iter()
+        .filter(|app| app.is_windows_app())
+        .filter_map(|app| {
+            let prefix_path = app.prefix_path.as_ref().filter(|p| p.exists())?;
+            let proton_app = find_proton_app(&steam_path, &steam_apps, app.appid);
+            let recipe = recipe::export_recipe(
+                app.appid,
+                &app.name,
+                prefix_path,
+                proton_app.as_ref().map(|p| p.name.as_str()),
+                crate::steam::launch_options(&steam_path, app.appid).as_deref(),
+            );
+            Some(recipe).filter(migrate::has_setup)
+        })
+        .collect()
+}
+
+/// Export a game's setup as a recipe file, apply one, or search the
+/// community index for one.
+fn run_recipe_mode(env: &CliEnv, args: &[String], parsed: &util::ParsedArgs, no_term: bool) {
+    use crate::recipe::{self, Recipe};
+
+    let (command, target) = match args {
+        [command, target, ..] if ["export", "apply", "search"].contains(&command.as_str()) => {
+            (command.as_str(), target.as_str())
+        }
+        _ => env.exit.exit_with_error(
+            "Usage: protontool recipe export APPID [FILE] | recipe apply FILE | recipe search APPID",
+            no_term,
+        ),
+    };
+    let loaded = if command == "apply" {
+        match Recipe::load(Path::new(target)) {
+            Ok(r) => Some(r),
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        }
+    } else {
+        None
+    };
+    let appid: u32 = match &loaded {
+        Some(recipe) => recipe.appid,
+        None => match target.parse() {
+            Ok(id) => id,
+            Err(_) => env
+                .exit
+                .exit_with_error(&format!("Invalid APPID: {}", target), no_term),
+        },
+    };
+    let community = if command == "search" {
+        match recipe::search_community(appid) {
+            Ok(found) if found.is_empty() => {
+                println!("No community recipes found for {}.", appid);
+                return;
+            }
+            Ok(found) => found,
+            Err(e) => env.exit.exit_with_error(&e, no_term),
+        }
+    } else {
+        Vec::new()
+    };
+
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+    let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
+    let steam_app = match steam_apps
+        .iter()
+        .find(|app| app.appid == appid && app.is_windows_app())
+    {
+        Some(app) => app.clone(),
+        None => env.exit.exit_with_error(
+            &format!(
+                "Steam app {} could not be found. Is it installed and have you launched it at least once?",
+                appid
+            ),
+            no_term,
+        ),
+    };
+    let prefix_path = match steam_app.prefix_path.as_ref().filter(|p| p.exists()) {
+        Some(prefix) => prefix.clone(),
+        None => env.exit.exit_with_error(
+            &format!(
+                "{} ({}) has no Proton prefix yet. Launch it once or run 'protontool {} --prewarm'.",
+                steam_app.name, appid, appid
+            ),
+            no_term,
+        ),
+    };
+    let proton_app = find_proton_app(&steam_path, &steam_apps, appid);
+    let launch_options = crate::steam::launch_options(&steam_path, appid);
+
+    if command == "export" {
+        let recipe = recipe::export_recipe(
+            appid,
+            &steam_app.name,
+            &prefix_path,
+            proton_app.as_ref().map(|p| p.name.as_str()),
+            launch_options.as_deref(),
+        );
+        let default_file = format!("protontool-recipe-{}.json", appid);
+        let file = args.get(2).map(|f| f.as_str()).unwrap_or(&default_file);
+        if file == "-" {
+            println!("{}", recipe.to_json());
+            return;
+        }
+        if let Err(e) = recipe.save(Path::new(file)) {
+            env.exit.exit_with_error(&e, no_term);
+        }
+        println!("{}\n\nWrote {}", recipe.describe(), file);
+        return;
+    }
+
+    let proton_app = match proton_app {
+        Some(app) if app.is_proton_ready => app,
+        _ => env
+            .exit
+            .exit_with_error("Proton installation could not be found!", no_term),
+    };
+    let recipe = match loaded {
+        Some(recipe) => {
+            if !confirm(
+                "Apply Recipe",
+                &recipe.describe(),
+                "Apply this recipe?",
+                no_term,
+            ) {
+                return;
+            }
+            recipe
+        }
+        None => {
+            let total = community.len();
+            let chosen = community.into_iter().enumerate().find(|(i, shared)| {
+                confirm(
+                    &format!("Community Recipe {}/{}", i + 1, total),
+                    &format!("{}\n\n{}", shared.provenance(), shared.recipe.describe()),
+                    "Apply this recipe?",
+                    no_term,
+                )
+            });
+            match chosen {
+                Some((_, shared)) => shared.recipe,
+                None => {
+                    println!("No recipe applied.");
+                    return;
+                }
+            }
+        }
+    };
+    apply_recipe(
+        env,
+        &recipe,
+        &proton_app,
+        &prefix_path,
+        &steam_path,
+        no_term,
+    );
+}
+
+/// Apply a confirmed recipe to a game's prefix.
+fn apply_recipe(
+    env: &CliEnv,
+    recipe: &crate::recipe::Recipe,
+    proton_app: &crate::steam::ProtonApp,
+    prefix_path: &Path,
+    steam_path: &Path,
+    no_term: bool,
+) {
+    use crate::recipe;
+
+    if let Some(proton) = recipe.proton.as_ref().filter(|p| **p != proton_app.name) {
+        eprintln!(
+            "Warning: The recipe was made with {}, this game uses {}. Select it in the game's Steam compatibility settings if the game misbehaves.",
+            proton, proton_app.name
+        );
+    }
+
+    let verb_runner = Wine::new(proton_app, prefix_path);
+    let mut success = true;
+    for result in [
+        recipe::apply_env(recipe, prefix_path),
+        recipe::apply_dll_overrides(recipe, &verb_runner.wine_ctx),
+        recipe::apply_notes(recipe),
+    ] {
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+            success = false;
+        }
+    }
+    for verb_name in recipe::pending_verbs(recipe, prefix_path) {
+        if let Err(e) = verb_runner.verb_registry.resolve(&verb_name) {
+            eprintln!("Warning: {} in recipe, skipping", e);
+            success = false;
+            continue;
+        }
+        if !accept_verb_licenses(&verb_runner, &verb_name, no_term) {
+            success = false;
+            continue;
+        }
+        println!("Running verb: {}", verb_name);
+        if let Err(e) = verb_runner.run_verb(&verb_name) {
+            eprintln!("Error running {}: {}", verb_name, e);
+            success = false;
+        }
+    }
+    print_verb_summary(&verb_runner);
+    if let Some(options) = recipe.launch_options.as_deref() {
+        match crate::launch_options::add(steam_path, recipe.appid, options) {
+            Ok(updated) => {
+                for options in updated {
+                    println!("Launch options: {}", options);
+                }
+            }
+            Err(e) => eprintln!(
+                "Warning: {}\nAdd these launch options in the game's Steam properties:\n  {}",
+                e, options
+            ),
+        }
+    }
+
+    if !success {
+        env.exit.exit(1);
+    }
+    println!(
+        "Recipe applied. Use 'protontool undo' to revert environment, DLL override and launch option changes."
+    );
+}
+
+/// Search verbs and community recipes for an application.
+/// Pass an nxm:// link to the mod manager registered by the `mo2` verb,
+/// or remove the registration with `off`.
+fn run_nxm_mode(url: &str, no_term: bool) {
+    if url == "off" {
+        match crate::desktop::unregister_nxm_handler() {
+            Ok(true) => println!("Removed the nxm:// handler"),
+            Ok(false) => println!("No nxm:// handler is registered"),
+            Err(e) => exit_with_error(&e, no_term),
+        }
+        return;
+    }
+    if !url.to_lowercase().starts_with("nxm://") {
+        exit_with_error(&format!("Not an nxm:// link: {}", url), no_term);
+    }
+    let Some(handler) = crate::desktop::nxm_handler() else {
+        exit_with_error(
+            "No nxm:// handler is registered. Install Mod Organizer 2 with the mo2 verb first.",
+            no_term,
+        );
+    };
+    if !handler.prefix_path.exists() {
+        exit_with_error(
+            &format!(
+                "The prefix of the nxm:// handler no longer exists: {}",
+                handler.prefix_path.display()
+            ),
+            no_term,
+        );
+    }
+
+    let proton_app = crate::steam::ProtonApp {
+        name: handler
+            .proton_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        appid: 0,
+        install_path: handler.proton_path.clone(),
+        is_proton_ready: true,
+    };
+    let arch =
+        crate::wine::WineArch::from_str(&handler.arch).unwrap_or(crate::wine::WineArch::Win64);
+    let wine_ctx =
+        crate::wine::WineContext::from_proton_with_arch(&proton_app, &handler.prefix_path, arch);
+    match wine_ctx.run_wine(&[handler.program.as_str(), url]) {
+        Ok(output) => process::exit(output.status.code().unwrap_or(0)),
+        Err(e) => exit_with_error(
+            &format!("Failed to run {}: {}", handler.program, e),
+            no_term,
+        ),
+    }
+}
+
+/// Serve JSON-RPC requests on a Unix socket until killed.
+fn run_serve_mode(env: &CliEnv, socket: Option<&str>, parsed: &util::ParsedArgs, no_term: bool) {
+    let extra_libs = parsed.get_multi_option("steam_library").to_vec();
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
+    let socket_path = socket.map_or_else(daemon::default_socket_path, PathBuf::from);
+    let daemon = match daemon::Daemon::new(
+        steam_path,
+        steam_root,
+        steam_lib_paths,
+        &crate::config::UserConfig::load(),
+    ) {
+        Ok(daemon) => daemon,
+        Err(e) => env.exit.exit_with_error(&e, no_term),
+    };
+
+    println!("Serving on {}", socket_path.display());
+    if let Err(e) = daemon::serve(daemon, &socket_path) {
+        env.exit.exit_with_error(&e, no_term);
+    }
+}
+
+fn run_search_app_mode(query: &str) {
+    use crate::search;
+
+    let registry = crate::wine::VerbRegistry::new();
+    let mut matches = search::search_verbs(&registry, query);
+    match crate::recipe::fetch_community_index() {
+        Some(Ok(recipes)) => matches.extend(search::search_recipes(&recipes, query)),
+        Some(Err(e)) => eprintln!("Warning: Community recipes unavailable: {}", e),
+        None => {}
     }
 
-    // Initialize the prefix with Proton's wine
-    let wine_ctx = crate::wine::WineContext::from_proton_with_arch(&proton_app, &prefix_path, arch);
-    // Proton uses "files" subdirectory, older versions may use "dist"
-    let dist_dir = {
-        let files_dir = proton_app.install_path.join("files");
-        let dist_dir = proton_app.install_path.join("dist");
-        if files_dir.exists() {
-            files_dir
+    if matches.is_empty() {
+        println!("Found no applications matching '{}'.", query);
+        return;
+    }
+    println!("Found the following applications:");
+    for found in &matches {
+        if crate::util::is_plain_output() {
+            println!(
+                "\nName: {}\nType: {}\nDescription: {}\nInstall: {}",
+                found.name,
+                found.source.as_str(),
+                found.description,
+                found.command
+            );
         } else {
-            dist_dir
+            println!(
+                "\n{} [{}] {}\n  $ {}",
+                found.name,
+                found.source.as_str(),
+                found.description,
+                found.command
+            );
+        }
+    }
+    println!("\nReplace APPID with the game's APPID, or use --prefix PATH for a custom prefix.");
+}
+
+/// List the available verbs, sorted by category and name.
+fn run_verbs_mode(args: &[String], parsed: &util::ParsedArgs, no_term: bool) {
+    use crate::wine::VerbCategory;
+
+    let category = match args {
+        [list] if list == "list" => None,
+        [list, name] if list == "list" => {
+            match VerbCategory::all().iter().find(|c| c.as_str() == name) {
+                Some(category) => Some(*category),
+                None => exit_with_error(
+                    &format!(
+                        "Unknown verb category '{}' (use apps, dlls, fonts, settings, gamefixes or custom)",
+                        name
+                    ),
+                    no_term,
+                ),
+            }
         }
+        _ => exit_with_error(
+            "Usage: protontool verbs list [CATEGORY] [--names-only]",
+            no_term,
+        ),
     };
 
-    println!("Initializing prefix...");
-    if let Err(e) = crate::wine::prefix::init_prefix(&prefix_path, &dist_dir, true, Some(&wine_ctx))
-    {
-        exit_with_error(&format!("Failed to initialize prefix: {}", e), no_term);
+    let registry = crate::wine::VerbRegistry::new();
+    let verbs = registry.list(category);
+    if parsed.get_flag("names_only") {
+        for verb in &verbs {
+            println!("{}", registry.reference(verb));
+        }
+        return;
     }
 
-    // Save prefix metadata for future use
-    let metadata_path = prefix_path.join(".protontool");
-    let metadata = format!(
-        "proton_name={}\nproton_path={}\narch={}\ncreated={}\n",
-        proton_app.name,
-        proton_app.install_path.display(),
-        arch.as_str(),
-        chrono_lite_now()
-    );
-    std::fs::write(&metadata_path, metadata).ok();
-
-    println!("\nPrefix created successfully!");
-    println!("\nTo use this prefix:");
-    println!("  protontool --prefix '{}' <verbs>", prefix_path.display());
-    println!(
-        "  protontool --prefix '{}' -c <command>",
-        prefix_path.display()
-    );
+    let names: Vec<String> = verbs.iter().map(|v| registry.reference(v)).collect();
+    let width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+    let mut current = None;
+    for (verb, name) in verbs.iter().zip(&names) {
+        if crate::util::is_plain_output() {
+            println!(
+                "Name: {}\nCategory: {}\nTitle: {}\n",
+                name,
+                verb.category.as_str(),
+                verb.title
+            );
+            continue;
+        }
+        if current != Some(verb.category) {
+            if current.is_some() {
+                println!();
+            }
+            println!("{}:", verb.category.as_str());
+            current = Some(verb.category);
+        }
+        println!("  {:<width$}  {}", name, verb.title, width = width);
+    }
 }
 
-fn run_delete_prefix_mode(prefix_path: &str, no_term: bool) {
-    let prefix_path = PathBuf::from(prefix_path);
+fn run_analyze_log_mode(log_path: &str, parsed: &util::ParsedArgs, no_term: bool) {
+    let sink = parsed.get_option("report").map(|format| {
+        crate::report::sink_for_format(format).unwrap_or_else(|| {
+            exit_with_error(
+                &format!("Unknown report format '{}' (use markdown or html)", format),
+                no_term,
+            )
+        })
+    });
 
-    if !prefix_path.exists() {
-        exit_with_error(
-            &format!("Prefix path does not exist: {}", prefix_path.display()),
-            no_term,
-        );
+    let analysis = match crate::analyze::analyze_log(&PathBuf::from(log_path), 0) {
+        Ok(a) => a,
+        Err(e) => exit_with_error(&e, no_term),
+    };
+
+    if let Some(sink) = sink {
+        let rendered = sink.render(&crate::report::Report::from_analysis(&analysis));
+        match parsed.get_option("report_output") {
+            Some(output) => {
+                if let Err(e) = std::fs::write(output, rendered) {
+                    exit_with_error(&format!("Failed to write {}: {}", output, e), no_term);
+                }
+                println!("Report written to {}", output);
+            }
+            None => print!("{}", rendered),
+        }
+        return;
     }
 
-    let prefix_name = prefix_path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("Unknown");
+    print_analysis_table(&analysis);
+    if analysis.summaries.iter().any(|s| s.code == "NET-CERT") {
+        println!();
+        println!(
+            "Certificate errors: if you are behind a TLS proxy, import its CA with \
+             'protontool APPID certs import FILE' or the host's bundle with 'certs import-host'."
+        );
+    }
+    if analysis
+        .summaries
+        .iter()
+        .any(|s| s.code.starts_with("MEDIA-GST-"))
+    {
+        println!();
+        println!("{}", crate::wine::media::log_hint());
+    }
+}
 
-    // Confirm deletion
+/// Print a ranked table of known errors found in a log.
+fn print_analysis_table(analysis: &crate::analyze::LogAnalysis) {
     println!(
-        "Are you sure you want to delete the prefix '{}'?",
-        prefix_name
+        "Analyzed {} ({} lines)",
+        analysis.path.display(),
+        analysis.total_lines
     );
-    println!("Path: {}", prefix_path.display());
-    println!();
-    print!("Type 'yes' to confirm: ");
-    std::io::Write::flush(&mut std::io::stdout()).ok();
 
-    let mut input = String::new();
-    if std::io::stdin().read_line(&mut input).is_err() {
-        exit_with_error("Failed to read input.", no_term);
+    if analysis.summaries.is_empty() {
+        println!("No known errors found.");
+        return;
     }
 
-    if input.trim().to_lowercase() != "yes" {
-        println!("Deletion cancelled.");
+    println!();
+    if crate::util::is_plain_output() {
+        for (rank, summary) in analysis.summaries.iter().enumerate() {
+            println!("Rank: {}", rank + 1);
+            println!("Code: {}", summary.code);
+            println!("Count: {}", summary.count);
+            println!("First: {}", summary.first.describe());
+            println!("Last: {}", summary.last.describe());
+            println!("Description: {}", summary.description);
+            if !summary.dlls.is_empty() {
+                println!("Missing: {}", summary.dlls.join(", "));
+            }
+            println!();
+        }
         return;
     }
 
-    // Delete the prefix directory
-    match std::fs::remove_dir_all(&prefix_path) {
-        Ok(()) => {
-            println!("Prefix '{}' deleted successfully.", prefix_name);
-        }
-        Err(e) => {
-            exit_with_error(&format!("Failed to delete prefix: {}", e), no_term);
+    println!(
+        "{:>4}  {:<20} {:>8}  {:<16} {:<16} Description",
+        "Rank", "Code", "Count", "First", "Last"
+    );
+    for (rank, summary) in analysis.summaries.iter().enumerate() {
+        let mut description = summary.description.clone();
+        if !summary.dlls.is_empty() {
+            description.push_str(&format!(" [Missing: {}]", summary.dlls.join(", ")));
         }
+        println!(
+            "{:>4}  {:<20} {:>8}  {:<16} {:<16} {}",
+            rank + 1,
+            summary.code,
+            summary.count,
+            summary.first.describe(),
+            summary.last.describe(),
+            description
+        );
     }
 }
 
 fn run_custom_prefix_mode(
+    env: &CliEnv,
     prefix_path: &str,
     verbs: &[String],
     parsed: &util::ParsedArgs,
@@ -3118,19 +7883,35 @@ fn run_custom_prefix_mode(
     let prefix_path = PathBuf::from(prefix_path);
 
     if !prefix_path.exists() {
-        exit_with_error(
+        env.exit.exit_with_error(
             &format!("Prefix path does not exist: {}", prefix_path.display()),
             no_term,
         );
     }
+    if verbs.first().is_some_and(|v| v == "compare") {
+        run_compare_command(env, &prefix_path, &verbs[1..], parsed, no_term);
+        return;
+    }
+    if parsed.get_flag("cleanup") && parsed.get_flag("dry_run") {
+        run_cleanup_dry_run(&prefix_path);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "notes") {
+        let mut args = verbs[1..].to_vec();
+        args.extend(parsed.trailing().iter().cloned());
+        run_notes_command(&crate::notes::Subject::Prefix(&prefix_path), &args, no_term);
+        return;
+    }
 
     let extra_libs = parsed.get_multi_option("steam_library").to_vec();
-    let (steam_path, steam_root, steam_lib_paths) = match get_steam_context(no_term, &extra_libs) {
-        Some(ctx) => ctx,
-        None => {
-            exit_with_error("No Steam installation was selected.", no_term);
-        }
-    };
+    let (steam_path, steam_root, steam_lib_paths) =
+        match get_steam_context(env, no_term, &extra_libs) {
+            Some(ctx) => ctx,
+            None => {
+                env.exit
+                    .exit_with_error("No Steam installation was selected.", no_term);
+            }
+        };
 
     let steam_apps = get_steam_apps(&steam_root, &steam_path, &steam_lib_paths);
     let proton_apps = get_proton_apps(&steam_apps);
@@ -3167,7 +7948,7 @@ fn run_custom_prefix_mode(
         match find_proton_by_name(&steam_apps, proton_name) {
             Some(app) => app,
             None => {
-                exit_with_error(
+                env.exit.exit_with_error(
                     &format!("Proton version '{}' not found.", proton_name),
                     no_term,
                 );
@@ -3180,17 +7961,68 @@ fn run_custom_prefix_mode(
         match select_proton_with_gui(&proton_apps) {
             Some(app) => app,
             None => {
-                exit_with_error("No Proton version selected.", no_term);
+                env.exit
+                    .exit_with_error("No Proton version selected.", no_term);
             }
         }
     };
 
     if !proton_app.is_proton_ready {
-        exit_with_error("Proton installation is not ready.", no_term);
+        env.exit
+            .exit_with_error("Proton installation is not ready.", no_term);
     }
 
-    let verb_runner = Wine::new_with_arch(&proton_app, &prefix_path, saved_arch);
+    let mut verb_runner = Wine::new_with_arch(&proton_app, &prefix_path, saved_arch);
+    apply_override_args(env, &mut verb_runner.wine_ctx, parsed, no_term);
 
+    if parsed.get_flag("rollback_last") {
+        run_rollback_last(&verb_runner.wine_ctx, no_term);
+        return;
+    }
+    if parsed.get_flag("cleanup") {
+        run_cleanup_mode(env, &verb_runner.wine_ctx, no_term);
+        return;
+    }
+    if parsed.get_flag("verify_graphics") {
+        run_verify_graphics(&verb_runner.wine_ctx, parsed, no_term);
+        return;
+    }
+    if parsed.get_option("export_overrides").is_some()
+        || parsed.get_option("import_overrides").is_some()
+    {
+        run_overrides_command(&verb_runner.wine_ctx, parsed, no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "services") {
+        run_services_command(&verb_runner.wine_ctx, &verbs[1..], no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "certs") {
+        run_certs_command(&verb_runner.wine_ctx, &verbs[1..], no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "doctor") {
+        run_doctor_command(&verb_runner.wine_ctx, &verbs[1..], no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "deferred") {
+        run_deferred_command(&verb_runner.wine_ctx, &verbs[1..], no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "winedebug") {
+        let mut args = verbs[1..].to_vec();
+        args.extend(parsed.trailing().iter().cloned());
+        run_winedebug_command(&verb_runner.wine_ctx.prefix_path, &args, no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "test-controller") {
+        run_test_controller_command(&verb_runner.wine_ctx, None, &verbs[1..], no_term);
+        return;
+    }
+    if verbs.first().is_some_and(|v| v == "snapshot") {
+        run_snapshot_command(&verb_runner.wine_ctx, &verbs[1..], no_term);
+        return;
+    }
     if verbs.is_empty() {
         // Interactive mode - show verb selection
         loop {
@@ -3201,6 +8033,7 @@ fn run_custom_prefix_mode(
 
             let verb_list = verb_runner.list_verbs(Some(category));
             let selected = select_verbs_with_gui(
+                &verb_runner.verb_registry,
                 &verb_list,
                 Some(&format!("Select {} to install", category.as_str())),
             );
@@ -3208,28 +8041,55 @@ fn run_custom_prefix_mode(
             if selected.is_empty() {
                 continue;
             }
+            if !check_prefix_before_changes(&verb_runner.wine_ctx, true) {
+                continue;
+            }
 
+            let prefix_arg = prefix_path.to_string_lossy();
+            let mut command = vec!["--prefix", prefix_arg.as_ref()];
             for verb_name in &selected {
+                if !accept_verb_licenses(&verb_runner, verb_name, true) {
+                    continue;
+                }
+                if !confirm_setting_changes(&verb_runner, verb_name, true) {
+                    continue;
+                }
                 println!("Running verb: {}", verb_name);
+                command.push(verb_name);
                 if let Err(e) = verb_runner.run_verb(verb_name) {
                     eprintln!("Error running {}: {}", verb_name, e);
                 }
             }
 
             println!("Completed running verbs.");
+
+            print_verb_summary(&verb_runner);
+            if command.len() > 2 {
+                equivalent::show(&command);
+            }
         }
     } else {
+        if !check_prefix_before_changes(&verb_runner.wine_ctx, no_term) {
+            env.exit.exit(1);
+        }
         // Run specified verbs
         for verb_name in verbs {
             if verb_name.starts_with('-') {
                 continue;
             }
+            if !accept_verb_licenses(&verb_runner, verb_name, no_term) {
+                continue;
+            }
+            if !confirm_setting_changes(&verb_runner, verb_name, no_term) {
+                continue;
+            }
             println!("Running verb: {}", verb_name);
             match verb_runner.run_verb(verb_name) {
                 Ok(()) => println!("Successfully completed: {}", verb_name),
                 Err(e) => eprintln!("Error running {}: {}", verb_name, e),
             }
         }
+        print_verb_summary(&verb_runner);
     }
 }
 
@@ -3240,3 +8100,510 @@ fn chrono_lite_now() -> String {
         .unwrap_or_default();
     format!("{}", duration.as_secs())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::environment::{ExitHandler, GuiProvider, SteamDiscovery};
+    use super::*;
+    use crate::steam::SteamInstallation;
+    use std::cell::Cell;
+
+    struct FakeSteam(Vec<SteamInstallation>);
+
+    impl SteamDiscovery for FakeSteam {
+        fn find_installations(&self) -> Vec<SteamInstallation> {
+            self.0.clone()
+        }
+    }
+
+    /// Picks the installation at `pick` (None cancels) and counts prompts.
+    struct FakeGui {
+        pick: Option<usize>,
+        prompts: std::rc::Rc<Cell<usize>>,
+    }
+
+    impl GuiProvider for FakeGui {
+        fn select_steam_installation(
+            &self,
+            installations: &[SteamInstallation],
+        ) -> Option<SteamInstallation> {
+            self.prompts.set(self.prompts.get() + 1);
+            installations.get(self.pick?).cloned()
+        }
+
+        fn prompt_filesystem_access(&self, _paths: &[&Path], _no_term: bool) -> bool {
+            true
+        }
+    }
+
+    /// Turns exits into panics so tests can observe them.
+    struct PanicExit;
+
+    impl ExitHandler for PanicExit {
+        fn exit_with_error(&self, error: &str, _no_term: bool) -> ! {
+            panic!("exit: {}", error)
+        }
+
+        fn exit(&self, code: i32) -> ! {
+            panic!("exit code {}", code)
+        }
+    }
+
+    fn installation(path: &str) -> SteamInstallation {
+        SteamInstallation {
+            steam_path: PathBuf::from(path),
+            steam_root: PathBuf::from(path),
+        }
+    }
+
+    fn test_env(installations: Vec<SteamInstallation>, pick: Option<usize>) -> CliEnv {
+        CliEnv {
+            steam: Box::new(FakeSteam(installations)),
+            gui: Box::new(FakeGui {
+                pick,
+                prompts: Default::default(),
+            }),
+            exit: Box::new(PanicExit),
+            base_dir: Some(std::env::temp_dir().join(format!("pt-cli-{}", std::process::id()))),
+        }
+    }
+
+    fn action(args: &[&str]) -> Result<CliAction, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let parsed = build_parser().parse(&args).unwrap();
+        select_action(&parsed, args.is_empty())
+    }
+
+    #[test]
+    fn test_select_action() {
+        assert_eq!(action(&[]), Ok(CliAction::Gui));
+        assert_eq!(action(&["-v"]), Ok(CliAction::Help));
+        assert_eq!(
+            action(&["1091500", "d3dx9", "vcrun2019"]),
+            Ok(CliAction::RunVerbs {
+                appid: 1091500,
+                verbs: vec!["d3dx9".to_string(), "vcrun2019".to_string()],
+            })
+        );
+        assert_eq!(action(&["1091500", "play"]), Ok(CliAction::Play(1091500)));
+        assert_eq!(
+            action(&["1091500", "--prewarm"]),
+            Ok(CliAction::Prewarm(1091500))
+        );
+    }
+
+    #[test]
+    fn test_select_action_subcommands() {
+        assert_eq!(
+            action(&["recipe", "apply", "cp2077.json"]),
+            Ok(CliAction::Recipe(vec![
+                "apply".to_string(),
+                "cp2077.json".to_string()
+            ]))
+        );
+        assert_eq!(
+            action(&["migrate", "export", "backup.tar.zst", "--with-prefixes"]),
+            Ok(CliAction::Migrate(vec![
+                "export".to_string(),
+                "backup.tar.zst".to_string()
+            ]))
+        );
+        assert_eq!(
+            action(&["migrate", "import", "backup.tar.zst", "--with-prefixes"]),
+            Err("--with-prefixes requires migrate export".to_string())
+        );
+        assert_eq!(
+            action(&["verbs", "list", "dlls", "--names-only"]),
+            Ok(CliAction::Verbs(vec![
+                "list".to_string(),
+                "dlls".to_string()
+            ]))
+        );
+        assert_eq!(
+            action(&["-l", "--names-only"]),
+            Err("--names-only requires verbs list".to_string())
+        );
+        assert_eq!(action(&["version", "--json"]), Ok(CliAction::Version));
+        assert!(action(&["-l", "--json"]).is_err());
+        assert_eq!(
+            action(&["search-app", "7", "zip"]),
+            Ok(CliAction::SearchApp("7 zip".to_string()))
+        );
+        assert_eq!(action(&["serve"]), Ok(CliAction::Serve(None)));
+        assert_eq!(
+            action(&["serve", "/tmp/pt.sock"]),
+            Ok(CliAction::Serve(Some("/tmp/pt.sock".to_string())))
+        );
+        assert_eq!(
+            action(&["nxm", "nxm://skyrimspecialedition/mods/266/files/1"]),
+            Ok(CliAction::Nxm(
+                "nxm://skyrimspecialedition/mods/266/files/1".to_string()
+            ))
+        );
+        assert_eq!(
+            action(&["--install-desktop-entry"]),
+            Ok(CliAction::InstallDesktopEntry)
+        );
+    }
+
+    #[test]
+    fn test_select_action_app_tools() {
+        assert_eq!(
+            action(&["1091500", "dxvk-conf", "set", "dxgi.maxFrameRate=60"]),
+            Ok(CliAction::DxvkConf {
+                appid: 1091500,
+                args: vec!["set".to_string(), "dxgi.maxFrameRate=60".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "services", "restart", "EasyAntiCheat"]),
+            Ok(CliAction::Services {
+                appid: 1091500,
+                args: vec!["restart".to_string(), "EasyAntiCheat".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "certs", "import-host"]),
+            Ok(CliAction::Certs {
+                appid: 1091500,
+                args: vec!["import-host".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "doctor", "fix"]),
+            Ok(CliAction::Doctor {
+                appid: 1091500,
+                args: vec!["fix".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["620", "launch-options", "add", "gamemoderun %command%"]),
+            Ok(CliAction::LaunchOptions {
+                appid: 620,
+                args: vec!["add".to_string(), "gamemoderun %command%".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "winedebug", "set", "--", "-all,err+module"]),
+            Ok(CliAction::WineDebug {
+                appid: 1091500,
+                args: vec!["set".to_string(), "-all,err+module".to_string()],
+            })
+        );
+        assert!(action(&["1091500", "--winedebug", "-all", "winedebug", "--read-only"]).is_ok());
+        assert_eq!(
+            action(&["1091500", "notes", "tag", "needs dotnet48"]),
+            Ok(CliAction::Notes {
+                appid: 1091500,
+                args: vec!["tag".to_string(), "needs dotnet48".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "deferred", "wait"]),
+            Ok(CliAction::Deferred {
+                appid: 1091500,
+                args: vec!["wait".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "snapshot", "restore", "clean"]),
+            Ok(CliAction::Snapshot {
+                appid: 1091500,
+                args: vec!["restore".to_string(), "clean".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "test-controller"]),
+            Ok(CliAction::TestController {
+                appid: 1091500,
+                args: vec![],
+            })
+        );
+        assert_eq!(
+            action(&["489830", "workshop", "link"]),
+            Ok(CliAction::Workshop {
+                appid: 489830,
+                args: vec!["link".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "vkd3d-config", "enable", "dxr"]),
+            Ok(CliAction::Vkd3dConfig {
+                appid: 1091500,
+                args: vec!["enable".to_string(), "dxr".to_string()],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "compare", "--with", "/tmp/good"]),
+            Ok(CliAction::Compare {
+                appid: 1091500,
+                args: vec![],
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "vcrun2022", "--with", "/tmp/good"]),
+            Err("--with requires compare".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_action_command_options() {
+        assert_eq!(
+            action(&["-l", "--detach"]),
+            Err("--workdir, --detach and --wait-wineserver require -c".to_string())
+        );
+        assert_eq!(
+            action(&["1091500", "-c", "game.exe", "--detach", "--wait-wineserver"]),
+            Err("--detach and --wait-wineserver can't be combined".to_string())
+        );
+        assert_eq!(
+            action(&["-c", "winecfg", "--prefix", "/tmp/pfx"]),
+            Ok(CliAction::PrefixCommand {
+                prefix: "/tmp/pfx".to_string(),
+                command: "winecfg".to_string(),
+            })
+        );
+        assert_eq!(
+            action(&["1091500", "vcrun2022", "--nice", "5"]),
+            Err(
+                "--nice, --cpu-affinity, --gamemode, --memory-limit and --large-address-aware \
+                 require play, run or -c"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_select_action_prefix_maintenance() {
+        assert_eq!(
+            action(&["489830", "--mount", "M:=~/MO2"]),
+            Ok(CliAction::MountApp {
+                appid: 489830,
+                spec: "M:=~/MO2".to_string(),
+            })
+        );
+        assert_eq!(
+            action(&["-l", "--mount", "list"]),
+            Err("--mount requires an APPID or --prefix".to_string())
+        );
+        assert_eq!(
+            action(&["1091500", "--export-overrides", "cp2077.toml"]),
+            Ok(CliAction::OverridesApp(1091500))
+        );
+        assert_eq!(
+            action(&["-l", "--import-overrides", "cp2077.toml"]),
+            Err(
+                "--export-overrides and --import-overrides require an APPID or --prefix"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            action(&["1091500", "--rollback-last"]),
+            Ok(CliAction::RollbackApp(1091500))
+        );
+        assert_eq!(
+            action(&["1091500", "--verify-graphics", "--dry-run", "--read-only"]),
+            Ok(CliAction::VerifyGraphicsApp(1091500))
+        );
+        assert_eq!(
+            action(&["--prefix", "/tmp/pfx", "--fix-ownership"]),
+            Ok(CliAction::PrefixFixOwnership("/tmp/pfx".to_string()))
+        );
+        assert_eq!(
+            action(&["1091500", "--fix-ownership"]),
+            Ok(CliAction::FixOwnershipApp(1091500))
+        );
+        assert_eq!(
+            action(&["--prefix", "/tmp/pfx", "--cleanup"]),
+            Ok(CliAction::PrefixCleanup("/tmp/pfx".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_select_action_read_only() {
+        assert!(action(&["1091500", "notes", "--read-only"]).is_ok());
+        assert_eq!(
+            action(&["--prefix", "/tmp/pfx", "snapshot", "list", "--read-only"]),
+            Ok(CliAction::UsePrefix {
+                prefix: "/tmp/pfx".to_string(),
+                verbs: vec!["snapshot".to_string(), "list".to_string()],
+            })
+        );
+        assert!(action(&[
+            "--prefix",
+            "/tmp/pfx",
+            "--cleanup",
+            "--dry-run",
+            "--read-only"
+        ])
+        .is_ok());
+        assert!(action(&["--prefix", "/tmp/pfx", "--cleanup", "--read-only"]).is_err());
+        assert!(action(&["1091500", "doctor", "fix", "--read-only"]).is_err());
+        assert!(action(&["1091500", "vcrun2022", "--read-only"]).is_err());
+    }
+
+    #[test]
+    fn test_select_action_conflicts() {
+        assert_eq!(action(&["--gui", "-l"]), Ok(CliAction::Conflict));
+        assert_eq!(
+            action(&["--create-prefix", "/tmp/a", "--delete-prefix", "/tmp/b"]),
+            Ok(CliAction::Conflict)
+        );
+        assert_eq!(
+            action(&["--adopt-prefix", "/tmp/a", "--arch", "win32"]),
+            Ok(CliAction::AdoptPrefix("/tmp/a".to_string()))
+        );
+        assert_eq!(
+            action(&["-l", "--sandbox", "on"]),
+            Err("--sandbox requires --create-prefix or --prefix".to_string())
+        );
+        assert_eq!(
+            action(&["-l", "--user-dirs", "none"]),
+            Err("--user-dirs requires --create-prefix or --prefix".to_string())
+        );
+        assert!(action(&["-l", "--cleanup"]).is_err());
+        assert_eq!(
+            action(&["--prefix", "/tmp/p", "--image", "unmount"]),
+            Ok(CliAction::PrefixImage {
+                prefix: "/tmp/p".to_string(),
+                value: "unmount".to_string(),
+            })
+        );
+        assert_eq!(
+            action(&["-l", "--image", "20G"]),
+            Err("--image requires --create-prefix or --prefix".to_string())
+        );
+        assert!(action(&["1091500", "play", "--prewarm"]).is_err());
+    }
+
+    #[test]
+    fn test_audited_action_appid() {
+        let appid = |args: &[&str]| action_appid(&action(args).unwrap());
+        assert_eq!(appid(&["1091500", "vcrun2022", "--audit"]), Some(1091500));
+        assert_eq!(
+            appid(&["1091500", "-c", "game.exe", "--audit"]),
+            Some(1091500)
+        );
+        assert_eq!(appid(&["--prefix", "/tmp/pfx", "--audit", "d3dx9"]), None);
+        assert!(action(&["-l", "--audit"]).is_err());
+    }
+
+    #[test]
+    fn test_command_argv() {
+        let strings =
+            |args: &[&str]| -> Vec<String> { args.iter().map(|a| a.to_string()).collect() };
+        let dir = std::env::temp_dir().join(format!("pt-command-{}", std::process::id()));
+        let game = dir.join("Tom Clancy's Rainbow Six® Siege (2015)/\"Game\" v1.0.exe");
+        std::fs::create_dir_all(game.parent().unwrap()).unwrap();
+        std::fs::write(&game, "").unwrap();
+        let game = game.to_string_lossy().into_owned();
+
+        // An existing path is one program, spaces and quotes included
+        assert_eq!(command_argv(&game, &[]), Ok(strings(&[&game])));
+        assert_eq!(
+            command_argv(&game, &strings(&["-windowed", "save dir/a b"])),
+            Ok(strings(&[&game, "-windowed", "save dir/a b"]))
+        );
+        assert_eq!(
+            command_argv(r"C:\Program Files (x86)\Game\game.exe", &[]),
+            Ok(strings(&[r"C:\Program Files (x86)\Game\game.exe"]))
+        );
+        assert_eq!(
+            command_argv("wine notepad 'C:\\My Files\\a.txt'", &[]),
+            Ok(strings(&["notepad", r"C:\My Files\a.txt"]))
+        );
+        assert!(command_argv("", &[]).is_err());
+
+        // `-c -- PROGRAM ARGS` leaves the option empty and everything trailing
+        let args = strings(&["1091500", "-c", "--", &game, "--skip intro"]);
+        let parsed = build_parser().parse(&args).unwrap();
+        assert_eq!(parsed.get_option("command"), Some(""));
+        assert_eq!(
+            command_argv("", parsed.trailing()),
+            Ok(strings(&[&game, "--skip intro"]))
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "exit: --z-drive requires --prefix")]
+    fn test_main_cli_flag_conflict_exits() {
+        let env = test_env(vec![], None);
+        main_cli_with_env(
+            Some(vec![
+                "-l".to_string(),
+                "--z-drive".to_string(),
+                "off".to_string(),
+            ]),
+            &env,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exit code 2")]
+    fn test_main_cli_unknown_option_exits() {
+        let env = test_env(vec![], None);
+        main_cli_with_env(Some(vec!["--no-such-option".to_string()]), &env);
+    }
+
+    #[test]
+    #[should_panic(expected = "exit: Steam installation directory could not be found.")]
+    fn test_main_cli_without_steam_exits() {
+        let env = test_env(vec![], None);
+        main_cli_with_env(Some(vec!["-l".to_string()]), &env);
+    }
+
+    #[test]
+    #[should_panic(expected = "exit: Proton installation is incomplete.")]
+    fn test_main_cli_runs_verbs_for_app() {
+        let steam = std::env::temp_dir().join(format!("pt-cli-steam-{}", std::process::id()));
+        let steamapps = steam.join("steamapps");
+        std::fs::create_dir_all(steamapps.join("compatdata/620/pfx")).unwrap();
+        std::fs::create_dir_all(steamapps.join("common/Proton 9.0")).unwrap();
+        for (appid, name, installdir) in [
+            (620, "Portal 2", "Portal 2"),
+            (1, "Proton 9.0", "Proton 9.0"),
+        ] {
+            std::fs::write(
+                steamapps.join(format!("appmanifest_{}.acf", appid)),
+                format!(
+                    "\"AppState\"\n{{\n\t\"appid\"\t\"{}\"\n\t\"name\"\t\"{}\"\n\t\"installdir\"\t\"{}\"\n}}\n",
+                    appid, name, installdir
+                ),
+            )
+            .unwrap();
+        }
+
+        // The game and its Proton are found; Proton was never run, so the
+        // verbs are refused through the environment's exit handler
+        let env = test_env(vec![installation(&steam.to_string_lossy())], Some(0));
+        main_cli_with_env(Some(vec!["620".to_string(), "d3dx9".to_string()]), &env);
+    }
+
+    #[test]
+    fn test_steam_context_with_multiple_installations() {
+        let prompts = std::rc::Rc::new(Cell::new(0));
+        let installations = vec![
+            installation("/nonexistent/steam-a"),
+            installation("/nonexistent/steam-b"),
+        ];
+        let env = CliEnv {
+            steam: Box::new(FakeSteam(installations.clone())),
+            gui: Box::new(FakeGui {
+                pick: Some(1),
+                prompts: prompts.clone(),
+            }),
+            exit: Box::new(PanicExit),
+            base_dir: None,
+        };
+        let (steam_path, _, lib_paths) = get_steam_context(&env, false, &[]).unwrap();
+        assert_eq!(steam_path, PathBuf::from("/nonexistent/steam-b"));
+        assert!(lib_paths.is_empty());
+        assert_eq!(prompts.get(), 1);
+
+        // Cancelling the selection gives no context
+        let env = test_env(installations, None);
+        assert!(get_steam_context(&env, false, &[]).is_none());
+    }
+}
@@ -111,6 +111,7 @@ pub struct ParsedArgs {
     options: HashMap<String, String>,
     multi_options: HashMap<String, Vec<String>>,
     positional: Vec<String>,
+    trailing: Vec<String>,
 }
 
 impl Default for ParsedArgs {
@@ -120,6 +121,7 @@ impl Default for ParsedArgs {
             options: HashMap::new(),
             multi_options: HashMap::new(),
             positional: Vec::new(),
+            trailing: Vec::new(),
         }
     }
 }
@@ -154,6 +156,11 @@ impl ParsedArgs {
     pub fn positional(&self) -> &[String] {
         &self.positional
     }
+
+    /// Get arguments after `--`, passed through verbatim.
+    pub fn trailing(&self) -> &[String] {
+        &self.trailing
+    }
 }
 
 /// Simple command-line argument parser.
@@ -214,6 +221,11 @@ impl ArgParser {
         while i < args.len() {
             let arg = &args[i];
 
+            if arg == "--" {
+                parsed.trailing = args[i + 1..].to_vec();
+                break;
+            }
+
             if arg.starts_with('-') {
                 let mut found = false;
 
@@ -225,6 +237,12 @@ impl ArgParser {
                             if i >= args.len() {
                                 return Err(format!("Option {} requires a value", arg));
                             }
+                            // An option right before `--` is empty (`-c -- PROGRAM ARGS`)
+                            if args[i] == "--" {
+                                parsed.options.insert(def.name.clone(), String::new());
+                                i -= 1;
+                                break;
+                            }
                             if def.is_multi {
                                 parsed
                                     .multi_options
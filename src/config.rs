@@ -1,5 +1,6 @@
 use std::env;
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 #[cfg(not(feature = "custom_steam_dir"))]
 pub const DEFAULT_STEAM_DIR: Option<&str> = None;
@@ -32,8 +33,21 @@ pub mod defaults {
     pub const GUI_PROVIDERS: &[&str] = &["yad", "zenity"];
 }
 
+/// Base directory set by [`set_base_dir`], replacing `~/.protontool`.
+static BASE_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Keep protontool's files (config, state, caches, work directories) in
+/// `dir` instead of `~/.protontool` for the rest of this run; None restores
+/// the default.
+pub fn set_base_dir(dir: Option<PathBuf>) {
+    *BASE_DIR.lock().unwrap() = dir;
+}
+
 /// Get the base protontool directory (~/.protontool)
 pub fn get_base_dir() -> PathBuf {
+    if let Some(dir) = BASE_DIR.lock().unwrap().clone() {
+        return dir;
+    }
     if let Ok(home) = env::var("HOME") {
         PathBuf::from(home).join(".protontool")
     } else {
@@ -56,16 +70,119 @@ pub fn get_verbs_dir() -> PathBuf {
     get_base_dir().join("verb")
 }
 
+/// Get the verb plugins directory (~/.protontool/plugin)
+pub fn get_plugins_dir() -> PathBuf {
+    get_base_dir().join("plugin")
+}
+
+/// Get the persistent state directory (~/.protontool/state)
+pub fn get_state_dir() -> PathBuf {
+    get_base_dir().join("state")
+}
+
 /// Get the custom prefixes directory (~/.protontool/pfx)
 pub fn get_prefixes_dir() -> PathBuf {
     get_base_dir().join("pfx")
 }
 
+/// Get the default prefix used by `protontool run` for one-off apps.
+/// Set `default_prefix` in the `[run]` section of config.toml to override
+/// ~/.protontool/pfx/default.
+pub fn get_default_prefix_path() -> PathBuf {
+    UserConfig::load()
+        .get("run", "default_prefix")
+        .map(|p| PathBuf::from(crate::wine::custom::expand_path(p)))
+        .unwrap_or_else(|| get_prefixes_dir().join("default"))
+}
+
+/// Get the directory holding temporary files of running operations.
+/// `protontool_WORK_DIR` or `work_dir` in the `[paths]` section of
+/// config.toml override ~/.protontool/work (useful when /tmp is a small
+/// tmpfs or the home directory is short on space).
+pub fn get_work_dir() -> PathBuf {
+    if let Ok(dir) = env::var("protontool_WORK_DIR") {
+        return PathBuf::from(dir);
+    }
+    UserConfig::load()
+        .get("paths", "work_dir")
+        .map(|p| PathBuf::from(crate::wine::custom::expand_path(p)))
+        .unwrap_or_else(|| get_base_dir().join("work"))
+}
+
 /// Get the logs directory (~/.protontool/log)
 pub fn get_log_dir() -> PathBuf {
     get_base_dir().join("log")
 }
 
+/// Get the user configuration file (~/.protontool/config.toml)
+pub fn get_config_file() -> PathBuf {
+    get_base_dir().join("config.toml")
+}
+
+/// Settings from `config.toml`, grouped by `[section]`.
+/// Keys before the first section header belong to the section `""`.
+#[derive(Debug, Clone, Default)]
+pub struct UserConfig {
+    sections: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl UserConfig {
+    /// Parse config file content (flat TOML: sections, strings and string arrays).
+    pub fn parse(content: &str) -> Self {
+        let mut config = UserConfig::default();
+        config.sections.push((String::new(), Vec::new()));
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') && line.ends_with(']') {
+                let name = line.trim_matches(|c| c == '[' || c == ']').trim();
+                config.sections.push((name.to_string(), Vec::new()));
+                continue;
+            }
+            if let Some((key, value)) = crate::wine::custom::parse_toml_line(line) {
+                if let Some((_, entries)) = config.sections.last_mut() {
+                    entries.push((key, value));
+                }
+            }
+        }
+        config
+    }
+
+    /// Load `config.toml`. A missing or unreadable file is an empty config.
+    pub fn load() -> Self {
+        std::fs::read_to_string(get_config_file())
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// All entries of a section (merged if the section appears more than once).
+    pub fn section(&self, name: &str) -> Vec<(&str, &str)> {
+        self.sections
+            .iter()
+            .filter(|(section, _)| section == name)
+            .flat_map(|(_, entries)| entries.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+            .collect()
+    }
+
+    /// Get a value. The last occurrence of a key wins.
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.section(section)
+            .into_iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Get a string array value (`key = ["a", "b"]`).
+    pub fn get_array(&self, section: &str, key: &str) -> Option<Vec<String>> {
+        self.get(section, key)
+            .map(crate::wine::custom::parse_string_array)
+    }
+}
+
 /// Get the Steam directory from STEAM_DIR environment variable or compile-time default.
 /// Returns None if neither is set.
 pub fn get_steam_dir() -> Option<PathBuf> {
@@ -0,0 +1,283 @@
+//! Steam Input conflict detection and controller diagnostics.
+//!
+//! While the Steam client runs, Steam Input grabs supported controllers and
+//! only hands its virtual gamepad to games started by Steam. A game launched
+//! by protontool directly then sees no (or a doubled) controller, the classic
+//! "controller works in Steam but not via protontool" problem. This module
+//! detects the likely conflict so a warning with guidance can be shown.
+//!
+//! Inside a prefix, controllers are picked up by Wine's `winebus` driver
+//! through SDL, hidraw or evdev, and every device it reports is recorded
+//! under `HKLM\System\CurrentControlSet\Enum`. Comparing those records with
+//! the host's gamepads shows which controllers a prefix actually sees.
+
+use std::fs;
+use std::path::Path;
+
+use crate::wine::registry;
+
+/// Enumerator keys winebus and hidclass record devices under.
+const DEVICE_ENUM_KEYS: &[&str] = &[
+    "HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Enum\\WINEBUS",
+    "HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Enum\\HID",
+];
+
+/// Registry key with winebus' backend switches.
+const WINEBUS_KEY: &str = "HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Services\\winebus";
+
+/// Device names (lowercase substrings) of Steam's virtual gamepads.
+const STEAM_VIRTUAL_DEVICES: &[&str] = &["steam virtual gamepad", "steam deck"];
+
+/// An input device from `/proc/bus/input/devices`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDevice {
+    pub name: String,
+    /// USB vendor and product ID, if reported.
+    pub vendor: Option<u16>,
+    pub product: Option<u16>,
+    /// Kernel handlers, e.g. `event5`, `js0`.
+    pub handlers: Vec<String>,
+}
+
+impl InputDevice {
+    /// Whether the device is a joystick/gamepad.
+    pub fn is_gamepad(&self) -> bool {
+        self.handlers.iter().any(|h| h.starts_with("js"))
+    }
+
+    /// Whether the device is a virtual gamepad created by Steam.
+    pub fn is_steam_virtual(&self) -> bool {
+        let name = self.name.to_lowercase();
+        STEAM_VIRTUAL_DEVICES.iter().any(|v| name.contains(v))
+    }
+}
+
+/// Parse the content of `/proc/bus/input/devices`.
+///
+/// ```
+/// use protontool::controller::parse_input_devices;
+/// let content = "I: Bus=0003 Vendor=045e Product=028e\nN: Name=\"Microsoft X-Box 360 pad\"\nH: Handlers=event20 js0\n\n";
+/// let devices = parse_input_devices(content);
+/// assert_eq!(devices[0].name, "Microsoft X-Box 360 pad");
+/// assert_eq!(devices[0].product, Some(0x028e));
+/// assert!(devices[0].is_gamepad());
+/// ```
+pub fn parse_input_devices(content: &str) -> Vec<InputDevice> {
+    let mut devices = Vec::new();
+    for block in content.split("\n\n") {
+        let mut name = None;
+        let mut handlers = Vec::new();
+        let (mut vendor, mut product) = (None, None);
+        for line in block.lines() {
+            if let Some(ids) = line.strip_prefix("I: ") {
+                for field in ids.split_whitespace() {
+                    match field.split_once('=') {
+                        Some(("Vendor", v)) => vendor = u16::from_str_radix(v, 16).ok(),
+                        Some(("Product", p)) => product = u16::from_str_radix(p, 16).ok(),
+                        _ => {}
+                    }
+                }
+            } else if let Some(n) = line.strip_prefix("N: Name=") {
+                name = Some(n.trim_matches('"').to_string());
+            } else if let Some(h) = line.strip_prefix("H: Handlers=") {
+                handlers = h.split_whitespace().map(|s| s.to_string()).collect();
+            }
+        }
+        if let Some(name) = name {
+            devices.push(InputDevice {
+                name,
+                vendor,
+                product,
+                handlers,
+            });
+        }
+    }
+    devices
+}
+
+/// Physical gamepads currently connected.
+pub fn connected_gamepads() -> Vec<InputDevice> {
+    fs::read_to_string("/proc/bus/input/devices")
+        .map(|content| parse_input_devices(&content))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|d| d.is_gamepad() && !d.is_steam_virtual())
+        .collect()
+}
+
+/// Whether the Steam client is running.
+pub fn steam_running() -> bool {
+    let entries = match fs::read_dir("/proc") {
+        Ok(e) => e,
+        Err(_) => return false,
+    };
+    entries.flatten().any(|entry| {
+        fs::read_to_string(entry.path().join("comm"))
+            .map(|comm| matches!(comm.trim(), "steam" | "steamwebhelper"))
+            .unwrap_or(false)
+    })
+}
+
+/// Guidance when Steam Input is likely to intercept a controller for a game
+/// started outside Steam. `appid` is given for Steam games, whose per-game
+/// Steam Input setting is checked and which can be launched via Steam.
+pub fn steam_input_warning(steam_path: Option<&Path>, appid: Option<u32>) -> Option<String> {
+    if let (Some(steam_path), Some(appid)) = (steam_path, appid) {
+        // Steam Input disabled for this game: nothing is intercepted
+        if crate::steam::steam_input_setting(steam_path, appid).as_deref() == Some("0") {
+            return None;
+        }
+    }
+    let gamepads = connected_gamepads();
+    if gamepads.is_empty() || !steam_running() {
+        return None;
+    }
+
+    let names: Vec<&str> = gamepads.iter().map(|d| d.name.as_str()).collect();
+    let mut warning = format!(
+        "Steam is running and may be intercepting your controller ({}) through Steam Input. \
+         Games started outside Steam don't get Steam's virtual controller.\n",
+        names.join(", ")
+    );
+    match appid {
+        Some(_) => warning.push_str(
+            "Launch through Steam with --via-steam, disable Steam Input for this game \
+             (Properties > Controller), or close Steam before launching.",
+        ),
+        None => warning.push_str(
+            "Add the program to Steam as a non-Steam game, or close Steam before launching.",
+        ),
+    }
+    Some(warning)
+}
+
+/// A USB vendor/product ID pair, as in `VID_045E&PID_028E`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DeviceId {
+    pub vendor: u16,
+    pub product: u16,
+}
+
+impl DeviceId {
+    /// Parse the IDs out of a device key name such as
+    /// `VID_045E&PID_028E&MI_00`.
+    ///
+    /// ```
+    /// use protontool::controller::DeviceId;
+    /// let id = DeviceId::from_key_name("VID_045E&PID_028E&IG_00").unwrap();
+    /// assert_eq!((id.vendor, id.product), (0x045e, 0x028e));
+    /// assert!(DeviceId::from_key_name("{some-guid}").is_none());
+    /// ```
+    pub fn from_key_name(name: &str) -> Option<Self> {
+        let mut vendor = None;
+        let mut product = None;
+        for part in name.to_ascii_uppercase().split('&') {
+            if let Some(v) = part.strip_prefix("VID_") {
+                vendor = u16::from_str_radix(v, 16).ok();
+            } else if let Some(p) = part.strip_prefix("PID_") {
+                product = u16::from_str_radix(p, 16).ok();
+            }
+        }
+        Some(DeviceId {
+            vendor: vendor?,
+            product: product?,
+        })
+    }
+}
+
+impl std::fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vendor, self.product)
+    }
+}
+
+impl InputDevice {
+    pub fn id(&self) -> Option<DeviceId> {
+        Some(DeviceId {
+            vendor: self.vendor?,
+            product: self.product?,
+        })
+    }
+}
+
+/// Devices a prefix's winebus has reported, read from its registry files
+/// without running Wine. Sorted, without duplicates.
+pub fn prefix_devices(prefix_path: &Path) -> Vec<DeviceId> {
+    let mut ids: Vec<DeviceId> = DEVICE_ENUM_KEYS
+        .iter()
+        .flat_map(|key| registry::read_prefix_subkeys(prefix_path, key))
+        .filter_map(|name| DeviceId::from_key_name(&name))
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// Number of `/dev/hidraw*` nodes and how many of them this user can open.
+pub fn hidraw_access() -> (usize, usize) {
+    let nodes: Vec<_> = fs::read_dir("/dev")
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.file_name().to_string_lossy().starts_with("hidraw"))
+                .map(|e| e.path())
+                .collect()
+        })
+        .unwrap_or_default();
+    let readable = nodes.iter().filter(|n| fs::File::open(n).is_ok()).count();
+    (nodes.len(), readable)
+}
+
+/// Hints for a prefix that sees fewer controllers than the host has.
+/// `missing` are host gamepads the prefix hasn't reported.
+pub fn controller_hints(prefix_path: &Path, missing: &[&InputDevice]) -> Vec<String> {
+    let mut hints = Vec::new();
+    if missing.is_empty() {
+        return hints;
+    }
+    let setting = |name: &str| {
+        registry::read_prefix_value(prefix_path, WINEBUS_KEY, name)
+            .and_then(|d| u32::from_str_radix(d.strip_prefix("dword:")?, 16).ok())
+    };
+    if setting("Enable SDL") == Some(0) {
+        hints.push(
+            "SDL is disabled for winebus (\"Enable SDL\"=0 under Services\\winebus); \
+             most controllers are only found through SDL"
+                .to_string(),
+        );
+    }
+    if setting("DisableHidraw") == Some(1) {
+        hints.push(
+            "hidraw is disabled for winebus (\"DisableHidraw\"=1); PlayStation and \
+             Switch controllers need it for full support"
+                .to_string(),
+        );
+    }
+    if setting("DisableInput") == Some(1) {
+        hints.push("evdev input is disabled for winebus (\"DisableInput\"=1)".to_string());
+    }
+    let (nodes, readable) = hidraw_access();
+    if nodes > readable {
+        hints.push(format!(
+            "{} of {} /dev/hidraw devices are not accessible; install your \
+             distribution's steam-devices (or game-devices-udev) rules and replug",
+            nodes - readable,
+            nodes
+        ));
+    }
+    if std::env::var_os("SDL_GAMECONTROLLER_IGNORE_DEVICES").is_some()
+        || std::env::var_os("SDL_JOYSTICK_HIDAPI").is_some_and(|v| v == "0")
+    {
+        hints.push(
+            "SDL_GAMECONTROLLER_IGNORE_DEVICES or SDL_JOYSTICK_HIDAPI=0 is set and may \
+             hide controllers from SDL"
+                .to_string(),
+        );
+    }
+    hints.push(
+        "Wine only records controllers it has seen: keep the controller plugged in \
+         while the test window is open"
+            .to_string(),
+    );
+    hints
+}
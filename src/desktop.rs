@@ -0,0 +1,342 @@
+//! Desktop integration: the application entry and icon, and registering
+//! protontool as the handler for Windows executables and installers.
+//!
+//! `--install-desktop-entry` installs `protontool.desktop` and protontool's
+//! icon (a full-color and a symbolic SVG) into the user's hicolor icon
+//! theme, so protontool shows up in app grids and taskbars under its own
+//! name and icon. Icon themes can replace the icon by providing
+//! `protontool`. Dialogs get the same icon as their window icon.
+//!
+//! Registration installs `protontool-file-handler.desktop` in
+//! `~/.local/share/applications/` and makes it the default application for
+//! .exe and .msi MIME types via `xdg-mime`. Opening such a file then asks
+//! for confirmation and runs it in the default prefix (`protontool run`).
+//!
+//! Mod managers such as Mod Organizer 2 receive Nexus Mods downloads through
+//! `nxm://` links. `register_nxm_handler` installs
+//! `protontool-nxm-handler.desktop` for the `x-scheme-handler/nxm` type and
+//! remembers the prefix and handler program in the `nxm-handler` state
+//! record; `protontool nxm URL` then passes the link to that program.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::util::which;
+
+/// Name of protontool's icon in icon themes, for desktop entries and any
+/// GUI backend that looks icons up by name.
+pub const ICON_NAME: &str = "protontool";
+
+/// The application icon.
+const ICON_SVG: &str = include_str!("../assets/protontool.svg");
+
+/// Single-color icon for panels and themes using symbolic icons.
+const SYMBOLIC_ICON_SVG: &str = include_str!("../assets/protontool-symbolic.svg");
+
+/// Desktop entry ID of the application entry.
+pub const APP_ENTRY_ID: &str = "protontool.desktop";
+
+/// Desktop entry ID of the file handler.
+pub const FILE_HANDLER_ID: &str = "protontool-file-handler.desktop";
+
+/// MIME types handled: Windows executables and MSI packages.
+pub const FILE_HANDLER_MIME_TYPES: &[&str] = &[
+    "application/x-ms-dos-executable",
+    "application/x-msdos-program",
+    "application/x-msdownload",
+    "application/x-msi",
+];
+
+/// Desktop entry ID of the nxm:// URL handler.
+pub const NXM_HANDLER_ID: &str = "protontool-nxm-handler.desktop";
+
+/// MIME type of nxm:// links.
+pub const NXM_MIME_TYPE: &str = "x-scheme-handler/nxm";
+
+/// State record holding the registered nxm:// handler:
+/// `prefix<TAB>proton-path<TAB>arch<TAB>program`.
+const NXM_HANDLER_STATE: &str = "nxm-handler";
+
+/// A Windows program inside a prefix that handles nxm:// links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NxmHandler {
+    pub prefix_path: PathBuf,
+    /// Proton installation the prefix runs with.
+    pub proton_path: PathBuf,
+    /// Prefix architecture (`win32` or `win64`).
+    pub arch: String,
+    /// Windows path of the handler program, e.g. MO2's `nxmhandler.exe`.
+    pub program: String,
+}
+
+fn applications_dir() -> Result<PathBuf, String> {
+    let home = std::env::var("HOME").map_err(|_| "HOME not set")?;
+    Ok(PathBuf::from(home).join(".local/share/applications"))
+}
+
+/// The user's hicolor icon theme directory.
+fn icon_theme_dir() -> Result<PathBuf, String> {
+    let home = std::env::var("HOME").map_err(|_| "HOME not set")?;
+    Ok(PathBuf::from(home).join(".local/share/icons/hicolor"))
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Install the icon into the user's hicolor icon theme. Returns the path of
+/// the full-color icon.
+pub fn install_icon() -> Result<PathBuf, String> {
+    let theme = icon_theme_dir()?;
+    let icon = theme.join(format!("scalable/apps/{}.svg", ICON_NAME));
+    write_file(&icon, ICON_SVG)?;
+    write_file(
+        &theme.join(format!("symbolic/apps/{}-symbolic.svg", ICON_NAME)),
+        SYMBOLIC_ICON_SVG,
+    )?;
+    Ok(icon)
+}
+
+/// Icon file for dialog windows: the installed icon, or else a copy kept
+/// in `~/.protontool`.
+pub fn icon_path() -> Option<PathBuf> {
+    if let Ok(theme) = icon_theme_dir() {
+        let installed = theme.join(format!("scalable/apps/{}.svg", ICON_NAME));
+        if installed.is_file() {
+            return Some(installed);
+        }
+    }
+    let copy = crate::config::get_base_dir().join(format!("{}.svg", ICON_NAME));
+    if !copy.is_file() {
+        write_file(&copy, ICON_SVG).ok()?;
+    }
+    Some(copy)
+}
+
+fn app_entry() -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Protontool\n\
+         GenericName=Proton Prefix Manager\n\
+         Comment=Manage Wine/Proton prefixes and install Windows components\n\
+         Exec=protontool --gui --no-term\n\
+         Icon={}\n\
+         Terminal=false\n\
+         Categories=Utility;Game;\n\
+         Keywords=wine;proton;steam;prefix;winetricks;\n",
+        ICON_NAME
+    )
+}
+
+/// Install the application entry and icon. Returns the path of the entry.
+pub fn install_desktop_entry() -> Result<PathBuf, String> {
+    install_icon()?;
+    let dir = applications_dir()?;
+    let entry_path = dir.join(APP_ENTRY_ID);
+    write_file(&entry_path, &app_entry())?;
+    update_desktop_database(&dir);
+    Ok(entry_path)
+}
+
+/// Path of the user's `mimeapps.list` holding default applications.
+fn mimeapps_path() -> Result<PathBuf, String> {
+    if let Ok(config_home) = std::env::var("XDG_CONFIG_HOME") {
+        if !config_home.is_empty() {
+            return Ok(PathBuf::from(config_home).join("mimeapps.list"));
+        }
+    }
+    let home = std::env::var("HOME").map_err(|_| "HOME not set")?;
+    Ok(PathBuf::from(home).join(".config/mimeapps.list"))
+}
+
+fn file_handler_entry() -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Protontool\n\
+         Comment=Run Windows programs and installers in the default Proton prefix\n\
+         Exec=protontool --no-term --confirm run %f\n\
+         Icon={}\n\
+         Terminal=false\n\
+         NoDisplay=true\n\
+         Categories=Utility;\n\
+         MimeType={};\n",
+        ICON_NAME,
+        FILE_HANDLER_MIME_TYPES.join(";")
+    )
+}
+
+fn update_desktop_database(dir: &Path) {
+    if let Some(tool) = which("update-desktop-database") {
+        Command::new(tool).arg(dir).status().ok();
+    }
+}
+
+fn nxm_handler_entry() -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Protontool NXM Handler\n\
+         Comment=Send Nexus Mods downloads to a mod manager in a Proton prefix\n\
+         Exec=protontool --no-term nxm %u\n\
+         Icon={}\n\
+         Terminal=false\n\
+         NoDisplay=true\n\
+         Categories=Game;\n\
+         MimeType={};\n",
+        ICON_NAME, NXM_MIME_TYPE
+    )
+}
+
+/// Install a desktop entry and make it the default for the MIME types.
+/// Returns the path of the installed entry.
+fn install_default_entry(id: &str, content: &str, mime_types: &[&str]) -> Result<PathBuf, String> {
+    install_icon()?;
+    let dir = applications_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let entry_path = dir.join(id);
+    fs::write(&entry_path, content)
+        .map_err(|e| format!("Failed to write {}: {}", entry_path.display(), e))?;
+    update_desktop_database(&dir);
+
+    let xdg_mime = which("xdg-mime").ok_or("xdg-mime is not installed")?;
+    let status = Command::new(xdg_mime)
+        .arg("default")
+        .arg(id)
+        .args(mime_types)
+        .status()
+        .map_err(|e| format!("Failed to run xdg-mime: {}", e))?;
+    if !status.success() {
+        return Err(format!(
+            "xdg-mime exited with code {}",
+            status.code().unwrap_or(-1)
+        ));
+    }
+    Ok(entry_path)
+}
+
+/// Install the file handler desktop entry and make it the default for
+/// .exe and .msi files. Returns the path of the installed entry.
+pub fn register_file_handler() -> Result<PathBuf, String> {
+    install_default_entry(
+        FILE_HANDLER_ID,
+        &file_handler_entry(),
+        FILE_HANDLER_MIME_TYPES,
+    )
+}
+
+/// Make a program in a prefix the handler of nxm:// links, replacing an
+/// earlier registration. Returns the path of the installed entry.
+pub fn register_nxm_handler(handler: &NxmHandler) -> Result<PathBuf, String> {
+    let record = format!(
+        "{}\t{}\t{}\t{}\n",
+        handler.prefix_path.display(),
+        handler.proton_path.display(),
+        handler.arch,
+        handler.program
+    );
+    crate::state::write(NXM_HANDLER_STATE, &record)?;
+    install_default_entry(NXM_HANDLER_ID, &nxm_handler_entry(), &[NXM_MIME_TYPE])
+}
+
+/// The registered nxm:// handler, if any.
+pub fn nxm_handler() -> Option<NxmHandler> {
+    let lines = crate::state::read_lines(NXM_HANDLER_STATE);
+    let mut fields = lines.first()?.split('\t');
+    Some(NxmHandler {
+        prefix_path: PathBuf::from(fields.next()?),
+        proton_path: PathBuf::from(fields.next()?),
+        arch: fields.next()?.to_string(),
+        program: fields.next()?.to_string(),
+    })
+}
+
+/// Remove a desktop entry from the associations in `mimeapps.list` content.
+/// Entries left without any application are dropped.
+///
+/// ```
+/// use protontool::desktop::remove_mime_associations;
+/// let list = "[Default Applications]\napplication/x-msi=protontool-file-handler.desktop\ntext/plain=gedit.desktop\n";
+/// assert_eq!(
+///     remove_mime_associations(list, "protontool-file-handler.desktop"),
+///     "[Default Applications]\ntext/plain=gedit.desktop\n"
+/// );
+/// ```
+pub fn remove_mime_associations(content: &str, desktop_id: &str) -> String {
+    let mut output = String::new();
+    for line in content.lines() {
+        if let Some((mime, apps)) = line.split_once('=') {
+            if !line.trim_start().starts_with('#') && apps.contains(desktop_id) {
+                let remaining: Vec<&str> = apps
+                    .split(';')
+                    .filter(|app| !app.is_empty() && *app != desktop_id)
+                    .collect();
+                if !remaining.is_empty() {
+                    output.push_str(&format!("{}={};\n", mime, remaining.join(";")));
+                }
+                continue;
+            }
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
+/// Remove the file handler desktop entry and its default associations.
+/// Returns false if it wasn't registered.
+pub fn unregister_file_handler() -> Result<bool, String> {
+    let dir = applications_dir()?;
+    let entry_path = dir.join(FILE_HANDLER_ID);
+    let registered = entry_path.exists();
+    if registered {
+        fs::remove_file(&entry_path)
+            .map_err(|e| format!("Failed to remove {}: {}", entry_path.display(), e))?;
+        update_desktop_database(&dir);
+    }
+
+    let mimeapps = mimeapps_path()?;
+    if let Ok(content) = fs::read_to_string(&mimeapps) {
+        if content.contains(FILE_HANDLER_ID) {
+            fs::write(
+                &mimeapps,
+                remove_mime_associations(&content, FILE_HANDLER_ID),
+            )
+            .map_err(|e| format!("Failed to write {}: {}", mimeapps.display(), e))?;
+            return Ok(true);
+        }
+    }
+    Ok(registered)
+}
+
+/// Remove the nxm:// handler desktop entry, its association and the
+/// registration. Returns false if it wasn't registered.
+pub fn unregister_nxm_handler() -> Result<bool, String> {
+    let registered = nxm_handler().is_some();
+    crate::state::write(NXM_HANDLER_STATE, "")?;
+
+    let dir = applications_dir()?;
+    let entry_path = dir.join(NXM_HANDLER_ID);
+    if entry_path.exists() {
+        fs::remove_file(&entry_path)
+            .map_err(|e| format!("Failed to remove {}: {}", entry_path.display(), e))?;
+        update_desktop_database(&dir);
+    }
+    let mimeapps = mimeapps_path()?;
+    if let Ok(content) = fs::read_to_string(&mimeapps) {
+        if content.contains(NXM_HANDLER_ID) {
+            fs::write(
+                &mimeapps,
+                remove_mime_associations(&content, NXM_HANDLER_ID),
+            )
+            .map_err(|e| format!("Failed to write {}: {}", mimeapps.display(), e))?;
+        }
+    }
+    Ok(registered)
+}
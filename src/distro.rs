@@ -0,0 +1,183 @@
+//! Host distribution detection for package installation hints.
+//!
+//! Missing host components (GStreamer plugins, tools) are easy to fix once
+//! the user knows the package name, and package names differ between
+//! distribution families. The family is read from `/etc/os-release` (`ID`,
+//! then `ID_LIKE`, so derivatives like Manjaro or Pop!_OS are covered), and
+//! packages can be installed through `pkexec` where polkit is available.
+
+use std::fs;
+use std::process::Command;
+
+use crate::util::which;
+
+/// A family of distributions sharing a package manager and package names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Family {
+    Arch,
+    Debian,
+    Fedora,
+    Suse,
+}
+
+impl Family {
+    /// Family of an os-release `ID` or `ID_LIKE` word.
+    fn from_id(id: &str) -> Option<Family> {
+        match id {
+            "arch" | "steamos" | "manjaro" | "endeavouros" => Some(Family::Arch),
+            "debian" | "ubuntu" | "linuxmint" | "pop" => Some(Family::Debian),
+            "fedora" | "rhel" | "centos" | "nobara" => Some(Family::Fedora),
+            "suse" | "opensuse" | "opensuse-tumbleweed" | "opensuse-leap" => Some(Family::Suse),
+            _ => None,
+        }
+    }
+
+    /// Package manager command installing packages, without `sudo`.
+    pub fn install_args(&self) -> &'static [&'static str] {
+        match self {
+            Family::Arch => &["pacman", "-S", "--needed"],
+            Family::Debian => &["apt", "install"],
+            Family::Fedora => &["dnf", "install"],
+            Family::Suse => &["zypper", "install"],
+        }
+    }
+
+    /// Arguments that make the package manager install without asking;
+    /// polkit's authentication prompt is the confirmation.
+    fn assume_yes(&self) -> &'static str {
+        match self {
+            Family::Arch => "--noconfirm",
+            Family::Debian | Family::Fedora => "-y",
+            Family::Suse => "--non-interactive",
+        }
+    }
+
+    /// Shell command installing packages.
+    ///
+    /// ```
+    /// use protontool::distro::Family;
+    /// assert_eq!(
+    ///     Family::Debian.install_command(&["gstreamer1.0-libav"]),
+    ///     "sudo apt install gstreamer1.0-libav"
+    /// );
+    /// ```
+    pub fn install_command(&self, packages: &[&str]) -> String {
+        let mut command = vec!["sudo"];
+        command.extend(self.install_args());
+        command.extend(packages);
+        command.join(" ")
+    }
+
+    /// Install packages as root through `pkexec`.
+    pub fn install_with_pkexec(&self, packages: &[String]) -> Result<(), String> {
+        let pkexec = which("pkexec").ok_or("pkexec is not installed")?;
+        let status = Command::new(pkexec)
+            .args(self.install_args())
+            .arg(self.assume_yes())
+            .args(packages)
+            .status()
+            .map_err(|e| format!("Failed to run pkexec: {}", e))?;
+        if !status.success() {
+            return Err(format!("{} exited with {}", self.install_args()[0], status));
+        }
+        Ok(())
+    }
+}
+
+/// A host program protontool uses, and the package providing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostTool {
+    pub command: &'static str,
+    pub purpose: &'static str,
+    /// Package on Arch, Debian, Fedora and openSUSE.
+    packages: [&'static str; 4],
+}
+
+impl HostTool {
+    pub fn package(&self, family: Family) -> &'static str {
+        match family {
+            Family::Arch => self.packages[0],
+            Family::Debian => self.packages[1],
+            Family::Fedora => self.packages[2],
+            Family::Suse => self.packages[3],
+        }
+    }
+}
+
+/// Host programs `doctor` checks for.
+pub const HOST_TOOLS: &[HostTool] = &[
+    HostTool {
+        command: "cabextract",
+        purpose: "extracting DirectX cabinets (d3dx9, xinput, d3dcompiler verbs)",
+        packages: ["cabextract", "cabextract", "cabextract", "cabextract"],
+    },
+    HostTool {
+        command: "7z",
+        purpose: "extracting self-extracting installers and 7z archives",
+        packages: ["7zip", "p7zip-full", "p7zip-plugins", "7zip"],
+    },
+    HostTool {
+        command: "unzip",
+        purpose: "extracting zip downloads",
+        packages: ["unzip", "unzip", "unzip", "unzip"],
+    },
+    HostTool {
+        command: "vulkaninfo",
+        purpose: "checking the Vulkan driver DXVK and VKD3D-Proton run on",
+        packages: [
+            "vulkan-tools",
+            "vulkan-tools",
+            "vulkan-tools",
+            "vulkan-tools",
+        ],
+    },
+];
+
+/// Host tools that aren't installed.
+pub fn missing_tools() -> Vec<&'static HostTool> {
+    HOST_TOOLS
+        .iter()
+        .filter(|tool| which(tool.command).is_none())
+        .collect()
+}
+
+/// The host distribution.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Distro {
+    pub id: String,
+    pub name: String,
+    pub family: Option<Family>,
+}
+
+/// Parse `/etc/os-release` content.
+///
+/// ```
+/// use protontool::distro::{parse_os_release, Family};
+/// let distro = parse_os_release("NAME=\"Pop!_OS\"\nID=pop\nID_LIKE=\"ubuntu debian\"\n");
+/// assert_eq!(distro.family, Some(Family::Debian));
+/// assert_eq!(parse_os_release("ID=gentoo\n").family, None);
+/// ```
+pub fn parse_os_release(content: &str) -> Distro {
+    let value = |key: &str| {
+        content.lines().find_map(|line| {
+            let rest = line.strip_prefix(key)?.strip_prefix('=')?;
+            Some(rest.trim().trim_matches('"').to_string())
+        })
+    };
+    let id = value("ID").unwrap_or_default();
+    let family = std::iter::once(id.as_str())
+        .chain(value("ID_LIKE").unwrap_or_default().split_whitespace())
+        .find_map(Family::from_id);
+    Distro {
+        name: value("PRETTY_NAME").unwrap_or_else(|| id.clone()),
+        id,
+        family,
+    }
+}
+
+/// Detect the host distribution.
+pub fn detect() -> Distro {
+    fs::read_to_string("/etc/os-release")
+        .map(|content| parse_os_release(&content))
+        .unwrap_or_default()
+}
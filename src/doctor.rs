@@ -0,0 +1,338 @@
+//! Health checks for a prefix and the system it runs on.
+//!
+//! `protontool APPID doctor` runs the checks and reports what it finds;
+//! `doctor fix` also applies the fixes that can be made inside the prefix.
+//! Problems outside the prefix, like a host clock that is off, are reported
+//! with a hint instead.
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::distro::{self, Family};
+use crate::util::which;
+use crate::wine::access;
+use crate::wine::filesystem;
+use crate::wine::locale;
+use crate::wine::media;
+use crate::wine::WineContext;
+
+/// Server whose `Date` header the host clock is compared with.
+const TIME_REFERENCE_URL: &str = "https://store.steampowered.com/";
+
+/// Clock differences up to this many seconds are fine; TLS and sign-in
+/// tokens usually allow a few minutes.
+const MAX_CLOCK_SKEW_SECS: i64 = 120;
+
+/// How bad a finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Ok,
+    Warning,
+    Problem,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Ok => "ok",
+            Severity::Warning => "warning",
+            Severity::Problem => "problem",
+        }
+    }
+}
+
+/// A fix that can be applied inside the prefix, or a host package install
+/// the user authorizes through polkit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fix {
+    /// Set the prefix time zone to a Windows zone and UTC offset (minutes).
+    TimeZone { zone: String, utc_offset: i32 },
+    /// Install missing host packages through `pkexec`.
+    InstallPackages {
+        family: Family,
+        packages: Vec<String>,
+    },
+}
+
+impl Fix {
+    pub fn describe(&self) -> String {
+        match self {
+            Fix::TimeZone { zone, .. } => format!("set the prefix time zone to {}", zone),
+            Fix::InstallPackages { packages, .. } => {
+                format!("install {} (with pkexec)", packages.join(", "))
+            }
+        }
+    }
+
+    pub fn apply(&self, wine_ctx: &WineContext) -> Result<(), String> {
+        match self {
+            Fix::TimeZone { zone, utc_offset } => locale::set_timezone(wine_ctx, zone, *utc_offset),
+            Fix::InstallPackages { family, packages } => family.install_with_pkexec(packages),
+        }
+    }
+}
+
+/// Result of one check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub check: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+impl Finding {
+    fn ok(check: &'static str, message: String) -> Self {
+        Finding {
+            check,
+            severity: Severity::Ok,
+            message,
+            fix: None,
+        }
+    }
+}
+
+/// Run all checks on a prefix.
+pub fn check_prefix(wine_ctx: &WineContext) -> Vec<Finding> {
+    let prefix_path = &wine_ctx.prefix_path;
+    let mut findings = vec![check_clock(), check_timezone(prefix_path), check_tools()];
+    findings.extend(check_filesystems(prefix_path));
+    findings.extend(access::check(wine_ctx));
+    findings.extend(media::check(wine_ctx));
+    findings
+}
+
+/// A fix installing host packages, where the distribution is known and
+/// `pkexec` can run the package manager.
+pub fn install_fix(family: Option<Family>, packages: Vec<String>) -> Option<Fix> {
+    let family = family?;
+    if packages.is_empty() || which("pkexec").is_none() {
+        return None;
+    }
+    Some(Fix::InstallPackages { family, packages })
+}
+
+/// Check for the host programs verbs and diagnostics use, with the command
+/// installing missing ones.
+fn check_tools() -> Finding {
+    let missing = distro::missing_tools();
+    if missing.is_empty() {
+        let commands: Vec<&str> = distro::HOST_TOOLS.iter().map(|t| t.command).collect();
+        return Finding::ok("tools", format!("{} are installed", commands.join(", ")));
+    }
+    let lines: Vec<String> = missing
+        .iter()
+        .map(|tool| format!("{} (for {})", tool.command, tool.purpose))
+        .collect();
+    let family = distro::detect().family;
+    let hint = match family {
+        Some(family) => {
+            let packages: Vec<&str> = missing.iter().map(|tool| tool.package(family)).collect();
+            format!("Install with: {}", family.install_command(&packages))
+        }
+        None => "Install them with your package manager".to_string(),
+    };
+    let packages = family.map_or_else(Vec::new, |family| {
+        missing
+            .iter()
+            .map(|tool| tool.package(family).to_string())
+            .collect()
+    });
+    Finding {
+        check: "tools",
+        severity: Severity::Warning,
+        message: format!("Missing host tools:\n  {}\n{}", lines.join("\n  "), hint),
+        fix: install_fix(family, packages),
+    }
+}
+
+/// Check the filesystems of the prefix (probed for symlink and case
+/// support) and, for a Steam prefix, of the library holding it.
+fn check_filesystems(prefix_path: &Path) -> Vec<Finding> {
+    let library = prefix_path
+        .ancestors()
+        .find(|dir| dir.file_name().is_some_and(|name| name == "steamapps"))
+        .and_then(Path::parent);
+    let reports = [
+        filesystem::check(prefix_path, true),
+        library.and_then(|library| filesystem::check(library, false)),
+    ];
+    reports
+        .into_iter()
+        .flatten()
+        .map(|report| Finding {
+            check: "filesystem",
+            severity: if report.problems.is_empty() {
+                Severity::Ok
+            } else {
+                Severity::Problem
+            },
+            message: report.summary(),
+            fix: None,
+        })
+        .collect()
+}
+
+/// Compare the host clock with a server's `Date` header.
+fn check_clock() -> Finding {
+    let Some(server_time) = reference_time() else {
+        return Finding {
+            check: "clock",
+            severity: Severity::Warning,
+            message: format!(
+                "Could not read the time from {} (offline or curl missing)",
+                TIME_REFERENCE_URL
+            ),
+            fix: None,
+        };
+    };
+    let local_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let skew = local_time - server_time;
+    if skew.abs() <= MAX_CLOCK_SKEW_SECS {
+        return Finding::ok("clock", format!("System clock is within {}s", skew.abs()));
+    }
+    Finding {
+        check: "clock",
+        severity: Severity::Problem,
+        message: format!(
+            "System clock is {}s {} (enable time sync: timedatectl set-ntp true)",
+            skew.abs(),
+            if skew > 0 { "ahead" } else { "behind" }
+        ),
+        fix: None,
+    }
+}
+
+/// Compare the prefix time zone with the host's.
+fn check_timezone(prefix_path: &Path) -> Finding {
+    let prefix_zone = locale::prefix_timezone(prefix_path);
+    let host_offset = locale::host_utc_offset();
+    let host_zone = locale::host_timezone();
+    let windows_zone = host_zone.as_deref().and_then(locale::windows_zone);
+
+    let name_matches = match (&prefix_zone.key_name, windows_zone) {
+        (Some(prefix), Some(host)) => prefix.eq_ignore_ascii_case(host),
+        _ => true,
+    };
+    let offset_matches = match (prefix_zone.utc_offset(), host_offset) {
+        (Some(prefix), Some(host)) => prefix == host,
+        _ => true,
+    };
+    let describe = |offset: Option<i32>| match offset {
+        Some(offset) => format!(
+            "UTC{}{:02}:{:02}",
+            if offset < 0 { '-' } else { '+' },
+            offset.abs() / 60,
+            offset.abs() % 60
+        ),
+        None => "unknown offset".to_string(),
+    };
+    if name_matches && offset_matches {
+        return Finding::ok(
+            "timezone",
+            format!(
+                "Prefix time zone {} ({}) matches the host",
+                prefix_zone.key_name.as_deref().unwrap_or("(unset)"),
+                describe(prefix_zone.utc_offset())
+            ),
+        );
+    }
+
+    let fix = match (windows_zone, host_offset) {
+        (Some(zone), Some(utc_offset)) => Some(Fix::TimeZone {
+            zone: zone.to_string(),
+            utc_offset,
+        }),
+        _ => None,
+    };
+    let mut message = format!(
+        "Prefix time zone is {} ({}), the host uses {} ({})",
+        prefix_zone.key_name.as_deref().unwrap_or("(unset)"),
+        describe(prefix_zone.utc_offset()),
+        host_zone.as_deref().unwrap_or("an unknown zone"),
+        describe(host_offset)
+    );
+    if fix.is_none() {
+        message.push_str("; no matching Windows zone is known, set it with winecfg");
+    }
+    Finding {
+        check: "timezone",
+        severity: Severity::Warning,
+        message,
+        fix,
+    }
+}
+
+/// Unix time from the reference server's `Date` header.
+fn reference_time() -> Option<i64> {
+    let curl = crate::util::which("curl")?;
+    let output = Command::new(curl)
+        .args(["-sI", "--max-time", "5", TIME_REFERENCE_URL])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("date")
+                .then(|| parse_http_date(value.trim()))
+                .flatten()
+        })
+}
+
+/// Parse an HTTP date (`Fri, 16 Oct 2026 12:34:56 GMT`) into Unix time.
+///
+/// ```
+/// use protontool::doctor::parse_http_date;
+/// assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:01:40 GMT"), Some(100));
+/// assert_eq!(parse_http_date("Fri, 16 Oct 2026 12:00:00 GMT"), Some(1792152000));
+/// ```
+pub fn parse_http_date(value: &str) -> Option<i64> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut fields = value.split_whitespace().skip(1);
+    let day: i64 = fields.next()?.parse().ok()?;
+    let month_name = fields.next()?;
+    let month = MONTHS.iter().position(|m| *m == month_name)? as i64 + 1;
+    let year: i64 = fields.next()?.parse().ok()?;
+    let mut time = fields.next()?.split(':').map(|t| t.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+
+    // Days since 1970-01-01 (Howard Hinnant's days_from_civil)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    Some(days * 86400 + hour * 3600 + minute * 60 + second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_description() {
+        let fix = Fix::TimeZone {
+            zone: "W. Europe Standard Time".to_string(),
+            utc_offset: 120,
+        };
+        assert_eq!(
+            fix.describe(),
+            "set the prefix time zone to W. Europe Standard Time"
+        );
+        assert_eq!(Severity::Problem.label(), "problem");
+        assert_eq!(
+            parse_http_date("Mon, 29 Feb 2032 00:00:00 GMT"),
+            Some(1961625600)
+        );
+        assert_eq!(parse_http_date("not a date"), None);
+    }
+}
@@ -0,0 +1,60 @@
+//! Running inside a Flatpak sandbox.
+//!
+//! A Flatpak-packaged protontool only sees the host paths its permissions
+//! grant. Paths it needs (the Steam directory, library folders) are checked
+//! up front, and missing access is reported with the `flatpak override`
+//! command that grants it, instead of failing later with EACCES or
+//! silently finding no games.
+
+use std::fs;
+use std::path::Path;
+
+/// File present at the root of every Flatpak sandbox.
+const FLATPAK_INFO: &str = "/.flatpak-info";
+
+/// Whether protontool runs inside a Flatpak sandbox.
+pub fn is_sandboxed() -> bool {
+    Path::new(FLATPAK_INFO).exists()
+}
+
+/// Application ID of the sandbox, from `FLATPAK_ID` or `/.flatpak-info`.
+pub fn app_id() -> Option<String> {
+    if let Ok(id) = std::env::var("FLATPAK_ID") {
+        if !id.is_empty() {
+            return Some(id);
+        }
+    }
+    let info = fs::read_to_string(FLATPAK_INFO).ok()?;
+    info.lines()
+        .skip_while(|line| line.trim() != "[Application]")
+        .find_map(|line| line.trim().strip_prefix("name="))
+        .map(|name| name.trim().to_string())
+}
+
+/// Whether a directory can be listed from here.
+pub fn is_accessible(path: &Path) -> bool {
+    fs::read_dir(path).is_ok()
+}
+
+/// The `flatpak override` command granting an app access to paths.
+///
+/// ```
+/// use std::path::Path;
+/// use protontool::flatpak::override_command;
+/// assert_eq!(
+///     override_command("com.example.App", &[Path::new("/mnt/My Games")]),
+///     "flatpak override --user --filesystem='/mnt/My Games' com.example.App"
+/// );
+/// ```
+pub fn override_command(app_id: &str, paths: &[&Path]) -> String {
+    let mut command = String::from("flatpak override --user");
+    for path in paths {
+        command.push_str(&format!(
+            " --filesystem={}",
+            crate::util::shell_quote(&path.to_string_lossy())
+        ));
+    }
+    command.push(' ');
+    command.push_str(app_id);
+    command
+}
@@ -9,7 +9,7 @@ use std::process::{Command, Stdio};
 use crate::config;
 use crate::steam::{ProtonApp, SteamApp, SteamInstallation};
 use crate::util::{output_to_string, which};
-use crate::wine::{Verb, VerbCategory};
+use crate::wine::{Verb, VerbCategory, VerbRegistry};
 
 /// Find an available GUI dialog tool (zenity or yad).
 /// Checks environment override first, then falls back to defaults.
@@ -26,10 +26,20 @@ pub fn get_gui_tool() -> Option<std::path::PathBuf> {
     None
 }
 
+/// A command running a dialog tool (zenity or yad) with protontool's icon
+/// as the window icon.
+pub fn dialog(tool: impl AsRef<std::ffi::OsStr>) -> Command {
+    let mut command = Command::new(tool);
+    if let Some(icon) = crate::desktop::icon_path() {
+        command.arg(format!("--window-icon={}", icon.display()));
+    }
+    command
+}
+
 /// Display a scrollable text dialog for showing logs or error messages.
 pub fn show_text_dialog(title: &str, text: &str) {
     if let Some(zenity) = which("zenity") {
-        let _ = Command::new(zenity)
+        let _ = dialog(zenity)
             .args([
                 "--text-info",
                 "--title",
@@ -49,7 +59,7 @@ pub fn show_text_dialog(title: &str, text: &str) {
                 child.wait()
             });
     } else if let Some(yad) = which("yad") {
-        let _ = Command::new(yad)
+        let _ = dialog(yad)
             .args([
                 "--text-info",
                 "--title",
@@ -74,9 +84,80 @@ pub fn show_text_dialog(title: &str, text: &str) {
     }
 }
 
-/// Prompt for filesystem access (no-op on native Linux, used for Flatpak).
-pub fn prompt_filesystem_access(_paths: &[&Path], _show_dialog: bool) {
-    // On native Linux without Flatpak, no filesystem access prompts are needed
+/// Ask a yes/no question. Returns false if declined or no dialog tool is available.
+pub fn ask_question_gui(title: &str, text: &str) -> bool {
+    let gui_tool = match get_gui_tool() {
+        Some(t) => t,
+        None => return false,
+    };
+    dialog(gui_tool)
+        .args([
+            "--question",
+            "--title",
+            title,
+            "--text",
+            text,
+            "--width",
+            "500",
+        ])
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// Ask the user to pick one of several choices. Returns None if cancelled
+/// or no dialog tool is available.
+pub fn ask_choice_gui(title: &str, text: &str, choices: &[&str]) -> Option<String> {
+    let gui_tool = get_gui_tool()?;
+    let mut args = vec![
+        "--list", "--title", title, "--text", text, "--column", "Action", "--width", "500",
+        "--height", "300",
+    ];
+    args.extend(choices);
+    let output = dialog(gui_tool).args(&args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let selected = output_to_string(&output);
+    choices
+        .iter()
+        .find(|choice| **choice == selected)
+        .map(|choice| choice.to_string())
+}
+
+/// Check that paths outside a Flatpak sandbox are accessible. For paths
+/// that aren't, print (and with `show_dialog` also show) the
+/// `flatpak override` command granting access. Returns false if a path is
+/// inaccessible; always true on native Linux.
+pub fn prompt_filesystem_access(paths: &[&Path], show_dialog: bool) -> bool {
+    if !crate::flatpak::is_sandboxed() {
+        return true;
+    }
+    let missing: Vec<&Path> = paths
+        .iter()
+        .copied()
+        .filter(|path| !crate::flatpak::is_accessible(path))
+        .collect();
+    if missing.is_empty() {
+        return true;
+    }
+
+    let app_id = crate::flatpak::app_id().unwrap_or_else(|| "APP_ID".to_string());
+    let listed: Vec<String> = missing
+        .iter()
+        .map(|path| format!("  {}", path.display()))
+        .collect();
+    let message = format!(
+        "protontool runs in a Flatpak sandbox without access to:\n{}\n\n\
+         Grant access by running this outside the sandbox, then restart protontool:\n  {}",
+        listed.join("\n"),
+        crate::flatpak::override_command(&app_id, &missing)
+    );
+    eprintln!("{}", message);
+    if show_dialog {
+        show_text_dialog("Filesystem Access Needed", &message);
+    }
+    false
 }
 
 /// Prompt user to add additional Steam library paths via GUI.
@@ -101,7 +182,7 @@ pub fn select_steam_library_paths() -> Vec<PathBuf> {
         };
 
         // Show dialog with Add and Next buttons
-        let output = Command::new(&gui_tool)
+        let output = dialog(&gui_tool)
             .args([
                 "--question",
                 "--title",
@@ -124,7 +205,7 @@ pub fn select_steam_library_paths() -> Vec<PathBuf> {
         match output {
             Ok(status) if status.success() => {
                 // User clicked "Add Path", show directory picker
-                let dir_output = Command::new(&gui_tool)
+                let dir_output = dialog(&gui_tool)
                     .args([
                         "--file-selection",
                         "--directory",
@@ -146,7 +227,7 @@ pub fn select_steam_library_paths() -> Vec<PathBuf> {
                                 }
                             } else {
                                 // Warn user this doesn't look like a Steam library
-                                let _ = Command::new(&gui_tool)
+                                let _ = dialog(&gui_tool)
                                     .args([
                                         "--warning",
                                         "--title", "Invalid Path",
@@ -191,6 +272,8 @@ pub fn select_steam_installation(installations: &[SteamInstallation]) -> Option<
         "--list".to_string(),
         "--title".to_string(),
         "Select Steam installation".to_string(),
+        "--text".to_string(),
+        "Select Steam installation".to_string(),
         "--column".to_string(),
         "Steam Path".to_string(),
     ];
@@ -199,7 +282,7 @@ pub fn select_steam_installation(installations: &[SteamInstallation]) -> Option<
         args.push(inst.steam_path.to_string_lossy().to_string());
     }
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+    let output = dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -214,11 +297,12 @@ pub fn select_steam_installation(installations: &[SteamInstallation]) -> Option<
 }
 
 /// Show a list dialog to select a Steam app.
-/// Filters to only show Windows apps, sorted alphabetically.
+/// Filters to only show Windows apps, sorted alphabetically, with a status
+/// column summarizing applied verbs and outdated DXVK/Proton.
 pub fn select_steam_app_with_gui(
     steam_apps: &[SteamApp],
     title: Option<&str>,
-    _steam_path: &Path,
+    steam_path: &Path,
 ) -> Option<SteamApp> {
     let gui_tool = get_gui_tool()?;
 
@@ -228,27 +312,58 @@ pub fn select_steam_app_with_gui(
         "--list".to_string(),
         "--title".to_string(),
         title.to_string(),
+        "--text".to_string(),
+        title.to_string(),
         "--column".to_string(),
         "App ID".to_string(),
         "--column".to_string(),
         "Name".to_string(),
+        "--column".to_string(),
+        "Status".to_string(),
+        "--column".to_string(),
+        "Last used".to_string(),
+        "--column".to_string(),
+        "Notes".to_string(),
         "--print-column".to_string(),
         "1".to_string(),
     ];
 
-    let mut windows_apps: Vec<_> = steam_apps
+    let statuses = crate::status::scan_app_status(steam_path, steam_apps);
+
+    let windows_apps: Vec<_> = steam_apps
         .iter()
         .filter(|app| app.is_windows_app())
         .collect();
 
-    windows_apps.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
-
-    for app in &windows_apps {
+    // Most recently used first, then by name
+    let now = crate::wine::usage::now_secs();
+    let mut rows: Vec<_> = windows_apps
+        .iter()
+        .map(|app| {
+            let usage = app
+                .prefix_path
+                .as_deref()
+                .map(crate::wine::usage::usage)
+                .unwrap_or_default();
+            (app, usage)
+        })
+        .collect();
+    rows.sort_by(|(a, a_usage), (b, b_usage)| {
+        b_usage
+            .last_used
+            .cmp(&a_usage.last_used)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    for (app, usage) in &rows {
         args.push(app.appid.to_string());
         args.push(app.name.clone());
+        args.push(statuses.get(&app.appid).cloned().unwrap_or_default());
+        args.push(crate::wine::usage::describe_age(usage.age(now)));
+        args.push(crate::notes::get(&crate::notes::Subject::App(app.appid)).summary());
     }
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+    let output = dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -264,7 +379,11 @@ pub fn select_steam_app_with_gui(
 
 /// Show a checklist dialog to select multiple verbs.
 /// Returns list of selected verb names.
-pub fn select_verbs_with_gui(verbs: &[&Verb], title: Option<&str>) -> Vec<String> {
+pub fn select_verbs_with_gui(
+    registry: &VerbRegistry,
+    verbs: &[&Verb],
+    title: Option<&str>,
+) -> Vec<String> {
     let gui_tool = match get_gui_tool() {
         Some(tool) => tool,
         None => return vec![],
@@ -276,6 +395,8 @@ pub fn select_verbs_with_gui(verbs: &[&Verb], title: Option<&str>) -> Vec<String
         "--list".to_string(),
         "--title".to_string(),
         title.to_string(),
+        "--text".to_string(),
+        title.to_string(),
         "--checklist".to_string(),
         "--column".to_string(),
         "".to_string(),
@@ -297,12 +418,12 @@ pub fn select_verbs_with_gui(verbs: &[&Verb], title: Option<&str>) -> Vec<String
 
     for verb in verbs {
         args.push("FALSE".to_string()); // checkbox state
-        args.push(verb.name.clone());
+        args.push(registry.reference(verb));
         args.push(verb.category.as_str().to_string());
         args.push(verb.title.clone());
     }
 
-    let output = match Command::new(&gui_tool).args(&args).output() {
+    let output = match dialog(&gui_tool).args(&args).output() {
         Ok(out) => out,
         Err(_) => return vec![],
     };
@@ -317,7 +438,7 @@ pub fn select_verbs_with_gui(verbs: &[&Verb], title: Option<&str>) -> Vec<String
         .collect()
 }
 
-/// Show a menu to select a verb category (dlls, fonts, settings, apps).
+/// Show a menu to select a verb category (dlls, fonts, settings, apps, gamefixes).
 pub fn select_verb_category_gui() -> Option<VerbCategory> {
     let gui_tool = get_gui_tool()?;
 
@@ -325,6 +446,8 @@ pub fn select_verb_category_gui() -> Option<VerbCategory> {
         "--list",
         "--title",
         "Select category",
+        "--text",
+        "Select category",
         "--column",
         "Category",
         "--column",
@@ -339,9 +462,11 @@ pub fn select_verb_category_gui() -> Option<VerbCategory> {
         "Change Wine settings",
         "apps",
         "Install applications",
+        "gamefixes",
+        "Apply per-game fixes",
     ];
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+    let output = dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -354,6 +479,7 @@ pub fn select_verb_category_gui() -> Option<VerbCategory> {
         "fonts" => Some(VerbCategory::Font),
         "settings" => Some(VerbCategory::Setting),
         "apps" => Some(VerbCategory::App),
+        "gamefixes" => Some(VerbCategory::GameFix),
         _ => None,
     }
 }
@@ -364,7 +490,7 @@ pub fn select_proton_with_gui(proton_apps: &[ProtonApp]) -> Option<ProtonApp> {
     let gui_tool = get_gui_tool()?;
 
     if proton_apps.is_empty() {
-        let _ = Command::new(&gui_tool)
+        let _ = dialog(&gui_tool)
             .args([
                 "--error",
                 "--title",
@@ -382,6 +508,8 @@ pub fn select_proton_with_gui(proton_apps: &[ProtonApp]) -> Option<ProtonApp> {
         "--list".to_string(),
         "--title".to_string(),
         "Select Proton version".to_string(),
+        "--text".to_string(),
+        "Select Proton version".to_string(),
         "--column".to_string(),
         "Name".to_string(),
         "--column".to_string(),
@@ -406,7 +534,7 @@ pub fn select_proton_with_gui(proton_apps: &[ProtonApp]) -> Option<ProtonApp> {
         });
     }
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+    let output = dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -424,7 +552,7 @@ pub fn select_proton_with_gui(proton_apps: &[ProtonApp]) -> Option<ProtonApp> {
 pub fn get_prefix_name_gui() -> Option<String> {
     let gui_tool = get_gui_tool()?;
 
-    let output = Command::new(&gui_tool)
+    let output = dialog(&gui_tool)
         .args([
             "--entry",
             "--title",
@@ -463,7 +591,7 @@ pub fn select_prefix_location_gui(default_name: &str) -> Option<PathBuf> {
         .to_string_lossy()
         .to_string();
 
-    let question = Command::new(&gui_tool)
+    let question = dialog(&gui_tool)
         .args([
             "--question",
             "--title", "Prefix Location",
@@ -482,7 +610,7 @@ pub fn select_prefix_location_gui(default_name: &str) -> Option<PathBuf> {
         }
         _ => {
             // User wants to pick custom location
-            let output = Command::new(&gui_tool)
+            let output = dialog(&gui_tool)
                 .args([
                     "--file-selection",
                     "--directory",
@@ -515,6 +643,7 @@ pub enum GuiAction {
     CreatePrefix,
     DeletePrefix,
     ManagePrefix,
+    GameNotes,
 }
 
 /// Show the main menu for GUI mode and return the selected action.
@@ -545,9 +674,11 @@ pub fn show_main_menu_gui() -> Option<GuiAction> {
         "Delete a custom prefix",
         "prefix",
         "Manage an existing custom prefix",
+        "notes",
+        "Edit a game's notes and tags",
     ];
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+    let output = dialog(&gui_tool).args(&args).output().ok()?;
 
     if !output.status.success() {
         return None;
@@ -560,70 +691,133 @@ pub fn show_main_menu_gui() -> Option<GuiAction> {
         "create" => Some(GuiAction::CreatePrefix),
         "delete" => Some(GuiAction::DeletePrefix),
         "prefix" => Some(GuiAction::ManagePrefix),
+        "notes" => Some(GuiAction::GameNotes),
         _ => None,
     }
 }
 
-/// Show a list dialog to select from existing custom prefixes.
-/// Lists all subdirectories in the prefixes directory.
+/// Path column value of the "browse" row in the prefix list.
+const BROWSE_ROW: &str = "(browse)";
+
+/// Show a list dialog to select from existing custom prefixes, most
+/// recently used first. Lists the prefixes directory and the extra roots
+/// (see [`crate::wine::discovery`]), with a row to browse for a prefix
+/// elsewhere; the browsed-to location is remembered as a root.
 pub fn select_custom_prefix_gui(prefixes_dir: &Path) -> Option<PathBuf> {
+    use crate::wine::discovery;
+
     let gui_tool = get_gui_tool()?;
 
-    // List subdirectories in the prefixes directory
-    let entries: Vec<_> = std::fs::read_dir(prefixes_dir)
-        .ok()?
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_dir())
-        .collect();
+    loop {
+        let mut entries = discovery::discover(prefixes_dir, &config::UserConfig::load());
+        crate::wine::usage::sort_by_last_used(&mut entries);
 
-    if entries.is_empty() {
-        let _ = Command::new(&gui_tool)
-            .args([
-                "--info",
-                "--title",
-                "No Prefixes Found",
-                "--text",
-                "No custom prefixes found.\n\nUse 'Create a new custom prefix' to create one.",
-                "--width",
-                "400",
-            ])
-            .status();
-        return None;
-    }
+        let text = if entries.is_empty() {
+            "No custom prefixes found. Browse for one, or use 'Create a new custom prefix'."
+        } else {
+            "Select a custom prefix"
+        };
+        let mut args = vec![
+            "--list".to_string(),
+            "--title".to_string(),
+            "Select a custom prefix".to_string(),
+            "--text".to_string(),
+            text.to_string(),
+            "--column".to_string(),
+            "Name".to_string(),
+            "--column".to_string(),
+            "Path".to_string(),
+            "--column".to_string(),
+            "Last used".to_string(),
+            "--column".to_string(),
+            "Notes".to_string(),
+            "--print-column".to_string(),
+            "2".to_string(),
+            "--width".to_string(),
+            "800".to_string(),
+            "--height".to_string(),
+            "400".to_string(),
+        ];
+
+        let now = crate::wine::usage::now_secs();
+        for entry in &entries {
+            let name = entry
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let path = entry.to_string_lossy().to_string();
+            let usage = crate::wine::usage::usage(entry);
+            args.push(name);
+            args.push(path);
+            args.push(crate::wine::usage::describe_age(usage.age(now)));
+            args.push(crate::notes::get(&crate::notes::Subject::Prefix(entry)).summary());
+        }
+        args.extend([
+            "Browse for prefix...".to_string(),
+            BROWSE_ROW.to_string(),
+            String::new(),
+            String::new(),
+        ]);
 
-    let mut args = vec![
-        "--list".to_string(),
-        "--title".to_string(),
-        "Select a custom prefix".to_string(),
-        "--column".to_string(),
-        "Name".to_string(),
-        "--column".to_string(),
-        "Path".to_string(),
-        "--print-column".to_string(),
-        "2".to_string(),
-        "--width".to_string(),
-        "600".to_string(),
-        "--height".to_string(),
-        "400".to_string(),
-    ];
+        let output = dialog(&gui_tool).args(&args).output().ok()?;
 
-    for entry in &entries {
-        let name = entry.file_name().to_string_lossy().to_string();
-        let path = entry.path().to_string_lossy().to_string();
-        args.push(name);
-        args.push(path);
-    }
+        if !output.status.success() {
+            return None;
+        }
 
-    let output = Command::new(&gui_tool).args(&args).output().ok()?;
+        let selected = output_to_string(&output);
+        if selected.is_empty() {
+            return None;
+        }
+        if selected != BROWSE_ROW {
+            return Some(prefixes_dir.join(&selected));
+        }
 
-    if !output.status.success() {
-        return None;
+        let browsed = browse_for_prefix(&gui_tool, prefixes_dir)?;
+        if discovery::is_prefix(&browsed) {
+            // Remember where it lives so its neighbours are listed next time
+            if let Some(parent) = browsed.parent() {
+                discovery::remember_root(parent).ok();
+            }
+            return Some(browsed);
+        }
+        if discovery::find_prefixes(&browsed, discovery::SCAN_DEPTH).is_empty() {
+            let _ = dialog(&gui_tool)
+                .args([
+                    "--info",
+                    "--title",
+                    "No Prefixes Found",
+                    "--text",
+                    &format!(
+                        "No Wine prefixes (system.reg and drive_c) were found in {}.",
+                        browsed.display()
+                    ),
+                    "--width",
+                    "400",
+                ])
+                .status();
+        } else {
+            discovery::remember_root(&browsed).ok();
+        }
     }
+}
 
-    let selected = output_to_string(&output);
-    if selected.is_empty() {
-        None
-    } else {
-        Some(prefixes_dir.join(&selected))
+/// Let the user pick a prefix directory, or a directory containing prefixes.
+fn browse_for_prefix(gui_tool: &Path, start: &Path) -> Option<PathBuf> {
+    let output = dialog(gui_tool)
+        .args([
+            "--file-selection",
+            "--directory",
+            "--title",
+            "Select a prefix, or a directory containing prefixes",
+            "--filename",
+            &format!("{}/", start.display()),
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
     }
+    let path = output_to_string(&output);
+    (!path.is_empty()).then(|| PathBuf::from(path))
 }
@@ -0,0 +1,293 @@
+//! Background job scheduler for prefix operations.
+//!
+//! Runs jobs such as "install dxvk in prefix A" and "create prefix B" on a
+//! bounded pool of worker threads. Jobs that name the same resource (usually
+//! a prefix path) never run at the same time, since Wine prefixes can't be
+//! modified concurrently. Jobs can be queried and cancelled: queued jobs are
+//! dropped immediately, running jobs are asked to stop through their
+//! [`CancelToken`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+/// Identifier of a submitted job.
+pub type JobId = u64;
+
+/// Lifecycle state of a job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobState {
+    Queued,
+    Running,
+    Succeeded,
+    Failed(String),
+    Cancelled,
+}
+
+impl JobState {
+    /// Whether the job has finished (successfully or not).
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, JobState::Queued | JobState::Running)
+    }
+}
+
+/// Snapshot of a job's status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobStatus {
+    pub id: JobId,
+    pub name: String,
+    /// Resource the job needs exclusive access to (e.g. a prefix path).
+    pub resource: Option<String>,
+    pub state: JobState,
+}
+
+/// Cooperative cancellation flag handed to running jobs.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+type JobFn = Box<dyn FnOnce(&CancelToken) -> Result<(), String> + Send>;
+
+struct Job {
+    status: JobStatus,
+    cancel: CancelToken,
+    work: Option<JobFn>,
+}
+
+#[derive(Default)]
+struct Queue {
+    jobs: Vec<Job>,
+    next_id: JobId,
+    shutdown: bool,
+}
+
+type Shared = Arc<(Mutex<Queue>, Condvar)>;
+
+/// A scheduler running jobs with bounded parallelism.
+///
+/// Dropping the scheduler waits for queued and running jobs to finish.
+pub struct JobScheduler {
+    shared: Shared,
+    workers: Vec<JoinHandle<()>>,
+}
+
+fn lock(shared: &Shared) -> MutexGuard<'_, Queue> {
+    shared.0.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Take the next queued job whose resource isn't busy, marking it running.
+fn next_runnable(queue: &mut Queue) -> Option<(JobId, CancelToken, JobFn)> {
+    let busy: Vec<String> = queue
+        .jobs
+        .iter()
+        .filter(|j| j.status.state == JobState::Running)
+        .filter_map(|j| j.status.resource.clone())
+        .collect();
+
+    let job = queue.jobs.iter_mut().find(|j| {
+        j.status.state == JobState::Queued
+            && !j.status.resource.as_ref().is_some_and(|r| busy.contains(r))
+    })?;
+    job.status.state = JobState::Running;
+    let work = job.work.take()?;
+    Some((job.status.id, job.cancel.clone(), work))
+}
+
+fn worker_loop(shared: Shared) {
+    loop {
+        let (id, cancel, work) = {
+            let mut queue = lock(&shared);
+            loop {
+                if let Some(next) = next_runnable(&mut queue) {
+                    break next;
+                }
+                let pending = queue
+                    .jobs
+                    .iter()
+                    .any(|j| j.status.state == JobState::Queued);
+                if queue.shutdown && !pending {
+                    return;
+                }
+                queue = shared.1.wait(queue).unwrap_or_else(|e| e.into_inner());
+            }
+        };
+
+        let result = work(&cancel);
+
+        let mut queue = lock(&shared);
+        if let Some(job) = queue.jobs.iter_mut().find(|j| j.status.id == id) {
+            job.status.state = match result {
+                Ok(()) => JobState::Succeeded,
+                Err(_) if cancel.is_cancelled() => JobState::Cancelled,
+                Err(e) => JobState::Failed(e),
+            };
+        }
+        shared.1.notify_all();
+    }
+}
+
+impl JobScheduler {
+    /// Create a scheduler running at most `max_parallel` jobs at once (minimum 1).
+    pub fn new(max_parallel: usize) -> Self {
+        let shared: Shared = Arc::new((Mutex::new(Queue::default()), Condvar::new()));
+        let workers = (0..max_parallel.max(1))
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || worker_loop(shared))
+            })
+            .collect();
+        Self { shared, workers }
+    }
+
+    /// Queue a job. Jobs with the same `resource` run one at a time, in
+    /// submission order.
+    pub fn submit<F>(&self, name: &str, resource: Option<&str>, work: F) -> JobId
+    where
+        F: FnOnce(&CancelToken) -> Result<(), String> + Send + 'static,
+    {
+        let mut queue = lock(&self.shared);
+        queue.next_id += 1;
+        let id = queue.next_id;
+        queue.jobs.push(Job {
+            status: JobStatus {
+                id,
+                name: name.to_string(),
+                resource: resource.map(|r| r.to_string()),
+                state: JobState::Queued,
+            },
+            cancel: CancelToken::default(),
+            work: Some(Box::new(work)),
+        });
+        self.shared.1.notify_all();
+        id
+    }
+
+    /// Current status of a job.
+    pub fn status(&self, id: JobId) -> Option<JobStatus> {
+        lock(&self.shared)
+            .jobs
+            .iter()
+            .find(|j| j.status.id == id)
+            .map(|j| j.status.clone())
+    }
+
+    /// Status of all jobs, in submission order.
+    pub fn list(&self) -> Vec<JobStatus> {
+        lock(&self.shared)
+            .jobs
+            .iter()
+            .map(|j| j.status.clone())
+            .collect()
+    }
+
+    /// Cancel a job. Returns false if the job is unknown or already finished.
+    pub fn cancel(&self, id: JobId) -> bool {
+        let mut queue = lock(&self.shared);
+        let job = match queue.jobs.iter_mut().find(|j| j.status.id == id) {
+            Some(j) => j,
+            None => return false,
+        };
+        match job.status.state {
+            JobState::Queued => {
+                job.status.state = JobState::Cancelled;
+                job.work = None;
+            }
+            JobState::Running => job.cancel.cancel(),
+            _ => return false,
+        }
+        self.shared.1.notify_all();
+        true
+    }
+
+    /// Block until a job has finished and return its final status.
+    pub fn wait(&self, id: JobId) -> Option<JobStatus> {
+        let mut queue = lock(&self.shared);
+        loop {
+            let status = queue
+                .jobs
+                .iter()
+                .find(|j| j.status.id == id)
+                .map(|j| j.status.clone())?;
+            if status.state.is_finished() {
+                return Some(status);
+            }
+            queue = self.shared.1.wait(queue).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    /// Block until every submitted job has finished.
+    pub fn wait_all(&self) -> Vec<JobStatus> {
+        let mut queue = lock(&self.shared);
+        while queue.jobs.iter().any(|j| !j.status.state.is_finished()) {
+            queue = self.shared.1.wait(queue).unwrap_or_else(|e| e.into_inner());
+        }
+        queue.jobs.iter().map(|j| j.status.clone()).collect()
+    }
+}
+
+impl Drop for JobScheduler {
+    fn drop(&mut self) {
+        lock(&self.shared).shutdown = true;
+        self.shared.1.notify_all();
+        for worker in self.workers.drain(..) {
+            worker.join().ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    #[test]
+    fn test_scheduler_serializes_same_resource_and_cancels() {
+        let scheduler = JobScheduler::new(4);
+        let active = Arc::new(AtomicUsize::new(0));
+        let overlapped = Arc::new(AtomicBool::new(false));
+
+        for i in 0..3 {
+            let active = Arc::clone(&active);
+            let overlapped = Arc::clone(&overlapped);
+            scheduler.submit(&format!("job{}", i), Some("/pfx/a"), move |_| {
+                if active.fetch_add(1, Ordering::SeqCst) > 0 {
+                    overlapped.store(true, Ordering::SeqCst);
+                }
+                thread::sleep(Duration::from_millis(10));
+                active.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            });
+        }
+
+        let long = scheduler.submit("long", Some("/pfx/b"), |cancel| {
+            while !cancel.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Err("stopped".to_string())
+        });
+        let queued = scheduler.submit("queued", Some("/pfx/b"), |_| Ok(()));
+
+        assert!(scheduler.cancel(queued));
+        while scheduler.status(long).unwrap().state != JobState::Running {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(scheduler.cancel(long));
+
+        let statuses = scheduler.wait_all();
+        assert!(!overlapped.load(Ordering::SeqCst));
+        assert_eq!(statuses.len(), 5);
+        assert_eq!(scheduler.status(long).unwrap().state, JobState::Cancelled);
+        assert_eq!(scheduler.status(queued).unwrap().state, JobState::Cancelled);
+        assert!(statuses[..3].iter().all(|s| s.state == JobState::Succeeded));
+    }
+}
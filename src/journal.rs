@@ -0,0 +1,374 @@
+//! Journal of reversible changes.
+//!
+//! Settings changes protontool makes (registry values written by settings
+//! verbs and GUI settings, DLL overrides, environment profile edits, Steam
+//! launch options) are recorded together with the values they replaced in
+//! the `journal` state record, one JSON object per line.
+//! `protontool undo` restores the values of the most recent entry and drops
+//! it from the journal, so repeated undos walk back through history.
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::json::{self, JsonValue};
+use crate::steam::ProtonApp;
+use crate::wine::profile::EnvProfile;
+use crate::wine::registry::{self, RegistryEditor};
+use crate::wine::{WineArch, WineContext};
+
+/// State record holding the journal.
+const JOURNAL_STATE: &str = "journal";
+
+/// A single reversible change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalChange {
+    /// A registry value in a prefix. `before` is the replaced data in .reg
+    /// syntax, or None if the value didn't exist.
+    Registry {
+        prefix: PathBuf,
+        proton: PathBuf,
+        arch: WineArch,
+        key: String,
+        name: String,
+        before: Option<String>,
+    },
+    /// A variable in a prefix's environment profile.
+    EnvVar {
+        prefix: PathBuf,
+        name: String,
+        before: Option<String>,
+    },
+    /// A game's launch options in a Steam user's `localconfig.vdf`; empty
+    /// when none were set.
+    LaunchOptions {
+        config: PathBuf,
+        appid: u32,
+        before: String,
+    },
+}
+
+/// A group of changes made by one operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    pub timestamp: u64,
+    pub description: String,
+    pub changes: Vec<JournalChange>,
+}
+
+fn optional_string(value: &Option<String>) -> JsonValue {
+    match value {
+        Some(s) => JsonValue::String(s.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+fn string_field(value: &JsonValue, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(|s| s.to_string())
+}
+
+impl JournalChange {
+    /// Human-readable description of what reverting restores.
+    pub fn describe(&self) -> String {
+        let (what, before) = match self {
+            JournalChange::Registry {
+                key, name, before, ..
+            } => (format!("[{}] {}", key, name), before),
+            JournalChange::EnvVar { name, before, .. } => (name.clone(), before),
+            JournalChange::LaunchOptions { appid, before, .. } => {
+                let what = format!("launch options of {}", appid);
+                return match before.as_str() {
+                    "" => format!("{} (none)", what),
+                    before => format!("{} = {}", what, before),
+                };
+            }
+        };
+        match before {
+            Some(value) => format!("{} = {}", what, value),
+            None => format!("{} (unset)", what),
+        }
+    }
+
+    fn to_json(&self) -> JsonValue {
+        match self {
+            JournalChange::Registry {
+                prefix,
+                proton,
+                arch,
+                key,
+                name,
+                before,
+            } => JsonValue::Object(vec![
+                ("type".into(), JsonValue::String("registry".into())),
+                (
+                    "prefix".into(),
+                    JsonValue::String(prefix.to_string_lossy().into()),
+                ),
+                (
+                    "proton".into(),
+                    JsonValue::String(proton.to_string_lossy().into()),
+                ),
+                ("arch".into(), JsonValue::String(arch.as_str().into())),
+                ("key".into(), JsonValue::String(key.clone())),
+                ("name".into(), JsonValue::String(name.clone())),
+                ("before".into(), optional_string(before)),
+            ]),
+            JournalChange::EnvVar {
+                prefix,
+                name,
+                before,
+            } => JsonValue::Object(vec![
+                ("type".into(), JsonValue::String("env".into())),
+                (
+                    "prefix".into(),
+                    JsonValue::String(prefix.to_string_lossy().into()),
+                ),
+                ("name".into(), JsonValue::String(name.clone())),
+                ("before".into(), optional_string(before)),
+            ]),
+            JournalChange::LaunchOptions {
+                config,
+                appid,
+                before,
+            } => JsonValue::Object(vec![
+                ("type".into(), JsonValue::String("launch-options".into())),
+                (
+                    "config".into(),
+                    JsonValue::String(config.to_string_lossy().into()),
+                ),
+                ("appid".into(), JsonValue::Number(*appid as f64)),
+                ("before".into(), JsonValue::String(before.clone())),
+            ]),
+        }
+    }
+
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        let prefix = || string_field(value, "prefix").map(PathBuf::from);
+        let before = string_field(value, "before");
+        match value.get("type")?.as_str()? {
+            "registry" => Some(JournalChange::Registry {
+                prefix: prefix()?,
+                proton: PathBuf::from(string_field(value, "proton")?),
+                arch: WineArch::from_str(&string_field(value, "arch")?)?,
+                key: string_field(value, "key")?,
+                name: string_field(value, "name")?,
+                before,
+            }),
+            "env" => Some(JournalChange::EnvVar {
+                prefix: prefix()?,
+                name: string_field(value, "name")?,
+                before,
+            }),
+            "launch-options" => Some(JournalChange::LaunchOptions {
+                config: PathBuf::from(string_field(value, "config")?),
+                appid: value.get("appid")?.as_f64()? as u32,
+                before: before.unwrap_or_default(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Restore the value this change replaced.
+    fn revert(&self) -> Result<(), String> {
+        match self {
+            JournalChange::Registry {
+                prefix,
+                proton,
+                arch,
+                key,
+                name,
+                before,
+            } => {
+                let proton_app = ProtonApp {
+                    name: String::new(),
+                    appid: 0,
+                    install_path: proton.clone(),
+                    is_proton_ready: true,
+                };
+                let wine_ctx = WineContext::from_proton_with_arch(&proton_app, prefix, *arch);
+                let editor = RegistryEditor::new(&wine_ctx);
+                match before {
+                    Some(data) => editor.set_raw_value(key, name, data),
+                    None => editor.delete_value(key, name),
+                }
+            }
+            JournalChange::EnvVar {
+                prefix,
+                name,
+                before,
+            } => {
+                let mut profile = EnvProfile::load(prefix);
+                match before {
+                    Some(value) => profile.set(name, value),
+                    None => {
+                        profile.unset(name);
+                    }
+                }
+                profile.save(prefix)
+            }
+            JournalChange::LaunchOptions {
+                config,
+                appid,
+                before,
+            } => crate::launch_options::write(config, *appid, before),
+        }
+    }
+}
+
+impl JournalEntry {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::Object(vec![
+            ("timestamp".into(), JsonValue::Number(self.timestamp as f64)),
+            (
+                "description".into(),
+                JsonValue::String(self.description.clone()),
+            ),
+            (
+                "changes".into(),
+                JsonValue::Array(self.changes.iter().map(|c| c.to_json()).collect()),
+            ),
+        ])
+    }
+
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        Some(JournalEntry {
+            timestamp: value.get("timestamp")?.as_f64()? as u64,
+            description: string_field(value, "description")?,
+            changes: value
+                .get("changes")?
+                .as_array()?
+                .iter()
+                .filter_map(JournalChange::from_json)
+                .collect(),
+        })
+    }
+}
+
+/// Snapshot the current values of everything a .reg file would change in
+/// a prefix. Call before applying the content.
+pub fn registry_changes(wine_ctx: &WineContext, content: &str) -> Vec<JournalChange> {
+    let mut changes: Vec<JournalChange> = Vec::new();
+    for (key, name) in registry::reg_content_values(content) {
+        let duplicate = changes.iter().any(|c| {
+            matches!(c, JournalChange::Registry { key: k, name: n, .. } if k == &key && n == &name)
+        });
+        if duplicate {
+            continue;
+        }
+        let before = registry::read_prefix_value(&wine_ctx.prefix_path, &key, &name);
+        changes.push(JournalChange::Registry {
+            prefix: wine_ctx.prefix_path.clone(),
+            proton: wine_ctx.proton_path.clone(),
+            arch: wine_ctx.arch,
+            key,
+            name,
+            before,
+        });
+    }
+    changes
+}
+
+/// Set (or with None, remove) an environment profile variable, recording
+/// the previous value in the journal.
+pub fn set_profile_var(prefix_path: &Path, name: &str, value: Option<&str>) -> Result<(), String> {
+    let mut profile = EnvProfile::load(prefix_path);
+    let change = JournalChange::EnvVar {
+        prefix: prefix_path.to_path_buf(),
+        name: name.to_string(),
+        before: profile.get(name).map(|v| v.to_string()),
+    };
+    match value {
+        Some(value) => profile.set(name, value),
+        None => {
+            profile.unset(name);
+        }
+    }
+    profile.save(prefix_path)?;
+
+    let description = match value {
+        Some(value) => format!("Set {}={} in {}", name, value, prefix_path.display()),
+        None => format!("Unset {} in {}", name, prefix_path.display()),
+    };
+    record(&description, vec![change])
+}
+
+/// Append an entry to the journal. Entries without changes are skipped.
+pub fn record(description: &str, changes: Vec<JournalChange>) -> Result<(), String> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+    let entry = JournalEntry {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        description: description.to_string(),
+        changes,
+    };
+    crate::state::append_line(JOURNAL_STATE, &entry.to_json().to_string())
+}
+
+/// All journal entries, oldest first. Unreadable lines are skipped.
+pub fn entries() -> Vec<JournalEntry> {
+    crate::state::read_lines(JOURNAL_STATE)
+        .iter()
+        .filter_map(|line| json::parse(line).ok())
+        .filter_map(|value| JournalEntry::from_json(&value))
+        .collect()
+}
+
+/// Revert the most recent journal entry and remove it from the journal.
+/// Returns None when the journal is empty.
+pub fn undo_last() -> Result<Option<JournalEntry>, String> {
+    let mut all = entries();
+    let entry = match all.pop() {
+        Some(e) => e,
+        None => return Ok(None),
+    };
+
+    for change in entry.changes.iter().rev() {
+        change
+            .revert()
+            .map_err(|e| format!("Failed to restore {}: {}", change.describe(), e))?;
+    }
+
+    let content: String = all.iter().map(|e| format!("{}\n", e.to_json())).collect();
+    crate::state::write(JOURNAL_STATE, &content)?;
+    Ok(Some(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_entry_roundtrip() {
+        let entry = JournalEntry {
+            timestamp: 1700000000,
+            description: "mwo=force".to_string(),
+            changes: vec![
+                JournalChange::Registry {
+                    prefix: PathBuf::from("/pfx"),
+                    proton: PathBuf::from("/proton"),
+                    arch: WineArch::Win32,
+                    key: "HKEY_CURRENT_USER\\Software\\Wine\\DirectInput".to_string(),
+                    name: "MouseWarpOverride".to_string(),
+                    before: Some("\"enable\"".to_string()),
+                },
+                JournalChange::EnvVar {
+                    prefix: PathBuf::from("/pfx"),
+                    name: "DXVK_HUD".to_string(),
+                    before: None,
+                },
+                JournalChange::LaunchOptions {
+                    config: PathBuf::from("/steam/userdata/1/config/localconfig.vdf"),
+                    appid: 620,
+                    before: "mangohud %command%".to_string(),
+                },
+            ],
+        };
+        let line = entry.to_json().to_string();
+        let parsed = JournalEntry::from_json(&json::parse(&line).unwrap()).unwrap();
+        assert_eq!(parsed, entry);
+        assert_eq!(parsed.changes[1].describe(), "DXVK_HUD (unset)");
+    }
+}
@@ -0,0 +1,406 @@
+//! Editing a game's Steam launch options.
+//!
+//! Launch options are often hand-tuned (`mangohud %command%`,
+//! `gamescope -f -- %command%`), so protontool merges what it needs into
+//! them rather than replacing them. Both sides are split into environment
+//! assignments, wrapper commands, `%command%` and game arguments: missing
+//! variables are added after the user's, protontool's wrappers go innermost
+//! (right before `%command%`, inside the user's), and missing arguments are
+//! appended. A variable or wrapper both sides set differently, or wrappers
+//! known to clash, stop the merge instead.
+//!
+//! The options live in each Steam user's `localconfig.vdf`, which is edited
+//! in place. The replaced value is recorded in the journal, so
+//! `protontool undo` restores it. Steam rewrites the file when it exits,
+//! so it must not be running.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::journal::{self, JournalChange};
+use crate::vdf::parse_vdf;
+
+/// The game's command line in launch options.
+const COMMAND: &str = "%command%";
+
+/// Wrapper commands, to tell where one ends and the next begins.
+const KNOWN_WRAPPERS: &[&str] = &[
+    "env",
+    "firejail",
+    "game-performance",
+    "gamemoderun",
+    "gamescope",
+    "mangohud",
+    "nice",
+    "obs-gamecapture",
+    "prime-run",
+    "pw-jack",
+    "strangle",
+    "taskset",
+];
+
+/// Wrappers and variables that clash with each other, and why.
+const CONFLICTS: &[(&str, &str, &str)] = &[
+    ("mangohud", "MANGOHUD", "MangoHud would be loaded twice"),
+    (
+        "gamemoderun",
+        "game-performance",
+        "both switch the CPU to performance mode and restore it on exit",
+    ),
+    (
+        "prime-run",
+        "DRI_PRIME",
+        "both select the GPU and may pick different ones",
+    ),
+];
+
+/// Split launch options into words, keeping quotes as written so that
+/// variables and quoting behave as before when the words are joined again.
+fn words(options: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quote = None;
+    let mut chars = options.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, c) if c.is_whitespace() => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+                continue;
+            }
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (Some('\''), _) => {}
+            (_, '\\') => {
+                word.push(c);
+                if let Some(next) = chars.next() {
+                    word.push(next);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Name of a `KEY=VALUE` word.
+fn env_key(word: &str) -> Option<&str> {
+    let (key, _) = word.split_once('=')?;
+    let mut chars = key.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then_some(key)
+}
+
+/// Program name of a wrapper command.
+fn program(wrapper: &[String]) -> &str {
+    let first = wrapper.first().map(|w| w.as_str()).unwrap_or_default();
+    first.rsplit('/').next().unwrap_or(first)
+}
+
+/// Launch options split into their parts.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LaunchOptions {
+    /// `KEY=VALUE` assignments before the wrappers.
+    pub env: Vec<String>,
+    /// Wrapper commands around the game, outermost first.
+    pub wrappers: Vec<Vec<String>>,
+    /// Arguments after `%command%`.
+    pub args: Vec<String>,
+}
+
+impl LaunchOptions {
+    /// Parse launch options. Without `%command%`, Steam appends them to the
+    /// game's command line, so they are all arguments.
+    pub fn parse(options: &str) -> Self {
+        let words = words(options);
+        let Some(command) = words.iter().position(|w| w == COMMAND) else {
+            return LaunchOptions {
+                args: words,
+                ..Default::default()
+            };
+        };
+        let mut parsed = LaunchOptions {
+            args: words[command + 1..].to_vec(),
+            ..Default::default()
+        };
+        let mut prefix = words[..command].iter().peekable();
+        while let Some(word) = prefix.next_if(|w| env_key(w).is_some()) {
+            parsed.env.push(word.clone());
+        }
+        for word in prefix {
+            let starts_wrapper = match parsed.wrappers.last() {
+                None => true,
+                Some(wrapper) => {
+                    let previous = wrapper.last().map(|w| w.as_str()).unwrap_or_default();
+                    previous == "--"
+                        || KNOWN_WRAPPERS.contains(&word.as_str())
+                        || (!word.starts_with('-') && !previous.starts_with('-'))
+                }
+            };
+            if starts_wrapper {
+                parsed.wrappers.push(vec![word.clone()]);
+            } else if let Some(wrapper) = parsed.wrappers.last_mut() {
+                wrapper.push(word.clone());
+            }
+        }
+        parsed
+    }
+
+    /// Names of the variables and wrapper programs used.
+    fn names(&self) -> Vec<&str> {
+        let env = self.env.iter().filter_map(|w| env_key(w));
+        env.chain(self.wrappers.iter().map(|w| program(w)))
+            .collect()
+    }
+
+    /// Merge `template` into these options, keeping everything already set.
+    /// Fails with the conflicts found.
+    ///
+    /// ```
+    /// use protontool::launch_options::LaunchOptions;
+    /// let user = LaunchOptions::parse("DXVK_HUD=fps mangohud %command% -windowed");
+    /// let merged = user.merge(&LaunchOptions::parse("PROTON_LOG=1 gamemoderun %command% -skipintro"));
+    /// assert_eq!(
+    ///     merged.unwrap().to_string(),
+    ///     "DXVK_HUD=fps PROTON_LOG=1 mangohud gamemoderun %command% -windowed -skipintro"
+    /// );
+    /// assert!(user.merge(&LaunchOptions::parse("MANGOHUD=1 %command%")).is_err());
+    /// ```
+    pub fn merge(&self, template: &LaunchOptions) -> Result<LaunchOptions, Vec<String>> {
+        let mut merged = self.clone();
+        let mut conflicts = Vec::new();
+        for word in &template.env {
+            let key = env_key(word).unwrap_or_default();
+            match self.env.iter().find(|w| env_key(w) == Some(key)) {
+                Some(existing) if existing == word => {}
+                Some(existing) => conflicts.push(format!(
+                    "{} is already set differently ({} instead of {})",
+                    key, existing, word
+                )),
+                None => merged.env.push(word.clone()),
+            }
+        }
+        let mut wrappers = Vec::new();
+        for wrapper in &template.wrappers {
+            let name = program(wrapper);
+            match self.wrappers.iter().find(|w| program(w) == name) {
+                Some(existing) if existing == wrapper => {}
+                Some(existing) => conflicts.push(format!(
+                    "{} is already used differently ({} instead of {})",
+                    name,
+                    existing.join(" "),
+                    wrapper.join(" ")
+                )),
+                None => wrappers.push(wrapper.clone()),
+            }
+        }
+        merged.wrappers.extend(wrappers);
+        for arg in &template.args {
+            if !merged.args.contains(arg) {
+                merged.args.push(arg.clone());
+            }
+        }
+        let (ours, theirs) = (template.names(), self.names());
+        for (a, b, why) in CONFLICTS {
+            for (mine, other) in [(a, b), (b, a)] {
+                if theirs.contains(mine) && ours.contains(other) {
+                    conflicts.push(format!("{} clashes with {}: {}", other, mine, why));
+                }
+            }
+        }
+        if conflicts.is_empty() {
+            Ok(merged)
+        } else {
+            Err(conflicts)
+        }
+    }
+}
+
+impl std::fmt::Display for LaunchOptions {
+    /// The options as Steam takes them; empty when nothing is set.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.env.is_empty() && self.wrappers.is_empty() && self.args.is_empty() {
+            return Ok(());
+        }
+        let words: Vec<&str> = self
+            .env
+            .iter()
+            .chain(self.wrappers.iter().flatten())
+            .map(|w| w.as_str())
+            .chain(std::iter::once(COMMAND))
+            .chain(self.args.iter().map(|w| w.as_str()))
+            .collect();
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+/// `localconfig.vdf` files of the Steam users that have played the game,
+/// with their launch options ("" when none are set).
+pub fn user_configs(steam_path: &Path, appid: u32) -> Vec<(PathBuf, String)> {
+    let Ok(users) = fs::read_dir(steam_path.join("userdata")) else {
+        return Vec::new();
+    };
+    let mut configs: Vec<(PathBuf, String)> = users
+        .flatten()
+        .filter_map(|user| {
+            let path = user.path().join("config/localconfig.vdf");
+            let config = parse_vdf(&path).ok()?;
+            let store = config.get_dict("UserLocalConfigStore")?;
+            let apps = store.get_dict("apps").or_else(|| store.get_dict("Apps"))?;
+            let app = apps.get_dict(&appid.to_string())?;
+            let options = app.get("LaunchOptions").unwrap_or_default().to_string();
+            Some((path, options))
+        })
+        .collect();
+    configs.sort();
+    configs
+}
+
+fn check_steam_closed() -> Result<(), String> {
+    if crate::controller::steam_running() {
+        return Err(
+            "Steam is running and would overwrite the launch options when it exits. Close Steam first."
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// A user's `localconfig.vdf` with a game's launch options replaced.
+fn edited_config(config: &Path, appid: u32, options: &str) -> Result<String, String> {
+    let content = fs::read_to_string(config)
+        .map_err(|e| format!("Failed to read {}: {}", config.display(), e))?;
+    let appid = appid.to_string();
+    crate::vdf::set_string(
+        &content,
+        &["UserLocalConfigStore", "apps", &appid],
+        "LaunchOptions",
+        options,
+    )
+    .map_err(|e| format!("{}: {}", config.display(), e))
+}
+
+/// Replace a `localconfig.vdf` through a temporary file in the same
+/// directory, so an interrupted write can't leave it truncated.
+fn replace_config(config: &Path, content: &str) -> Result<(), String> {
+    let mut tmp_name = config.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".protontool-tmp");
+    let tmp_path = config.with_file_name(tmp_name);
+    fs::write(&tmp_path, content)
+        .and_then(|_| fs::rename(&tmp_path, config))
+        .map_err(|e| {
+            fs::remove_file(&tmp_path).ok();
+            format!("Failed to write {}: {}", config.display(), e)
+        })
+}
+
+/// Write a game's launch options into a user's `localconfig.vdf`.
+pub fn write(config: &Path, appid: u32, options: &str) -> Result<(), String> {
+    check_steam_closed()?;
+    replace_config(config, &edited_config(config, appid, options)?)
+}
+
+/// Set a game's launch options for every Steam user that has played it,
+/// with `change` computing the new options from each user's current ones.
+/// Every user's new `localconfig.vdf` is prepared before any is written,
+/// and the replaced options are recorded in the journal, including when a
+/// later write fails. Returns the new options of the users whose options
+/// changed.
+pub fn update(
+    steam_path: &Path,
+    appid: u32,
+    description: &str,
+    change: impl Fn(&str) -> Result<String, String>,
+) -> Result<Vec<String>, String> {
+    let configs = user_configs(steam_path, appid);
+    if configs.is_empty() {
+        return Err(format!(
+            "No Steam user has launch settings for {} yet. Launch the game once first.",
+            appid
+        ));
+    }
+    check_steam_closed()?;
+    let mut edits = Vec::new();
+    for (config, current) in configs {
+        let new = change(&current)?;
+        if new == current {
+            continue;
+        }
+        let content = edited_config(&config, appid, &new)?;
+        edits.push((config, current, new, content));
+    }
+
+    let mut changes = Vec::new();
+    let mut updated = Vec::new();
+    for (config, current, new, content) in edits {
+        if let Err(e) = replace_config(&config, &content) {
+            journal::record(description, changes)?;
+            return Err(e);
+        }
+        changes.push(JournalChange::LaunchOptions {
+            config,
+            appid,
+            before: current,
+        });
+        updated.push(new);
+    }
+    journal::record(description, changes)?;
+    Ok(updated)
+}
+
+/// Merge a launch options template into a game's launch options.
+pub fn add(steam_path: &Path, appid: u32, template: &str) -> Result<Vec<String>, String> {
+    let template = LaunchOptions::parse(template);
+    update(
+        steam_path,
+        appid,
+        &format!("Add launch options {} to {}", template, appid),
+        |current| {
+            LaunchOptions::parse(current)
+                .merge(&template)
+                .map(|merged| merged.to_string())
+                .map_err(|conflicts| {
+                    format!(
+                        "Launch options '{}' conflict with '{}':\n  {}",
+                        current,
+                        template,
+                        conflicts.join("\n  ")
+                    )
+                })
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keeps_quoting_and_wrapper_args() {
+        let options = LaunchOptions::parse(
+            "WINEDLLOVERRIDES=\"dinput8=n,b\" gamescope -W 1920 -f -- mangohud %command% -dx11",
+        );
+        assert_eq!(options.env, vec!["WINEDLLOVERRIDES=\"dinput8=n,b\""]);
+        assert_eq!(
+            options.wrappers,
+            vec![
+                vec!["gamescope", "-W", "1920", "-f", "--"],
+                vec!["mangohud"]
+            ]
+        );
+        assert_eq!(
+            LaunchOptions::parse("-novid").to_string(),
+            "%command% -novid"
+        );
+        assert_eq!(LaunchOptions::parse("").to_string(), "");
+    }
+}
@@ -3,15 +3,39 @@
 //! This lib.rs exists to expose protontool's modules for doc tests and as a library.
 //! The main binary entry point is in main.rs which re-exports these modules.
 
+pub mod analyze;
+pub mod audit;
 pub mod cli;
 pub mod config;
+pub mod controller;
+pub mod desktop;
+pub mod distro;
+pub mod doctor;
+pub mod flatpak;
 pub mod gui;
+pub mod jobs;
+pub mod journal;
+pub mod json;
+pub mod launch_options;
 pub mod log;
+pub mod migrate;
+pub mod notes;
+pub mod notify;
+pub mod permissions;
+pub mod play;
+pub mod prewarm;
+pub mod recipe;
+pub mod report;
+pub mod search;
+pub mod state;
+pub mod status;
 pub mod steam;
 pub mod util;
 pub mod vdf;
+pub mod version;
 pub mod wine;
 pub mod wine_data;
+pub mod workdir;
 
 /// Package version from Cargo.toml.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
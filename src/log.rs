@@ -3,6 +3,18 @@
 //! Provides structured logging to `~/.local/share/protontool/logs/` with automatic
 //! file rotation when logs exceed 5MB. Also scans Wine/Proton output for known
 //! error patterns and displays helpful diagnostics.
+//!
+//! Retention and how known errors are reported are set in `config.toml`:
+//!
+//! ```toml
+//! [logging]
+//! max_size_mb = "5"       # rotate the log past this size
+//! max_files = "5"         # rotated logs to keep
+//! crash = "error"         # level for crash codes: "error", "warn", "info" or "off"
+//! error = "warn"          # level for other errors
+//! fixme = "off"           # level for unimplemented-feature (fixme) noise
+//! mute = ["WINE-NTDLL-003", "fixme:d3d:"]  # codes or patterns never reported
+//! ```
 
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
@@ -14,7 +26,8 @@ use crate::wine_data::KNOWN_ERRORS;
 
 // Re-export wine_data items for convenience
 pub use crate::wine_data::{
-    is_valid_channel, lookup_hresult, lookup_ntstatus, lookup_win32_error, WINE_DEBUG_CHANNELS,
+    is_valid_channel, lookup_hresult, lookup_ntstatus, lookup_win32_error, HResult, NtStatus,
+    WINE_DEBUG_CHANNELS,
 };
 
 /// Maximum log file size before rotation (5 MB)
@@ -51,6 +64,8 @@ pub struct Logger {
     log_dir: PathBuf,
     current_log: PathBuf,
     min_level: LogLevel,
+    max_size: u64,
+    max_files: usize,
 }
 
 impl Logger {
@@ -66,6 +81,8 @@ impl Logger {
             log_dir,
             current_log,
             min_level: LogLevel::Info,
+            max_size: MAX_LOG_SIZE,
+            max_files: MAX_LOG_FILES,
         };
 
         // Rotate if needed
@@ -86,6 +103,17 @@ impl Logger {
         }
     }
 
+    /// Set the size at which the log is rotated and how many rotated logs
+    /// are kept
+    pub fn set_retention(max_size: u64, max_files: usize) {
+        if let Ok(mut global) = LOGGER.lock() {
+            if let Some(ref mut logger) = *global {
+                logger.max_size = max_size;
+                logger.max_files = max_files;
+            }
+        }
+    }
+
     /// Get current timestamp in ISO 8601 format
     fn timestamp() -> String {
         let now = SystemTime::now()
@@ -140,7 +168,7 @@ impl Logger {
     /// Rotate log files if the current one is too large
     fn rotate_if_needed(&self) {
         if let Ok(metadata) = fs::metadata(&self.current_log) {
-            if metadata.len() >= MAX_LOG_SIZE {
+            if metadata.len() >= self.max_size {
                 self.rotate();
             }
         }
@@ -151,11 +179,11 @@ impl Logger {
         // Remove oldest log if we have too many
         let oldest = self
             .log_dir
-            .join(format!("protontool.{}.log", MAX_LOG_FILES));
+            .join(format!("protontool.{}.log", self.max_files));
         let _ = fs::remove_file(&oldest);
 
         // Shift existing logs
-        for i in (1..MAX_LOG_FILES).rev() {
+        for i in (1..self.max_files).rev() {
             let from = self.log_dir.join(format!("protontool.{}.log", i));
             let to = self.log_dir.join(format!("protontool.{}.log", i + 1));
             let _ = fs::rename(&from, &to);
@@ -230,6 +258,156 @@ pub fn error(message: &str) {
     }
 }
 
+/// How serious a `KNOWN_ERRORS` entry is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSeverity {
+    /// Exceptions, crash codes and fatal errors
+    Crash,
+    /// Other errors
+    Error,
+    /// Unimplemented-feature (`fixme:`) messages, usually harmless
+    Fixme,
+}
+
+impl ErrorSeverity {
+    /// Severity of a known error, from its pattern and code.
+    pub fn of(pattern: &str, code: &str) -> Self {
+        if pattern.starts_with("fixme:") {
+            ErrorSeverity::Fixme
+        } else if code.starts_with("CRASH-")
+            || code.starts_with("WINE-SEH-")
+            || code.starts_with("NTSTATUS-")
+        {
+            ErrorSeverity::Crash
+        } else {
+            ErrorSeverity::Error
+        }
+    }
+}
+
+/// How known errors found in program output are reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannerConfig {
+    /// Level per severity; None suppresses the severity.
+    pub crash: Option<LogLevel>,
+    pub error: Option<LogLevel>,
+    pub fixme: Option<LogLevel>,
+    /// Codes or patterns (lowercase) that are never reported.
+    pub muted: Vec<String>,
+}
+
+impl Default for ScannerConfig {
+    fn default() -> Self {
+        ScannerConfig {
+            crash: Some(LogLevel::Error),
+            error: Some(LogLevel::Warning),
+            fixme: Some(LogLevel::Warning),
+            muted: Vec::new(),
+        }
+    }
+}
+
+/// Parse a reporting level: `error`, `warn` or `off`.
+fn parse_report_level(value: &str) -> Option<Option<LogLevel>> {
+    match value.to_lowercase().as_str() {
+        "error" => Some(Some(LogLevel::Error)),
+        "warn" | "warning" => Some(Some(LogLevel::Warning)),
+        "info" => Some(Some(LogLevel::Info)),
+        "off" | "none" => Some(None),
+        _ => None,
+    }
+}
+
+impl ScannerConfig {
+    /// Read the `[logging]` section of `config.toml`.
+    pub fn from_config(config: &crate::config::UserConfig) -> Result<Self, String> {
+        let mut scanner = ScannerConfig::default();
+        for (key, slot) in [
+            ("crash", &mut scanner.crash),
+            ("error", &mut scanner.error),
+            ("fixme", &mut scanner.fixme),
+        ] {
+            if let Some(value) = config.get("logging", key) {
+                *slot = parse_report_level(value).ok_or_else(|| {
+                    format!(
+                        "Invalid [logging] {} level '{}': expected error, warn, info or off",
+                        key, value
+                    )
+                })?;
+            }
+        }
+        scanner.muted = config
+            .get_array("logging", "mute")
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| entry.to_lowercase())
+            .collect();
+        Ok(scanner)
+    }
+
+    /// Level a known error is reported at, None when it's muted or its
+    /// severity is suppressed.
+    pub fn level_for(&self, code: &str) -> Option<LogLevel> {
+        let pattern = KNOWN_ERRORS
+            .iter()
+            .find(|(_, c, _)| *c == code)
+            .map_or("", |(p, _, _)| *p);
+        let muted = self
+            .muted
+            .iter()
+            .any(|entry| entry.eq_ignore_ascii_case(code) || entry.eq_ignore_ascii_case(pattern));
+        if muted {
+            return None;
+        }
+        match ErrorSeverity::of(pattern, code) {
+            ErrorSeverity::Crash => self.crash,
+            ErrorSeverity::Error => self.error,
+            ErrorSeverity::Fixme => self.fixme,
+        }
+    }
+}
+
+static SCANNER: Mutex<Option<ScannerConfig>> = Mutex::new(None);
+
+/// Set how known errors in program output are reported.
+pub fn set_scanner_config(config: ScannerConfig) {
+    if let Ok(mut scanner) = SCANNER.lock() {
+        *scanner = Some(config);
+    }
+}
+
+fn scanner_config() -> ScannerConfig {
+    SCANNER
+        .lock()
+        .ok()
+        .and_then(|scanner| scanner.clone())
+        .unwrap_or_default()
+}
+
+/// Apply the `[logging]` section of `config.toml`: log retention and how
+/// known errors are reported.
+pub fn apply_config(config: &crate::config::UserConfig) -> Result<(), String> {
+    let max_size_mb = match config.get("logging", "max_size_mb") {
+        Some(value) => value
+            .parse::<u64>()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| format!("Invalid [logging] max_size_mb '{}'", value))?,
+        None => MAX_LOG_SIZE / (1024 * 1024),
+    };
+    let max_files = match config.get("logging", "max_files") {
+        Some(value) => value
+            .parse::<usize>()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| format!("Invalid [logging] max_files '{}'", value))?,
+        None => MAX_LOG_FILES,
+    };
+    Logger::set_retention(max_size_mb * 1024 * 1024, max_files);
+    set_scanner_config(ScannerConfig::from_config(config)?);
+    Ok(())
+}
+
 /// Log executable output and scan for known Wine/Windows errors.
 /// Automatically detects missing DLLs and other common issues from output.
 pub fn log_executable_output(executable: &str, stdout: &str, stderr: &str, exit_code: i32) {
@@ -261,17 +439,25 @@ pub fn log_executable_output(executable: &str, stdout: &str, stderr: &str, exit_
                 }
             }
 
-            // Scan for known errors and print formatted output
+            // Scan for known errors and print formatted output, leaving out
+            // muted and suppressed ones
             let combined = format!("{}\n{}", stdout, stderr);
-            let matches = scan_for_errors(&combined);
+            let scanner = scanner_config();
+            let matches: Vec<(String, String, LogLevel)> = scan_for_errors(&combined)
+                .into_iter()
+                .filter_map(|(code, description)| {
+                    let level = scanner.level_for(&code)?;
+                    Some((code, description, level))
+                })
+                .collect();
 
             if !matches.is_empty() {
                 println!();
-                for (code, description) in matches {
+                for (code, description, level) in matches {
                     let formatted = format_error_message(executable, &code, &description);
                     print!("{}", formatted);
                     logger.write(
-                        LogLevel::Warning,
+                        level,
                         &format!(
                             "[{}] Known issue detected: {} - {}",
                             executable, code, description
@@ -280,19 +466,32 @@ pub fn log_executable_output(executable: &str, stdout: &str, stderr: &str, exit_
                 }
             }
 
-            // Log non-zero exit code as error
+            // Log non-zero exit code as error, naming the crash if it was one
             if exit_code != 0 {
-                logger.write(
-                    LogLevel::Error,
-                    &format!("[{}] Exited with code {}", executable, exit_code),
-                );
+                let message = match crash_exception(exit_code, &combined) {
+                    Some(status) => format!(
+                        "[{}] Crashed with {} (exit code {})",
+                        executable,
+                        describe_exception(status),
+                        exit_code
+                    ),
+                    None => format!("[{}] Exited with code {}", executable, exit_code),
+                };
+                logger.write(LogLevel::Error, &message);
             }
         }
     }
 }
 
-/// Format an error message with box-drawing characters for terminal display.
+/// Format an error message with box-drawing characters for terminal display
+/// (or as plain `key: value` lines in plain output mode).
 fn format_error_message(executable: &str, code: &str, description: &str) -> String {
+    if crate::util::is_plain_output() {
+        return format!(
+            "Error in: {}\nCode: {}\nDetails: {}\n",
+            executable, code, description
+        );
+    }
     format!(
         "┌─ {} ─────────────────────────────────────────\n\
          │ Code: {}\n\
@@ -304,7 +503,7 @@ fn format_error_message(executable: &str, code: &str, description: &str) -> Stri
 
 /// Extract DLL name from a line of Wine output.
 /// Handles various Wine error message formats like "Library X.dll not found".
-fn extract_dll_name(line: &str) -> Option<String> {
+pub(crate) fn extract_dll_name(line: &str) -> Option<String> {
     // Common patterns in Wine output:
     // "Library MSVCP140.dll (which is needed by ...)"
     // "failed to load L\"d3d11.dll\""
@@ -390,6 +589,100 @@ fn scan_for_errors(output: &str) -> Vec<(String, String)> {
     found
 }
 
+/// Known error codes (from KNOWN_ERRORS) found in the given output.
+pub fn known_error_codes(output: &str) -> Vec<String> {
+    scan_for_errors(output)
+        .into_iter()
+        .map(|(code, _)| code)
+        .collect()
+}
+
+/// Name and description of a Windows exit code: a Win32 error for small
+/// codes, otherwise an HRESULT or NTSTATUS.
+///
+/// ```
+/// use protontool::log::describe_exit_code;
+/// assert_eq!(describe_exit_code(5).unwrap(), "ERROR_ACCESS_DENIED: access denied");
+/// ```
+pub fn describe_exit_code(code: i32) -> Option<String> {
+    let value = code as u32;
+    let found = if value < 0x10000 {
+        lookup_win32_error(value)
+    } else {
+        HResult::from_u32(value)
+            .map(|hr| (hr.name(), hr.description()))
+            .or_else(|| NtStatus::from_u32(value).map(|st| (st.name(), st.description())))
+    };
+    found.map(|(name, description)| format!("{}: {}", name, description))
+}
+
+/// Exceptions Wine names in its `wine: Unhandled ...` crash message instead
+/// of printing the code.
+const UNHANDLED_EXCEPTIONS: &[(&str, NtStatus)] = &[
+    ("page fault", NtStatus::AccessViolation),
+    ("stack overflow", NtStatus::StackOverflow),
+    ("illegal instruction", NtStatus::IllegalInstruction),
+    ("division by zero", NtStatus::IntegerDivideByZero),
+    ("privileged instruction", NtStatus::PrivilegedInstruction),
+    ("breakpoint", NtStatus::Breakpoint),
+];
+
+/// The exception in one `wine: Unhandled ...` line, if it is one.
+fn unhandled_exception(line: &str) -> Option<NtStatus> {
+    let lower = line.to_lowercase();
+    let (_, rest) = lower.split_once("unhandled ")?;
+    if let Some(hex) = rest.strip_prefix("exception 0x") {
+        let digits: String = hex.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+        return u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(NtStatus::from_u32);
+    }
+    UNHANDLED_EXCEPTIONS
+        .iter()
+        .find(|(phrase, _)| rest.starts_with(phrase))
+        .map(|(_, status)| *status)
+}
+
+/// The Windows exception that ended a process, from its exit code and output.
+///
+/// Wine reports an unhandled exception on stderr and exits with the
+/// exception code, of which the Unix exit status only keeps the low 8 bits
+/// (0xC0000005 arrives as 5). The crash message names the exception; when
+/// there are several (crashing child processes), the one matching the exit
+/// status wins. Without a message only a full 32-bit error code counts, as
+/// a truncated one can't be told apart from an ordinary exit code.
+///
+/// ```
+/// use protontool::log::{crash_exception, NtStatus};
+/// let stderr = "wine: Unhandled page fault on read access to 0000000000000000 at address 0000000140001000";
+/// assert_eq!(crash_exception(5, stderr), Some(NtStatus::AccessViolation));
+/// assert_eq!(crash_exception(0xC0000409u32 as i32, ""), Some(NtStatus::StackBufferOverrun));
+/// assert_eq!(crash_exception(5, ""), None);
+/// assert_eq!(crash_exception(0, stderr), None);
+/// ```
+pub fn crash_exception(exit_code: i32, output: &str) -> Option<NtStatus> {
+    if exit_code == 0 {
+        return None;
+    }
+    let reported: Vec<NtStatus> = output.lines().filter_map(unhandled_exception).collect();
+    let low_byte = exit_code as u32 & 0xff;
+    if let Some(status) = reported
+        .iter()
+        .rev()
+        .find(|status| status.code() & 0xff == low_byte)
+        .or(reported.last())
+    {
+        return Some(*status);
+    }
+    NtStatus::from_u32(exit_code as u32).filter(|status| status.code() >= 0xC000_0000)
+}
+
+/// Describe an exception for errors and logs, e.g.
+/// `STATUS_ACCESS_VIOLATION (0xC0000005): access violation`.
+pub fn describe_exception(status: NtStatus) -> String {
+    format!("{}: {}", status, status.description())
+}
+
 /// Get the path to the current log file
 pub fn get_current_log_path() -> PathBuf {
     crate::config::get_log_dir().join("protontool.log")
@@ -440,6 +733,118 @@ mod tests {
         // Should contain the DLL name in the description
         assert!(errors.iter().any(|(_, desc)| desc.contains("MSVCP140.dll")));
     }
+
+    #[test]
+    fn test_typed_codes() {
+        let status = NtStatus::from_u32(0xC0000005).unwrap();
+        assert_eq!(status, NtStatus::AccessViolation);
+        assert_eq!(status.name(), "STATUS_ACCESS_VIOLATION");
+        assert_eq!(status.to_string(), "STATUS_ACCESS_VIOLATION (0xC0000005)");
+        assert_eq!(HResult::from_u32(0x80004005), Some(HResult::Fail));
+        assert_eq!(HResult::Notimpl.code(), 0x80004001);
+        assert_eq!(NtStatus::from_u32(0x12345678), None);
+        assert_eq!(
+            describe_exit_code(0xC0000135u32 as i32).unwrap(),
+            "STATUS_DLL_NOT_FOUND: dll not found"
+        );
+    }
+
+    #[test]
+    fn test_scanner_config() {
+        let defaults = ScannerConfig::default();
+        assert_eq!(defaults.level_for("WINE-SEH-AV"), Some(LogLevel::Error));
+        assert_eq!(
+            defaults.level_for("WINE-NTDLL-003"),
+            Some(LogLevel::Warning)
+        );
+
+        let config = crate::config::UserConfig::parse(
+            "[logging]\nfixme = \"off\"\nerror = \"error\"\nmute = [\"err:x11drv\", \"dx-not_found\"]\n",
+        );
+        let scanner = ScannerConfig::from_config(&config).unwrap();
+        assert_eq!(scanner.level_for("WINE-NTDLL-003"), None);
+        assert_eq!(scanner.level_for("WINE-MODULE-001"), Some(LogLevel::Error));
+        assert_eq!(scanner.level_for("WINE-DISPLAY-002"), None);
+        assert_eq!(scanner.level_for("DX-NOT_FOUND"), None);
+        assert_eq!(scanner.level_for("CRASH-SEGFAULT"), Some(LogLevel::Error));
+
+        let invalid = crate::config::UserConfig::parse("[logging]\ncrash = \"loud\"\n");
+        assert!(ScannerConfig::from_config(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_classify_output_line() {
+        let cases = [
+            (
+                "1234.567:0024:0028:fixme:d3d:wined3d_guess_card No card selector",
+                Some((LogSource::Wine, LogLevel::Warning)),
+            ),
+            (
+                "trace:seh:dispatch_exception code=c0000005",
+                Some((LogSource::Wine, LogLevel::Debug)),
+            ),
+            ("info:  DXVK: v2.3", Some((LogSource::Dxvk, LogLevel::Info))),
+            (
+                "err:   DxgiSwapChain: Failed to create surface",
+                Some((LogSource::Dxvk, LogLevel::Error)),
+            ),
+            (
+                "0f84:err:vkd3d_create_device: Failed to create Vulkan device.",
+                Some((LogSource::Vkd3d, LogLevel::Error)),
+            ),
+            (
+                "0f84:info:vkd3d_instance_init: vkd3d-proton - build: 1d14f4b",
+                Some((LogSource::Vkd3d, LogLevel::Info)),
+            ),
+            (
+                "Proton: Upgrading prefix from None to 8.0-104",
+                Some((LogSource::Proton, LogLevel::Info)),
+            ),
+            (
+                "pressure-vessel-wrap[4321]: W: Unable to find Vulkan ICD",
+                Some((LogSource::Proton, LogLevel::Warning)),
+            ),
+            (
+                "[S_API FAIL] SteamAPI_Init() failed",
+                Some((LogSource::Proton, LogLevel::Error)),
+            ),
+            ("Note: game started", None),
+            ("error code: 5", None),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(classify_output_line(line), expected, "{}", line);
+        }
+
+        assert_eq!(
+            split_program_output("[game.exe] stderr: info:  DXVK: v2.3"),
+            Some(("game.exe", "info:  DXVK: v2.3"))
+        );
+        assert_eq!(split_program_output("Executed: game.exe"), None);
+    }
+
+    #[test]
+    fn test_normalize_log_message() {
+        let a = normalize_log_message(
+            "0024:fixme:heap:RtlSetHeapInformation 0x7fe1a000 0 0x3c6e0 4 stub",
+        );
+        let b = normalize_log_message(
+            "0110:fixme:heap:RtlSetHeapInformation 0x7fe2c000 0 0x3c6e0 4 stub",
+        );
+        assert_eq!(a, b);
+        assert_eq!(a, "fixme:heap:RtlSetHeapInformation <addr> 0 <addr> 4 stub");
+        assert_eq!(
+            normalize_log_message("pressure-vessel-wrap[4321]: W: pid 4321 at 12:03:44.512"),
+            "pressure-vessel-wrap[<pid>]: W: pid <pid> at <time>"
+        );
+        assert_eq!(
+            normalize_log_message("Loaded d3d11.dll at 00007FF6A1B20000"),
+            "Loaded d3d11.dll at <addr>"
+        );
+        assert_eq!(
+            normalize_log_message("Installing vcrun2022 (1 of 3)"),
+            "Installing vcrun2022 (1 of 3)"
+        );
+    }
 }
 
 /// Read the last N lines from the current log file.
@@ -460,16 +865,275 @@ pub fn tail_log(lines: usize) -> Vec<String> {
     }
 }
 
+/// Component a log line came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogSource {
+    /// protontool's own messages
+    Protontool,
+    /// Output of a launched program that matches no known format
+    Program,
+    /// Wine's `err:channel:function` style lines
+    Wine,
+    /// DXVK's `info:  message` style lines
+    Dxvk,
+    /// vkd3d-proton's `tid:err:function: message` lines
+    Vkd3d,
+    /// Proton script, pressure-vessel and other bracket-prefixed lines
+    Proton,
+}
+
+impl LogSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogSource::Protontool => "protontool",
+            LogSource::Program => "program",
+            LogSource::Wine => "wine",
+            LogSource::Dxvk => "dxvk",
+            LogSource::Vkd3d => "vkd3d",
+            LogSource::Proton => "proton",
+        }
+    }
+}
+
+impl std::fmt::Display for LogSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Level of a Wine, DXVK or vkd3d level word.
+fn level_from_word(word: &str) -> Option<LogLevel> {
+    match word {
+        "err" | "error" => Some(LogLevel::Error),
+        "warn" | "fixme" => Some(LogLevel::Warning),
+        "info" => Some(LogLevel::Info),
+        "trace" | "debug" => Some(LogLevel::Debug),
+        _ => None,
+    }
+}
+
+/// Level of pressure-vessel's single-letter `E:`/`W:`/`I:`/`D:` markers.
+fn level_from_letter(letter: &str) -> Option<LogLevel> {
+    match letter {
+        "E" | "C" => Some(LogLevel::Error),
+        "W" => Some(LogLevel::Warning),
+        "I" | "N" => Some(LogLevel::Info),
+        "D" => Some(LogLevel::Debug),
+        _ => None,
+    }
+}
+
+/// Work out which component printed a line of program output and at what
+/// level. Recognizes:
+///
+/// - Wine: `err:module:import_dll ...`, optionally prefixed with a
+///   `+timestamp` and `pid:tid:` fields
+/// - DXVK: `info:  DXVK: v2.3`, `err:   D3D11Device: ...`
+/// - vkd3d-proton: `0f84:err:vkd3d_create_device: ...` and `vkd3d-proton:` lines
+/// - Proton: `Proton: ...`, `pressure-vessel-wrap[123]: W: ...` and
+///   bracket-prefixed lines such as `[S_API FAIL] ...`
+///
+/// ```
+/// use protontool::log::{classify_output_line, LogLevel, LogSource};
+/// assert_eq!(
+///     classify_output_line("0024:err:module:import_dll Library MSVCP140.dll not found"),
+///     Some((LogSource::Wine, LogLevel::Error))
+/// );
+/// assert_eq!(
+///     classify_output_line("warn:  D3D11DeviceContext: Unsupported format"),
+///     Some((LogSource::Dxvk, LogLevel::Warning))
+/// );
+/// assert_eq!(classify_output_line("Loading game data..."), None);
+/// ```
+pub fn classify_output_line(line: &str) -> Option<(LogSource, LogLevel)> {
+    let line = line.trim_end();
+
+    if let Some(rest) = line.strip_prefix("Proton:") {
+        let lower = rest.to_lowercase();
+        let level = if lower.contains("error") || lower.contains("fail") {
+            LogLevel::Error
+        } else {
+            LogLevel::Info
+        };
+        return Some((LogSource::Proton, level));
+    }
+    if line.starts_with("vkd3d-proton:") || line.starts_with("vkd3d:") {
+        return Some((LogSource::Vkd3d, LogLevel::Info));
+    }
+    if let Some(rest) = line.strip_prefix('[') {
+        // [S_API FAIL] SteamAPI_Init() failed
+        let (tag, _) = rest.split_once(']')?;
+        if tag.is_empty() || tag.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        let tag = tag.to_lowercase();
+        let level = if tag.contains("fail") || tag.contains("err") {
+            LogLevel::Error
+        } else if tag.contains("warn") {
+            LogLevel::Warning
+        } else {
+            LogLevel::Info
+        };
+        return Some((LogSource::Proton, level));
+    }
+    if let Some((head, rest)) = line.split_once("]: ") {
+        // pressure-vessel-wrap[1234]: W: message
+        if head.starts_with("pressure-vessel") || head.starts_with("steam-runtime") {
+            let level = rest
+                .split_once(": ")
+                .and_then(|(letter, _)| level_from_letter(letter))
+                .unwrap_or(LogLevel::Info);
+            return Some((LogSource::Proton, level));
+        }
+    }
+
+    let (word, after) = strip_thread_prefix(line).split_once(':')?;
+    let level = level_from_word(word)?;
+    if after.starts_with(char::is_whitespace) {
+        return Some((LogSource::Dxvk, level));
+    }
+    let (channel, _) = after.split_once(':')?;
+    if channel.is_empty()
+        || !channel
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    if channel.starts_with("vkd3d") || channel.starts_with("d3d12_") {
+        return Some((LogSource::Vkd3d, level));
+    }
+    Some((LogSource::Wine, level))
+}
+
+/// Strip Wine's `+timestamp` and `pid:tid:` fields from the start of a line
+/// when a level word (`err:`, `info:`, ...) follows them.
+fn strip_thread_prefix(line: &str) -> &str {
+    let mut rest = line;
+    while let Some((field, after)) = rest.split_once(':') {
+        if field.is_empty() || !field.chars().all(|c| c.is_ascii_hexdigit() || c == '.') {
+            break;
+        }
+        rest = after;
+    }
+    match rest.split_once(':') {
+        Some((word, _)) if level_from_word(word).is_some() => rest,
+        _ => line,
+    }
+}
+
+/// Length of a `HH:MM:SS` or `HH:MM:SS.mmm` time at the start of `s`.
+fn time_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let digits = |from: usize| {
+        bytes[from.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let hours = digits(0);
+    if !(1..=2).contains(&hours) {
+        return None;
+    }
+    let mut end = hours;
+    for _ in 0..2 {
+        if bytes.get(end) != Some(&b':') || digits(end + 1) != 2 {
+            return None;
+        }
+        end += 3;
+    }
+    if bytes.get(end) == Some(&b'.') && digits(end + 1) > 0 {
+        end += 1 + digits(end + 1);
+    }
+    Some(end)
+}
+
+/// Mask the parts of a log message that differ between otherwise identical
+/// lines, so they deduplicate: Wine's thread prefix is dropped, times become
+/// `<time>`, hex addresses `<addr>`, and process/thread ids `<pid>`.
+///
+/// ```
+/// use protontool::log::normalize_log_message;
+/// assert_eq!(
+///     normalize_log_message("0024:err:ntdll:RtlpWaitForCriticalSection section 0x7bc4a1e0 wait timed out in thread 0024"),
+///     "err:ntdll:RtlpWaitForCriticalSection section <addr> wait timed out in thread <pid>"
+/// );
+/// ```
+pub fn normalize_log_message(message: &str) -> String {
+    let message = strip_thread_prefix(message.trim());
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    let mut previous = String::new();
+
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphanumeric()) {
+        out.push_str(&rest[..start]);
+        let token_start = &rest[start..];
+        if let Some(len) = time_len(token_start) {
+            out.push_str("<time>");
+            rest = &token_start[len..];
+            previous.clear();
+            continue;
+        }
+        let len = token_start
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(token_start.len());
+        let token = &token_start[..len];
+        rest = &token_start[len..];
+
+        let hex_digits = token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"));
+        let is_address = match hex_digits {
+            Some(digits) => !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit()),
+            None => {
+                token.len() >= 8
+                    && token.chars().all(|c| c.is_ascii_hexdigit())
+                    && token.chars().any(|c| c.is_ascii_digit())
+            }
+        };
+        let is_id = token.chars().all(|c| c.is_ascii_hexdigit())
+            && token.chars().any(|c| c.is_ascii_digit())
+            && (matches!(previous.as_str(), "pid" | "tid" | "thread" | "process")
+                || (out.ends_with('[') && rest.starts_with(']')));
+
+        if is_address {
+            out.push_str("<addr>");
+        } else if is_id {
+            out.push_str("<pid>");
+        } else {
+            out.push_str(token);
+        }
+        previous = token.to_lowercase();
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Split a logged line of program output (`[game.exe] stderr: LINE`) into
+/// the executable and the line.
+fn split_program_output(message: &str) -> Option<(&str, &str)> {
+    let (executable, rest) = message.strip_prefix('[')?.split_once("] ")?;
+    let line = rest
+        .strip_prefix("stdout: ")
+        .or_else(|| rest.strip_prefix("stderr: "))?;
+    Some((executable, line))
+}
+
 /// Parsed log entry for the viewer
 #[derive(Debug, Clone)]
 pub struct LogEntry {
     pub timestamp: String,
     pub level: String,
+    /// Component that produced the message
+    pub source: LogSource,
     pub message: String,
     pub count: usize,
 }
 
-/// Parse log file and deduplicate entries by (level, message).
+/// Parse log file and deduplicate entries by (level, source, message), with
+/// addresses, ids and times masked by `normalize_log_message`.
+/// Logged program output is attributed to Wine, DXVK, vkd3d or Proton when
+/// it matches their formats, taking its level from the line itself.
 /// Returns entries sorted by timestamp (most recent first) with occurrence counts.
 pub fn parse_log_deduplicated(
     show_error: bool,
@@ -479,7 +1143,7 @@ pub fn parse_log_deduplicated(
     search_filter: Option<&str>,
 ) -> Vec<LogEntry> {
     let log_path = get_current_log_path();
-    let mut entries: std::collections::HashMap<(String, String), LogEntry> =
+    let mut entries: std::collections::HashMap<(String, LogSource, String), LogEntry> =
         std::collections::HashMap::new();
 
     let file = match File::open(&log_path) {
@@ -499,11 +1163,22 @@ pub fn parse_log_deduplicated(
 
         let timestamp = parts[0].trim_start_matches('[').to_string();
         let level_part = parts.get(1).unwrap_or(&"");
-        let level = level_part
+        let mut level = level_part
             .trim_start_matches('[')
             .trim_end_matches(']')
             .to_string();
-        let message = parts.get(2).map(|s| s.to_string()).unwrap_or_default();
+        let mut message = parts.get(2).map(|s| s.to_string()).unwrap_or_default();
+        let mut source = LogSource::Protontool;
+
+        if let Some((_, output)) = split_program_output(&message) {
+            source = LogSource::Program;
+            if let Some((component, component_level)) = classify_output_line(output) {
+                source = component;
+                level = component_level.to_string();
+                message = output.trim().to_string();
+            }
+        }
+        let message = normalize_log_message(&message);
 
         // Filter by level
         let include = match level.as_str() {
@@ -523,13 +1198,14 @@ pub fn parse_log_deduplicated(
             let filter_lower = filter.to_lowercase();
             if !message.to_lowercase().contains(&filter_lower)
                 && !level.to_lowercase().contains(&filter_lower)
+                && !source.name().contains(&filter_lower)
             {
                 continue;
             }
         }
 
-        // Deduplicate by (level, message)
-        let key = (level.clone(), message.clone());
+        // Deduplicate by (level, source, message)
+        let key = (level.clone(), source, message.clone());
         if let Some(entry) = entries.get_mut(&key) {
             entry.count += 1;
             entry.timestamp = timestamp; // Update to latest timestamp
@@ -539,6 +1215,7 @@ pub fn parse_log_deduplicated(
                 LogEntry {
                     timestamp,
                     level,
+                    source,
                     message,
                     count: 1,
                 },
@@ -5,15 +5,39 @@
 //! - Creating and managing custom prefixes
 //! - Running applications with proper Wine/Proton environment
 
+pub mod analyze;
+pub mod audit;
 pub mod cli;
 pub mod config;
+pub mod controller;
+pub mod desktop;
+pub mod distro;
+pub mod doctor;
+pub mod flatpak;
 pub mod gui;
+pub mod jobs;
+pub mod journal;
+pub mod json;
+pub mod launch_options;
 pub mod log;
+pub mod migrate;
+pub mod notes;
+pub mod notify;
+pub mod permissions;
+pub mod play;
+pub mod prewarm;
+pub mod recipe;
+pub mod report;
+pub mod search;
+pub mod state;
+pub mod status;
 pub mod steam;
 pub mod util;
 pub mod vdf;
+pub mod version;
 pub mod wine;
 pub mod wine_data;
+pub mod workdir;
 pub use cli::main_cli;
 pub use wine::*;
 
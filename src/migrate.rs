@@ -0,0 +1,266 @@
+//! Moving protontool's setup to another machine.
+//!
+//! `protontool migrate export FILE` packs what a new PC or a distribution
+//! reinstall would lose into one tar archive, compressed as the file name
+//! says (`.tar.zst`, `.tar.gz`, `.tar.xz`, ...): `config.toml`, custom verbs
+//! and plugins, the state store (accepted licenses, undo journal, notes,
+//! prefix roots, ...) and a recipe for each Steam game protontool set up.
+//! The recipes carry the verbs from each game's ledger along with its
+//! environment profile, DLL overrides, launch options and notes, since
+//! Steam recreates game prefixes rather than restoring them. With
+//! `--with-prefixes` the custom prefixes in `~/.protontool/pfx`, ledgers
+//! included, are archived too.
+//!
+//! `protontool migrate import FILE` unpacks an archive into
+//! `~/.protontool`. The game recipes end up in `~/.protontool/recipes`,
+//! ready for `protontool recipe apply` once the games have been installed
+//! and launched.
+//!
+//! The archive starts with a `protontool-migration` manifest of `key=value`
+//! lines naming the format, the protontool version that wrote it and the
+//! top-level entries it holds.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::recipe::Recipe;
+use crate::util::{output_stderr_to_string, which};
+use crate::workdir::WorkDir;
+
+/// Archive format version written and accepted.
+pub const FORMAT: u32 = 1;
+
+/// File name of the manifest inside an archive.
+const MANIFEST_FILE: &str = "protontool-migration";
+
+/// Directory holding the game recipes, in archives and `~/.protontool`.
+pub const RECIPES_DIR: &str = "recipes";
+
+/// Custom prefix directory, archived with `--with-prefixes`.
+const PREFIXES_DIR: &str = "pfx";
+
+/// Parts of `~/.protontool` always archived (when present), with what
+/// they hold.
+const ENTRIES: &[(&str, &str)] = &[
+    ("config.toml", "settings"),
+    ("verb", "custom verbs"),
+    ("plugin", "verb plugins"),
+    ("state", "licenses, undo journal, notes and other state"),
+];
+
+/// What a migration archive holds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub format: u32,
+    /// protontool version that wrote the archive.
+    pub version: String,
+    pub created: u64,
+    /// Top-level entries besides the manifest (`config.toml`, `state`,
+    /// `recipes`, `pfx`, ...).
+    pub entries: Vec<String>,
+}
+
+impl Manifest {
+    fn to_text(&self) -> String {
+        format!(
+            "format={}\nversion={}\ncreated={}\nentries={}\n",
+            self.format,
+            self.version,
+            self.created,
+            self.entries.join(",")
+        )
+    }
+
+    /// Parse a manifest, rejecting newer formats and unknown entries.
+    ///
+    /// ```
+    /// use protontool::migrate::Manifest;
+    /// let manifest = Manifest::parse("format=1\nversion=1.4.0\ncreated=0\nentries=config.toml,state,recipes\n").unwrap();
+    /// assert_eq!(manifest.entries, ["config.toml", "state", "recipes"]);
+    /// assert!(Manifest::parse("format=99\nentries=state\n").is_err());
+    /// assert!(Manifest::parse("format=1\nentries=../.bashrc\n").is_err());
+    /// ```
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut manifest = Manifest::default();
+        for (key, value) in content.lines().filter_map(|line| line.split_once('=')) {
+            match key.trim() {
+                "format" => manifest.format = value.trim().parse().unwrap_or_default(),
+                "version" => manifest.version = value.trim().to_string(),
+                "created" => manifest.created = value.trim().parse().unwrap_or_default(),
+                "entries" => {
+                    manifest.entries = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|e| !e.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+        if manifest.format == 0 {
+            return Err("Not a protontool migration archive".to_string());
+        }
+        if manifest.format > FORMAT {
+            return Err(format!(
+                "Migration format {} is newer than this protontool supports ({})",
+                manifest.format, FORMAT
+            ));
+        }
+        if let Some(entry) = manifest.entries.iter().find(|e| !is_known_entry(e)) {
+            return Err(format!("Unexpected entry in migration archive: {}", entry));
+        }
+        Ok(manifest)
+    }
+
+    /// Multi-line summary for review before importing.
+    pub fn describe(&self) -> String {
+        let [year, month, day, ..] = crate::util::utc_datetime(self.created);
+        let mut lines = vec![format!(
+            "Exported by protontool {} on {:04}-{:02}-{:02}",
+            self.version, year, month, day
+        )];
+        for entry in &self.entries {
+            let what = match entry.as_str() {
+                RECIPES_DIR => "recipes of the games set up",
+                PREFIXES_DIR => "custom prefixes",
+                name => ENTRIES
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .map_or("", |(_, what)| what),
+            };
+            lines.push(format!("  {:<12} {}", entry, what));
+        }
+        lines.join("\n")
+    }
+}
+
+fn is_known_entry(entry: &str) -> bool {
+    entry == RECIPES_DIR || entry == PREFIXES_DIR || ENTRIES.iter().any(|(n, _)| *n == entry)
+}
+
+/// Whether a game's recipe holds anything protontool set up, so it is
+/// worth carrying over. DLL overrides alone don't count, as Proton writes
+/// its own.
+pub fn has_setup(recipe: &Recipe) -> bool {
+    !recipe.verbs.is_empty()
+        || !recipe.env.is_empty()
+        || recipe.launch_options.is_some()
+        || !recipe.tags.is_empty()
+        || recipe.notes.is_some()
+}
+
+fn run_tar(args: &[&str]) -> Result<std::process::Output, String> {
+    let tar = which("tar").ok_or("tar is required for migration archives")?;
+    let output = Command::new(tar)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run tar: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("tar failed: {}", output_stderr_to_string(&output)));
+    }
+    Ok(output)
+}
+
+fn absolute(path: &Path) -> Result<PathBuf, String> {
+    std::path::absolute(path).map_err(|e| format!("Invalid path {}: {}", path.display(), e))
+}
+
+/// Write a migration archive of `~/.protontool` with the given game
+/// recipes, and with `with_prefixes` the custom prefixes.
+pub fn export(archive: &Path, recipes: &[Recipe], with_prefixes: bool) -> Result<Manifest, String> {
+    let base = crate::config::get_base_dir();
+    let work_dir = WorkDir::create("migrate")?;
+
+    let mut base_entries: Vec<&str> = ENTRIES
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| base.join(name).exists())
+        .collect();
+    if with_prefixes && base.join(PREFIXES_DIR).is_dir() {
+        base_entries.push(PREFIXES_DIR);
+    }
+    let mut work_entries = vec![MANIFEST_FILE];
+    if !recipes.is_empty() {
+        let recipes_dir = work_dir.path().join(RECIPES_DIR);
+        fs::create_dir_all(&recipes_dir)
+            .map_err(|e| format!("Failed to create {}: {}", recipes_dir.display(), e))?;
+        for recipe in recipes {
+            recipe.save(&recipes_dir.join(format!("{}.json", recipe.appid)))?;
+        }
+        work_entries.push(RECIPES_DIR);
+    }
+
+    let manifest = Manifest {
+        format: FORMAT,
+        version: crate::VERSION.to_string(),
+        created: crate::wine::usage::now_secs(),
+        entries: base_entries
+            .iter()
+            .chain(&work_entries[1..])
+            .map(|e| e.to_string())
+            .collect(),
+    };
+    let manifest_path = work_dir.path().join(MANIFEST_FILE);
+    fs::write(&manifest_path, manifest.to_text())
+        .map_err(|e| format!("Failed to write {}: {}", manifest_path.display(), e))?;
+
+    let archive = absolute(archive)?.to_string_lossy().into_owned();
+    let work_path = work_dir.path().to_string_lossy().into_owned();
+    let base_path = base.to_string_lossy().into_owned();
+    let mut args = vec![
+        "--create",
+        "--auto-compress",
+        "--sparse",
+        "--file",
+        &archive,
+        "-C",
+        &work_path,
+    ];
+    args.extend(&work_entries);
+    if !base_entries.is_empty() {
+        args.extend(["-C", &base_path]);
+        args.extend(&base_entries);
+    }
+    run_tar(&args)?;
+    Ok(manifest)
+}
+
+/// Read the manifest of a migration archive.
+pub fn read_manifest(archive: &Path) -> Result<Manifest, String> {
+    let archive = archive.to_string_lossy();
+    let output = run_tar(&[
+        "--extract",
+        "--to-stdout",
+        "--file",
+        &archive,
+        MANIFEST_FILE,
+    ])
+    .map_err(|_| format!("{} is not a protontool migration archive", archive))?;
+    Manifest::parse(&String::from_utf8_lossy(&output.stdout))
+        .map_err(|e| format!("{}: {}", archive, e))
+}
+
+/// Paths in `~/.protontool` an import of the archive would replace.
+pub fn existing_entries(manifest: &Manifest) -> Vec<PathBuf> {
+    let base = crate::config::get_base_dir();
+    manifest
+        .entries
+        .iter()
+        .map(|entry| base.join(entry))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Unpack a migration archive into `~/.protontool`. Only the entries the
+/// manifest lists are extracted.
+pub fn import(archive: &Path, manifest: &Manifest) -> Result<(), String> {
+    let base = crate::config::get_base_dir();
+    fs::create_dir_all(&base).map_err(|e| format!("Failed to create {}: {}", base.display(), e))?;
+    let archive = absolute(archive)?.to_string_lossy().into_owned();
+    let base_path = base.to_string_lossy().into_owned();
+    let mut args = vec!["--extract", "--file", &archive, "-C", &base_path];
+    args.extend(manifest.entries.iter().map(|e| e.as_str()));
+    run_tar(&args).map(|_| ())
+}
@@ -0,0 +1,182 @@
+//! Notes and tags on games and prefixes.
+//!
+//! What a user learned about a game ("needs dotnet48", "works on GE9-20
+//! only") is kept in the `notes` state record, one line per game or custom
+//! prefix: `app:APPID` or the prefix's canonical path, the comma-separated
+//! tags and the note, separated by tabs. Line breaks and tabs in notes are
+//! stored escaped. Tags are shown next to the game in listings and the GUI,
+//! and exported recipes carry the notes of their game.
+
+use std::path::Path;
+
+/// State record holding the notes.
+const NOTES_STATE: &str = "notes";
+
+/// What notes are attached to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Subject<'a> {
+    App(u32),
+    Prefix(&'a Path),
+}
+
+impl Subject<'_> {
+    fn key(&self) -> String {
+        match self {
+            Subject::App(appid) => format!("app:{}", appid),
+            Subject::Prefix(path) => path
+                .canonicalize()
+                .unwrap_or_else(|_| path.to_path_buf())
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+}
+
+/// Tags and a free-form note.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Notes {
+    pub tags: Vec<String>,
+    pub note: String,
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => out.push('\n'),
+            ('\\', Some('t')) => out.push('\t'),
+            ('\\', Some('\\')) => out.push('\\'),
+            _ => {
+                out.push(c);
+                continue;
+            }
+        }
+        chars.next();
+    }
+    out
+}
+
+/// Split a comma-separated tag list, trimming and dropping empty and
+/// repeated tags.
+///
+/// ```
+/// use protontool::notes::parse_tags;
+/// assert_eq!(parse_tags(" needs dotnet48,works on GE9-20 only,, needs dotnet48"),
+///            vec!["needs dotnet48", "works on GE9-20 only"]);
+/// ```
+pub fn parse_tags(list: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in list.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+impl Notes {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.note.trim().is_empty()
+    }
+
+    /// Tags in brackets, e.g. `[needs dotnet48] [works on GE9-20 only]`.
+    pub fn tags_label(&self) -> String {
+        let tags: Vec<String> = self.tags.iter().map(|t| format!("[{}]", t)).collect();
+        tags.join(" ")
+    }
+
+    /// One-line summary: the tags and the note's first line.
+    pub fn summary(&self) -> String {
+        let first_line = self.note.lines().next().unwrap_or_default().trim();
+        [self.tags_label().as_str(), first_line]
+            .iter()
+            .filter(|part| !part.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Add tags that aren't there yet.
+    pub fn add_tags(&mut self, tags: &[String]) {
+        for tag in tags {
+            if !self.tags.contains(tag) {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Option<(String, Notes)> {
+    let mut fields = line.splitn(3, '\t');
+    let key = fields.next()?.to_string();
+    let tags = parse_tags(&unescape(fields.next()?));
+    let note = unescape(fields.next().unwrap_or_default());
+    Some((key, Notes { tags, note }))
+}
+
+/// Notes of a game or prefix (empty when none were written).
+pub fn get(subject: &Subject) -> Notes {
+    let key = subject.key();
+    crate::state::read_lines(NOTES_STATE)
+        .iter()
+        .filter_map(|line| parse_line(line))
+        .find(|(k, _)| *k == key)
+        .map(|(_, notes)| notes)
+        .unwrap_or_default()
+}
+
+/// Replace the notes of a game or prefix; empty notes remove the entry.
+pub fn set(subject: &Subject, notes: &Notes) -> Result<(), String> {
+    let key = subject.key();
+    let mut lines: Vec<String> = crate::state::read_lines(NOTES_STATE)
+        .into_iter()
+        .filter(|line| parse_line(line).is_none_or(|(k, _)| k != key))
+        .collect();
+    if !notes.is_empty() {
+        lines.push(format!(
+            "{}\t{}\t{}",
+            key,
+            escape(&notes.tags.join(",")),
+            escape(notes.note.trim())
+        ));
+    }
+    let mut content = lines.join("\n");
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    crate::state::write(NOTES_STATE, &content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_round_trip() {
+        let notes = Notes {
+            tags: vec!["needs dotnet48".to_string(), "GE9-20".to_string()],
+            note: "Crashes on launch with\n\tProton 8 \\ 9".to_string(),
+        };
+        let line = format!(
+            "app:620\t{}\t{}",
+            escape(&notes.tags.join(",")),
+            escape(&notes.note)
+        );
+        assert!(!line.contains('\n'));
+        assert_eq!(
+            parse_line(&line),
+            Some(("app:620".to_string(), notes.clone()))
+        );
+        assert_eq!(
+            notes.summary(),
+            "[needs dotnet48] [GE9-20] Crashes on launch with"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Desktop notifications when long operations finish.
+//!
+//! A batch of verbs that takes longer than a threshold ends with a desktop
+//! notification (`notify-send`) and a sound, so a user who switched away
+//! during a long install knows when to come back. Success and failure get
+//! different icons and sounds. Set in `config.toml`:
+//!
+//! ```toml
+//! [notifications]
+//! enabled = "on"
+//! after_secs = "60"
+//! sound = "on"
+//! success_sound = "complete"
+//! failure_sound = "dialog-error"
+//! ```
+//!
+//! Sounds are sound theme event names (played with `canberra-gtk-play`, or
+//! the freedesktop theme file with `pw-play`/`paplay`) or paths to sound
+//! files.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::config::UserConfig;
+use crate::util::which;
+
+/// `config.toml` section holding the notification settings.
+const SECTION: &str = "notifications";
+
+/// Operations shorter than this don't notify by default.
+pub const DEFAULT_AFTER: Duration = Duration::from_secs(60);
+
+/// Directory of the freedesktop sound theme's event sounds.
+const SOUND_THEME_DIR: &str = "/usr/share/sounds/freedesktop/stereo";
+
+/// Notification settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifyConfig {
+    pub enabled: bool,
+    /// Minimum duration of an operation that notifies.
+    pub after: Duration,
+    /// Sound for success, None when sounds are off.
+    pub success_sound: Option<String>,
+    /// Sound for failure, None when sounds are off.
+    pub failure_sound: Option<String>,
+}
+
+impl Default for NotifyConfig {
+    fn default() -> Self {
+        NotifyConfig {
+            enabled: true,
+            after: DEFAULT_AFTER,
+            success_sound: Some("complete".to_string()),
+            failure_sound: Some("dialog-error".to_string()),
+        }
+    }
+}
+
+impl NotifyConfig {
+    /// Read the `[notifications]` section. Unknown values keep the defaults.
+    pub fn from_config(config: &UserConfig) -> Self {
+        let mut settings = NotifyConfig::default();
+        let off = |key: &str| config.get(SECTION, key) == Some("off");
+        settings.enabled = !off("enabled");
+        if let Some(secs) = config
+            .get(SECTION, "after_secs")
+            .and_then(|s| s.parse::<u64>().ok())
+        {
+            settings.after = Duration::from_secs(secs);
+        }
+        if off("sound") {
+            settings.success_sound = None;
+            settings.failure_sound = None;
+        } else {
+            if let Some(sound) = config.get(SECTION, "success_sound") {
+                settings.success_sound = Some(sound.to_string());
+            }
+            if let Some(sound) = config.get(SECTION, "failure_sound") {
+                settings.failure_sound = Some(sound.to_string());
+            }
+        }
+        settings
+    }
+
+    /// Whether an operation that took `elapsed` should notify.
+    pub fn applies_to(&self, elapsed: Duration) -> bool {
+        self.enabled && elapsed >= self.after
+    }
+}
+
+/// Whether a graphical session is available to show notifications in.
+fn has_display() -> bool {
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| std::env::var(var).is_ok_and(|v| !v.is_empty()))
+}
+
+/// Start a command without waiting for it, discarding its output.
+fn spawn_quiet(mut cmd: Command) {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok();
+}
+
+/// Play a sound theme event or sound file.
+fn play_sound(sound: &str) {
+    if !Path::new(sound).is_file() {
+        if let Some(canberra) = which("canberra-gtk-play") {
+            let mut cmd = Command::new(canberra);
+            cmd.args(["-i", sound]);
+            spawn_quiet(cmd);
+            return;
+        }
+    }
+    let file = if Path::new(sound).is_file() {
+        sound.to_string()
+    } else {
+        format!("{}/{}.oga", SOUND_THEME_DIR, sound)
+    };
+    if !Path::new(&file).is_file() {
+        return;
+    }
+    if let Some(player) = ["pw-play", "paplay"].iter().find_map(|p| which(p)) {
+        let mut cmd = Command::new(player);
+        cmd.arg(file);
+        spawn_quiet(cmd);
+    }
+}
+
+/// Show a notification that a long operation finished, if it took long
+/// enough and notifications are enabled.
+pub fn operation_finished(summary: &str, body: &str, succeeded: bool, elapsed: Duration) {
+    let settings = NotifyConfig::from_config(&UserConfig::load());
+    if !settings.applies_to(elapsed) || !has_display() {
+        return;
+    }
+    if let Some(notify_send) = which("notify-send") {
+        let mut cmd = Command::new(notify_send);
+        cmd.args(["--app-name", "protontool", "--icon"])
+            .arg(if succeeded {
+                "dialog-information"
+            } else {
+                "dialog-error"
+            })
+            .args(["--urgency", if succeeded { "normal" } else { "critical" }])
+            .arg(summary)
+            .arg(body);
+        spawn_quiet(cmd);
+    }
+    let sound = if succeeded {
+        &settings.success_sound
+    } else {
+        &settings.failure_sound
+    };
+    if let Some(sound) = sound {
+        play_sound(sound);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notify_config() {
+        let defaults = NotifyConfig::from_config(&UserConfig::default());
+        assert_eq!(defaults, NotifyConfig::default());
+        assert!(!defaults.applies_to(Duration::from_secs(30)));
+        assert!(defaults.applies_to(Duration::from_secs(600)));
+
+        let config = UserConfig::parse(
+            "[notifications]\nafter_secs = \"5\"\nsound = \"off\"\nfailure_sound = \"bell\"\n",
+        );
+        let settings = NotifyConfig::from_config(&config);
+        assert!(settings.applies_to(Duration::from_secs(5)));
+        assert_eq!(settings.failure_sound, None);
+
+        let config = UserConfig::parse("[notifications]\nenabled = \"off\"\n");
+        assert!(!NotifyConfig::from_config(&config).applies_to(Duration::from_secs(600)));
+    }
+}
@@ -0,0 +1,227 @@
+//! Allowlist policy for operations requested over IPC (`protontool serve`).
+//!
+//! Clients of the long-running protontool service (desktop widgets, scripts)
+//! only get the operation classes, app IDs and prefixes granted in the
+//! `[daemon]` section of `config.toml`:
+//!
+//! ```toml
+//! [daemon]
+//! allow = ["read", "launch"]
+//! appids = ["570", "730"]
+//! prefixes = ["~/.protontool/pfx/work"]
+//! ```
+//!
+//! Without configuration only `read` is allowed, so a widget can list games
+//! but can't modify or delete anything until explicitly granted.
+
+use std::path::{Path, PathBuf};
+
+use crate::config::UserConfig;
+
+/// Config section holding the policy.
+const POLICY_SECTION: &str = "daemon";
+
+/// Classes of operations that can be granted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationClass {
+    /// List games, prefixes, verbs and status.
+    Read,
+    /// Launch games or programs.
+    Launch,
+    /// Run verbs and change settings in existing prefixes.
+    Modify,
+    /// Create new prefixes.
+    Create,
+    /// Delete prefixes.
+    Delete,
+}
+
+impl OperationClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationClass::Read => "read",
+            OperationClass::Launch => "launch",
+            OperationClass::Modify => "modify",
+            OperationClass::Create => "create",
+            OperationClass::Delete => "delete",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "read" => Some(OperationClass::Read),
+            "launch" => Some(OperationClass::Launch),
+            "modify" => Some(OperationClass::Modify),
+            "create" => Some(OperationClass::Create),
+            "delete" => Some(OperationClass::Delete),
+            _ => None,
+        }
+    }
+
+    pub fn all() -> &'static [OperationClass] {
+        &[
+            OperationClass::Read,
+            OperationClass::Launch,
+            OperationClass::Modify,
+            OperationClass::Create,
+            OperationClass::Delete,
+        ]
+    }
+}
+
+/// What an operation acts on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// Global operations (e.g. listing all games).
+    None,
+    App(u32),
+    Prefix(PathBuf),
+}
+
+/// Permission policy for IPC clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionPolicy {
+    pub allowed: Vec<OperationClass>,
+    /// Permitted app IDs; `None` permits all.
+    pub appids: Option<Vec<u32>>,
+    /// Permitted prefixes (and everything below them); `None` permits all.
+    pub prefixes: Option<Vec<PathBuf>>,
+}
+
+impl Default for PermissionPolicy {
+    /// Read-only access to everything.
+    fn default() -> Self {
+        Self {
+            allowed: vec![OperationClass::Read],
+            appids: None,
+            prefixes: None,
+        }
+    }
+}
+
+/// `None` for a missing key or a `"*"` entry, otherwise the listed values.
+fn scoped_list(config: &UserConfig, key: &str) -> Option<Vec<String>> {
+    let values = config.get_array(POLICY_SECTION, key)?;
+    if values.iter().any(|v| v == "*") {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+impl PermissionPolicy {
+    /// Build the policy from the `[daemon]` section of a config.
+    pub fn from_config(config: &UserConfig) -> Result<Self, String> {
+        let mut policy = PermissionPolicy::default();
+
+        if let Some(allow) = config.get_array(POLICY_SECTION, "allow") {
+            policy.allowed = if allow.iter().any(|a| a == "*") {
+                OperationClass::all().to_vec()
+            } else {
+                allow
+                    .iter()
+                    .map(|a| {
+                        OperationClass::parse(a).ok_or_else(|| {
+                            format!("Unknown operation class '{}' in [daemon] allow", a)
+                        })
+                    })
+                    .collect::<Result<_, _>>()?
+            };
+        }
+
+        if let Some(appids) = scoped_list(config, "appids") {
+            policy.appids = Some(
+                appids
+                    .iter()
+                    .map(|a| {
+                        a.parse()
+                            .map_err(|_| format!("Invalid app ID '{}' in [daemon] appids", a))
+                    })
+                    .collect::<Result<_, _>>()?,
+            );
+        }
+
+        if let Some(prefixes) = scoped_list(config, "prefixes") {
+            policy.prefixes = Some(
+                prefixes
+                    .iter()
+                    .map(|p| PathBuf::from(crate::wine::custom::expand_path(p)))
+                    .collect(),
+            );
+        }
+
+        Ok(policy)
+    }
+
+    /// Load the policy from `config.toml`.
+    pub fn load() -> Result<Self, String> {
+        Self::from_config(&UserConfig::load())
+    }
+
+    fn prefix_allowed(&self, prefix: &Path) -> bool {
+        match &self.prefixes {
+            None => true,
+            Some(allowed) => allowed.iter().any(|p| prefix.starts_with(p)),
+        }
+    }
+
+    /// Check whether an operation on a target is permitted.
+    pub fn check(&self, op: OperationClass, target: &Target) -> Result<(), String> {
+        if !self.allowed.contains(&op) {
+            return Err(format!("Operation '{}' is not permitted", op.as_str()));
+        }
+        match target {
+            Target::None => Ok(()),
+            Target::App(appid) => match &self.appids {
+                Some(appids) if !appids.contains(appid) => {
+                    Err(format!("App {} is not permitted", appid))
+                }
+                _ => Ok(()),
+            },
+            Target::Prefix(prefix) if !self.prefix_allowed(prefix) => {
+                Err(format!("Prefix {} is not permitted", prefix.display()))
+            }
+            Target::Prefix(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_from_config() {
+        assert_eq!(
+            PermissionPolicy::from_config(&UserConfig::default()).unwrap(),
+            PermissionPolicy::default()
+        );
+
+        let config = UserConfig::parse(
+            "[daemon]\nallow = [\"read\", \"modify\"]\nappids = [\"570\"]\nprefixes = [\"/games/pfx\"]\n",
+        );
+        let policy = PermissionPolicy::from_config(&config).unwrap();
+        assert!(policy.check(OperationClass::Read, &Target::None).is_ok());
+        assert!(policy
+            .check(OperationClass::Modify, &Target::App(570))
+            .is_ok());
+        assert!(policy
+            .check(OperationClass::Modify, &Target::App(730))
+            .is_err());
+        assert!(policy
+            .check(OperationClass::Delete, &Target::App(570))
+            .is_err());
+        assert!(policy
+            .check(
+                OperationClass::Modify,
+                &Target::Prefix("/games/pfx/a".into())
+            )
+            .is_ok());
+        assert!(policy
+            .check(OperationClass::Modify, &Target::Prefix("/other".into()))
+            .is_err());
+
+        let bad = UserConfig::parse("[daemon]\nallow = [\"everything\"]\n");
+        assert!(PermissionPolicy::from_config(&bad).is_err());
+    }
+}
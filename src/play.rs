@@ -0,0 +1,255 @@
+//! Launching games for troubleshooting sessions.
+//!
+//! `protontool APPID play` starts a game through its mapped Proton's
+//! `proton` script the way Steam does, with the prefix environment profile
+//! applied (including its nice level, CPU affinity, GameMode request and
+//! memory settings) and all output captured to a log file that is analyzed after the
+//! game exits. If the game executable can't be determined, the launch is
+//! handed to Steam instead (`steam -applaunch`), without env or log capture.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::steam::{ProtonApp, SteamApp};
+use crate::util::which;
+use crate::wine::profile::EnvProfile;
+use crate::wine::scheduling::Scheduling;
+
+/// Executable names (lowercase substrings) that are never the game itself.
+const NON_GAME_EXECUTABLES: &[&str] = &[
+    "unins",
+    "setup",
+    "redist",
+    "vcredist",
+    "dxsetup",
+    "dotnet",
+    "installer",
+    "crash",
+    "report",
+    "prereq",
+    "easyanticheat",
+    "battleye",
+];
+
+/// Directory depth searched below the install dir (covers `Game/Binaries/Win64`).
+const MAX_SEARCH_DEPTH: usize = 4;
+
+/// Result of a play session.
+#[derive(Debug)]
+pub struct PlaySession {
+    /// Exit code of the game (or Steam when handed off).
+    pub exit_code: i32,
+    /// Captured output, when launched through Proton directly.
+    pub log_path: Option<PathBuf>,
+    /// Exception the game crashed with, found in the captured output.
+    pub exception: Option<crate::log::NtStatus>,
+}
+
+fn collect_executables(dir: &Path, depth: usize, found: &mut Vec<(u64, PathBuf)>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if depth < MAX_SEARCH_DEPTH {
+                collect_executables(&path, depth + 1, found);
+            }
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        if !name.ends_with(".exe") || NON_GAME_EXECUTABLES.iter().any(|n| name.contains(n)) {
+            continue;
+        }
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        found.push((size, path));
+    }
+}
+
+/// Guess the game executable: the largest `.exe` under the install dir
+/// that isn't an installer, redistributable or crash handler.
+pub fn find_game_executable(install_path: &Path) -> Option<PathBuf> {
+    let mut found = Vec::new();
+    collect_executables(install_path, 0, &mut found);
+    found
+        .into_iter()
+        .max_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)))
+        .map(|(_, path)| path)
+}
+
+/// Log file for a play session.
+fn session_log_path(appid: u32) -> PathBuf {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    crate::config::get_log_dir().join(format!("play-{}-{}.log", appid, timestamp))
+}
+
+/// Build the command Steam would run for a Proton game.
+pub fn proton_command(
+    steam_path: &Path,
+    app: &SteamApp,
+    proton_app: &ProtonApp,
+    exe: &Path,
+    args: &[String],
+    profile: &EnvProfile,
+) -> Command {
+    let compat_data = app.compat_data_path().unwrap_or_default();
+
+    let mut cmd = Command::new(proton_app.install_path.join("proton"));
+    cmd.arg("waitforexitandrun")
+        .arg(exe)
+        .args(args)
+        .current_dir(exe.parent().unwrap_or(&app.install_path))
+        .env("STEAM_COMPAT_DATA_PATH", compat_data)
+        .env("STEAM_COMPAT_CLIENT_INSTALL_PATH", steam_path)
+        .env("STEAM_COMPAT_INSTALL_PATH", &app.install_path)
+        .env("SteamAppId", app.appid.to_string())
+        .env("SteamGameId", app.appid.to_string());
+    if let Some(shader_cache) = app.shader_cache_path() {
+        cmd.env("STEAM_COMPAT_SHADER_PATH", shader_cache);
+    }
+    for (key, value) in &profile.vars {
+        cmd.env(key, value);
+    }
+    cmd
+}
+
+/// Launch a game and wait for it to exit. `dll_overrides` apply to this
+/// launch only, on top of the profile's WINEDLLOVERRIDES.
+pub fn play(
+    steam_path: &Path,
+    app: &SteamApp,
+    proton_app: &ProtonApp,
+    args: &[String],
+    dll_overrides: &[(String, String)],
+) -> Result<PlaySession, String> {
+    let exe = match find_game_executable(&app.install_path) {
+        Some(exe) => exe,
+        None => return steam_applaunch(app.appid, args),
+    };
+    let mut profile = app
+        .prefix_path
+        .as_deref()
+        .map(EnvProfile::load)
+        .unwrap_or_default();
+    if let Some(spec) = app
+        .prefix_path
+        .as_deref()
+        .and_then(crate::wine::winedebug::for_prefix)
+    {
+        profile.set("WINEDEBUG", &spec);
+    }
+    if !dll_overrides.is_empty() {
+        // Per-run overrides win over the profile's and the parent environment's
+        let base = profile
+            .get("WINEDLLOVERRIDES")
+            .map(|v| v.to_string())
+            .or_else(|| std::env::var("WINEDLLOVERRIDES").ok())
+            .unwrap_or_default();
+        let merged = crate::wine::merge_dll_overrides(&base, dll_overrides);
+        profile.set("WINEDLLOVERRIDES", &merged);
+    }
+
+    let log_path = session_log_path(app.appid);
+    if let Some(parent) = log_path.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    let log = File::create(&log_path)
+        .map_err(|e| format!("Failed to create {}: {}", log_path.display(), e))?;
+    let log_err = log
+        .try_clone()
+        .map_err(|e| format!("Failed to create {}: {}", log_path.display(), e))?;
+
+    crate::log::info(&format!(
+        "Launching {} ({}) via {}: {}",
+        app.name,
+        app.appid,
+        proton_app.name,
+        exe.display()
+    ));
+    if let Some(prefix) = &app.prefix_path {
+        crate::wine::usage::record_use(prefix);
+    }
+    let scheduling = Scheduling::from_profile(&profile);
+    let status = scheduling
+        .wrap(proton_command(
+            steam_path, app, proton_app, &exe, args, &profile,
+        ))
+        .stdout(Stdio::from(log))
+        .stderr(Stdio::from(log_err))
+        .status()
+        .map_err(|e| format!("Failed to launch {}: {}", exe.display(), e))?;
+
+    let exit_code = status.code().unwrap_or(-1);
+    let output = std::fs::read_to_string(&log_path).unwrap_or_default();
+    let exception = crate::log::crash_exception(exit_code, &output);
+    if let Some(status) = exception {
+        crate::log::error(&format!(
+            "{} ({}) crashed with {}",
+            app.name,
+            app.appid,
+            crate::log::describe_exception(status)
+        ));
+    }
+    Ok(PlaySession {
+        exit_code,
+        log_path: Some(log_path),
+        exception,
+    })
+}
+
+/// Launch a game through the Steam client (`steam://run`), so Steam Input
+/// and the overlay apply. Returns once Steam has accepted the request.
+pub fn launch_via_steam(appid: u32, args: &[String]) -> Result<PlaySession, String> {
+    let url = if args.is_empty() {
+        format!("steam://run/{}", appid)
+    } else {
+        format!(
+            "steam://run/{}//{}/",
+            appid,
+            args.join(" ").replace(' ', "%20")
+        )
+    };
+    let mut cmd = match which("steam") {
+        Some(steam) => Command::new(steam),
+        None => {
+            let xdg_open = which("xdg-open").ok_or("Neither steam nor xdg-open is installed")?;
+            Command::new(xdg_open)
+        }
+    };
+    let status = cmd
+        .arg(&url)
+        .status()
+        .map_err(|e| format!("Failed to open {}: {}", url, e))?;
+    Ok(PlaySession {
+        exit_code: status.code().unwrap_or(-1),
+        log_path: None,
+        exception: None,
+    })
+}
+
+/// Hand the launch to the Steam client.
+fn steam_applaunch(appid: u32, args: &[String]) -> Result<PlaySession, String> {
+    let steam = which("steam").ok_or("Game executable not found and steam is not installed")?;
+    crate::log::warn(&format!(
+        "Could not determine the executable for {}, launching through Steam without env profile or log capture",
+        appid
+    ));
+    let status = Command::new(steam)
+        .arg("-applaunch")
+        .arg(appid.to_string())
+        .args(args)
+        .status()
+        .map_err(|e| format!("Failed to run steam: {}", e))?;
+    Ok(PlaySession {
+        exit_code: status.code().unwrap_or(-1),
+        log_path: None,
+        exception: None,
+    })
+}
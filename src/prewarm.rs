@@ -0,0 +1,125 @@
+//! Pre-warming a game's Proton prefix before its first launch.
+//!
+//! `protontool APPID --prewarm` runs the game's mapped Proton once with a
+//! no-op program (`wineboot`). This makes Proton create and populate
+//! `compatdata/APPID` exactly as on a first launch from Steam (default
+//! prefix, DXVK/VKD3D-Proton DLLs, fonts, Steam stubs). The `[provision]`
+//! baseline from config.toml is then applied and the game's shader cache
+//! directory is created, so the first real launch doesn't start with a
+//! multi-minute wait.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use crate::steam::{ProtonApp, SteamApp};
+use crate::wine::provision;
+
+/// What a prewarm did.
+#[derive(Debug)]
+pub struct PrewarmResult {
+    pub prefix_path: PathBuf,
+    /// The prefix didn't exist (or wasn't initialized) before.
+    pub created: bool,
+    /// The `[provision]` baseline was applied.
+    pub provisioned: bool,
+    pub shader_cache: Option<PathBuf>,
+}
+
+/// Build the command initializing a game's prefix through Proton, with the
+/// environment Steam passes on launch.
+pub fn proton_init_command(
+    steam_path: &Path,
+    app: &SteamApp,
+    proton_app: &ProtonApp,
+    compat_data: &Path,
+) -> Command {
+    let mut cmd = Command::new(proton_app.install_path.join("proton"));
+    cmd.args(["run", "wineboot"])
+        .env("STEAM_COMPAT_DATA_PATH", compat_data)
+        .env("STEAM_COMPAT_CLIENT_INSTALL_PATH", steam_path)
+        .env("STEAM_COMPAT_INSTALL_PATH", &app.install_path)
+        .env("SteamAppId", app.appid.to_string())
+        .env("SteamGameId", app.appid.to_string());
+    if let Some(shader_cache) = app.shader_cache_path() {
+        cmd.env("STEAM_COMPAT_SHADER_PATH", shader_cache);
+    }
+    cmd
+}
+
+/// Run a command quietly behind a spinner, or with its output on the
+/// console when `--show-wine-output` is set.
+fn run_quietly(cmd: &mut Command, label: &str) -> Result<Output, String> {
+    let result = if crate::wine::is_wine_output_shown() {
+        cmd.status().map(|status| Output {
+            status,
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        })
+    } else {
+        let _spinner = crate::util::Spinner::start(label);
+        cmd.output()
+    };
+    result.map_err(|e| format!("Failed to run Proton: {}", e))
+}
+
+/// Create and initialize a game's prefix with its mapped Proton, apply the
+/// provisioning baseline and create the shader cache directory. Safe to run
+/// on an existing prefix: Proton only updates it.
+pub fn prewarm(
+    steam_path: &Path,
+    app: &SteamApp,
+    proton_app: &ProtonApp,
+) -> Result<PrewarmResult, String> {
+    let compat_data = app.compat_data_path().ok_or_else(|| {
+        format!(
+            "Can't determine the compatdata directory of {}",
+            app.install_path.display()
+        )
+    })?;
+    let prefix_path = compat_data.join("pfx");
+    let created = !prefix_path.join("system.reg").exists();
+    fs::create_dir_all(&compat_data)
+        .map_err(|e| format!("Failed to create {}: {}", compat_data.display(), e))?;
+
+    let shader_cache = app.shader_cache_path();
+    if let Some(dir) = &shader_cache {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    let mut cmd = proton_init_command(steam_path, app, proton_app, &compat_data);
+    let output = run_quietly(
+        &mut cmd,
+        &format!("Initializing prefix with {}", proton_app.name),
+    )?;
+    crate::log::log_executable_output(
+        "proton",
+        &String::from_utf8_lossy(&output.stdout),
+        &String::from_utf8_lossy(&output.stderr),
+        output.status.code().unwrap_or(-1),
+    );
+    if !prefix_path.join("system.reg").exists() {
+        return Err(format!(
+            "{} did not initialize the prefix (exit code {})",
+            proton_app.name,
+            output.status.code().unwrap_or(-1)
+        ));
+    }
+
+    let wine_ctx = crate::wine::WineContext::from_proton(proton_app, &prefix_path);
+    wine_ctx.wait_for_wineserver().ok();
+
+    let provisioned =
+        !provision::is_provisioned(&prefix_path) && !provision::Provisioning::load().is_empty();
+    if provisioned {
+        provision::provision_prefix(&wine_ctx);
+    }
+
+    Ok(PrewarmResult {
+        prefix_path,
+        created,
+        provisioned,
+        shader_cache,
+    })
+}
@@ -0,0 +1,442 @@
+//! Shareable setup recipes.
+//!
+//! A recipe describes what makes a game work in its prefix: the Proton
+//! version, applied verbs, DLL overrides from the prefix registry, the
+//! environment profile, Steam launch options and the game's notes and tags
+//! (see [`crate::notes`]). `protontool recipe export APPID` writes one as a
+//! JSON file:
+//!
+//! ```json
+//! {"protontool_recipe":1,"appid":1091500,"game":"Cyberpunk 2077",
+//!  "proton":"Proton 9.0","launch_options":"%command% --launcher-skip",
+//!  "verbs":["vcrun2022"],"dll_overrides":{"winmm":"native,builtin"},
+//!  "env":{"DXVK_ASYNC":"1"},"tags":["needs vcrun2022"],"notes":null}
+//! ```
+//!
+//! `protontool recipe apply FILE` applies a recipe to the same game in
+//! another user's prefix. Environment and DLL override changes are journaled
+//! so `protontool undo` can revert them. The recipe's tags are added to the
+//! game's, and its notes are kept unless the game already has some.
+//!
+//! `protontool recipe search APPID` looks up recipes shared by other users in
+//! a community index. It is off until an index is configured in
+//! `config.toml`:
+//!
+//! ```toml
+//! [recipes]
+//! index = "https://example.org/recipes/{appid}.json"
+//! ```
+//!
+//! The index is a static JSON file (`{appid}` is replaced with the game's
+//! APPID, so an index can be split per game) listing recipes with their
+//! provenance:
+//!
+//! ```json
+//! {"recipes":[{"title":"Skip launcher","author":"someone",
+//!   "source":"https://example.org/forum/123","updated":"2026-05-01",
+//!   "recipe":{"protontool_recipe":1,"appid":1091500,"verbs":[]}}]}
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use crate::json::{self, JsonValue};
+use crate::wine::profile::EnvProfile;
+use crate::wine::{ledger, overrides, WineContext};
+
+/// Recipe format version written to and accepted from files.
+pub const RECIPE_FORMAT: u32 = 1;
+
+/// `config.toml` section and key naming the community recipe index.
+pub const INDEX_SECTION: &str = "recipes";
+pub const INDEX_KEY: &str = "index";
+
+/// Everything needed to reproduce a game setup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recipe {
+    pub appid: u32,
+    pub game: String,
+    /// Name of the Proton version the game was set up with.
+    pub proton: Option<String>,
+    pub launch_options: Option<String>,
+    pub verbs: Vec<String>,
+    pub dll_overrides: Vec<(String, String)>,
+    pub env: Vec<(String, String)>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+}
+
+fn string_map(entries: &[(String, String)]) -> JsonValue {
+    JsonValue::Object(
+        entries
+            .iter()
+            .map(|(k, v)| (k.clone(), JsonValue::String(v.clone())))
+            .collect(),
+    )
+}
+
+fn string_list(values: &[String]) -> JsonValue {
+    JsonValue::Array(
+        values
+            .iter()
+            .map(|v| JsonValue::String(v.clone()))
+            .collect(),
+    )
+}
+
+fn parse_string_list(value: Option<&JsonValue>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect()
+}
+
+fn parse_string_map(value: Option<&JsonValue>) -> Vec<(String, String)> {
+    value
+        .and_then(|v| v.as_object())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+        .collect()
+}
+
+impl Recipe {
+    pub fn to_json(&self) -> JsonValue {
+        let optional = |value: &Option<String>| match value {
+            Some(s) => JsonValue::String(s.clone()),
+            None => JsonValue::Null,
+        };
+        JsonValue::Object(vec![
+            (
+                "protontool_recipe".into(),
+                JsonValue::Number(RECIPE_FORMAT as f64),
+            ),
+            ("appid".into(), JsonValue::Number(self.appid as f64)),
+            ("game".into(), JsonValue::String(self.game.clone())),
+            ("proton".into(), optional(&self.proton)),
+            ("launch_options".into(), optional(&self.launch_options)),
+            ("verbs".into(), string_list(&self.verbs)),
+            ("dll_overrides".into(), string_map(&self.dll_overrides)),
+            ("env".into(), string_map(&self.env)),
+            ("tags".into(), string_list(&self.tags)),
+            ("notes".into(), optional(&self.notes)),
+        ])
+    }
+
+    pub fn from_json(value: &JsonValue) -> Result<Self, String> {
+        let format = value
+            .get("protontool_recipe")
+            .and_then(|v| v.as_f64())
+            .ok_or("Not a protontool recipe")?;
+        if format as u32 > RECIPE_FORMAT {
+            return Err(format!(
+                "Recipe format {} is newer than this protontool supports ({})",
+                format, RECIPE_FORMAT
+            ));
+        }
+        let string = |key: &str| {
+            value
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+        Ok(Recipe {
+            appid: value
+                .get("appid")
+                .and_then(|v| v.as_f64())
+                .ok_or("Recipe has no appid")? as u32,
+            game: string("game").unwrap_or_default(),
+            proton: string("proton"),
+            launch_options: string("launch_options"),
+            verbs: parse_string_list(value.get("verbs")),
+            dll_overrides: parse_string_map(value.get("dll_overrides")),
+            env: parse_string_map(value.get("env")),
+            tags: parse_string_list(value.get("tags")),
+            notes: string("notes"),
+        })
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        Self::from_json(&json::parse(content)?)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, format!("{}\n", self.to_json()))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Multi-line summary for review before applying.
+    pub fn describe(&self) -> String {
+        let mut lines = vec![format!("Recipe for {} ({})", self.game, self.appid)];
+        if let Some(proton) = &self.proton {
+            lines.push(format!("Proton: {}", proton));
+        }
+        if let Some(options) = &self.launch_options {
+            lines.push(format!("Launch options: {}", options));
+        }
+        if !self.verbs.is_empty() {
+            lines.push(format!("Verbs: {}", self.verbs.join(" ")));
+        }
+        if !self.dll_overrides.is_empty() {
+            let overrides: Vec<String> = self
+                .dll_overrides
+                .iter()
+                .map(|(dll, mode)| format!("{}={}", dll, mode))
+                .collect();
+            lines.push(format!("DLL overrides: {}", overrides.join(" ")));
+        }
+        for (key, value) in &self.env {
+            lines.push(format!("Environment: {}={}", key, value));
+        }
+        if !self.tags.is_empty() {
+            lines.push(format!("Tags: {}", self.tags.join(", ")));
+        }
+        if let Some(notes) = &self.notes {
+            lines.push(format!("Notes: {}", notes));
+        }
+        lines.join("\n")
+    }
+}
+
+/// A recipe from a community index along with where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommunityRecipe {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// Where the recipe was shared (forum post, issue, ...).
+    pub source: Option<String>,
+    pub updated: Option<String>,
+    /// URL of the index the recipe was listed in.
+    pub index: String,
+    pub recipe: Recipe,
+}
+
+impl CommunityRecipe {
+    /// Multi-line provenance summary.
+    pub fn provenance(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(title) = &self.title {
+            lines.push(title.clone());
+        }
+        lines.push(format!(
+            "Shared by: {}",
+            self.author.as_deref().unwrap_or("(unknown)")
+        ));
+        if let Some(source) = &self.source {
+            lines.push(format!("Source: {}", source));
+        }
+        if let Some(updated) = &self.updated {
+            lines.push(format!("Updated: {}", updated));
+        }
+        lines.push(format!("Index: {}", self.index));
+        lines.join("\n")
+    }
+}
+
+/// Parse a community index, keeping the valid recipes for `appid`.
+pub fn parse_index(
+    content: &str,
+    appid: u32,
+    index_url: &str,
+) -> Result<Vec<CommunityRecipe>, String> {
+    let mut recipes = parse_index_all(content, index_url)?;
+    recipes.retain(|shared| shared.recipe.appid == appid);
+    Ok(recipes)
+}
+
+/// Parse a community index, keeping all valid recipes.
+pub fn parse_index_all(content: &str, index_url: &str) -> Result<Vec<CommunityRecipe>, String> {
+    let value = json::parse(content)?;
+    let entries = value
+        .get("recipes")
+        .and_then(|v| v.as_array())
+        .ok_or("Not a recipe index (missing \"recipes\" list)")?;
+    let string = |entry: &JsonValue, key: &str| {
+        entry
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+    Ok(entries
+        .iter()
+        .filter_map(|entry| {
+            Some(CommunityRecipe {
+                title: string(entry, "title"),
+                author: string(entry, "author"),
+                source: string(entry, "source"),
+                updated: string(entry, "updated"),
+                index: index_url.to_string(),
+                recipe: Recipe::from_json(entry.get("recipe")?).ok()?,
+            })
+        })
+        .collect())
+}
+
+/// Index URL for a game from `config.toml`, None when community recipes
+/// haven't been enabled.
+pub fn community_index_url(config: &crate::config::UserConfig, appid: u32) -> Option<String> {
+    config
+        .get(INDEX_SECTION, INDEX_KEY)
+        .filter(|url| !url.is_empty())
+        .map(|url| url.replace("{appid}", &appid.to_string()))
+}
+
+/// Fetch every recipe of the community index, for searching by name. None
+/// when community recipes are disabled or the index is split per game
+/// (its URL contains `{appid}`).
+pub fn fetch_community_index() -> Option<Result<Vec<CommunityRecipe>, String>> {
+    let config = crate::config::UserConfig::load();
+    let url = config
+        .get(INDEX_SECTION, INDEX_KEY)
+        .filter(|url| !url.is_empty() && !url.contains("{appid}"))?;
+    Some(
+        crate::wine::download::fetch_text(url)
+            .and_then(|content| parse_index_all(&content, url))
+            .map_err(|e| format!("{}: {}", url, e)),
+    )
+}
+
+/// Fetch the community recipes shared for a game.
+pub fn search_community(appid: u32) -> Result<Vec<CommunityRecipe>, String> {
+    let url = community_index_url(&crate::config::UserConfig::load(), appid).ok_or_else(|| {
+        format!(
+            "Community recipes are disabled. Set {} in the [{}] section of {} to a recipe index URL to enable them.",
+            INDEX_KEY,
+            INDEX_SECTION,
+            crate::config::get_config_file().display()
+        )
+    })?;
+    let content = crate::wine::download::fetch_text(&url)?;
+    parse_index(&content, appid, &url).map_err(|e| format!("{}: {}", url, e))
+}
+
+/// Build a recipe from a game's prefix. Environment variables holding
+/// absolute paths are left out as they only make sense on this machine.
+pub fn export_recipe(
+    appid: u32,
+    game: &str,
+    prefix_path: &Path,
+    proton: Option<&str>,
+    launch_options: Option<&str>,
+) -> Recipe {
+    let notes = crate::notes::get(&crate::notes::Subject::App(appid));
+    Recipe {
+        appid,
+        game: game.to_string(),
+        proton: proton.map(|p| p.to_string()),
+        launch_options: launch_options.map(|o| o.to_string()),
+        verbs: ledger::applied_verbs(prefix_path),
+        dll_overrides: overrides::read(prefix_path),
+        env: EnvProfile::load(prefix_path)
+            .vars
+            .into_iter()
+            .filter(|(_, value)| !value.starts_with('/'))
+            .collect(),
+        tags: notes.tags,
+        notes: Some(notes.note).filter(|note| !note.is_empty()),
+    }
+}
+
+/// Verbs of a recipe not yet applied to a prefix.
+pub fn pending_verbs(recipe: &Recipe, prefix_path: &Path) -> Vec<String> {
+    let applied = ledger::applied_verbs(prefix_path);
+    recipe
+        .verbs
+        .iter()
+        .filter(|verb| !applied.contains(verb))
+        .cloned()
+        .collect()
+}
+
+/// Set the recipe's environment variables in a prefix's profile.
+pub fn apply_env(recipe: &Recipe, prefix_path: &Path) -> Result<(), String> {
+    let profile = EnvProfile::load(prefix_path);
+    for (key, value) in &recipe.env {
+        if profile.get(key) != Some(value.as_str()) {
+            crate::journal::set_profile_var(prefix_path, key, Some(value))?;
+        }
+    }
+    Ok(())
+}
+
+/// Add the recipe's tags to the game's, and take its notes if the game has
+/// none.
+pub fn apply_notes(recipe: &Recipe) -> Result<(), String> {
+    let subject = crate::notes::Subject::App(recipe.appid);
+    let mut notes = crate::notes::get(&subject);
+    notes.add_tags(&recipe.tags);
+    if notes.note.is_empty() {
+        notes.note = recipe.notes.clone().unwrap_or_default();
+    }
+    crate::notes::set(&subject, &notes)
+}
+
+/// Write the recipe's DLL overrides to the prefix registry, journaling the
+/// values they replace.
+pub fn apply_dll_overrides(recipe: &Recipe, wine_ctx: &WineContext) -> Result<(), String> {
+    let description = format!("Recipe DLL overrides in {}", wine_ctx.prefix_path.display());
+    overrides::apply(wine_ctx, &recipe.dll_overrides, &description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recipe_round_trip() {
+        let recipe = Recipe {
+            appid: 1091500,
+            game: "Cyberpunk 2077".to_string(),
+            proton: Some("Proton 9.0".to_string()),
+            launch_options: Some("%command% --launcher-skip".to_string()),
+            verbs: vec!["vcrun2022".to_string()],
+            dll_overrides: vec![("winmm".to_string(), "native,builtin".to_string())],
+            env: vec![("DXVK_HUD".to_string(), "fps,devinfo".to_string())],
+            tags: vec!["needs vcrun2022".to_string()],
+            notes: Some("Skip the launcher".to_string()),
+        };
+        let parsed = Recipe::parse(&recipe.to_json().to_string()).unwrap();
+        assert_eq!(parsed, recipe);
+        assert!(Recipe::parse("{\"appid\": 10}").is_err());
+        assert!(Recipe::parse("{\"protontool_recipe\": 99, \"appid\": 10}").is_err());
+    }
+
+    #[test]
+    fn test_parse_index() {
+        let index = r#"{"recipes": [
+            {"title": "Skip launcher", "author": "someone",
+             "recipe": {"protontool_recipe": 1, "appid": 1091500,
+                        "launch_options": "%command% --launcher-skip"}},
+            {"recipe": {"protontool_recipe": 1, "appid": 10}},
+            {"recipe": {"appid": 1091500}}
+        ]}"#;
+        let url = "https://example.org/recipes.json";
+        let found = parse_index(index, 1091500, url).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].author.as_deref(), Some("someone"));
+        assert_eq!(
+            found[0].recipe.launch_options.as_deref(),
+            Some("%command% --launcher-skip")
+        );
+        assert!(found[0]
+            .provenance()
+            .contains("Index: https://example.org/recipes.json"));
+        assert!(parse_index("[]", 10, url).is_err());
+
+        let config = crate::config::UserConfig::parse(
+            "[recipes]\nindex = \"https://example.org/{appid}.json\"\n",
+        );
+        assert_eq!(
+            community_index_url(&config, 10).as_deref(),
+            Some("https://example.org/10.json")
+        );
+    }
+}
@@ -0,0 +1,388 @@
+//! Shareable reports for log analysis results.
+//!
+//! A `Report` bundles system information, errors grouped by subsystem and
+//! suggested verbs. Output formats are provided by `ReportSink`
+//! implementations; Markdown and HTML both use collapsible `<details>`
+//! sections so reports can be pasted into GitHub issues or ProtonDB.
+
+use std::fs;
+
+use crate::analyze::{ErrorSummary, LogAnalysis};
+
+/// DLL name prefixes and the verb that usually provides them.
+const DLL_SUGGESTIONS: &[(&str, &str)] = &[
+    ("msvcp140", "vcrun2022"),
+    ("vcruntime140", "vcrun2022"),
+    ("msvcp120", "vcrun2013"),
+    ("msvcr120", "vcrun2013"),
+    ("msvcp110", "vcrun2012"),
+    ("msvcr110", "vcrun2012"),
+    ("msvcp100", "vcrun2010"),
+    ("msvcr100", "vcrun2010"),
+    ("msvcp90", "vcrun2008"),
+    ("msvcr90", "vcrun2008"),
+    ("msvcp80", "vcrun2005"),
+    ("msvcr80", "vcrun2005"),
+    ("d3dx9_", "d3dx9"),
+    ("d3dcompiler_47", "d3dcompiler_47"),
+    ("d3dcompiler_43", "d3dcompiler_43"),
+    ("xinput", "xinput"),
+    ("xaudio2_", "faudio"),
+    ("x3daudio", "faudio"),
+    ("mscoree", "dotnet48"),
+    ("gdiplus", "gdiplus"),
+    ("physxloader", "physx"),
+    ("openal32", "openal"),
+    ("msvbvm60", "vb6run"),
+    ("mfplat", "mf"),
+    ("quartz", "quartz"),
+];
+
+/// Error code prefixes (subsystem) and the verb that commonly helps.
+const CODE_SUGGESTIONS: &[(&str, &str)] = &[
+    ("DOTNET", "dotnet48"),
+    ("FONT", "corefonts"),
+    ("DWRITE", "corefonts"),
+    ("XINPUT", "xinput"),
+];
+
+/// A verb suggested by the analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub verb: String,
+    pub reason: String,
+}
+
+/// Errors belonging to one subsystem.
+#[derive(Debug, Clone)]
+pub struct ErrorGroup {
+    pub subsystem: String,
+    pub errors: Vec<ErrorSummary>,
+}
+
+/// A complete analysis report, independent of output format.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub title: String,
+    pub source: String,
+    pub total_lines: usize,
+    pub system_info: Vec<(String, String)>,
+    pub groups: Vec<ErrorGroup>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Output format for a report.
+pub trait ReportSink {
+    /// Render the report as a self-contained document.
+    fn render(&self, report: &Report) -> String;
+
+    /// File extension for saved reports.
+    fn extension(&self) -> &'static str;
+}
+
+/// Get the sink for a format name ("markdown"/"md" or "html").
+pub fn sink_for_format(format: &str) -> Option<Box<dyn ReportSink>> {
+    match format.to_lowercase().as_str() {
+        "markdown" | "md" => Some(Box::new(MarkdownSink)),
+        "html" | "htm" => Some(Box::new(HtmlSink)),
+        _ => None,
+    }
+}
+
+/// Subsystem name for an error code (e.g. `WINE-MODULE-001` -> "DLL loading").
+pub fn subsystem_for_code(code: &str) -> String {
+    let mut parts = code.split('-');
+    let first = parts.next().unwrap_or(code);
+    let token = if first == "WINE" {
+        parts.next().unwrap_or(first)
+    } else {
+        first
+    };
+
+    match token {
+        "MODULE" | "NTDLL" => "DLL loading",
+        "SEH" | "CRASH" | "VIRT" => "Crashes and exceptions",
+        "D3D" | "D3D11" | "D3D12" | "DXGI" | "WINED3D" | "DX" | "DXVK" | "VKD3D" | "VULKAN"
+        | "DISPLAY" => "Graphics",
+        "AUDIO" => "Audio",
+        "NET" => "Network",
+        "DOTNET" => ".NET",
+        "FONT" | "DWRITE" => "Fonts",
+        "INPUT" | "XINPUT" => "Input",
+        "CRYPT" | "BCRYPT" | "SEC" => "Security and crypto",
+        "OLE" | "OLEAUT" | "SHELL" | "EXPLORER" => "COM and shell",
+        other => return other.to_string(),
+    }
+    .to_string()
+}
+
+/// Suggest verbs based on missing DLLs and error subsystems.
+pub fn suggest_fixes(summaries: &[ErrorSummary]) -> Vec<Suggestion> {
+    let mut suggestions: Vec<Suggestion> = Vec::new();
+    let mut add = |verb: &str, reason: String| {
+        if !suggestions.iter().any(|s| s.verb == verb) {
+            suggestions.push(Suggestion {
+                verb: verb.to_string(),
+                reason,
+            });
+        }
+    };
+
+    for summary in summaries {
+        for dll in &summary.dlls {
+            let lower = dll.to_lowercase();
+            if let Some((_, verb)) = DLL_SUGGESTIONS.iter().find(|(p, _)| lower.starts_with(p)) {
+                add(verb, format!("{} could not be loaded", dll));
+            }
+        }
+        for (prefix, verb) in CODE_SUGGESTIONS {
+            if summary.code.split('-').any(|part| part == *prefix) {
+                add(verb, format!("{}: {}", summary.code, summary.description));
+            }
+        }
+    }
+
+    suggestions
+}
+
+/// Read a `KEY="value"` entry from /etc/os-release.
+fn os_release_value(key: &str) -> Option<String> {
+    let content = fs::read_to_string("/etc/os-release").ok()?;
+    content.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix('=')?;
+        Some(value.trim_matches('"').to_string())
+    })
+}
+
+/// Collect basic system information for reports.
+pub fn collect_system_info() -> Vec<(String, String)> {
+    let mut info = vec![("protontool".to_string(), crate::VERSION.to_string())];
+
+    if let Some(name) = os_release_value("PRETTY_NAME") {
+        info.push(("Distribution".to_string(), name));
+    }
+    if let Ok(kernel) = fs::read_to_string("/proc/sys/kernel/osrelease") {
+        info.push(("Kernel".to_string(), kernel.trim().to_string()));
+    }
+    if let Ok(cpuinfo) = fs::read_to_string("/proc/cpuinfo") {
+        if let Some(model) = cpuinfo
+            .lines()
+            .find(|l| l.starts_with("model name"))
+            .and_then(|l| l.split_once(':'))
+        {
+            info.push(("CPU".to_string(), model.1.trim().to_string()));
+        }
+    }
+    if let Ok(meminfo) = fs::read_to_string("/proc/meminfo") {
+        if let Some(kb) = meminfo
+            .lines()
+            .find(|l| l.starts_with("MemTotal:"))
+            .and_then(|l| l.split_whitespace().nth(1))
+            .and_then(|v| v.parse::<u64>().ok())
+        {
+            info.push(("Memory".to_string(), format!("{} MiB", kb / 1024)));
+        }
+    }
+    if crate::steam::is_steam_deck() {
+        info.push(("Device".to_string(), "Steam Deck".to_string()));
+    }
+
+    info
+}
+
+impl Report {
+    /// Build a report from a log analysis, including local system info.
+    pub fn from_analysis(analysis: &LogAnalysis) -> Self {
+        let mut groups: Vec<ErrorGroup> = Vec::new();
+        for summary in &analysis.summaries {
+            let subsystem = subsystem_for_code(&summary.code);
+            match groups.iter_mut().find(|g| g.subsystem == subsystem) {
+                Some(group) => group.errors.push(summary.clone()),
+                None => groups.push(ErrorGroup {
+                    subsystem,
+                    errors: vec![summary.clone()],
+                }),
+            }
+        }
+
+        Self {
+            title: "protontool log analysis".to_string(),
+            source: analysis.path.display().to_string(),
+            total_lines: analysis.total_lines,
+            system_info: collect_system_info(),
+            groups,
+            suggestions: suggest_fixes(&analysis.summaries),
+        }
+    }
+
+    fn error_count(&self) -> usize {
+        self.groups
+            .iter()
+            .flat_map(|g| g.errors.iter())
+            .map(|e| e.count)
+            .sum()
+    }
+}
+
+/// Description with missing DLLs appended.
+fn full_description(summary: &ErrorSummary) -> String {
+    if summary.dlls.is_empty() {
+        summary.description.clone()
+    } else {
+        format!(
+            "{} [Missing: {}]",
+            summary.description,
+            summary.dlls.join(", ")
+        )
+    }
+}
+
+/// GitHub-flavored Markdown report.
+pub struct MarkdownSink;
+
+fn md_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+impl ReportSink for MarkdownSink {
+    fn render(&self, report: &Report) -> String {
+        let mut out = format!("## {}\n\n", report.title);
+        out.push_str(&format!(
+            "Source: `{}` ({} lines, {} known error occurrences)\n\n",
+            report.source,
+            report.total_lines,
+            report.error_count()
+        ));
+
+        out.push_str("<details>\n<summary>System information</summary>\n\n");
+        out.push_str("| Item | Value |\n|------|-------|\n");
+        for (key, value) in &report.system_info {
+            out.push_str(&format!("| {} | {} |\n", md_cell(key), md_cell(value)));
+        }
+        out.push_str("\n</details>\n\n");
+
+        if report.groups.is_empty() {
+            out.push_str("No known errors found.\n");
+        }
+        for group in &report.groups {
+            let count: usize = group.errors.iter().map(|e| e.count).sum();
+            out.push_str(&format!(
+                "<details>\n<summary>{} ({})</summary>\n\n",
+                group.subsystem, count
+            ));
+            out.push_str("| Code | Count | First | Last | Description |\n");
+            out.push_str("|------|------:|-------|------|-------------|\n");
+            for error in &group.errors {
+                out.push_str(&format!(
+                    "| `{}` | {} | {} | {} | {} |\n",
+                    error.code,
+                    error.count,
+                    md_cell(&error.first.describe()),
+                    md_cell(&error.last.describe()),
+                    md_cell(&full_description(error))
+                ));
+            }
+            out.push_str("\n</details>\n\n");
+        }
+
+        if !report.suggestions.is_empty() {
+            out.push_str("<details open>\n<summary>Suggested fixes</summary>\n\n");
+            for suggestion in &report.suggestions {
+                out.push_str(&format!(
+                    "- `protontool APPID {}`: {}\n",
+                    suggestion.verb, suggestion.reason
+                ));
+            }
+            out.push_str("\n</details>\n");
+        }
+
+        out
+    }
+
+    fn extension(&self) -> &'static str {
+        "md"
+    }
+}
+
+/// Standalone HTML report (inline styles, no external resources).
+pub struct HtmlSink;
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl ReportSink for HtmlSink {
+    fn render(&self, report: &Report) -> String {
+        let mut out = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        out.push_str(&format!("<title>{}</title>\n", html_escape(&report.title)));
+        out.push_str(
+            "<style>body{font-family:sans-serif;margin:2em}table{border-collapse:collapse}\
+             td,th{border:1px solid #999;padding:4px 8px;text-align:left}\
+             summary{font-weight:bold;cursor:pointer;margin:1em 0 .5em}</style>\n",
+        );
+        out.push_str("</head>\n<body>\n");
+        out.push_str(&format!("<h2>{}</h2>\n", html_escape(&report.title)));
+        out.push_str(&format!(
+            "<p>Source: <code>{}</code> ({} lines, {} known error occurrences)</p>\n",
+            html_escape(&report.source),
+            report.total_lines,
+            report.error_count()
+        ));
+
+        out.push_str("<details>\n<summary>System information</summary>\n<table>\n");
+        for (key, value) in &report.system_info {
+            out.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                html_escape(key),
+                html_escape(value)
+            ));
+        }
+        out.push_str("</table>\n</details>\n");
+
+        if report.groups.is_empty() {
+            out.push_str("<p>No known errors found.</p>\n");
+        }
+        for group in &report.groups {
+            let count: usize = group.errors.iter().map(|e| e.count).sum();
+            out.push_str(&format!(
+                "<details>\n<summary>{} ({})</summary>\n<table>\n\
+                 <tr><th>Code</th><th>Count</th><th>First</th><th>Last</th><th>Description</th></tr>\n",
+                html_escape(&group.subsystem),
+                count
+            ));
+            for error in &group.errors {
+                out.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    html_escape(&error.code),
+                    error.count,
+                    html_escape(&error.first.describe()),
+                    html_escape(&error.last.describe()),
+                    html_escape(&full_description(error))
+                ));
+            }
+            out.push_str("</table>\n</details>\n");
+        }
+
+        if !report.suggestions.is_empty() {
+            out.push_str("<details open>\n<summary>Suggested fixes</summary>\n<ul>\n");
+            for suggestion in &report.suggestions {
+                out.push_str(&format!(
+                    "<li><code>protontool APPID {}</code>: {}</li>\n",
+                    html_escape(&suggestion.verb),
+                    html_escape(&suggestion.reason)
+                ));
+            }
+            out.push_str("</ul>\n</details>\n");
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    fn extension(&self) -> &'static str {
+        "html"
+    }
+}
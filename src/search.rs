@@ -0,0 +1,143 @@
+//! Application search across verbs and community recipes.
+//!
+//! `protontool search-app NAME` looks for an application in the App verbs,
+//! custom verbs and the community recipe index, and shows the one command
+//! that installs each match. Names are compared ignoring case and
+//! punctuation, so `7zip` finds `7-Zip`.
+
+use crate::recipe::CommunityRecipe;
+use crate::wine::{VerbCategory, VerbRegistry};
+
+/// Where a search match comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchSource {
+    AppVerb,
+    CustomVerb,
+    Recipe,
+}
+
+impl MatchSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchSource::AppVerb => "app",
+            MatchSource::CustomVerb => "custom verb",
+            MatchSource::Recipe => "recipe",
+        }
+    }
+}
+
+/// An installable match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppMatch {
+    pub source: MatchSource,
+    pub name: String,
+    pub description: String,
+    /// Command installing it into a prefix.
+    pub command: String,
+}
+
+/// Lowercase a name and drop everything but letters and digits.
+///
+/// ```
+/// use protontool::search::normalize;
+/// assert_eq!(normalize("7-Zip File Manager"), "7zipfilemanager");
+/// ```
+pub fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+fn matches_query(query: &str, fields: &[&str]) -> bool {
+    let query = normalize(query);
+    !query.is_empty() && fields.iter().any(|f| normalize(f).contains(&query))
+}
+
+/// App and custom verbs matching a query by name, title or publisher.
+pub fn search_verbs(registry: &VerbRegistry, query: &str) -> Vec<AppMatch> {
+    let mut found: Vec<AppMatch> = [VerbCategory::App, VerbCategory::Custom]
+        .iter()
+        .flat_map(|category| registry.list(Some(*category)))
+        .filter(|verb| matches_query(query, &[&verb.name, &verb.title, &verb.publisher]))
+        .map(|verb| AppMatch {
+            source: if verb.category == VerbCategory::App {
+                MatchSource::AppVerb
+            } else {
+                MatchSource::CustomVerb
+            },
+            name: registry.reference(verb),
+            description: format!("{} ({}, {})", verb.title, verb.publisher, verb.year),
+            command: format!("protontool APPID {}", registry.reference(verb)),
+        })
+        .collect();
+    found.sort_by(|a, b| a.name.cmp(&b.name));
+    found
+}
+
+/// Community recipes matching a query by game name or recipe title.
+pub fn search_recipes(recipes: &[CommunityRecipe], query: &str) -> Vec<AppMatch> {
+    recipes
+        .iter()
+        .filter(|shared| {
+            matches_query(
+                query,
+                &[
+                    &shared.recipe.game,
+                    shared.title.as_deref().unwrap_or_default(),
+                ],
+            )
+        })
+        .map(|shared| AppMatch {
+            source: MatchSource::Recipe,
+            name: shared.recipe.game.clone(),
+            description: match (&shared.title, &shared.author) {
+                (Some(title), Some(author)) => format!("{} by {}", title, author),
+                (Some(title), None) => title.clone(),
+                (None, Some(author)) => format!("by {}", author),
+                (None, None) => String::new(),
+            },
+            command: format!("protontool recipe search {}", shared.recipe.appid),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recipe::Recipe;
+
+    #[test]
+    fn test_search_verbs_and_recipes() {
+        let mut registry = VerbRegistry::new();
+        registry.register(crate::wine::Verb::new(
+            "7zip",
+            VerbCategory::App,
+            "7-Zip file archiver",
+            "Igor Pavlov",
+            "2024",
+        ));
+        let found = search_verbs(&registry, "7-zip");
+        assert!(found.iter().any(|m| m.name == "7zip"
+            && m.source == MatchSource::AppVerb
+            && m.command == "protontool APPID 7zip"));
+        assert!(search_verbs(&registry, "--").is_empty());
+
+        let recipes = vec![CommunityRecipe {
+            title: Some("Skip launcher".to_string()),
+            author: Some("someone".to_string()),
+            source: None,
+            updated: None,
+            index: "https://example.org/recipes.json".to_string(),
+            recipe: Recipe {
+                appid: 1091500,
+                game: "Cyberpunk 2077".to_string(),
+                ..Default::default()
+            },
+        }];
+        let found = search_recipes(&recipes, "cyberpunk");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].command, "protontool recipe search 1091500");
+        assert!(search_recipes(&recipes, "7zip").is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+//! Persistent state store.
+//!
+//! Small records protontool keeps across runs (accepted licenses, cached
+//! measurements, user annotations) live as plain text files under
+//! `~/.protontool/state/`, one file per record name.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Path of a named state record.
+pub fn state_path(name: &str) -> PathBuf {
+    crate::config::get_state_dir().join(name)
+}
+
+/// Read a record. Missing records read as empty.
+pub fn read(name: &str) -> String {
+    fs::read_to_string(state_path(name)).unwrap_or_default()
+}
+
+/// Non-empty lines of a record.
+pub fn read_lines(name: &str) -> Vec<String> {
+    read(name)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Replace a record's content.
+pub fn write(name: &str, content: &str) -> Result<(), String> {
+    let path = state_path(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Append a line to a record.
+pub fn append_line(name: &str, line: &str) -> Result<(), String> {
+    let path = state_path(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
@@ -0,0 +1,214 @@
+//! Lightweight per-game status badges for the app selection list.
+//!
+//! Badges are derived from cheap file checks only (the prefix verb ledger,
+//! the prefix `version` file and the mapped Proton's `proton` script) and are
+//! cached by file modification times so repeated scans stay fast.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::steam::{find_proton_app, ProtonApp, SteamApp};
+use crate::util::compare_versions;
+use crate::wine::ledger;
+use crate::wine::verbs::DXVK_VERB_VERSIONS;
+
+/// Cache file name inside the protontool cache directory.
+const STATUS_CACHE_FILE: &str = "app_status.cache";
+
+/// A status badge shown next to a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusBadge {
+    /// Number of distinct verbs applied through protontool.
+    VerbsApplied(usize),
+    /// DXVK installed by a verb is older than the newest available verb.
+    DxvkOutdated { installed: String, latest: String },
+    /// The prefix was last updated by an older Proton than the one mapped now.
+    ProtonOutdated { prefix: String, mapped: String },
+}
+
+impl StatusBadge {
+    /// Short label for list columns.
+    pub fn label(&self) -> String {
+        match self {
+            StatusBadge::VerbsApplied(n) => format!("{} verb(s)", n),
+            StatusBadge::DxvkOutdated { installed, latest } => {
+                format!("DXVK {} < {}", installed, latest)
+            }
+            StatusBadge::ProtonOutdated { prefix, mapped } => {
+                format!("Proton {} < {}", prefix, mapped)
+            }
+        }
+    }
+}
+
+/// Newest DXVK version available through the built-in verbs.
+pub fn latest_dxvk_version() -> &'static str {
+    DXVK_VERB_VERSIONS
+        .iter()
+        .map(|(_, version)| *version)
+        .max_by(|a, b| compare_versions(a, b))
+        .unwrap_or("")
+}
+
+/// DXVK version installed in a prefix, based on the last DXVK verb applied.
+pub fn installed_dxvk_version(prefix_path: &Path) -> Option<String> {
+    ledger::read_ledger(prefix_path)
+        .iter()
+        .rev()
+        .find_map(|entry| {
+            DXVK_VERB_VERSIONS
+                .iter()
+                .find(|(verb, _)| *verb == entry.verb)
+                .map(|(_, version)| version.to_string())
+        })
+}
+
+/// Prefix version written by Proton to `compatdata/<appid>/version`.
+fn prefix_proton_version(prefix_path: &Path) -> Option<String> {
+    let version_file = prefix_path.parent()?.join("version");
+    let content = fs::read_to_string(version_file).ok()?;
+    let version = content.trim();
+    if version.is_empty() {
+        None
+    } else {
+        Some(version.to_string())
+    }
+}
+
+/// Prefix version a Proton installation upgrades prefixes to
+/// (`CURRENT_PREFIX_VERSION` in its `proton` script).
+pub fn proton_prefix_version(proton_install: &Path) -> Option<String> {
+    let script = fs::read_to_string(proton_install.join("proton")).ok()?;
+    script.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("CURRENT_PREFIX_VERSION")?;
+        let value = value.trim_start().strip_prefix('=')?;
+        Some(value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Compute the badges for a game given the Proton it is currently mapped to.
+pub fn compute_badges(app: &SteamApp, mapped_proton: Option<&ProtonApp>) -> Vec<StatusBadge> {
+    let mut badges = Vec::new();
+    let prefix_path = match &app.prefix_path {
+        Some(p) => p,
+        None => return badges,
+    };
+
+    let applied = ledger::applied_verbs(prefix_path).len();
+    if applied > 0 {
+        badges.push(StatusBadge::VerbsApplied(applied));
+    }
+
+    if let Some(installed) = installed_dxvk_version(prefix_path) {
+        let latest = latest_dxvk_version();
+        if compare_versions(&installed, latest).is_lt() {
+            badges.push(StatusBadge::DxvkOutdated {
+                installed,
+                latest: latest.to_string(),
+            });
+        }
+    }
+
+    if let (Some(prefix), Some(mapped)) = (
+        prefix_proton_version(prefix_path),
+        mapped_proton.and_then(|p| proton_prefix_version(&p.install_path)),
+    ) {
+        if compare_versions(&prefix, &mapped).is_lt() {
+            badges.push(StatusBadge::ProtonOutdated { prefix, mapped });
+        }
+    }
+
+    badges
+}
+
+/// Join badge labels for display.
+pub fn badges_label(badges: &[StatusBadge]) -> String {
+    badges
+        .iter()
+        .map(|b| b.label())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Modification time in seconds, or 0 if the file does not exist.
+fn mtime_secs(path: &Path) -> u64 {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn status_cache_path() -> PathBuf {
+    crate::config::get_cache_dir().join(STATUS_CACHE_FILE)
+}
+
+/// Compute status labels for all Windows apps, keyed by app ID.
+///
+/// Results are cached per app and only recomputed when the prefix ledger,
+/// the prefix version file or Steam's config.vdf (compat tool mapping) change.
+pub fn scan_app_status(steam_path: &Path, steam_apps: &[SteamApp]) -> HashMap<u32, String> {
+    let cache_path = status_cache_path();
+    let mut cache: HashMap<u32, (String, String)> = HashMap::new();
+    if let Ok(content) = fs::read_to_string(&cache_path) {
+        for line in content.lines() {
+            let mut fields = line.splitn(3, '\t');
+            if let (Some(appid), Some(fingerprint), Some(label)) =
+                (fields.next(), fields.next(), fields.next())
+            {
+                if let Ok(appid) = appid.parse() {
+                    cache.insert(appid, (fingerprint.to_string(), label.to_string()));
+                }
+            }
+        }
+    }
+
+    let config_mtime = mtime_secs(&steam_path.join("config/config.vdf"));
+    let mut result = HashMap::new();
+    let mut changed = false;
+
+    for app in steam_apps.iter().filter(|a| a.is_windows_app()) {
+        let prefix_path = match &app.prefix_path {
+            Some(p) => p,
+            None => continue,
+        };
+        let fingerprint = format!(
+            "{}-{}-{}",
+            mtime_secs(&ledger::ledger_path(prefix_path)),
+            prefix_path
+                .parent()
+                .map(|p| mtime_secs(&p.join("version")))
+                .unwrap_or(0),
+            config_mtime
+        );
+
+        let label = match cache.get(&app.appid) {
+            Some((cached_fp, label)) if *cached_fp == fingerprint => label.clone(),
+            _ => {
+                let proton = find_proton_app(steam_path, steam_apps, app.appid);
+                let label = badges_label(&compute_badges(app, proton.as_ref()));
+                cache.insert(app.appid, (fingerprint, label.clone()));
+                changed = true;
+                label
+            }
+        };
+        result.insert(app.appid, label);
+    }
+
+    if changed {
+        let mut lines: Vec<String> = cache
+            .iter()
+            .map(|(appid, (fp, label))| format!("{}\t{}\t{}", appid, fp, label))
+            .collect();
+        lines.sort();
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+        fs::write(&cache_path, lines.join("\n") + "\n").ok();
+    }
+
+    result
+}
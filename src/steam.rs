@@ -52,6 +52,35 @@ impl SteamApp {
     pub fn name_contains(&self, query: &str) -> bool {
         self.name.to_lowercase().contains(&query.to_lowercase())
     }
+
+    /// Proton's compat data directory (`steamapps/compatdata/APPID`), the
+    /// parent of the prefix. For a game that was never launched this is where
+    /// Steam would create it: in the library the game is installed in.
+    pub fn compat_data_path(&self) -> Option<PathBuf> {
+        if let Some(prefix) = &self.prefix_path {
+            return prefix.parent().map(|p| p.to_path_buf());
+        }
+        let steamapps = self.install_path.parent()?.parent()?;
+        Some(steamapps.join("compatdata").join(self.appid.to_string()))
+    }
+
+    /// Steam's shader cache directory for the game
+    /// (`steamapps/shadercache/APPID` in the game's library).
+    pub fn shader_cache_path(&self) -> Option<PathBuf> {
+        let steamapps = self.install_path.parent()?.parent()?;
+        Some(steamapps.join("shadercache").join(self.appid.to_string()))
+    }
+
+    /// Steam Workshop content directory for the game
+    /// (`steamapps/workshop/content/APPID` in the game's library).
+    pub fn workshop_content_path(&self) -> Option<PathBuf> {
+        let steamapps = self.install_path.parent()?.parent()?;
+        Some(
+            steamapps
+                .join("workshop/content")
+                .join(self.appid.to_string()),
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -62,23 +91,25 @@ pub struct ProtonApp {
     pub is_proton_ready: bool,
 }
 
-/// Find all Steam installations on the system.
-/// Checks common paths and STEAM_DIR environment variable.
-pub fn find_steam_installations() -> Vec<SteamInstallation> {
-    let mut installations = Vec::new();
-
+/// Default locations of a Steam installation in the home directory.
+pub fn steam_path_candidates() -> Vec<PathBuf> {
     let home = match std::env::var("HOME") {
         Ok(h) => PathBuf::from(h),
-        Err(_) => return installations,
+        Err(_) => return Vec::new(),
     };
-
-    let candidates = [
+    vec![
         home.join(".steam/steam"),
         home.join(".local/share/Steam"),
         home.join(".var/app/com.valvesoftware.Steam/.steam/steam"),
-    ];
+    ]
+}
 
-    for candidate in &candidates {
+/// Find all Steam installations on the system.
+/// Checks common paths and STEAM_DIR environment variable.
+pub fn find_steam_installations() -> Vec<SteamInstallation> {
+    let mut installations = Vec::new();
+
+    for candidate in &steam_path_candidates() {
         if candidate.join("steamapps").exists() {
             installations.push(SteamInstallation {
                 steam_path: candidate.clone(),
@@ -103,6 +134,76 @@ pub fn find_steam_installations() -> Vec<SteamInstallation> {
     installations
 }
 
+/// Metadata keys of the `libraryfolders` section that aren't libraries.
+const LIBRARYFOLDERS_METADATA_KEYS: &[&str] = &["contentstatsid", "timenextstatsreport"];
+
+/// Library paths listed in a parsed libraryfolders.vdf, along with warnings
+/// for entries that don't match a known schema. Known schemas:
+///
+/// - current (Steam 2021+): `"libraryfolders" { "0" { "path" "/lib" ... } }`,
+///   with `"contentstatsid"` next to the numbered folders;
+/// - legacy: `"LibraryFolders" { "TimeNextStatsReport" ".." "ContentStatsID"
+///   ".." "1" "/lib" }`, with the path as the value of the numbered key (the
+///   Steam directory itself isn't listed).
+pub fn parse_library_folders(vdf: &VDFDict) -> (Vec<PathBuf>, Vec<String>) {
+    let mut paths = Vec::new();
+    let mut warnings = Vec::new();
+
+    let folders = vdf.iter().find_map(|(key, value)| match value {
+        crate::vdf::VDFValue::Dict(dict) if key.eq_ignore_ascii_case("libraryfolders") => {
+            Some(dict)
+        }
+        _ => None,
+    });
+    let folders = match folders {
+        Some(f) => f,
+        None => {
+            warnings.push(
+                "libraryfolders.vdf has no libraryfolders section (unknown schema)".to_string(),
+            );
+            return (paths, warnings);
+        }
+    };
+
+    for (key, value) in folders.iter() {
+        if key.parse::<u32>().is_err() {
+            if !LIBRARYFOLDERS_METADATA_KEYS.contains(&key.to_lowercase().as_str()) {
+                warnings.push(format!(
+                    "libraryfolders.vdf: ignoring unknown entry \"{}\"",
+                    key
+                ));
+            }
+            continue;
+        }
+        let path = match value {
+            crate::vdf::VDFValue::String(path) => path.as_str(),
+            crate::vdf::VDFValue::Dict(folder) => match folder.get("path") {
+                Some(path) => path,
+                None => {
+                    warnings.push(format!(
+                        "libraryfolders.vdf: library folder \"{}\" has no path (unknown schema)",
+                        key
+                    ));
+                    continue;
+                }
+            },
+        };
+        let path = PathBuf::from(path);
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    (paths, warnings)
+}
+
+/// Library paths listed in a Steam installation's libraryfolders.vdf,
+/// whether or not they exist.
+pub fn listed_library_paths(steam_path: &Path) -> Vec<PathBuf> {
+    parse_vdf(&steam_path.join("steamapps/libraryfolders.vdf"))
+        .map(|vdf| parse_library_folders(&vdf).0)
+        .unwrap_or_default()
+}
+
 /// Get all Steam library paths from libraryfolders.vdf and extra sources.
 /// Includes paths from STEAM_EXTRA_COMPAT_TOOLS_PATHS environment variable.
 pub fn get_steam_lib_paths(steam_path: &Path, extra_paths: &[PathBuf]) -> Vec<PathBuf> {
@@ -110,21 +211,29 @@ pub fn get_steam_lib_paths(steam_path: &Path, extra_paths: &[PathBuf]) -> Vec<Pa
 
     let libraryfolders_path = steam_path.join("steamapps/libraryfolders.vdf");
 
-    if let Ok(vdf) = parse_vdf(&libraryfolders_path) {
-        if let Some(libraryfolders) = vdf.get_dict("libraryfolders") {
-            for (key, value) in libraryfolders.iter() {
-                if key.parse::<u32>().is_ok() {
-                    if let crate::vdf::VDFValue::Dict(folder_dict) = value {
-                        if let Some(path) = folder_dict.get("path") {
-                            let lib_path = PathBuf::from(path);
-                            if lib_path.exists() && !lib_paths.contains(&lib_path) {
-                                lib_paths.push(lib_path);
-                            }
-                        }
-                    }
+    match parse_vdf(&libraryfolders_path) {
+        Ok(vdf) => {
+            let (paths, warnings) = parse_library_folders(&vdf);
+            for warning in warnings {
+                crate::log::warn(&warning);
+                eprintln!("Warning: {}", warning);
+            }
+            for lib_path in paths {
+                if lib_path.exists() && !lib_paths.contains(&lib_path) {
+                    lib_paths.push(lib_path);
                 }
             }
         }
+        Err(e) if libraryfolders_path.exists() => {
+            let warning = format!(
+                "Failed to parse {}: {:?}. Only the main Steam library is used.",
+                libraryfolders_path.display(),
+                e
+            );
+            crate::log::warn(&warning);
+            eprintln!("Warning: {}", warning);
+        }
+        Err(_) => {}
     }
 
     let steamapps = steam_path.join("steamapps");
@@ -307,6 +416,61 @@ fn find_compat_tool_for_app(config_vdf: &VDFDict, appid: u32) -> Option<String>
     app_config.get("name").map(|s| s.to_string())
 }
 
+/// Find screenshot folders for an app across all Steam users
+/// (`userdata/<user>/760/remote/<appid>/screenshots`), most recently
+/// modified first.
+pub fn find_screenshot_dirs(steam_path: &Path, appid: u32) -> Vec<PathBuf> {
+    let userdata = steam_path.join("userdata");
+    let mut dirs: Vec<(std::time::SystemTime, PathBuf)> = fs::read_dir(&userdata)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| {
+                    e.path()
+                        .join("760/remote")
+                        .join(appid.to_string())
+                        .join("screenshots")
+                })
+                .filter(|p| p.is_dir())
+                .map(|p| {
+                    let modified = fs::metadata(&p)
+                        .and_then(|m| m.modified())
+                        .unwrap_or(std::time::UNIX_EPOCH);
+                    (modified, p)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    dirs.into_iter().map(|(_, p)| p).collect()
+}
+
+/// Per-game Steam Input setting (`UseSteamControllerConfig`) from the first
+/// Steam user that has one: "0" disabled, "1" enabled, "2" use the default.
+pub fn steam_input_setting(steam_path: &Path, appid: u32) -> Option<String> {
+    local_app_config_value(steam_path, appid, "UseSteamControllerConfig")
+}
+
+/// Per-game launch options (`LaunchOptions`) from the first Steam user that
+/// has them set.
+pub fn launch_options(steam_path: &Path, appid: u32) -> Option<String> {
+    local_app_config_value(steam_path, appid, "LaunchOptions").filter(|o| !o.is_empty())
+}
+
+/// A value from an app's section in the first user's `localconfig.vdf`
+/// that has it.
+fn local_app_config_value(steam_path: &Path, appid: u32, key: &str) -> Option<String> {
+    let users = fs::read_dir(steam_path.join("userdata")).ok()?;
+    users.flatten().find_map(|user| {
+        let config = parse_vdf(&user.path().join("config/localconfig.vdf")).ok()?;
+        let store = config.get_dict("UserLocalConfigStore")?;
+        let apps = store.get_dict("apps").or_else(|| store.get_dict("Apps"))?;
+        apps.get_dict(&appid.to_string())?
+            .get(key)
+            .map(|s| s.to_string())
+    })
+}
+
 /// Find the legacy Steam Runtime (ubuntu12_32) path if it exists.
 pub fn find_legacy_steam_runtime_path(steam_root: &Path) -> Option<PathBuf> {
     let runtime_path = steam_root.join("ubuntu12_32/steam-runtime");
@@ -339,6 +503,20 @@ pub fn get_proton_apps(steam_apps: &[SteamApp]) -> Vec<ProtonApp> {
         .collect()
 }
 
+/// Pick a Proton for prefixes not tied to a game: PROTON_VERSION if set,
+/// otherwise the newest ready installation.
+pub fn find_default_proton(steam_apps: &[SteamApp]) -> Option<ProtonApp> {
+    if let Ok(name) = std::env::var("PROTON_VERSION") {
+        if let Some(app) = find_proton_by_name(steam_apps, &name) {
+            return Some(app);
+        }
+    }
+    get_proton_apps(steam_apps)
+        .into_iter()
+        .filter(|app| app.is_proton_ready)
+        .max_by(|a, b| a.name.cmp(&b.name))
+}
+
 /// Find a specific Proton app by name
 pub fn find_proton_by_name(steam_apps: &[SteamApp], name: &str) -> Option<ProtonApp> {
     let app = steam_apps
@@ -356,3 +534,85 @@ pub fn find_proton_by_name(steam_apps: &[SteamApp], name: &str) -> Option<Proton
         is_proton_ready: is_ready,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vdf::parse_vdf_string;
+
+    /// libraryfolders.vdf written by current Steam clients.
+    const LIBRARYFOLDERS_CURRENT: &str = r#"
+"libraryfolders"
+{
+	"contentstatsid"		"-4176918934221343735"
+	"0"
+	{
+		"path"		"/home/deck/.local/share/Steam"
+		"label"		""
+		"contentid"		"6210153862226924397"
+		"totalsize"		"0"
+		"update_clean_bytes_tally"		"3514932412"
+		"time_last_update_corruption"		"0"
+		"apps"
+		{
+			"228980"		"382883297"
+			"1091500"		"65865846113"
+		}
+	}
+	"1"
+	{
+		"path"		"/run/media/mmcblk0p1"
+		"label"		""
+		"contentid"		"3043298163386391187"
+		"totalsize"		"511732432896"
+		"apps"
+		{
+		}
+	}
+}
+"#;
+
+    /// libraryfolders.vdf written by Steam clients before mid-2021.
+    const LIBRARYFOLDERS_LEGACY: &str = r#"
+"LibraryFolders"
+{
+	"TimeNextStatsReport"		"1612345678"
+	"ContentStatsID"		"-4176918934221343735"
+	"1"		"/mnt/games/SteamLibrary"
+	"2"		"/mnt/ssd/Steam"
+}
+"#;
+
+    #[test]
+    fn test_parse_library_folders_schemas() {
+        let (paths, warnings) =
+            parse_library_folders(&parse_vdf_string(LIBRARYFOLDERS_CURRENT).unwrap());
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/home/deck/.local/share/Steam"),
+                PathBuf::from("/run/media/mmcblk0p1"),
+            ]
+        );
+        assert!(warnings.is_empty());
+
+        let (paths, warnings) =
+            parse_library_folders(&parse_vdf_string(LIBRARYFOLDERS_LEGACY).unwrap());
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/mnt/games/SteamLibrary"),
+                PathBuf::from("/mnt/ssd/Steam"),
+            ]
+        );
+        assert!(warnings.is_empty());
+
+        let unknown = "\"libraryfolders\" { \"0\" { \"mount\" \"/lib\" } \"version\" \"3\" }";
+        let (paths, warnings) = parse_library_folders(&parse_vdf_string(unknown).unwrap());
+        assert!(paths.is_empty());
+        assert_eq!(warnings.len(), 2);
+
+        let (_, warnings) = parse_library_folders(&parse_vdf_string("\"folders\" { }").unwrap());
+        assert_eq!(warnings.len(), 1);
+    }
+}
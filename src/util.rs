@@ -3,6 +3,71 @@ use std::fs;
 use std::os::unix::fs::symlink;
 use std::path::{Path, PathBuf};
 use std::process::Output;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Whether output should avoid box drawing, colors and column alignment.
+static PLAIN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable plain (screen-reader friendly) output.
+pub fn set_plain_output(plain: bool) {
+    PLAIN_OUTPUT.store(plain, Ordering::SeqCst);
+}
+
+/// Check whether plain output was requested with `--plain`.
+pub fn is_plain_output() -> bool {
+    PLAIN_OUTPUT.load(Ordering::SeqCst)
+}
+
+/// Progress spinner on stderr while a long-running command works. Nothing is
+/// drawn when stderr isn't a terminal, in plain output mode, or when the
+/// spinner is dropped within half a second.
+pub struct Spinner {
+    done: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Spinner {
+    pub fn start(label: &str) -> Self {
+        use std::io::IsTerminal;
+
+        let done = Arc::new(AtomicBool::new(false));
+        if is_plain_output() || !std::io::stderr().is_terminal() {
+            return Spinner { done, thread: None };
+        }
+        let label = label.to_string();
+        let stop = done.clone();
+        let thread = std::thread::spawn(move || {
+            const FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+            let mut tick = 0usize;
+            while !stop.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(100));
+                tick += 1;
+                if tick >= 5 {
+                    eprint!("\r{} {}", FRAMES[tick % FRAMES.len()], label);
+                }
+            }
+            if tick >= 5 {
+                eprint!("\r\x1b[2K");
+            }
+        });
+        Spinner {
+            done,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.done.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
 
 /// Extract stdout from a command output as a trimmed string
 pub fn output_to_string(output: &Output) -> String {
@@ -31,7 +96,7 @@ pub fn which(name: &str) -> Option<std::path::PathBuf> {
 
 /// Check if a file has executable permissions (unix only).
 #[cfg(unix)]
-fn is_executable(path: &Path) -> bool {
+pub(crate) fn is_executable(path: &Path) -> bool {
     use std::os::unix::fs::PermissionsExt;
     if let Ok(metadata) = path.metadata() {
         let permissions = metadata.permissions();
@@ -76,6 +141,42 @@ pub fn shell_quote(s: &str) -> String {
     quoted
 }
 
+/// Split a command line into arguments at whitespace, keeping text in
+/// single or double quotes together. Backslashes are literal, so Windows
+/// paths can be typed as they are.
+///
+/// ```
+/// use protontool::util::split_args;
+/// assert_eq!(
+///     split_args(r#"notepad "C:\My Files\a.txt""#),
+///     Ok(vec!["notepad".to_string(), r"C:\My Files\a.txt".to_string()])
+/// );
+/// assert_eq!(split_args("winedbg  --help"), Ok(vec!["winedbg".to_string(), "--help".to_string()]));
+/// assert!(split_args("cmd /c 'unterminated").is_err());
+/// ```
+pub fn split_args(s: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current: Option<String> = None;
+    let mut quote: Option<char> = None;
+    for c in s.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.get_or_insert_with(String::new).push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                current.get_or_insert_with(String::new);
+            }
+            None if c.is_whitespace() => args.extend(current.take()),
+            None => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if let Some(q) = quote {
+        return Err(format!("Unterminated {} quote", q));
+    }
+    args.extend(current);
+    Ok(args)
+}
+
 /// Recursively walk a directory and collect all files with the given extension.
 /// Skips symlinks to avoid infinite loops. Extension should not include the dot.
 pub fn walk_dir_files_with_ext(dir: &Path, ext: &str) -> Vec<PathBuf> {
@@ -126,6 +227,86 @@ pub fn parse_hex(s: &str) -> Option<u32> {
     }
 }
 
+/// Compare two dotted version strings numerically ("2.10" > "2.6").
+/// Any non-digit character separates components; missing components count as 0.
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use protontool::util::compare_versions;
+/// assert_eq!(compare_versions("2.10", "2.6"), Ordering::Greater);
+/// assert_eq!(compare_versions("9.0-301", "9.0-301"), Ordering::Equal);
+/// assert_eq!(compare_versions("2.5", "2.5.3"), Ordering::Less);
+/// ```
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let parse = |s: &str| -> Vec<u64> {
+        s.split(|c: char| !c.is_ascii_digit())
+            .filter(|p| !p.is_empty())
+            .map(|p| p.parse().unwrap_or(0))
+            .collect()
+    };
+    let (a, b) = (parse(a), parse(b));
+    for i in 0..a.len().max(b.len()) {
+        let ord = a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0));
+        if ord != std::cmp::Ordering::Equal {
+            return ord;
+        }
+    }
+    std::cmp::Ordering::Equal
+}
+
+/// Format a byte count for display using binary units.
+///
+/// ```
+/// use protontool::util::format_size;
+/// assert_eq!(format_size(512), "512 B");
+/// assert_eq!(format_size(1536), "1.5 KiB");
+/// assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GiB");
+/// ```
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// UTC date and time of a Unix time, as `[year, month, day, hour, minute,
+/// second]`.
+///
+/// ```
+/// use protontool::util::utc_datetime;
+/// assert_eq!(utc_datetime(0), [1970, 1, 1, 0, 0, 0]);
+/// assert_eq!(utc_datetime(1792152000), [2026, 10, 16, 12, 0, 0]);
+/// ```
+pub fn utc_datetime(unix_time: u64) -> [u64; 6] {
+    // Civil date from days since 1970-01-01 (Howard Hinnant's civil_from_days)
+    let days = (unix_time / 86400) as i64;
+    let secs = unix_time % 86400;
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    [
+        year as u64,
+        month as u64,
+        day as u64,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    ]
+}
+
 /// Calculate a relative path from one directory to another.
 /// Both paths are canonicalized before computation.
 /// Returns None if either path cannot be canonicalized.
@@ -202,3 +383,15 @@ pub fn make_symlink(target: &Path, link: &Path, relative: bool) -> std::io::Resu
 pub fn make_relative_symlink(target: &Path, linkname: &Path) -> std::io::Result<()> {
     make_symlink(target, linkname, true)
 }
+
+/// Open a file or directory with the desktop's default application (`xdg-open`).
+pub fn open_path(path: &Path) -> Result<(), String> {
+    let xdg_open = which("xdg-open").ok_or("xdg-open is not installed")?;
+    std::process::Command::new(xdg_open)
+        .arg(path)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))
+}
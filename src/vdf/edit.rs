@@ -0,0 +1,124 @@
+/// A token of VDF text and where it is.
+#[derive(Debug)]
+enum Token {
+    Str {
+        text: String,
+        start: usize,
+        end: usize,
+    },
+    Open,
+    Close {
+        start: usize,
+    },
+}
+
+/// Escape a string for a quoted VDF token.
+///
+/// ```
+/// use protontool::vdf::escape;
+/// assert_eq!(escape(r#"A="b c" %command%"#), r#"A=\"b c\" %command%"#);
+/// ```
+pub fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn tokenize(content: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = content.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' => tokens.push(Token::Open),
+            '}' => tokens.push(Token::Close { start }),
+            '"' => {
+                let mut text = String::new();
+                let mut escaped = false;
+                let end = loop {
+                    match chars.next() {
+                        None => return Err("Unexpected end of string".to_string()),
+                        Some((_, '\\')) if !escaped => escaped = true,
+                        Some((i, '"')) if !escaped => break i + 1,
+                        Some((_, c)) => {
+                            escaped = false;
+                            text.push(c);
+                        }
+                    }
+                };
+                tokens.push(Token::Str { text, start, end });
+            }
+            '/' if chars.peek().is_some_and(|(_, c)| *c == '/') => {
+                while chars.next_if(|(_, c)| *c != '\n').is_some() {}
+            }
+            c if c.is_whitespace() => {}
+            c => return Err(format!("Unexpected character: {}", c)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Set a string value in the block at `path` of VDF text, leaving the rest
+/// of the text as it was. Block names match case-insensitively, as Steam
+/// writes both `apps` and `Apps`. A missing value is added at the end of
+/// the block; a missing block is an error.
+///
+/// ```
+/// use protontool::vdf::set_string;
+/// let vdf = "\"Store\"\n{\n\t\"apps\"\n\t{\n\t\t\"620\"\n\t\t{\n\t\t}\n\t}\n}\n";
+/// let edited = set_string(vdf, &["Store", "Apps", "620"], "LaunchOptions", "%command% -x").unwrap();
+/// assert!(edited.contains("\t\t\t\"LaunchOptions\"\t\t\"%command% -x\"\n\t\t}"));
+/// let edited = set_string(&edited, &["Store", "apps", "620"], "LaunchOptions", "").unwrap();
+/// assert!(edited.contains("\"LaunchOptions\"\t\t\"\""));
+/// ```
+pub fn set_string(content: &str, path: &[&str], key: &str, value: &str) -> Result<String, String> {
+    let tokens = tokenize(content)?;
+    let mut stack: Vec<&str> = Vec::new();
+    let in_block = |stack: &[&str]| {
+        stack.len() == path.len()
+            && stack
+                .iter()
+                .zip(path)
+                .all(|(a, b)| a.eq_ignore_ascii_case(b))
+    };
+    let quoted = format!("\"{}\"", escape(value));
+    let mut i = 0;
+    while i < tokens.len() {
+        match (&tokens[i], tokens.get(i + 1)) {
+            (Token::Str { text, .. }, Some(Token::Open)) => {
+                stack.push(text);
+                i += 2;
+            }
+            (Token::Str { text, .. }, Some(Token::Str { start, end, .. })) => {
+                if in_block(&stack) && text.eq_ignore_ascii_case(key) {
+                    return Ok(format!(
+                        "{}{}{}",
+                        &content[..*start],
+                        quoted,
+                        &content[*end..]
+                    ));
+                }
+                i += 2;
+            }
+            (Token::Close { start }, _) => {
+                if in_block(&stack) {
+                    let line_start = content[..*start].rfind('\n').map_or(0, |n| n + 1);
+                    let indent = &content[line_start..*start];
+                    let entry = format!("\"{}\"\t\t{}", escape(key), quoted);
+                    return Ok(if indent.trim().is_empty() {
+                        format!(
+                            "{}{}\t{}\n{}",
+                            &content[..line_start],
+                            indent,
+                            entry,
+                            &content[line_start..]
+                        )
+                    } else {
+                        format!("{}{} {}", &content[..*start], entry, &content[*start..])
+                    });
+                }
+                stack.pop();
+                i += 1;
+            }
+            _ => return Err("Malformed VDF".to_string()),
+        }
+    }
+    Err(format!("No \"{}\" section", path.join("/")))
+}
@@ -1,10 +1,12 @@
 //! Valve Data Format (VDF) parser module.
 //!
 //! Parses Steam's VDF files (libraryfolders.vdf, appmanifest_*.acf, config.vdf)
-//! into a key-value dictionary structure.
+//! into a key-value dictionary structure, and edits values in place.
 
+mod edit;
 mod parser;
 mod vdict;
 
+pub use edit::*;
 pub use parser::*;
 pub use vdict::*;
@@ -0,0 +1,235 @@
+//! Build provenance for bug reports.
+//!
+//! `protontool version` reports the exact build: the crate version, the git
+//! commit and build date recorded by `build.rs`, compile-time features, the
+//! built-in verb catalogue and the Wine source the error database was
+//! extracted from. `--json` prints the same as one JSON object.
+
+use crate::json::JsonValue;
+use crate::wine::verbs::{VerbRegistry, BUILTIN_NAMESPACE};
+
+/// Git commit the binary was built from (`-dirty` with uncommitted
+/// changes), if it was built from a git checkout.
+pub const GIT_COMMIT: Option<&str> = option_env!("PROTONTOOL_GIT_COMMIT");
+
+/// Unix time of the build (`SOURCE_DATE_EPOCH` for reproducible builds).
+const BUILD_TIME: Option<&str> = option_env!("PROTONTOOL_BUILD_TIME");
+
+/// Compile-time features and whether each is enabled.
+const FEATURES: &[(&str, bool)] = &[
+    ("custom_steam_dir", cfg!(feature = "custom_steam_dir")),
+    ("custom_gui_provider", cfg!(feature = "custom_gui_provider")),
+    (
+        "custom_steam_runtime",
+        cfg!(feature = "custom_steam_runtime"),
+    ),
+];
+
+/// Everything `protontool version` reports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_commit: Option<&'static str>,
+    /// Build date in ISO 8601, UTC.
+    pub build_date: Option<String>,
+    pub features: Vec<&'static str>,
+    /// GUI provider compiled in with `custom_gui_provider`.
+    pub default_gui_provider: Option<&'static str>,
+    /// Dialog tool found at runtime.
+    pub gui_tool: Option<String>,
+    pub plugin_protocol: u32,
+    pub builtin_verbs: usize,
+    pub custom_verbs: usize,
+    pub wine_source_version: Option<&'static str>,
+    pub debug_channels: usize,
+    pub known_errors: usize,
+    pub distro: String,
+}
+
+/// Format a Unix time as an ISO 8601 UTC timestamp.
+///
+/// ```
+/// use protontool::version::iso_date;
+/// assert_eq!(iso_date(1792152000), "2026-10-16T12:00:00Z");
+/// ```
+pub fn iso_date(unix_time: u64) -> String {
+    let [year, month, day, hour, minute, second] = crate::util::utc_datetime(unix_time);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, hour, minute, second
+    )
+}
+
+impl BuildInfo {
+    /// Collect the build details and what this host adds to them.
+    pub fn collect() -> Self {
+        let registry = VerbRegistry::new();
+        let verbs = registry.list(None);
+        let builtin_verbs = verbs
+            .iter()
+            .filter(|verb| verb.namespace == BUILTIN_NAMESPACE)
+            .count();
+        BuildInfo {
+            version: crate::VERSION,
+            git_commit: GIT_COMMIT,
+            build_date: BUILD_TIME.and_then(|time| time.parse().ok()).map(iso_date),
+            features: FEATURES
+                .iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| *name)
+                .collect(),
+            default_gui_provider: crate::config::DEFAULT_GUI_PROVIDER,
+            gui_tool: crate::gui::get_gui_tool().map(|tool| tool.display().to_string()),
+            plugin_protocol: crate::wine::plugin::PLUGIN_PROTOCOL_VERSION,
+            builtin_verbs,
+            custom_verbs: verbs.len() - builtin_verbs,
+            wine_source_version: crate::wine_data::WINE_SOURCE_VERSION,
+            debug_channels: crate::wine_data::WINE_DEBUG_CHANNELS.len(),
+            known_errors: crate::wine_data::KNOWN_ERRORS.len(),
+            distro: crate::distro::detect().name,
+        }
+    }
+
+    /// The details as a JSON object.
+    pub fn to_json(&self) -> JsonValue {
+        let string = |s: &str| JsonValue::String(s.to_string());
+        let optional = |s: Option<&str>| s.map_or(JsonValue::Null, string);
+        let number = |n: usize| JsonValue::Number(n as f64);
+        JsonValue::Object(vec![
+            ("version".to_string(), string(self.version)),
+            ("git_commit".to_string(), optional(self.git_commit)),
+            (
+                "build_date".to_string(),
+                optional(self.build_date.as_deref()),
+            ),
+            (
+                "features".to_string(),
+                JsonValue::Array(self.features.iter().map(|f| string(f)).collect()),
+            ),
+            (
+                "gui".to_string(),
+                JsonValue::Object(vec![
+                    (
+                        "default_provider".to_string(),
+                        optional(self.default_gui_provider),
+                    ),
+                    ("tool".to_string(), optional(self.gui_tool.as_deref())),
+                ]),
+            ),
+            (
+                "plugin_protocol".to_string(),
+                number(self.plugin_protocol as usize),
+            ),
+            (
+                "verbs".to_string(),
+                JsonValue::Object(vec![
+                    ("builtin".to_string(), number(self.builtin_verbs)),
+                    ("custom".to_string(), number(self.custom_verbs)),
+                ]),
+            ),
+            (
+                "wine_data".to_string(),
+                JsonValue::Object(vec![
+                    (
+                        "wine_version".to_string(),
+                        optional(self.wine_source_version),
+                    ),
+                    ("debug_channels".to_string(), number(self.debug_channels)),
+                    ("known_errors".to_string(), number(self.known_errors)),
+                ]),
+            ),
+            ("distro".to_string(), string(&self.distro)),
+        ])
+    }
+
+    /// The details as text for a terminal.
+    pub fn describe(&self) -> String {
+        let or_unknown = |s: Option<&str>| s.unwrap_or("unknown").to_string();
+        let rows = [
+            ("Commit", or_unknown(self.git_commit)),
+            ("Built", or_unknown(self.build_date.as_deref())),
+            (
+                "Features",
+                if self.features.is_empty() {
+                    "none".to_string()
+                } else {
+                    self.features.join(", ")
+                },
+            ),
+            (
+                "GUI",
+                format!(
+                    "{} (compiled default: {})",
+                    self.gui_tool.as_deref().unwrap_or("no dialog tool found"),
+                    self.default_gui_provider.unwrap_or("none")
+                ),
+            ),
+            ("Plugin protocol", self.plugin_protocol.to_string()),
+            (
+                "Verbs",
+                format!(
+                    "{} built-in, {} custom",
+                    self.builtin_verbs, self.custom_verbs
+                ),
+            ),
+            (
+                "Wine data",
+                format!(
+                    "Wine {}, {} debug channels, {} known errors",
+                    or_unknown(self.wine_source_version),
+                    self.debug_channels,
+                    self.known_errors
+                ),
+            ),
+            (
+                "Host",
+                if self.distro.is_empty() {
+                    "unknown".to_string()
+                } else {
+                    self.distro.clone()
+                },
+            ),
+        ];
+        let mut text = format!("protontool {}\n", self.version);
+        for (label, value) in rows {
+            text.push_str(&format!("  {:<16} {}\n", format!("{}:", label), value));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let info = BuildInfo {
+            version: "1.2.3",
+            git_commit: None,
+            build_date: Some(iso_date(0)),
+            features: vec!["custom_gui_provider"],
+            default_gui_provider: Some("yad"),
+            gui_tool: None,
+            plugin_protocol: 1,
+            builtin_verbs: 200,
+            custom_verbs: 2,
+            wine_source_version: None,
+            debug_channels: 10,
+            known_errors: 5,
+            distro: "Arch Linux".to_string(),
+        };
+        let parsed = crate::json::parse(&info.to_json().to_string()).unwrap();
+        assert_eq!(
+            parsed.get("version").and_then(|v| v.as_str()),
+            Some("1.2.3")
+        );
+        assert_eq!(parsed.get("git_commit"), Some(&JsonValue::Null));
+        assert_eq!(
+            parsed.get("build_date").and_then(|v| v.as_str()),
+            Some("1970-01-01T00:00:00Z")
+        );
+        let verbs = parsed.get("verbs").unwrap();
+        assert_eq!(verbs.get("custom").and_then(|v| v.as_f64()), Some(2.0));
+    }
+}
@@ -0,0 +1,253 @@
+//! Permission and security module checks for `doctor`.
+//!
+//! "It just doesn't start" is often a permission problem: prefix files
+//! owned by root after a `sudo` run, registry hives without write
+//! permission, a Proton install whose binaries lost their executable bit,
+//! or a SELinux/AppArmor policy denying Wine or the Steam Runtime
+//! container. These checks look at the prefix and Proton paths and at the
+//! denials the kernel logged for them, and say how to fix what they find.
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::Command;
+
+use super::ownership;
+use super::WineContext;
+use crate::doctor::{Finding, Severity};
+use crate::util::which;
+
+/// SELinux remediation guide.
+const SELINUX_HELP: &str = "https://wiki.archlinux.org/title/SELinux";
+
+/// AppArmor remediation guide.
+const APPARMOR_HELP: &str = "https://wiki.archlinux.org/title/AppArmor";
+
+/// Explanation of Ubuntu's user namespace restriction.
+const USERNS_HELP: &str =
+    "https://ubuntu.com/blog/ubuntu-23-10-restricted-unprivileged-user-namespaces";
+
+/// Processes whose denials matter even when the log line names no path.
+const WINE_PROCESSES: &[&str] = &[
+    "wine",
+    "wine64",
+    "wineserver",
+    "wine-preloader",
+    "wine64-preloader",
+    "pressure-vessel",
+    "pv-bwrap",
+    "bwrap",
+];
+
+/// How many logged denials are shown.
+const MAX_DENIALS: usize = 5;
+
+fn finding(severity: Severity, message: String) -> Finding {
+    Finding {
+        check: "permissions",
+        severity,
+        message,
+        fix: None,
+    }
+}
+
+/// Run the permission checks for a prefix and its Proton.
+pub fn check(wine_ctx: &WineContext) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    findings.extend(check_ownership(&wine_ctx.prefix_path));
+    findings.extend(check_modes(wine_ctx));
+    findings.extend(check_selinux());
+    findings.extend(check_apparmor());
+    findings.extend(check_denials(wine_ctx));
+    if findings.is_empty() {
+        findings.push(finding(
+            Severity::Ok,
+            "Prefix and Proton files are owned by you and accessible; no denials logged"
+                .to_string(),
+        ));
+    }
+    findings
+}
+
+/// Prefix files owned by another user (usually root after a `sudo` run).
+fn check_ownership(prefix_path: &Path) -> Option<Finding> {
+    let uid = ownership::effective_uid()?;
+    let foreign = ownership::foreign_owned(prefix_path, uid);
+    let first = foreign.first()?;
+    Some(finding(
+        Severity::Problem,
+        format!(
+            "{} file(s) in the prefix belong to another user (e.g. {}); \
+             fix with: sudo protontool --prefix {} --fix-ownership",
+            foreign.len(),
+            first.display(),
+            crate::util::shell_quote(&prefix_path.to_string_lossy())
+        ),
+    ))
+}
+
+/// Mode bit problems: prefix files that can't be written, a prefix others
+/// can write, and Proton binaries that can't be executed.
+fn check_modes(wine_ctx: &WineContext) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let prefix = &wine_ctx.prefix_path;
+    for path in [
+        prefix.to_path_buf(),
+        prefix.join("drive_c"),
+        prefix.join("system.reg"),
+        prefix.join("user.reg"),
+        prefix.join("userdef.reg"),
+    ] {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.permissions().mode() & 0o200 == 0 {
+            findings.push(finding(
+                Severity::Problem,
+                format!("{} is not writable (chmod u+w)", path.display()),
+            ));
+        }
+    }
+    if fs::metadata(prefix).is_ok_and(|m| m.permissions().mode() & 0o002 != 0) {
+        findings.push(finding(
+            Severity::Warning,
+            format!("{} is writable by every user (chmod o-w)", prefix.display()),
+        ));
+    }
+    for path in [
+        wine_ctx.proton_path.join("proton"),
+        wine_ctx.wine_path.clone(),
+        wine_ctx.wineserver_path.clone(),
+    ] {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.permissions().mode() & 0o111 == 0 {
+            findings.push(finding(
+                Severity::Problem,
+                format!(
+                    "{} is not executable (chmod +x, or verify the Proton files in Steam)",
+                    path.display()
+                ),
+            ));
+        }
+    }
+    findings
+}
+
+/// SELinux in enforcing mode without the boolean Wine needs to map
+/// executable memory.
+fn check_selinux() -> Option<Finding> {
+    let enforce = fs::read_to_string("/sys/fs/selinux/enforce").ok()?;
+    if enforce.trim() != "1" {
+        return None;
+    }
+    let execmod = fs::read_to_string("/sys/fs/selinux/booleans/selinuxuser_execmod").ok()?;
+    if execmod.split_whitespace().next() != Some("0") {
+        return None;
+    }
+    Some(finding(
+        Severity::Problem,
+        format!(
+            "SELinux is enforcing and selinuxuser_execmod is off, which stops Wine from \
+             loading Windows code; enable it with: sudo setsebool -P selinuxuser_execmod 1 \
+             (see {})",
+            SELINUX_HELP
+        ),
+    ))
+}
+
+/// AppArmor restricting the unprivileged user namespaces the Steam Runtime
+/// container (pressure-vessel) needs.
+fn check_apparmor() -> Option<Finding> {
+    let enabled = fs::read_to_string("/sys/module/apparmor/parameters/enabled").ok()?;
+    if enabled.trim() != "Y" {
+        return None;
+    }
+    let restricted =
+        fs::read_to_string("/proc/sys/kernel/apparmor_restrict_unprivileged_userns").ok()?;
+    if restricted.trim() != "1" {
+        return None;
+    }
+    Some(finding(
+        Severity::Warning,
+        format!(
+            "AppArmor restricts unprivileged user namespaces, which the Steam Runtime \
+             container needs; Steam's AppArmor profile must be installed, or Proton games \
+             fail to start (see {})",
+            USERNS_HELP
+        ),
+    ))
+}
+
+/// Kernel log lines that record a SELinux or AppArmor denial involving one
+/// of `paths` or a Wine or Steam Runtime process.
+///
+/// ```
+/// use protontool::wine::access::denials;
+/// let log = "\
+/// audit: type=1400 apparmor=\"DENIED\" operation=\"open\" name=\"/home/u/pfx/user.reg\" comm=\"wineserver\"
+/// audit: type=1400 apparmor=\"DENIED\" operation=\"open\" name=\"/etc/shadow\" comm=\"cat\"
+/// audit: type=1400 avc:  denied  { execmod } for pid=42 comm=\"wine64-preloader\"
+/// audit: type=1400 apparmor=\"ALLOWED\" name=\"/home/u/pfx/system.reg\"";
+/// assert_eq!(denials(log, &["/home/u/pfx"]).len(), 2);
+/// ```
+pub fn denials<'a>(log: &'a str, paths: &[&str]) -> Vec<&'a str> {
+    log.lines()
+        .filter(|line| {
+            (line.contains("avc:") && line.contains("denied"))
+                || line.contains("apparmor=\"DENIED\"")
+        })
+        .filter(|line| {
+            paths
+                .iter()
+                .any(|path| !path.is_empty() && line.contains(path))
+                || WINE_PROCESSES
+                    .iter()
+                    .any(|process| line.contains(&format!("comm=\"{}\"", process)))
+        })
+        .collect()
+}
+
+/// The kernel and audit log, as far as this user can read it.
+fn security_log() -> Option<String> {
+    if let Ok(audit) = fs::read_to_string("/var/log/audit/audit.log") {
+        return Some(audit);
+    }
+    let journalctl = which("journalctl")?;
+    let output = Command::new(journalctl)
+        .args(["-k", "-b", "-q", "--no-pager", "-n", "5000"])
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Denials logged since boot for the prefix, Proton or Wine processes.
+fn check_denials(wine_ctx: &WineContext) -> Option<Finding> {
+    let log = security_log()?;
+    let prefix = wine_ctx.prefix_path.to_string_lossy();
+    let proton = wine_ctx.proton_path.to_string_lossy();
+    let found = denials(&log, &[&prefix, &proton]);
+    if found.is_empty() {
+        return None;
+    }
+    let help = if found.iter().any(|line| line.contains("apparmor=")) {
+        APPARMOR_HELP
+    } else {
+        SELINUX_HELP
+    };
+    let shown: Vec<&str> = found
+        .iter()
+        .skip(found.len().saturating_sub(MAX_DENIALS))
+        .map(|line| line.trim())
+        .collect();
+    Some(finding(
+        Severity::Problem,
+        format!(
+            "{} security policy denial(s) logged for Wine or this prefix (see {}):\n  {}",
+            found.len(),
+            help,
+            shown.join("\n  ")
+        ),
+    ))
+}
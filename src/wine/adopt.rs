@@ -0,0 +1,134 @@
+//! Adopting prefixes created by other tools.
+//!
+//! `--adopt-prefix PATH` takes over an existing prefix made by plain Wine,
+//! Lutris, Bottles, PlayOnLinux or CrossOver without recreating it. The
+//! prefix is inspected without running Wine: the architecture comes from
+//! the `#arch=` header of `system.reg` (or whether `syswow64` exists), the
+//! Windows version from the Wine `Version` setting or the build in
+//! `CurrentVersion`. Both are written to the prefix metadata along with the
+//! tool that created it, and the prefix is remembered as a prefix root so it
+//! is listed with the custom prefixes.
+
+use std::fs;
+use std::path::Path;
+
+use super::discovery;
+use super::metadata::PrefixMetadata;
+use super::registry::{read_prefix_value, WINDOWS_VERSIONS};
+use super::WineArch;
+use crate::steam::ProtonApp;
+
+const WINE_KEY: &str = "HKEY_CURRENT_USER\\Software\\Wine";
+const CURRENT_VERSION_KEY: &str =
+    "HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows NT\\CurrentVersion";
+
+/// What an existing prefix was found to be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixInfo {
+    pub arch: WineArch,
+    /// Windows version name (`win10`, `win7`, ...), if it could be told.
+    pub windows_version: Option<String>,
+    /// Tool that created the prefix: `crossover`, `bottles`,
+    /// `playonlinux`, `lutris` or `wine`.
+    pub origin: &'static str,
+}
+
+/// Architecture from the `#arch=win32|win64` header Wine writes to the
+/// registry files.
+///
+/// ```
+/// use protontool::wine::adopt::registry_arch;
+/// use protontool::wine::WineArch;
+/// let header = "WINE REGISTRY Version 2\n;; All keys relative to \\\\Machine\n\n#arch=win32\n";
+/// assert_eq!(registry_arch(header), Some(WineArch::Win32));
+/// assert_eq!(registry_arch("WINE REGISTRY Version 2\n"), None);
+/// ```
+pub fn registry_arch(content: &str) -> Option<WineArch> {
+    content
+        .lines()
+        .take_while(|line| !line.starts_with('['))
+        .find_map(|line| line.strip_prefix("#arch="))
+        .and_then(|arch| WineArch::from_str(arch.trim()))
+}
+
+/// Tool that created a prefix, from the files it leaves next to the Wine
+/// files or the path it keeps prefixes under.
+fn origin(prefix_path: &Path) -> &'static str {
+    let path = prefix_path.to_string_lossy().to_lowercase();
+    if prefix_path.join("cxbottle.conf").exists() {
+        "crossover"
+    } else if prefix_path.join("bottle.yml").exists() {
+        "bottles"
+    } else if path.contains("/.playonlinux/") {
+        "playonlinux"
+    } else if path.contains("lutris") {
+        "lutris"
+    } else {
+        "wine"
+    }
+}
+
+/// Windows version a prefix reports: the Wine setting if set, else the
+/// winver table entry with the build in `CurrentVersion`.
+fn windows_version(prefix_path: &Path) -> Option<String> {
+    let string = |data: String| data.trim_matches('"').to_string();
+    if let Some(version) = read_prefix_value(prefix_path, WINE_KEY, "Version") {
+        return Some(string(version));
+    }
+    let build: u32 = read_prefix_value(prefix_path, CURRENT_VERSION_KEY, "CurrentBuild")
+        .and_then(|build| string(build).parse().ok())?;
+    let server = read_prefix_value(prefix_path, CURRENT_VERSION_KEY, "ProductName")
+        .is_some_and(|product| product.contains("Server"));
+    WINDOWS_VERSIONS
+        .iter()
+        .find(|version| version.build == build && version.server == server)
+        .map(|version| version.name.to_string())
+}
+
+/// Inspect an existing prefix.
+pub fn inspect(prefix_path: &Path) -> Result<PrefixInfo, String> {
+    if !discovery::is_prefix(prefix_path) {
+        return Err(format!(
+            "{} is not a Wine prefix (no system.reg and drive_c)",
+            prefix_path.display()
+        ));
+    }
+    let arch = fs::read_to_string(prefix_path.join("system.reg"))
+        .ok()
+        .and_then(|content| registry_arch(&content))
+        .unwrap_or(if prefix_path.join("drive_c/windows/syswow64").is_dir() {
+            WineArch::Win64
+        } else {
+            WineArch::Win32
+        });
+    Ok(PrefixInfo {
+        arch,
+        windows_version: windows_version(prefix_path),
+        origin: origin(prefix_path),
+    })
+}
+
+/// Write protontool's metadata for an inspected prefix and list it with the
+/// custom prefixes. Without a Proton, one is asked for on first use.
+pub fn adopt(
+    prefix_path: &Path,
+    info: &PrefixInfo,
+    proton_app: Option<&ProtonApp>,
+) -> Result<(), String> {
+    let mut metadata = PrefixMetadata::load(prefix_path);
+    metadata.set("arch", info.arch.as_str());
+    if let Some(version) = &info.windows_version {
+        metadata.set("windows_version", version);
+    }
+    if let Some(proton_app) = proton_app {
+        metadata.set("proton_name", &proton_app.name);
+        metadata.set(
+            "proton_path",
+            &proton_app.install_path.display().to_string(),
+        );
+    }
+    metadata.set("adopted_from", info.origin);
+    metadata.set("adopted", &super::usage::now_secs().to_string());
+    metadata.save(prefix_path)?;
+    discovery::remember_root(prefix_path)
+}
@@ -0,0 +1,397 @@
+//! Trusted root certificates in a prefix.
+//!
+//! Windows programs verify TLS connections against the crypt32 certificate
+//! stores, which Wine keeps in the registry. Proton games run inside the
+//! Steam Runtime container, so certificates added to the host (a corporate
+//! proxy's CA, for instance) never reach the prefix and launchers fail with
+//! certificate errors. Wine ships no `certutil`, so certificates are written
+//! to the machine's Root store directly:
+//!
+//! ```text
+//! [HKEY_LOCAL_MACHINE\Software\Microsoft\SystemCertificates\Root\Certificates\<SHA-1>]
+//! "Blob"=hex:20,00,00,00,01,00,00,00,<length>,<DER certificate>
+//! ```
+//!
+//! The key name must be the certificate's SHA-1 thumbprint; crypt32 ignores
+//! entries where it doesn't match.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::registry::{self, RegistryEditor};
+use super::WineContext;
+
+/// Registry key of the machine's trusted root certificates.
+pub const ROOT_STORE_KEY: &str =
+    "HKEY_LOCAL_MACHINE\\Software\\Microsoft\\SystemCertificates\\Root\\Certificates";
+
+/// CA bundles of the common distributions, in lookup order after
+/// `SSL_CERT_FILE`.
+pub const HOST_CA_BUNDLES: &[&str] = &[
+    "/etc/ssl/certs/ca-certificates.crt",
+    "/etc/pki/tls/certs/ca-bundle.crt",
+    "/etc/ssl/ca-bundle.pem",
+    "/etc/ssl/cert.pem",
+];
+
+/// `CERT_CERT_PROP_ID`, the property holding the encoded certificate in a
+/// serialized store element.
+const CERT_CERT_PROP_ID: u32 = 32;
+
+/// An X.509 certificate in DER encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Certificate {
+    pub der: Vec<u8>,
+}
+
+impl Certificate {
+    /// SHA-1 thumbprint in upper-case hex, as shown by Windows.
+    pub fn thumbprint(&self) -> String {
+        sha1(&self.der)
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect()
+    }
+
+    /// Common name of the subject, falling back to the organization. Empty
+    /// if the certificate can't be decoded.
+    pub fn subject(&self) -> String {
+        subject_name(&self.der).unwrap_or_default()
+    }
+}
+
+/// CA bundle of the host: `SSL_CERT_FILE` if set, otherwise the first of
+/// `HOST_CA_BUNDLES` that exists.
+pub fn host_ca_bundle() -> Option<PathBuf> {
+    std::env::var_os("SSL_CERT_FILE")
+        .map(PathBuf::from)
+        .filter(|p| p.is_file())
+        .or_else(|| {
+            HOST_CA_BUNDLES
+                .iter()
+                .map(PathBuf::from)
+                .find(|p| p.is_file())
+        })
+}
+
+/// Parse certificates from PEM text (any number of `CERTIFICATE` blocks) or
+/// a single DER-encoded certificate.
+pub fn parse_certificates(data: &[u8]) -> Result<Vec<Certificate>, String> {
+    let text = String::from_utf8_lossy(data);
+    if !text.contains("-----BEGIN") {
+        if data.first() == Some(&0x30) && der_element(data, 0).is_some() {
+            return Ok(vec![Certificate { der: data.to_vec() }]);
+        }
+        return Err("Not a PEM or DER certificate".to_string());
+    }
+
+    let mut certs = Vec::new();
+    let mut rest = text.as_ref();
+    while let Some(start) = rest.find("-----BEGIN CERTIFICATE-----") {
+        rest = &rest[start + "-----BEGIN CERTIFICATE-----".len()..];
+        let end = rest
+            .find("-----END CERTIFICATE-----")
+            .ok_or("Unterminated PEM certificate")?;
+        let der = base64_decode(&rest[..end]).ok_or("Invalid base64 in PEM certificate")?;
+        certs.push(Certificate { der });
+        rest = &rest[end..];
+    }
+    if certs.is_empty() {
+        return Err("No certificates found".to_string());
+    }
+    Ok(certs)
+}
+
+/// Read certificates from a PEM or DER file.
+pub fn read_certificates(path: &Path) -> Result<Vec<Certificate>, String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    parse_certificates(&data).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Certificates in the prefix's Root store, read from the registry files
+/// without running Wine, sorted by subject.
+pub fn installed(prefix_path: &Path) -> Vec<Certificate> {
+    let mut certs: Vec<Certificate> = registry::read_prefix_subkeys(prefix_path, ROOT_STORE_KEY)
+        .iter()
+        .filter_map(|thumbprint| {
+            let key = format!("{}\\{}", ROOT_STORE_KEY, thumbprint);
+            let blob = registry::read_prefix_value(prefix_path, &key, "Blob")?;
+            certificate_from_blob(&parse_hex_data(&blob)?)
+        })
+        .collect();
+    certs.sort_by_key(|c| c.subject().to_lowercase());
+    certs
+}
+
+/// Add certificates to the prefix's Root store, skipping ones already
+/// there. Returns how many were added.
+pub fn import(wine_ctx: &WineContext, certs: &[Certificate]) -> Result<usize, String> {
+    let mut present: Vec<String> =
+        registry::read_prefix_subkeys(&wine_ctx.prefix_path, ROOT_STORE_KEY)
+            .iter()
+            .map(|p| p.to_ascii_uppercase())
+            .collect();
+    let mut new: Vec<&Certificate> = Vec::new();
+    for cert in certs {
+        let thumbprint = cert.thumbprint();
+        if !present.contains(&thumbprint) {
+            present.push(thumbprint);
+            new.push(cert);
+        }
+    }
+    if new.is_empty() {
+        return Ok(0);
+    }
+
+    let mut content = String::from("Windows Registry Editor Version 5.00\n");
+    for cert in &new {
+        content.push_str(&format!(
+            "\n[{}\\{}]\n\"Blob\"=hex:{}\n",
+            ROOT_STORE_KEY,
+            cert.thumbprint(),
+            hex_list(&store_blob(&cert.der))
+        ));
+    }
+    let work_dir = crate::workdir::WorkDir::create("certs")?;
+    let reg_file = work_dir.file("root_certs", "reg");
+    fs::write(&reg_file, content)
+        .map_err(|e| format!("Failed to write {}: {}", reg_file.display(), e))?;
+    RegistryEditor::new(wine_ctx).apply_reg_file(&reg_file)?;
+    Ok(new.len())
+}
+
+/// Remove a certificate from the prefix's Root store by thumbprint.
+pub fn remove(wine_ctx: &WineContext, thumbprint: &str) -> Result<(), String> {
+    let present = registry::read_prefix_subkeys(&wine_ctx.prefix_path, ROOT_STORE_KEY);
+    let key = present
+        .iter()
+        .find(|p| p.eq_ignore_ascii_case(thumbprint))
+        .ok_or_else(|| {
+            format!(
+                "No certificate with thumbprint {} in the prefix",
+                thumbprint
+            )
+        })?;
+    RegistryEditor::new(wine_ctx).delete_key(&format!("{}\\{}", ROOT_STORE_KEY, key))
+}
+
+/// Serialized store element holding just the certificate property.
+fn store_blob(der: &[u8]) -> Vec<u8> {
+    let mut blob = Vec::with_capacity(der.len() + 12);
+    blob.extend_from_slice(&CERT_CERT_PROP_ID.to_le_bytes());
+    blob.extend_from_slice(&1u32.to_le_bytes());
+    blob.extend_from_slice(&(der.len() as u32).to_le_bytes());
+    blob.extend_from_slice(der);
+    blob
+}
+
+/// Find the certificate property in a serialized store element.
+fn certificate_from_blob(blob: &[u8]) -> Option<Certificate> {
+    let mut pos = 0;
+    while pos + 12 <= blob.len() {
+        let field =
+            |i: usize| u32::from_le_bytes(blob[pos + i * 4..pos + i * 4 + 4].try_into().unwrap());
+        let (prop_id, len) = (field(0), field(2) as usize);
+        let data = blob.get(pos + 12..pos + 12 + len)?;
+        if prop_id == CERT_CERT_PROP_ID {
+            return Some(Certificate { der: data.to_vec() });
+        }
+        pos += 12 + len;
+    }
+    None
+}
+
+/// Decode `hex:xx,xx,...` registry data.
+fn parse_hex_data(data: &str) -> Option<Vec<u8>> {
+    data.strip_prefix("hex:")?
+        .split(',')
+        .map(|b| u8::from_str_radix(b.trim(), 16).ok())
+        .collect()
+}
+
+fn hex_list(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Decode standard base64, ignoring whitespace.
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in text.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+/// A DER element at `pos`: (tag, content start, content end).
+fn der_element(data: &[u8], pos: usize) -> Option<(u8, usize, usize)> {
+    let tag = *data.get(pos)?;
+    let first = *data.get(pos + 1)? as usize;
+    let (len, start) = if first < 0x80 {
+        (first, pos + 2)
+    } else {
+        let count = first & 0x7f;
+        if count == 0 || count > 4 {
+            return None;
+        }
+        let bytes = data.get(pos + 2..pos + 2 + count)?;
+        let len = bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        (len, pos + 2 + count)
+    };
+    let end = start.checked_add(len)?;
+    (end <= data.len()).then_some((tag, start, end))
+}
+
+/// Subject common name (or organization) of a DER certificate.
+fn subject_name(der: &[u8]) -> Option<String> {
+    // Certificate ::= SEQUENCE { tbsCertificate SEQUENCE { [0] version
+    // (optional), serialNumber, signature, issuer, validity, subject, ... } }
+    let (_, cert_start, _) = der_element(der, 0)?;
+    let (_, tbs_start, tbs_end) = der_element(der, cert_start)?;
+    let mut pos = tbs_start;
+    let mut index = 0;
+    while pos < tbs_end {
+        let (tag, start, end) = der_element(der, pos)?;
+        if tag != 0xa0 {
+            if index == 4 {
+                let subject = &der[start..end];
+                return attribute(subject, 0x03).or_else(|| attribute(subject, 0x0a));
+            }
+            index += 1;
+        }
+        pos = end;
+    }
+    None
+}
+
+/// Value of the X.520 attribute `2.5.4.<id>` in an encoded name.
+fn attribute(name: &[u8], id: u8) -> Option<String> {
+    let oid = [0x06, 0x03, 0x55, 0x04, id];
+    let at = name.windows(oid.len()).position(|w| w == oid)?;
+    let (_, start, end) = der_element(name, at + oid.len())?;
+    Some(String::from_utf8_lossy(&name[start..end]).into_owned())
+}
+
+/// SHA-1 digest, used only for certificate thumbprints.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, &word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (state, value) in h.iter_mut().zip([a, b, c, d, e]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+    for (i, word) in h.iter().enumerate() {
+        digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_certificate_and_store_blob() {
+        assert_eq!(
+            Certificate {
+                der: b"abc".to_vec()
+            }
+            .thumbprint(),
+            "A9993E364706816ABA3E25717850C26C9CD0D89D"
+        );
+
+        let tlv = |tag: u8, content: &[u8]| {
+            let mut out = vec![tag, content.len() as u8];
+            out.extend_from_slice(content);
+            out
+        };
+        let cn = [&[0x06, 0x03, 0x55, 0x04, 0x03][..], &tlv(0x0c, b"Proxy CA")].concat();
+        let subject = tlv(0x30, &tlv(0x31, &tlv(0x30, &cn)));
+        let tbs = [
+            tlv(0xa0, &tlv(0x02, &[2])),
+            tlv(0x02, &[1]),
+            tlv(0x30, &[]),
+            tlv(0x30, &[]),
+            tlv(0x30, &[]),
+            subject,
+        ]
+        .concat();
+        let der = tlv(0x30, &tlv(0x30, &tbs));
+        let cert = Certificate { der: der.clone() };
+        assert_eq!(cert.subject(), "Proxy CA");
+
+        // base64 of the DER above, wrapped like a PEM bundle
+        let encoded = "MCUwI6ADAgECAgEBMAAwADAAMBMxETAPBgNVBAMMCFByb3h5IENB";
+        let pem = format!(
+            "# Proxy\n-----BEGIN CERTIFICATE-----\n{}\n-----END CERTIFICATE-----\n",
+            encoded
+        );
+        assert_eq!(
+            parse_certificates(pem.as_bytes()).unwrap(),
+            vec![cert.clone()]
+        );
+        assert_eq!(parse_certificates(&der).unwrap(), vec![cert.clone()]);
+        assert!(parse_certificates(b"hello").is_err());
+
+        let data = format!("hex:{}", hex_list(&store_blob(&der)));
+        assert_eq!(
+            parse_hex_data(&data).and_then(|b| certificate_from_blob(&b)),
+            Some(cert)
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Cleanup of temporary files and crash artifacts inside a prefix.
+//!
+//! Installers and games leave temp files, crash dumps and extracted setup
+//! payloads behind; prefixes commonly grow by gigabytes this way. Cleanup
+//! empties the Windows temp folders and removes crash dumps older than a
+//! week and InstallShield leftovers, reporting the space freed per category.
+//! Package caches needed to repair or uninstall software are left alone.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Crash dumps younger than this are kept for troubleshooting.
+const CRASH_DUMP_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// File extensions of crash dumps.
+const CRASH_DUMP_EXTENSIONS: &[&str] = &["dmp", "mdmp"];
+
+/// Space freed (or freeable) in one cleanup category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanupCategory {
+    pub name: &'static str,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Result of cleaning up a prefix.
+#[derive(Debug, Clone, Default)]
+pub struct CleanupReport {
+    pub categories: Vec<CleanupCategory>,
+}
+
+impl CleanupReport {
+    pub fn total_bytes(&self) -> u64 {
+        self.categories.iter().map(|c| c.bytes).sum()
+    }
+
+    pub fn total_files(&self) -> usize {
+        self.categories.iter().map(|c| c.files).sum()
+    }
+}
+
+/// User profile directories of a prefix (`drive_c/users/*`).
+fn user_profiles(prefix_path: &Path) -> Vec<PathBuf> {
+    fs::read_dir(prefix_path.join("drive_c/users"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Temp directories of a prefix (Windows and per-user, old and new layout).
+fn temp_dirs(prefix_path: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![prefix_path.join("drive_c/windows/temp")];
+    for profile in user_profiles(prefix_path) {
+        dirs.push(profile.join("Temp"));
+        dirs.push(profile.join("AppData/Local/Temp"));
+    }
+    dirs
+}
+
+/// Directories searched for crash dumps.
+fn crash_dump_dirs(prefix_path: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![prefix_path.join("drive_c/windows/Minidump")];
+    for profile in user_profiles(prefix_path) {
+        dirs.push(profile.join("AppData/Local/CrashDumps"));
+    }
+    dirs
+}
+
+/// Directories left behind by installers.
+fn installer_leftover_dirs(prefix_path: &Path) -> Vec<PathBuf> {
+    vec![prefix_path.join("drive_c/windows/Downloaded Installations")]
+}
+
+/// Collect files below a directory (symlinks are not followed).
+fn collect_files(dir: &Path, files: &mut Vec<(PathBuf, fs::Metadata)>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let metadata = match fs::symlink_metadata(entry.path()) {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+        if metadata.is_dir() {
+            collect_files(&entry.path(), files);
+        } else {
+            files.push((entry.path(), metadata));
+        }
+    }
+}
+
+/// Remove the contents of a directory, keeping the directory itself.
+fn empty_dir(dir: &Path) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() && !path.is_symlink() {
+                fs::remove_dir_all(&path).ok();
+            } else {
+                fs::remove_file(&path).ok();
+            }
+        }
+    }
+}
+
+/// Measure and (unless `dry_run`) remove everything in the given directories.
+fn clean_dirs(name: &'static str, dirs: &[PathBuf], dry_run: bool) -> CleanupCategory {
+    let mut category = CleanupCategory {
+        name,
+        files: 0,
+        bytes: 0,
+    };
+    for dir in dirs {
+        // Never follow a linked temp folder out of the prefix
+        if dir.is_symlink() {
+            continue;
+        }
+        let mut files = Vec::new();
+        collect_files(dir, &mut files);
+        category.files += files.len();
+        category.bytes += files.iter().map(|(_, m)| m.len()).sum::<u64>();
+        if !dry_run {
+            empty_dir(dir);
+        }
+    }
+    category
+}
+
+/// Measure and (unless `dry_run`) remove crash dumps older than a week.
+fn clean_crash_dumps(prefix_path: &Path, dry_run: bool) -> CleanupCategory {
+    let mut category = CleanupCategory {
+        name: "Crash dumps",
+        files: 0,
+        bytes: 0,
+    };
+    let now = SystemTime::now();
+    for dir in crash_dump_dirs(prefix_path) {
+        let mut files = Vec::new();
+        collect_files(&dir, &mut files);
+        for (path, metadata) in files {
+            let is_dump = path
+                .extension()
+                .map(|e| e.to_string_lossy().to_lowercase())
+                .is_some_and(|e| CRASH_DUMP_EXTENSIONS.contains(&e.as_str()));
+            let age = metadata
+                .modified()
+                .ok()
+                .and_then(|m| now.duration_since(m).ok())
+                .unwrap_or_default();
+            if !is_dump || age < CRASH_DUMP_MAX_AGE {
+                continue;
+            }
+            category.files += 1;
+            category.bytes += metadata.len();
+            if !dry_run {
+                fs::remove_file(&path).ok();
+            }
+        }
+    }
+    category
+}
+
+/// Clean up a prefix. With `dry_run`, only report what would be removed.
+pub fn cleanup_prefix(prefix_path: &Path, dry_run: bool) -> CleanupReport {
+    CleanupReport {
+        categories: vec![
+            clean_dirs("Temp files", &temp_dirs(prefix_path), dry_run),
+            clean_crash_dumps(prefix_path, dry_run),
+            clean_dirs(
+                "Installer leftovers",
+                &installer_leftover_dirs(prefix_path),
+                dry_run,
+            ),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cleanup_prefix_empties_temp_dirs() {
+        let prefix =
+            std::env::temp_dir().join(format!("protontool-cleanup-{}", std::process::id()));
+        let temp = prefix.join("drive_c/users/steamuser/AppData/Local/Temp");
+        fs::create_dir_all(temp.join("setup")).unwrap();
+        fs::write(temp.join("setup/data.cab"), [0u8; 100]).unwrap();
+        fs::write(temp.join("log.txt"), [0u8; 20]).unwrap();
+        let dumps = prefix.join("drive_c/users/steamuser/AppData/Local/CrashDumps");
+        fs::create_dir_all(&dumps).unwrap();
+        fs::write(dumps.join("game.exe.1234.dmp"), [0u8; 50]).unwrap();
+
+        let report = cleanup_prefix(&prefix, true);
+        assert_eq!(report.total_files(), 2);
+        assert_eq!(report.total_bytes(), 120);
+        assert!(temp.join("log.txt").exists());
+
+        cleanup_prefix(&prefix, false);
+        assert!(temp.is_dir());
+        assert_eq!(fs::read_dir(&temp).unwrap().count(), 0);
+        // Recent crash dumps are kept
+        assert!(dumps.join("game.exe.1234.dmp").exists());
+
+        fs::remove_dir_all(&prefix).ok();
+    }
+}
@@ -0,0 +1,200 @@
+//! Side-by-side comparison of two prefixes.
+//!
+//! `protontool --prefix X compare --with Y` reads the settings that most
+//! often explain why a game works in one prefix and not another (Windows
+//! version, DLL overrides, applied verbs, DXVK and VKD3D-Proton versions
+//! and the environment profile) from both prefixes without running Wine,
+//! and lists what differs.
+
+use std::path::Path;
+
+use super::ledger;
+use super::overrides;
+use super::profile::EnvProfile;
+use super::registry::read_prefix_value;
+use super::vkd3d;
+
+const WINE_KEY: &str = "HKEY_CURRENT_USER\\Software\\Wine";
+const CURRENT_VERSION_KEY: &str =
+    "HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows NT\\CurrentVersion";
+
+/// The compared settings of one prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixSummary {
+    pub windows_version: Option<String>,
+    /// DLL name and override mode.
+    pub dll_overrides: Vec<(String, String)>,
+    pub verbs: Vec<String>,
+    pub dxvk: Option<String>,
+    pub vkd3d: Option<String>,
+    pub env: Vec<(String, String)>,
+}
+
+impl PrefixSummary {
+    /// Read the settings of a prefix.
+    pub fn read(prefix_path: &Path) -> Self {
+        let string = |data: String| data.trim_matches('"').to_string();
+        let windows_version = match read_prefix_value(prefix_path, WINE_KEY, "Version") {
+            Some(version) => Some(format!("{} (Wine setting)", string(version))),
+            None => {
+                read_prefix_value(prefix_path, CURRENT_VERSION_KEY, "ProductName").map(|product| {
+                    match read_prefix_value(prefix_path, CURRENT_VERSION_KEY, "CurrentBuild") {
+                        Some(build) => format!("{} (build {})", string(product), string(build)),
+                        None => string(product),
+                    }
+                })
+            }
+        };
+        PrefixSummary {
+            windows_version,
+            dll_overrides: overrides::read(prefix_path),
+            verbs: ledger::applied_verbs(prefix_path),
+            dxvk: crate::status::installed_dxvk_version(prefix_path),
+            vkd3d: vkd3d::detect_version(prefix_path)
+                .map(|(major, minor)| format!("{}.{}", major, minor)),
+            env: EnvProfile::load(prefix_path).vars,
+        }
+    }
+}
+
+/// A setting that differs between the prefixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    pub section: &'static str,
+    /// Entry within the section (a DLL, verb or variable), if any.
+    pub item: Option<String>,
+    /// Value in the compared prefix; None when unset.
+    pub this: Option<String>,
+    /// Value in the baseline prefix; None when unset.
+    pub other: Option<String>,
+}
+
+/// Differences between a prefix and a baseline, grouped by section.
+pub fn compare(this: &PrefixSummary, other: &PrefixSummary) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    let mut scalar = |section, this: &Option<String>, other: &Option<String>| {
+        if this != other {
+            differences.push(Difference {
+                section,
+                item: None,
+                this: this.clone(),
+                other: other.clone(),
+            });
+        }
+    };
+    scalar(
+        "Windows version",
+        &this.windows_version,
+        &other.windows_version,
+    );
+    scalar("DXVK", &this.dxvk, &other.dxvk);
+    scalar("VKD3D-Proton", &this.vkd3d, &other.vkd3d);
+
+    let applied = |verbs: &[String]| -> Vec<(String, String)> {
+        verbs
+            .iter()
+            .map(|verb| (verb.clone(), "applied".to_string()))
+            .collect()
+    };
+    compare_entries(
+        &mut differences,
+        "DLL overrides",
+        &this.dll_overrides,
+        &other.dll_overrides,
+        true,
+    );
+    compare_entries(
+        &mut differences,
+        "Verbs",
+        &applied(&this.verbs),
+        &applied(&other.verbs),
+        false,
+    );
+    compare_entries(
+        &mut differences,
+        "Environment",
+        &this.env,
+        &other.env,
+        false,
+    );
+    differences
+}
+
+/// Add the entries of a section that are missing from one side or differ.
+fn compare_entries(
+    differences: &mut Vec<Difference>,
+    section: &'static str,
+    this: &[(String, String)],
+    other: &[(String, String)],
+    ignore_case: bool,
+) {
+    let same_name = |a: &str, b: &str| {
+        if ignore_case {
+            a.eq_ignore_ascii_case(b)
+        } else {
+            a == b
+        }
+    };
+    let lookup = |entries: &[(String, String)], name: &str| {
+        entries
+            .iter()
+            .find(|(n, _)| same_name(n, name))
+            .map(|(_, v)| v.clone())
+    };
+    let mut names: Vec<&String> = this.iter().map(|(n, _)| n).collect();
+    for (name, _) in other {
+        if !names.iter().any(|n| same_name(n, name)) {
+            names.push(name);
+        }
+    }
+    names.sort_by_key(|n| n.to_lowercase());
+    for name in names {
+        let (this_value, other_value) = (lookup(this, name), lookup(other, name));
+        if this_value != other_value {
+            differences.push(Difference {
+                section,
+                item: Some(name.clone()),
+                this: this_value,
+                other: other_value,
+            });
+        }
+    }
+}
+
+/// Readable report of the differences, `this | other` per line.
+///
+/// ```
+/// use protontool::wine::compare::{compare, format_differences, PrefixSummary};
+/// let this = PrefixSummary {
+///     dxvk: Some("2.3".to_string()),
+///     verbs: vec!["vcrun2022".to_string()],
+///     ..Default::default()
+/// };
+/// let other = PrefixSummary {
+///     dxvk: Some("2.4".to_string()),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     format_differences(&compare(&this, &other)),
+///     "DXVK: 2.3 | 2.4\nVerbs:\n  vcrun2022: applied | (unset)"
+/// );
+/// ```
+pub fn format_differences(differences: &[Difference]) -> String {
+    let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "(unset)".to_string());
+    let mut lines: Vec<String> = Vec::new();
+    let mut section = "";
+    for difference in differences {
+        let values = format!("{} | {}", show(&difference.this), show(&difference.other));
+        match &difference.item {
+            None => lines.push(format!("{}: {}", difference.section, values)),
+            Some(item) => {
+                if difference.section != section {
+                    lines.push(format!("{}:", difference.section));
+                }
+                lines.push(format!("  {}: {}", item, values));
+            }
+        }
+        section = difference.section;
+    }
+    lines.join("\n")
+}
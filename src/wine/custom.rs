@@ -1,7 +1,8 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use super::verbs::{LocalFile, Verb, VerbAction, VerbCategory};
+use super::uninstall::InstallMarker;
+use super::verbs::{LocalFile, RetryPolicy, RetryStrategy, Verb, VerbAction, VerbCategory};
 
 /// Loads custom verbs from the user's config directory.
 ///
@@ -24,38 +25,100 @@ use super::verbs::{LocalFile, Verb, VerbAction, VerbCategory};
 /// publisher = "Trimble"
 /// year = "2024"
 ///
+/// # Optional: license info; an eula URL must be accepted before installing
+/// homepage = "https://www.sketchup.com/"
+/// license = "Trimble EULA"
+/// eula = "https://www.sketchup.com/license"
+///
+/// # Optional: retry strategies tried in order when the verb fails,
+/// # limited to failures matching these KNOWN_ERRORS codes
+/// retry = ["fresh_tmp", "winver=win7", "force"]
+/// retry_on = ["WINE-SEH-AV"]
+///
+/// # Optional: installer exit codes meaning success besides 0, 1641 and 3010
+/// success_codes = [1638]
+///
+/// # Optional: evidence of a successful install, checked after the verb
+/// # runs ("uninstall:NAME" or "file:C:\\Program Files\\...")
+/// installed = "uninstall:SketchUp 2024"
+///
 /// [[actions]]
 /// type = "local_installer"
 /// path = "~/Downloads/SketchUpPro-2024.exe"
 /// args = ["/S"]
+///
+/// # Registry content may use {{prefix}}, {{arch}}, {{username}} and
+/// # {{resolution}}, expanded when the verb runs
+/// [[actions]]
+/// type = "registry"
+/// content = "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\Explorer\\Desktops]\n\"Default\"=\"{{resolution}}\"\n"
 /// ```
 pub fn get_custom_verbs_dir() -> PathBuf {
     crate::config::get_verbs_dir()
 }
 
+/// Namespace of custom verbs placed directly in the verb directory.
+pub const USER_NAMESPACE: &str = "user";
+
 /// Load all custom verbs from the user's verb directory.
-/// Supports both shell scripts (.sh) and TOML definitions (.toml).
+///
+/// Verbs directly in the directory are in the `user` namespace; verb packs
+/// are subdirectories, and their verbs are in a namespace named after the
+/// subdirectory (`verbs/community/dgvoodoo2.toml` is `community/dgvoodoo2`).
 pub fn load_custom_verbs() -> Vec<Verb> {
     let verbs_dir = get_custom_verbs_dir();
     if !verbs_dir.exists() {
         return Vec::new();
     }
 
+    let mut verbs = load_verbs_in(&verbs_dir, USER_NAMESPACE);
+
+    let mut packs: Vec<PathBuf> = fs::read_dir(&verbs_dir)
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    packs.retain(|path| path.is_dir());
+    packs.sort();
+    for pack in packs {
+        let namespace = pack
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if namespace.starts_with('.') {
+            continue;
+        }
+        if namespace == super::verbs::BUILTIN_NAMESPACE {
+            crate::log::warn(&format!(
+                "Skipping verb pack {}: the '{}' namespace is reserved",
+                pack.display(),
+                namespace
+            ));
+            continue;
+        }
+        verbs.extend(load_verbs_in(&pack, &namespace));
+    }
+
+    verbs
+}
+
+/// Load the script and TOML verbs in a directory into a namespace.
+fn load_verbs_in(dir: &Path, namespace: &str) -> Vec<Verb> {
     let mut verbs = Vec::new();
 
-    if let Ok(entries) = fs::read_dir(&verbs_dir) {
+    if let Ok(entries) = fs::read_dir(dir) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if let Some(ext) = path.extension() {
-                if ext == "sh" {
-                    if let Some(verb) = load_script_verb(&path) {
-                        verbs.push(verb);
-                    }
-                } else if ext == "toml" {
-                    if let Some(verb) = load_toml_verb(&path) {
-                        verbs.push(verb);
-                    }
-                }
+            let verb = match path.extension() {
+                Some(ext) if ext == "sh" => load_script_verb(&path),
+                Some(ext) if ext == "toml" => load_toml_verb(&path),
+                _ => None,
+            };
+            match verb {
+                Some(verb) if verb.name.contains('/') => crate::log::warn(&format!(
+                    "Skipping {}: verb names can't contain '/'",
+                    path.display()
+                )),
+                Some(verb) => verbs.push(verb.in_namespace(namespace)),
+                None => {}
             }
         }
     }
@@ -119,13 +182,19 @@ fn load_toml_verb(toml_path: &Path) -> Option<Verb> {
 /// Parse a TOML verb definition.
 ///
 /// Simple parser that doesn't require external dependencies.
-fn parse_toml_verb(content: &str) -> Option<Verb> {
+pub(crate) fn parse_toml_verb(content: &str) -> Option<Verb> {
     let mut name = String::new();
     let mut category = VerbCategory::App;
     let mut title = String::new();
     let mut publisher = String::new();
     let mut year = String::new();
     let mut actions: Vec<VerbAction> = Vec::new();
+    let mut retry = RetryPolicy::default();
+    let mut homepage = String::new();
+    let mut license = String::new();
+    let mut eula: Option<String> = None;
+    let mut success_codes: Vec<i32> = Vec::new();
+    let mut installed: Option<InstallMarker> = None;
 
     let mut in_verb_section = false;
     let mut in_action_section = false;
@@ -173,6 +242,23 @@ fn parse_toml_verb(content: &str) -> Option<Verb> {
                     "title" => title = value,
                     "publisher" => publisher = value,
                     "year" => year = value,
+                    "retry" => {
+                        retry.strategies = parse_string_array(&value)
+                            .iter()
+                            .filter_map(|s| RetryStrategy::parse(s))
+                            .collect()
+                    }
+                    "retry_on" => retry.on_errors = parse_string_array(&value),
+                    "homepage" => homepage = value,
+                    "license" => license = value,
+                    "eula" => eula = Some(value),
+                    "success_codes" => {
+                        success_codes = parse_string_array(&value)
+                            .iter()
+                            .filter_map(|s| s.parse().ok())
+                            .collect()
+                    }
+                    "installed" => installed = InstallMarker::parse(&value),
                     _ => {}
                 }
             } else if in_action_section {
@@ -183,6 +269,8 @@ fn parse_toml_verb(content: &str) -> Option<Verb> {
                     "dll" => current_action_path = value, // reuse path for dll name
                     "mode" => current_action_args = vec![value], // reuse args for mode
                     "content" => current_action_path = value, // reuse for registry content
+                    "verb" => current_action_path = value, // reuse path for verb name
+                    "plugin" => current_action_path = value, // reuse path for plugin name
                     _ => {}
                 }
             }
@@ -208,22 +296,75 @@ fn parse_toml_verb(content: &str) -> Option<Verb> {
         title = name.clone();
     }
 
-    Some(Verb::new(&name, category, &title, &publisher, &year).with_actions(actions))
+    let mut verb = Verb::new(&name, category, &title, &publisher, &year)
+        .with_homepage(&homepage)
+        .with_license(&license, eula.as_deref())
+        .with_success_codes(&success_codes)
+        .with_actions(actions);
+    if !retry.strategies.is_empty() {
+        verb = verb.with_retry(retry);
+    }
+    if let Some(marker) = installed {
+        verb = verb.with_install_marker(marker);
+    }
+    Some(verb)
 }
 
 /// Parse a single TOML key-value line like `key = "value"`.
-/// Returns the key and value with quotes stripped.
-fn parse_toml_line(line: &str) -> Option<(String, String)> {
+/// Returns the key and value with quotes stripped and basic string
+/// escapes (`\n`, `\t`, `\"`, `\\`) resolved.
+pub(crate) fn parse_toml_line(line: &str) -> Option<(String, String)> {
     let mut parts = line.splitn(2, '=');
     let key = parts.next()?.trim().to_string();
     let value = parts.next()?.trim();
 
-    // Remove quotes from string values
-    let value = value.trim_matches('"').to_string();
+    // Arrays are split later by parse_string_array
+    if value.starts_with('[') {
+        return Some((key, value.to_string()));
+    }
+
+    let value = if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        unescape_toml_string(&value[1..value.len() - 1])
+    } else {
+        value.trim_matches('"').to_string()
+    };
 
     Some((key, value))
 }
 
+/// Resolve escape sequences inside a TOML basic string.
+fn unescape_toml_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Escape a string for use as a TOML basic string value (without quotes).
+pub fn escape_toml_string(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
 /// Convert a category string to VerbCategory enum.
 /// Accepts various forms like "app", "apps", "dll", "dlls", etc.
 fn parse_category(s: &str) -> VerbCategory {
@@ -232,13 +373,14 @@ fn parse_category(s: &str) -> VerbCategory {
         "dll" | "dlls" => VerbCategory::Dll,
         "font" | "fonts" => VerbCategory::Font,
         "setting" | "settings" => VerbCategory::Setting,
+        "gamefix" | "gamefixes" => VerbCategory::GameFix,
         "custom" => VerbCategory::Custom,
         _ => VerbCategory::Custom, // Default to Custom for user-defined verbs
     }
 }
 
 /// Expand tilde (~) in paths to the user's home directory.
-fn expand_path(path: &str) -> String {
+pub(crate) fn expand_path(path: &str) -> String {
     if path.starts_with("~/") {
         if let Ok(home) = std::env::var("HOME") {
             return path.replacen("~", &home, 1);
@@ -248,7 +390,7 @@ fn expand_path(path: &str) -> String {
 }
 
 /// Parse a simple TOML string array like `["arg1", "arg2"]`.
-fn parse_string_array(s: &str) -> Vec<String> {
+pub(crate) fn parse_string_array(s: &str) -> Vec<String> {
     // Simple array parser: ["arg1", "arg2"]
     let s = s.trim();
     if !s.starts_with('[') || !s.ends_with(']') {
@@ -264,7 +406,7 @@ fn parse_string_array(s: &str) -> Vec<String> {
 }
 
 /// Create a VerbAction from parsed TOML action fields.
-/// Supports: local_installer, script, override, registry, winecfg.
+/// Supports: local_installer, script, override, registry, winecfg, verb, plugin.
 fn create_action(action_type: &str, path: &str, args: &[String]) -> Option<VerbAction> {
     match action_type {
         "local_installer" => {
@@ -297,6 +439,13 @@ fn create_action(action_type: &str, path: &str, args: &[String]) -> Option<VerbA
         "winecfg" => Some(VerbAction::Winecfg {
             args: args.to_vec(),
         }),
+        "verb" => Some(VerbAction::CallVerb {
+            name: path.to_string(),
+        }),
+        "plugin" => Some(VerbAction::Plugin {
+            name: path.to_string(),
+            args: args.to_vec(),
+        }),
         _ => None,
     }
 }
@@ -327,6 +476,44 @@ args = ["/S"]
         assert_eq!(verb.actions.len(), 1);
     }
 
+    #[test]
+    fn test_parse_toml_verb_retry() {
+        let toml = r#"
+[verb]
+name = "flaky"
+category = "app"
+retry = ["fresh_tmp", "winver=win7", "force", "bogus"]
+retry_on = ["WINE-SEH-AV"]
+"#;
+        let retry = parse_toml_verb(toml).unwrap().retry.unwrap();
+        assert_eq!(
+            retry.strategies,
+            vec![
+                RetryStrategy::FreshTempDir,
+                RetryStrategy::Winver("win7".to_string()),
+                RetryStrategy::Force,
+            ]
+        );
+        assert_eq!(retry.on_errors, vec!["WINE-SEH-AV".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_toml_verb_success_codes() {
+        let toml = r#"
+[verb]
+name = "redist"
+category = "dll"
+success_codes = [1638, 5100, "bogus"]
+installed = "uninstall:Redist 2024"
+"#;
+        let verb = parse_toml_verb(toml).unwrap();
+        assert_eq!(verb.success_codes, vec![1638, 5100]);
+        assert_eq!(
+            verb.installed,
+            Some(InstallMarker::Uninstall("Redist 2024".to_string()))
+        );
+    }
+
     #[test]
     fn test_parse_script_metadata() {
         let script = r#"#!/bin/bash
@@ -0,0 +1,130 @@
+//! Verbs deferred until a prefix is idle.
+//!
+//! Verbs protontool applies on its own (the `[provision]` baseline for
+//! Steam game prefixes) replace DLLs and registry keys, which must not
+//! happen under a running game. While a wineserver serves the prefix, such
+//! verbs are queued in the `deferred-verbs` state record instead, one
+//! `prefix<TAB>verb` line each. `protontool APPID deferred run` waits for
+//! the prefix to go idle and applies them; a watcher (a systemd user timer,
+//! a launch wrapper) can call it after the game exits.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::verbs::VerbRegistry;
+use super::WineContext;
+
+/// State record holding the queue.
+const QUEUE_STATE: &str = "deferred-verbs";
+
+/// How often a busy prefix is checked again.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Whether no wineserver serves the prefix, so no program runs in it.
+pub fn is_idle(prefix_path: &Path) -> bool {
+    super::preflight::running_servers(prefix_path).is_empty()
+}
+
+/// Wait until the prefix is idle. Returns false if it still isn't after
+/// `timeout`.
+pub fn wait_until_idle(prefix_path: &Path, timeout: Option<Duration>) -> bool {
+    let started = Instant::now();
+    while !is_idle(prefix_path) {
+        if timeout.is_some_and(|timeout| started.elapsed() >= timeout) {
+            return false;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+    true
+}
+
+/// Queue lines with `verbs` added for a prefix, skipping verbs already
+/// queued for it.
+///
+/// ```
+/// use protontool::wine::deferred::with_queued;
+/// let lines = vec!["/pfx/a\tvcrun2022".to_string()];
+/// assert_eq!(
+///     with_queued(&lines, "/pfx/a", &["vcrun2022".into(), "dxvk".into()]),
+///     vec!["/pfx/a\tvcrun2022", "/pfx/a\tdxvk"]
+/// );
+/// ```
+pub fn with_queued(lines: &[String], prefix: &str, verbs: &[String]) -> Vec<String> {
+    let mut lines = lines.to_vec();
+    for verb in verbs {
+        let line = format!("{}\t{}", prefix, verb);
+        if !lines.contains(&line) {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+fn prefix_key(prefix_path: &Path) -> String {
+    prefix_path.to_string_lossy().into_owned()
+}
+
+fn save(lines: &[String]) -> Result<(), String> {
+    let mut content = lines.join("\n");
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    crate::state::write(QUEUE_STATE, &content)
+}
+
+/// Queue verbs for a prefix.
+pub fn queue(prefix_path: &Path, verbs: &[String]) -> Result<(), String> {
+    let lines = crate::state::read_lines(QUEUE_STATE);
+    save(&with_queued(&lines, &prefix_key(prefix_path), verbs))
+}
+
+/// Verbs queued for a prefix, oldest first.
+pub fn pending(prefix_path: &Path) -> Vec<String> {
+    let key = prefix_key(prefix_path);
+    crate::state::read_lines(QUEUE_STATE)
+        .iter()
+        .filter_map(|line| {
+            let (prefix, verb) = line.split_once('\t')?;
+            (prefix == key).then(|| verb.to_string())
+        })
+        .collect()
+}
+
+/// Remove verbs from a prefix's queue (all of them if `verbs` is None).
+pub fn remove(prefix_path: &Path, verbs: Option<&[String]>) -> Result<(), String> {
+    let key = prefix_key(prefix_path);
+    let lines: Vec<String> = crate::state::read_lines(QUEUE_STATE)
+        .into_iter()
+        .filter(|line| match line.split_once('\t') {
+            Some((prefix, verb)) if prefix == key => {
+                verbs.is_some_and(|verbs| !verbs.iter().any(|v| v == verb))
+            }
+            _ => true,
+        })
+        .collect();
+    save(&lines)
+}
+
+/// Apply the verbs queued for an idle prefix, removing those that
+/// succeeded. Returns the verbs that failed with their errors.
+pub fn run_pending(wine_ctx: &WineContext) -> Result<Vec<(String, String)>, String> {
+    let prefix_path = &wine_ctx.prefix_path;
+    if !is_idle(prefix_path) {
+        return Err(format!(
+            "{} is in use; the queued verbs run once it is idle",
+            prefix_path.display()
+        ));
+    }
+    let registry = VerbRegistry::new();
+    let cache_dir = crate::config::get_cache_dir().join("wine");
+    std::fs::create_dir_all(&cache_dir).ok();
+    let mut failed = Vec::new();
+    for verb in pending(prefix_path) {
+        eprintln!("Running deferred verb {}", verb);
+        match registry.execute(&verb, wine_ctx, &cache_dir) {
+            Ok(()) => remove(prefix_path, Some(std::slice::from_ref(&verb)))?,
+            Err(e) => failed.push((verb, e)),
+        }
+    }
+    Ok(failed)
+}
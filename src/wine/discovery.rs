@@ -0,0 +1,130 @@
+//! Custom prefix discovery.
+//!
+//! Prefixes are found in the default prefixes directory and in additional
+//! roots: those listed as `prefix_roots` in the `[paths]` section of
+//! `config.toml`, and those remembered after browsing to a prefix in the GUI
+//! (the `prefix-roots` state record). Roots are scanned a few levels deep
+//! for directories holding both `system.reg` and `drive_c`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::UserConfig;
+
+/// State record holding remembered prefix roots, one path per line.
+const ROOTS_RECORD: &str = "prefix-roots";
+
+/// How many directory levels below a root are searched for prefixes.
+pub const SCAN_DEPTH: usize = 3;
+
+/// Whether a directory looks like an initialized Wine prefix.
+pub fn is_prefix(path: &Path) -> bool {
+    path.join("system.reg").is_file() && path.join("drive_c").is_dir()
+}
+
+/// Prefixes at or below `root`, up to `depth` levels down. Prefixes aren't
+/// searched for nested ones, and hidden directories (like snapshot
+/// directories) and symlinks are skipped.
+pub fn find_prefixes(root: &Path, depth: usize) -> Vec<PathBuf> {
+    if is_prefix(root) {
+        return vec![root.to_path_buf()];
+    }
+    if depth == 0 {
+        return Vec::new();
+    }
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .filter(|e| !e.file_name().to_string_lossy().starts_with('.'))
+        .map(|e| e.path())
+        .collect();
+    dirs.sort();
+    dirs.iter()
+        .flat_map(|dir| find_prefixes(dir, depth - 1))
+        .collect()
+}
+
+/// Additional roots from `config.toml` and the remembered ones.
+pub fn extra_roots(config: &UserConfig) -> Vec<PathBuf> {
+    let configured = config
+        .get_array("paths", "prefix_roots")
+        .unwrap_or_default()
+        .into_iter()
+        .map(|root| PathBuf::from(crate::wine::custom::expand_path(&root)));
+    let remembered = crate::state::read_lines(ROOTS_RECORD)
+        .into_iter()
+        .map(PathBuf::from);
+    let mut roots: Vec<PathBuf> = Vec::new();
+    for root in configured.chain(remembered) {
+        if !roots.contains(&root) {
+            roots.push(root);
+        }
+    }
+    roots
+}
+
+/// Remember a root to scan in later sessions.
+pub fn remember_root(root: &Path) -> Result<(), String> {
+    let line = root.to_string_lossy();
+    if crate::state::read_lines(ROOTS_RECORD)
+        .iter()
+        .any(|r| *r == line)
+    {
+        return Ok(());
+    }
+    crate::state::append_line(ROOTS_RECORD, &line)
+}
+
+/// All known custom prefixes: every non-hidden directory in `prefixes_dir`
+/// (including prefixes that were never initialized), plus prefixes found in
+/// it and in the extra roots.
+pub fn discover(prefixes_dir: &Path, config: &UserConfig) -> Vec<PathBuf> {
+    let mut found: Vec<PathBuf> = fs::read_dir(prefixes_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| !e.file_name().to_string_lossy().starts_with('.'))
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+    for root in std::iter::once(prefixes_dir.to_path_buf()).chain(extra_roots(config)) {
+        found.extend(find_prefixes(&root, SCAN_DEPTH));
+    }
+    found.sort();
+    found.dedup();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_nested_prefixes() {
+        let root = std::env::temp_dir().join(format!("pt-discovery-{}", std::process::id()));
+        let make_prefix = |path: &Path| {
+            fs::create_dir_all(path.join("drive_c")).unwrap();
+            fs::write(path.join("system.reg"), "").unwrap();
+        };
+        make_prefix(&root.join("games/a"));
+        make_prefix(&root.join("games/a/drive_c/nested"));
+        make_prefix(&root.join("b"));
+        make_prefix(&root.join(".b.snapshots/old"));
+        make_prefix(&root.join("x/y/z/too-deep"));
+        fs::create_dir_all(root.join("empty")).unwrap();
+
+        assert_eq!(
+            find_prefixes(&root, SCAN_DEPTH),
+            vec![root.join("b"), root.join("games/a")]
+        );
+        assert!(is_prefix(&root.join("b")));
+        assert!(!is_prefix(&root.join("empty")));
+
+        fs::remove_dir_all(&root).ok();
+    }
+}
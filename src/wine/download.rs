@@ -1,13 +1,291 @@
 //! File download utilities with caching and checksum verification.
+//!
+//! Downloads can be limited in number and bandwidth so verb installs don't
+//! saturate the connection during an online game. The limits come from
+//! `--max-downloads`/`--limit-rate` or the `[downloads]` section of
+//! `config.toml`:
+//!
+//! ```toml
+//! [downloads]
+//! max_parallel = 2
+//! rate_limit = "2M"    # bytes per second, shared by all downloads
+//! ```
 
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Child, Command};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Downloads run at once unless configured otherwise.
+pub const DEFAULT_MAX_PARALLEL: usize = 2;
+
+/// Downloads running at once (at least 1).
+static MAX_PARALLEL: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_PARALLEL);
+/// Bandwidth cap in bytes per second shared by all downloads, 0 for none.
+static RATE_LIMIT: AtomicU64 = AtomicU64::new(0);
+
+/// Downloads finished since the last [`take_transfers`].
+static TRANSFERS: Mutex<Vec<Transfer>> = Mutex::new(Vec::new());
+
+/// A finished download, for statistics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    /// Host it came from.
+    pub host: String,
+    pub bytes: u64,
+    pub duration: Duration,
+}
+
+/// Take the downloads finished since the last call.
+pub fn take_transfers() -> Vec<Transfer> {
+    TRANSFERS
+        .lock()
+        .map(|mut transfers| std::mem::take(&mut *transfers))
+        .unwrap_or_default()
+}
+
+/// Set how many downloads run at once and the total bandwidth they may use.
+pub fn set_download_limits(max_parallel: usize, rate_limit: Option<u64>) {
+    MAX_PARALLEL.store(max_parallel.max(1), Ordering::SeqCst);
+    RATE_LIMIT.store(rate_limit.unwrap_or(0), Ordering::SeqCst);
+}
+
+/// How many downloads run at once.
+pub fn max_parallel_downloads() -> usize {
+    MAX_PARALLEL.load(Ordering::SeqCst)
+}
+
+/// Total bandwidth cap in bytes per second, if any.
+pub fn rate_limit() -> Option<u64> {
+    Some(RATE_LIMIT.load(Ordering::SeqCst)).filter(|&rate| rate > 0)
+}
+
+/// Parse a bandwidth in bytes per second, with an optional K, M or G
+/// suffix (powers of 1024).
+///
+/// ```
+/// use protontool::wine::download::parse_rate;
+/// assert_eq!(parse_rate("500K"), Some(512_000));
+/// assert_eq!(parse_rate("2m"), Some(2 * 1024 * 1024));
+/// assert_eq!(parse_rate("65536"), Some(65536));
+/// assert_eq!(parse_rate("fast"), None);
+/// ```
+pub fn parse_rate(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let (number, multiplier) = match s.chars().last()?.to_ascii_uppercase() {
+        'K' => (&s[..s.len() - 1], 1024),
+        'M' => (&s[..s.len() - 1], 1024 * 1024),
+        'G' => (&s[..s.len() - 1], 1024 * 1024 * 1024),
+        _ => (s, 1),
+    };
+    number
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|&n| n > 0)
+        .map(|n| n * multiplier)
+}
+
+/// Limits from the `[downloads]` section of a config: `max_parallel` and
+/// `rate_limit`. Invalid values are reported and ignored.
+pub fn limits_from_config(config: &crate::config::UserConfig) -> (Option<usize>, Option<u64>) {
+    let max_parallel = config.get("downloads", "max_parallel").and_then(|v| {
+        let parsed = v.parse::<usize>().ok().filter(|&n| n > 0);
+        if parsed.is_none() {
+            eprintln!("Warning: [downloads] max_parallel must be a positive number");
+        }
+        parsed
+    });
+    let rate_limit = config.get("downloads", "rate_limit").and_then(|v| {
+        let parsed = parse_rate(v);
+        if parsed.is_none() {
+            eprintln!("Warning: [downloads] rate_limit must be bytes per second, like 500K or 2M");
+        }
+        parsed
+    });
+    (max_parallel, rate_limit)
+}
+
+/// Progress of a running download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadProgress {
+    pub filename: String,
+    /// Bytes downloaded so far.
+    pub downloaded: u64,
+    /// Current throughput; the average over the whole download once finished.
+    pub bytes_per_sec: u64,
+    pub finished: bool,
+}
+
+/// Receiver of download progress events.
+pub type ProgressFn = Arc<dyn Fn(&DownloadProgress) + Send + Sync>;
+
+/// Run `task` on every item with at most `max_parallel_downloads()` running
+/// at once. Results are returned in the order of `items`.
+pub fn for_each_parallel<T, R, F>(items: &[T], task: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = max_parallel_downloads().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(task).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let item = match items.get(index) {
+                    Some(item) => item,
+                    None => break,
+                };
+                let result = task(item);
+                if let Ok(mut results) = results.lock() {
+                    results[index] = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// One part of a file split into several downloads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadPart {
+    pub url: String,
+    pub filename: String,
+    pub sha256: Option<String>,
+}
+
+impl DownloadPart {
+    pub fn new(url: &str, filename: &str, sha256: Option<&str>) -> Self {
+        Self {
+            url: url.to_string(),
+            filename: filename.to_string(),
+            sha256: sha256.map(|s| s.to_string()),
+        }
+    }
+}
+
+/// How the parts of a split download make up the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartAssembly {
+    /// Byte ranges of one file (`setup.exe.001`, ...), joined in order.
+    Concatenate,
+    /// Volumes read together by the program using them (`file.part1.rar`,
+    /// `setup.exe` with `setup-1.bin`), kept side by side in the cache.
+    Volumes,
+}
+
+/// Join files in order into `dest`, writing to a temporary file first so an
+/// interrupted join doesn't leave a truncated file behind.
+pub fn concatenate_parts(parts: &[PathBuf], dest: &Path) -> Result<(), String> {
+    let partial = dest.with_extension("joining");
+    let join = || -> io::Result<()> {
+        let mut out = fs::File::create(&partial)?;
+        for part in parts {
+            io::copy(&mut fs::File::open(part)?, &mut out)?;
+        }
+        out.sync_all()?;
+        fs::rename(&partial, dest)
+    };
+    join().map_err(|e| {
+        fs::remove_file(&partial).ok();
+        format!("Failed to join parts into {}: {}", dest.display(), e)
+    })
+}
+
+/// SHA-256 of a file as lowercase hex, computed with sha256sum or openssl.
+/// None when neither is installed.
+pub fn sha256_file(path: &Path) -> Result<Option<String>, String> {
+    if let Some(sha256sum) = crate::util::which("sha256sum") {
+        let output = Command::new(sha256sum)
+            .arg(path)
+            .output()
+            .map_err(|e| format!("Failed to run sha256sum: {}", e))?;
+
+        if output.status.success() {
+            let output_str = String::from_utf8_lossy(&output.stdout);
+            let computed = output_str.split_whitespace().next().unwrap_or("");
+            return Ok(Some(computed.to_lowercase()));
+        }
+    }
+
+    if let Some(openssl) = crate::util::which("openssl") {
+        let output = Command::new(openssl)
+            .args(["dgst", "-sha256", &path.to_string_lossy()])
+            .output()
+            .map_err(|e| format!("Failed to run openssl: {}", e))?;
+
+        if output.status.success() {
+            let output_str = String::from_utf8_lossy(&output.stdout);
+            let computed = output_str.split('=').last().map(|s| s.trim()).unwrap_or("");
+            return Ok(Some(computed.to_lowercase()));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Fetch a small text document (e.g. a JSON index) over HTTPS without
+/// caching it. Uses curl or wget, refusing redirects to plain HTTP.
+pub fn fetch_text(url: &str) -> Result<String, String> {
+    if !url.starts_with("https://") {
+        return Err(format!(
+            "Refusing to fetch {}: only https:// URLs are allowed",
+            url
+        ));
+    }
+
+    if let Some(curl) = crate::util::which("curl") {
+        let output = Command::new(curl)
+            .args(["-fsSL", "--proto", "=https", "--proto-redir", "=https", url])
+            .output()
+            .map_err(|e| format!("Failed to run curl: {}", e))?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+        return Err(format!(
+            "Failed to fetch {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    if let Some(wget) = crate::util::which("wget") {
+        let output = Command::new(wget)
+            .args(["-q", "--https-only", "-O", "-", url])
+            .output()
+            .map_err(|e| format!("Failed to run wget: {}", e))?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+        return Err(format!("Failed to fetch {}", url));
+    }
+
+    Err("No download tool available (curl or wget required)".to_string())
+}
 
 /// Downloads files with local caching and optional SHA256 verification.
 /// Uses curl or wget for downloads, sha256sum or openssl for verification.
+#[derive(Clone)]
 pub struct Downloader {
     cache_dir: PathBuf,
+    progress: Option<ProgressFn>,
+    /// Hide the download tool's own progress bar.
+    quiet: bool,
 }
 
 impl Downloader {
@@ -17,7 +295,38 @@ impl Downloader {
         fs::create_dir_all(cache_dir).ok();
         Self {
             cache_dir: cache_dir.to_path_buf(),
+            progress: None,
+            quiet: false,
+        }
+    }
+
+    /// Report progress and throughput to `progress` instead of showing the
+    /// download tool's progress bar (builder pattern).
+    pub fn with_progress(mut self, progress: ProgressFn) -> Self {
+        self.progress = Some(progress);
+        self.quiet = true;
+        self
+    }
+
+    /// A copy for downloads running side by side, whose progress bars would
+    /// garble each other: without a progress receiver, finished downloads
+    /// are reported as one line each.
+    pub fn for_parallel(&self) -> Downloader {
+        let mut parallel = self.clone();
+        parallel.quiet = true;
+        if parallel.progress.is_none() {
+            parallel.progress = Some(Arc::new(|progress: &DownloadProgress| {
+                if progress.finished {
+                    eprintln!(
+                        "Downloaded {} ({}, {}/s)",
+                        progress.filename,
+                        crate::util::format_size(progress.downloaded),
+                        crate::util::format_size(progress.bytes_per_sec)
+                    );
+                }
+            }));
         }
+        parallel
     }
 
     /// Get the cache directory path.
@@ -33,6 +342,17 @@ impl Downloader {
         url: &str,
         filename: &str,
         expected_sha256: Option<&str>,
+    ) -> Result<PathBuf, String> {
+        self.download_from(&[url.to_string()], filename, expected_sha256)
+    }
+
+    /// Download a file available from several mirrors, trying the fastest
+    /// first (see `mirrors`) and the next one when a download fails.
+    pub fn download_from(
+        &self,
+        urls: &[String],
+        filename: &str,
+        expected_sha256: Option<&str>,
     ) -> Result<PathBuf, String> {
         let cached_path = self.cache_dir.join(filename);
 
@@ -47,39 +367,142 @@ impl Downloader {
             }
         }
 
-        self.download_file(url, &cached_path)?;
+        let urls = super::mirrors::order_by_health(urls);
+        let mut error = format!("No download URL for {}", filename);
+        for url in &urls {
+            let started = Instant::now();
+            let result =
+                self.download_file(url, &cached_path)
+                    .and_then(|()| match expected_sha256 {
+                        Some(expected) if !self.verify_sha256(&cached_path, expected)? => {
+                            fs::remove_file(&cached_path).ok();
+                            Err(format!("SHA256 verification failed for {}", filename))
+                        }
+                        _ => Ok(()),
+                    });
+            match result {
+                Ok(()) => {
+                    if let Ok(mut transfers) = TRANSFERS.lock() {
+                        transfers.push(Transfer {
+                            host: super::mirrors::host_of(url).to_string(),
+                            bytes: fs::metadata(&cached_path).map(|m| m.len()).unwrap_or(0),
+                            duration: started.elapsed(),
+                        });
+                    }
+                    return Ok(cached_path);
+                }
+                Err(e) if urls.len() > 1 => {
+                    eprintln!(
+                        "Warning: downloading {} from {} failed: {}",
+                        filename, url, e
+                    );
+                    super::mirrors::record_health(
+                        super::mirrors::host_of(url),
+                        super::mirrors::MirrorHealth::Failed,
+                    );
+                    error = e;
+                }
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
 
-        if let Some(expected) = expected_sha256 {
-            if !self.verify_sha256(&cached_path, expected)? {
-                fs::remove_file(&cached_path).ok();
-                return Err(format!("SHA256 verification failed for {}", filename));
+    /// Download a file split into parts, each verified against its own
+    /// checksum. Concatenated parts are joined into `filename` (verified
+    /// against `expected_sha256`) and then removed; volumes are kept side by
+    /// side and the first one is returned.
+    pub fn download_parts(
+        &self,
+        parts: &[DownloadPart],
+        filename: &str,
+        assembly: PartAssembly,
+        expected_sha256: Option<&str>,
+    ) -> Result<PathBuf, String> {
+        if parts.is_empty() {
+            return Err(format!("No parts to download for {}", filename));
+        }
+
+        let joined = self.cache_dir.join(filename);
+        if assembly == PartAssembly::Concatenate && joined.exists() {
+            match expected_sha256 {
+                Some(expected) if !self.verify_sha256(&joined, expected)? => {
+                    fs::remove_file(&joined).ok();
+                }
+                _ => return Ok(joined),
             }
         }
 
-        Ok(cached_path)
+        eprintln!("Downloading {} in {} parts", filename, parts.len());
+        let downloader = if parts.len() > 1 && max_parallel_downloads() > 1 {
+            self.for_parallel()
+        } else {
+            self.clone()
+        };
+        let mut local_parts = for_each_parallel(parts, |part| {
+            downloader.download(&part.url, &part.filename, part.sha256.as_deref())
+        })
+        .into_iter()
+        .collect::<Result<Vec<PathBuf>, String>>()?;
+
+        match assembly {
+            PartAssembly::Volumes => Ok(local_parts.swap_remove(0)),
+            PartAssembly::Concatenate => {
+                concatenate_parts(&local_parts, &joined)?;
+                if let Some(expected) = expected_sha256 {
+                    if !self.verify_sha256(&joined, expected)? {
+                        fs::remove_file(&joined).ok();
+                        return Err(format!("SHA256 verification failed for {}", filename));
+                    }
+                }
+                for part in &local_parts {
+                    fs::remove_file(part).ok();
+                }
+                Ok(joined)
+            }
+        }
     }
 
     /// Download a file using curl or wget.
     /// Tries curl first, falls back to wget if curl is unavailable.
     fn download_file(&self, url: &str, dest: &Path) -> Result<(), String> {
+        // The bandwidth cap is shared by the downloads running at once
+        let per_download = rate_limit().map(|rate| {
+            (rate / max_parallel_downloads() as u64)
+                .max(1024)
+                .to_string()
+        });
+
         if let Some(curl) = crate::util::which("curl") {
-            let status = Command::new(curl)
-                .args(["-L", "-o", &dest.to_string_lossy(), "--progress-bar", url])
-                .status()
+            let mut cmd = Command::new(curl);
+            cmd.args(["-L", "-o", &dest.to_string_lossy()])
+                .arg(if self.quiet { "-sS" } else { "--progress-bar" });
+            if let Some(rate) = &per_download {
+                cmd.args(["--limit-rate", rate]);
+            }
+            let child = cmd
+                .arg(url)
+                .spawn()
                 .map_err(|e| format!("Failed to run curl: {}", e))?;
 
-            if status.success() {
+            if self.watch_transfer(child, dest)? {
                 return Ok(());
             }
         }
 
         if let Some(wget) = crate::util::which("wget") {
-            let status = Command::new(wget)
-                .args(["-O", &dest.to_string_lossy(), "--progress=bar", url])
-                .status()
+            let mut cmd = Command::new(wget);
+            cmd.args(["-O", &dest.to_string_lossy()])
+                .arg(if self.quiet { "-q" } else { "--progress=bar" });
+            if let Some(rate) = &per_download {
+                cmd.arg(format!("--limit-rate={}", rate));
+            }
+            let child = cmd
+                .arg(url)
+                .spawn()
                 .map_err(|e| format!("Failed to run wget: {}", e))?;
 
-            if status.success() {
+            if self.watch_transfer(child, dest)? {
                 return Ok(());
             }
         }
@@ -87,36 +510,55 @@ impl Downloader {
         Err("No download tool available (curl or wget required)".to_string())
     }
 
-    /// Verify a file's SHA256 checksum using sha256sum or openssl.
-    /// Returns true if checksum matches or no verification tool is available.
-    fn verify_sha256(&self, path: &Path, expected: &str) -> Result<bool, String> {
-        if let Some(sha256sum) = crate::util::which("sha256sum") {
-            let output = Command::new(sha256sum)
-                .arg(path)
-                .output()
-                .map_err(|e| format!("Failed to run sha256sum: {}", e))?;
-
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                let computed = output_str.split_whitespace().next().unwrap_or("");
-                return Ok(computed.eq_ignore_ascii_case(expected));
-            }
-        }
-
-        if let Some(openssl) = crate::util::which("openssl") {
-            let output = Command::new(openssl)
-                .args(["dgst", "-sha256", &path.to_string_lossy()])
-                .output()
-                .map_err(|e| format!("Failed to run openssl: {}", e))?;
+    /// Wait for a download tool to finish, reporting the growth of `dest`
+    /// as progress events. Returns whether the tool succeeded.
+    fn watch_transfer(&self, mut child: Child, dest: &Path) -> Result<bool, String> {
+        const INTERVAL: Duration = Duration::from_millis(500);
+        let filename = dest
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let size = || fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+        let started = Instant::now();
+        let mut last = (started, 0u64);
 
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                let computed = output_str.split('=').last().map(|s| s.trim()).unwrap_or("");
-                return Ok(computed.eq_ignore_ascii_case(expected));
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) => {}
+                Err(e) => return Err(format!("Failed to wait for the download: {}", e)),
+            }
+            std::thread::sleep(INTERVAL);
+            if let Some(progress) = &self.progress {
+                let downloaded = size();
+                let elapsed = last.0.elapsed().as_secs_f64().max(0.001);
+                progress(&DownloadProgress {
+                    filename: filename.clone(),
+                    downloaded,
+                    bytes_per_sec: (downloaded.saturating_sub(last.1) as f64 / elapsed) as u64,
+                    finished: false,
+                });
+                last = (Instant::now(), downloaded);
             }
+        };
+
+        if let (Some(progress), true) = (&self.progress, status.success()) {
+            let downloaded = size();
+            let elapsed = started.elapsed().as_secs_f64().max(0.001);
+            progress(&DownloadProgress {
+                filename,
+                downloaded,
+                bytes_per_sec: (downloaded as f64 / elapsed) as u64,
+                finished: true,
+            });
         }
+        Ok(status.success())
+    }
 
-        Ok(true)
+    /// Verify a file's SHA256 checksum using sha256sum or openssl.
+    /// Returns true if checksum matches or no verification tool is available.
+    fn verify_sha256(&self, path: &Path, expected: &str) -> Result<bool, String> {
+        Ok(sha256_file(path)?.is_none_or(|computed| computed.eq_ignore_ascii_case(expected)))
     }
 
     /// Get the full path where a file would be cached.
@@ -140,3 +582,46 @@ impl Downloader {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concatenate_parts() {
+        let dir = std::env::temp_dir().join(format!("pt-parts-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let parts: Vec<PathBuf> = ["abc", "def", "g"]
+            .iter()
+            .enumerate()
+            .map(|(i, content)| {
+                let path = dir.join(format!("setup.exe.{:03}", i + 1));
+                fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let dest = dir.join("setup.exe");
+        concatenate_parts(&parts, &dest).unwrap();
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "abcdefg");
+        assert!(!dest.with_extension("joining").exists());
+
+        let missing = vec![parts[0].clone(), dir.join("setup.exe.404")];
+        assert!(concatenate_parts(&missing, &dir.join("other.exe")).is_err());
+        assert!(!dir.join("other.joining").exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_for_each_parallel_keeps_order() {
+        set_download_limits(3, None);
+        let items: Vec<u64> = (0..10).collect();
+        let doubled = for_each_parallel(&items, |n| {
+            std::thread::sleep(Duration::from_millis(10 - n));
+            n * 2
+        });
+        assert_eq!(doubled, (0..10).map(|n| n * 2).collect::<Vec<_>>());
+        set_download_limits(DEFAULT_MAX_PARALLEL, None);
+    }
+}
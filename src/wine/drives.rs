@@ -0,0 +1,142 @@
+//! Drive letter mappings of a prefix.
+//!
+//! Wine maps drive letters through symlinks in `dosdevices/` (`c:` →
+//! `../drive_c`, `z:` → `/`). The mount manager additionally records drives
+//! in the registry (`MountedDevices`, `Software\Wine\Drives`), which is
+//! cleaned up when a drive is removed so Wine doesn't bring it back.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::registry::delete_prefix_value;
+
+/// Registry key holding the mount manager's drive records.
+const MOUNTED_DEVICES_KEY: &str = "HKEY_LOCAL_MACHINE\\System\\MountedDevices";
+/// Registry key holding drive types.
+const DRIVES_KEY: &str = "HKEY_LOCAL_MACHINE\\Software\\Wine\\Drives";
+
+/// A mapped drive letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriveMapping {
+    pub letter: char,
+    pub target: PathBuf,
+}
+
+/// Path of the `dosdevices` link for a drive letter.
+pub fn drive_link(prefix_path: &Path, letter: char) -> PathBuf {
+    prefix_path
+        .join("dosdevices")
+        .join(format!("{}:", letter.to_ascii_lowercase()))
+}
+
+/// All drive letters mapped in a prefix, sorted by letter.
+pub fn list_drives(prefix_path: &Path) -> Vec<DriveMapping> {
+    let mut drives: Vec<DriveMapping> = fs::read_dir(prefix_path.join("dosdevices"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_lowercase();
+                    let mut chars = name.chars();
+                    let letter = chars.next().filter(|c| c.is_ascii_lowercase())?;
+                    if chars.as_str() != ":" {
+                        return None;
+                    }
+                    let target = fs::read_link(entry.path()).ok()?;
+                    Some(DriveMapping { letter, target })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    drives.sort_by_key(|d| d.letter);
+    drives
+}
+
+/// Current target of a drive letter.
+pub fn drive_target(prefix_path: &Path, letter: char) -> Option<PathBuf> {
+    fs::read_link(drive_link(prefix_path, letter)).ok()
+}
+
+/// Remove a drive letter and the mount manager's records of it.
+/// Returns false if the drive wasn't mapped.
+pub fn unmap_drive(prefix_path: &Path, letter: char) -> Result<bool, String> {
+    let letter = letter.to_ascii_lowercase();
+    let link = drive_link(prefix_path, letter);
+    let mapped = link.is_symlink();
+    if mapped {
+        fs::remove_file(&link)
+            .map_err(|e| format!("Failed to remove {}: {}", link.display(), e))?;
+    }
+    // Raw device link (e.g. "d::") used for CD-ROM drives
+    let device = prefix_path.join("dosdevices").join(format!("{}::", letter));
+    if device.is_symlink() {
+        fs::remove_file(&device).ok();
+    }
+
+    delete_prefix_value(
+        prefix_path,
+        MOUNTED_DEVICES_KEY,
+        &format!("\\??\\{}:", letter.to_ascii_uppercase()),
+    )?;
+    delete_prefix_value(prefix_path, DRIVES_KEY, &format!("{}:", letter))?;
+    Ok(mapped)
+}
+
+/// Map a drive letter to a host directory, replacing any existing mapping.
+#[cfg(unix)]
+pub fn map_drive(prefix_path: &Path, letter: char, target: &Path) -> Result<(), String> {
+    if !letter.is_ascii_alphabetic() {
+        return Err(format!("Invalid drive letter: {}", letter));
+    }
+    let link = drive_link(prefix_path, letter);
+    if let Some(parent) = link.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    if link.is_symlink() {
+        fs::remove_file(&link)
+            .map_err(|e| format!("Failed to remove {}: {}", link.display(), e))?;
+    }
+    std::os::unix::fs::symlink(target, &link)
+        .map_err(|e| format!("Failed to create {}: {}", link.display(), e))
+}
+
+#[cfg(not(unix))]
+pub fn map_drive(_prefix_path: &Path, _letter: char, _target: &Path) -> Result<(), String> {
+    Err("Drive mappings are only supported on Unix".to_string())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unmap_drive_removes_link_and_records() {
+        let prefix = std::env::temp_dir().join(format!("protontool-drives-{}", std::process::id()));
+        fs::create_dir_all(prefix.join("dosdevices")).unwrap();
+        map_drive(&prefix, 'z', Path::new("/")).unwrap();
+        map_drive(&prefix, 'c', Path::new("../drive_c")).unwrap();
+        fs::write(
+            prefix.join("system.reg"),
+            "WINE REGISTRY Version 2\n\n[System\\\\MountedDevices] 1700000000\n\"\\\\??\\\\C:\"=hex:01,02,\\\n  03,04\n\"\\\\??\\\\Z:\"=hex:05,06,\\\n  07,08\n\n[Software\\\\Wine\\\\Drives] 1700000000\n\"z:\"=\"hd\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(list_drives(&prefix).len(), 2);
+        assert!(unmap_drive(&prefix, 'Z').unwrap());
+        assert!(!unmap_drive(&prefix, 'z').unwrap());
+        assert_eq!(
+            list_drives(&prefix),
+            vec![DriveMapping {
+                letter: 'c',
+                target: PathBuf::from("../drive_c")
+            }]
+        );
+        assert_eq!(
+            fs::read_to_string(prefix.join("system.reg")).unwrap(),
+            "WINE REGISTRY Version 2\n\n[System\\\\MountedDevices] 1700000000\n\"\\\\??\\\\C:\"=hex:01,02,\\\n  03,04\n\n[Software\\\\Wine\\\\Drives] 1700000000\n"
+        );
+
+        fs::remove_dir_all(&prefix).ok();
+    }
+}
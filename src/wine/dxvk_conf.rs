@@ -0,0 +1,305 @@
+//! DXVK configuration (`dxvk.conf`) management.
+//!
+//! DXVK reads `dxvk.conf` from the game's working directory, or from the
+//! file named by `DXVK_CONFIG_FILE`. A game's config can live in either
+//! place:
+//!
+//! - next to the game executable, which also works when the game is started
+//!   from Steam;
+//! - inside the prefix, referenced through `DXVK_CONFIG_FILE` in the prefix's
+//!   environment profile, which keeps the game directory untouched but only
+//!   applies to launches through protontool.
+//!
+//! Common options are validated against their type before being written;
+//! other lines (comments, options protontool doesn't know) are preserved.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::profile::EnvProfile;
+
+/// File name DXVK looks for in the working directory.
+pub const DXVK_CONF_FILE: &str = "dxvk.conf";
+
+/// Environment variable pointing DXVK to a config file.
+pub const DXVK_CONFIG_FILE_VAR: &str = "DXVK_CONFIG_FILE";
+
+/// Value type of a DXVK option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DxvkValueKind {
+    /// Integer, optionally limited to a range.
+    Int { min: i64, max: i64 },
+    /// `True` or `False`.
+    Bool,
+    /// Free-form text.
+    Text,
+}
+
+/// A commonly used DXVK option.
+#[derive(Debug, Clone, Copy)]
+pub struct DxvkOption {
+    pub key: &'static str,
+    pub kind: DxvkValueKind,
+    pub description: &'static str,
+}
+
+/// Options offered by the GUI form and validated when set.
+pub const DXVK_OPTIONS: &[DxvkOption] = &[
+    DxvkOption {
+        key: "dxgi.maxFrameRate",
+        kind: DxvkValueKind::Int { min: 0, max: 1000 },
+        description: "Frame rate limit for D3D10/11 games (0 = unlimited)",
+    },
+    DxvkOption {
+        key: "d3d9.maxFrameRate",
+        kind: DxvkValueKind::Int { min: 0, max: 1000 },
+        description: "Frame rate limit for D3D9 games (0 = unlimited)",
+    },
+    DxvkOption {
+        key: "dxgi.syncInterval",
+        kind: DxvkValueKind::Int { min: -1, max: 4 },
+        description: "Override vsync (-1 = game default, 0 = off)",
+    },
+    DxvkOption {
+        key: "dxvk.enableAsync",
+        kind: DxvkValueKind::Bool,
+        description: "Asynchronous shader compilation (dxvk-async/gplasync builds)",
+    },
+    DxvkOption {
+        key: "dxvk.hud",
+        kind: DxvkValueKind::Text,
+        description: "HUD elements, e.g. fps,frametimes,devinfo",
+    },
+    DxvkOption {
+        key: "dxvk.numCompilerThreads",
+        kind: DxvkValueKind::Int { min: 0, max: 256 },
+        description: "Shader compiler threads (0 = automatic)",
+    },
+    DxvkOption {
+        key: "d3d11.samplerAnisotropy",
+        kind: DxvkValueKind::Int { min: -1, max: 16 },
+        description: "Force anisotropic filtering (-1 = game default)",
+    },
+];
+
+/// Look up a known option by key (case-insensitive, like DXVK).
+pub fn find_option(key: &str) -> Option<&'static DxvkOption> {
+    DXVK_OPTIONS
+        .iter()
+        .find(|o| o.key.eq_ignore_ascii_case(key))
+}
+
+/// Check a value against a known option's type and normalize it
+/// (booleans become `True`/`False`). Unknown keys are accepted as is.
+///
+/// ```
+/// use protontool::wine::dxvk_conf::validate_option;
+/// assert_eq!(validate_option("dxvk.enableAsync", "yes"), Ok("True".to_string()));
+/// assert!(validate_option("dxgi.maxFrameRate", "fast").is_err());
+/// ```
+pub fn validate_option(key: &str, value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) || key.contains('=') {
+        return Err(format!("Invalid DXVK option name: {}", key));
+    }
+    let option = match find_option(key) {
+        Some(o) => o,
+        None => return Ok(value.to_string()),
+    };
+    match option.kind {
+        DxvkValueKind::Int { min, max } => match value.parse::<i64>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n.to_string()),
+            _ => Err(format!(
+                "{} must be a number from {} to {}",
+                option.key, min, max
+            )),
+        },
+        DxvkValueKind::Bool => match value.to_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Ok("True".to_string()),
+            "false" | "0" | "no" | "off" => Ok("False".to_string()),
+            _ => Err(format!("{} must be True or False", option.key)),
+        },
+        DxvkValueKind::Text => Ok(value.to_string()),
+    }
+}
+
+/// Contents of a dxvk.conf file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DxvkConfig {
+    lines: Vec<String>,
+}
+
+/// Split a `key = value` line. Comments and blank lines yield None.
+fn split_option_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (key, value) = line.split_once('=')?;
+    Some((key.trim(), value.trim()))
+}
+
+impl DxvkConfig {
+    pub fn parse(content: &str) -> Self {
+        DxvkConfig {
+            lines: content.lines().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    /// Load a config file. A missing file is an empty config.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        super::check_writable(path)?;
+        let mut content = self.lines.join("\n");
+        content.push('\n');
+        fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Options set in the file, in file order.
+    pub fn options(&self) -> Vec<(String, String)> {
+        self.lines
+            .iter()
+            .filter_map(|l| split_option_line(l))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.options()
+            .into_iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v)
+    }
+
+    /// Validate and set an option, replacing an existing line in place.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let value = validate_option(key, value)?;
+        let key = find_option(key).map(|o| o.key).unwrap_or(key);
+        let line = format!("{} = {}", key, value);
+        let existing = self
+            .lines
+            .iter()
+            .position(|l| split_option_line(l).is_some_and(|(k, _)| k.eq_ignore_ascii_case(key)));
+        match existing {
+            Some(i) => self.lines[i] = line,
+            None => self.lines.push(line),
+        }
+        Ok(())
+    }
+
+    /// Remove an option. Returns true if it was set.
+    pub fn unset(&mut self, key: &str) -> bool {
+        let before = self.lines.len();
+        self.lines
+            .retain(|l| !split_option_line(l).is_some_and(|(k, _)| k.eq_ignore_ascii_case(key)));
+        self.lines.len() != before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.options().is_empty()
+    }
+}
+
+/// Where a game's dxvk.conf lives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DxvkConfLocation {
+    /// Next to the game executable (the game's working directory).
+    GameDir(PathBuf),
+    /// A file referenced through `DXVK_CONFIG_FILE`, normally inside the
+    /// prefix.
+    ConfigFile(PathBuf),
+}
+
+impl DxvkConfLocation {
+    /// Path of the config file.
+    pub fn path(&self) -> PathBuf {
+        match self {
+            DxvkConfLocation::GameDir(dir) => dir.join(DXVK_CONF_FILE),
+            DxvkConfLocation::ConfigFile(file) => file.clone(),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            DxvkConfLocation::GameDir(_) => format!("game directory ({})", self.path().display()),
+            DxvkConfLocation::ConfigFile(_) => {
+                format!("{} (via {})", self.path().display(), DXVK_CONFIG_FILE_VAR)
+            }
+        }
+    }
+}
+
+/// The config DXVK will use for a prefix and game directory:
+/// `DXVK_CONFIG_FILE` in the environment profile takes precedence over a
+/// dxvk.conf next to the game. None if neither is set up.
+pub fn active_location(prefix_path: &Path, game_dir: Option<&Path>) -> Option<DxvkConfLocation> {
+    if let Some(file) = EnvProfile::load(prefix_path).get(DXVK_CONFIG_FILE_VAR) {
+        return Some(DxvkConfLocation::ConfigFile(PathBuf::from(file)));
+    }
+    game_dir
+        .filter(|dir| dir.join(DXVK_CONF_FILE).is_file())
+        .map(|dir| DxvkConfLocation::GameDir(dir.to_path_buf()))
+}
+
+/// Point DXVK at a config location, carrying over the current config.
+/// Switching to a config file (e.g. `dxvk.conf` in the prefix) copies the
+/// config there and sets `DXVK_CONFIG_FILE`; switching to the game directory writes the file next
+/// to the game and removes the variable. The previous file is left in place.
+pub fn switch_location(
+    prefix_path: &Path,
+    game_dir: Option<&Path>,
+    target: &DxvkConfLocation,
+) -> Result<(), String> {
+    let config = active_location(prefix_path, game_dir)
+        .map(|current| DxvkConfig::load(&current.path()))
+        .unwrap_or_default();
+    let target_path = target.path();
+    if !target_path.exists() || !config.is_empty() {
+        config.save(&target_path)?;
+    }
+    match target {
+        DxvkConfLocation::ConfigFile(_) => crate::journal::set_profile_var(
+            prefix_path,
+            DXVK_CONFIG_FILE_VAR,
+            Some(&target_path.to_string_lossy()),
+        ),
+        DxvkConfLocation::GameDir(_) => {
+            if EnvProfile::load(prefix_path)
+                .get(DXVK_CONFIG_FILE_VAR)
+                .is_some()
+            {
+                crate::journal::set_profile_var(prefix_path, DXVK_CONFIG_FILE_VAR, None)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_preserves_other_lines() {
+        let mut config =
+            DxvkConfig::parse("# Limit FPS\ndxgi.maxframerate = 30\nd3d9.shaderModel = 2\n");
+        config.set("dxgi.maxFrameRate", "60").unwrap();
+        config.set("dxvk.enableAsync", "on").unwrap();
+        assert!(config.set("dxgi.syncInterval", "9").is_err());
+        assert!(config.unset("d3d9.shaderModel"));
+        assert_eq!(
+            config.lines,
+            vec![
+                "# Limit FPS",
+                "dxgi.maxFrameRate = 60",
+                "dxvk.enableAsync = True",
+            ]
+        );
+        assert_eq!(config.get("DXGI.MAXFRAMERATE").as_deref(), Some("60"));
+    }
+}
@@ -0,0 +1,223 @@
+//! Filesystem capability detection for prefixes and Steam libraries.
+//!
+//! Proton needs symlinks (`dosdevices`, DXVK and VKD3D-Proton DLLs are
+//! linked into `system32`), Unix permissions and executable mappings.
+//! Libraries and prefixes on NTFS or exFAT mounts, or on `noexec` mounts,
+//! are one of the most common root causes of unexplained failures, so the
+//! filesystem a path lives on is looked up in `/proc/self/mounts` and,
+//! where protontool may write, probed for symlink and case sensitivity
+//! support.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Filesystem types that can't hold a Proton prefix reliably: no Unix
+/// permissions, and no symlinks or only emulated ones.
+const WINDOWS_FILESYSTEMS: &[&str] = &["ntfs", "ntfs3", "exfat", "vfat", "msdos"];
+
+/// A mounted filesystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mount {
+    pub point: PathBuf,
+    pub fstype: String,
+    pub options: Vec<String>,
+}
+
+impl Mount {
+    pub fn has_option(&self, option: &str) -> bool {
+        self.options.iter().any(|o| o == option)
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.has_option("ro")
+    }
+
+    /// Whether this is a Windows filesystem. `fuseblk` is usually NTFS
+    /// through ntfs-3g, but only probing can tell.
+    pub fn is_windows_filesystem(&self) -> bool {
+        WINDOWS_FILESYSTEMS.contains(&self.fstype.as_str())
+    }
+}
+
+/// Undo the octal escapes (`\040` for a space) of a `/proc/mounts` field.
+fn unescape_mount_field(field: &str) -> String {
+    let mut out = String::new();
+    let mut chars = field.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let digits: String = chars.clone().take(3).collect();
+            if let Ok(byte) = u8::from_str_radix(&digits, 8) {
+                out.push(byte as char);
+                chars.nth(2);
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Parse `/proc/mounts` content.
+pub fn parse_mounts(content: &str) -> Vec<Mount> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            Some(Mount {
+                point: PathBuf::from(unescape_mount_field(fields.get(1)?)),
+                fstype: fields.get(2)?.to_string(),
+                options: fields.get(3)?.split(',').map(str::to_string).collect(),
+            })
+        })
+        .collect()
+}
+
+/// The mount `path` lives on. Later mounts on the same point hide earlier
+/// ones.
+pub fn mount_of<'a>(mounts: &'a [Mount], path: &Path) -> Option<&'a Mount> {
+    mounts
+        .iter()
+        .filter(|mount| path.starts_with(&mount.point))
+        .fold(None, |best: Option<&Mount>, mount| match best {
+            Some(b) if b.point.components().count() > mount.point.components().count() => Some(b),
+            _ => Some(mount),
+        })
+}
+
+/// The mount an existing path lives on.
+pub fn mount_for(path: &Path) -> Option<Mount> {
+    let path = fs::canonicalize(path).ok()?;
+    let mounts = parse_mounts(&fs::read_to_string("/proc/self/mounts").ok()?);
+    mount_of(&mounts, &path).cloned()
+}
+
+/// What a probe in a directory found; None where it couldn't tell.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Probe {
+    pub symlinks: Option<bool>,
+    pub case_sensitive: Option<bool>,
+}
+
+/// Create and remove a few files in a scratch directory under `dir` to see
+/// whether symlinks work and names are case sensitive. Does nothing in
+/// read-only mode or where `dir` can't be written.
+pub fn probe(dir: &Path) -> Probe {
+    if super::check_writable(dir).is_err() {
+        return Probe::default();
+    }
+    let scratch = dir.join(format!(".protontool-probe-{}", std::process::id()));
+    if fs::create_dir(&scratch).is_err() {
+        return Probe::default();
+    }
+    let symlinks = Some(std::os::unix::fs::symlink("target", scratch.join("link")).is_ok());
+    let case_sensitive = fs::write(scratch.join("case"), "")
+        .ok()
+        .map(|_| !scratch.join("CASE").exists());
+    fs::remove_dir_all(&scratch).ok();
+    Probe {
+        symlinks,
+        case_sensitive,
+    }
+}
+
+/// Problems with a filesystem, from its mount and a probe (if any).
+pub fn problems(mount: &Mount, probe: &Probe) -> Vec<String> {
+    let mut problems = Vec::new();
+    if mount.is_windows_filesystem() {
+        problems.push(format!(
+            "{} is a Windows filesystem without Unix permissions; Proton prefixes \
+             belong on a Linux filesystem like ext4, btrfs or xfs",
+            mount.fstype
+        ));
+    }
+    if probe.symlinks == Some(false) {
+        problems.push("symlinks can't be created (DXVK, dosdevices and Proton need them)".into());
+    }
+    if mount.has_option("noexec") {
+        problems.push(format!(
+            "mounted noexec, so programs can't run from it (remount {} with exec)",
+            mount.point.display()
+        ));
+    }
+    problems
+}
+
+/// The filesystem of a prefix or library and its problems.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub path: PathBuf,
+    pub mount: Mount,
+    pub probe: Probe,
+    pub problems: Vec<String>,
+}
+
+impl Report {
+    /// One line naming the filesystem and its problems.
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} is on {} ({})",
+            self.path.display(),
+            self.mount.fstype,
+            self.mount.point.display()
+        );
+        if self.probe.case_sensitive == Some(false) {
+            summary.push_str(", case-insensitive");
+        }
+        if !self.problems.is_empty() {
+            summary.push_str(": ");
+            summary.push_str(&self.problems.join("; "));
+        }
+        summary
+    }
+}
+
+/// Check the filesystem an existing path is on, probing it if `run_probe`.
+pub fn check(path: &Path, run_probe: bool) -> Option<Report> {
+    let mount = mount_for(path)?;
+    let probe = if run_probe {
+        probe(path)
+    } else {
+        Probe::default()
+    };
+    Some(Report {
+        path: path.to_path_buf(),
+        problems: problems(&mount, &probe),
+        mount,
+        probe,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mounts() {
+        let mounts = parse_mounts(
+            "\
+/dev/sda1 / ext4 rw,relatime 0 0
+/dev/sdb1 /mnt/games ntfs3 rw,noexec 0 0
+/dev/sdc1 /mnt/games/My\\040Backups ext4 ro,noatime 0 0
+/dev/sdd1 /mnt/games ext4 rw 0 0
+",
+        );
+        let point = |path: &str| mount_of(&mounts, Path::new(path)).map(|m| m.point.clone());
+        assert_eq!(point("/home/user/pfx"), Some(PathBuf::from("/")));
+        assert_eq!(point("/mnt/gamesx"), Some(PathBuf::from("/")));
+
+        let backups = mount_of(&mounts, Path::new("/mnt/games/My Backups/pfx")).unwrap();
+        assert_eq!(backups.point, PathBuf::from("/mnt/games/My Backups"));
+        assert!(backups.is_read_only());
+
+        let games = mount_of(&mounts, Path::new("/mnt/games/pfx")).unwrap();
+        assert_eq!(games.fstype, "ext4", "the last mount on a point wins");
+        assert!(problems(games, &Probe::default()).is_empty());
+
+        let ntfs = &mounts[1];
+        let probe = Probe {
+            symlinks: Some(false),
+            case_sensitive: Some(false),
+        };
+        assert_eq!(problems(ntfs, &probe).len(), 3);
+    }
+}
@@ -0,0 +1,160 @@
+//! Manifest of the graphics DLLs installed by verbs.
+//!
+//! The DXVK and VKD3D-Proton verbs copy their DLLs over the ones in
+//! `system32` and `syswow64`. When Proton updates a prefix it puts its own
+//! builds back, silently undoing the verb, and a game that needed that
+//! version breaks after the update. So the verbs record each file they
+//! install in `protontool-graphics.manifest` inside the prefix, one
+//! `verb<TAB>version<TAB>path<TAB>sha256` line per file (paths relative to
+//! the prefix), and `--verify-graphics` checks the files against it.
+//! Reinstalling the affected verbs from the download cache repairs them.
+
+use std::fs;
+use std::path::Path;
+
+use super::download::sha256_file;
+use super::verbs::VerbRegistry;
+use super::WineContext;
+
+/// File name of the manifest inside a prefix.
+pub const MANIFEST_FILE: &str = "protontool-graphics.manifest";
+
+/// Hash recorded when no SHA-256 tool was available; such files are only
+/// checked for existence.
+const UNKNOWN_HASH: &str = "-";
+
+/// A file installed by a graphics verb.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub verb: String,
+    pub version: String,
+    /// Path relative to the prefix, e.g. `drive_c/windows/system32/d3d11.dll`.
+    pub path: String,
+    pub sha256: String,
+}
+
+/// How an installed file differs from the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    Missing,
+    Replaced,
+}
+
+/// Parse manifest lines, skipping malformed ones.
+///
+/// ```
+/// use protontool::wine::graphics::parse_manifest;
+/// let entries = parse_manifest("dxvk\t2.5.3\tdrive_c/windows/system32/d3d11.dll\tab12\nbad\n");
+/// assert_eq!(entries.len(), 1);
+/// assert_eq!(entries[0].version, "2.5.3");
+/// ```
+pub fn parse_manifest(content: &str) -> Vec<ManifestEntry> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let entry = ManifestEntry {
+                verb: fields.next()?.to_string(),
+                version: fields.next()?.to_string(),
+                path: fields.next()?.to_string(),
+                sha256: fields.next()?.to_string(),
+            };
+            Some(entry)
+        })
+        .collect()
+}
+
+/// Read a prefix's manifest (empty when no graphics verb was installed).
+pub fn read_manifest(prefix_path: &Path) -> Vec<ManifestEntry> {
+    fs::read_to_string(prefix_path.join(MANIFEST_FILE))
+        .map(|content| parse_manifest(&content))
+        .unwrap_or_default()
+}
+
+fn write_manifest(prefix_path: &Path, entries: &[ManifestEntry]) -> Result<(), String> {
+    let path = prefix_path.join(MANIFEST_FILE);
+    let content: String = entries
+        .iter()
+        .map(|e| format!("{}\t{}\t{}\t{}\n", e.verb, e.version, e.path, e.sha256))
+        .collect();
+    fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Copy a graphics verb's DLLs from an extracted release into the prefix
+/// and record them in the manifest, replacing earlier records of the same
+/// files. `x32_dir` and `x64_dir` are the release's subdirectories for
+/// each architecture (`x32`/`x64` for DXVK, `x86`/`x64` for VKD3D-Proton).
+pub fn install_dlls(
+    wine_ctx: &WineContext,
+    verb: &str,
+    version: &str,
+    release_dir: &Path,
+    (x32_dir, x64_dir): (&str, &str),
+    dlls: &[&str],
+) -> Result<(), String> {
+    let syswow = Path::new("drive_c/windows/syswow64");
+    let sys32 = Path::new("drive_c/windows/system32");
+    let targets = if wine_ctx.prefix_path.join(syswow).exists() {
+        vec![(x32_dir, syswow), (x64_dir, sys32)]
+    } else {
+        vec![(x32_dir, sys32)]
+    };
+
+    let mut installed = Vec::new();
+    for (arch_dir, target_dir) in targets {
+        for dll in dlls {
+            let target = target_dir.join(dll);
+            let dest = wine_ctx.prefix_path.join(&target);
+            fs::copy(release_dir.join(arch_dir).join(dll), &dest)
+                .map_err(|e| format!("Failed to install {}: {}", dest.display(), e))?;
+            installed.push(ManifestEntry {
+                verb: verb.to_string(),
+                version: version.to_string(),
+                path: target.to_string_lossy().into_owned(),
+                sha256: sha256_file(&dest)?.unwrap_or_else(|| UNKNOWN_HASH.to_string()),
+            });
+        }
+    }
+
+    let mut entries = read_manifest(&wine_ctx.prefix_path);
+    entries.retain(|e| !installed.iter().any(|i| i.path == e.path));
+    entries.extend(installed);
+    write_manifest(&wine_ctx.prefix_path, &entries)
+}
+
+/// Files that no longer match the manifest.
+pub fn verify(prefix_path: &Path) -> Result<Vec<(ManifestEntry, Drift)>, String> {
+    let mut drifted = Vec::new();
+    for entry in read_manifest(prefix_path) {
+        let path = prefix_path.join(&entry.path);
+        if !path.exists() {
+            drifted.push((entry, Drift::Missing));
+        } else if entry.sha256 != UNKNOWN_HASH
+            && sha256_file(&path)?.is_some_and(|sha256| sha256 != entry.sha256)
+        {
+            drifted.push((entry, Drift::Replaced));
+        }
+    }
+    Ok(drifted)
+}
+
+/// Reinstall the verbs whose files drifted. The releases are normally
+/// still in the download cache, so this works offline.
+pub fn repair(wine_ctx: &WineContext, drifted: &[(ManifestEntry, Drift)]) -> Result<(), String> {
+    let mut verbs: Vec<&str> = Vec::new();
+    for (entry, _) in drifted {
+        if !verbs.contains(&entry.verb.as_str()) {
+            verbs.push(&entry.verb);
+        }
+    }
+    let registry = VerbRegistry::new();
+    let cache_dir = crate::config::get_cache_dir().join("wine");
+    fs::create_dir_all(&cache_dir).ok();
+    for verb in verbs {
+        eprintln!("Reinstalling {}", verb);
+        registry
+            .execute(verb, wine_ctx, &cache_dir)
+            .map_err(|e| format!("Failed to reinstall {}: {}", verb, e))?;
+    }
+    Ok(())
+}
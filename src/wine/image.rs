@@ -0,0 +1,409 @@
+//! Prefixes hosted in a disk image.
+//!
+//! An image prefix lives in a sparse ext4 file next to its mount point:
+//! `~/Games/MyPrefix.img` is mounted on `~/Games/MyPrefix`. The image caps
+//! how much space the prefix can take and moves to another machine as a
+//! single file. Images are mounted with fuse2fs, which needs no root; as
+//! root a plain loop mount is used instead.
+
+use std::fs::{self, OpenOptions};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::util::{output_stderr_to_string, which};
+
+/// Image sizes are rounded up to whole mebibytes.
+const SIZE_ALIGN: u64 = 1024 * 1024;
+
+/// Smallest image mkfs.ext4 and a Wine prefix are happy with.
+const MIN_IMAGE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Image file backing a prefix mount point.
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use protontool::wine::image::image_path;
+/// assert_eq!(
+///     image_path(Path::new("/games/MyPrefix")),
+///     PathBuf::from("/games/MyPrefix.img")
+/// );
+/// ```
+pub fn image_path(prefix_path: &Path) -> PathBuf {
+    let mut path = prefix_path.as_os_str().to_owned();
+    path.push(".img");
+    PathBuf::from(path)
+}
+
+/// Whether a prefix is backed by an image file.
+pub fn has_image(prefix_path: &Path) -> bool {
+    image_path(prefix_path).is_file()
+}
+
+/// Parse a size such as `20G`, `512M` or `1.5T` into bytes.
+///
+/// ```
+/// use protontool::wine::image::parse_size;
+/// assert_eq!(parse_size("512M"), Ok(512 * 1024 * 1024));
+/// assert_eq!(parse_size("1.5g"), Ok(1536 * 1024 * 1024));
+/// assert!(parse_size("big").is_err());
+/// ```
+pub fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("Invalid size '{}' (e.g. 20G or 512M)", value))?;
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().trim_end_matches("IB") {
+        "" | "B" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        "T" => 1024 * 1024 * 1024 * 1024,
+        _ => {
+            return Err(format!(
+                "Invalid size unit in '{}' (use K, M, G or T)",
+                value
+            ))
+        }
+    };
+    Ok((number * multiplier as f64) as u64)
+}
+
+/// Round a size up to the image alignment.
+fn aligned(size: u64) -> u64 {
+    size.div_ceil(SIZE_ALIGN) * SIZE_ALIGN
+}
+
+/// State of an image prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageStatus {
+    pub image: PathBuf,
+    /// Apparent size of the image, the prefix's size limit.
+    pub size: u64,
+    /// Disk space the sparse image actually uses.
+    pub allocated: u64,
+    /// Filesystem type of the mount, when mounted.
+    pub mount_type: Option<String>,
+}
+
+/// Size and mount state of a prefix's image.
+pub fn status(prefix_path: &Path) -> Result<ImageStatus, String> {
+    let image = image_path(prefix_path);
+    let metadata =
+        fs::metadata(&image).map_err(|e| format!("No image at {}: {}", image.display(), e))?;
+    Ok(ImageStatus {
+        image,
+        size: metadata.len(),
+        allocated: metadata.blocks() * 512,
+        mount_type: mount_type(prefix_path),
+    })
+}
+
+/// Create a sparse ext4 image of `size` bytes for a new prefix and mount it.
+pub fn create(prefix_path: &Path, size: u64) -> Result<(), String> {
+    let image = image_path(prefix_path);
+    if image.exists() {
+        return Err(format!("Image already exists: {}", image.display()));
+    }
+    let is_empty_dir = fs::read_dir(prefix_path).map_or(true, |mut e| e.next().is_none());
+    if prefix_path.exists() && !is_empty_dir {
+        return Err(format!(
+            "{} is not empty; image prefixes need a new directory",
+            prefix_path.display()
+        ));
+    }
+    let size = aligned(size);
+    if size < MIN_IMAGE_SIZE {
+        return Err(format!(
+            "Image size must be at least {}",
+            crate::util::format_size(MIN_IMAGE_SIZE)
+        ));
+    }
+    let mkfs = which("mkfs.ext4").ok_or("mkfs.ext4 not found (install e2fsprogs)")?;
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&image)
+        .map_err(|e| format!("Failed to create {}: {}", image.display(), e))?;
+    file.set_len(size)
+        .map_err(|e| format!("Failed to size {}: {}", image.display(), e))?;
+    let owner = file
+        .metadata()
+        .map(|m| format!("{}:{}", m.uid(), m.gid()))
+        .map_err(|e| format!("Failed to read {}: {}", image.display(), e))?;
+    drop(file);
+
+    // root_owner gives the filesystem root to the user instead of root
+    let label = prefix_path
+        .file_name()
+        .map(|n| n.to_string_lossy().chars().take(16).collect::<String>())
+        .unwrap_or_default();
+    let output = Command::new(mkfs)
+        .args(["-q", "-F", "-L", &label, "-E"])
+        .arg(format!("root_owner={}", owner))
+        .arg(&image)
+        .output()
+        .map_err(|e| format!("Failed to run mkfs.ext4: {}", e))?;
+    if !output.status.success() {
+        fs::remove_file(&image).ok();
+        return Err(format!(
+            "mkfs.ext4 failed: {}",
+            output_stderr_to_string(&output)
+        ));
+    }
+    mount(prefix_path)
+}
+
+/// Mount a prefix's image on the prefix path. Does nothing if it's already
+/// mounted.
+pub fn mount(prefix_path: &Path) -> Result<(), String> {
+    let image = image_path(prefix_path);
+    if !image.is_file() {
+        return Err(format!("No image at {}", image.display()));
+    }
+    if mount_type(prefix_path).is_some() {
+        return Ok(());
+    }
+    fs::create_dir_all(prefix_path)
+        .map_err(|e| format!("Failed to create {}: {}", prefix_path.display(), e))?;
+
+    let mut command = if let Some(fuse2fs) = which("fuse2fs") {
+        let mut command = Command::new(fuse2fs);
+        command.arg(&image).arg(prefix_path);
+        command
+    } else if super::ownership::is_root() {
+        let mut command = Command::new("mount");
+        command.args(["-o", "loop"]).arg(&image).arg(prefix_path);
+        command
+    } else {
+        return Err(format!(
+            "fuse2fs not found (install it, or mount as root: sudo mount -o loop {} {})",
+            crate::util::shell_quote(&image.to_string_lossy()),
+            crate::util::shell_quote(&prefix_path.to_string_lossy())
+        ));
+    };
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to mount {}: {}", image.display(), e))?;
+    if !output.status.success() || mount_type(prefix_path).is_none() {
+        return Err(format!(
+            "Failed to mount {}: {}",
+            image.display(),
+            output_stderr_to_string(&output)
+        ));
+    }
+    Ok(())
+}
+
+/// Unmount a prefix's image. Does nothing if it isn't mounted.
+pub fn unmount(prefix_path: &Path) -> Result<(), String> {
+    let Some(fs_type) = mount_type(prefix_path) else {
+        return Ok(());
+    };
+    let mut command = if fs_type.starts_with("fuse") {
+        let fusermount = which("fusermount3")
+            .or_else(|| which("fusermount"))
+            .ok_or("fusermount not found")?;
+        let mut command = Command::new(fusermount);
+        command.arg("-u");
+        command
+    } else {
+        Command::new("umount")
+    };
+    let output = command
+        .arg(prefix_path)
+        .output()
+        .map_err(|e| format!("Failed to unmount {}: {}", prefix_path.display(), e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to unmount {} (is a program still using the prefix?): {}",
+            prefix_path.display(),
+            output_stderr_to_string(&output)
+        ));
+    }
+    Ok(())
+}
+
+/// Grow or shrink an unmounted prefix image to `size` bytes.
+pub fn resize(prefix_path: &Path, size: u64) -> Result<(), String> {
+    let current = status(prefix_path)?;
+    if current.mount_type.is_some() {
+        return Err(format!(
+            "{} is mounted; unmount it before resizing",
+            prefix_path.display()
+        ));
+    }
+    let size = aligned(size);
+    if size < MIN_IMAGE_SIZE {
+        return Err(format!(
+            "Image size must be at least {}",
+            crate::util::format_size(MIN_IMAGE_SIZE)
+        ));
+    }
+    let e2fsck = which("e2fsck").ok_or("e2fsck not found (install e2fsprogs)")?;
+    let resize2fs = which("resize2fs").ok_or("resize2fs not found (install e2fsprogs)")?;
+    let image = current.image;
+
+    // resize2fs insists on a freshly checked filesystem
+    let output = Command::new(e2fsck)
+        .args(["-f", "-p"])
+        .arg(&image)
+        .output()
+        .map_err(|e| format!("Failed to run e2fsck: {}", e))?;
+    // Exit code 1 means errors were corrected
+    if !matches!(output.status.code(), Some(0) | Some(1)) {
+        return Err(format!(
+            "e2fsck found problems in {}: {}",
+            image.display(),
+            output_stderr_to_string(&output)
+        ));
+    }
+
+    let set_len = |len: u64| {
+        OpenOptions::new()
+            .write(true)
+            .open(&image)
+            .and_then(|file| file.set_len(len))
+            .map_err(|e| format!("Failed to resize {}: {}", image.display(), e))
+    };
+    let run_resize2fs = |args: &[String]| -> Result<(), String> {
+        let output = Command::new(&resize2fs)
+            .arg(&image)
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run resize2fs: {}", e))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "resize2fs failed: {}",
+                output_stderr_to_string(&output)
+            ))
+        }
+    };
+    if size >= current.size {
+        set_len(size)?;
+        run_resize2fs(&[])
+    } else {
+        // Shrink the filesystem first; resize2fs refuses if the data won't fit
+        run_resize2fs(&[format!("{}K", size / 1024)])?;
+        set_len(size)
+    }
+}
+
+/// Unmount and delete a prefix's image and its mount point.
+pub fn delete(prefix_path: &Path) -> Result<(), String> {
+    unmount(prefix_path)?;
+    let image = image_path(prefix_path);
+    fs::remove_file(&image).map_err(|e| format!("Failed to delete {}: {}", image.display(), e))?;
+    if prefix_path.exists() {
+        fs::remove_dir(prefix_path)
+            .map_err(|e| format!("Failed to remove {}: {}", prefix_path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Filesystem type mounted on `path`, from /proc/self/mountinfo.
+fn mount_type(path: &Path) -> Option<String> {
+    let path = path.canonicalize().ok()?;
+    mount_entries()
+        .into_iter()
+        .rfind(|entry| entry.mount_point == path)
+        .map(|entry| entry.fs_type)
+}
+
+/// One line of /proc/self/mountinfo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MountEntry {
+    pub mount_point: PathBuf,
+    pub fs_type: String,
+    /// Device, image or dataset mounted, e.g. `pool/games` for ZFS.
+    pub source: String,
+}
+
+/// Current mounts, in mount order.
+pub(crate) fn mount_entries() -> Vec<MountEntry> {
+    fs::read_to_string("/proc/self/mountinfo")
+        .map(|mountinfo| mountinfo.lines().filter_map(parse_mountinfo_line).collect())
+        .unwrap_or_default()
+}
+
+/// The mount `path` lives on: the last-mounted entry with the longest
+/// mount point containing it.
+pub(crate) fn containing_mount(path: &Path) -> Option<MountEntry> {
+    let path = path.canonicalize().ok()?;
+    mount_entries()
+        .into_iter()
+        .filter(|entry| path.starts_with(&entry.mount_point))
+        .fold(None, |best: Option<MountEntry>, entry| match best {
+            Some(best)
+                if best.mount_point.as_os_str().len() > entry.mount_point.as_os_str().len() =>
+            {
+                Some(best)
+            }
+            _ => Some(entry),
+        })
+}
+
+fn parse_mountinfo_line(line: &str) -> Option<MountEntry> {
+    let (mount_fields, fs_fields) = line.split_once(" - ")?;
+    let mount_point = mount_fields.split(' ').nth(4)?;
+    let mut fs_fields = fs_fields.split(' ');
+    let fs_type = fs_fields.next()?;
+    let source = fs_fields.next().unwrap_or("");
+    Some(MountEntry {
+        mount_point: PathBuf::from(unescape_octal(mount_point)),
+        fs_type: fs_type.to_string(),
+        source: unescape_octal(source),
+    })
+}
+
+/// Undo the `\040`-style escapes mountinfo uses for spaces and the like.
+fn unescape_octal(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 4)
+            .filter(|_| bytes[i] == b'\\')
+            .and_then(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 8).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 4;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mountinfo_line() {
+        let line = "36 35 0:40 / /home/me/My\\040Prefix rw,nosuid,nodev - fuse.fuse2fs /home/me/My\\040Prefix.img rw,user_id=1000";
+        assert_eq!(
+            parse_mountinfo_line(line),
+            Some(MountEntry {
+                mount_point: PathBuf::from("/home/me/My Prefix"),
+                fs_type: "fuse.fuse2fs".to_string(),
+                source: "/home/me/My Prefix.img".to_string(),
+            })
+        );
+        assert_eq!(parse_mountinfo_line("garbage"), None);
+        assert_eq!(aligned(1), SIZE_ALIGN);
+        assert_eq!(aligned(SIZE_ALIGN), SIZE_ALIGN);
+    }
+}
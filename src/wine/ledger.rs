@@ -0,0 +1,163 @@
+//! Per-prefix record of applied verbs.
+//!
+//! Every successfully applied verb is appended to `protontool-verbs.log`
+//! inside the prefix (similar to winetricks.log), one tab-separated entry
+//! per line: `verb<TAB>unix-timestamp`, followed by `<TAB>programs` when
+//! the verb's installer added Uninstall entries (names separated by `;`).
+//!
+//! How a failed action of a verb was resolved interactively (retried,
+//! skipped or aborted) is kept in the same file, on lines starting with
+//! `#action`: `#action<TAB>verb<TAB>index<TAB>action<TAB>resolution<TAB>unix-timestamp`.
+//! A skipped action is skipped again without asking when the verb is rerun.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File name of the verb ledger inside a prefix.
+pub const LEDGER_FILE: &str = "protontool-verbs.log";
+
+/// A single applied-verb record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub verb: String,
+    pub timestamp: u64,
+    /// Programs the verb added to the Uninstall registry keys.
+    pub installed: Vec<String>,
+}
+
+/// Path to the ledger file for a prefix.
+pub fn ledger_path(prefix_path: &Path) -> PathBuf {
+    prefix_path.join(LEDGER_FILE)
+}
+
+/// Read all ledger entries for a prefix, oldest first.
+pub fn read_ledger(prefix_path: &Path) -> Vec<LedgerEntry> {
+    let content = match fs::read_to_string(ledger_path(prefix_path)) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split('\t');
+            let verb = fields.next().unwrap_or_default().trim().to_string();
+            let timestamp = fields
+                .next()
+                .and_then(|t| t.trim().parse().ok())
+                .unwrap_or(0);
+            let installed = fields
+                .next()
+                .map(|names| {
+                    names
+                        .split(';')
+                        .map(|n| n.trim().to_string())
+                        .filter(|n| !n.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+            LedgerEntry {
+                verb,
+                timestamp,
+                installed,
+            }
+        })
+        .collect()
+}
+
+/// Names of verbs applied to a prefix, without duplicates, in first-applied order.
+pub fn applied_verbs(prefix_path: &Path) -> Vec<String> {
+    let mut verbs: Vec<String> = Vec::new();
+    for entry in read_ledger(prefix_path) {
+        if !verbs.contains(&entry.verb) {
+            verbs.push(entry.verb);
+        }
+    }
+    verbs
+}
+
+/// Prefix of the lines recording action resolutions.
+const RESOLUTION_PREFIX: &str = "#action";
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Append a line to the ledger, creating it if needed.
+fn append(prefix_path: &Path, line: &str) -> std::io::Result<()> {
+    let path = ledger_path(prefix_path);
+    super::check_writable(&path)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::PermissionDenied, e))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// Record how a failed action of a verb was resolved. `action` is the
+/// action's description, so a changed verb definition doesn't match old
+/// resolutions.
+pub fn record_resolution(
+    prefix_path: &Path,
+    verb: &str,
+    index: usize,
+    action: &str,
+    resolution: &str,
+) -> std::io::Result<()> {
+    append(
+        prefix_path,
+        &format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            RESOLUTION_PREFIX,
+            verb,
+            index,
+            action.replace(['\t', '\n'], " "),
+            resolution,
+            now()
+        ),
+    )
+}
+
+/// The last recorded resolution of a verb's action, if any.
+pub fn action_resolution(
+    prefix_path: &Path,
+    verb: &str,
+    index: usize,
+    action: &str,
+) -> Option<String> {
+    let content = fs::read_to_string(ledger_path(prefix_path)).ok()?;
+    let action = action.replace(['\t', '\n'], " ");
+    let index = index.to_string();
+    content.lines().rev().find_map(|line| {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields.as_slice() {
+            [RESOLUTION_PREFIX, v, i, a, resolution, ..]
+                if *v == verb && *i == index && *a == action =>
+            {
+                Some(resolution.to_string())
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Append a verb to the prefix ledger, with the programs it installed.
+pub fn record_verb(prefix_path: &Path, verb: &str, installed: &[String]) -> std::io::Result<()> {
+    let timestamp = now();
+    if installed.is_empty() {
+        append(prefix_path, &format!("{}\t{}", verb, timestamp))
+    } else {
+        let names: Vec<String> = installed
+            .iter()
+            .map(|name| name.replace(['\t', ';', '\n'], " "))
+            .collect();
+        append(
+            prefix_path,
+            &format!("{}\t{}\t{}", verb, timestamp, names.join(";")),
+        )
+    }
+}
@@ -0,0 +1,57 @@
+//! License (EULA) acknowledgment for verbs installing proprietary software.
+//!
+//! Verbs with an `eula` must have their license accepted once before they
+//! are installed. Acceptances are recorded per EULA (not per verb) in the
+//! state store, so e.g. accepting the core fonts EULA covers every font verb
+//! sharing it.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::verbs::Verb;
+
+/// State record holding accepted EULAs (`eula<TAB>timestamp` lines).
+const EULA_STATE: &str = "eula-accepted";
+
+/// Check whether a EULA has been accepted.
+pub fn is_accepted(eula: &str) -> bool {
+    crate::state::read_lines(EULA_STATE)
+        .iter()
+        .any(|line| line.split('\t').next() == Some(eula))
+}
+
+/// Record acceptance of a EULA.
+pub fn record_acceptance(eula: &str) -> Result<(), String> {
+    if is_accepted(eula) {
+        return Ok(());
+    }
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    crate::state::append_line(EULA_STATE, &format!("{}\t{}", eula, timestamp))
+}
+
+/// Whether a verb still needs its license accepted before installing.
+pub fn needs_acceptance(verb: &Verb) -> bool {
+    verb.eula.as_deref().is_some_and(|eula| !is_accepted(eula))
+}
+
+/// Human-readable license notice shown before installing a verb.
+pub fn license_notice(verb: &Verb) -> String {
+    let mut notice = format!(
+        "{} ({}) is proprietary software",
+        verb.title, verb.publisher
+    );
+    if !verb.license.is_empty() {
+        notice.push_str(&format!(" distributed under the {}", verb.license));
+    }
+    notice.push_str(".\n");
+    if let Some(eula) = &verb.eula {
+        notice.push_str(&format!("\nLicense agreement: {}\n", eula));
+    }
+    if !verb.homepage.is_empty() {
+        notice.push_str(&format!("Homepage: {}\n", verb.homepage));
+    }
+    notice.push_str("\nInstalling it means you accept the license agreement.");
+    notice
+}
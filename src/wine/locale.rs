@@ -0,0 +1,325 @@
+//! Locale and time zone settings of a prefix.
+//!
+//! Windows programs read the time zone from
+//! `HKLM\System\CurrentControlSet\Control\TimeZoneInformation`, which Wine
+//! fills in when the prefix is created. A prefix copied from another
+//! machine, or created inside a container without the host's
+//! `/etc/localtime`, keeps a zone that doesn't match the host, so local
+//! times shown and sent by launchers are off by hours; sign-in tokens and
+//! TLS session checks then fail as if the clock were wrong.
+//!
+//! Host zones (IANA names such as `Europe/Berlin`) are mapped to Windows
+//! zone names with the common entries of the CLDR `windowsZones` table.
+
+use std::path::Path;
+use std::process::Command;
+
+use super::registry::{self, RegistryEditor};
+use super::WineContext;
+
+/// Registry key holding the active time zone.
+pub const TIMEZONE_KEY: &str =
+    "HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Control\\TimeZoneInformation";
+
+/// Registry key with the definition of every Windows time zone.
+const TIME_ZONES_KEY: &str =
+    "HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows NT\\CurrentVersion\\Time Zones";
+
+/// IANA zone to Windows zone name.
+const WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("UTC", "UTC"),
+    ("Etc/UTC", "UTC"),
+    ("Etc/GMT", "UTC"),
+    ("GMT", "UTC"),
+    ("Europe/London", "GMT Standard Time"),
+    ("Europe/Dublin", "GMT Standard Time"),
+    ("Europe/Lisbon", "GMT Standard Time"),
+    ("Atlantic/Reykjavik", "Greenwich Standard Time"),
+    ("Europe/Berlin", "W. Europe Standard Time"),
+    ("Europe/Amsterdam", "W. Europe Standard Time"),
+    ("Europe/Rome", "W. Europe Standard Time"),
+    ("Europe/Stockholm", "W. Europe Standard Time"),
+    ("Europe/Vienna", "W. Europe Standard Time"),
+    ("Europe/Zurich", "W. Europe Standard Time"),
+    ("Europe/Oslo", "W. Europe Standard Time"),
+    ("Europe/Paris", "Romance Standard Time"),
+    ("Europe/Brussels", "Romance Standard Time"),
+    ("Europe/Madrid", "Romance Standard Time"),
+    ("Europe/Copenhagen", "Romance Standard Time"),
+    ("Europe/Prague", "Central Europe Standard Time"),
+    ("Europe/Budapest", "Central Europe Standard Time"),
+    ("Europe/Belgrade", "Central Europe Standard Time"),
+    ("Europe/Warsaw", "Central European Standard Time"),
+    ("Europe/Zagreb", "Central European Standard Time"),
+    ("Europe/Athens", "GTB Standard Time"),
+    ("Europe/Bucharest", "GTB Standard Time"),
+    ("Europe/Helsinki", "FLE Standard Time"),
+    ("Europe/Kiev", "FLE Standard Time"),
+    ("Europe/Kyiv", "FLE Standard Time"),
+    ("Europe/Riga", "FLE Standard Time"),
+    ("Europe/Sofia", "FLE Standard Time"),
+    ("Europe/Tallinn", "FLE Standard Time"),
+    ("Europe/Vilnius", "FLE Standard Time"),
+    ("Europe/Istanbul", "Turkey Standard Time"),
+    ("Europe/Minsk", "Belarus Standard Time"),
+    ("Europe/Moscow", "Russian Standard Time"),
+    ("America/New_York", "Eastern Standard Time"),
+    ("America/Detroit", "Eastern Standard Time"),
+    ("America/Toronto", "Eastern Standard Time"),
+    ("America/Chicago", "Central Standard Time"),
+    ("America/Winnipeg", "Central Standard Time"),
+    ("America/Mexico_City", "Central Standard Time (Mexico)"),
+    ("America/Denver", "Mountain Standard Time"),
+    ("America/Edmonton", "Mountain Standard Time"),
+    ("America/Phoenix", "US Mountain Standard Time"),
+    ("America/Los_Angeles", "Pacific Standard Time"),
+    ("America/Vancouver", "Pacific Standard Time"),
+    ("America/Anchorage", "Alaskan Standard Time"),
+    ("Pacific/Honolulu", "Hawaiian Standard Time"),
+    ("America/Halifax", "Atlantic Standard Time"),
+    ("America/St_Johns", "Newfoundland Standard Time"),
+    ("America/Sao_Paulo", "E. South America Standard Time"),
+    ("America/Argentina/Buenos_Aires", "Argentina Standard Time"),
+    ("America/Santiago", "Pacific SA Standard Time"),
+    ("America/Bogota", "SA Pacific Standard Time"),
+    ("America/Lima", "SA Pacific Standard Time"),
+    ("Africa/Cairo", "Egypt Standard Time"),
+    ("Africa/Johannesburg", "South Africa Standard Time"),
+    ("Africa/Lagos", "W. Central Africa Standard Time"),
+    ("Africa/Nairobi", "E. Africa Standard Time"),
+    ("Asia/Jerusalem", "Israel Standard Time"),
+    ("Asia/Dubai", "Arabian Standard Time"),
+    ("Asia/Riyadh", "Arab Standard Time"),
+    ("Asia/Tehran", "Iran Standard Time"),
+    ("Asia/Karachi", "Pakistan Standard Time"),
+    ("Asia/Kolkata", "India Standard Time"),
+    ("Asia/Calcutta", "India Standard Time"),
+    ("Asia/Dhaka", "Bangladesh Standard Time"),
+    ("Asia/Bangkok", "SE Asia Standard Time"),
+    ("Asia/Jakarta", "SE Asia Standard Time"),
+    ("Asia/Ho_Chi_Minh", "SE Asia Standard Time"),
+    ("Asia/Singapore", "Singapore Standard Time"),
+    ("Asia/Kuala_Lumpur", "Singapore Standard Time"),
+    ("Asia/Manila", "Singapore Standard Time"),
+    ("Asia/Shanghai", "China Standard Time"),
+    ("Asia/Hong_Kong", "China Standard Time"),
+    ("Asia/Taipei", "Taipei Standard Time"),
+    ("Asia/Seoul", "Korea Standard Time"),
+    ("Asia/Tokyo", "Tokyo Standard Time"),
+    ("Australia/Perth", "W. Australia Standard Time"),
+    ("Australia/Adelaide", "Cen. Australia Standard Time"),
+    ("Australia/Darwin", "AUS Central Standard Time"),
+    ("Australia/Brisbane", "E. Australia Standard Time"),
+    ("Australia/Sydney", "AUS Eastern Standard Time"),
+    ("Australia/Melbourne", "AUS Eastern Standard Time"),
+    ("Australia/Hobart", "Tasmania Standard Time"),
+    ("Pacific/Auckland", "New Zealand Standard Time"),
+];
+
+/// Windows zone name for an IANA zone.
+///
+/// ```
+/// use protontool::wine::locale::windows_zone;
+/// assert_eq!(windows_zone("Europe/Berlin"), Some("W. Europe Standard Time"));
+/// assert_eq!(windows_zone("Mars/Olympus_Mons"), None);
+/// ```
+pub fn windows_zone(iana: &str) -> Option<&'static str> {
+    WINDOWS_ZONES
+        .iter()
+        .find(|(name, _)| *name == iana)
+        .map(|(_, windows)| *windows)
+}
+
+/// IANA name of the host's time zone, from `TZ`, `/etc/timezone` or the
+/// `/etc/localtime` link.
+pub fn host_timezone() -> Option<String> {
+    if let Some(tz) = std::env::var("TZ").ok().filter(|tz| !tz.is_empty()) {
+        return Some(tz.trim_start_matches(':').to_string());
+    }
+    if let Ok(content) = std::fs::read_to_string("/etc/timezone") {
+        let tz = content.trim();
+        if !tz.is_empty() {
+            return Some(tz.to_string());
+        }
+    }
+    let target = std::fs::read_link("/etc/localtime").ok()?;
+    let target = target.to_string_lossy();
+    let (_, zone) = target.split_once("zoneinfo/")?;
+    Some(zone.to_string())
+}
+
+/// Current UTC offset of the host in minutes (east of UTC is positive).
+pub fn host_utc_offset() -> Option<i32> {
+    let output = Command::new("date").arg("+%z").output().ok()?;
+    parse_utc_offset(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+/// Parse a `+HHMM`/`-HHMM` offset into minutes.
+fn parse_utc_offset(offset: &str) -> Option<i32> {
+    let (sign, digits) = match offset.strip_prefix('+') {
+        Some(digits) => (1, digits),
+        None => (-1, offset.strip_prefix('-')?),
+    };
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = digits[2..].parse().ok()?;
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// Time zone settings of a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixTimeZone {
+    /// Windows zone name, e.g. `W. Europe Standard Time`.
+    pub key_name: Option<String>,
+    /// Minutes to add to local time to get UTC, including daylight saving.
+    pub active_bias: Option<i32>,
+}
+
+impl PrefixTimeZone {
+    /// Current UTC offset in minutes, the way `host_utc_offset` reports it.
+    pub fn utc_offset(&self) -> Option<i32> {
+        self.active_bias.map(|bias| -bias)
+    }
+}
+
+fn parse_dword(data: &str) -> Option<u32> {
+    u32::from_str_radix(data.strip_prefix("dword:")?, 16).ok()
+}
+
+fn parse_string(data: &str) -> Option<String> {
+    let s = data.strip_prefix('"')?.strip_suffix('"')?;
+    Some(s.replace("\\\\", "\\"))
+}
+
+/// Read the prefix's time zone from its registry files without running
+/// Wine.
+pub fn prefix_timezone(prefix_path: &Path) -> PrefixTimeZone {
+    let value = |name: &str| registry::read_prefix_value(prefix_path, TIMEZONE_KEY, name);
+    PrefixTimeZone {
+        key_name: value("TimeZoneKeyName").and_then(|d| parse_string(&d)),
+        active_bias: value("ActiveTimeBias")
+            .and_then(|d| parse_dword(&d))
+            .map(|bias| bias as i32),
+    }
+}
+
+/// Biases and transition dates of a zone, from its `TZI` value: three
+/// LONGs (bias, standard bias, daylight bias) and two SYSTEMTIMEs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ZoneInfo {
+    bias: i32,
+    standard_bias: i32,
+    daylight_bias: i32,
+    standard_start: Vec<u8>,
+    daylight_start: Vec<u8>,
+}
+
+fn parse_tzi(data: &str) -> Option<ZoneInfo> {
+    let bytes: Vec<u8> = data
+        .strip_prefix("hex:")?
+        .split(',')
+        .map(|b| u8::from_str_radix(b.trim(), 16).ok())
+        .collect::<Option<_>>()?;
+    if bytes.len() != 44 {
+        return None;
+    }
+    let long = |i: usize| i32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+    Some(ZoneInfo {
+        bias: long(0),
+        standard_bias: long(1),
+        daylight_bias: long(2),
+        standard_start: bytes[12..28].to_vec(),
+        daylight_start: bytes[28..44].to_vec(),
+    })
+}
+
+fn dword(value: i32) -> String {
+    format!("dword:{:08x}", value as u32)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let hex: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("hex:{}", hex.join(","))
+}
+
+/// Set the prefix's time zone to a Windows zone. Names and daylight saving
+/// rules come from the zone's definition in the prefix; `utc_offset` (in
+/// minutes, as from `host_utc_offset`) sets the currently active bias.
+pub fn set_timezone(wine_ctx: &WineContext, zone: &str, utc_offset: i32) -> Result<(), String> {
+    let zone_key = format!("{}\\{}", TIME_ZONES_KEY, zone);
+    let zone_value =
+        |name: &str| registry::read_prefix_value(&wine_ctx.prefix_path, &zone_key, name);
+    let standard_name = zone_value("Std")
+        .and_then(|d| parse_string(&d))
+        .unwrap_or_else(|| zone.to_string());
+    let daylight_name = zone_value("Dlt")
+        .and_then(|d| parse_string(&d))
+        .unwrap_or_else(|| zone.replace("Standard", "Daylight"));
+    let info = zone_value("TZI")
+        .and_then(|d| parse_tzi(&d))
+        .unwrap_or(ZoneInfo {
+            bias: -utc_offset,
+            standard_bias: 0,
+            daylight_bias: 0,
+            standard_start: vec![0; 16],
+            daylight_start: vec![0; 16],
+        });
+
+    let content = format!(
+        "Windows Registry Editor Version 5.00\n\n[{}]\n\
+         \"TimeZoneKeyName\"={}\n\
+         \"StandardName\"={}\n\
+         \"DaylightName\"={}\n\
+         \"Bias\"={}\n\
+         \"StandardBias\"={}\n\
+         \"DaylightBias\"={}\n\
+         \"ActiveTimeBias\"={}\n\
+         \"StandardStart\"={}\n\
+         \"DaylightStart\"={}\n",
+        TIMEZONE_KEY,
+        registry::RegType::String.format_value(zone),
+        registry::RegType::String.format_value(&standard_name),
+        registry::RegType::String.format_value(&daylight_name),
+        dword(info.bias),
+        dword(info.standard_bias),
+        dword(info.daylight_bias),
+        dword(-utc_offset),
+        hex(&info.standard_start),
+        hex(&info.daylight_start),
+    );
+    let work_dir = crate::workdir::WorkDir::create("timezone")?;
+    let reg_file = work_dir.file("timezone", "reg");
+    std::fs::write(&reg_file, content)
+        .map_err(|e| format!("Failed to write {}: {}", reg_file.display(), e))?;
+    RegistryEditor::new(wine_ctx).apply_reg_file(&reg_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_zone_settings() {
+        assert_eq!(parse_utc_offset("+0200"), Some(120));
+        assert_eq!(parse_utc_offset("-0330"), Some(-210));
+        assert_eq!(parse_utc_offset("0200"), None);
+
+        // W. Europe Standard Time: bias -60, daylight bias -60, last Sunday
+        // of October and March
+        let tzi = "hex:c4,ff,ff,ff,00,00,00,00,c4,ff,ff,ff,00,00,0a,00,00,00,05,00,03,\
+                   00,00,00,00,00,00,00,00,00,03,00,00,00,05,00,02,00,00,00,00,00,00,00";
+        let info = parse_tzi(tzi).unwrap();
+        assert_eq!(info.bias, -60);
+        assert_eq!(info.daylight_bias, -60);
+        assert_eq!(info.standard_start[2], 10);
+        assert_eq!(dword(-120), "dword:ffffff88");
+
+        let zone = PrefixTimeZone {
+            key_name: Some("UTC".to_string()),
+            active_bias: Some(-120),
+        };
+        assert_eq!(zone.utc_offset(), Some(120));
+    }
+}
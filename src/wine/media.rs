@@ -0,0 +1,379 @@
+//! GStreamer and media playback checks for `doctor` and log analysis.
+//!
+//! Wine plays videos and audio (Media Foundation, DirectShow, XAudio's WMA
+//! decoding) through winegstreamer, which needs GStreamer plugins for the
+//! containers and codecs a game uses. Proton bundles its own GStreamer;
+//! other Wine builds use the host's, where the "good", "ugly" and libav
+//! plugin sets are often not installed, or only for 64-bit. Older Proton
+//! versions also play pre-transcoded videos from the media converter cache
+//! Steam downloads into the game's shader cache; without it, videos show a
+//! placeholder pattern.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::WineContext;
+use crate::distro::{self, Family};
+use crate::doctor::{Finding, Severity};
+
+/// A GStreamer plugin set, packaged separately by distributions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginSet {
+    Base,
+    Good,
+    Bad,
+    Ugly,
+    Libav,
+}
+
+impl PluginSet {
+    pub fn all() -> &'static [PluginSet] {
+        &[
+            PluginSet::Base,
+            PluginSet::Good,
+            PluginSet::Bad,
+            PluginSet::Ugly,
+            PluginSet::Libav,
+        ]
+    }
+
+    /// Plugin files that show the set is installed.
+    fn plugin_files(&self) -> &'static [&'static str] {
+        match self {
+            PluginSet::Base => &[
+                "libgstapp.so",
+                "libgstplayback.so",
+                "libgstaudioconvert.so",
+                "libgsttypefindfunctions.so",
+            ],
+            PluginSet::Good => &["libgstisomp4.so", "libgstmatroska.so", "libgstwavparse.so"],
+            PluginSet::Bad => &["libgstvideoparsersbad.so"],
+            PluginSet::Ugly => &["libgstasf.so"],
+            PluginSet::Libav => &["libgstlibav.so"],
+        }
+    }
+
+    /// What playback needs the set for.
+    pub fn purpose(&self) -> &'static str {
+        match self {
+            PluginSet::Base => "all media playback",
+            PluginSet::Good => "MP4, Matroska and WAV files",
+            PluginSet::Bad => "H.264 and HEVC stream parsing",
+            PluginSet::Ugly => "ASF/WMV files",
+            PluginSet::Libav => "H.264, WMV and WMA decoding",
+        }
+    }
+
+    /// Whether games play videos or audio without the set at all.
+    fn is_essential(&self) -> bool {
+        matches!(self, PluginSet::Base | PluginSet::Good)
+    }
+
+    /// The distribution package, for 64-bit or 32-bit (where packaged).
+    pub fn package(&self, family: Family, bits32: bool) -> Option<String> {
+        let name = match (family, self) {
+            (Family::Arch, PluginSet::Libav) => "gst-libav",
+            (Family::Arch, _) => match self {
+                PluginSet::Base => "gst-plugins-base",
+                PluginSet::Good => "gst-plugins-good",
+                PluginSet::Bad => "gst-plugins-bad",
+                _ => "gst-plugins-ugly",
+            },
+            (Family::Debian, PluginSet::Libav) => "gstreamer1.0-libav",
+            (Family::Debian, _) => match self {
+                PluginSet::Base => "gstreamer1.0-plugins-base",
+                PluginSet::Good => "gstreamer1.0-plugins-good",
+                PluginSet::Bad => "gstreamer1.0-plugins-bad",
+                _ => "gstreamer1.0-plugins-ugly",
+            },
+            (Family::Fedora, PluginSet::Libav) => "gstreamer1-plugin-libav",
+            (Family::Fedora, _) => match self {
+                PluginSet::Base => "gstreamer1-plugins-base",
+                PluginSet::Good => "gstreamer1-plugins-good",
+                PluginSet::Bad => "gstreamer1-plugins-bad-free",
+                _ => "gstreamer1-plugins-ugly-free",
+            },
+            (Family::Suse, PluginSet::Libav) => "gstreamer-plugins-libav",
+            (Family::Suse, _) => match self {
+                PluginSet::Base => "gstreamer-plugins-base",
+                PluginSet::Good => "gstreamer-plugins-good",
+                PluginSet::Bad => "gstreamer-plugins-bad",
+                _ => "gstreamer-plugins-ugly",
+            },
+        };
+        if !bits32 {
+            return Some(name.to_string());
+        }
+        match family {
+            // Arch only packages base and good for multilib
+            Family::Arch => self.is_essential().then(|| format!("lib32-{}", name)),
+            Family::Debian => Some(format!("{}:i386", name)),
+            Family::Fedora => Some(format!("{}.i686", name)),
+            Family::Suse => Some(format!("{}-32bit", name)),
+        }
+    }
+
+    /// Name for messages when the distribution is unknown.
+    pub fn generic_name(&self) -> &'static str {
+        match self {
+            PluginSet::Base => "gst-plugins-base",
+            PluginSet::Good => "gst-plugins-good",
+            PluginSet::Bad => "gst-plugins-bad",
+            PluginSet::Ugly => "gst-plugins-ugly",
+            PluginSet::Libav => "gst-libav",
+        }
+    }
+}
+
+/// Host GStreamer plugin directories, 64-bit first. Where `/usr/lib64` is
+/// a real directory (Fedora, openSUSE), `/usr/lib` holds 32-bit plugins.
+pub fn host_plugin_dirs() -> (Option<PathBuf>, Option<PathBuf>) {
+    let existing = |dirs: &[&str]| {
+        dirs.iter()
+            .map(|dir| Path::new(dir).join("gstreamer-1.0"))
+            .find(|dir| dir.is_dir())
+            .and_then(|dir| fs::canonicalize(dir).ok())
+    };
+    let dirs64 = existing(&["/usr/lib/x86_64-linux-gnu", "/usr/lib64", "/usr/lib"]);
+    let dirs32 = existing(&["/usr/lib/i386-linux-gnu", "/usr/lib32", "/usr/lib"])
+        .filter(|dir| Some(dir) != dirs64.as_ref());
+    (dirs64, dirs32)
+}
+
+/// Plugin sets missing from a plugin directory.
+pub fn missing_sets(plugin_dir: &Path) -> Vec<PluginSet> {
+    PluginSet::all()
+        .iter()
+        .copied()
+        .filter(|set| {
+            set.plugin_files()
+                .iter()
+                .any(|file| !plugin_dir.join(file).exists())
+        })
+        .collect()
+}
+
+/// GStreamer plugin directory bundled with a Proton or Wine build, if any.
+pub fn bundled_plugin_dir(proton_path: &Path) -> Option<PathBuf> {
+    [
+        "files/lib64/gstreamer-1.0",
+        "files/lib/x86_64-linux-gnu/gstreamer-1.0",
+        "dist/lib64/gstreamer-1.0",
+        "lib64/gstreamer-1.0",
+    ]
+    .iter()
+    .map(|dir| proton_path.join(dir))
+    .find(|dir| dir.is_dir())
+}
+
+/// Distribution packages of missing plugin sets.
+fn packages(family: Family, missing64: &[PluginSet], missing32: &[PluginSet]) -> Vec<String> {
+    missing64
+        .iter()
+        .filter_map(|set| set.package(family, false))
+        .chain(missing32.iter().filter_map(|set| set.package(family, true)))
+        .collect()
+}
+
+/// The command (or package names, for an unknown distribution) installing
+/// plugin sets.
+pub fn install_hint(missing64: &[PluginSet], missing32: &[PluginSet]) -> String {
+    if let Some(family) = distro::detect().family {
+        let packages = packages(family, missing64, missing32);
+        if !packages.is_empty() {
+            let packages: Vec<&str> = packages.iter().map(String::as_str).collect();
+            return family.install_command(&packages);
+        }
+    }
+    let mut names: Vec<String> = missing64
+        .iter()
+        .map(|set| set.generic_name().to_string())
+        .collect();
+    names.extend(
+        missing32
+            .iter()
+            .map(|set| format!("{} (32-bit)", set.generic_name())),
+    );
+    format!("install {} with your package manager", names.join(", "))
+}
+
+/// Missing host plugin sets, 64-bit and 32-bit. A missing directory
+/// counts as every set missing for 64-bit, and is ignored for 32-bit.
+fn host_missing() -> (Vec<PluginSet>, Vec<PluginSet>) {
+    let (dir64, dir32) = host_plugin_dirs();
+    let missing64 = match dir64 {
+        Some(dir) => missing_sets(&dir),
+        None => PluginSet::all().to_vec(),
+    };
+    let missing32 = dir32.map(|dir| missing_sets(&dir)).unwrap_or_default();
+    (missing64, missing32)
+}
+
+fn finding(severity: Severity, message: String) -> Finding {
+    Finding {
+        check: "media",
+        severity,
+        message,
+        fix: None,
+    }
+}
+
+/// Check the GStreamer plugins the prefix's Wine plays media with, and the
+/// media converter cache of a Steam game.
+pub fn check(wine_ctx: &WineContext) -> Vec<Finding> {
+    let mut findings = vec![check_plugins(wine_ctx)];
+    findings.extend(check_converter_cache(&wine_ctx.prefix_path));
+    findings
+}
+
+fn check_plugins(wine_ctx: &WineContext) -> Finding {
+    if let Some(dir) = bundled_plugin_dir(&wine_ctx.proton_path) {
+        return finding(
+            Severity::Ok,
+            format!("Proton bundles GStreamer ({})", dir.display()),
+        );
+    }
+
+    let (missing64, missing32) = host_missing();
+    if missing64.is_empty() && missing32.is_empty() {
+        return finding(
+            Severity::Ok,
+            "Host GStreamer plugins for media playback are installed".to_string(),
+        );
+    }
+    let severity = if missing64.iter().any(PluginSet::is_essential) {
+        Severity::Problem
+    } else {
+        Severity::Warning
+    };
+    let mut lines: Vec<String> = missing64
+        .iter()
+        .map(|set| format!("{} (needed for {})", set.generic_name(), set.purpose()))
+        .collect();
+    lines.extend(missing32.iter().map(|set| {
+        format!(
+            "32-bit {} (needed for {} in 32-bit games)",
+            set.generic_name(),
+            set.purpose()
+        )
+    }));
+    let family = distro::detect().family;
+    Finding {
+        fix: crate::doctor::install_fix(
+            family,
+            family.map_or_else(Vec::new, |f| packages(f, &missing64, &missing32)),
+        ),
+        ..finding(
+            severity,
+            format!(
+                "Missing host GStreamer plugins; videos and music may not play:\n  {}\n\
+                 Fix with: {}",
+                lines.join("\n  "),
+                install_hint(&missing64, &missing32)
+            ),
+        )
+    }
+}
+
+/// The Steam library `steamapps` directory and app ID of a Steam prefix
+/// (`steamapps/compatdata/APPID/pfx`).
+fn steam_app_of(prefix_path: &Path) -> Option<(&Path, &str)> {
+    let app_dir = prefix_path.ancestors().find(|dir| {
+        dir.parent()
+            .and_then(Path::file_name)
+            .is_some_and(|name| name == "compatdata")
+    })?;
+    let steamapps = app_dir.parent()?.parent()?;
+    Some((steamapps, app_dir.file_name()?.to_str()?))
+}
+
+/// State of Proton's media converter cache (`fozmediav1` in the game's
+/// shader cache) for a Steam prefix.
+fn check_converter_cache(prefix_path: &Path) -> Option<Finding> {
+    let (steamapps, appid) = steam_app_of(prefix_path)?;
+    let cache = steamapps.join("shadercache").join(appid).join("fozmediav1");
+    let Ok(entries) = fs::read_dir(&cache) else {
+        return Some(finding(
+            Severity::Ok,
+            "No Proton media converter cache for this game (only older Proton versions \
+             and games with videos use one)"
+                .to_string(),
+        ));
+    };
+    let sizes: Vec<u64> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "foz"))
+        .filter_map(|entry| entry.metadata().ok().map(|m| m.len()))
+        .collect();
+    let total: u64 = sizes.iter().sum();
+    Some(if total == 0 {
+        finding(
+            Severity::Warning,
+            format!(
+                "The media converter cache {} is empty; videos may show a placeholder. \
+                 Enable shader pre-caching in Steam's settings so Steam downloads the \
+                 transcoded videos",
+                cache.display()
+            ),
+        )
+    } else {
+        finding(
+            Severity::Ok,
+            format!(
+                "Media converter cache: {} file(s), {:.1} MB",
+                sizes.len(),
+                total as f64 / (1024.0 * 1024.0)
+            ),
+        )
+    })
+}
+
+/// Advice for media errors found in a log: what to install, or that the
+/// plugins look complete.
+pub fn log_hint() -> String {
+    let (missing64, missing32) = host_missing();
+    if missing64.is_empty() && missing32.is_empty() {
+        return "Media playback errors: the host GStreamer plugins look complete; \
+                with Proton, videos may need the media converter cache (run 'doctor')."
+            .to_string();
+    }
+    format!(
+        "Media playback errors: GStreamer plugins are missing. Fix with: {}",
+        install_hint(&missing64, &missing32)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_sets_and_packages() {
+        let dir = std::env::temp_dir().join(format!("pt-gst-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in PluginSet::Base
+            .plugin_files()
+            .iter()
+            .chain(PluginSet::Libav.plugin_files())
+        {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        fs::write(dir.join("libgstisomp4.so"), "").unwrap();
+        let missing = missing_sets(&dir);
+        fs::remove_dir_all(&dir).ok();
+        assert_eq!(
+            missing,
+            vec![PluginSet::Good, PluginSet::Bad, PluginSet::Ugly]
+        );
+
+        assert_eq!(
+            PluginSet::Good.package(Family::Debian, true).as_deref(),
+            Some("gstreamer1.0-plugins-good:i386")
+        );
+        assert_eq!(PluginSet::Ugly.package(Family::Arch, true), None);
+        assert_eq!(
+            PluginSet::Libav.package(Family::Fedora, false).as_deref(),
+            Some("gstreamer1-plugin-libav")
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Prefix metadata.
+//!
+//! protontool keeps per-prefix settings in `.protontool` inside the prefix,
+//! one `key=value` per line (`proton_name`, `proton_path`, `arch`,
+//! `created`, `sandboxed`, ...). Unknown keys are preserved when saving.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the metadata file inside a prefix.
+pub const METADATA_FILE: &str = ".protontool";
+
+/// Key/value settings of a prefix, in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixMetadata {
+    pub entries: Vec<(String, String)>,
+}
+
+/// Path to the metadata file of a prefix.
+pub fn metadata_path(prefix_path: &Path) -> PathBuf {
+    prefix_path.join(METADATA_FILE)
+}
+
+impl PrefixMetadata {
+    /// Parse metadata file content.
+    pub fn parse(content: &str) -> Self {
+        let mut metadata = PrefixMetadata::default();
+        for line in content.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                metadata.set(key.trim(), value.trim());
+            }
+        }
+        metadata
+    }
+
+    /// Load the metadata of a prefix. A missing file is empty metadata.
+    pub fn load(prefix_path: &Path) -> Self {
+        fs::read_to_string(metadata_path(prefix_path))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Write the metadata to a prefix.
+    pub fn save(&self, prefix_path: &Path) -> Result<(), String> {
+        let path = metadata_path(prefix_path);
+        super::check_writable(&path)?;
+        let content: String = self
+            .entries
+            .iter()
+            .map(|(key, value)| format!("{}={}\n", key, value))
+            .collect();
+        fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Get a value.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Get a boolean value (`true`/`1`/`yes`). Missing keys are false.
+    pub fn get_bool(&self, key: &str) -> bool {
+        self.get(key)
+            .is_some_and(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"))
+    }
+
+    /// Set a value, replacing any existing one.
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.entries.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => self.entries.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Remove a value. Returns true if it was set.
+    pub fn unset(&mut self, key: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|(k, _)| k != key);
+        self.entries.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metadata() {
+        let mut metadata =
+            PrefixMetadata::parse("proton_name=Proton 9.0\narch=win64\nsandboxed=true\n");
+        assert_eq!(metadata.get("proton_name"), Some("Proton 9.0"));
+        assert!(metadata.get_bool("sandboxed"));
+        assert!(!metadata.get_bool("missing"));
+
+        metadata.set("sandboxed", "false");
+        metadata.set("winedebug", "-all");
+        assert_eq!(
+            metadata.entries.last(),
+            Some(&("winedebug".to_string(), "-all".to_string()))
+        );
+        assert!(!metadata.get_bool("sandboxed"));
+    }
+}
@@ -0,0 +1,223 @@
+//! Mirror health and fastest-mirror selection.
+//!
+//! When a file can be downloaded from several URLs, each mirror's host is
+//! probed for its time to first byte and the mirrors are tried fastest
+//! first. Results are remembered in the `mirrors` state record for an hour,
+//! so one session probes each host once. Mirrors that fail a probe or a
+//! download are tried last.
+
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// State record holding probe results: `host<TAB>millis-or-fail<TAB>timestamp`.
+const MIRROR_STATE: &str = "mirrors";
+
+/// How long a probe result is trusted.
+const PROBE_TTL_SECS: u64 = 60 * 60;
+
+/// Give up on a probe after this long.
+const PROBE_TIMEOUT_SECS: &str = "5";
+
+/// Health of a mirror host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorHealth {
+    /// Time to the first byte of a response.
+    FirstByte(Duration),
+    Failed,
+}
+
+impl MirrorHealth {
+    /// Sort key: fastest first, failed last.
+    fn rank(&self) -> u128 {
+        match self {
+            MirrorHealth::FirstByte(latency) => latency.as_millis(),
+            MirrorHealth::Failed => u128::MAX,
+        }
+    }
+}
+
+/// Host part of a URL.
+///
+/// ```
+/// use protontool::wine::mirrors::host_of;
+/// assert_eq!(host_of("https://download.microsoft.com/download/x.exe"), "download.microsoft.com");
+/// assert_eq!(host_of("https://user@example.org:8443"), "example.org:8443");
+/// ```
+pub fn host_of(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    authority.rsplit('@').next().unwrap_or(authority)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Parse the state record into (host, health, timestamp) entries.
+fn parse_records(lines: &[String]) -> Vec<(String, MirrorHealth, u64)> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let host = fields.next()?.to_string();
+            let health = match fields.next()? {
+                "fail" => MirrorHealth::Failed,
+                millis => MirrorHealth::FirstByte(Duration::from_millis(millis.parse().ok()?)),
+            };
+            let timestamp = fields.next()?.parse().ok()?;
+            Some((host, health, timestamp))
+        })
+        .collect()
+}
+
+/// Remembered health of a host, if probed within the last hour.
+fn cached_health(
+    records: &[(String, MirrorHealth, u64)],
+    host: &str,
+    now: u64,
+) -> Option<MirrorHealth> {
+    records
+        .iter()
+        .rev()
+        .find(|(h, _, timestamp)| h == host && now.saturating_sub(*timestamp) < PROBE_TTL_SECS)
+        .map(|(_, health, _)| *health)
+}
+
+/// Remember the health of a host, dropping expired records.
+pub fn record_health(host: &str, health: MirrorHealth) {
+    let now = now_secs();
+    let mut records: Vec<(String, MirrorHealth, u64)> =
+        parse_records(&crate::state::read_lines(MIRROR_STATE))
+            .into_iter()
+            .filter(|(h, _, timestamp)| {
+                h != host && now.saturating_sub(*timestamp) < PROBE_TTL_SECS
+            })
+            .collect();
+    records.push((host.to_string(), health, now));
+
+    let content: String = records
+        .iter()
+        .map(|(host, health, timestamp)| {
+            let value = match health {
+                MirrorHealth::FirstByte(latency) => latency.as_millis().to_string(),
+                MirrorHealth::Failed => "fail".to_string(),
+            };
+            format!("{}\t{}\t{}\n", host, value, timestamp)
+        })
+        .collect();
+    if let Err(e) = crate::state::write(MIRROR_STATE, &content) {
+        crate::log::warn(&format!("Failed to save mirror health: {}", e));
+    }
+}
+
+/// Measure the time to the first byte of a URL with curl, requesting a
+/// single byte. None when curl isn't available.
+fn probe(url: &str) -> Option<MirrorHealth> {
+    let curl = crate::util::which("curl")?;
+    let output = Command::new(curl)
+        .args([
+            "-sS",
+            "-L",
+            "-o",
+            "/dev/null",
+            "-r",
+            "0-0",
+            "--max-time",
+            PROBE_TIMEOUT_SECS,
+            "-w",
+            "%{time_starttransfer}",
+            url,
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return Some(MirrorHealth::Failed);
+    }
+    let seconds: f64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(MirrorHealth::FirstByte(Duration::from_secs_f64(seconds)))
+}
+
+/// Order mirrors by the health of their hosts, fastest first. Hosts without
+/// a recent result are probed in parallel; mirrors that can't be measured
+/// keep their place after the measured ones.
+pub fn order_by_health(urls: &[String]) -> Vec<String> {
+    if urls.len() < 2 {
+        return urls.to_vec();
+    }
+    let now = now_secs();
+    let records = parse_records(&crate::state::read_lines(MIRROR_STATE));
+
+    let cached: Vec<Option<MirrorHealth>> = urls
+        .iter()
+        .map(|url| cached_health(&records, host_of(url), now))
+        .collect();
+    let probed: Vec<Option<MirrorHealth>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = urls
+            .iter()
+            .zip(&cached)
+            .map(|(url, cached)| {
+                scope.spawn(move || cached.is_none().then(|| probe(url)).flatten())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().ok().flatten())
+            .collect()
+    });
+    for (url, health) in urls.iter().zip(&probed) {
+        if let Some(health) = health {
+            record_health(host_of(url), *health);
+        }
+    }
+    let health: Vec<Option<MirrorHealth>> = cached
+        .iter()
+        .zip(&probed)
+        .map(|(cached, probed)| cached.or(*probed))
+        .collect();
+
+    let mut ranked: Vec<(u128, &String)> = urls
+        .iter()
+        .zip(&health)
+        .map(|(url, health)| (health.map_or(u128::MAX - 1, |h| h.rank()), url))
+        .collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+    ranked.into_iter().map(|(_, url)| url.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_health() {
+        let lines = vec![
+            "cdn.example.org\t120\t1000".to_string(),
+            "mirror.example.net\tfail\t1000".to_string(),
+            "cdn.example.org\t80\t2000".to_string(),
+            "broken line".to_string(),
+        ];
+        let records = parse_records(&lines);
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            cached_health(&records, "cdn.example.org", 2500),
+            Some(MirrorHealth::FirstByte(Duration::from_millis(80)))
+        );
+        assert_eq!(
+            cached_health(&records, "mirror.example.net", 2500),
+            Some(MirrorHealth::Failed)
+        );
+        assert_eq!(
+            cached_health(&records, "cdn.example.org", 2000 + PROBE_TTL_SECS),
+            None
+        );
+        assert!(
+            MirrorHealth::Failed.rank() > MirrorHealth::FirstByte(Duration::from_secs(5)).rank()
+        );
+    }
+}
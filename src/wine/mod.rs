@@ -3,26 +3,171 @@
 //! Provides WineContext for running Wine commands, verb execution,
 //! and utilities for managing Wine prefixes.
 
+pub mod access;
+pub mod adopt;
+pub mod certs;
+pub mod cleanup;
+pub mod compare;
 pub mod custom;
+pub mod deferred;
+pub mod discovery;
 pub mod download;
+pub mod drives;
+pub mod dxvk_conf;
+pub mod filesystem;
+pub mod graphics;
+pub mod image;
+pub mod ledger;
+pub mod license;
+pub mod locale;
+pub mod media;
+pub mod metadata;
+pub mod mirrors;
+pub mod mono;
+pub mod mounts;
+pub mod overrides;
+pub mod ownership;
+pub mod plugin;
 pub mod prefix;
+pub mod preflight;
+pub mod profile;
+pub mod protonfixes;
+pub mod provision;
 pub mod registry;
+pub mod runner;
+pub mod sandbox;
+pub mod scheduling;
+pub mod services;
+pub mod snapshot;
+pub mod stats;
+pub mod template;
+pub mod uninstall;
+pub mod usage;
+pub mod userdirs;
 pub mod util;
 pub mod verbs;
+pub mod vkd3d;
+pub mod wasm;
+pub mod winedebug;
+pub mod workshop;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Output};
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::log as ptlog;
 use crate::steam::ProtonApp;
 pub use verbs::{Verb, VerbCategory, VerbRegistry};
 
+/// Whether the output of wine commands is streamed to the console.
+static SHOW_WINE_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Stream the output of wine commands (installers, verbs) to the console as
+/// it's produced (`--show-wine-output`). By default it only goes to the log
+/// while a spinner shows progress, and failures are summarized.
+pub fn set_show_wine_output(show: bool) {
+    SHOW_WINE_OUTPUT.store(show, Ordering::SeqCst);
+}
+
+/// Check whether wine output is streamed to the console.
+pub fn is_wine_output_shown() -> bool {
+    SHOW_WINE_OUTPUT.load(Ordering::SeqCst)
+}
+
+/// Whether prefixes may be changed and Wine started (`--read-only` clears it).
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Refuse everything that could change a prefix (`--read-only`): starting
+/// Wine or a wineserver, and writes through protontool's prefix writers
+/// (registry hives, the environment profile, the verb ledger, metadata and
+/// `dxvk.conf`). For inspecting backups and other users' prefixes.
+pub fn set_read_only(read_only: bool) {
+    READ_ONLY.store(read_only, Ordering::SeqCst);
+}
+
+/// Check whether read-only mode is on.
+pub fn is_read_only() -> bool {
+    READ_ONLY.load(Ordering::SeqCst)
+}
+
+/// Fail if a prefix file may not be written because read-only mode is on.
+pub fn check_writable(path: &Path) -> Result<(), String> {
+    if is_read_only() {
+        return Err(format!(
+            "Not writing {}: protontool is running with --read-only",
+            path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Fail if Wine may not be started because read-only mode is on.
+fn check_may_run(program: &str) -> std::io::Result<()> {
+    if is_read_only() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!(
+                "Not starting {}: protontool is running with --read-only",
+                program
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `config.toml` asks for wine output by default
+/// (`show_wine_output` in the `[output]` section).
+pub fn wine_output_shown_by_config(config: &crate::config::UserConfig) -> bool {
+    config
+        .get("output", "show_wine_output")
+        .is_some_and(|v| matches!(v, "true" | "1" | "yes"))
+}
+
+/// Copy everything from `source` to `sink` as it arrives, returning a copy.
+fn tee(source: Option<impl Read>, mut sink: impl Write) -> Vec<u8> {
+    let mut collected = Vec::new();
+    let mut source = match source {
+        Some(source) => source,
+        None => return collected,
+    };
+    let mut buf = [0u8; 4096];
+    loop {
+        match source.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                sink.write_all(&buf[..n]).ok();
+                sink.flush().ok();
+                collected.extend_from_slice(&buf[..n]);
+            }
+        }
+    }
+    collected
+}
+
+/// Run a command, streaming its output to the console while collecting it.
+fn output_streamed(cmd: &mut Command) -> std::io::Result<Output> {
+    let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    let stderr = child.stderr.take();
+    let stderr_thread = std::thread::spawn(move || tee(stderr, std::io::stderr()));
+    let stdout = tee(child.stdout.take(), std::io::stdout());
+    let status = child.wait()?;
+    Ok(Output {
+        status,
+        stdout,
+        stderr: stderr_thread.join().unwrap_or_default(),
+    })
+}
+
 /// High-level Wine interface combining context, cache, and verb registry.
 pub struct Wine {
     pub wine_ctx: WineContext,
     pub cache_dir: PathBuf,
     pub verb_registry: VerbRegistry,
+    /// Measurements of the verbs run since the last [`Wine::take_stats`].
+    stats: RefCell<Vec<stats::VerbStats>>,
 }
 
 impl Wine {
@@ -42,23 +187,58 @@ impl Wine {
         let cache_dir = crate::config::get_cache_dir().join("wine");
         std::fs::create_dir_all(&cache_dir).ok();
 
-        let verb_registry = VerbRegistry::new();
+        let mut verb_registry = VerbRegistry::new();
+        verb_registry
+            .add_runner_settings(&runner::RunnerCapabilities::probe(&proton_app.install_path));
 
         Self {
             wine_ctx,
             cache_dir,
             verb_registry,
+            stats: RefCell::new(Vec::new()),
         }
     }
 
-    /// Execute a verb by name.
+    /// Execute a verb by name, running any verbs it depends on first.
+    ///
+    /// DLL and application verbs are preceded by an automatic snapshot; when
+    /// one fails, `--rollback-last` is suggested.
     pub fn run_verb(&self, verb_name: &str) -> Result<(), String> {
-        let verb = self
+        let component = self
             .verb_registry
             .get(verb_name)
-            .ok_or_else(|| format!("Unknown verb: {}", verb_name))?;
+            .is_some_and(|verb| matches!(verb.category, VerbCategory::Dll | VerbCategory::App));
+        let safety = if component {
+            let policy = snapshot::SafetyPolicy::from_config(&crate::config::UserConfig::load());
+            snapshot::safety_snapshot(&self.wine_ctx.prefix_path, verb_name, &policy)
+                .unwrap_or_else(|e| {
+                    ptlog::warn(&format!("Could not snapshot the prefix: {}", e));
+                    None
+                })
+        } else {
+            None
+        };
 
-        verb.execute(&self.wine_ctx, &self.cache_dir)
+        let (result, measured) = stats::VerbStats::measure(verb_name, || {
+            self.verb_registry
+                .execute(verb_name, &self.wine_ctx, &self.cache_dir)
+        });
+        if let Err(e) = stats::record(&measured) {
+            ptlog::warn(&format!("Could not record verb statistics: {}", e));
+        }
+        self.stats.borrow_mut().push(measured);
+        if let (Err(_), Some(snapshot)) = (&result, &safety) {
+            eprintln!(
+                "The prefix was snapshotted as '{}' before {}; undo with --rollback-last",
+                snapshot.name, verb_name
+            );
+        }
+        result
+    }
+
+    /// Take the measurements of the verbs run so far, for a batch summary.
+    pub fn take_stats(&self) -> Vec<stats::VerbStats> {
+        self.stats.take()
     }
 
     /// List verbs, optionally filtered by category.
@@ -104,6 +284,47 @@ impl Default for WineArch {
     }
 }
 
+/// Split a WINEDLLOVERRIDES value (`d3d11,dxgi=n;mscoree=`) into one
+/// `(dll, mode)` entry per DLL, in order. DLL names are lowercased.
+pub fn parse_dll_overrides(value: &str) -> Vec<(String, String)> {
+    let mut overrides = Vec::new();
+    for entry in value.split(';') {
+        let (dlls, mode) = match entry.split_once('=') {
+            Some((dlls, mode)) => (dlls, mode.trim()),
+            None => continue,
+        };
+        for dll in dlls.split(',').map(|d| d.trim()).filter(|d| !d.is_empty()) {
+            overrides.push((dll.to_lowercase(), mode.to_string()));
+        }
+    }
+    overrides
+}
+
+/// Merge per-DLL overrides into a WINEDLLOVERRIDES value. Entries in
+/// `overrides` replace those for the same DLL in `base`.
+///
+/// ```
+/// use protontool::wine::merge_dll_overrides;
+/// let merged = merge_dll_overrides(
+///     "d3d11,dxgi=n;mscoree=",
+///     &[("d3d11".to_string(), "b".to_string())],
+/// );
+/// assert_eq!(merged, "dxgi=n;mscoree=;d3d11=b");
+/// ```
+pub fn merge_dll_overrides(base: &str, overrides: &[(String, String)]) -> String {
+    let mut merged = parse_dll_overrides(base);
+    for (dll, mode) in overrides {
+        let dll = dll.to_lowercase();
+        merged.retain(|(d, _)| *d != dll);
+        merged.push((dll, mode.clone()));
+    }
+    merged
+        .iter()
+        .map(|(dll, mode)| format!("{}={}", dll, mode))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
 /// Context for running Wine/Proton commands with proper environment.
 /// Holds paths to Wine binaries, prefix, and environment variables.
 #[derive(Debug, Clone)]
@@ -115,6 +336,8 @@ pub struct WineContext {
     pub proton_path: PathBuf,
     pub arch: WineArch,
     pub dll_overrides: HashMap<String, String>,
+    /// Priority, CPU affinity and GameMode for programs run with `run_wine`.
+    pub scheduling: scheduling::Scheduling,
     env: HashMap<String, String>,
 }
 
@@ -197,6 +420,9 @@ impl WineContext {
             wine_path.to_string_lossy().to_string(),
         );
         env.insert("WINEARCH".to_string(), arch.as_str().to_string());
+        if let Some(spec) = winedebug::for_prefix(prefix_path) {
+            env.insert("WINEDEBUG".to_string(), spec);
+        }
 
         Self {
             wine_path,
@@ -206,6 +432,7 @@ impl WineContext {
             proton_path: proton_app.install_path.clone(),
             arch,
             dll_overrides: HashMap::new(),
+            scheduling: scheduling::Scheduling::default(),
             env,
         }
     }
@@ -220,28 +447,28 @@ impl WineContext {
         self.dll_overrides.insert(dll.to_string(), mode.to_string());
     }
 
-    /// Build the WINEDLLOVERRIDES string from current overrides.
-    fn build_dll_overrides_string(&self) -> String {
-        self.dll_overrides
-            .iter()
-            .map(|(dll, mode)| format!("{}={}", dll, mode))
-            .collect::<Vec<_>>()
-            .join(";")
-    }
-
     /// Apply Wine environment variables and DLL overrides to a command.
+    /// DLL overrides are merged into WINEDLLOVERRIDES from `set_env` or,
+    /// failing that, the parent environment, replacing entries for the same DLL.
     fn apply_env(&self, cmd: &mut Command) {
         for (key, value) in &self.env {
             cmd.env(key, value);
         }
 
         if !self.dll_overrides.is_empty() {
-            let overrides = self.build_dll_overrides_string();
-            if let Ok(existing) = std::env::var("WINEDLLOVERRIDES") {
-                cmd.env("WINEDLLOVERRIDES", format!("{};{}", existing, overrides));
-            } else {
-                cmd.env("WINEDLLOVERRIDES", overrides);
-            }
+            let base = self
+                .env
+                .get("WINEDLLOVERRIDES")
+                .cloned()
+                .or_else(|| std::env::var("WINEDLLOVERRIDES").ok())
+                .unwrap_or_default();
+            let mut overrides: Vec<(String, String)> = self
+                .dll_overrides
+                .iter()
+                .map(|(dll, mode)| (dll.clone(), mode.clone()))
+                .collect();
+            overrides.sort();
+            cmd.env("WINEDLLOVERRIDES", merge_dll_overrides(&base, &overrides));
         }
     }
 
@@ -270,6 +497,30 @@ impl WineContext {
         cwd: Option<&Path>,
         auto_cwd: bool,
     ) -> std::io::Result<Output> {
+        let executable = args.first().unwrap_or(&"wine");
+        check_may_run(executable)?;
+        let mut cmd = self.wine_command(args, cwd, auto_cwd);
+        let output = if is_wine_output_shown() {
+            output_streamed(&mut cmd)?
+        } else {
+            let name = Path::new(executable)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| executable.to_string());
+            let _spinner = crate::util::Spinner::start(&format!("Running {}", name));
+            cmd.output()?
+        };
+
+        // Log the output with error scanning
+        self.log_output(executable, &output);
+        usage::record_use(&self.prefix_path);
+
+        Ok(output)
+    }
+
+    /// The wine command for `args` with the prefix environment and
+    /// scheduling applied (see `run_wine_ex` for `cwd` and `auto_cwd`).
+    fn wine_command(&self, args: &[&str], cwd: Option<&Path>, auto_cwd: bool) -> Command {
         let mut cmd = Command::new(&self.wine_path);
         cmd.args(args);
 
@@ -291,13 +542,35 @@ impl WineContext {
         }
 
         self.apply_env(&mut cmd);
-        let output = cmd.output()?;
-
-        // Log the output with error scanning
-        let executable = args.first().unwrap_or(&"wine");
-        self.log_output(executable, &output);
+        self.scheduling.wrap(cmd)
+    }
 
-        Ok(output)
+    /// Start wine without waiting for it, in its own process group so it
+    /// outlives the terminal's Ctrl-C. Output goes to `log`.
+    pub fn spawn_wine(
+        &self,
+        args: &[&str],
+        cwd: Option<&Path>,
+        log: std::fs::File,
+    ) -> std::io::Result<std::process::Child> {
+        use std::os::unix::process::CommandExt;
+
+        check_may_run(args.first().unwrap_or(&"wine"))?;
+        let mut cmd = self.wine_command(args, cwd, true);
+        let log_err = log.try_clone()?;
+        let child = cmd
+            .stdin(Stdio::null())
+            .stdout(Stdio::from(log))
+            .stderr(Stdio::from(log_err))
+            .process_group(0)
+            .spawn()?;
+        crate::log::info(&format!(
+            "Started {} detached (pid {})",
+            args.first().unwrap_or(&"wine"),
+            child.id()
+        ));
+        usage::record_use(&self.prefix_path);
+        Ok(child)
     }
 
     /// Log output from a wine command and scan for known errors
@@ -371,6 +644,7 @@ impl WineContext {
         Ok(())
     }
     pub fn wineserver(&self, args: &[&str]) -> std::io::Result<Output> {
+        check_may_run("wineserver")?;
         let mut cmd = Command::new(&self.wineserver_path);
         let mut cleaned_args = Vec::new();
         for arg in args {
@@ -0,0 +1,90 @@
+//! Wine Mono management.
+//!
+//! Wine ships Wine Mono as its built-in .NET Framework replacement. The
+//! Microsoft .NET Framework installers refuse to run (or install a broken
+//! framework) while it is present, so it has to be removed first.
+
+use std::path::Path;
+
+use super::download::Downloader;
+use super::registry::RegistryEditor;
+use super::WineContext;
+use crate::util::output_to_string;
+
+/// Registry keys Wine Mono registers to make Windows think .NET is installed.
+const MONO_REGISTRY_KEYS: &[&str] = &[
+    r"HKEY_LOCAL_MACHINE\Software\Microsoft\NET Framework Setup\NDP\v3.5",
+    r"HKEY_LOCAL_MACHINE\Software\Microsoft\NET Framework Setup\NDP\v4",
+];
+
+/// An installed Wine Mono package as listed by `uninstaller --list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonoInstall {
+    pub guid: String,
+    pub name: String,
+}
+
+/// Parse `uninstaller --list` output (`{GUID}|||Name` per line) for Wine Mono.
+fn parse_uninstaller_list(output: &str) -> Vec<MonoInstall> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (guid, name) = line.split_once("|||")?;
+            if name.to_lowercase().contains("mono") {
+                Some(MonoInstall {
+                    guid: guid.trim().to_string(),
+                    name: name.trim().to_string(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// List Wine Mono packages installed in the prefix.
+pub fn installed_mono(wine_ctx: &WineContext) -> Vec<MonoInstall> {
+    match wine_ctx.run_wine_no_cwd(&["uninstaller", "--list"]) {
+        Ok(output) => parse_uninstaller_list(&output_to_string(&output)),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Remove Wine Mono from the prefix so a Microsoft .NET Framework can be installed.
+///
+/// Uninstalls every Wine Mono package, deletes the builtin `mscoree.dll`
+/// copies and the registry keys that advertise an installed framework.
+pub fn remove_mono(wine_ctx: &WineContext) -> Result<(), String> {
+    for install in installed_mono(wine_ctx) {
+        crate::log::info(&format!("Removing {} ({})", install.name, install.guid));
+        wine_ctx
+            .run_wine_no_cwd(&["uninstaller", "--remove", &install.guid])
+            .map_err(|e| format!("Failed to remove {}: {}", install.name, e))?;
+    }
+    wine_ctx.wait_for_wineserver().ok();
+
+    // Otherwise Wine still considers mono installed
+    for dir in [wine_ctx.get_system32_path(), wine_ctx.get_syswow64_path()] {
+        let mscoree = dir.join("mscoree.dll");
+        if mscoree.exists() {
+            std::fs::remove_file(&mscoree)
+                .map_err(|e| format!("Failed to remove {}: {}", mscoree.display(), e))?;
+        }
+    }
+
+    let editor = RegistryEditor::new(wine_ctx);
+    for key in MONO_REGISTRY_KEYS {
+        editor.delete_key(key)?;
+    }
+
+    Ok(())
+}
+
+/// `remove_mono` with the verb custom action signature.
+pub fn remove_mono_action(
+    wine_ctx: &WineContext,
+    _downloader: &Downloader,
+    _tmp_dir: &Path,
+) -> Result<(), String> {
+    remove_mono(wine_ctx)
+}
@@ -0,0 +1,373 @@
+//! Host directories mounted into a prefix for mod managers.
+//!
+//! Mod Organizer 2 instances, Vortex staging folders or plain mod folders
+//! often live outside the prefix. A mount makes such a host directory
+//! appear at a Windows path: a whole drive letter (`M:`, a `dosdevices`
+//! link) or a directory on a drive inside the prefix (`C:\Modding\MO2`, a
+//! directory symlink).
+//!
+//! Mounts are recorded in the prefix metadata (`mount:WINPATH=HOSTDIR`) and
+//! mirrored in the `prefix-mounts` state record, so they can be re-created
+//! after Steam resets a game prefix and takes the metadata with it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::drives;
+use super::metadata::PrefixMetadata;
+
+/// State record mirroring the mounts of every prefix:
+/// `prefix<TAB>windows-path<TAB>host-dir`.
+const MOUNTS_STATE: &str = "prefix-mounts";
+
+/// Prefix of the metadata keys holding mounts.
+const MOUNT_KEY_PREFIX: &str = "mount:";
+
+/// A host directory mounted at a Windows path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mount {
+    /// Normalized Windows path, e.g. `M:` or `C:\Modding\MO2`.
+    pub windows_path: String,
+    pub host: PathBuf,
+}
+
+/// Split a Windows path into its drive letter and directory components.
+fn split_windows_path(path: &str) -> Result<(char, Vec<String>), String> {
+    let path = path.trim().replace('/', "\\");
+    let mut chars = path.chars();
+    let letter = chars
+        .next()
+        .filter(|c| c.is_ascii_alphabetic())
+        .filter(|_| chars.next() == Some(':'))
+        .ok_or_else(|| format!("Not a Windows path with a drive letter: {}", path))?;
+    let components: Vec<String> = chars
+        .as_str()
+        .split('\\')
+        .filter(|c| !c.is_empty())
+        .map(|c| c.to_string())
+        .collect();
+    if components.iter().any(|c| c == "." || c == "..") {
+        return Err(format!("Windows path must not contain . or ..: {}", path));
+    }
+    Ok((letter.to_ascii_uppercase(), components))
+}
+
+/// Normalize a Windows path: upper-case drive letter, backslashes, no
+/// trailing separator.
+///
+/// ```
+/// use protontool::wine::mounts::normalize_windows_path;
+/// assert_eq!(normalize_windows_path("c:/Modding/MO2/").unwrap(), "C:\\Modding\\MO2");
+/// assert_eq!(normalize_windows_path("m:\\").unwrap(), "M:");
+/// assert!(normalize_windows_path("Modding").is_err());
+/// ```
+pub fn normalize_windows_path(path: &str) -> Result<String, String> {
+    let (letter, components) = split_windows_path(path)?;
+    let mut normalized = format!("{}:", letter);
+    for component in &components {
+        normalized.push('\\');
+        normalized.push_str(component);
+    }
+    Ok(normalized)
+}
+
+/// Where the link for a mount goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LinkLocation {
+    Drive(char),
+    Dir(PathBuf),
+}
+
+/// Find the link location of a Windows path. Directories must be on a
+/// drive that lives inside the prefix, such as `C:`.
+fn link_location(prefix_path: &Path, windows_path: &str) -> Result<LinkLocation, String> {
+    let (letter, components) = split_windows_path(windows_path)?;
+    if components.is_empty() {
+        return Ok(LinkLocation::Drive(letter));
+    }
+    let target = drives::drive_target(prefix_path, letter)
+        .ok_or_else(|| format!("Drive {}: is not mapped in the prefix", letter))?;
+    let drive_dir = prefix_path.join("dosdevices").join(target);
+    let inside = match (drive_dir.canonicalize(), prefix_path.canonicalize()) {
+        (Ok(dir), Ok(prefix)) => dir.starts_with(prefix),
+        _ => false,
+    };
+    if !inside {
+        return Err(format!(
+            "Drive {}: is not inside the prefix; mount to a drive letter or a path on C:",
+            letter
+        ));
+    }
+    Ok(LinkLocation::Dir(
+        components
+            .iter()
+            .fold(drive_dir, |path, component| path.join(component)),
+    ))
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(not(unix))]
+fn symlink(_target: &Path, _link: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "symlinks are not supported on this platform",
+    ))
+}
+
+/// Create the link of a mount. An existing drive or link pointing
+/// elsewhere is only replaced with `replace`, when it belongs to an earlier
+/// mount at the same path.
+fn create_link(prefix_path: &Path, mount: &Mount, replace: bool) -> Result<(), String> {
+    match link_location(prefix_path, &mount.windows_path)? {
+        LinkLocation::Drive(letter) => {
+            if let Some(target) = drives::drive_target(prefix_path, letter) {
+                if !replace && target != mount.host {
+                    return Err(format!(
+                        "Drive {}: is already mapped to {}",
+                        letter,
+                        target.display()
+                    ));
+                }
+            }
+            drives::map_drive(prefix_path, letter, &mount.host)
+        }
+        LinkLocation::Dir(link) => {
+            if link.is_symlink() {
+                if !replace && fs::read_link(&link).ok().as_deref() != Some(mount.host.as_path()) {
+                    return Err(format!(
+                        "{} already links to another directory",
+                        mount.windows_path
+                    ));
+                }
+                fs::remove_file(&link)
+                    .map_err(|e| format!("Failed to remove {}: {}", link.display(), e))?;
+            } else if link.exists() {
+                return Err(format!(
+                    "{} already exists in the prefix; move it away first",
+                    mount.windows_path
+                ));
+            }
+            if let Some(parent) = link.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+            }
+            symlink(&mount.host, &link).map_err(|e| {
+                format!(
+                    "Failed to link {} to {}: {}",
+                    link.display(),
+                    mount.host.display(),
+                    e
+                )
+            })
+        }
+    }
+}
+
+/// Whether the link of a mount exists and points at its host directory.
+pub fn is_linked(prefix_path: &Path, mount: &Mount) -> bool {
+    let target = match link_location(prefix_path, &mount.windows_path) {
+        Ok(LinkLocation::Drive(letter)) => drives::drive_target(prefix_path, letter),
+        Ok(LinkLocation::Dir(link)) => fs::read_link(link).ok(),
+        Err(_) => None,
+    };
+    target.as_deref() == Some(mount.host.as_path())
+}
+
+/// Mounts recorded in the prefix metadata.
+fn metadata_mounts(metadata: &PrefixMetadata) -> Vec<Mount> {
+    metadata
+        .entries
+        .iter()
+        .filter_map(|(key, value)| {
+            let windows_path = key.strip_prefix(MOUNT_KEY_PREFIX)?;
+            Some(Mount {
+                windows_path: windows_path.to_string(),
+                host: PathBuf::from(value),
+            })
+        })
+        .collect()
+}
+
+/// Parse the state record into (prefix, mount) entries.
+fn parse_records(lines: &[String]) -> Vec<(PathBuf, Mount)> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let prefix = PathBuf::from(fields.next()?);
+            let windows_path = fields.next()?.to_string();
+            let host = PathBuf::from(fields.next()?);
+            Some((prefix, Mount { windows_path, host }))
+        })
+        .collect()
+}
+
+/// Mounts of a prefix. Falls back to the state record when the metadata
+/// has none, e.g. after Steam reset the prefix.
+pub fn mounts(prefix_path: &Path) -> Vec<Mount> {
+    let recorded = metadata_mounts(&PrefixMetadata::load(prefix_path));
+    if !recorded.is_empty() {
+        return recorded;
+    }
+    parse_records(&crate::state::read_lines(MOUNTS_STATE))
+        .into_iter()
+        .filter(|(prefix, _)| prefix == prefix_path)
+        .map(|(_, mount)| mount)
+        .collect()
+}
+
+/// Record the mounts of a prefix in its metadata and the state record.
+fn save(prefix_path: &Path, mounts: &[Mount]) -> Result<(), String> {
+    let mut metadata = PrefixMetadata::load(prefix_path);
+    metadata
+        .entries
+        .retain(|(key, _)| !key.starts_with(MOUNT_KEY_PREFIX));
+    for mount in mounts {
+        metadata.set(
+            &format!("{}{}", MOUNT_KEY_PREFIX, mount.windows_path),
+            &mount.host.to_string_lossy(),
+        );
+    }
+    metadata.save(prefix_path)?;
+
+    let mut records: Vec<(PathBuf, Mount)> = parse_records(&crate::state::read_lines(MOUNTS_STATE))
+        .into_iter()
+        .filter(|(prefix, _)| prefix != prefix_path)
+        .collect();
+    records.extend(
+        mounts
+            .iter()
+            .map(|m| (prefix_path.to_path_buf(), m.clone())),
+    );
+    let content: String = records
+        .iter()
+        .map(|(prefix, mount)| {
+            format!(
+                "{}\t{}\t{}\n",
+                prefix.display(),
+                mount.windows_path,
+                mount.host.display()
+            )
+        })
+        .collect();
+    crate::state::write(MOUNTS_STATE, &content)
+}
+
+/// Mount a host directory at a Windows path, replacing an earlier mount
+/// at the same path.
+pub fn mount(prefix_path: &Path, windows_path: &str, host: &Path) -> Result<Mount, String> {
+    if !host.is_dir() {
+        return Err(format!("Not a directory: {}", host.display()));
+    }
+    let mount = Mount {
+        windows_path: normalize_windows_path(windows_path)?,
+        host: host.canonicalize().unwrap_or_else(|_| host.to_path_buf()),
+    };
+    let mut all = mounts(prefix_path);
+    let replace = all.iter().any(|m| m.windows_path == mount.windows_path);
+    create_link(prefix_path, &mount, replace)?;
+
+    all.retain(|m| m.windows_path != mount.windows_path);
+    all.push(mount.clone());
+    save(prefix_path, &all)?;
+    Ok(mount)
+}
+
+/// Remove the mount at a Windows path. Returns false if there was none.
+pub fn unmount(prefix_path: &Path, windows_path: &str) -> Result<bool, String> {
+    let windows_path = normalize_windows_path(windows_path)?;
+    let mut all = mounts(prefix_path);
+    let Some(index) = all.iter().position(|m| m.windows_path == windows_path) else {
+        return Ok(false);
+    };
+    let mount = all.remove(index);
+    match link_location(prefix_path, &mount.windows_path)? {
+        LinkLocation::Drive(letter) => {
+            drives::unmap_drive(prefix_path, letter)?;
+        }
+        LinkLocation::Dir(link) => {
+            if link.is_symlink() {
+                fs::remove_file(&link)
+                    .map_err(|e| format!("Failed to remove {}: {}", link.display(), e))?;
+            }
+        }
+    }
+    save(prefix_path, &all)?;
+    Ok(true)
+}
+
+/// Re-create the links of mounts that are missing, e.g. after the prefix
+/// was reset. Mounts whose host directory is gone are skipped. Returns the
+/// re-created mounts.
+pub fn restore(prefix_path: &Path) -> Result<Vec<Mount>, String> {
+    let all = mounts(prefix_path);
+    let missing: Vec<Mount> = all
+        .iter()
+        .filter(|m| m.host.is_dir() && !is_linked(prefix_path, m))
+        .cloned()
+        .collect();
+    for mount in &missing {
+        create_link(prefix_path, mount, false)?;
+    }
+    // Put the mounts back into the metadata if they came from the state
+    if !all.is_empty() && metadata_mounts(&PrefixMetadata::load(prefix_path)).is_empty() {
+        save(prefix_path, &all)?;
+    }
+    Ok(missing)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_location() {
+        let prefix = std::env::temp_dir().join(format!("pt-mounts-{}", std::process::id()));
+        fs::create_dir_all(prefix.join("drive_c")).unwrap();
+        fs::create_dir_all(prefix.join("dosdevices")).unwrap();
+        drives::map_drive(&prefix, 'c', Path::new("../drive_c")).unwrap();
+        drives::map_drive(&prefix, 'z', Path::new("/")).unwrap();
+
+        assert_eq!(link_location(&prefix, "m:"), Ok(LinkLocation::Drive('M')));
+        assert_eq!(
+            link_location(&prefix, "C:\\Modding\\MO2"),
+            Ok(LinkLocation::Dir(
+                prefix.join("dosdevices/../drive_c/Modding/MO2")
+            ))
+        );
+        assert!(link_location(&prefix, "Z:\\mods").is_err());
+        assert!(link_location(&prefix, "D:\\mods").is_err());
+        assert!(link_location(&prefix, "C:\\..\\mods").is_err());
+
+        let mount = Mount {
+            windows_path: "C:\\Modding\\MO2".to_string(),
+            host: std::env::temp_dir(),
+        };
+        assert!(!is_linked(&prefix, &mount));
+        create_link(&prefix, &mount, false).unwrap();
+        assert!(is_linked(&prefix, &mount));
+        assert!(prefix.join("drive_c/Modding").is_dir());
+
+        let drive = Mount {
+            windows_path: "Z:".to_string(),
+            host: std::env::temp_dir(),
+        };
+        assert!(create_link(&prefix, &drive, false).is_err());
+        assert_eq!(
+            parse_records(&["/pfx\tM:\t/mods".to_string(), "/pfx\tC:\\Mods".to_string()]),
+            vec![(
+                PathBuf::from("/pfx"),
+                Mount {
+                    windows_path: "M:".to_string(),
+                    host: PathBuf::from("/mods"),
+                }
+            )]
+        );
+
+        fs::remove_dir_all(&prefix).ok();
+    }
+}
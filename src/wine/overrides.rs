@@ -0,0 +1,161 @@
+//! DLL override sets.
+//!
+//! The DLL overrides of a prefix (`HKCU\Software\Wine\DllOverrides`) can be
+//! exported to a small TOML file and imported into another prefix, so a
+//! curated set of overrides for a game can be shared without a full recipe:
+//!
+//! ```toml
+//! [overrides]
+//! "d3d11" = "native,builtin"
+//! "winemenubuilder.exe" = ""
+//! ```
+//!
+//! An empty mode disables the DLL.
+
+use std::path::Path;
+
+use super::registry;
+use super::WineContext;
+use crate::config::UserConfig;
+use crate::wine::custom::escape_toml_string;
+
+/// Registry key holding the prefix's DLL overrides.
+pub const DLL_OVERRIDES_KEY: &str = "HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides";
+
+/// Section of an override set file holding the overrides.
+const SECTION: &str = "overrides";
+
+/// DLL modes accepted in overrides, alone or as a comma-separated list.
+const OVERRIDE_MODES: &[&str] = &["n", "b", "d", "native", "builtin", "disabled"];
+
+/// Whether a mode is a valid override (empty disables the DLL).
+pub fn is_valid_mode(mode: &str) -> bool {
+    mode.is_empty()
+        || mode
+            .split(',')
+            .all(|m| OVERRIDE_MODES.contains(&m.trim().to_lowercase().as_str()))
+}
+
+/// The DLL overrides stored in a prefix's registry, read without running
+/// Wine.
+pub fn read(prefix_path: &Path) -> Vec<(String, String)> {
+    registry::read_prefix_key_values(prefix_path, DLL_OVERRIDES_KEY)
+        .into_iter()
+        .filter_map(|(dll, data)| {
+            let mode = data.strip_prefix('"')?.strip_suffix('"')?;
+            Some((dll, mode.to_string()))
+        })
+        .collect()
+}
+
+/// Override set file content, with a comment line naming where it came from.
+pub fn to_toml(overrides: &[(String, String)], source: &str) -> String {
+    let mut content = format!("# DLL overrides from {}\n[{}]\n", source, SECTION);
+    for (dll, mode) in overrides {
+        content.push_str(&format!(
+            "\"{}\" = \"{}\"\n",
+            escape_toml_string(dll),
+            escape_toml_string(mode)
+        ));
+    }
+    content
+}
+
+/// Parse override set file content. Invalid modes are errors.
+pub fn parse_toml(content: &str) -> Result<Vec<(String, String)>, String> {
+    let config = UserConfig::parse(content);
+    let mut overrides = Vec::new();
+    for (dll, mode) in config.section(SECTION) {
+        let dll = dll.trim_matches('"').to_lowercase();
+        if dll.is_empty() {
+            continue;
+        }
+        if !is_valid_mode(mode) {
+            return Err(format!(
+                "Invalid override mode '{}' for {}: use n, b, n,b, b,n or d",
+                mode, dll
+            ));
+        }
+        overrides.retain(|(d, _): &(String, String)| *d != dll);
+        overrides.push((dll, mode.to_string()));
+    }
+    if overrides.is_empty() {
+        return Err(format!("No [{}] entries found", SECTION));
+    }
+    Ok(overrides)
+}
+
+/// Export a prefix's DLL overrides to a file. Returns how many were written.
+pub fn export(prefix_path: &Path, file: &Path) -> Result<usize, String> {
+    let overrides = read(prefix_path);
+    if overrides.is_empty() {
+        return Err(format!("No DLL overrides set in {}", prefix_path.display()));
+    }
+    std::fs::write(
+        file,
+        to_toml(&overrides, &prefix_path.display().to_string()),
+    )
+    .map_err(|e| format!("Failed to write {}: {}", file.display(), e))?;
+    Ok(overrides.len())
+}
+
+/// Write DLL overrides to the prefix registry, journaling the values they
+/// replace under `description`.
+pub fn apply(
+    wine_ctx: &WineContext,
+    overrides: &[(String, String)],
+    description: &str,
+) -> Result<(), String> {
+    if overrides.is_empty() {
+        return Ok(());
+    }
+    let pairs: Vec<(&str, &str)> = overrides
+        .iter()
+        .map(|(dll, mode)| (dll.as_str(), mode.as_str()))
+        .collect();
+    let content = super::verbs::dll_overrides_content(&pairs);
+    let changes = crate::journal::registry_changes(wine_ctx, &content);
+
+    let work_dir = crate::workdir::WorkDir::create("overrides")?;
+    super::verbs::import_dll_overrides(wine_ctx, work_dir.path(), &pairs)?;
+
+    crate::journal::record(description, changes)
+}
+
+/// Import the overrides of a file into a prefix. Returns the imported
+/// overrides.
+pub fn import(wine_ctx: &WineContext, file: &Path) -> Result<Vec<(String, String)>, String> {
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    let overrides = parse_toml(&content).map_err(|e| format!("{}: {}", file.display(), e))?;
+    let description = format!(
+        "DLL overrides from {} in {}",
+        file.display(),
+        wine_ctx.prefix_path.display()
+    );
+    apply(wine_ctx, &overrides, &description)?;
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_set_round_trip() {
+        let overrides = vec![
+            ("d3d11".to_string(), "native,builtin".to_string()),
+            ("winemenubuilder.exe".to_string(), String::new()),
+        ];
+        let content = to_toml(&overrides, "/tmp/pfx");
+        assert!(content.contains("\"winemenubuilder.exe\" = \"\"\n"));
+        assert_eq!(parse_toml(&content), Ok(overrides));
+
+        assert!(parse_toml("[overrides]\nd3d9 = \"fast\"\n").is_err());
+        assert!(parse_toml("[other]\nd3d9 = \"n\"\n").is_err());
+        assert_eq!(
+            parse_toml("[overrides]\nD3D9 = \"n\"\nd3d9 = \"b\"\n"),
+            Ok(vec![("d3d9".to_string(), "b".to_string())])
+        );
+    }
+}
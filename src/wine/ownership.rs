@@ -0,0 +1,184 @@
+//! Root execution guard and prefix ownership repair.
+//!
+//! Running protontool as root (usually through `sudo`) leaves root-owned
+//! files in prefixes that belong to a normal user; Steam and later runs as
+//! that user then fail to write them. protontool warns when it runs as
+//! root, and `--fix-ownership` gives every file in a prefix back to the
+//! invoking user: the `sudo`/`pkexec` caller, or else the prefix owner.
+
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+/// A user files are given back to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Owner {
+    pub uid: u32,
+    pub gid: u32,
+    /// Login name, when found in `/etc/passwd`.
+    pub name: Option<String>,
+}
+
+impl Owner {
+    /// Name or uid for messages.
+    pub fn describe(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("uid {}", self.uid),
+        }
+    }
+}
+
+/// Effective user id of this process.
+pub fn effective_uid() -> Option<u32> {
+    fs::metadata("/proc/self").ok().map(|m| m.uid())
+}
+
+/// Whether protontool runs as root.
+pub fn is_root() -> bool {
+    effective_uid() == Some(0)
+}
+
+/// Warning shown when protontool runs as root.
+pub fn root_warning() -> String {
+    "protontool is running as root. Files it creates in prefixes will be owned by root, \
+     which breaks Steam and later runs as your user. Run protontool as your normal user. \
+     To repair a prefix that was already modified as root, run: \
+     sudo protontool APPID|--prefix PATH --fix-ownership"
+        .to_string()
+}
+
+/// Login name and primary group of a uid in `/etc/passwd` content.
+fn passwd_entry(content: &str, uid: u32) -> Option<(String, u32)> {
+    content.lines().find_map(|line| {
+        let fields: Vec<&str> = line.split(':').collect();
+        if fields.len() < 4 || fields[2].parse::<u32>().ok()? != uid {
+            return None;
+        }
+        Some((fields[0].to_string(), fields[3].parse().ok()?))
+    })
+}
+
+/// Look up a uid in `/etc/passwd`, falling back to `gid` as the group.
+fn owner_for_uid(uid: u32, gid: Option<u32>) -> Owner {
+    let entry = fs::read_to_string("/etc/passwd")
+        .ok()
+        .and_then(|content| passwd_entry(&content, uid));
+    Owner {
+        uid,
+        gid: gid.or(entry.as_ref().map(|(_, g)| *g)).unwrap_or(uid),
+        name: entry.map(|(name, _)| name),
+    }
+}
+
+/// The user a prefix belongs to: the user who ran `sudo` or `pkexec`, else
+/// the non-root owner of the prefix or its parent directory, else (when
+/// not root) the current user.
+pub fn prefix_owner(prefix_path: &Path) -> Option<Owner> {
+    let env_id = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<u32>().ok());
+    if let Some(uid) = env_id("SUDO_UID").filter(|&uid| uid != 0) {
+        return Some(owner_for_uid(uid, env_id("SUDO_GID")));
+    }
+    if let Some(uid) = env_id("PKEXEC_UID").filter(|&uid| uid != 0) {
+        return Some(owner_for_uid(uid, None));
+    }
+    let existing = [Some(prefix_path), prefix_path.parent()]
+        .into_iter()
+        .flatten()
+        .filter_map(|path| fs::metadata(path).ok())
+        .find(|m| m.uid() != 0);
+    if let Some(metadata) = existing {
+        return Some(owner_for_uid(metadata.uid(), Some(metadata.gid())));
+    }
+    effective_uid()
+        .filter(|&uid| uid != 0)
+        .map(|uid| owner_for_uid(uid, None))
+}
+
+/// Refuse paths that aren't Wine prefixes, so a mistyped `--prefix` can't
+/// hand a system directory to a user.
+pub fn check_prefix(prefix_path: &Path) -> Result<(), String> {
+    if super::discovery::is_prefix(prefix_path) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} is not a Wine prefix (no system.reg and drive_c); refusing to change its ownership",
+            prefix_path.display()
+        ))
+    }
+}
+
+/// Files and directories below (and including) `root` not owned by `uid`.
+/// Symlinks are checked themselves but never followed, so `dosdevices`
+/// links to `/` stay out of the walk, and the walk stays on the filesystem
+/// of `root` without entering mount points.
+pub fn foreign_owned(root: &Path, uid: u32) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Ok(root_dev) = fs::symlink_metadata(root).map(|m| m.dev()) else {
+        return found;
+    };
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if metadata.dev() != root_dev {
+            continue;
+        }
+        if metadata.uid() != uid {
+            found.push(path.clone());
+        }
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|e| e.path()));
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
+/// Give every file in a prefix not owned by `owner` to it. Returns the
+/// paths changed; fails on the first file that can't be changed (as a
+/// normal user, files owned by root).
+pub fn fix_ownership(prefix_path: &Path, owner: &Owner) -> Result<Vec<PathBuf>, String> {
+    check_prefix(prefix_path)?;
+    let paths = foreign_owned(prefix_path, owner.uid);
+    for path in &paths {
+        std::os::unix::fs::lchown(path, Some(owner.uid), Some(owner.gid)).map_err(|e| {
+            format!(
+                "Failed to give {} to {}: {}",
+                path.display(),
+                owner.describe(),
+                e
+            )
+        })?;
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passwd_and_foreign_owned() {
+        let passwd = "root:x:0:0:root:/root:/bin/bash\nalice:x:1000:100::/home/alice:/bin/sh\n";
+        assert_eq!(passwd_entry(passwd, 1000), Some(("alice".to_string(), 100)));
+        assert_eq!(passwd_entry(passwd, 1001), None);
+
+        let prefix = std::env::temp_dir().join(format!("pt-ownership-{}", std::process::id()));
+        fs::create_dir_all(prefix.join("dosdevices")).unwrap();
+        std::os::unix::fs::symlink("/", prefix.join("dosdevices/z:")).unwrap();
+        let uid = effective_uid().unwrap();
+        assert!(foreign_owned(&prefix, uid).is_empty());
+        assert_eq!(
+            foreign_owned(&prefix, uid + 1).len(),
+            3,
+            "the symlink to / must not be followed"
+        );
+        assert!(check_prefix(&prefix).is_err());
+        assert!(fix_ownership(&prefix, &owner_for_uid(uid, None)).is_err());
+        fs::remove_dir_all(&prefix).ok();
+    }
+}
@@ -0,0 +1,312 @@
+//! External executable plugins for verb actions.
+//!
+//! Data files can't embed Rust closures, so complex community verbs can
+//! delegate work to an executable in `~/.protontool/plugin/`. A TOML verb
+//! invokes one with:
+//!
+//! ```toml
+//! [[actions]]
+//! type = "plugin"
+//! plugin = "fix-launcher"
+//! args = ["--mode", "fast"]
+//! ```
+//!
+//! The plugin receives a JSON object describing the context on stdin:
+//!
+//! ```json
+//! {"protocol": 1, "prefix": "...", "arch": "win64", "wine": "...",
+//!  "wineserver": "...", "proton": "...", "tmp_dir": "...",
+//!  "cache_dir": "...", "workshop": "...", "args": ["--mode", "fast"]}
+//! ```
+//!
+//! `workshop` is the host path of the game's Steam Workshop content, or null
+//! outside Steam prefixes (also set as `STEAM_WORKSHOP_PATH`).
+//!
+//! The Wine environment (`WINEPREFIX`, `WINE`, `WINESERVER`, ...) is also set,
+//! so plugins can run Wine themselves. Each stdout line that is a JSON object
+//! is a message to protontool; other lines are logged as-is:
+//!
+//! | Message                                                | Effect                      |
+//! |--------------------------------------------------------|-----------------------------|
+//! | `{"type":"log","level":"warn","message":"..."}`        | Write to the protontool log |
+//! | `{"type":"registry","content":"..."}`                  | Apply a .reg patch          |
+//! | `{"type":"wine","args":["C:\\setup.exe","/S"]}`        | Run a Wine command          |
+//! | `{"type":"result","status":"error","message":"..."}`   | Report success or failure   |
+//!
+//! Registry and Wine requests are carried out in order after the plugin
+//! exits successfully. A non-zero exit code or an error result fails the verb.
+//!
+//! A plugin file ending in `.wasm` is run in a sandbox instead (see `wasm.rs`).
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use super::download::Downloader;
+use super::WineContext;
+use crate::json::{self, JsonValue};
+
+/// Version of the stdin/stdout protocol passed to plugins.
+pub const PLUGIN_PROTOCOL_VERSION: u32 = 1;
+
+/// A request from a plugin, carried out after it exits.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluginRequest {
+    Registry(String),
+    Wine(Vec<String>),
+}
+
+/// Parsed plugin stdout.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginOutput {
+    pub requests: Vec<PluginRequest>,
+    /// `Some(Err(message))` if the plugin reported an error result.
+    pub result: Option<Result<String, String>>,
+}
+
+/// Find a plugin by name in the plugins directory. Accepts the exact file
+/// name or a file whose stem matches (`fix-launcher` finds `fix-launcher.py`).
+pub fn find_plugin(name: &str) -> Option<PathBuf> {
+    if name.is_empty() || name.contains('/') {
+        return None;
+    }
+
+    let dir = crate::config::get_plugins_dir();
+    let exact = dir.join(name);
+    if exact.is_file() {
+        return Some(exact);
+    }
+
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.file_stem().and_then(|s| s.to_str()) == Some(name))
+        .collect();
+    candidates.sort();
+    candidates.into_iter().next()
+}
+
+fn path_value(path: &Path) -> JsonValue {
+    JsonValue::String(path.to_string_lossy().to_string())
+}
+
+/// `STEAM_WORKSHOP_PATH` for scripts and plugins, when the prefix's game has
+/// Workshop content.
+pub fn workshop_env(wine_ctx: &WineContext) -> Vec<(&'static str, PathBuf)> {
+    super::workshop::content_dir_for_prefix(&wine_ctx.prefix_path)
+        .map(|path| ("STEAM_WORKSHOP_PATH", path))
+        .into_iter()
+        .collect()
+}
+
+/// JSON context passed to a plugin on stdin.
+pub fn plugin_context(
+    wine_ctx: &WineContext,
+    downloader: &Downloader,
+    tmp_dir: &Path,
+    args: &[String],
+) -> JsonValue {
+    JsonValue::Object(vec![
+        (
+            "protocol".to_string(),
+            JsonValue::Number(PLUGIN_PROTOCOL_VERSION as f64),
+        ),
+        ("prefix".to_string(), path_value(&wine_ctx.prefix_path)),
+        (
+            "arch".to_string(),
+            JsonValue::String(wine_ctx.arch.as_str().to_string()),
+        ),
+        ("wine".to_string(), path_value(&wine_ctx.wine_path)),
+        (
+            "wineserver".to_string(),
+            path_value(&wine_ctx.wineserver_path),
+        ),
+        ("proton".to_string(), path_value(&wine_ctx.proton_path)),
+        ("tmp_dir".to_string(), path_value(tmp_dir)),
+        ("cache_dir".to_string(), path_value(downloader.cache_dir())),
+        (
+            "workshop".to_string(),
+            super::workshop::content_dir_for_prefix(&wine_ctx.prefix_path)
+                .map_or(JsonValue::Null, |path| path_value(&path)),
+        ),
+        (
+            "args".to_string(),
+            JsonValue::Array(args.iter().map(|a| JsonValue::String(a.clone())).collect()),
+        ),
+    ])
+}
+
+/// Parse plugin stdout into requests and a result, logging messages as they appear.
+pub fn parse_plugin_output(name: &str, stdout: &str) -> PluginOutput {
+    let mut output = PluginOutput::default();
+
+    for line in stdout.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let message = match json::parse(line) {
+            Ok(value @ JsonValue::Object(_)) => value,
+            _ => {
+                crate::log::info(&format!("[{}] {}", name, line));
+                continue;
+            }
+        };
+        let text = message
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        match message.get("type").and_then(|t| t.as_str()) {
+            Some("log") => {
+                let text = format!("[{}] {}", name, text);
+                match message.get("level").and_then(|l| l.as_str()) {
+                    Some("error") => crate::log::error(&text),
+                    Some("warn") | Some("warning") => crate::log::warn(&text),
+                    Some("debug") => crate::log::debug(&text),
+                    _ => crate::log::info(&text),
+                }
+            }
+            Some("registry") => {
+                if let Some(content) = message.get("content").and_then(|c| c.as_str()) {
+                    output
+                        .requests
+                        .push(PluginRequest::Registry(content.to_string()));
+                }
+            }
+            Some("wine") => {
+                let args: Vec<String> = message
+                    .get("args")
+                    .and_then(|a| a.as_array())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|a| a.as_str().map(|s| s.to_string()))
+                    .collect();
+                if !args.is_empty() {
+                    output.requests.push(PluginRequest::Wine(args));
+                }
+            }
+            Some("result") => {
+                output.result = Some(match message.get("status").and_then(|s| s.as_str()) {
+                    Some("error") => Err(text),
+                    _ => Ok(text),
+                });
+            }
+            _ => crate::log::warn(&format!("[{}] Unknown plugin message: {}", name, line)),
+        }
+    }
+
+    output
+}
+
+/// Run a plugin and carry out its requests.
+pub fn run_plugin(
+    name: &str,
+    args: &[String],
+    wine_ctx: &WineContext,
+    downloader: &Downloader,
+    tmp_dir: &Path,
+) -> Result<(), String> {
+    let path = find_plugin(name).ok_or_else(|| {
+        format!(
+            "Plugin '{}' not found in {}",
+            name,
+            crate::config::get_plugins_dir().display()
+        )
+    })?;
+    if path.extension().is_some_and(|ext| ext == "wasm") {
+        let host = super::wasm::WasmHost::new(wine_ctx, downloader, tmp_dir);
+        return super::wasm::run_wasm_plugin(&path, host, args);
+    }
+    if !crate::util::is_executable(&path) {
+        return Err(format!("Plugin {} is not executable", path.display()));
+    }
+
+    let context = plugin_context(wine_ctx, downloader, tmp_dir, args).to_string();
+    let mut child = Command::new(&path)
+        .args(args)
+        .env("WINEPREFIX", &wine_ctx.prefix_path)
+        .env("WINEARCH", wine_ctx.arch.as_str())
+        .env("WINE", &wine_ctx.wine_path)
+        .env("WINESERVER", &wine_ctx.wineserver_path)
+        .env("PROTON_PATH", &wine_ctx.proton_path)
+        .env("W_TMP", tmp_dir)
+        .env("W_CACHE", downloader.cache_dir())
+        .envs(workshop_env(wine_ctx))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start plugin {}: {}", path.display(), e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A plugin that doesn't read its context closes the pipe early; that's fine
+        stdin.write_all(context.as_bytes()).ok();
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to run plugin {}: {}", path.display(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for line in stderr.lines().filter(|l| !l.trim().is_empty()) {
+        crate::log::warn(&format!("[{}] {}", name, line));
+    }
+
+    let parsed = parse_plugin_output(name, &stdout);
+    if !output.status.success() {
+        let detail = match parsed.result {
+            Some(Err(message)) if !message.is_empty() => message,
+            _ => format!("exited with code {}", output.status.code().unwrap_or(-1)),
+        };
+        return Err(format!("Plugin {} failed: {}", name, detail));
+    }
+    if let Some(Err(message)) = parsed.result {
+        return Err(format!("Plugin {} failed: {}", name, message));
+    }
+
+    for request in parsed.requests {
+        match request {
+            PluginRequest::Registry(content) => {
+                let reg_file =
+                    crate::workdir::unique_file(tmp_dir, &format!("plugin-{}", name), "reg");
+                let content = super::template::expand_for_prefix(&content, wine_ctx);
+                std::fs::write(&reg_file, content).map_err(|e| e.to_string())?;
+                wine_ctx.run_regedit(&reg_file).map_err(|e| e.to_string())?;
+                std::fs::remove_file(&reg_file).ok();
+            }
+            PluginRequest::Wine(wine_args) => {
+                let refs: Vec<&str> = wine_args.iter().map(|s| s.as_str()).collect();
+                wine_ctx.run_wine(&refs).map_err(|e| e.to_string())?;
+                wine_ctx.wait_for_wineserver().ok();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plugin_output() {
+        let stdout = "plain progress line\n\
+            {\"type\":\"registry\",\"content\":\"REGEDIT4\"}\n\
+            {\"type\":\"wine\",\"args\":[\"C:\\\\setup.exe\",\"/S\"]}\n\
+            {\"type\":\"result\",\"status\":\"error\",\"message\":\"no game found\"}\n";
+        let output = parse_plugin_output("test", stdout);
+        assert_eq!(
+            output.requests,
+            vec![
+                PluginRequest::Registry("REGEDIT4".to_string()),
+                PluginRequest::Wine(vec!["C:\\setup.exe".to_string(), "/S".to_string()]),
+            ]
+        );
+        assert_eq!(output.result, Some(Err("no game found".to_string())));
+    }
+}
@@ -151,6 +151,11 @@ pub fn init_prefix(
         }
     }
 
+    // Baseline verbs/winver/env from the [provision] section of config.toml
+    if let Some(ctx) = wine_ctx {
+        super::provision::provision_prefix(ctx);
+    }
+
     eprintln!("Prefix initialization complete.");
     Ok(())
 }
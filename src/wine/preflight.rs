@@ -0,0 +1,155 @@
+//! Checks before modifying a prefix.
+//!
+//! A wineserver left running by a different Wine or Proton version keeps
+//! serving the prefix to every Wine process started in it. Wine processes
+//! of another version then either fail to talk to it or, worse, run
+//! `wineboot --update` against registry hives the old server writes back
+//! on exit: the classic cause of surprise "prefix update" prompts and
+//! corrupted prefixes. Before running verbs or wineboot, protontool looks
+//! for such a server and offers to shut it down, and refuses to touch a
+//! prefix on a read-only mount.
+//!
+//! A wineserver holds the lock in its server directory,
+//! `/tmp/.wine-UID/server-DEV-INODE` (the device and inode of the prefix),
+//! and runs with that directory as its working directory, which is how
+//! the process serving a prefix is found in `/proc`.
+
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use super::WineContext;
+
+/// How long a shut down wineserver is given to exit.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A wineserver process serving a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningServer {
+    pub pid: u32,
+    /// The wineserver binary, if `/proc` shows it.
+    pub exe: Option<PathBuf>,
+}
+
+impl RunningServer {
+    /// Version reported by the server's binary (`Wine 9.0`), if it runs.
+    pub fn version(&self) -> Option<String> {
+        let output = Command::new(self.exe.as_ref()?)
+            .arg("--version")
+            .output()
+            .ok()?;
+        let version = String::from_utf8_lossy(&output.stdout);
+        Some(version.lines().next()?.trim().to_string()).filter(|v| !v.is_empty())
+    }
+
+    /// Whether this server comes from a different Wine build than the one
+    /// protontool would use. Servers whose binary can't be seen count as
+    /// different.
+    pub fn is_foreign(&self, wineserver_path: &Path) -> bool {
+        let Some(exe) = &self.exe else {
+            return true;
+        };
+        let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        canonical(exe) != canonical(wineserver_path)
+    }
+
+    /// Readable description for messages.
+    pub fn describe(&self) -> String {
+        let exe = match &self.exe {
+            Some(exe) => exe.display().to_string(),
+            None => "unknown binary".to_string(),
+        };
+        match self.version() {
+            Some(version) => format!("{} (PID {}, {})", version, self.pid, exe),
+            None => format!("PID {}, {}", self.pid, exe),
+        }
+    }
+}
+
+/// The server directory a wineserver for a prefix uses.
+pub fn server_dir(prefix_path: &Path) -> Option<PathBuf> {
+    let prefix = fs::metadata(prefix_path).ok()?;
+    let uid = super::ownership::effective_uid()?;
+    Some(PathBuf::from(format!(
+        "/tmp/.wine-{}/server-{:x}-{:x}",
+        uid,
+        prefix.dev(),
+        prefix.ino()
+    )))
+}
+
+/// Wineserver processes currently serving a prefix.
+pub fn running_servers(prefix_path: &Path) -> Vec<RunningServer> {
+    let Some(dir) = server_dir(prefix_path) else {
+        return Vec::new();
+    };
+    if !dir.is_dir() {
+        return Vec::new();
+    }
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    let mut servers: Vec<RunningServer> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse::<u32>().ok()?;
+            let cwd = fs::read_link(entry.path().join("cwd")).ok()?;
+            (cwd == dir).then(|| RunningServer {
+                pid,
+                exe: fs::read_link(entry.path().join("exe")).ok(),
+            })
+        })
+        .collect();
+    servers.sort_by_key(|s| s.pid);
+    servers
+}
+
+/// Running wineservers for the prefix from a different Wine build than
+/// `wine_ctx`'s.
+pub fn foreign_servers(wine_ctx: &WineContext) -> Vec<RunningServer> {
+    running_servers(&wine_ctx.prefix_path)
+        .into_iter()
+        .filter(|server| server.is_foreign(&wine_ctx.wineserver_path))
+        .collect()
+}
+
+/// Shut down a wineserver with its own binary (a server only understands
+/// its own protocol version), killing its Wine processes, and wait for it
+/// to exit.
+pub fn shut_down(server: &RunningServer, prefix_path: &Path) -> Result<(), String> {
+    let proc_dir = PathBuf::from(format!("/proc/{}", server.pid));
+    let result = match &server.exe {
+        Some(exe) => Command::new(exe)
+            .arg("-k")
+            .env("WINEPREFIX", prefix_path)
+            .output()
+            .map(|_| ()),
+        None => Command::new("kill")
+            .arg(server.pid.to_string())
+            .output()
+            .map(|_| ()),
+    };
+    result.map_err(|e| format!("Failed to stop wineserver {}: {}", server.pid, e))?;
+
+    let started = Instant::now();
+    while proc_dir.exists() {
+        if started.elapsed() > SHUTDOWN_TIMEOUT {
+            return Err(format!(
+                "wineserver {} is still running after {} seconds",
+                server.pid,
+                SHUTDOWN_TIMEOUT.as_secs()
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    Ok(())
+}
+
+/// The read-only mount point a prefix is on, if it is.
+pub fn read_only_mount(prefix_path: &Path) -> Option<PathBuf> {
+    super::filesystem::mount_for(prefix_path)
+        .filter(|mount| mount.is_read_only())
+        .map(|mount| mount.point)
+}
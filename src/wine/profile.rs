@@ -0,0 +1,114 @@
+//! Per-prefix environment profile.
+//!
+//! Environment variables protontool applies when it launches a game from a
+//! prefix are stored in `protontool-env.conf` inside the prefix, one
+//! `KEY=VALUE` per line. Blank lines and lines starting with `#` are ignored,
+//! and values may be wrapped in double quotes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the environment profile inside a prefix.
+pub const PROFILE_FILE: &str = "protontool-env.conf";
+
+/// Environment variables for a prefix, in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvProfile {
+    pub vars: Vec<(String, String)>,
+}
+
+/// Path to the environment profile for a prefix.
+pub fn profile_path(prefix_path: &Path) -> PathBuf {
+    prefix_path.join(PROFILE_FILE)
+}
+
+impl EnvProfile {
+    /// Parse profile file content.
+    pub fn parse(content: &str) -> Self {
+        let mut profile = EnvProfile::default();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            if let Some((key, value)) = line.split_once('=') {
+                let key = key.trim();
+                if key.is_empty() {
+                    continue;
+                }
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .unwrap_or(value);
+                profile.set(key, value);
+            }
+        }
+        profile
+    }
+
+    /// Load the profile for a prefix. A missing file is an empty profile.
+    pub fn load(prefix_path: &Path) -> Self {
+        fs::read_to_string(profile_path(prefix_path))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Write the profile to a prefix.
+    pub fn save(&self, prefix_path: &Path) -> Result<(), String> {
+        let path = profile_path(prefix_path);
+        super::check_writable(&path)?;
+        let mut content = String::from("# Environment applied by protontool when launching\n");
+        for (key, value) in &self.vars {
+            content.push_str(&format!("{}=\"{}\"\n", key, value));
+        }
+        fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Get a variable's value.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.vars
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Set a variable, replacing any existing value.
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.vars.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => self.vars.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Remove a variable. Returns true if it was set.
+    pub fn unset(&mut self, key: &str) -> bool {
+        let before = self.vars.len();
+        self.vars.retain(|(k, _)| k != key);
+        self.vars.len() != before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vars.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profile() {
+        let profile = EnvProfile::parse(
+            "# comment\nDXVK_HUD=fps\nexport PROTON_NO_ESYNC=\"1\"\n\nDXVK_HUD=full\nbogus\n",
+        );
+        assert_eq!(
+            profile.vars,
+            vec![
+                ("DXVK_HUD".to_string(), "full".to_string()),
+                ("PROTON_NO_ESYNC".to_string(), "1".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,531 @@
+//! Import of protonfixes game fixes as GameFix verbs.
+//!
+//! protonfixes ships one Python module per game (`gamefixes-steam/<appid>.py`)
+//! whose `main()` is mostly a list of declarative calls such as
+//! `util.protontricks('vcrun2019')`, `util.set_environment('KEY', 'value')`
+//! and `util.winedll_override('d3d9', 'n')`. Those calls, or the equivalent
+//! JSON metadata, are converted into TOML verbs in the custom verb directory
+//! so they can be applied like any other verb.
+
+use std::fs;
+use std::path::Path;
+
+use super::custom::escape_toml_string;
+use crate::json::{self, JsonValue};
+
+/// Environment variables that are read by the Proton launcher script rather
+/// than by Windows processes, so they cannot be baked into the prefix.
+const LAUNCHER_ENV_PREFIXES: &[&str] = &["PROTON_", "WINE", "STEAM_", "SteamGame"];
+
+/// A game fix extracted from protonfixes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameFix {
+    /// Game identifier (Steam app ID or umu game ID).
+    pub id: String,
+    pub title: String,
+    /// Verbs requested through `util.protontricks()`.
+    pub verbs: Vec<String>,
+    /// Environment variables requested through `util.set_environment()`.
+    pub env: Vec<(String, String)>,
+    /// DLL overrides as (dll, wine mode string).
+    pub dll_overrides: Vec<(String, String)>,
+    /// Calls that could not be converted, kept for the import report.
+    pub skipped: Vec<String>,
+}
+
+impl GameFix {
+    /// Name of the generated verb, e.g. `gamefix_1091500`.
+    pub fn verb_name(&self) -> String {
+        let id: String = self
+            .id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("gamefix_{}", id.to_lowercase())
+    }
+
+    /// True if nothing in the fix could be converted into an action.
+    pub fn is_empty(&self) -> bool {
+        self.verbs.is_empty() && self.env.is_empty() && self.dll_overrides.is_empty()
+    }
+
+    /// Build the `.reg` content applying the overrides and environment.
+    fn registry_content(&self) -> Option<String> {
+        if self.env.is_empty() && self.dll_overrides.is_empty() {
+            return None;
+        }
+
+        let mut content = String::from("Windows Registry Editor Version 5.00\n");
+        if !self.dll_overrides.is_empty() {
+            content.push_str("\n[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n");
+            for (dll, mode) in &self.dll_overrides {
+                content.push_str(&format!(
+                    "\"{}\"=\"{}\"\n",
+                    reg_escape(dll),
+                    reg_escape(mode)
+                ));
+            }
+        }
+        if !self.env.is_empty() {
+            content.push_str("\n[HKEY_CURRENT_USER\\Environment]\n");
+            for (key, value) in &self.env {
+                content.push_str(&format!(
+                    "\"{}\"=\"{}\"\n",
+                    reg_escape(key),
+                    reg_escape(value)
+                ));
+            }
+        }
+        Some(content)
+    }
+
+    /// Render the fix as a custom verb TOML definition.
+    pub fn to_toml(&self, source: &str) -> String {
+        let mut out = format!("# Imported from protonfixes: {}\n", source);
+        for skipped in &self.skipped {
+            out.push_str(&format!("# not imported: {}\n", skipped));
+        }
+        out.push_str(&format!(
+            "\n[verb]\nname = \"{}\"\ncategory = \"gamefix\"\ntitle = \"{}\"\npublisher = \"protonfixes\"\nyear = \"\"\n",
+            self.verb_name(),
+            escape_toml_string(&self.title)
+        ));
+        for verb in &self.verbs {
+            out.push_str(&format!(
+                "\n[[actions]]\ntype = \"verb\"\nverb = \"{}\"\n",
+                escape_toml_string(verb)
+            ));
+        }
+        if let Some(content) = self.registry_content() {
+            out.push_str(&format!(
+                "\n[[actions]]\ntype = \"registry\"\ncontent = \"{}\"\n",
+                escape_toml_string(&content)
+            ));
+        }
+        out
+    }
+}
+
+/// Escape a string for a quoted .reg value.
+fn reg_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Convert a protonfixes override order (`'n,b'`, `OverrideOrder.NATIVE`, ...)
+/// into the value Wine stores under `DllOverrides`.
+fn override_mode(order: &str) -> Option<&'static str> {
+    let order = order.rsplit('.').next().unwrap_or(order);
+    match order.to_lowercase().replace(' ', "").as_str() {
+        "n" | "native" => Some("native"),
+        "b" | "builtin" => Some("builtin"),
+        "n,b" | "native_builtin" => Some("native,builtin"),
+        "b,n" | "builtin_native" => Some("builtin,native"),
+        "" | "d" | "disabled" => Some(""),
+        _ => None,
+    }
+}
+
+/// Parse a Python string literal (`'x'`, `"x"`, optionally `r'x'`).
+fn python_string(arg: &str) -> Option<String> {
+    let arg = arg.trim();
+    let arg = arg.strip_prefix('r').unwrap_or(arg);
+    let quote = arg.chars().next()?;
+    if (quote != '\'' && quote != '"') || arg.len() < 2 || !arg.ends_with(quote) {
+        return None;
+    }
+    Some(arg[1..arg.len() - 1].replace("\\\\", "\\"))
+}
+
+/// Split call arguments on top-level commas, ignoring commas inside strings
+/// and nested brackets. Keyword arguments keep their `name=` prefix.
+fn split_call_args(args: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+
+    for c in args.chars() {
+        match quote {
+            Some(q) => {
+                current.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '\'' | '"' => {
+                    quote = Some(c);
+                    current.push(c);
+                }
+                '(' | '[' | '{' => {
+                    depth += 1;
+                    current.push(c);
+                }
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    current.push(c);
+                }
+                ',' if depth == 0 => {
+                    parts.push(current.trim().to_string());
+                    current.clear();
+                }
+                _ => current.push(c),
+            },
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// Strip a `name=` keyword prefix from a call argument.
+fn positional_value(arg: &str) -> &str {
+    match arg.split_once('=') {
+        Some((name, value)) if name.trim().chars().all(|c| c.is_alphanumeric() || c == '_') => {
+            value.trim()
+        }
+        _ => arg,
+    }
+}
+
+/// Find every `util.<func>(...)` call in the source, returning the function
+/// name and the raw argument text.
+fn find_util_calls(content: &str) -> Vec<(String, String)> {
+    let mut calls = Vec::new();
+
+    for line in content.lines() {
+        let code = line.trim();
+        if code.starts_with('#') || code.starts_with("import") || code.starts_with("from") {
+            continue;
+        }
+
+        let mut rest = code;
+        while let Some(idx) = rest.find("util.") {
+            let after = &rest[idx + 5..];
+            let name_len = after
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            let name = &after[..name_len];
+            let tail = &after[name_len..];
+
+            if !tail.starts_with('(') {
+                rest = tail;
+                continue;
+            }
+
+            // Find the matching closing parenthesis on this line
+            let mut depth = 0;
+            let mut quote: Option<char> = None;
+            let mut end = None;
+            for (i, c) in tail.char_indices() {
+                match quote {
+                    Some(q) if c == q => quote = None,
+                    Some(_) => {}
+                    None => match c {
+                        '\'' | '"' => quote = Some(c),
+                        '(' => depth += 1,
+                        ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                end = Some(i);
+                                break;
+                            }
+                        }
+                        _ => {}
+                    },
+                }
+            }
+
+            match end {
+                Some(end) => {
+                    calls.push((name.to_string(), tail[1..end].to_string()));
+                    rest = &tail[end + 1..];
+                }
+                None => {
+                    calls.push((name.to_string(), String::new()));
+                    break;
+                }
+            }
+        }
+    }
+
+    calls
+}
+
+/// Extract the game title from the module docstring ("Game fix for X").
+fn parse_docstring_title(content: &str) -> Option<String> {
+    let trimmed = content.trim_start();
+    let body = trimmed
+        .strip_prefix("\"\"\"")
+        .or_else(|| trimmed.strip_prefix("'''"))?;
+    let first = body.lines().next()?.trim();
+    let first = first
+        .trim_end_matches("\"\"\"")
+        .trim_end_matches("'''")
+        .trim();
+    let title = first
+        .strip_prefix("Game fix for")
+        .or_else(|| first.strip_prefix("Gamefix for"))
+        .unwrap_or(first)
+        .trim();
+    if title.is_empty() {
+        None
+    } else {
+        Some(title.to_string())
+    }
+}
+
+/// Parse the declarative parts of a protonfixes gamefix Python module.
+pub fn parse_gamefix_module(id: &str, content: &str) -> GameFix {
+    let mut fix = GameFix {
+        id: id.to_string(),
+        title: parse_docstring_title(content)
+            .unwrap_or_else(|| format!("protonfixes fix for {}", id)),
+        ..Default::default()
+    };
+
+    for (func, raw_args) in find_util_calls(content) {
+        let args = split_call_args(&raw_args);
+        let call = format!("util.{}({})", func, raw_args);
+
+        match func.as_str() {
+            "protontricks" => match args
+                .first()
+                .and_then(|a| python_string(positional_value(a)))
+            {
+                Some(verb) => fix.verbs.push(verb),
+                None => fix.skipped.push(call),
+            },
+            "set_environment" => {
+                let key = args
+                    .first()
+                    .and_then(|a| python_string(positional_value(a)));
+                let value = args.get(1).and_then(|a| python_string(positional_value(a)));
+                match (key, value) {
+                    (Some(key), Some(_))
+                        if LAUNCHER_ENV_PREFIXES.iter().any(|p| key.starts_with(p)) =>
+                    {
+                        fix.skipped
+                            .push(format!("{} (read by the Proton launcher)", call));
+                    }
+                    (Some(key), Some(value)) => fix.env.push((key, value)),
+                    _ => fix.skipped.push(call),
+                }
+            }
+            "winedll_override" => {
+                let dll = args
+                    .first()
+                    .and_then(|a| python_string(positional_value(a)));
+                let mode = args.get(1).map(|a| {
+                    let a = positional_value(a);
+                    python_string(a).unwrap_or_else(|| a.to_string())
+                });
+                match (dll, mode.as_deref().and_then(override_mode)) {
+                    (Some(dll), Some(mode)) => fix.dll_overrides.push((dll, mode.to_string())),
+                    _ => fix.skipped.push(call),
+                }
+            }
+            _ => fix.skipped.push(call),
+        }
+    }
+
+    fix
+}
+
+/// Convert one JSON metadata object into a GameFix.
+fn gamefix_from_json(value: &JsonValue, fallback_id: &str) -> Result<GameFix, String> {
+    if value.as_object().is_none() {
+        return Err("Expected a JSON object for each game fix".to_string());
+    }
+
+    let id = value
+        .get("appid")
+        .or_else(|| value.get("id"))
+        .and_then(|v| v.to_plain_string())
+        .unwrap_or_else(|| fallback_id.to_string());
+    if id.is_empty() {
+        return Err("Game fix entry has no 'appid' or 'id'".to_string());
+    }
+
+    let mut fix = GameFix {
+        title: value
+            .get("title")
+            .or_else(|| value.get("name"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| format!("protonfixes fix for {}", id)),
+        id,
+        ..Default::default()
+    };
+
+    if let Some(verbs) = value.get("protontricks").and_then(|v| v.as_array()) {
+        fix.verbs = verbs
+            .iter()
+            .filter_map(|v| v.as_str().map(|s| s.to_string()))
+            .collect();
+    }
+
+    if let Some(env) = value.get("env").and_then(|v| v.as_object()) {
+        for (key, value) in env {
+            let Some(value) = value.to_plain_string() else {
+                fix.skipped.push(format!("env {}", key));
+                continue;
+            };
+            if LAUNCHER_ENV_PREFIXES.iter().any(|p| key.starts_with(p)) {
+                fix.skipped.push(format!(
+                    "env {}={} (read by the Proton launcher)",
+                    key, value
+                ));
+            } else {
+                fix.env.push((key.clone(), value));
+            }
+        }
+    }
+
+    if let Some(overrides) = value.get("dll_overrides").and_then(|v| v.as_object()) {
+        for (dll, mode) in overrides {
+            match mode.as_str().and_then(override_mode) {
+                Some(mode) => fix.dll_overrides.push((dll.clone(), mode.to_string())),
+                None => fix.skipped.push(format!("dll override {}", dll)),
+            }
+        }
+    }
+
+    Ok(fix)
+}
+
+/// Parse protonfixes JSON metadata: a single fix object or an array of them.
+///
+/// Recognized keys: `appid`/`id`, `title`/`name`, `protontricks` (array of
+/// verb names), `env` (object) and `dll_overrides` (object of dll to order).
+pub fn parse_gamefix_json(content: &str, fallback_id: &str) -> Result<Vec<GameFix>, String> {
+    let value = json::parse(content)?;
+    match &value {
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|item| gamefix_from_json(item, ""))
+            .collect(),
+        _ => Ok(vec![gamefix_from_json(&value, fallback_id)?]),
+    }
+}
+
+/// Import game fixes from a gamefix module, a JSON metadata file, or a
+/// directory containing either (e.g. protonfixes' `gamefixes-steam`).
+pub fn import_path(path: &Path) -> Result<Vec<GameFix>, String> {
+    if path.is_dir() {
+        let mut entries: Vec<_> = fs::read_dir(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .collect();
+        entries.sort();
+
+        let mut fixes = Vec::new();
+        for entry in entries {
+            let stem = entry
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            // Package plumbing and the global default fix are not per-game
+            if stem.starts_with("__") || stem == "default" {
+                continue;
+            }
+            match entry.extension().and_then(|e| e.to_str()) {
+                Some("py") | Some("json") => fixes.extend(import_path(&entry)?),
+                _ => {}
+            }
+        }
+        return Ok(fixes);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => {
+            parse_gamefix_json(&content, &stem).map_err(|e| format!("{}: {}", path.display(), e))
+        }
+        _ => Ok(vec![parse_gamefix_module(&stem, &content)]),
+    }
+}
+
+/// Write imported fixes as TOML verbs into `dest_dir`.
+/// Fixes without any convertible action are skipped. Returns the number of
+/// verb files written.
+pub fn write_gamefix_verbs(
+    fixes: &[GameFix],
+    dest_dir: &Path,
+    source: &str,
+) -> Result<usize, String> {
+    fs::create_dir_all(dest_dir)
+        .map_err(|e| format!("Failed to create {}: {}", dest_dir.display(), e))?;
+
+    let mut written = 0;
+    for fix in fixes.iter().filter(|f| !f.is_empty()) {
+        let path = dest_dir.join(format!("{}.toml", fix.verb_name()));
+        fs::write(&path, fix.to_toml(source))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gamefix_module() {
+        let module = r#"""" Game fix for Example Game
+"""
+# pylint: disable=C0103
+
+from protonfixes import util
+
+
+def main() -> None:
+    """Install dependencies"""
+    util.protontricks('vcrun2019')
+    util.protontricks("d3dx9")
+    util.set_environment('DXVK_ASYNC', '1')
+    util.set_environment('PROTON_NO_ESYNC', '1')
+    util.winedll_override('dinput8', util.OverrideOrder.NATIVE_BUILTIN)
+    util.disable_fsync()
+"#;
+        let fix = parse_gamefix_module("12345", module);
+        assert_eq!(fix.title, "Example Game");
+        assert_eq!(fix.verbs, vec!["vcrun2019", "d3dx9"]);
+        assert_eq!(fix.env, vec![("DXVK_ASYNC".to_string(), "1".to_string())]);
+        assert_eq!(
+            fix.dll_overrides,
+            vec![("dinput8".to_string(), "native,builtin".to_string())]
+        );
+        assert_eq!(fix.skipped.len(), 2);
+        assert_eq!(fix.verb_name(), "gamefix_12345");
+    }
+
+    #[test]
+    fn test_gamefix_toml_roundtrip() {
+        let json = r#"{"appid": 400, "title": "Portal", "protontricks": ["corefonts"],
+                       "dll_overrides": {"d3d9": "n"}}"#;
+        let fixes = parse_gamefix_json(json, "").unwrap();
+        let toml = fixes[0].to_toml("400.json");
+        let verb = super::super::custom::parse_toml_verb(&toml).unwrap();
+        assert_eq!(verb.name, "gamefix_400");
+        assert_eq!(verb.category, super::super::VerbCategory::GameFix);
+        assert_eq!(verb.actions.len(), 2);
+        match &verb.actions[1] {
+            super::super::verbs::VerbAction::Registry { content } => {
+                assert!(content.contains("[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n"));
+                assert!(content.contains("\"d3d9\"=\"native\""));
+            }
+            _ => panic!("expected registry action"),
+        }
+    }
+}
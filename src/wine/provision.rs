@@ -0,0 +1,194 @@
+//! Baseline provisioning of new prefixes.
+//!
+//! The `[provision]` section of `config.toml` lists verbs, a Windows version
+//! and environment variables applied to every prefix protontool creates:
+//!
+//! ```toml
+//! [provision]
+//! verbs = ["corefonts", "vcrun2022"]
+//! winver = "win10"
+//! env = ["DXVK_ASYNC=1", "PROTON_NO_ESYNC=1"]
+//! # Host folders linked into the prefix (see `userdirs`)
+//! user_dirs = "documents,downloads"
+//! # Also provision Steam game prefixes (new or reset by Steam)
+//! steam_prefixes = "true"
+//! ```
+//!
+//! Provisioned prefixes are marked with `provisioned` in their metadata; a
+//! Steam game prefix without the marker has been created or reset by Steam.
+//! Verbs for a prefix that is in use are deferred until it is idle (see
+//! `deferred`).
+
+use std::path::Path;
+
+use super::deferred;
+use super::metadata::PrefixMetadata;
+use super::profile::EnvProfile;
+use super::registry;
+use super::userdirs::{self, UserDirPolicy};
+use super::verbs::VerbRegistry;
+use super::WineContext;
+use crate::config::UserConfig;
+
+/// Metadata key marking a provisioned prefix.
+const PROVISIONED_KEY: &str = "provisioned";
+
+/// Baseline applied to new prefixes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Provisioning {
+    pub verbs: Vec<String>,
+    pub winver: Option<String>,
+    pub env: Vec<(String, String)>,
+    pub user_dirs: Option<UserDirPolicy>,
+    /// Whether Steam game prefixes are provisioned too.
+    pub steam_prefixes: bool,
+}
+
+impl Provisioning {
+    /// Read the `[provision]` section of a config.
+    pub fn from_config(config: &UserConfig) -> Self {
+        Provisioning {
+            verbs: config.get_array("provision", "verbs").unwrap_or_default(),
+            winver: config
+                .get("provision", "winver")
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string()),
+            env: config
+                .get_array("provision", "env")
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|var| {
+                    let (key, value) = var.split_once('=')?;
+                    Some((key.trim().to_string(), value.trim().to_string()))
+                })
+                .collect(),
+            user_dirs: config.get("provision", "user_dirs").and_then(|spec| {
+                UserDirPolicy::parse(spec)
+                    .map_err(|e| eprintln!("Warning: [provision] user_dirs: {}", e))
+                    .ok()
+            }),
+            steam_prefixes: config
+                .get("provision", "steam_prefixes")
+                .is_some_and(|v| matches!(v, "true" | "1" | "yes")),
+        }
+    }
+
+    /// Load the provisioning from `config.toml`.
+    pub fn load() -> Self {
+        Self::from_config(&UserConfig::load())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.verbs.is_empty()
+            && self.winver.is_none()
+            && self.env.is_empty()
+            && self.user_dirs.is_none()
+    }
+}
+
+/// Apply the configured baseline to a prefix and mark it provisioned.
+/// Steps that fail are reported as warnings; the others still run.
+pub fn provision_prefix(wine_ctx: &WineContext) {
+    let provisioning = Provisioning::load();
+    if provisioning.is_empty() {
+        return;
+    }
+    let prefix_path = &wine_ctx.prefix_path;
+    eprintln!("Applying provisioning from config.toml...");
+
+    if !provisioning.env.is_empty() {
+        let mut profile = EnvProfile::load(prefix_path);
+        for (key, value) in &provisioning.env {
+            profile.set(key, value);
+        }
+        if let Err(e) = profile.save(prefix_path) {
+            eprintln!("Warning: {}", e);
+        }
+    }
+
+    if let Some(policy) = &provisioning.user_dirs {
+        if let Err(e) = userdirs::set_policy(prefix_path, policy) {
+            eprintln!("Warning: {}", e);
+        }
+    }
+
+    if let Some(winver) = &provisioning.winver {
+        // Table entries carry exact builds; anything else goes to winecfg as is
+        if let Some(build) = registry::windows_build(winver) {
+            if let Err(e) = registry::set_windows_build(wine_ctx, build) {
+                eprintln!("Warning: Failed to set Windows version {}: {}", winver, e);
+            }
+        } else {
+            match wine_ctx.run_winecfg(&["-v", winver]) {
+                Ok(output) if output.status.success() => {}
+                Ok(_) => eprintln!("Warning: Failed to set Windows version {}", winver),
+                Err(e) => eprintln!("Warning: Failed to set Windows version {}: {}", winver, e),
+            }
+        }
+        wine_ctx.wait_for_wineserver().ok();
+    }
+
+    if !provisioning.verbs.is_empty() && !deferred::is_idle(prefix_path) {
+        // Don't replace DLLs under a running game
+        match deferred::queue(prefix_path, &provisioning.verbs) {
+            Ok(()) => eprintln!(
+                "The prefix is in use; provisioning verbs ({}) are queued. Apply them after \
+                 the game exits with: protontool APPID deferred run",
+                provisioning.verbs.join(", ")
+            ),
+            Err(e) => eprintln!("Warning: Failed to queue provisioning verbs: {}", e),
+        }
+    } else if !provisioning.verbs.is_empty() {
+        let registry = VerbRegistry::new();
+        let cache_dir = crate::config::get_cache_dir().join("wine");
+        std::fs::create_dir_all(&cache_dir).ok();
+        for verb in &provisioning.verbs {
+            eprintln!("Provisioning: running verb {}", verb);
+            if let Err(e) = registry.execute(verb, wine_ctx, &cache_dir) {
+                eprintln!("Warning: Provisioning verb {} failed: {}", verb, e);
+            }
+        }
+    }
+
+    let mut metadata = PrefixMetadata::load(prefix_path);
+    metadata.set(PROVISIONED_KEY, "true");
+    if let Err(e) = metadata.save(prefix_path) {
+        eprintln!("Warning: {}", e);
+    }
+}
+
+/// Whether the baseline has been applied to a prefix since it was created.
+pub fn is_provisioned(prefix_path: &Path) -> bool {
+    PrefixMetadata::load(prefix_path).get_bool(PROVISIONED_KEY)
+}
+
+/// Whether a Steam game prefix should be provisioned: enabled in the config,
+/// initialized by Steam and not provisioned since it was (re)created.
+pub fn steam_prefix_needs_provisioning(prefix_path: &Path) -> bool {
+    let provisioning = Provisioning::load();
+    provisioning.steam_prefixes
+        && !provisioning.is_empty()
+        && prefix_path.join("system.reg").exists()
+        && !is_provisioned(prefix_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provisioning_from_config() {
+        let config = UserConfig::parse(
+            "[provision]\nverbs = [\"corefonts\", \"vcrun2022\"]\nwinver = \"win10\"\nenv = [\"DXVK_ASYNC=1\", \"bogus\"]\n",
+        );
+        let provisioning = Provisioning::from_config(&config);
+        assert_eq!(provisioning.verbs, vec!["corefonts", "vcrun2022"]);
+        assert_eq!(provisioning.winver.as_deref(), Some("win10"));
+        assert_eq!(
+            provisioning.env,
+            vec![("DXVK_ASYNC".to_string(), "1".to_string())]
+        );
+        assert!(!provisioning.steam_prefixes);
+        assert!(Provisioning::from_config(&UserConfig::default()).is_empty());
+    }
+}
@@ -119,6 +119,308 @@ pub fn filter_registry_file(filename: &Path, filter_keys: &[&str]) -> std::io::R
     Ok(())
 }
 
+/// Map a full registry key to the prefix hive file holding it and the key
+/// path relative to that hive.
+fn hive_for_key(key: &str) -> Option<(&'static str, &str)> {
+    let (root, rest) = key.split_once('\\')?;
+    match root.to_uppercase().as_str() {
+        "HKEY_CURRENT_USER" | "HKCU" => Some(("user.reg", rest)),
+        "HKEY_LOCAL_MACHINE" | "HKLM" => Some(("system.reg", rest)),
+        _ => None,
+    }
+}
+
+/// Split a value line into its unescaped name and raw data, e.g.
+/// `"Version"="win10"` or `@=dword:00000001`.
+///
+/// ```
+/// use protontool::wine::registry::split_value_line;
+/// assert_eq!(
+///     split_value_line(r#""d3d11"="native""#),
+///     Some(("d3d11".to_string(), r#""native""#))
+/// );
+/// assert_eq!(split_value_line("@=dword:00000001"), Some(("@".to_string(), "dword:00000001")));
+/// assert_eq!(split_value_line("[Software]"), None);
+/// ```
+pub fn split_value_line(line: &str) -> Option<(String, &str)> {
+    let trimmed = line.trim();
+    if let Some(data) = trimmed.strip_prefix("@=") {
+        return Some(("@".to_string(), data));
+    }
+    let rest = trimmed.strip_prefix('"')?;
+    let mut name = String::new();
+    let mut chars = rest.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => name.push(chars.next()?.1),
+            '"' => {
+                let data = rest[i + 1..].trim_start().strip_prefix('=')?;
+                return Some((name, data.trim()));
+            }
+            c => name.push(c),
+        }
+    }
+    None
+}
+
+/// Registry values (`key`, `name`) written or deleted by .reg file content.
+/// Whole-key deletions (`[-KEY]`) are not included.
+///
+/// ```
+/// use protontool::wine::registry::reg_content_values;
+/// let content = "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine]\n\"Version\"=\"win10\"\n";
+/// assert_eq!(
+///     reg_content_values(content),
+///     vec![("HKEY_CURRENT_USER\\Software\\Wine".to_string(), "Version".to_string())]
+/// );
+/// ```
+pub fn reg_content_values(content: &str) -> Vec<(String, String)> {
+    reg_content_assignments(content)
+        .into_iter()
+        .map(|(key, name, _)| (key, name))
+        .collect()
+}
+
+/// Values set by .reg file content as (`key`, `name`, data), with data in
+/// .reg syntax and None for deletions (`"name"=-`). Hex data continued over
+/// several lines is joined.
+///
+/// ```
+/// use protontool::wine::registry::reg_content_assignments;
+/// let content = "[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n\"d3d9\"=\"native\"\n\"dinput8\"=-\n";
+/// let values = reg_content_assignments(content);
+/// assert_eq!(values[0].2.as_deref(), Some("\"native\""));
+/// assert_eq!(values[1].2, None);
+/// ```
+pub fn reg_content_assignments(content: &str) -> Vec<(String, String, Option<String>)> {
+    let mut values = Vec::new();
+    let mut current_key: Option<String> = None;
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if let Some(key) = trimmed.strip_prefix('[').and_then(|k| k.strip_suffix(']')) {
+            current_key = if key.starts_with('-') {
+                None
+            } else {
+                Some(key.to_string())
+            };
+            continue;
+        }
+        if let (Some(key), Some((name, data))) = (&current_key, split_value_line(trimmed)) {
+            let mut data = data.to_string();
+            while data.ends_with('\\') {
+                data.pop();
+                data.push_str(lines.next().unwrap_or_default().trim());
+            }
+            let data = if data == "-" { None } else { Some(data) };
+            values.push((key.clone(), name, data));
+        }
+    }
+    values
+}
+
+/// Read a value from a prefix's registry files without running Wine.
+///
+/// Returns the value data in .reg syntax (e.g. `"native"` or
+/// `dword:00000001`), or None if the key or value doesn't exist. Only
+/// `HKEY_CURRENT_USER` and `HKEY_LOCAL_MACHINE` keys are supported.
+pub fn read_prefix_value(prefix_path: &Path, key: &str, name: &str) -> Option<String> {
+    read_prefix_key_values(prefix_path, key)
+        .into_iter()
+        .find(|(value_name, _)| value_name.eq_ignore_ascii_case(name))
+        .map(|(_, data)| data)
+}
+
+/// Read all values of a key from a prefix's registry files without running
+/// Wine, as (`name`, data in .reg syntax) in file order. Empty if the key
+/// doesn't exist.
+pub fn read_prefix_key_values(prefix_path: &Path, key: &str) -> Vec<(String, String)> {
+    let mut values = Vec::new();
+    let (hive, key_path) = match hive_for_key(key) {
+        Some(h) => h,
+        None => return values,
+    };
+    let content = match fs::read_to_string(prefix_path.join(hive)) {
+        Ok(c) => c,
+        Err(_) => return values,
+    };
+
+    let mut in_key = false;
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        if let Some(file_key) = parse_registry_key_line(line) {
+            in_key = file_key
+                .replace("\\\\", "\\")
+                .eq_ignore_ascii_case(key_path);
+            continue;
+        }
+        if !in_key {
+            continue;
+        }
+        let (value_name, data) = match split_value_line(line) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        // Hex data may continue over several lines ending in a backslash
+        let mut data = data.to_string();
+        while data.ends_with('\\') {
+            data.pop();
+            data.push_str(lines.next().unwrap_or_default().trim());
+        }
+        // Wine stores REG_EXPAND_SZ as str(2):"..." which regedit doesn't accept
+        if let Some(expand) = data
+            .strip_prefix("str(2):\"")
+            .and_then(|d| d.strip_suffix('"'))
+        {
+            data = RegType::ExpandString.format_value(&expand.replace("\\\\", "\\"));
+        }
+        values.push((value_name, data));
+    }
+    values
+}
+
+/// Names of the direct subkeys of a key in a prefix's registry files,
+/// read without running Wine. Empty if the key doesn't exist.
+pub fn read_prefix_subkeys(prefix_path: &Path, key: &str) -> Vec<String> {
+    let mut subkeys: Vec<String> = Vec::new();
+    let (hive, key_path) = match hive_for_key(key) {
+        Some(h) => h,
+        None => return subkeys,
+    };
+    let content = match fs::read_to_string(prefix_path.join(hive)) {
+        Ok(c) => c,
+        Err(_) => return subkeys,
+    };
+
+    let parent = format!("{}\\", key_path.to_ascii_lowercase());
+    for line in content.lines() {
+        let file_key = match parse_registry_key_line(line) {
+            Some(k) => k.replace("\\\\", "\\"),
+            None => continue,
+        };
+        if file_key.len() <= parent.len() || !file_key.to_ascii_lowercase().starts_with(&parent) {
+            continue;
+        }
+        let child = file_key[parent.len()..]
+            .split('\\')
+            .next()
+            .unwrap_or_default();
+        if !child.is_empty() && !subkeys.iter().any(|k| k.eq_ignore_ascii_case(child)) {
+            subkeys.push(child.to_string());
+        }
+    }
+    subkeys
+}
+
+/// Delete a value from a prefix's registry files without running Wine.
+///
+/// Must not be used while the prefix's wineserver is running, as it would
+/// write the old value back. Returns false if the value didn't exist.
+pub fn delete_prefix_value(prefix_path: &Path, key: &str, name: &str) -> Result<bool, String> {
+    let (hive, key_path) = match hive_for_key(key) {
+        Some(h) => h,
+        None => return Err(format!("Unsupported registry key: {}", key)),
+    };
+    let hive_path = prefix_path.join(hive);
+    let content = match fs::read_to_string(&hive_path) {
+        Ok(c) => c,
+        Err(_) => return Ok(false),
+    };
+
+    let mut output = String::with_capacity(content.len());
+    let mut in_key = false;
+    let mut deleted = false;
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        if let Some(file_key) = parse_registry_key_line(line) {
+            in_key = file_key
+                .replace("\\\\", "\\")
+                .eq_ignore_ascii_case(key_path);
+        } else if in_key && !deleted {
+            if let Some((value_name, data)) = split_value_line(line) {
+                if value_name.eq_ignore_ascii_case(name) {
+                    // Skip continuation lines of multi-line hex data too
+                    let mut continued = data.ends_with('\\');
+                    while continued {
+                        continued = lines.next().is_some_and(|l| l.trim_end().ends_with('\\'));
+                    }
+                    deleted = true;
+                    continue;
+                }
+            }
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    if deleted {
+        super::check_writable(&hive_path)?;
+        fs::write(&hive_path, output)
+            .map_err(|e| format!("Failed to write {}: {}", hive_path.display(), e))?;
+    }
+    Ok(deleted)
+}
+
+/// A registry value a .reg import would change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryChange {
+    pub key: String,
+    pub name: String,
+    /// Current data in .reg syntax, None if unset.
+    pub before: Option<String>,
+    /// New data in .reg syntax, None if deleted.
+    pub after: Option<String>,
+}
+
+impl RegistryChange {
+    /// One-line `key\name: before → after` description.
+    pub fn describe(&self) -> String {
+        format!(
+            "{}\\{}: {} → {}",
+            self.key,
+            self.name,
+            self.before.as_deref().unwrap_or("(unset)"),
+            self.after.as_deref().unwrap_or("(deleted)")
+        )
+    }
+}
+
+/// Compare .reg content with the values currently in a prefix, read with
+/// [`read_prefix_value`]. Values that wouldn't change are left out.
+pub fn preview_reg_content(prefix_path: &Path, content: &str) -> Vec<RegistryChange> {
+    let mut changes: Vec<RegistryChange> = Vec::new();
+    for (key, name, after) in reg_content_assignments(content) {
+        // A later assignment to the same value wins
+        changes
+            .retain(|c| !(c.key.eq_ignore_ascii_case(&key) && c.name.eq_ignore_ascii_case(&name)));
+        let before = read_prefix_value(prefix_path, &key, &name);
+        let unchanged = match (&before, &after) {
+            (Some(b), Some(a)) => b.eq_ignore_ascii_case(a),
+            (None, None) => true,
+            _ => false,
+        };
+        if !unchanged {
+            changes.push(RegistryChange {
+                key,
+                name,
+                before,
+                after,
+            });
+        }
+    }
+    changes
+}
+
+/// Value name as written in a .reg file (`@` for the default value).
+fn quote_value_name(name: &str) -> String {
+    if name == "@" {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
 /// Helper for modifying the Windows registry within a Wine prefix.
 pub struct RegistryEditor<'a> {
     wine_ctx: &'a WineContext,
@@ -148,11 +450,25 @@ impl<'a> RegistryEditor<'a> {
         self.apply_reg_content(&reg_content)
     }
 
+    /// Set a registry value from data already in .reg syntax (e.g. as
+    /// returned by [`read_prefix_value`]).
+    pub fn set_raw_value(&self, key: &str, name: &str, data: &str) -> Result<(), String> {
+        let reg_content = format!(
+            "Windows Registry Editor Version 5.00\n\n[{}]\n{}={}",
+            key,
+            quote_value_name(name),
+            data
+        );
+
+        self.apply_reg_content(&reg_content)
+    }
+
     /// Delete a specific registry value.
     pub fn delete_value(&self, key: &str, name: &str) -> Result<(), String> {
         let reg_content = format!(
-            "Windows Registry Editor Version 5.00\n\n[{}]\n\"{}\"=-",
-            key, name
+            "Windows Registry Editor Version 5.00\n\n[{}]\n{}=-",
+            key,
+            quote_value_name(name)
         );
 
         self.apply_reg_content(&reg_content)
@@ -175,20 +491,15 @@ impl<'a> RegistryEditor<'a> {
 
     /// Write registry content to a temp file and apply it via regedit.
     fn apply_reg_content(&self, content: &str) -> Result<(), String> {
-        let temp_dir = std::env::temp_dir();
-        let temp_file = temp_dir.join("protontool_reg_patch.reg");
+        let work_dir = crate::workdir::WorkDir::create("reg_patch")?;
+        let temp_file = work_dir.file("reg_patch", "reg");
 
         std::fs::write(&temp_file, content)
             .map_err(|e| format!("Failed to write temp registry file: {}", e))?;
 
-        let result = self
-            .wine_ctx
+        self.wine_ctx
             .run_regedit(&temp_file)
-            .map_err(|e| format!("Failed to apply registry patch: {}", e));
-
-        std::fs::remove_file(&temp_file).ok();
-
-        result?;
+            .map_err(|e| format!("Failed to apply registry patch: {}", e))?;
         Ok(())
     }
 }
@@ -252,58 +563,406 @@ impl RegType {
     }
 }
 
-/// Set the Windows version reported by Wine to applications.
-pub fn set_windows_version(wine_ctx: &WineContext, version: WindowsVersion) -> Result<(), String> {
-    let editor = RegistryEditor::new(wine_ctx);
-
-    let (product_name, csd_version, build, build_num, current_version, csd_dword) = match version {
-        WindowsVersion::Win11 => ("Microsoft Windows 11", "", "22000", "22000", "6.3", 0u32),
-        WindowsVersion::Win10 => ("Microsoft Windows 10", "", "19041", "19041", "6.3", 0),
-        WindowsVersion::Win81 => ("Microsoft Windows 8.1", "", "9600", "9600", "6.3", 0),
-        WindowsVersion::Win8 => ("Microsoft Windows 8", "", "9200", "9200", "6.2", 0),
-        WindowsVersion::Win7 => (
-            "Microsoft Windows 7",
-            "Service Pack 1",
-            "7601",
-            "7601",
-            "6.1",
-            0x100,
-        ),
-        WindowsVersion::Vista => (
-            "Microsoft Windows Vista",
-            "Service Pack 2",
-            "6002",
-            "6002",
-            "6.0",
-            0x200,
-        ),
-        WindowsVersion::WinXP => (
-            "Microsoft Windows XP",
-            "Service Pack 3",
-            "2600",
-            "2600",
-            "5.1",
-            0x300,
-        ),
-    };
+/// A Windows release as reported to applications: one row of the winver table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowsBuild {
+    /// Verb name, e.g. `win10_22h2`.
+    pub name: &'static str,
+    /// Human-readable name shown in the GUI picker.
+    pub description: &'static str,
+    /// `ProductName` as Windows stores it; always English, even on localized
+    /// installs. Windows 11 still reports "Windows 10".
+    pub product_name: &'static str,
+    pub major: u32,
+    pub minor: u32,
+    pub build: u32,
+    /// Service pack number, 0 for none.
+    pub service_pack: u32,
+    /// Feature update (`DisplayVersion`), e.g. "22H2"; empty before Windows 10.
+    pub display_version: &'static str,
+    pub server: bool,
+    /// Release year, used for the verb listing.
+    pub year: &'static str,
+}
+
+const fn client(
+    name: &'static str,
+    description: &'static str,
+    product_name: &'static str,
+    (major, minor, build): (u32, u32, u32),
+    service_pack: u32,
+    display_version: &'static str,
+    year: &'static str,
+) -> WindowsBuild {
+    WindowsBuild {
+        name,
+        description,
+        product_name,
+        major,
+        minor,
+        build,
+        service_pack,
+        display_version,
+        server: false,
+        year,
+    }
+}
+
+const fn server(
+    name: &'static str,
+    description: &'static str,
+    (major, minor, build): (u32, u32, u32),
+    service_pack: u32,
+    display_version: &'static str,
+    year: &'static str,
+) -> WindowsBuild {
+    WindowsBuild {
+        server: true,
+        ..client(
+            name,
+            description,
+            description,
+            (major, minor, build),
+            service_pack,
+            display_version,
+            year,
+        )
+    }
+}
+
+/// Windows versions protontool can set, newest first, clients before servers.
+///
+/// The plain names (`win10`, `win11`, ...) match the ones `winecfg -v`
+/// accepts; build-specific entries exist because some installers check exact
+/// builds or feature updates.
+pub const WINDOWS_VERSIONS: &[WindowsBuild] = &[
+    client(
+        "win11_24h2",
+        "Windows 11 24H2",
+        "Windows 10 Pro",
+        (10, 0, 26100),
+        0,
+        "24H2",
+        "2024",
+    ),
+    client(
+        "win11_23h2",
+        "Windows 11 23H2",
+        "Windows 10 Pro",
+        (10, 0, 22631),
+        0,
+        "23H2",
+        "2023",
+    ),
+    client(
+        "win11_22h2",
+        "Windows 11 22H2",
+        "Windows 10 Pro",
+        (10, 0, 22621),
+        0,
+        "22H2",
+        "2022",
+    ),
+    client(
+        "win11",
+        "Windows 11",
+        "Windows 10 Pro",
+        (10, 0, 22000),
+        0,
+        "21H2",
+        "2021",
+    ),
+    client(
+        "win10_22h2",
+        "Windows 10 22H2",
+        "Windows 10 Pro",
+        (10, 0, 19045),
+        0,
+        "22H2",
+        "2022",
+    ),
+    client(
+        "win10_21h2",
+        "Windows 10 21H2",
+        "Windows 10 Pro",
+        (10, 0, 19044),
+        0,
+        "21H2",
+        "2021",
+    ),
+    client(
+        "win10",
+        "Windows 10",
+        "Windows 10 Pro",
+        (10, 0, 19041),
+        0,
+        "2004",
+        "2015",
+    ),
+    client(
+        "win10_1809",
+        "Windows 10 1809",
+        "Windows 10 Pro",
+        (10, 0, 17763),
+        0,
+        "1809",
+        "2018",
+    ),
+    client(
+        "win81",
+        "Windows 8.1",
+        "Windows 8.1 Pro",
+        (6, 3, 9600),
+        0,
+        "",
+        "2013",
+    ),
+    client(
+        "win8",
+        "Windows 8",
+        "Windows 8 Pro",
+        (6, 2, 9200),
+        0,
+        "",
+        "2012",
+    ),
+    client(
+        "win7",
+        "Windows 7",
+        "Windows 7 Professional",
+        (6, 1, 7601),
+        1,
+        "",
+        "2009",
+    ),
+    client(
+        "vista",
+        "Windows Vista",
+        "Windows Vista Business",
+        (6, 0, 6002),
+        2,
+        "",
+        "2007",
+    ),
+    client(
+        "winxp64",
+        "Windows XP (64-bit)",
+        "Microsoft Windows XP",
+        (5, 2, 3790),
+        2,
+        "",
+        "2005",
+    ),
+    client(
+        "winxp",
+        "Windows XP",
+        "Microsoft Windows XP",
+        (5, 1, 2600),
+        3,
+        "",
+        "2001",
+    ),
+    client(
+        "win2k",
+        "Windows 2000",
+        "Microsoft Windows 2000",
+        (5, 0, 2195),
+        4,
+        "",
+        "2000",
+    ),
+    client(
+        "win98",
+        "Windows 98",
+        "Microsoft Windows 98",
+        (4, 10, 2222),
+        0,
+        "",
+        "1998",
+    ),
+    server(
+        "win2025",
+        "Windows Server 2025 Standard",
+        (10, 0, 26100),
+        0,
+        "24H2",
+        "2024",
+    ),
+    server(
+        "win2022",
+        "Windows Server 2022 Standard",
+        (10, 0, 20348),
+        0,
+        "21H2",
+        "2021",
+    ),
+    server(
+        "win2019",
+        "Windows Server 2019 Standard",
+        (10, 0, 17763),
+        0,
+        "1809",
+        "2018",
+    ),
+    server(
+        "win2016",
+        "Windows Server 2016 Standard",
+        (10, 0, 14393),
+        0,
+        "1607",
+        "2016",
+    ),
+    server(
+        "win2012r2",
+        "Windows Server 2012 R2 Standard",
+        (6, 3, 9600),
+        0,
+        "",
+        "2013",
+    ),
+    server(
+        "win2012",
+        "Windows Server 2012 Standard",
+        (6, 2, 9200),
+        0,
+        "",
+        "2012",
+    ),
+    server(
+        "win2008r2",
+        "Windows Server 2008 R2 Standard",
+        (6, 1, 7601),
+        1,
+        "",
+        "2009",
+    ),
+    server(
+        "win2008",
+        "Windows Server 2008 Standard",
+        (6, 0, 6002),
+        2,
+        "",
+        "2008",
+    ),
+    server(
+        "win2003",
+        "Windows Server 2003",
+        (5, 2, 3790),
+        2,
+        "",
+        "2003",
+    ),
+];
+
+/// Look up a Windows version by verb name.
+///
+/// ```
+/// use protontool::wine::registry::windows_build;
+/// let build = windows_build("win10_22h2").unwrap();
+/// assert_eq!(build.version_string(), "10.0.19045");
+/// assert!(windows_build("win2019").unwrap().server);
+/// assert!(windows_build("win3000").is_none());
+/// ```
+pub fn windows_build(name: &str) -> Option<&'static WindowsBuild> {
+    WINDOWS_VERSIONS.iter().find(|v| v.name == name)
+}
 
-    let reg_content = format!(
-        r#"Windows Registry Editor Version 5.00
+impl WindowsBuild {
+    /// Version as `major.minor.build`.
+    pub fn version_string(&self) -> String {
+        format!("{}.{}.{}", self.major, self.minor, self.build)
+    }
 
-[HKEY_LOCAL_MACHINE\Software\Microsoft\Windows NT\CurrentVersion]
-"ProductName"="{}"
-"CSDVersion"="{}"
-"CurrentBuild"="{}"
-"CurrentBuildNumber"="{}"
-"CurrentVersion"="{}"
+    /// Registry content that makes Wine report this version.
+    ///
+    /// NT versions are written to the `CurrentVersion` keys Windows itself
+    /// uses and the global `Version` override is removed, so Wine reports the
+    /// exact build from there. Windows 9x has no such keys and is set through
+    /// the override instead.
+    pub fn reg_content(&self) -> String {
+        if self.major < 5 {
+            return format!(
+                "Windows Registry Editor Version 5.00\n\n\
+                 [HKEY_CURRENT_USER\\Software\\Wine]\n\
+                 \"Version\"=\"{}\"\n",
+                self.name
+            );
+        }
+        let string_or_delete = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                format!("\"{}\"", value)
+            }
+        };
+        let service_pack = if self.service_pack > 0 {
+            format!("Service Pack {}", self.service_pack)
+        } else {
+            String::new()
+        };
+        // Windows 10 and later keep CurrentVersion at 6.3 for compatibility
+        // and report the real version in the *VersionNumber values
+        let (current_version, major_number, minor_number) = if self.major >= 10 {
+            (
+                "6.3".to_string(),
+                format!("dword:{:08x}", self.major),
+                format!("dword:{:08x}", self.minor),
+            )
+        } else {
+            (
+                format!("{}.{}", self.major, self.minor),
+                "-".to_string(),
+                "-".to_string(),
+            )
+        };
+        let (installation_type, edition, product_type) = if self.server {
+            ("Server", "ServerStandard", "ServerNT")
+        } else {
+            ("Client", "Professional", "WinNT")
+        };
+        format!(
+            "Windows Registry Editor Version 5.00\n\n\
+             [HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows NT\\CurrentVersion]\n\
+             \"ProductName\"=\"{}\"\n\
+             \"EditionID\"=\"{}\"\n\
+             \"InstallationType\"=\"{}\"\n\
+             \"CurrentVersion\"=\"{}\"\n\
+             \"CurrentBuild\"=\"{}\"\n\
+             \"CurrentBuildNumber\"=\"{}\"\n\
+             \"CurrentMajorVersionNumber\"={}\n\
+             \"CurrentMinorVersionNumber\"={}\n\
+             \"CSDVersion\"={}\n\
+             \"DisplayVersion\"={}\n\n\
+             [HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Control\\ProductOptions]\n\
+             \"ProductType\"=\"{}\"\n\n\
+             [HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Control\\Windows]\n\
+             \"CSDVersion\"=dword:{:08x}\n\n\
+             [HKEY_CURRENT_USER\\Software\\Wine]\n\
+             \"Version\"=-\n",
+            self.product_name,
+            edition,
+            installation_type,
+            current_version,
+            self.build,
+            self.build,
+            major_number,
+            minor_number,
+            string_or_delete(&service_pack),
+            string_or_delete(self.display_version),
+            product_type,
+            self.service_pack << 8
+        )
+    }
+}
 
-[HKEY_LOCAL_MACHINE\System\CurrentControlSet\Control\Windows]
-"CSDVersion"=dword:{:08x}
-"#,
-        product_name, csd_version, build, build_num, current_version, csd_dword
-    );
+/// Set the Windows version reported by Wine to applications.
+pub fn set_windows_version(wine_ctx: &WineContext, version: WindowsVersion) -> Result<(), String> {
+    set_windows_build(wine_ctx, version.build())
+}
 
-    editor.apply_reg_content(&reg_content)
+/// Set the Windows version to an entry of [`WINDOWS_VERSIONS`].
+pub fn set_windows_build(wine_ctx: &WineContext, build: &WindowsBuild) -> Result<(), String> {
+    RegistryEditor::new(wine_ctx).apply_reg_content(&build.reg_content())
 }
 
 /// Supported Windows versions for Wine compatibility settings.
@@ -339,4 +998,18 @@ impl WindowsVersion {
             _ => None,
         }
     }
+
+    /// The winver table entry for this version.
+    pub fn build(&self) -> &'static WindowsBuild {
+        let name = match self {
+            Self::Win11 => "win11",
+            Self::Win10 => "win10",
+            Self::Win81 => "win81",
+            Self::Win8 => "win8",
+            Self::Win7 => "win7",
+            Self::Vista => "vista",
+            Self::WinXP => "winxp",
+        };
+        windows_build(name).expect("every WindowsVersion is in WINDOWS_VERSIONS")
+    }
 }
@@ -0,0 +1,255 @@
+//! Runner capability probing.
+//!
+//! Wine-staging and TKG builds (and Proton forks built on them, like
+//! GE-Proton) carry patches with extra knobs that plain Proton ignores. The
+//! runner's flavour is read from its build id and name, and each optional
+//! feature is confirmed by finding the setting it reads (a registry value or
+//! environment variable name) in the runner's binaries, so settings verbs
+//! are only offered for knobs the runner actually has.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directories of a Proton build that hold Wine binaries, relative to its
+/// `dist` or `files` directory.
+const BINARY_DIRS: &[&str] = &[
+    "lib/wine/x86_64-unix",
+    "lib64/wine/x86_64-unix",
+    "lib/wine/x86_64-windows",
+    "lib64/wine/x86_64-windows",
+    "lib/wine/i386-windows",
+    "bin",
+];
+
+/// Which Wine a runner is built on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunnerFlavor {
+    Proton,
+    Staging,
+    Tkg,
+}
+
+impl RunnerFlavor {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunnerFlavor::Proton => "Proton",
+            RunnerFlavor::Staging => "Wine-staging",
+            RunnerFlavor::Tkg => "Wine-TKG",
+        }
+    }
+
+    /// Whether staging patches (and so staging features) can be present.
+    pub fn is_staging_based(&self) -> bool {
+        matches!(self, RunnerFlavor::Staging | RunnerFlavor::Tkg)
+    }
+}
+
+/// An optional feature of staging-based runners.
+#[derive(Debug, Clone, Copy)]
+pub struct StagingFeature {
+    /// Settings verb prefix, e.g. `eax` for `eax=on` / `eax=off`.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Setting name the runner reads; found in its binaries when supported.
+    pub marker: &'static str,
+    /// Binaries that read the setting.
+    pub files: &'static [&'static str],
+}
+
+/// Staging features protontool knows how to toggle.
+pub const STAGING_FEATURES: &[StagingFeature] = &[
+    StagingFeature {
+        name: "eax",
+        description: "EAX audio effects in DirectSound",
+        marker: "EAXEnabled",
+        files: &["dsound.dll"],
+    },
+    StagingFeature {
+        name: "large_address_aware",
+        description: "4 GB address space for 32-bit programs",
+        marker: "WINE_LARGE_ADDRESS_AWARE",
+        files: &["ntdll.so", "ntdll.dll"],
+    },
+    StagingFeature {
+        name: "staging_sharedmem",
+        description: "Shared memory between wineserver and programs",
+        marker: "STAGING_SHARED_MEMORY",
+        files: &["wineserver", "ntdll.so"],
+    },
+    StagingFeature {
+        name: "staging_writecopy",
+        description: "Copy-on-write mappings for PE images",
+        marker: "STAGING_WRITECOPY",
+        files: &["ntdll.so", "ntdll.dll"],
+    },
+];
+
+/// What a runner is and which staging features it supports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunnerCapabilities {
+    pub flavor: RunnerFlavor,
+    /// Wine build id, e.g. `wine-9.0 (Staging)`, when found.
+    pub build_id: Option<String>,
+    /// Names of the supported [`STAGING_FEATURES`].
+    pub features: Vec<&'static str>,
+}
+
+impl RunnerCapabilities {
+    /// Probe a Proton installation directory.
+    pub fn probe(proton_path: &Path) -> Self {
+        let build_id = runner_files(proton_path, "ntdll.so")
+            .iter()
+            .find_map(|path| fs::read(path).ok().and_then(|data| find_build_id(&data)));
+        let name = proton_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let version = fs::read_to_string(proton_path.join("version")).unwrap_or_default();
+        let flavor = detect_flavor(&[&name, &version, build_id.as_deref().unwrap_or("")]);
+
+        let features = if flavor.is_staging_based() {
+            STAGING_FEATURES
+                .iter()
+                .filter(|feature| has_feature(proton_path, feature))
+                .map(|feature| feature.name)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        RunnerCapabilities {
+            flavor,
+            build_id,
+            features,
+        }
+    }
+
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.contains(&feature)
+    }
+}
+
+/// Runner flavour from its name, version file and build id.
+///
+/// ```
+/// use protontool::wine::runner::{detect_flavor, RunnerFlavor};
+/// assert_eq!(detect_flavor(&["Proton 9.0", "wine-9.0"]), RunnerFlavor::Proton);
+/// assert_eq!(detect_flavor(&["GE-Proton9-20", ""]), RunnerFlavor::Staging);
+/// assert_eq!(detect_flavor(&["wine-9.5 (TkG Staging Esync Fsync)"]), RunnerFlavor::Tkg);
+/// ```
+pub fn detect_flavor(names: &[&str]) -> RunnerFlavor {
+    let names: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    if names.iter().any(|n| n.contains("tkg")) {
+        RunnerFlavor::Tkg
+    } else if names
+        .iter()
+        .any(|n| n.contains("staging") || n.starts_with("ge-proton"))
+    {
+        RunnerFlavor::Staging
+    } else {
+        RunnerFlavor::Proton
+    }
+}
+
+/// Whether a runner ships a Windows program (`NAME.exe`); some Proton builds
+/// strip tools like `wordpad`. Runners whose layout isn't recognized are
+/// assumed to have it.
+pub fn has_program(proton_path: &Path, name: &str) -> bool {
+    let recognized = ["dist", "files"].iter().any(|base| {
+        BINARY_DIRS
+            .iter()
+            .filter(|dir| dir.ends_with("-windows"))
+            .any(|dir| proton_path.join(base).join(dir).is_dir())
+    });
+    !recognized || !runner_files(proton_path, &format!("{}.exe", name)).is_empty()
+}
+
+/// Existing copies of a Wine binary in a Proton installation.
+fn runner_files(proton_path: &Path, file_name: &str) -> Vec<PathBuf> {
+    ["dist", "files"]
+        .iter()
+        .flat_map(|base| {
+            BINARY_DIRS
+                .iter()
+                .map(move |dir| proton_path.join(base).join(dir).join(file_name))
+        })
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// Whether any of the feature's binaries mentions its setting, as ASCII or
+/// UTF-16LE.
+fn has_feature(proton_path: &Path, feature: &StagingFeature) -> bool {
+    let ascii = feature.marker.as_bytes().to_vec();
+    let wide: Vec<u8> = feature
+        .marker
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    feature
+        .files
+        .iter()
+        .flat_map(|file| runner_files(proton_path, file))
+        .filter_map(|path| fs::read(path).ok())
+        .any(|data| contains(&data, &ascii) || contains(&data, &wide))
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// The `wine-X.Y (...)` build id string embedded in ntdll.
+fn find_build_id(data: &[u8]) -> Option<String> {
+    let needle = b"wine-";
+    let mut start = 0;
+    while let Some(pos) = data[start..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+    {
+        let begin = start + pos;
+        let end = data[begin..]
+            .iter()
+            .position(|&b| b == 0 || !(0x20..0x7f).contains(&b))
+            .map_or(data.len(), |len| begin + len);
+        let text = String::from_utf8_lossy(&data[begin..end]).to_string();
+        // Build ids start with a version number
+        if text[needle.len()..].starts_with(|c: char| c.is_ascii_digit()) {
+            return Some(text);
+        }
+        start = begin + needle.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_staging_runner() {
+        let runner = std::env::temp_dir().join(format!("pt-runner-{}", std::process::id()));
+        let unix_dir = runner.join("files/lib/wine/x86_64-unix");
+        fs::create_dir_all(&unix_dir).unwrap();
+        fs::write(
+            unix_dir.join("ntdll.so"),
+            b"\x7fELF\0wine-\0wine-9.0 (Staging)\0STAGING_WRITECOPY\0",
+        )
+        .unwrap();
+
+        let capabilities = RunnerCapabilities::probe(&runner);
+        assert_eq!(capabilities.flavor, RunnerFlavor::Staging);
+        assert_eq!(capabilities.build_id.as_deref(), Some("wine-9.0 (Staging)"));
+        assert_eq!(capabilities.features, vec!["staging_writecopy"]);
+        assert!(!capabilities.supports("eax"));
+
+        assert!(has_program(&runner, "wordpad"));
+        let pe_dir = runner.join("files/lib/wine/x86_64-windows");
+        fs::create_dir_all(&pe_dir).unwrap();
+        fs::write(pe_dir.join("notepad.exe"), b"MZ").unwrap();
+        assert!(has_program(&runner, "notepad"));
+        assert!(!has_program(&runner, "wordpad"));
+
+        fs::remove_dir_all(&runner).ok();
+    }
+}
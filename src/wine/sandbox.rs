@@ -0,0 +1,92 @@
+//! Prefix sandboxing for untrusted Windows software.
+//!
+//! A sandboxed prefix has its user folders (Desktop, Documents, ...) as
+//! plain directories instead of links into `$HOME`, and no `Z:` drive
+//! mapping the whole host filesystem. The choice is stored as `sandboxed`
+//! in the prefix metadata so protontool can warn about verbs and programs
+//! that would break the isolation.
+
+use std::path::Path;
+
+use super::drives;
+use super::metadata::PrefixMetadata;
+use super::userdirs::{self, UserDirPolicy};
+use super::verbs::{Verb, VerbAction};
+
+/// Metadata key recording whether a prefix is sandboxed.
+const SANDBOX_KEY: &str = "sandboxed";
+
+/// Whether a prefix is marked as sandboxed.
+pub fn is_sandboxed(prefix_path: &Path) -> bool {
+    PrefixMetadata::load(prefix_path).get_bool(SANDBOX_KEY)
+}
+
+/// Replace user folder links into `$HOME` with empty directories.
+/// Returns the number of links removed.
+pub fn isolate_home(prefix_path: &Path) -> Result<usize, String> {
+    userdirs::apply_policy(prefix_path, &UserDirPolicy::none()).map(|report| report.changed)
+}
+
+/// Link empty user folders back to the host, following the prefix's user
+/// dirs policy (all folders if none was set). Folders with content are left
+/// alone. Returns the number of links created.
+pub fn restore_home(prefix_path: &Path) -> usize {
+    let policy = userdirs::policy(prefix_path).unwrap_or_else(UserDirPolicy::all);
+    userdirs::apply_policy(prefix_path, &policy)
+        .map(|report| report.changed)
+        .unwrap_or(0)
+}
+
+/// Turn sandboxing of a prefix on or off and record it in the metadata.
+pub fn set_sandboxed(prefix_path: &Path, sandboxed: bool) -> Result<(), String> {
+    if sandboxed {
+        isolate_home(prefix_path)?;
+        drives::unmap_drive(prefix_path, 'z')?;
+    } else {
+        restore_home(prefix_path);
+        if drives::drive_target(prefix_path, 'z').is_none() {
+            drives::map_drive(prefix_path, 'z', Path::new("/"))?;
+        }
+    }
+
+    let mut metadata = PrefixMetadata::load(prefix_path);
+    metadata.set(SANDBOX_KEY, if sandboxed { "true" } else { "false" });
+    metadata.save(prefix_path)
+}
+
+/// Reasons a verb would break the isolation of a sandboxed prefix.
+pub fn verb_warnings(verb: &Verb) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for action in &verb.actions {
+        match action {
+            VerbAction::RunScript { script_path } => warnings.push(format!(
+                "{} runs the shell script {} on the host, outside the sandbox",
+                verb.name,
+                script_path.display()
+            )),
+            VerbAction::Plugin { name, .. } => warnings.push(format!(
+                "{} runs the plugin '{}' on the host, outside the sandbox",
+                verb.name, name
+            )),
+            _ => {}
+        }
+    }
+    if verb.name == "mimeassoc=on" {
+        warnings.push(format!(
+            "{} exports Windows file associations to the host desktop",
+            verb.name
+        ));
+    }
+    warnings
+}
+
+/// Warning for running a program stored outside a sandboxed prefix.
+pub fn program_warning(prefix_path: &Path, program: &Path) -> Option<String> {
+    if program.starts_with(prefix_path.join("drive_c")) {
+        return None;
+    }
+    Some(format!(
+        "{} is outside the sandboxed prefix. Files next to it may be inaccessible without the Z: drive; copy it into drive_c if it fails to start.",
+        program.display()
+    ))
+}
@@ -0,0 +1,278 @@
+//! Process priority, CPU affinity, GameMode and memory for programs run in
+//! a prefix.
+//!
+//! The settings are kept in the prefix's environment profile and applied by
+//! wrapping the launched command with the usual tools, the same way Steam
+//! launch options do:
+//!
+//! ```text
+//! PROTONTOOL_NICE=5                   # nice -n 5
+//! PROTONTOOL_CPU_AFFINITY=0-7         # taskset -c 0-7 (e.g. P-cores only)
+//! PROTONTOOL_GAMEMODE=1               # gamemoderun
+//! PROTONTOOL_MEMORY_MAX=6G            # systemd-run --user --scope -p MemoryMax=6G
+//! PROTONTOOL_LARGE_ADDRESS_AWARE=1    # WINE_LARGE_ADDRESS_AWARE=1
+//! ```
+//!
+//! Wine processes started by the program inherit the priority and affinity,
+//! and run in the same transient systemd scope, whose cgroup v2 memory limit
+//! covers them all. Large address awareness gives 32-bit games that run out
+//! of address space 4 GB instead of 2 GB; it is set for Wine
+//! (`WINE_LARGE_ADDRESS_AWARE`) and Proton
+//! (`PROTON_FORCE_LARGE_ADDRESS_AWARE`). Tools that aren't installed are
+//! skipped.
+
+use std::process::Command;
+
+use super::profile::EnvProfile;
+use crate::util::which;
+
+/// Profile variable holding the nice level.
+pub const NICE_VAR: &str = "PROTONTOOL_NICE";
+/// Profile variable holding the CPU list.
+pub const CPU_AFFINITY_VAR: &str = "PROTONTOOL_CPU_AFFINITY";
+/// Profile variable requesting GameMode.
+pub const GAMEMODE_VAR: &str = "PROTONTOOL_GAMEMODE";
+/// Profile variable holding the memory limit.
+pub const MEMORY_MAX_VAR: &str = "PROTONTOOL_MEMORY_MAX";
+/// Profile variable requesting large address awareness.
+pub const LARGE_ADDRESS_AWARE_VAR: &str = "PROTONTOOL_LARGE_ADDRESS_AWARE";
+
+/// Variables that make Wine and Proton treat every executable as large
+/// address aware.
+const LARGE_ADDRESS_AWARE_ENV: &[&str] = &[
+    "WINE_LARGE_ADDRESS_AWARE",
+    "PROTON_FORCE_LARGE_ADDRESS_AWARE",
+];
+
+/// How programs in a prefix are scheduled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scheduling {
+    /// Nice level, -20 (highest priority) to 19.
+    pub nice: Option<i32>,
+    /// CPU list in `taskset -c` syntax, e.g. `0-7,16`.
+    pub cpu_affinity: Option<String>,
+    /// Request GameMode through `gamemoderun`.
+    pub gamemode: bool,
+    /// Memory limit in systemd's syntax, e.g. `6G` or `50%`.
+    pub memory_max: Option<String>,
+    /// Treat 32-bit executables as large address aware.
+    pub large_address_aware: bool,
+}
+
+/// Parse a nice level.
+pub fn parse_nice(value: &str) -> Result<i32, String> {
+    value
+        .trim()
+        .parse()
+        .ok()
+        .filter(|n| (-20..=19).contains(n))
+        .ok_or_else(|| format!("Nice level must be between -20 and 19, got '{}'", value))
+}
+
+/// Validate a CPU list (`0-7`, `0,2,4`, `0-3,8-11`) and normalize its
+/// spacing.
+///
+/// ```
+/// use protontool::wine::scheduling::parse_cpu_list;
+/// assert_eq!(parse_cpu_list("0-7, 16").unwrap(), "0-7,16");
+/// assert!(parse_cpu_list("7-0").is_err());
+/// assert!(parse_cpu_list("all").is_err());
+/// ```
+pub fn parse_cpu_list(value: &str) -> Result<String, String> {
+    let invalid = || {
+        format!(
+            "CPU affinity must be a list of CPUs or ranges such as 0-7,16, got '{}'",
+            value
+        )
+    };
+    let mut parts = Vec::new();
+    for part in value.split(',').map(|p| p.trim()) {
+        let (first, last) = part.split_once('-').unwrap_or((part, part));
+        let first: u32 = first.trim().parse().map_err(|_| invalid())?;
+        let last: u32 = last.trim().parse().map_err(|_| invalid())?;
+        if first > last {
+            return Err(invalid());
+        }
+        parts.push(if first == last {
+            first.to_string()
+        } else {
+            format!("{}-{}", first, last)
+        });
+    }
+    Ok(parts.join(","))
+}
+
+/// Validate a memory limit (bytes with an optional K, M, G or T suffix, or
+/// a percentage of the system's memory) and normalize it for systemd.
+///
+/// ```
+/// use protontool::wine::scheduling::parse_memory_size;
+/// assert_eq!(parse_memory_size("6g").unwrap(), "6G");
+/// assert_eq!(parse_memory_size("75%").unwrap(), "75%");
+/// assert!(parse_memory_size("0").is_err());
+/// assert!(parse_memory_size("6GB").is_err());
+/// ```
+pub fn parse_memory_size(value: &str) -> Result<String, String> {
+    let invalid = || {
+        format!(
+            "Memory limit must be a size such as 6G or 4096M, or a percentage such as 75%, got '{}'",
+            value
+        )
+    };
+    let value = value.trim().to_uppercase();
+    let (number, unit) = match value.char_indices().last() {
+        Some((i, c)) if "KMGT%".contains(c) => (&value[..i], &value[i..]),
+        _ => (value.as_str(), ""),
+    };
+    let number: u64 = number.parse().map_err(|_| invalid())?;
+    if number == 0 || (unit == "%" && number > 100) {
+        return Err(invalid());
+    }
+    Ok(format!("{}{}", number, unit))
+}
+
+impl Scheduling {
+    /// Read the settings from an environment profile. Invalid values are
+    /// ignored with a warning in the log.
+    pub fn from_profile(profile: &EnvProfile) -> Self {
+        let nice = profile
+            .get(NICE_VAR)
+            .and_then(|v| parse_nice(v).map_err(|e| crate::log::warn(&e)).ok());
+        let cpu_affinity = profile
+            .get(CPU_AFFINITY_VAR)
+            .and_then(|v| parse_cpu_list(v).map_err(|e| crate::log::warn(&e)).ok());
+        let enabled = |var| {
+            profile
+                .get(var)
+                .is_some_and(|v| matches!(v, "1" | "true" | "yes" | "on"))
+        };
+        let memory_max = profile
+            .get(MEMORY_MAX_VAR)
+            .and_then(|v| parse_memory_size(v).map_err(|e| crate::log::warn(&e)).ok());
+        Scheduling {
+            nice,
+            cpu_affinity,
+            gamemode: enabled(GAMEMODE_VAR),
+            memory_max,
+            large_address_aware: enabled(LARGE_ADDRESS_AWARE_VAR),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nice.is_none()
+            && self.cpu_affinity.is_none()
+            && !self.gamemode
+            && self.memory_max.is_none()
+            && !self.large_address_aware
+    }
+
+    /// Wrapper command line to put in front of the program, skipping tools
+    /// that aren't installed.
+    pub fn wrapper_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(memory_max) = &self.memory_max {
+            if let Some(systemd_run) = which("systemd-run") {
+                args.push(systemd_run.to_string_lossy().into_owned());
+                args.extend(["--user", "--scope", "--quiet", "--collect", "-p"].map(String::from));
+                args.push(format!("MemoryMax={}", memory_max));
+                // Without this the limit only pushes the game into swap
+                args.extend([
+                    "-p".to_string(),
+                    "MemorySwapMax=0".to_string(),
+                    "--".to_string(),
+                ]);
+            }
+        }
+        if self.gamemode {
+            if let Some(gamemoderun) = which("gamemoderun") {
+                args.push(gamemoderun.to_string_lossy().into_owned());
+            }
+        }
+        if let Some(cpus) = &self.cpu_affinity {
+            if let Some(taskset) = which("taskset") {
+                args.push(taskset.to_string_lossy().into_owned());
+                args.extend(["-c".to_string(), cpus.clone()]);
+            }
+        }
+        if let Some(nice) = self.nice {
+            if let Some(nice_bin) = which("nice") {
+                args.push(nice_bin.to_string_lossy().into_owned());
+                args.extend(["-n".to_string(), nice.to_string()]);
+            }
+        }
+        args
+    }
+
+    /// Wrap a command with the wrapper tools, keeping its arguments,
+    /// environment and working directory. Stdio must be set up afterwards.
+    pub fn wrap(&self, mut cmd: Command) -> Command {
+        if self.large_address_aware {
+            for var in LARGE_ADDRESS_AWARE_ENV {
+                cmd.env(var, "1");
+            }
+        }
+        let wrapper = self.wrapper_args();
+        let Some((program, wrapper_args)) = wrapper.split_first() else {
+            return cmd;
+        };
+        let mut wrapped = Command::new(program);
+        wrapped
+            .args(wrapper_args)
+            .arg(cmd.get_program())
+            .args(cmd.get_args());
+        if let Some(dir) = cmd.get_current_dir() {
+            wrapped.current_dir(dir);
+        }
+        for (key, value) in cmd.get_envs() {
+            match value {
+                Some(value) => wrapped.env(key, value),
+                None => wrapped.env_remove(key),
+            };
+        }
+        wrapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduling_from_profile() {
+        let profile = EnvProfile::parse(
+            "PROTONTOOL_NICE=5\nPROTONTOOL_CPU_AFFINITY=0-7\nPROTONTOOL_GAMEMODE=1\n",
+        );
+        assert_eq!(
+            Scheduling::from_profile(&profile),
+            Scheduling {
+                nice: Some(5),
+                cpu_affinity: Some("0-7".to_string()),
+                gamemode: true,
+                ..Default::default()
+            }
+        );
+        let profile = EnvProfile::parse("PROTONTOOL_NICE=40\nPROTONTOOL_GAMEMODE=0\n");
+        assert!(Scheduling::from_profile(&profile).is_empty());
+        assert_eq!(parse_nice("-20"), Ok(-20));
+
+        let mut cmd = Command::new("wine");
+        cmd.arg("game.exe")
+            .env("WINEPREFIX", "/pfx")
+            .current_dir("/tmp");
+        let wrapped = Scheduling::default().wrap(cmd);
+        assert_eq!(wrapped.get_program(), "wine");
+
+        let profile =
+            EnvProfile::parse("PROTONTOOL_MEMORY_MAX=3500m\nPROTONTOOL_LARGE_ADDRESS_AWARE=1\n");
+        let scheduling = Scheduling::from_profile(&profile);
+        assert_eq!(scheduling.memory_max.as_deref(), Some("3500M"));
+        let wrapped = Scheduling {
+            memory_max: None,
+            ..scheduling
+        }
+        .wrap(Command::new("wine"));
+        assert!(wrapped
+            .get_envs()
+            .any(|(key, value)| key == "WINE_LARGE_ADDRESS_AWARE" && value == Some("1".as_ref())));
+    }
+}
@@ -0,0 +1,280 @@
+//! Windows services in a prefix.
+//!
+//! Wine emulates the service control manager in `services.exe`; services
+//! are registered under `HKLM\System\CurrentControlSet\Services` and started
+//! either at boot (start type auto) or on demand. Launchers and anti-cheat
+//! installers often register a service that has to be restarted before the
+//! game sees it, which is done here with Wine's `net start` and `net stop`.
+
+use super::registry::{self, RegType, RegistryEditor};
+use super::WineContext;
+
+/// Registry key holding all services and drivers.
+const SERVICES_KEY: &str = "HKEY_LOCAL_MACHINE\\System\\CurrentControlSet\\Services";
+
+/// `Type` bits of Win32 services (own and shared process); drivers use the
+/// lower bits.
+const SERVICE_WIN32: u32 = 0x30;
+
+/// When a service is started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartType {
+    Boot,
+    System,
+    Auto,
+    Manual,
+    Disabled,
+}
+
+impl StartType {
+    /// Start type from the `Start` registry value.
+    pub fn from_value(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(StartType::Boot),
+            1 => Some(StartType::System),
+            2 => Some(StartType::Auto),
+            3 => Some(StartType::Manual),
+            4 => Some(StartType::Disabled),
+            _ => None,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        match self {
+            StartType::Boot => 0,
+            StartType::System => 1,
+            StartType::Auto => 2,
+            StartType::Manual => 3,
+            StartType::Disabled => 4,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StartType::Boot => "boot",
+            StartType::System => "system",
+            StartType::Auto => "auto",
+            StartType::Manual => "manual",
+            StartType::Disabled => "disabled",
+        }
+    }
+
+    /// Parse a start type users can set: `auto`, `manual` (or `demand`) or
+    /// `disabled`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "auto" | "automatic" => Some(StartType::Auto),
+            "manual" | "demand" => Some(StartType::Manual),
+            "disabled" => Some(StartType::Disabled),
+            _ => None,
+        }
+    }
+}
+
+/// A Win32 service registered in a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Service {
+    /// Key name, used with `net start`/`net stop`.
+    pub name: String,
+    pub display_name: String,
+    pub start: Option<StartType>,
+    /// Program implementing the service.
+    pub image_path: String,
+}
+
+/// Parse `dword:XXXXXXXX` registry data.
+fn parse_dword(data: &str) -> Option<u32> {
+    u32::from_str_radix(data.strip_prefix("dword:")?, 16).ok()
+}
+
+/// Parse `"..."` registry string data, including expandable strings.
+fn parse_string(data: &str) -> Option<String> {
+    let s = data.strip_prefix('"')?.strip_suffix('"')?;
+    Some(s.replace("\\\\", "\\"))
+}
+
+/// Build a service from its registry values. None for drivers and keys
+/// that aren't services.
+fn service_from_values(name: &str, values: &[(String, String)]) -> Option<Service> {
+    let value = |key: &str| {
+        values
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(key))
+            .map(|(_, data)| data.as_str())
+    };
+    let kind = value("Type").and_then(parse_dword)?;
+    if kind & SERVICE_WIN32 == 0 {
+        return None;
+    }
+    let image_path = value("ImagePath")
+        .and_then(|d| parse_string(d).or_else(|| registry_expand_string(d)))
+        .unwrap_or_default();
+    Some(Service {
+        name: name.to_string(),
+        display_name: value("DisplayName")
+            .and_then(parse_string)
+            .unwrap_or_else(|| name.to_string()),
+        start: value("Start")
+            .and_then(parse_dword)
+            .and_then(StartType::from_value),
+        image_path,
+    })
+}
+
+/// Decode REG_EXPAND_SZ data as returned by `read_prefix_key_values`
+/// (`hex(2):` UTF-16LE bytes).
+fn registry_expand_string(data: &str) -> Option<String> {
+    let bytes: Vec<u8> = data
+        .strip_prefix("hex(2):")?
+        .split(',')
+        .filter_map(|b| u8::from_str_radix(b.trim(), 16).ok())
+        .collect();
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&unit| unit != 0)
+        .collect();
+    Some(String::from_utf16_lossy(&units))
+}
+
+/// Win32 services registered in a prefix, sorted by name. Read from the
+/// registry files without running Wine.
+pub fn list_services(prefix_path: &std::path::Path) -> Vec<Service> {
+    let mut services: Vec<Service> = registry::read_prefix_subkeys(prefix_path, SERVICES_KEY)
+        .iter()
+        .filter_map(|name| {
+            let key = format!("{}\\{}", SERVICES_KEY, name);
+            service_from_values(name, &registry::read_prefix_key_values(prefix_path, &key))
+        })
+        .collect();
+    services.sort_by_key(|s| s.name.to_lowercase());
+    services
+}
+
+/// Find a service by key or display name (case-insensitive).
+pub fn find_service(prefix_path: &std::path::Path, name: &str) -> Result<Service, String> {
+    list_services(prefix_path)
+        .into_iter()
+        .find(|s| s.name.eq_ignore_ascii_case(name) || s.display_name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("No service named '{}' in the prefix", name))
+}
+
+/// Names of the running services, as listed by `net start`.
+pub fn running_services(wine_ctx: &WineContext) -> Result<Vec<String>, String> {
+    let output = wine_ctx
+        .run_wine_no_cwd(&["net", "start"])
+        .map_err(|e| format!("Failed to run net start: {}", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // The first line is a header ("The following services are running:")
+    Ok(stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect())
+}
+
+/// Whether a service shows up in the `net start` list.
+pub fn is_running(service: &Service, running: &[String]) -> bool {
+    running.iter().any(|r| {
+        r.eq_ignore_ascii_case(&service.name) || r.eq_ignore_ascii_case(&service.display_name)
+    })
+}
+
+/// Run `net start NAME` or `net stop NAME`.
+fn net_command(wine_ctx: &WineContext, command: &str, service: &Service) -> Result<(), String> {
+    let output = wine_ctx
+        .run_wine_no_cwd(&["net", command, &service.name])
+        .map_err(|e| format!("Failed to run net {}: {}", command, e))?;
+    wine_ctx.wait_for_wineserver().ok();
+    if output.status.success() {
+        return Ok(());
+    }
+    let message = crate::util::output_to_string(&output);
+    Err(format!(
+        "net {} {} failed: {}",
+        command,
+        service.name,
+        message.trim()
+    ))
+}
+
+/// Start a service.
+pub fn start(wine_ctx: &WineContext, service: &Service) -> Result<(), String> {
+    net_command(wine_ctx, "start", service)
+}
+
+/// Stop a service.
+pub fn stop(wine_ctx: &WineContext, service: &Service) -> Result<(), String> {
+    net_command(wine_ctx, "stop", service)
+}
+
+/// Stop a service if it is running and start it again.
+pub fn restart(wine_ctx: &WineContext, service: &Service) -> Result<(), String> {
+    if is_running(service, &running_services(wine_ctx)?) {
+        stop(wine_ctx, service)?;
+    }
+    start(wine_ctx, service)
+}
+
+/// Change when a service is started.
+pub fn set_start_type(
+    wine_ctx: &WineContext,
+    service: &Service,
+    start: StartType,
+) -> Result<(), String> {
+    RegistryEditor::new(wine_ctx).set_value(
+        &format!("{}\\{}", SERVICES_KEY, service.name),
+        "Start",
+        &start.value().to_string(),
+        RegType::Dword,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_from_values() {
+        let values = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(n, d)| (n.to_string(), d.to_string()))
+                .collect()
+        };
+        let service = service_from_values(
+            "EasyAntiCheat",
+            &values(&[
+                ("DisplayName", "\"EasyAntiCheat\""),
+                (
+                    "ImagePath",
+                    "\"C:\\\\Program Files (x86)\\\\EasyAntiCheat\\\\EasyAntiCheat.exe\"",
+                ),
+                ("Start", "dword:00000003"),
+                ("Type", "dword:00000010"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(service.start, Some(StartType::Manual));
+        assert_eq!(
+            service.image_path,
+            "C:\\Program Files (x86)\\EasyAntiCheat\\EasyAntiCheat.exe"
+        );
+
+        // Drivers are not listed
+        assert!(service_from_values(
+            "winebus",
+            &values(&[("Start", "dword:00000002"), ("Type", "dword:00000001")])
+        )
+        .is_none());
+
+        let running = vec!["EasyAntiCheat".to_string()];
+        assert!(is_running(&service, &running));
+        assert_eq!(
+            registry_expand_string("hex(2):43,00,3a,00,00,00").as_deref(),
+            Some("C:")
+        );
+    }
+}
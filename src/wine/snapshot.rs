@@ -0,0 +1,695 @@
+//! Prefix snapshots.
+//!
+//! A snapshot is a restorable copy of a whole prefix. The backend is picked
+//! per prefix from the filesystem it lives on:
+//!
+//! - btrfs, when the prefix is a subvolume: `btrfs subvolume snapshot`
+//! - ZFS, when the prefix is a dataset's mount point: `zfs snapshot`
+//! - anything else: `cp -a --reflink=auto`, which shares data blocks on
+//!   filesystems that support it (XFS, bcachefs) and copies otherwise
+//!
+//! Native snapshots take well under a second regardless of prefix size.
+//! btrfs snapshots and copies are kept next to the prefix, in
+//! `.NAME.snapshots`; ZFS snapshots are `DATASET@NAME`.
+//!
+//! Before each DLL or application verb an automatic `auto-...` snapshot is
+//! taken, per the `[snapshots]` policy in `config.toml`. Where there are no
+//! native snapshots it covers only the registry hives, which is cheap and
+//! undoes most of what an installer changes.
+
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::image;
+use crate::util::{output_stderr_to_string, output_to_string, which};
+
+/// Inode number of a btrfs subvolume's root directory.
+const BTRFS_SUBVOLUME_INODE: u64 = 256;
+
+/// Registry files copied by registry-only snapshots.
+const REGISTRY_HIVES: &[&str] = &["system.reg", "user.reg", "userdef.reg"];
+
+/// File marking a snapshot directory as registry-only.
+const REGISTRY_ONLY_MARKER: &str = ".registry-only";
+
+/// Name prefix of automatic pre-verb snapshots.
+const AUTO_PREFIX: &str = "auto-";
+
+/// Automatic snapshots kept by default.
+const DEFAULT_KEEP: usize = 5;
+
+/// How snapshots of a prefix are taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotBackend {
+    Btrfs,
+    Zfs { dataset: String },
+    Copy,
+}
+
+impl SnapshotBackend {
+    /// Pick the backend for a prefix.
+    pub fn detect(prefix_path: &Path) -> Self {
+        let Some(mount) = image::containing_mount(prefix_path) else {
+            return SnapshotBackend::Copy;
+        };
+        let is_mount_point = prefix_path
+            .canonicalize()
+            .is_ok_and(|path| path == mount.mount_point);
+        match mount.fs_type.as_str() {
+            "zfs" if is_mount_point && which("zfs").is_some() => SnapshotBackend::Zfs {
+                dataset: mount.source,
+            },
+            "btrfs" if is_btrfs_subvolume(prefix_path) && which("btrfs").is_some() => {
+                SnapshotBackend::Btrfs
+            }
+            _ => SnapshotBackend::Copy,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SnapshotBackend::Btrfs => "btrfs",
+            SnapshotBackend::Zfs { .. } => "zfs",
+            SnapshotBackend::Copy => "copy",
+        }
+    }
+}
+
+/// A snapshot of a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub name: String,
+    /// Unix time the snapshot was taken, when known.
+    pub created: Option<u64>,
+    /// Only the registry hives were saved.
+    pub registry_only: bool,
+}
+
+fn is_btrfs_subvolume(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.is_dir() && m.ino() == BTRFS_SUBVOLUME_INODE)
+}
+
+/// Directory holding a prefix's btrfs snapshots and copies.
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use protontool::wine::snapshot::snapshot_dir;
+/// assert_eq!(
+///     snapshot_dir(Path::new("/games/MyPrefix")),
+///     PathBuf::from("/games/.MyPrefix.snapshots")
+/// );
+/// ```
+pub fn snapshot_dir(prefix_path: &Path) -> PathBuf {
+    let name = prefix_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    prefix_path
+        .parent()
+        .unwrap_or(Path::new("/"))
+        .join(format!(".{}.snapshots", name))
+}
+
+/// Check a snapshot name: letters, digits, `-`, `_` and `.`, not starting
+/// with a dot.
+fn validate_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid snapshot name '{}' (use letters, digits, '-', '_' and '.')",
+            name
+        ))
+    }
+}
+
+/// Default snapshot name from a Unix time, in UTC.
+///
+/// ```
+/// use protontool::wine::snapshot::default_name;
+/// assert_eq!(default_name(0), "19700101-000000");
+/// assert_eq!(default_name(1792152000), "20261016-120000");
+/// ```
+pub fn default_name(unix_time: u64) -> String {
+    let [year, month, day, hour, minute, second] = crate::util::utc_datetime(unix_time);
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        year, month, day, hour, minute, second
+    )
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Run a command, turning a failure into an error with its stderr.
+fn run(command: &mut Command, what: &str) -> Result<String, String> {
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to {}: {}", what, e))?;
+    if output.status.success() {
+        Ok(output_to_string(&output))
+    } else {
+        Err(format!(
+            "Failed to {}: {}",
+            what,
+            output_stderr_to_string(&output)
+        ))
+    }
+}
+
+/// Take a snapshot of a prefix. Without a name, the current time is used.
+pub fn create(prefix_path: &Path, name: Option<&str>) -> Result<Snapshot, String> {
+    let name = new_name(prefix_path, name)?;
+    match SnapshotBackend::detect(prefix_path) {
+        SnapshotBackend::Zfs { dataset } => {
+            run(
+                Command::new("zfs")
+                    .arg("snapshot")
+                    .arg(format!("{}@{}", dataset, name)),
+                "create ZFS snapshot",
+            )?;
+        }
+        backend => {
+            let dir = snapshot_dir(prefix_path);
+            fs::create_dir_all(&dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+            copy_tree(&backend, prefix_path, &dir.join(&name))?;
+        }
+    }
+    Ok(Snapshot {
+        name,
+        created: Some(now()),
+        registry_only: false,
+    })
+}
+
+/// Take a snapshot of only the registry hives (`system.reg`, `user.reg`,
+/// `userdef.reg`). Cheap on any filesystem, and enough to undo most of what
+/// an installer changes besides files.
+pub fn create_registry(prefix_path: &Path, name: Option<&str>) -> Result<Snapshot, String> {
+    let name = new_name(prefix_path, name)?;
+    let dir = snapshot_dir(prefix_path).join(&name);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let copied = REGISTRY_HIVES.iter().try_for_each(|hive| {
+        let from = prefix_path.join(hive);
+        if !from.exists() {
+            return Ok(());
+        }
+        fs::copy(&from, dir.join(hive))
+            .map(|_| ())
+            .map_err(|e| format!("Failed to copy {}: {}", from.display(), e))
+    });
+    if let Err(e) = copied.and_then(|_| {
+        fs::write(dir.join(REGISTRY_ONLY_MARKER), "")
+            .map_err(|e| format!("Failed to write {}: {}", dir.display(), e))
+    }) {
+        fs::remove_dir_all(&dir).ok();
+        return Err(e);
+    }
+    Ok(Snapshot {
+        name,
+        created: Some(now()),
+        registry_only: true,
+    })
+}
+
+/// Name for a new snapshot, checked for validity and uniqueness.
+fn new_name(prefix_path: &Path, name: Option<&str>) -> Result<String, String> {
+    let name = name.map_or_else(|| default_name(now()), str::to_string);
+    validate_name(&name)?;
+    if list(prefix_path).iter().any(|s| s.name == name) {
+        return Err(format!("Snapshot '{}' already exists", name));
+    }
+    if !prefix_path.is_dir() {
+        return Err(format!(
+            "Prefix path does not exist: {}",
+            prefix_path.display()
+        ));
+    }
+    Ok(name)
+}
+
+/// Snapshots of a prefix, oldest first.
+pub fn list(prefix_path: &Path) -> Vec<Snapshot> {
+    let mut snapshots = dir_snapshots(prefix_path);
+    if let SnapshotBackend::Zfs { dataset } = SnapshotBackend::detect(prefix_path) {
+        snapshots.extend(zfs_snapshots(&dataset));
+    }
+    snapshots.sort_by(|a, b| a.created.cmp(&b.created).then(a.name.cmp(&b.name)));
+    snapshots
+}
+
+/// btrfs, copy and registry-only snapshots in the snapshot directory.
+fn dir_snapshots(prefix_path: &Path) -> Vec<Snapshot> {
+    let Ok(entries) = fs::read_dir(snapshot_dir(prefix_path)) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .map(|e| Snapshot {
+            name: e.file_name().to_string_lossy().to_string(),
+            created: e
+                .metadata()
+                .ok()
+                .and_then(|m| m.created().or_else(|_| m.modified()).ok())
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs()),
+            registry_only: e.path().join(REGISTRY_ONLY_MARKER).exists(),
+        })
+        .collect()
+}
+
+/// Snapshots of a ZFS dataset.
+fn zfs_snapshots(dataset: &str) -> Vec<Snapshot> {
+    let output = Command::new("zfs")
+        .args(["list", "-H", "-p", "-t", "snapshot"])
+        .args(["-o", "name,creation", "-d", "1"])
+        .arg(dataset)
+        .output();
+    let stdout = output
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default();
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (full_name, created) = line.split_once('\t')?;
+            let (_, name) = full_name.split_once('@')?;
+            Some(Snapshot {
+                name: name.to_string(),
+                created: created.trim().parse().ok(),
+                registry_only: false,
+            })
+        })
+        .collect()
+}
+
+/// ZFS snapshots `zfs rollback -r` destroys when restoring `name`: those
+/// taken after it. Empty for other backends and directory snapshots.
+pub fn destroyed_by_restore(prefix_path: &Path, name: &str) -> Vec<String> {
+    if snapshot_dir(prefix_path).join(name).is_dir() {
+        return Vec::new();
+    }
+    let SnapshotBackend::Zfs { dataset } = SnapshotBackend::detect(prefix_path) else {
+        return Vec::new();
+    };
+    let mut snapshots = zfs_snapshots(&dataset);
+    snapshots.sort_by_key(|s| s.created);
+    let Some(restored) = snapshots.iter().position(|s| s.name == name) else {
+        return Vec::new();
+    };
+    snapshots[restored + 1..]
+        .iter()
+        .map(|s| s.name.clone())
+        .collect()
+}
+
+/// Move the entries of a directory into another one, except `skip`.
+fn move_entries(from: &Path, to: &Path, skip: &[&Path]) -> Result<(), String> {
+    let entries =
+        fs::read_dir(from).map_err(|e| format!("Failed to read {}: {}", from.display(), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if skip.contains(&path.as_path()) {
+            continue;
+        }
+        fs::rename(&path, to.join(entry.file_name()))
+            .map_err(|e| format!("Failed to move {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Replace the contents of a mount point (e.g. a disk image prefix), which
+/// can't be moved aside as a whole, with a snapshot. The snapshot is copied
+/// into a staging directory inside the mount point first; the old contents
+/// are only deleted once the copy succeeded and the staged files are in
+/// place.
+fn restore_into_mount_point(prefix_path: &Path, snapshot: &Path) -> Result<(), String> {
+    let staging = prefix_path.join(".protontool-restoring");
+    let replaced = prefix_path.join(".protontool-replaced");
+    for dir in [&staging, &replaced] {
+        if dir.exists() {
+            remove_tree(dir)?;
+        }
+    }
+    fs::create_dir(&staging)
+        .map_err(|e| format!("Failed to create {}: {}", staging.display(), e))?;
+    let mut contents = snapshot.as_os_str().to_owned();
+    contents.push("/.");
+    let copied = run(
+        Command::new("cp")
+            .args(["-a", "--reflink=auto"])
+            .arg(contents)
+            .arg(&staging),
+        "copy snapshot",
+    );
+    if let Err(e) = copied {
+        fs::remove_dir_all(&staging).ok();
+        return Err(e);
+    }
+
+    fs::create_dir(&replaced)
+        .map_err(|e| format!("Failed to create {}: {}", replaced.display(), e))?;
+    let own = [staging.as_path(), replaced.as_path()];
+    if let Err(e) = move_entries(prefix_path, &replaced, &own) {
+        move_entries(&replaced, prefix_path, &[]).ok();
+        fs::remove_dir(&replaced).ok();
+        fs::remove_dir_all(&staging).ok();
+        return Err(e);
+    }
+    if let Err(e) = move_entries(&staging, prefix_path, &[]) {
+        move_entries(prefix_path, &staging, &own).ok();
+        move_entries(&replaced, prefix_path, &[]).ok();
+        fs::remove_dir(&replaced).ok();
+        fs::remove_dir_all(&staging).ok();
+        return Err(e);
+    }
+    fs::remove_dir(&staging).ok();
+    remove_tree(&replaced)
+}
+
+/// Replace a prefix (or, for a registry-only snapshot, its registry) with
+/// one of its snapshots. Nothing may be running in the prefix. On ZFS,
+/// snapshots newer than the restored one are destroyed.
+pub fn restore(prefix_path: &Path, name: &str) -> Result<(), String> {
+    validate_name(name)?;
+    let snapshot = snapshot_dir(prefix_path).join(name);
+    if !snapshot.is_dir() {
+        return match SnapshotBackend::detect(prefix_path) {
+            SnapshotBackend::Zfs { dataset }
+                if zfs_snapshots(&dataset).iter().any(|s| s.name == name) =>
+            {
+                run(
+                    Command::new("zfs")
+                        .args(["rollback", "-r"])
+                        .arg(format!("{}@{}", dataset, name)),
+                    "roll back ZFS dataset",
+                )
+                .map(|_| ())
+            }
+            _ => Err(format!("No snapshot named '{}'", name)),
+        };
+    }
+
+    if snapshot.join(REGISTRY_ONLY_MARKER).exists() {
+        for hive in REGISTRY_HIVES {
+            let from = snapshot.join(hive);
+            if from.exists() {
+                fs::copy(&from, prefix_path.join(hive))
+                    .map_err(|e| format!("Failed to restore {}: {}", hive, e))?;
+            }
+        }
+        return Ok(());
+    }
+
+    let is_mount_point = image::containing_mount(prefix_path)
+        .is_some_and(|m| prefix_path.canonicalize().is_ok_and(|p| p == m.mount_point));
+    if is_mount_point {
+        return restore_into_mount_point(prefix_path, &snapshot);
+    }
+
+    // Keep the current prefix until the snapshot is back in place
+    let backend = SnapshotBackend::detect(prefix_path);
+    let mut aside = prefix_path.as_os_str().to_owned();
+    aside.push(".restoring");
+    let aside = PathBuf::from(aside);
+    fs::rename(prefix_path, &aside)
+        .map_err(|e| format!("Failed to move {} aside: {}", prefix_path.display(), e))?;
+    if let Err(e) = copy_tree(&backend, &snapshot, prefix_path) {
+        remove_tree(prefix_path).ok();
+        fs::rename(&aside, prefix_path).ok();
+        return Err(e);
+    }
+    remove_tree(&aside)
+}
+
+/// Delete a snapshot.
+pub fn delete(prefix_path: &Path, name: &str) -> Result<(), String> {
+    validate_name(name)?;
+    let snapshot = snapshot_dir(prefix_path).join(name);
+    if snapshot.is_dir() {
+        return remove_tree(&snapshot);
+    }
+    match SnapshotBackend::detect(prefix_path) {
+        SnapshotBackend::Zfs { dataset }
+            if zfs_snapshots(&dataset).iter().any(|s| s.name == name) =>
+        {
+            run(
+                Command::new("zfs")
+                    .arg("destroy")
+                    .arg(format!("{}@{}", dataset, name)),
+                "destroy ZFS snapshot",
+            )
+            .map(|_| ())
+        }
+        _ => Err(format!("No snapshot named '{}'", name)),
+    }
+}
+
+/// What to snapshot automatically before component verbs, from the
+/// `[snapshots]` section of `config.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafetyMode {
+    /// Native snapshots on btrfs and ZFS, registry-only elsewhere.
+    Auto,
+    /// Always the whole prefix, copying it where there are no native
+    /// snapshots.
+    Full,
+    Registry,
+    Off,
+}
+
+/// Automatic snapshot policy: `before_verbs` and how many to `keep`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub mode: SafetyMode,
+    pub keep: usize,
+}
+
+impl SafetyPolicy {
+    /// Read the policy; invalid values are reported and the defaults
+    /// (`auto`, keep 5) used.
+    pub fn from_config(config: &crate::config::UserConfig) -> Self {
+        let mode = match config.get("snapshots", "before_verbs") {
+            None | Some("auto") => SafetyMode::Auto,
+            Some("full") => SafetyMode::Full,
+            Some("registry") => SafetyMode::Registry,
+            Some("off") => SafetyMode::Off,
+            Some(other) => {
+                eprintln!(
+                    "Warning: [snapshots] before_verbs must be auto, full, registry or off, not '{}'",
+                    other
+                );
+                SafetyMode::Auto
+            }
+        };
+        let keep = config.get("snapshots", "keep").map_or(DEFAULT_KEEP, |v| {
+            v.parse().unwrap_or_else(|_| {
+                eprintln!("Warning: [snapshots] keep must be a number");
+                DEFAULT_KEEP
+            })
+        });
+        SafetyPolicy { mode, keep }
+    }
+}
+
+/// Take the automatic snapshot before running `verb`, then drop automatic
+/// snapshots beyond the policy's limit. Returns None when the policy is off.
+pub fn safety_snapshot(
+    prefix_path: &Path,
+    verb: &str,
+    policy: &SafetyPolicy,
+) -> Result<Option<Snapshot>, String> {
+    if policy.mode == SafetyMode::Off || policy.keep == 0 {
+        return Ok(None);
+    }
+    let base = format!(
+        "{}{}-{}",
+        AUTO_PREFIX,
+        default_name(now()),
+        verb.replace(
+            |c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '.',
+            "_"
+        )
+    );
+    // Verbs that fail quickly can run more than once a second
+    let existing = list(prefix_path);
+    let name = (1..)
+        .map(|n| match n {
+            1 => base.clone(),
+            n => format!("{}-{}", base, n),
+        })
+        .find(|name| !existing.iter().any(|s| &s.name == name))
+        .unwrap();
+    let native = SnapshotBackend::detect(prefix_path) != SnapshotBackend::Copy;
+    let snapshot = match policy.mode {
+        SafetyMode::Auto if native => create(prefix_path, Some(&name))?,
+        SafetyMode::Full => create(prefix_path, Some(&name))?,
+        _ => create_registry(prefix_path, Some(&name))?,
+    };
+
+    let automatic: Vec<Snapshot> = list(prefix_path)
+        .into_iter()
+        .filter(|s| s.name.starts_with(AUTO_PREFIX))
+        .collect();
+    for old in &automatic[..automatic.len().saturating_sub(policy.keep)] {
+        if let Err(e) = delete(prefix_path, &old.name) {
+            crate::log::warn(&format!("Failed to delete snapshot {}: {}", old.name, e));
+        }
+    }
+    Ok(Some(snapshot))
+}
+
+/// The most recent automatic snapshot, the one `--rollback-last` restores.
+pub fn latest_automatic(prefix_path: &Path) -> Option<Snapshot> {
+    list(prefix_path)
+        .into_iter()
+        .rfind(|s| s.name.starts_with(AUTO_PREFIX))
+}
+
+/// Snapshot (btrfs) or copy a directory tree to a new path.
+fn copy_tree(backend: &SnapshotBackend, from: &Path, to: &Path) -> Result<(), String> {
+    if *backend == SnapshotBackend::Btrfs && is_btrfs_subvolume(from) {
+        run(
+            Command::new("btrfs")
+                .args(["subvolume", "snapshot"])
+                .arg(from)
+                .arg(to),
+            "create btrfs snapshot",
+        )?;
+        return Ok(());
+    }
+    let result = run(
+        Command::new("cp")
+            .args(["-a", "--reflink=auto"])
+            .arg(from)
+            .arg(to),
+        "copy prefix",
+    );
+    if result.is_err() {
+        fs::remove_dir_all(to).ok();
+    }
+    result.map(|_| ())
+}
+
+/// Remove a directory tree, deleting it as a subvolume if it is one.
+fn remove_tree(path: &Path) -> Result<(), String> {
+    if is_btrfs_subvolume(path) && which("btrfs").is_some() {
+        let deleted = Command::new("btrfs")
+            .args(["subvolume", "delete"])
+            .arg(path)
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if deleted {
+            return Ok(());
+        }
+        // Without user_subvol_rm_allowed only emptying the subvolume and
+        // rmdir work for regular users
+    }
+    fs::remove_dir_all(path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))
+}
+
+/// Create a new prefix directory, as a btrfs subvolume when its parent is
+/// on btrfs so it can be snapshotted natively.
+pub fn create_prefix_dir(prefix_path: &Path) -> Result<(), String> {
+    if prefix_path.exists() {
+        return Ok(());
+    }
+    let parent = prefix_path.parent().unwrap_or(Path::new("/"));
+    fs::create_dir_all(parent)
+        .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    let on_btrfs = image::containing_mount(parent).is_some_and(|m| m.fs_type == "btrfs");
+    if on_btrfs && which("btrfs").is_some() {
+        let created = Command::new("btrfs")
+            .args(["subvolume", "create"])
+            .arg(prefix_path)
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if created {
+            return Ok(());
+        }
+    }
+    fs::create_dir_all(prefix_path).map_err(|e| format!("Failed to create prefix directory: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_snapshot_roundtrip() {
+        let root = std::env::temp_dir().join(format!("pt-snapshot-{}", std::process::id()));
+        let prefix = root.join("prefix");
+        fs::create_dir_all(prefix.join("drive_c")).unwrap();
+        fs::write(prefix.join("system.reg"), "before").unwrap();
+
+        let backend = SnapshotBackend::detect(&prefix);
+        if backend == SnapshotBackend::Copy {
+            create(&prefix, Some("clean")).unwrap();
+            assert!(create(&prefix, Some("clean")).is_err());
+            assert!(create(&prefix, Some("../x")).is_err());
+            fs::write(prefix.join("system.reg"), "after").unwrap();
+
+            restore(&prefix, "clean").unwrap();
+            assert_eq!(
+                fs::read_to_string(prefix.join("system.reg")).unwrap(),
+                "before"
+            );
+            assert_eq!(list(&prefix).len(), 1);
+
+            // Contents of a mount point are swapped in place
+            fs::write(prefix.join("user.reg"), "added").unwrap();
+            restore_into_mount_point(&prefix, &snapshot_dir(&prefix).join("clean")).unwrap();
+            assert!(!prefix.join("user.reg").exists());
+            assert!(prefix.join("drive_c").is_dir());
+            assert!(!prefix.join(".protontool-replaced").exists());
+            let missing = root.join("missing");
+            assert!(restore_into_mount_point(&prefix, &missing).is_err());
+            assert!(prefix.join("system.reg").exists());
+            delete(&prefix, "clean").unwrap();
+            assert!(list(&prefix).is_empty());
+        }
+
+        fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_registry_safety_snapshots() {
+        let root = std::env::temp_dir().join(format!("pt-safety-{}", std::process::id()));
+        let prefix = root.join("prefix");
+        fs::create_dir_all(&prefix).unwrap();
+        fs::write(prefix.join("user.reg"), "before").unwrap();
+
+        let policy = SafetyPolicy {
+            mode: SafetyMode::Registry,
+            keep: 2,
+        };
+        for _ in 0..3 {
+            let taken = safety_snapshot(&prefix, "vcrun2022", &policy).unwrap();
+            assert!(taken.is_some_and(|s| s.registry_only));
+        }
+        assert_eq!(list(&prefix).len(), 2);
+
+        fs::write(prefix.join("user.reg"), "after").unwrap();
+        let last = latest_automatic(&prefix).unwrap();
+        restore(&prefix, &last.name).unwrap();
+        assert_eq!(
+            fs::read_to_string(prefix.join("user.reg")).unwrap(),
+            "before"
+        );
+
+        fs::remove_dir_all(&root).ok();
+    }
+}
@@ -0,0 +1,206 @@
+//! Verb timing and download statistics.
+//!
+//! Each verb run records how much it downloaded, how long the downloads and
+//! the install took, and whether it succeeded. A batch of verbs ends with a
+//! summary table, and every run is appended to the `verb-stats` state record
+//! (`~/.protontool/state/verb-stats`), one tab-separated line per verb:
+//!
+//! ```text
+//! timestamp  verb  bytes  download-ms  install-ms  ok|failed  host=bytes/ms,...
+//! ```
+//!
+//! The per-host transfers make slow mirrors easy to spot in bug reports.
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::download::{self, Transfer};
+use crate::util::format_size;
+
+/// State record holding verb statistics.
+const STATS_RECORD: &str = "verb-stats";
+
+/// Measurements of one verb run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerbStats {
+    pub verb: String,
+    /// Bytes downloaded (cache hits don't count).
+    pub downloaded: u64,
+    pub download_time: Duration,
+    /// Time spent on everything but downloading.
+    pub install_time: Duration,
+    pub succeeded: bool,
+    pub transfers: Vec<Transfer>,
+}
+
+impl VerbStats {
+    /// Run a verb, measuring it. Downloads made by other threads in the
+    /// meantime are attributed to the verb as well.
+    pub fn measure<F>(verb: &str, run: F) -> (Result<(), String>, VerbStats)
+    where
+        F: FnOnce() -> Result<(), String>,
+    {
+        download::take_transfers();
+        let started = Instant::now();
+        let result = run();
+        let total = started.elapsed();
+        let transfers = download::take_transfers();
+
+        // Parallel downloads overlap, so their sum can exceed the wall time
+        let download_time = transfers
+            .iter()
+            .map(|t| t.duration)
+            .sum::<Duration>()
+            .min(total);
+        let stats = VerbStats {
+            verb: verb.to_string(),
+            downloaded: transfers.iter().map(|t| t.bytes).sum(),
+            download_time,
+            install_time: total - download_time,
+            succeeded: result.is_ok(),
+            transfers,
+        };
+        (result, stats)
+    }
+
+    /// The `verb-stats` record line for this run.
+    fn record_line(&self, timestamp: u64) -> String {
+        let hosts: Vec<String> = self
+            .transfers
+            .iter()
+            .map(|t| format!("{}={}/{}", t.host, t.bytes, t.duration.as_millis()))
+            .collect();
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            timestamp,
+            self.verb,
+            self.downloaded,
+            self.download_time.as_millis(),
+            self.install_time.as_millis(),
+            if self.succeeded { "ok" } else { "failed" },
+            hosts.join(",")
+        )
+    }
+}
+
+/// Append a verb run to the `verb-stats` record.
+pub fn record(stats: &VerbStats) -> Result<(), String> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    crate::state::append_line(STATS_RECORD, &stats.record_line(now))
+}
+
+/// A duration as `1m05s` or `4.2s`.
+///
+/// ```
+/// use protontool::wine::stats::format_duration;
+/// use std::time::Duration;
+/// assert_eq!(format_duration(Duration::from_millis(4200)), "4.2s");
+/// assert_eq!(format_duration(Duration::from_secs(65)), "1m05s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Summary table of a verb batch, with a line per verb and a total.
+pub fn summary_table(runs: &[VerbStats]) -> String {
+    let width = runs
+        .iter()
+        .map(|r| r.verb.len())
+        .chain(std::iter::once("Verb".len()))
+        .max()
+        .unwrap_or(0);
+    let row = |verb: &str, size: &str, download: &str, install: &str, result: &str| {
+        format!(
+            "{:<width$}  {:>10}  {:>8}  {:>8}  {}",
+            verb,
+            size,
+            download,
+            install,
+            result,
+            width = width
+        )
+    };
+
+    let mut lines = vec![row("Verb", "Download", "DL time", "Install", "Result")];
+    for run in runs {
+        let size = if run.downloaded > 0 {
+            format_size(run.downloaded)
+        } else {
+            "-".to_string()
+        };
+        lines.push(row(
+            &run.verb,
+            &size,
+            &format_duration(run.download_time),
+            &format_duration(run.install_time),
+            if run.succeeded { "ok" } else { "failed" },
+        ));
+    }
+    if runs.len() > 1 {
+        let failed = runs.iter().filter(|r| !r.succeeded).count();
+        lines.push(row(
+            "Total",
+            &format_size(runs.iter().map(|r| r.downloaded).sum()),
+            &format_duration(runs.iter().map(|r| r.download_time).sum()),
+            &format_duration(runs.iter().map(|r| r.install_time).sum()),
+            &if failed > 0 {
+                format!("{} failed", failed)
+            } else {
+                "ok".to_string()
+            },
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_and_record_line() {
+        let runs = vec![
+            VerbStats {
+                verb: "vcrun2022".to_string(),
+                downloaded: 25 * 1024 * 1024,
+                download_time: Duration::from_secs(5),
+                install_time: Duration::from_secs(70),
+                succeeded: true,
+                transfers: vec![Transfer {
+                    host: "aka.ms".to_string(),
+                    bytes: 25 * 1024 * 1024,
+                    duration: Duration::from_secs(5),
+                }],
+            },
+            VerbStats {
+                verb: "dxvk".to_string(),
+                downloaded: 0,
+                download_time: Duration::ZERO,
+                install_time: Duration::from_millis(1500),
+                succeeded: false,
+                transfers: Vec::new(),
+            },
+        ];
+        let table = summary_table(&runs);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "vcrun2022    25.0 MiB      5.0s     1m10s  ok");
+        assert_eq!(
+            lines[2],
+            "dxvk                -      0.0s      1.5s  failed"
+        );
+        assert!(lines[3].ends_with("1 failed"));
+
+        assert_eq!(
+            runs[0].record_line(100),
+            "100\tvcrun2022\t26214400\t5000\t70000\tok\taka.ms=26214400/5000"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Placeholder expansion for registry content in verb data files.
+//!
+//! Data-driven verbs can't compute values the way `Custom` actions do, so
+//! registry content may reference a few prefix-specific values that are
+//! filled in at execution time:
+//!
+//! | Placeholder      | Value                                          |
+//! |------------------|------------------------------------------------|
+//! | `{{prefix}}`     | Unix path of the Wine prefix                   |
+//! | `{{arch}}`       | Prefix architecture (`win32` or `win64`)       |
+//! | `{{username}}`   | Windows user name inside the prefix            |
+//! | `{{resolution}}` | Current screen resolution (`WIDTHxHEIGHT`)     |
+//! | `{{workshop}}`   | `C:\workshop`, linked to Workshop content      |
+//!
+//! `{{workshop}}` is only defined for Steam prefixes whose game has
+//! Workshop content; using it links the content into the prefix. Unknown
+//! placeholders are left untouched.
+
+use std::process::Command;
+
+use super::{workshop, WineContext};
+
+/// Resolution used when the screen size can't be detected.
+const DEFAULT_RESOLUTION: &str = "1024x768";
+
+/// Replace `{{name}}` placeholders using the given variables.
+///
+/// ```
+/// use protontool::wine::template::expand;
+///
+/// let vars = [("arch", "win64".to_string())];
+/// assert_eq!(expand("WINEARCH={{arch}} {{other}}", &vars), "WINEARCH=win64 {{other}}");
+/// ```
+pub fn expand(content: &str, vars: &[(&str, String)]) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = match after.find("}}") {
+            Some(e) => e,
+            None => {
+                rest = &rest[start..];
+                break;
+            }
+        };
+
+        let name = after[..end].trim();
+        match vars.iter().find(|(var, _)| *var == name) {
+            Some((_, value)) => result.push_str(value),
+            None => result.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Windows user name of the prefix: the first profile under `drive_c/users`
+/// other than the shared ones, falling back to `$USER`.
+pub fn prefix_username(wine_ctx: &WineContext) -> String {
+    let users = wine_ctx.prefix_path.join("drive_c/users");
+    if let Ok(entries) = std::fs::read_dir(&users) {
+        let mut names: Vec<String> = entries
+            .flatten()
+            .filter(|e| e.path().is_dir())
+            .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+            .filter(|name| !matches!(name.as_str(), "Public" | "Default" | "All Users"))
+            .collect();
+        names.sort();
+        if let Some(name) = names.into_iter().next() {
+            return name;
+        }
+    }
+    std::env::var("USER").unwrap_or_else(|_| "steamuser".to_string())
+}
+
+/// Current screen resolution as reported by `xrandr`.
+pub fn screen_resolution() -> Option<String> {
+    let output = Command::new("xrandr").arg("--current").output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // "Screen 0: minimum 8 x 8, current 2560 x 1440, maximum 32767 x 32767"
+    let current = stdout.lines().find_map(|line| {
+        let rest = &line[line.find("current ")? + "current ".len()..];
+        Some(rest.split(',').next()?.to_string())
+    })?;
+    let (width, height) = current.split_once(" x ")?;
+    let (width, height) = (width.trim(), height.trim());
+    if width.parse::<u32>().is_ok() && height.parse::<u32>().is_ok() {
+        Some(format!("{}x{}", width, height))
+    } else {
+        None
+    }
+}
+
+/// Placeholder values for a prefix.
+pub fn prefix_vars(wine_ctx: &WineContext) -> Vec<(&'static str, String)> {
+    let mut vars = vec![
+        ("prefix", wine_ctx.prefix_path.to_string_lossy().to_string()),
+        ("arch", wine_ctx.arch.as_str().to_string()),
+        ("username", prefix_username(wine_ctx)),
+        (
+            "resolution",
+            screen_resolution().unwrap_or_else(|| DEFAULT_RESOLUTION.to_string()),
+        ),
+    ];
+    if let Some(content) = workshop::content_dir_for_prefix(&wine_ctx.prefix_path) {
+        match workshop::link(&wine_ctx.prefix_path, &content) {
+            Ok(path) => vars.push(("workshop", path.to_string())),
+            Err(e) => crate::log::warn(&e),
+        }
+    }
+    vars
+}
+
+/// Expand all placeholders in `content` for the given prefix.
+///
+/// Values are only computed when the content contains a placeholder, so
+/// plain registry patches don't pay for running `xrandr`.
+pub fn expand_for_prefix(content: &str, wine_ctx: &WineContext) -> String {
+    if !content.contains("{{") {
+        return content.to_string();
+    }
+    expand(content, &prefix_vars(wine_ctx))
+}
@@ -0,0 +1,180 @@
+//! Installed software as recorded in a prefix's Uninstall registry keys.
+//!
+//! An installer exiting with code 0 doesn't mean it installed anything:
+//! some just unpack and quit, others bail out silently under Wine. Comparing
+//! the entries under the Uninstall keys before and after a verb tells the
+//! two apart. A verb can also declare an install marker, checked after it
+//! runs:
+//!
+//! - `uninstall:TEXT`: an Uninstall entry whose name contains TEXT
+//! - `file:PATH`: a file in the prefix, as a Windows path
+//!   (`C:\Program Files\7-Zip\7z.exe`) or relative to `drive_c`
+
+use std::path::{Path, PathBuf};
+
+use super::registry;
+
+/// Keys listing installed programs, for 64-bit, 32-bit and per-user installs.
+pub const UNINSTALL_KEYS: &[&str] = &[
+    "HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    "HKEY_LOCAL_MACHINE\\Software\\Wow6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    "HKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+];
+
+/// A program listed under an Uninstall key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledProgram {
+    /// Full registry key of the entry.
+    pub key: String,
+    /// `DisplayName`, or the subkey name when there is none.
+    pub name: String,
+}
+
+/// Turn a .reg string (`"7-Zip 23.01"`) into plain text.
+fn reg_string(data: &str) -> Option<String> {
+    let inner = data.strip_prefix('"')?.strip_suffix('"')?;
+    Some(inner.replace("\\\"", "\"").replace("\\\\", "\\"))
+}
+
+/// Programs listed under the Uninstall keys of a prefix.
+pub fn installed_programs(prefix_path: &Path) -> Vec<InstalledProgram> {
+    let mut programs = Vec::new();
+    for parent in UNINSTALL_KEYS {
+        for subkey in registry::read_prefix_subkeys(prefix_path, parent) {
+            let key = format!("{}\\{}", parent, subkey);
+            let name = registry::read_prefix_value(prefix_path, &key, "DisplayName")
+                .and_then(|data| reg_string(&data))
+                .filter(|name| !name.is_empty())
+                .unwrap_or(subkey);
+            programs.push(InstalledProgram { key, name });
+        }
+    }
+    programs
+}
+
+/// Programs in `after` that weren't in `before`.
+pub fn new_programs(
+    before: &[InstalledProgram],
+    after: &[InstalledProgram],
+) -> Vec<InstalledProgram> {
+    after
+        .iter()
+        .filter(|program| {
+            !before
+                .iter()
+                .any(|old| old.key.eq_ignore_ascii_case(&program.key))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Evidence a verb declares for a successful install.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallMarker {
+    /// An Uninstall entry whose name contains this text (case-insensitive).
+    Uninstall(String),
+    /// A file in the prefix, as a Windows path or relative to `drive_c`.
+    File(String),
+}
+
+impl InstallMarker {
+    /// Parse a marker as written in verb files: `uninstall:TEXT` or `file:PATH`.
+    ///
+    /// ```
+    /// use protontool::wine::uninstall::InstallMarker;
+    /// assert_eq!(
+    ///     InstallMarker::parse("uninstall:7-Zip"),
+    ///     Some(InstallMarker::Uninstall("7-Zip".to_string()))
+    /// );
+    /// assert_eq!(InstallMarker::parse("7-Zip"), None);
+    /// ```
+    pub fn parse(s: &str) -> Option<Self> {
+        let (kind, value) = s.split_once(':')?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        match kind.trim() {
+            "uninstall" => Some(InstallMarker::Uninstall(value.to_string())),
+            "file" => Some(InstallMarker::File(value.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Human-readable description for error messages.
+    pub fn describe(&self) -> String {
+        match self {
+            InstallMarker::Uninstall(name) => format!("an Uninstall entry named '{}'", name),
+            InstallMarker::File(path) => format!("the file {}", path),
+        }
+    }
+
+    /// Whether the marker is present in a prefix.
+    pub fn is_present(&self, prefix_path: &Path) -> bool {
+        match self {
+            InstallMarker::Uninstall(text) => {
+                let text = text.to_lowercase();
+                installed_programs(prefix_path)
+                    .iter()
+                    .any(|program| program.name.to_lowercase().contains(&text))
+            }
+            InstallMarker::File(path) => prefix_file(prefix_path, path).exists(),
+        }
+    }
+}
+
+/// Host path of a file given as a `C:` Windows path or relative to `drive_c`.
+fn prefix_file(prefix_path: &Path, windows_path: &str) -> PathBuf {
+    let normalized = windows_path.replace('\\', "/");
+    let relative = match normalized.get(..2) {
+        Some(drive) if drive.eq_ignore_ascii_case("c:") => &normalized[2..],
+        _ => normalized.as_str(),
+    };
+    prefix_path
+        .join("drive_c")
+        .join(relative.trim_start_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_installed_programs() {
+        let prefix = std::env::temp_dir().join(format!("pt-uninstall-{}", std::process::id()));
+        fs::create_dir_all(prefix.join("drive_c/Program Files/7-Zip")).unwrap();
+        fs::write(prefix.join("drive_c/Program Files/7-Zip/7z.exe"), "").unwrap();
+        let before = installed_programs(&prefix);
+        assert!(before.is_empty());
+
+        fs::write(
+            prefix.join("system.reg"),
+            "WINE REGISTRY Version 2\n\n\
+             [Software\\\\Microsoft\\\\Windows\\\\CurrentVersion\\\\Uninstall] 1700000000\n\n\
+             [Software\\\\Microsoft\\\\Windows\\\\CurrentVersion\\\\Uninstall\\\\7-Zip] 1700000001\n\
+             \"DisplayName\"=\"7-Zip 23.01 (x64)\"\n\n\
+             [Software\\\\Microsoft\\\\Windows\\\\CurrentVersion\\\\Uninstall\\\\{ABC}\\\\Sub] 1700000002\n",
+        )
+        .unwrap();
+        let after = installed_programs(&prefix);
+        let names: Vec<&str> = after.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["7-Zip 23.01 (x64)", "{ABC}"]);
+        assert_eq!(new_programs(&before, &after).len(), 2);
+        assert!(new_programs(&after, &after).is_empty());
+
+        assert!(InstallMarker::parse("uninstall:7-zip")
+            .unwrap()
+            .is_present(&prefix));
+        assert!(
+            InstallMarker::parse("file:C:\\Program Files\\7-Zip\\7z.exe")
+                .unwrap()
+                .is_present(&prefix)
+        );
+        assert!(!InstallMarker::parse("file:Program Files/7-Zip/7zFM.exe")
+            .unwrap()
+            .is_present(&prefix));
+
+        fs::remove_dir_all(&prefix).ok();
+    }
+}
@@ -0,0 +1,169 @@
+//! Prefix usage statistics and "last used" tracking.
+//!
+//! Every Wine command and game launch protontool runs in a prefix is
+//! recorded in the `prefix-usage` state record (`path<TAB>last-used<TAB>runs`).
+//! Games started by Steam never go through protontool, so the last use also
+//! considers the mtimes of the files Wine and Proton touch on each run: the
+//! registry hives, saved when the wineserver exits, and Proton's `pfx.lock`
+//! next to a Steam prefix.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// State record with one line per prefix.
+const USAGE_STATE: &str = "prefix-usage";
+
+/// Prefixes unused for this long are suggested for removal.
+pub const STALE_AFTER_SECS: u64 = 180 * 24 * 60 * 60;
+
+/// How much a prefix has been used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrefixUsage {
+    /// Last use as seconds since the epoch, from protontool's record or
+    /// the prefix's files, whichever is newer.
+    pub last_used: Option<u64>,
+    /// Commands and launches run through protontool.
+    pub runs: u64,
+}
+
+impl PrefixUsage {
+    /// Seconds since the last use, None if never used.
+    pub fn age(&self, now: u64) -> Option<u64> {
+        self.last_used.map(|last| now.saturating_sub(last))
+    }
+
+    /// Whether the prefix hasn't been used in `STALE_AFTER_SECS`.
+    pub fn is_stale(&self, now: u64) -> bool {
+        self.age(now).is_some_and(|age| age >= STALE_AFTER_SECS)
+    }
+}
+
+/// Current time as seconds since the epoch.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Parse the state record into (prefix, last used, runs) entries.
+fn parse_records(lines: &[String]) -> Vec<(PathBuf, u64, u64)> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let path = PathBuf::from(fields.next()?);
+            let last_used = fields.next()?.parse().ok()?;
+            let runs = fields.next()?.parse().ok()?;
+            Some((path, last_used, runs))
+        })
+        .collect()
+}
+
+/// Record that something ran in a prefix just now.
+pub fn record_use(prefix_path: &Path) {
+    let mut records = parse_records(&crate::state::read_lines(USAGE_STATE));
+    let now = now_secs();
+    match records.iter_mut().find(|(path, _, _)| path == prefix_path) {
+        Some(record) => {
+            record.1 = now;
+            record.2 += 1;
+        }
+        None => records.push((prefix_path.to_path_buf(), now, 1)),
+    }
+
+    let content: String = records
+        .iter()
+        .map(|(path, last_used, runs)| format!("{}\t{}\t{}\n", path.display(), last_used, runs))
+        .collect();
+    if let Err(e) = crate::state::write(USAGE_STATE, &content) {
+        crate::log::warn(&format!("Failed to save prefix usage: {}", e));
+    }
+}
+
+/// Newest mtime of the files Wine and Proton update when a prefix runs.
+fn activity_mtime(prefix_path: &Path) -> Option<u64> {
+    let mut candidates = vec![prefix_path.join("user.reg"), prefix_path.join("system.reg")];
+    if let Some(name) = prefix_path.file_name() {
+        // compatdata/APPID/pfx.lock for Steam prefixes
+        candidates.push(prefix_path.with_file_name(format!("{}.lock", name.to_string_lossy())));
+    }
+    candidates
+        .iter()
+        .filter_map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .filter_map(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .max()
+}
+
+/// Usage of a prefix.
+pub fn usage(prefix_path: &Path) -> PrefixUsage {
+    let records = parse_records(&crate::state::read_lines(USAGE_STATE));
+    let recorded = records.iter().find(|(path, _, _)| path == prefix_path);
+    let last_used = recorded
+        .map(|(_, last_used, _)| *last_used)
+        .max(activity_mtime(prefix_path));
+    PrefixUsage {
+        last_used,
+        runs: recorded.map_or(0, |(_, _, runs)| *runs),
+    }
+}
+
+/// Sort prefixes by last use, most recent first. Never-used prefixes go
+/// last, keeping their order.
+pub fn sort_by_last_used(prefixes: &mut [PathBuf]) {
+    prefixes.sort_by_cached_key(|path| std::cmp::Reverse(usage(path).last_used));
+}
+
+/// Short description of how long ago something was used.
+///
+/// ```
+/// use protontool::wine::usage::describe_age;
+/// assert_eq!(describe_age(None), "never");
+/// assert_eq!(describe_age(Some(90)), "just now");
+/// assert_eq!(describe_age(Some(3 * 3600)), "3 hours ago");
+/// assert_eq!(describe_age(Some(200 * 86400)), "6 months ago");
+/// ```
+pub fn describe_age(age_secs: Option<u64>) -> String {
+    let Some(age) = age_secs else {
+        return "never".to_string();
+    };
+    let (count, unit) = match age {
+        a if a < 3600 => return "just now".to_string(),
+        a if a < 86400 => (a / 3600, "hour"),
+        a if a < 30 * 86400 => (a / 86400, "day"),
+        a if a < 365 * 86400 => (a / (30 * 86400), "month"),
+        a => (a / (365 * 86400), "year"),
+    };
+    format!(
+        "{} {}{} ago",
+        count,
+        unit,
+        if count == 1 { "" } else { "s" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_records() {
+        let lines = vec![
+            "/home/user/.protontool/prefixes/a\t1000\t3".to_string(),
+            "/home/user/.protontool/prefixes/b\tx\t1".to_string(),
+        ];
+        let records = parse_records(&lines);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].1, 1000);
+
+        let usage = PrefixUsage {
+            last_used: Some(1000),
+            runs: 3,
+        };
+        assert!(!usage.is_stale(1000 + STALE_AFTER_SECS - 1));
+        assert!(usage.is_stale(1000 + STALE_AFTER_SECS));
+        assert!(!PrefixUsage::default().is_stale(u64::MAX));
+    }
+}
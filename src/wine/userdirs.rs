@@ -0,0 +1,398 @@
+//! Which host user directories a prefix links into its user profile.
+//!
+//! Wine links the profile folders of a new prefix (Desktop, Documents,
+//! Downloads, ...) to the host's XDG user directories, so saves and
+//! screenshots land in `~/Documents` and `~/Pictures`. A user dirs policy
+//! chooses per folder whether it links to the host directory, to a custom
+//! directory, or stays a plain directory inside the prefix:
+//!
+//! - `all`: link every folder to its host directory (Wine's default)
+//! - `none`: link nothing
+//! - `documents,downloads`: link only the listed folders
+//! - `documents=~/Saves,pictures`: link Documents to `~/Saves` instead
+//!
+//! Host directories are looked up with `xdg-user-dir` or `user-dirs.dirs`,
+//! so localized names such as `~/Dokumente` are followed. The policy is
+//! stored as `user_dirs` in the prefix metadata.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::custom::expand_path;
+use super::metadata::PrefixMetadata;
+use crate::util::which;
+
+/// Metadata key recording the policy of a prefix.
+const USER_DIRS_KEY: &str = "user_dirs";
+
+/// A profile folder Wine links to the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserDir {
+    Desktop,
+    Documents,
+    Downloads,
+    Music,
+    Pictures,
+    Videos,
+}
+
+impl UserDir {
+    pub const ALL: [UserDir; 6] = [
+        UserDir::Desktop,
+        UserDir::Documents,
+        UserDir::Downloads,
+        UserDir::Music,
+        UserDir::Pictures,
+        UserDir::Videos,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UserDir::Desktop => "desktop",
+            UserDir::Documents => "documents",
+            UserDir::Downloads => "downloads",
+            UserDir::Music => "music",
+            UserDir::Pictures => "pictures",
+            UserDir::Videos => "videos",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        Self::ALL.into_iter().find(|dir| dir.name() == name)
+    }
+
+    /// Name used by `xdg-user-dir` and `user-dirs.dirs`.
+    fn xdg_name(&self) -> &'static str {
+        match self {
+            UserDir::Desktop => "DESKTOP",
+            UserDir::Documents => "DOCUMENTS",
+            UserDir::Downloads => "DOWNLOAD",
+            UserDir::Music => "MUSIC",
+            UserDir::Pictures => "PICTURES",
+            UserDir::Videos => "VIDEOS",
+        }
+    }
+
+    /// Folder names in a Wine user profile. Older prefixes use "My ...".
+    fn folders(&self) -> &'static [&'static str] {
+        match self {
+            UserDir::Desktop => &["Desktop"],
+            UserDir::Documents => &["Documents", "My Documents"],
+            UserDir::Downloads => &["Downloads"],
+            UserDir::Music => &["Music", "My Music"],
+            UserDir::Pictures => &["Pictures", "My Pictures"],
+            UserDir::Videos => &["Videos", "My Videos"],
+        }
+    }
+}
+
+/// Where a linked folder points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// The host's XDG user directory.
+    Host,
+    Custom(PathBuf),
+}
+
+/// Which profile folders are linked, and where to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserDirPolicy {
+    links: Vec<(UserDir, LinkTarget)>,
+}
+
+impl UserDirPolicy {
+    pub fn all() -> Self {
+        UserDirPolicy {
+            links: UserDir::ALL
+                .into_iter()
+                .map(|dir| (dir, LinkTarget::Host))
+                .collect(),
+        }
+    }
+
+    pub fn none() -> Self {
+        UserDirPolicy { links: Vec::new() }
+    }
+
+    /// Parse a policy: `all`, `none` or a comma-separated list of folders,
+    /// each optionally followed by `=DIR`.
+    ///
+    /// ```
+    /// use protontool::wine::userdirs::{LinkTarget, UserDir, UserDirPolicy};
+    /// let policy = UserDirPolicy::parse("documents=/srv/saves,pictures").unwrap();
+    /// assert_eq!(
+    ///     policy.target(UserDir::Documents),
+    ///     Some(&LinkTarget::Custom("/srv/saves".into()))
+    /// );
+    /// assert_eq!(policy.target(UserDir::Desktop), None);
+    /// ```
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        match spec.trim() {
+            "all" => return Ok(Self::all()),
+            "none" | "" => return Ok(Self::none()),
+            _ => {}
+        }
+        let mut links: Vec<(UserDir, LinkTarget)> = Vec::new();
+        for item in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (name, target) = match item.split_once('=') {
+                Some((name, dir)) if !dir.trim().is_empty() => (
+                    name,
+                    LinkTarget::Custom(PathBuf::from(expand_path(dir.trim()))),
+                ),
+                Some((name, _)) => {
+                    return Err(format!("Missing directory for user folder '{}'", name))
+                }
+                None => (item, LinkTarget::Host),
+            };
+            let dir = UserDir::from_name(name).ok_or_else(|| {
+                let known: Vec<&str> = UserDir::ALL.iter().map(|d| d.name()).collect();
+                format!(
+                    "Unknown user folder '{}' (expected all, none or {})",
+                    name.trim(),
+                    known.join(", ")
+                )
+            })?;
+            links.retain(|(existing, _)| *existing != dir);
+            links.push((dir, target));
+        }
+        Ok(UserDirPolicy { links })
+    }
+
+    /// Where a folder is linked, or None to keep it inside the prefix.
+    pub fn target(&self, dir: UserDir) -> Option<&LinkTarget> {
+        self.links
+            .iter()
+            .find(|(linked, _)| *linked == dir)
+            .map(|(_, target)| target)
+    }
+
+    /// The policy in the form accepted by `parse`.
+    pub fn to_spec(&self) -> String {
+        if self.links.is_empty() {
+            return "none".to_string();
+        }
+        if *self == Self::all() {
+            return "all".to_string();
+        }
+        self.links
+            .iter()
+            .map(|(dir, target)| match target {
+                LinkTarget::Host => dir.name().to_string(),
+                LinkTarget::Custom(path) => format!("{}={}", dir.name(), path.display()),
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Result of applying a policy.
+#[derive(Debug, Default)]
+pub struct ApplyReport {
+    /// Folders linked or unlinked.
+    pub changed: usize,
+    /// Folders that should be links but contain files, left alone.
+    pub kept: Vec<PathBuf>,
+}
+
+/// User profile directories of a prefix (`drive_c/users/*`), except the
+/// shared `Public` profile, whose folders Wine never links.
+fn user_profiles(prefix_path: &Path) -> Vec<PathBuf> {
+    fs::read_dir(prefix_path.join("drive_c/users"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_dir() && !p.ends_with("Public"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read a directory from `user-dirs.dirs` (`XDG_DOCUMENTS_DIR="$HOME/..."`).
+fn user_dirs_file_entry(content: &str, xdg_name: &str, home: &str) -> Option<PathBuf> {
+    let key = format!("XDG_{}_DIR", xdg_name);
+    content.lines().find_map(|line| {
+        let (name, value) = line.trim().split_once('=')?;
+        if name.trim() != key {
+            return None;
+        }
+        let value = value.trim().trim_matches('"');
+        Some(PathBuf::from(value.replacen("$HOME", home, 1)))
+    })
+}
+
+/// Host directory for a user folder, if it isn't `$HOME` itself.
+fn host_dir(dir: UserDir) -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    let from_tool = which("xdg-user-dir")
+        .and_then(|tool| Command::new(tool).arg(dir.xdg_name()).output().ok())
+        .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()));
+    let found = from_tool
+        .filter(|path| !path.as_os_str().is_empty())
+        .or_else(|| {
+            let config_home = std::env::var("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| Path::new(&home).join(".config"));
+            let content = fs::read_to_string(config_home.join("user-dirs.dirs")).ok()?;
+            user_dirs_file_entry(&content, dir.xdg_name(), &home)
+        })?;
+    if found == Path::new(&home) || !found.is_dir() {
+        return None;
+    }
+    Some(found)
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(not(unix))]
+fn symlink(_target: &Path, _link: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "symlinks are not supported on this platform",
+    ))
+}
+
+/// Link or unlink the profile folders of a prefix according to a policy.
+/// Folders that should become links but contain files are left alone and
+/// reported in `kept`; a folder whose host directory can't be found is left
+/// as it is.
+pub fn apply_policy(prefix_path: &Path, policy: &UserDirPolicy) -> Result<ApplyReport, String> {
+    let mut report = ApplyReport::default();
+    for profile in user_profiles(prefix_path) {
+        for dir in UserDir::ALL {
+            let target = match policy.target(dir) {
+                Some(LinkTarget::Host) => match host_dir(dir) {
+                    Some(path) => Some(path),
+                    None => continue,
+                },
+                Some(LinkTarget::Custom(path)) => {
+                    fs::create_dir_all(path)
+                        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+                    Some(path.clone())
+                }
+                None => None,
+            };
+
+            for folder in dir.folders() {
+                let path = profile.join(folder);
+                let is_link = path.is_symlink();
+                if !is_link && !path.is_dir() {
+                    continue;
+                }
+                match &target {
+                    Some(target) => {
+                        if is_link {
+                            if fs::read_link(&path).is_ok_and(|current| current == *target) {
+                                continue;
+                            }
+                            fs::remove_file(&path).map_err(|e| {
+                                format!("Failed to remove {}: {}", path.display(), e)
+                            })?;
+                        } else if fs::remove_dir(&path).is_err() {
+                            // remove_dir only succeeds on empty directories
+                            report.kept.push(path);
+                            continue;
+                        }
+                        symlink(target, &path)
+                            .map_err(|e| format!("Failed to link {}: {}", path.display(), e))?;
+                        report.changed += 1;
+                    }
+                    None if is_link => {
+                        fs::remove_file(&path)
+                            .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+                        fs::create_dir_all(&path)
+                            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+                        report.changed += 1;
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// The policy recorded for a prefix, if one was set.
+pub fn policy(prefix_path: &Path) -> Option<UserDirPolicy> {
+    PrefixMetadata::load(prefix_path)
+        .get(USER_DIRS_KEY)
+        .and_then(|spec| UserDirPolicy::parse(spec).ok())
+}
+
+/// Record a policy in the prefix metadata without applying it.
+pub fn record_policy(prefix_path: &Path, policy: &UserDirPolicy) -> Result<(), String> {
+    let mut metadata = PrefixMetadata::load(prefix_path);
+    metadata.set(USER_DIRS_KEY, &policy.to_spec());
+    metadata.save(prefix_path)
+}
+
+/// Apply a policy to a prefix and record it in the metadata.
+pub fn set_policy(prefix_path: &Path, policy: &UserDirPolicy) -> Result<ApplyReport, String> {
+    let report = apply_policy(prefix_path, policy)?;
+    record_policy(prefix_path, policy)?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!(UserDirPolicy::parse("all").unwrap(), UserDirPolicy::all());
+        assert_eq!(UserDirPolicy::parse("none").unwrap().to_spec(), "none");
+
+        let policy = UserDirPolicy::parse("Documents, downloads=/tmp/dl,documents").unwrap();
+        assert_eq!(policy.target(UserDir::Documents), Some(&LinkTarget::Host));
+        assert_eq!(policy.to_spec(), "downloads=/tmp/dl,documents");
+        assert_eq!(UserDirPolicy::parse(&policy.to_spec()).unwrap(), policy);
+
+        assert!(UserDirPolicy::parse("saves").is_err());
+        assert!(UserDirPolicy::parse("documents=").is_err());
+    }
+
+    #[test]
+    fn test_user_dirs_file_entry() {
+        let content = "# written by xdg-user-dirs-update\n\
+                       XDG_DESKTOP_DIR=\"$HOME/Schreibtisch\"\n\
+                       XDG_DOCUMENTS_DIR=\"$HOME/Dokumente\"\n";
+        assert_eq!(
+            user_dirs_file_entry(content, "DOCUMENTS", "/home/user"),
+            Some(PathBuf::from("/home/user/Dokumente"))
+        );
+        assert_eq!(user_dirs_file_entry(content, "MUSIC", "/home/user"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_apply_policy() {
+        let prefix = std::env::temp_dir().join(format!("pt-userdirs-{}", std::process::id()));
+        let profile = prefix.join("drive_c/users/steamuser");
+        let custom = prefix.join("saves");
+        fs::create_dir_all(profile.join("Documents")).unwrap();
+        fs::create_dir_all(profile.join("Pictures")).unwrap();
+        fs::write(profile.join("Pictures/shot.png"), "").unwrap();
+
+        let policy = UserDirPolicy::parse(&format!(
+            "documents={},pictures={}",
+            custom.display(),
+            custom.display()
+        ))
+        .unwrap();
+        let report = apply_policy(&prefix, &policy).unwrap();
+        assert_eq!(report.changed, 1);
+        assert_eq!(report.kept, vec![profile.join("Pictures")]);
+        assert_eq!(fs::read_link(profile.join("Documents")).unwrap(), custom);
+
+        let report = apply_policy(&prefix, &UserDirPolicy::none()).unwrap();
+        assert_eq!(report.changed, 1);
+        assert!(profile.join("Documents").is_dir() && !profile.join("Documents").is_symlink());
+
+        fs::remove_dir_all(&prefix).ok();
+    }
+}
@@ -15,7 +15,9 @@ pub fn extract_archive(archive: &Path, dest: &Path) -> Result<(), String> {
 
     match (ext.to_lowercase().as_str(), is_tar_compressed) {
         ("zip", _) => extract_zip(archive, dest),
-        ("7z", _) => extract_7z(archive, dest),
+        // First volume of a split 7z archive (file.7z.001)
+        ("7z" | "001", _) => extract_7z(archive, dest),
+        ("rar", _) => extract_rar(archive, dest),
         ("tar" | "tgz" | "tbz2" | "txz" | "tlz", _) => extract_tar(archive, dest),
         ("gz" | "bz2" | "xz" | "lz", true) => extract_tar(archive, dest),
         ("zst", true) => extract_zst(archive, dest),
@@ -90,6 +92,29 @@ pub fn extract_7z(archive: &Path, dest: &Path) -> Result<(), String> {
     Err("7z not available for extraction".to_string())
 }
 
+/// Extract a RAR archive using unrar or 7z. Given the first volume of a
+/// multi-part archive (file.part1.rar), the other volumes are read from the
+/// same directory.
+pub fn extract_rar(archive: &Path, dest: &Path) -> Result<(), String> {
+    if let Some(unrar) = crate::util::which("unrar") {
+        let mut dest_arg = dest.to_string_lossy().to_string();
+        if !dest_arg.ends_with('/') {
+            dest_arg.push('/');
+        }
+        let status = Command::new(unrar)
+            .args(["x", "-o+", "-y", &archive.to_string_lossy(), &dest_arg])
+            .status()
+            .map_err(|e| format!("Failed to run unrar: {}", e))?;
+
+        if status.success() {
+            return Ok(());
+        }
+    }
+
+    extract_7z(archive, dest)
+        .map_err(|_| "No RAR extraction tool available (unrar or 7z required)".to_string())
+}
+
 /// Extract a tar archive (handles .tar, .tar.gz, .tar.bz2, .tar.xz).
 pub fn extract_tar(archive: &Path, dest: &Path) -> Result<(), String> {
     if let Some(tar) = crate::util::which("tar") {
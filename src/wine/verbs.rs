@@ -1,12 +1,24 @@
 //! Verb system for installing Windows components, DLLs, fonts, and settings.
 //!
 //! Verbs are reusable installation recipes similar to winetricks.
+//!
+//! Every verb lives in a namespace: `builtin` for the verbs defined here,
+//! `user` for custom verbs in the verb directory, and the subdirectory name
+//! for verb packs (`community/dgvoodoo2`). A verb can be named with or
+//! without its namespace; an unqualified name that several namespaces
+//! define is reported as ambiguous rather than picking one.
 
 use std::collections::HashMap;
 use std::path::Path;
 
-use super::download::Downloader;
+use super::download::{DownloadPart, Downloader, PartAssembly};
+use super::runner::{RunnerCapabilities, STAGING_FEATURES};
+use super::uninstall::{self, InstallMarker};
 use super::WineContext;
+use crate::workdir::{unique_file, WorkDir};
+
+/// Namespace of the verbs built into protontool.
+pub const BUILTIN_NAMESPACE: &str = "builtin";
 
 /// Category of a verb for organization and filtering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +27,7 @@ pub enum VerbCategory {
     Dll,
     Font,
     Setting,
+    GameFix,
     Custom,
 }
 
@@ -26,6 +39,7 @@ impl VerbCategory {
             VerbCategory::Dll => "dlls",
             VerbCategory::Font => "fonts",
             VerbCategory::Setting => "settings",
+            VerbCategory::GameFix => "gamefixes",
             VerbCategory::Custom => "custom",
         }
     }
@@ -37,6 +51,7 @@ impl VerbCategory {
             VerbCategory::Dll,
             VerbCategory::Font,
             VerbCategory::Setting,
+            VerbCategory::GameFix,
             VerbCategory::Custom,
         ]
     }
@@ -64,11 +79,16 @@ impl DllOverride {
 }
 
 /// A file to download from a URL with optional checksum verification.
+/// Large files may instead be split into parts with checksums of their own.
 #[derive(Debug, Clone)]
 pub struct DownloadFile {
     pub url: String,
     pub filename: String,
     pub sha256: Option<String>,
+    /// Other URLs serving the same file.
+    pub mirrors: Vec<String>,
+    pub parts: Vec<DownloadPart>,
+    pub assembly: PartAssembly,
 }
 
 impl DownloadFile {
@@ -78,8 +98,61 @@ impl DownloadFile {
             url: url.to_string(),
             filename: filename.to_string(),
             sha256: sha256.map(|s| s.to_string()),
+            mirrors: Vec::new(),
+            parts: Vec::new(),
+            assembly: PartAssembly::Concatenate,
+        }
+    }
+
+    /// A file downloaded in parts, in order. `sha256` verifies the joined
+    /// file of concatenated parts; volumes only use their own checksums.
+    pub fn multipart(
+        filename: &str,
+        parts: Vec<DownloadPart>,
+        assembly: PartAssembly,
+        sha256: Option<&str>,
+    ) -> Self {
+        Self {
+            url: String::new(),
+            filename: filename.to_string(),
+            sha256: sha256.map(|s| s.to_string()),
+            mirrors: Vec::new(),
+            parts,
+            assembly,
         }
     }
+
+    /// Add other URLs serving the same file (builder pattern). The fastest
+    /// mirror is tried first.
+    pub fn with_mirrors(mut self, mirrors: &[&str]) -> Self {
+        self.mirrors = mirrors.iter().map(|m| m.to_string()).collect();
+        self
+    }
+
+    /// Download the file (all its parts) into the cache and return the
+    /// path to use: the joined file, or the first volume.
+    pub fn fetch(&self, downloader: &Downloader) -> Result<std::path::PathBuf, String> {
+        if self.parts.is_empty() {
+            let urls: Vec<String> = std::iter::once(self.url.clone())
+                .chain(self.mirrors.iter().cloned())
+                .collect();
+            downloader.download_from(&urls, &self.filename, self.sha256.as_deref())
+        } else {
+            downloader.download_parts(
+                &self.parts,
+                &self.filename,
+                self.assembly,
+                self.sha256.as_deref(),
+            )
+        }
+    }
+
+    /// Names of the cached files making up this download.
+    pub fn cached_filenames(&self) -> Vec<&str> {
+        std::iter::once(self.filename.as_str())
+            .chain(self.parts.iter().map(|part| part.filename.as_str()))
+            .collect()
+    }
 }
 
 /// A local file path for offline installation (paid/licensed software)
@@ -145,18 +218,171 @@ pub enum VerbAction {
     CallVerb {
         name: String,
     },
+    /// Run an external plugin from the plugins directory.
+    Plugin {
+        name: String,
+        args: Vec<String>,
+    },
     Custom(CustomAction),
 }
 
+impl VerbAction {
+    /// Short description for prompts and the ledger.
+    pub fn describe(&self) -> String {
+        match self {
+            VerbAction::RunInstaller { file, .. } => format!("run {}", file.filename),
+            VerbAction::RunLocalInstaller { file, .. } => format!("run {}", file.name),
+            VerbAction::RunScript { script_path } => format!("run {}", script_path.display()),
+            VerbAction::Extract { file, dest } | VerbAction::ExtractCab { file, dest, .. } => {
+                format!("extract {} to {}", file.filename, dest)
+            }
+            VerbAction::Override { dll, mode } => {
+                format!("set DLL override {}={}", dll, mode.as_str())
+            }
+            VerbAction::Registry { .. } => "import registry settings".to_string(),
+            VerbAction::Winecfg { args } => format!("winecfg {}", args.join(" ")),
+            VerbAction::RegisterFont { filename, .. } => format!("register font {}", filename),
+            VerbAction::CallVerb { name } => format!("apply verb {}", name),
+            VerbAction::Plugin { name, .. } => format!("run plugin {}", name),
+            VerbAction::Custom(_) => "custom step".to_string(),
+        }
+    }
+}
+
+/// What to do after an action of a verb failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionResolution {
+    /// Run the action again.
+    Retry,
+    /// Continue with the next action.
+    Skip,
+    /// Fail the verb.
+    Abort,
+}
+
+impl ActionResolution {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActionResolution::Retry => "retry",
+            ActionResolution::Skip => "skip",
+            ActionResolution::Abort => "abort",
+        }
+    }
+}
+
+/// A failed action of a verb, for the failure prompt.
+#[derive(Debug, Clone)]
+pub struct ActionFailure<'a> {
+    pub verb: &'a str,
+    /// Position of the action in the verb (0-based) and number of actions.
+    pub index: usize,
+    pub count: usize,
+    pub action: String,
+    pub error: &'a str,
+}
+
+/// Start of the error of a verb the user aborted, which isn't retried.
+const ABORTED_PREFIX: &str = "Aborted after a failed step: ";
+
+/// Asks how a failed action is resolved.
+pub type ActionFailurePrompt = fn(&ActionFailure) -> ActionResolution;
+
+static FAILURE_PROMPT: std::sync::Mutex<Option<ActionFailurePrompt>> = std::sync::Mutex::new(None);
+
+/// Ask `prompt` how to go on when an action of a multi-action verb fails,
+/// instead of failing the verb. Only set in interactive mode.
+pub fn set_action_failure_prompt(prompt: Option<ActionFailurePrompt>) {
+    if let Ok(mut current) = FAILURE_PROMPT.lock() {
+        *current = prompt;
+    }
+}
+
+fn action_failure_prompt() -> Option<ActionFailurePrompt> {
+    FAILURE_PROMPT.lock().ok().and_then(|prompt| *prompt)
+}
+
+/// Exit codes installers use to report success (1641/3010: reboot initiated/required).
+const INSTALLER_SUCCESS_CODES: &[i32] = &[0, 1641, 3010];
+
+/// A recovery step tried when a verb fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryStrategy {
+    /// Run again with an empty temporary directory.
+    FreshTempDir,
+    /// Run again with the given Windows version, then restore the default.
+    Winver(String),
+    /// Discard the verb's cached downloads and run again.
+    Force,
+}
+
+impl RetryStrategy {
+    /// Parse a strategy as written in verb files: `fresh_tmp`, `winver=win7`, `force`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "fresh_tmp" | "fresh_temp" => Some(RetryStrategy::FreshTempDir),
+            "force" => Some(RetryStrategy::Force),
+            other => other
+                .strip_prefix("winver=")
+                .filter(|v| !v.is_empty())
+                .map(|v| RetryStrategy::Winver(v.to_string())),
+        }
+    }
+
+    /// Human-readable description for log messages.
+    pub fn describe(&self) -> String {
+        match self {
+            RetryStrategy::FreshTempDir => "with a fresh temp directory".to_string(),
+            RetryStrategy::Winver(v) => format!("with winver {}", v),
+            RetryStrategy::Force => "after discarding cached downloads".to_string(),
+        }
+    }
+}
+
+/// When and how to retry a failed verb.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// KNOWN_ERRORS codes that trigger a retry; empty means any failure.
+    pub on_errors: Vec<String>,
+    /// Strategies tried in order until one succeeds.
+    pub strategies: Vec<RetryStrategy>,
+}
+
+impl RetryPolicy {
+    /// Check whether a failure (error message including captured output) should be retried.
+    pub fn matches(&self, error: &str) -> bool {
+        if self.on_errors.is_empty() {
+            return true;
+        }
+        crate::log::known_error_codes(error)
+            .iter()
+            .any(|code| self.on_errors.contains(code))
+    }
+}
+
 /// A verb definition with metadata and actions to execute.
 #[derive(Clone)]
 pub struct Verb {
+    /// Name without the namespace.
     pub name: String,
+    /// Namespace the verb was loaded into, e.g. `builtin` or `user`.
+    pub namespace: String,
     pub category: VerbCategory,
     pub title: String,
     pub publisher: String,
     pub year: String,
     pub actions: Vec<VerbAction>,
+    pub retry: Option<RetryPolicy>,
+    pub homepage: String,
+    pub license: String,
+    /// URL of a license agreement that must be accepted before installing.
+    pub eula: Option<String>,
+    /// Installer exit codes meaning success besides 0, 1641 and 3010.
+    pub success_codes: Vec<i32>,
+    /// Evidence checked after the verb runs to confirm the install.
+    pub installed: Option<InstallMarker>,
+    /// Actions run after the others even when one of them failed, to undo
+    /// temporary changes.
+    pub cleanup: Vec<VerbAction>,
 }
 
 impl Verb {
@@ -170,31 +396,480 @@ impl Verb {
     ) -> Self {
         Self {
             name: name.to_string(),
+            namespace: BUILTIN_NAMESPACE.to_string(),
             category,
             title: title.to_string(),
             publisher: publisher.to_string(),
             year: year.to_string(),
             actions: Vec::new(),
+            retry: None,
+            homepage: String::new(),
+            license: String::new(),
+            eula: None,
+            success_codes: Vec::new(),
+            installed: None,
+            cleanup: Vec::new(),
         }
     }
 
+    /// Name including the namespace, e.g. `user/mygame-fix`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}/{}", self.namespace, self.name)
+    }
+
+    /// Place the verb in a namespace (builder pattern).
+    pub fn in_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = namespace.to_string();
+        self
+    }
+
     /// Add actions to this verb (builder pattern).
     pub fn with_actions(mut self, actions: Vec<VerbAction>) -> Self {
         self.actions = actions;
         self
     }
 
-    /// Execute all actions in this verb.
+    /// Add actions that run after the others whether or not they succeeded
+    /// (builder pattern).
+    pub fn with_cleanup(mut self, actions: Vec<VerbAction>) -> Self {
+        self.cleanup = actions;
+        self
+    }
+
+    /// Accept additional installer exit codes as success (builder pattern).
+    pub fn with_success_codes(mut self, codes: &[i32]) -> Self {
+        self.success_codes = codes.to_vec();
+        self
+    }
+
+    /// Require an install marker after the verb runs (builder pattern).
+    pub fn with_install_marker(mut self, marker: InstallMarker) -> Self {
+        self.installed = Some(marker);
+        self
+    }
+
+    /// Whether the verb runs an installer program.
+    pub fn runs_installer(&self) -> bool {
+        self.actions.iter().any(|action| {
+            matches!(
+                action,
+                VerbAction::RunInstaller { .. } | VerbAction::RunLocalInstaller { .. }
+            )
+        })
+    }
+
+    /// Set the retry policy for this verb (builder pattern).
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Set the project homepage (builder pattern).
+    pub fn with_homepage(mut self, homepage: &str) -> Self {
+        self.homepage = homepage.to_string();
+        self
+    }
+
+    /// Set the license name and, for proprietary software, the URL of the
+    /// license agreement that must be accepted first (builder pattern).
+    pub fn with_license(mut self, license: &str, eula: Option<&str>) -> Self {
+        self.license = license.to_string();
+        self.eula = eula.map(|e| e.to_string());
+        self
+    }
+
+    /// Execute all actions in this verb, applying the retry policy on failure.
     pub fn execute(&self, wine_ctx: &WineContext, cache_dir: &Path) -> Result<(), String> {
+        let mut result = self.execute_in(wine_ctx, cache_dir);
+
+        let policy = match &self.retry {
+            Some(p) => p,
+            None => return result,
+        };
+
+        for strategy in &policy.strategies {
+            let error = match &result {
+                Ok(()) => break,
+                Err(e) => e,
+            };
+            if !policy.matches(error) || error.starts_with(ABORTED_PREFIX) {
+                break;
+            }
+            crate::log::warn(&format!(
+                "Verb '{}' failed ({}), retrying {}",
+                self.name,
+                error.lines().next().unwrap_or_default(),
+                strategy.describe()
+            ));
+            result = self.retry_with(strategy, wine_ctx, cache_dir);
+        }
+
+        result
+    }
+
+    /// Execute all actions in a fresh temporary directory of their own,
+    /// then the cleanup actions, which also run after a failure.
+    fn execute_in(&self, wine_ctx: &WineContext, cache_dir: &Path) -> Result<(), String> {
         let downloader = Downloader::new(cache_dir);
-        let tmp_dir = cache_dir.join("tmp");
-        std::fs::create_dir_all(&tmp_dir).ok();
+        let work_dir = WorkDir::create(&format!("verb-{}", self.name))?;
+        let result = self.execute_actions(wine_ctx, &downloader, work_dir.path());
+
+        for action in &self.cleanup {
+            let cleaned = execute_action(
+                action,
+                wine_ctx,
+                &downloader,
+                work_dir.path(),
+                &self.success_codes,
+            );
+            match cleaned {
+                Err(e) if result.is_ok() => return Err(e),
+                Err(e) => crate::log::warn(&format!(
+                    "Verb '{}': cleanup step '{}' failed: {}",
+                    self.name,
+                    action.describe(),
+                    e
+                )),
+                Ok(()) => {}
+            }
+        }
+        result
+    }
+
+    /// Execute the actions of the verb, resolving failures as they happen.
+    fn execute_actions(
+        &self,
+        wine_ctx: &WineContext,
+        downloader: &Downloader,
+        work_dir: &Path,
+    ) -> Result<(), String> {
+        self.prefetch_downloads(downloader)?;
+
+        for (index, action) in self.actions.iter().enumerate() {
+            while let Err(error) =
+                execute_action(action, wine_ctx, downloader, work_dir, &self.success_codes)
+            {
+                match self.resolve_failure(wine_ctx, index, action, &error) {
+                    ActionResolution::Retry => {}
+                    ActionResolution::Skip => break,
+                    ActionResolution::Abort if self.asks_on_failure() => {
+                        return Err(format!("{}{}", ABORTED_PREFIX, error))
+                    }
+                    ActionResolution::Abort => return Err(error),
+                }
+            }
+        }
 
-        for action in &self.actions {
-            execute_action(action, wine_ctx, &downloader, &tmp_dir)?;
+        // An installer exiting with 0 may still not have installed anything
+        if let Some(marker) = &self.installed {
+            if !marker.is_present(&wine_ctx.prefix_path) {
+                return Err(format!(
+                    "The installer of {} exited successfully, but {} was not found; \
+                     the software was not installed",
+                    self.name,
+                    marker.describe()
+                ));
+            }
         }
         Ok(())
     }
+
+    /// Whether a failed action is resolved by asking the user.
+    fn asks_on_failure(&self) -> bool {
+        self.actions.len() > 1 && action_failure_prompt().is_some()
+    }
+
+    /// Decide how to go on after an action failed: skip it again if it was
+    /// skipped before, else ask the failure prompt (multi-action verbs
+    /// only), recording the answer in the ledger. Without a prompt the verb
+    /// fails.
+    fn resolve_failure(
+        &self,
+        wine_ctx: &WineContext,
+        index: usize,
+        action: &VerbAction,
+        error: &str,
+    ) -> ActionResolution {
+        let prefix_path = &wine_ctx.prefix_path;
+        let description = action.describe();
+        let remembered =
+            super::ledger::action_resolution(prefix_path, &self.name, index, &description);
+        if remembered.as_deref() == Some(ActionResolution::Skip.as_str()) {
+            crate::log::warn(&format!(
+                "Verb '{}': skipping '{}' as before ({})",
+                self.name,
+                description,
+                error.lines().next().unwrap_or_default()
+            ));
+            return ActionResolution::Skip;
+        }
+        let prompt = match action_failure_prompt() {
+            Some(prompt) if self.asks_on_failure() => prompt,
+            _ => return ActionResolution::Abort,
+        };
+        let resolution = prompt(&ActionFailure {
+            verb: &self.name,
+            index,
+            count: self.actions.len(),
+            action: description.clone(),
+            error,
+        });
+        if let Err(e) = super::ledger::record_resolution(
+            prefix_path,
+            &self.name,
+            index,
+            &description,
+            resolution.as_str(),
+        ) {
+            crate::log::warn(&format!("Failed to record action resolution: {}", e));
+        }
+        resolution
+    }
+
+    /// Download the files of all download actions up front, several at once,
+    /// instead of one by one between installers.
+    fn prefetch_downloads(&self, downloader: &Downloader) -> Result<(), String> {
+        let files: Vec<&DownloadFile> = self
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                VerbAction::RunInstaller { file, .. }
+                | VerbAction::Extract { file, .. }
+                | VerbAction::ExtractCab { file, .. } => Some(file),
+                _ => None,
+            })
+            .collect();
+        if files.len() < 2 || super::download::max_parallel_downloads() < 2 {
+            return Ok(());
+        }
+        let parallel = downloader.for_parallel();
+        super::download::for_each_parallel(&files, |file| file.fetch(&parallel))
+            .into_iter()
+            .collect::<Result<Vec<_>, String>>()
+            .map(|_| ())
+    }
+
+    /// Run the verb again after applying a retry strategy.
+    fn retry_with(
+        &self,
+        strategy: &RetryStrategy,
+        wine_ctx: &WineContext,
+        cache_dir: &Path,
+    ) -> Result<(), String> {
+        match strategy {
+            // Every attempt already runs in a new temporary directory.
+            RetryStrategy::FreshTempDir => self.execute_in(wine_ctx, cache_dir),
+            RetryStrategy::Winver(version) => {
+                let before = current_winver(wine_ctx);
+                wine_ctx
+                    .run_winecfg(&["-v", version])
+                    .map_err(|e| format!("Failed to set winver {}: {}", version, e))?;
+                wine_ctx.wait_for_wineserver().ok();
+                let result = self.execute_in(wine_ctx, cache_dir);
+                set_winver(wine_ctx, before.as_deref())?;
+                result
+            }
+            RetryStrategy::Force => {
+                let downloader = Downloader::new(cache_dir);
+                for action in &self.actions {
+                    match action {
+                        VerbAction::RunInstaller { file, .. }
+                        | VerbAction::Extract { file, .. }
+                        | VerbAction::ExtractCab { file, .. } => {
+                            for filename in file.cached_filenames() {
+                                std::fs::remove_file(downloader.get_cached_path(filename)).ok();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                self.execute_in(wine_ctx, cache_dir)
+            }
+        }
+    }
+}
+
+/// Whether an exit code matches an expected code. The Unix exit status only
+/// keeps the low 8 bits of a Windows exit code (3010 arrives as 194), so
+/// truncated codes match too.
+fn exit_code_matches(code: i32, expected: i32) -> bool {
+    code == expected || ((0..=255).contains(&code) && code == expected & 0xff)
+}
+
+/// Windows Installer errors, which the generated Win32 table doesn't cover.
+const INSTALLER_ERROR_CODES: &[(i32, &str, &str)] = &[
+    (
+        1602,
+        "ERROR_INSTALL_USEREXIT",
+        "installation cancelled by the user",
+    ),
+    (
+        1603,
+        "ERROR_INSTALL_FAILURE",
+        "fatal error during installation",
+    ),
+    (
+        1618,
+        "ERROR_INSTALL_ALREADY_RUNNING",
+        "another installation is in progress",
+    ),
+    (
+        1619,
+        "ERROR_INSTALL_PACKAGE_OPEN_FAILED",
+        "installation package could not be opened",
+    ),
+    (
+        1620,
+        "ERROR_INSTALL_PACKAGE_INVALID",
+        "installation package is invalid",
+    ),
+    (
+        1625,
+        "ERROR_INSTALL_PACKAGE_REJECTED",
+        "installation forbidden by system policy",
+    ),
+    (
+        1633,
+        "ERROR_INSTALL_PLATFORM_UNSUPPORTED",
+        "platform not supported by the package",
+    ),
+    (
+        1638,
+        "ERROR_PRODUCT_VERSION",
+        "another version of the product is installed",
+    ),
+    (
+        1639,
+        "ERROR_INVALID_COMMAND_LINE",
+        "invalid command line arguments",
+    ),
+];
+
+/// Describe a failing exit code with the Windows Installer errors and
+/// wine_data's Win32/HRESULT/NTSTATUS tables. A truncated code matching an
+/// installer error is reported as that error.
+fn describe_installer_exit_code(code: i32) -> Option<String> {
+    if let Some((full, name, description)) = INSTALLER_ERROR_CODES
+        .iter()
+        .find(|(c, _, _)| exit_code_matches(code, *c))
+    {
+        return Some(if code == *full {
+            format!("{}: {}", name, description)
+        } else {
+            format!("probably {} {}: {}", full, name, description)
+        });
+    }
+    crate::log::describe_exit_code(code)
+}
+
+/// Turn an installer's exit status into a result. Exit codes in
+/// `INSTALLER_SUCCESS_CODES` or the verb's `success_codes` are success.
+/// Failures carry the tail of the output so retry policies can match known
+/// error signatures.
+fn check_installer_output(
+    name: &str,
+    output: &std::process::Output,
+    success_codes: &[i32],
+) -> Result<(), String> {
+    let code = output.status.code().unwrap_or(-1);
+    if INSTALLER_SUCCESS_CODES
+        .iter()
+        .chain(success_codes)
+        .any(|&expected| exit_code_matches(code, expected))
+    {
+        return Ok(());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let crash = crate::log::crash_exception(code, &format!("{}\n{}", stdout, stderr));
+    let described = match crash
+        .map(|status| format!("crashed with {}", crate::log::describe_exception(status)))
+        .or_else(|| describe_installer_exit_code(code))
+    {
+        Some(desc) => format!("{} ({})", code, desc),
+        None => code.to_string(),
+    };
+    Err(format!(
+        "Installer {} exited with code {}\n{}\nFull output: {}",
+        name,
+        described,
+        summarize_output(&stdout, &stderr),
+        crate::log::get_current_log_path().display()
+    ))
+}
+
+/// The error lines of a failed command's output (the last few lines when
+/// none look like errors), so a failure doesn't dump the whole installer log.
+fn summarize_output(stdout: &str, stderr: &str) -> String {
+    let lines: Vec<&str> = stdout
+        .lines()
+        .chain(stderr.lines())
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+    let errors: Vec<&str> = lines
+        .iter()
+        .filter(|l| {
+            let lower = l.to_lowercase();
+            lower.contains("err:") || lower.contains("error")
+        })
+        .copied()
+        .collect();
+    let shown = if errors.is_empty() { &lines } else { &errors };
+    let skip = shown
+        .len()
+        .saturating_sub(if errors.is_empty() { 5 } else { 10 });
+    shown[skip..].join("\n")
+}
+
+/// .reg content setting DLL overrides.
+pub fn dll_overrides_content(overrides: &[(&str, &str)]) -> String {
+    let mut content = String::from(
+        "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n",
+    );
+    for (dll, mode) in overrides {
+        content.push_str(&format!("\"{}\"=\"{}\"\n", dll, mode));
+    }
+    content
+}
+
+/// Import DLL overrides without journaling them, for callers that journal
+/// the whole operation themselves.
+pub(crate) fn import_dll_overrides(
+    wine_ctx: &WineContext,
+    tmp_dir: &Path,
+    overrides: &[(&str, &str)],
+) -> Result<(), String> {
+    let reg_file = unique_file(tmp_dir, "dlloverrides", "reg");
+    std::fs::write(&reg_file, dll_overrides_content(overrides)).map_err(|e| e.to_string())?;
+    let result = wine_ctx.run_regedit(&reg_file).map_err(|e| e.to_string());
+    std::fs::remove_file(&reg_file).ok();
+    result.map(|_| ())
+}
+
+/// Write DLL overrides to the prefix registry (`HKCU\Software\Wine\DllOverrides`)
+/// so they apply to every later run in the prefix. The values they replace
+/// are journaled for `protontool undo`.
+pub fn persist_dll_overrides(
+    wine_ctx: &WineContext,
+    tmp_dir: &Path,
+    overrides: &[(&str, &str)],
+) -> Result<(), String> {
+    let changes = crate::journal::registry_changes(wine_ctx, &dll_overrides_content(overrides));
+    import_dll_overrides(wine_ctx, tmp_dir, overrides)?;
+    let pairs: Vec<String> = overrides
+        .iter()
+        .map(|(dll, mode)| format!("{}={}", dll, mode))
+        .collect();
+    let description = format!(
+        "DLL overrides {} in {}",
+        pairs.join(", "),
+        wine_ctx.prefix_path.display()
+    );
+    if let Err(e) = crate::journal::record(&description, changes) {
+        crate::log::warn(&format!("Failed to record DLL overrides in journal: {}", e));
+    }
+    Ok(())
 }
 
 /// Execute a single verb action.
@@ -203,15 +878,17 @@ fn execute_action(
     wine_ctx: &WineContext,
     downloader: &Downloader,
     tmp_dir: &Path,
+    success_codes: &[i32],
 ) -> Result<(), String> {
     match action {
         VerbAction::RunInstaller { file, args } => {
-            let local = downloader.download(&file.url, &file.filename, file.sha256.as_deref())?;
+            let local = file.fetch(downloader)?;
             let mut cmd_args: Vec<String> = vec![local.to_string_lossy().to_string()];
             cmd_args.extend(args.clone());
             let refs: Vec<&str> = cmd_args.iter().map(|s| s.as_str()).collect();
-            wine_ctx.run_wine(&refs).map_err(|e| e.to_string())?;
+            let output = wine_ctx.run_wine(&refs).map_err(|e| e.to_string())?;
             wine_ctx.wait_for_wineserver().ok();
+            check_installer_output(&file.filename, &output, success_codes)?;
         }
         VerbAction::RunLocalInstaller { file, args } => {
             if !file.path.exists() {
@@ -220,8 +897,9 @@ fn execute_action(
             let mut cmd_args: Vec<String> = vec![file.path.to_string_lossy().to_string()];
             cmd_args.extend(args.clone());
             let refs: Vec<&str> = cmd_args.iter().map(|s| s.as_str()).collect();
-            wine_ctx.run_wine(&refs).map_err(|e| e.to_string())?;
+            let output = wine_ctx.run_wine(&refs).map_err(|e| e.to_string())?;
             wine_ctx.wait_for_wineserver().ok();
+            check_installer_output(&file.name, &output, success_codes)?;
         }
         VerbAction::RunScript { script_path } => {
             if !script_path.exists() {
@@ -236,6 +914,7 @@ fn execute_action(
                 .env("PROTON_PATH", &wine_ctx.proton_path)
                 .env("W_TMP", tmp_dir)
                 .env("W_CACHE", downloader.cache_dir())
+                .envs(super::plugin::workshop_env(wine_ctx))
                 .env(
                     "W_SYSTEM32_DLLS",
                     wine_ctx.prefix_path.join("drive_c/windows/syswow64"),
@@ -254,13 +933,13 @@ fn execute_action(
             }
         }
         VerbAction::Extract { file, dest } => {
-            let local = downloader.download(&file.url, &file.filename, file.sha256.as_deref())?;
+            let local = file.fetch(downloader)?;
             let dest_path = wine_ctx.prefix_path.join(dest);
             std::fs::create_dir_all(&dest_path).ok();
             super::util::extract_archive(&local, &dest_path)?;
         }
         VerbAction::ExtractCab { file, dest, filter } => {
-            let local = downloader.download(&file.url, &file.filename, file.sha256.as_deref())?;
+            let local = file.fetch(downloader)?;
             let dest_path = if dest.is_empty() {
                 tmp_dir.to_path_buf()
             } else {
@@ -270,31 +949,36 @@ fn execute_action(
             super::util::extract_cab(&local, &dest_path, filter.as_deref())?;
         }
         VerbAction::Override { dll, mode } => {
-            let mut ctx = wine_ctx.clone();
-            ctx.set_dll_override(dll, mode.as_str());
+            import_dll_overrides(wine_ctx, tmp_dir, &[(dll.as_str(), mode.as_str())])?;
         }
         VerbAction::Registry { content } => {
-            let reg_file = tmp_dir.join("patch.reg");
+            let reg_file = unique_file(tmp_dir, "patch", "reg");
+            let content = super::template::expand_for_prefix(content, wine_ctx);
             std::fs::write(&reg_file, content).map_err(|e| e.to_string())?;
-            wine_ctx.run_regedit(&reg_file).map_err(|e| e.to_string())?;
+            let output = wine_ctx.run_regedit(&reg_file).map_err(|e| e.to_string());
             std::fs::remove_file(&reg_file).ok();
+            check_installer_output("regedit", &output?, &[])?;
         }
         VerbAction::Winecfg { args } => {
             let refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-            wine_ctx.run_winecfg(&refs).map_err(|e| e.to_string())?;
+            let output = wine_ctx.run_winecfg(&refs).map_err(|e| e.to_string())?;
             wine_ctx.wait_for_wineserver().ok();
+            check_installer_output("winecfg", &output, &[])?;
         }
         VerbAction::RegisterFont { filename, name } => {
             let content = format!(
                 "Windows Registry Editor Version 5.00\n\n[HKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows NT\\CurrentVersion\\Fonts]\n\"{} (TrueType)\"=\"{}\"\n",
                 name, filename
             );
-            let reg_file = tmp_dir.join("font.reg");
+            let reg_file = unique_file(tmp_dir, "font", "reg");
             std::fs::write(&reg_file, content).ok();
             wine_ctx.run_regedit(&reg_file).ok();
             std::fs::remove_file(&reg_file).ok();
         }
         VerbAction::CallVerb { .. } => { /* Handled by VerbRegistry */ }
+        VerbAction::Plugin { name, args } => {
+            super::plugin::run_plugin(name, args, wine_ctx, downloader, tmp_dir)?;
+        }
         VerbAction::Custom(func) => {
             func(wine_ctx, downloader, tmp_dir)?;
         }
@@ -302,6 +986,23 @@ fn execute_action(
     Ok(())
 }
 
+/// Sort verbs by category (in `VerbCategory::all` order), name and
+/// namespace, so listings don't follow hash map order.
+fn sort_verbs(verbs: &mut [&Verb]) {
+    let position = |category: VerbCategory| {
+        VerbCategory::all()
+            .iter()
+            .position(|c| *c == category)
+            .unwrap_or(usize::MAX)
+    };
+    verbs.sort_by(|a, b| {
+        position(a.category)
+            .cmp(&position(b.category))
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.namespace.cmp(&b.namespace))
+    });
+}
+
 /// Registry of all available verbs (built-in and custom).
 pub struct VerbRegistry {
     verbs: HashMap<String, Verb>,
@@ -326,31 +1027,179 @@ impl VerbRegistry {
         registry
     }
 
-    /// Register a verb in the registry.
+    /// Add the settings verbs that depend on the runner, like staging
+    /// feature toggles.
+    pub fn add_runner_settings(&mut self, capabilities: &RunnerCapabilities) {
+        register_staging_settings(self, capabilities);
+    }
+
+    /// Register a verb in the registry, replacing a verb of the same name in
+    /// the same namespace.
     pub fn register(&mut self, verb: Verb) {
-        self.verbs.insert(verb.name.clone(), verb);
+        self.verbs.insert(verb.qualified_name(), verb);
     }
 
-    /// Get a verb by name.
+    /// Get a verb by qualified or unambiguous name.
     pub fn get(&self, name: &str) -> Option<&Verb> {
-        self.verbs.get(name)
+        self.resolve(name).ok()
+    }
+
+    /// Look up a verb by qualified or unqualified name, explaining why the
+    /// lookup failed.
+    ///
+    /// ```
+    /// use protontool::wine::{Verb, VerbCategory, VerbRegistry};
+    /// let mut registry = VerbRegistry::new();
+    /// for namespace in ["user", "community"] {
+    ///     let verb = Verb::new("mygame-fix", VerbCategory::Custom, "Fix", "", "");
+    ///     registry.register(verb.in_namespace(namespace));
+    /// }
+    /// assert!(registry.resolve("mygame-fix").is_err());
+    /// assert_eq!(registry.resolve("user/mygame-fix").unwrap().namespace, "user");
+    /// assert_eq!(registry.resolve("vcrun2022").unwrap().namespace, "builtin");
+    /// ```
+    pub fn resolve(&self, name: &str) -> Result<&Verb, String> {
+        self.resolve_in(name, None)
+    }
+
+    /// Look up a verb, preferring `namespace` for unqualified names. Verbs
+    /// calling other verbs resolve them this way, so a pack's verbs find
+    /// the pack's own dependencies first.
+    fn resolve_in(&self, name: &str, namespace: Option<&str>) -> Result<&Verb, String> {
+        if name.contains('/') {
+            return self
+                .verbs
+                .get(name)
+                .ok_or_else(|| format!("Unknown verb: {}", name));
+        }
+        let candidates: Vec<&Verb> = self.verbs.values().filter(|v| v.name == name).collect();
+        if let Some(verb) = candidates
+            .iter()
+            .find(|v| Some(v.namespace.as_str()) == namespace)
+        {
+            return Ok(verb);
+        }
+        match candidates.as_slice() {
+            [] => Err(format!("Unknown verb: {}", name)),
+            [verb] => Ok(verb),
+            _ => {
+                let mut names: Vec<String> =
+                    candidates.iter().map(|v| v.qualified_name()).collect();
+                names.sort();
+                Err(format!(
+                    "Verb '{}' is defined in several namespaces; use one of: {}",
+                    name,
+                    names.join(", ")
+                ))
+            }
+        }
+    }
+
+    /// The shortest name that refers to a verb: its plain name, or its
+    /// qualified name when other namespaces define the same name.
+    pub fn reference(&self, verb: &Verb) -> String {
+        if self.verbs.values().filter(|v| v.name == verb.name).count() > 1 {
+            verb.qualified_name()
+        } else {
+            verb.name.clone()
+        }
     }
 
-    /// List all verbs, optionally filtered by category.
+    /// List all verbs, optionally filtered by category, sorted by category
+    /// and then by name.
     pub fn list(&self, category: Option<VerbCategory>) -> Vec<&Verb> {
-        match category {
+        let mut verbs: Vec<&Verb> = match category {
             Some(cat) => self.verbs.values().filter(|v| v.category == cat).collect(),
             None => self.verbs.values().collect(),
-        }
+        };
+        sort_verbs(&mut verbs);
+        verbs
     }
 
-    /// Search verbs by name or title.
+    /// Search verbs by name or title, in `list` order.
     pub fn search(&self, query: &str) -> Vec<&Verb> {
         let q = query.to_lowercase();
-        self.verbs
+        let mut verbs: Vec<&Verb> = self
+            .verbs
             .values()
             .filter(|v| v.name.to_lowercase().contains(&q) || v.title.to_lowercase().contains(&q))
-            .collect()
+            .collect();
+        sort_verbs(&mut verbs);
+        verbs
+    }
+
+    /// Registry values a settings verb (and settings verbs it calls) would
+    /// change in the prefix, read from the registry files before anything
+    /// runs. Empty for other verbs.
+    pub fn preview_settings(
+        &self,
+        name: &str,
+        wine_ctx: &WineContext,
+    ) -> Vec<super::registry::RegistryChange> {
+        let mut content = String::new();
+        self.settings_reg_content(name, wine_ctx, &mut content);
+        super::registry::preview_reg_content(&wine_ctx.prefix_path, &content)
+    }
+
+    /// Collect the .reg content a settings verb applies.
+    fn settings_reg_content(&self, name: &str, wine_ctx: &WineContext, content: &mut String) {
+        let verb = match self.get(name) {
+            Some(v) if v.category == VerbCategory::Setting => v,
+            _ => return,
+        };
+        for action in &verb.actions {
+            match action {
+                VerbAction::Registry { content: reg } => {
+                    content.push_str(&super::template::expand_for_prefix(reg, wine_ctx));
+                    content.push('\n');
+                }
+                VerbAction::Override { dll, mode } => {
+                    content.push_str(&format!(
+                        "[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n\"{}\"=\"{}\"\n",
+                        dll,
+                        mode.as_str()
+                    ));
+                }
+                VerbAction::CallVerb { name: dep_name } => {
+                    if let Ok(dep) = self.resolve_in(dep_name, Some(&verb.namespace)) {
+                        self.settings_reg_content(&dep.qualified_name(), wine_ctx, content);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Verbs (the named one and its dependencies) whose license still has
+    /// to be accepted, one per distinct EULA.
+    pub fn pending_licenses(&self, name: &str) -> Vec<&Verb> {
+        let mut pending: Vec<&Verb> = Vec::new();
+        let mut stack = vec![name.to_string()];
+        let mut seen: Vec<String> = Vec::new();
+
+        while let Some(current) = stack.pop() {
+            if seen.contains(&current) {
+                continue;
+            }
+            seen.push(current.clone());
+            let verb = match self.get(&current) {
+                Some(v) => v,
+                None => continue,
+            };
+            if super::license::needs_acceptance(verb)
+                && !pending.iter().any(|p| p.eula == verb.eula)
+            {
+                pending.push(verb);
+            }
+            for action in &verb.actions {
+                if let VerbAction::CallVerb { name: dep_name } = action {
+                    if let Ok(dep) = self.resolve_in(dep_name, Some(&verb.namespace)) {
+                        stack.push(dep.qualified_name());
+                    }
+                }
+            }
+        }
+        pending
     }
 
     /// Execute a verb by name, resolving CallVerb dependencies first.
@@ -360,15 +1209,77 @@ impl VerbRegistry {
         wine_ctx: &WineContext,
         cache_dir: &Path,
     ) -> Result<(), String> {
-        let verb = self
-            .get(name)
-            .ok_or_else(|| format!("Unknown verb: {}", name))?;
+        let verb = self.resolve(name)?;
+        if super::sandbox::is_sandboxed(&wine_ctx.prefix_path) {
+            for warning in super::sandbox::verb_warnings(verb) {
+                eprintln!("Warning: {}", warning);
+            }
+        }
+        if super::license::needs_acceptance(verb) {
+            return Err(format!(
+                "The license for {} must be accepted before installing it",
+                verb.title
+            ));
+        }
         for action in &verb.actions {
             if let VerbAction::CallVerb { name: dep_name } = action {
-                self.execute(dep_name, wine_ctx, cache_dir)?;
+                let dep = self.resolve_in(dep_name, Some(&verb.namespace))?;
+                self.execute(&self.reference(dep), wine_ctx, cache_dir)?;
             }
         }
-        verb.execute(wine_ctx, cache_dir)
+        // Settings verbs and DLL overrides are journaled so `protontool undo`
+        // can restore the values they replace
+        let journal_changes: Vec<crate::journal::JournalChange> = verb
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                VerbAction::Registry { content } if verb.category == VerbCategory::Setting => {
+                    Some(super::template::expand_for_prefix(content, wine_ctx))
+                }
+                VerbAction::Override { dll, mode } => {
+                    Some(dll_overrides_content(&[(dll.as_str(), mode.as_str())]))
+                }
+                _ => None,
+            })
+            .flat_map(|content| crate::journal::registry_changes(wine_ctx, &content))
+            .collect();
+
+        let programs_before = verb
+            .runs_installer()
+            .then(|| uninstall::installed_programs(&wine_ctx.prefix_path));
+
+        verb.execute(wine_ctx, cache_dir)?;
+
+        let installed: Vec<String> = match &programs_before {
+            Some(before) => {
+                let after = uninstall::installed_programs(&wine_ctx.prefix_path);
+                let new = uninstall::new_programs(before, &after);
+                if new.is_empty() && verb.installed.is_none() {
+                    crate::log::warn(&format!(
+                        "Verb '{}' ran its installer, but no new program appeared under the \
+                         Uninstall registry keys; it was already installed or didn't install",
+                        name
+                    ));
+                }
+                new.into_iter().map(|program| program.name).collect()
+            }
+            None => Vec::new(),
+        };
+
+        let description = format!("{} in {}", name, wine_ctx.prefix_path.display());
+        if let Err(e) = crate::journal::record(&description, journal_changes) {
+            crate::log::warn(&format!(
+                "Failed to record verb '{}' in journal: {}",
+                name, e
+            ));
+        }
+        if let Err(e) = super::ledger::record_verb(&wine_ctx.prefix_path, name, &installed) {
+            crate::log::warn(&format!(
+                "Failed to record verb '{}' in ledger: {}",
+                name, e
+            ));
+        }
+        Ok(())
     }
 }
 
@@ -384,67 +1295,21 @@ impl Default for VerbRegistry {
 
 /// Register built-in settings verbs (Windows version, graphics, sound, etc.).
 fn register_settings(registry: &mut VerbRegistry) {
-    // Windows versions (Win7+)
-    registry.register(
-        Verb::new(
-            "win7",
-            VerbCategory::Setting,
-            "Set Windows version to Windows 7",
-            "Microsoft",
-            "2009",
-        )
-        .with_actions(vec![VerbAction::Winecfg {
-            args: vec!["-v".into(), "win7".into()],
-        }]),
-    );
-    registry.register(
-        Verb::new(
-            "win8",
-            VerbCategory::Setting,
-            "Set Windows version to Windows 8",
-            "Microsoft",
-            "2012",
-        )
-        .with_actions(vec![VerbAction::Winecfg {
-            args: vec!["-v".into(), "win8".into()],
-        }]),
-    );
-    registry.register(
-        Verb::new(
-            "win81",
-            VerbCategory::Setting,
-            "Set Windows version to Windows 8.1",
-            "Microsoft",
-            "2013",
-        )
-        .with_actions(vec![VerbAction::Winecfg {
-            args: vec!["-v".into(), "win81".into()],
-        }]),
-    );
-    registry.register(
-        Verb::new(
-            "win10",
-            VerbCategory::Setting,
-            "Set Windows version to Windows 10",
-            "Microsoft",
-            "2015",
-        )
-        .with_actions(vec![VerbAction::Winecfg {
-            args: vec!["-v".into(), "win10".into()],
-        }]),
-    );
-    registry.register(
-        Verb::new(
-            "win11",
-            VerbCategory::Setting,
-            "Set Windows version to Windows 11",
-            "Microsoft",
-            "2021",
-        )
-        .with_actions(vec![VerbAction::Winecfg {
-            args: vec!["-v".into(), "win11".into()],
-        }]),
-    );
+    // Windows versions, from the winver table shared with the GUI picker
+    for version in super::registry::WINDOWS_VERSIONS {
+        registry.register(
+            Verb::new(
+                version.name,
+                VerbCategory::Setting,
+                &format!("Set Windows version to {}", version.description),
+                "Microsoft",
+                version.year,
+            )
+            .with_actions(vec![VerbAction::Registry {
+                content: version.reg_content(),
+            }]),
+        );
+    }
 
     // Graphics
     registry.register(Verb::new("graphics=x11", VerbCategory::Setting, "Set graphics driver to X11", "Wine", "")
@@ -481,6 +1346,8 @@ fn register_settings(registry: &mut VerbRegistry) {
         registry.register(Verb::new(name, VerbCategory::Setting, &format!("Enable virtual desktop {}", size), "Wine", "")
             .with_actions(vec![VerbAction::Registry { content: format!("Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\Explorer]\n\"Desktop\"=\"Default\"\n[HKEY_CURRENT_USER\\Software\\Wine\\Explorer\\Desktops]\n\"Default\"=\"{}\"\n", size) }]));
     }
+    registry.register(Verb::new("vd=screen", VerbCategory::Setting, "Enable virtual desktop at screen resolution", "Wine", "")
+        .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\Explorer]\n\"Desktop\"=\"Default\"\n[HKEY_CURRENT_USER\\Software\\Wine\\Explorer\\Desktops]\n\"Default\"=\"{{resolution}}\"\n".into() }]));
 
     // CSMT
     registry.register(Verb::new("csmt=on", VerbCategory::Setting, "Enable CSMT (default)", "Wine", "")
@@ -501,6 +1368,16 @@ fn register_settings(registry: &mut VerbRegistry) {
     // Other
     registry.register(Verb::new("nocrashdialog", VerbCategory::Setting, "Disable crash dialog", "Wine", "")
         .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\WineDbg]\n\"ShowCrashDialog\"=dword:00000000\n".into() }]));
+    registry.register(
+        Verb::new(
+            "remove_mono",
+            VerbCategory::Setting,
+            "Remove Wine Mono (builtin .NET replacement)",
+            "Wine",
+            "",
+        )
+        .with_actions(vec![VerbAction::Custom(super::mono::remove_mono_action)]),
+    );
     registry.register(Verb::new("mimeassoc=off", VerbCategory::Setting, "Disable MIME associations", "Wine", "")
         .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\FileOpenAssociations]\n\"Enable\"=\"N\"\n".into() }]));
     registry.register(Verb::new("mimeassoc=on", VerbCategory::Setting, "Enable MIME associations", "Wine", "")
@@ -522,6 +1399,77 @@ fn register_settings(registry: &mut VerbRegistry) {
             .with_actions(vec![VerbAction::Registry { content: format!("Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\Direct3D]\n\"VideoMemorySize\"=\"{}\"\n", size) }]));
     }
 
+    // Legacy (16-bit and 90s-era) applications
+    registry.register(Verb::new("ddr=gdi", VerbCategory::Setting, "Set DirectDraw renderer to GDI", "Wine", "")
+        .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\Direct3D]\n\"DirectDrawRenderer\"=\"gdi\"\n".into() }]));
+    registry.register(Verb::new("ddr=opengl", VerbCategory::Setting, "Set DirectDraw renderer to OpenGL", "Wine", "")
+        .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\Direct3D]\n\"DirectDrawRenderer\"=\"opengl\"\n".into() }]));
+    // 256-color games switch to 8-bit modes Wine only emulates reliably in a
+    // desktop window with the GDI DirectDraw renderer
+    for size in ["640x480", "800x600"] {
+        registry.register(
+            Verb::new(
+                &format!("legacy_desktop={}", size),
+                VerbCategory::Setting,
+                &format!("Virtual desktop {} for 256-color games", size),
+                "Wine",
+                "",
+            )
+            .with_actions(vec![
+                VerbAction::CallVerb {
+                    name: format!("vd={}", size),
+                },
+                VerbAction::CallVerb {
+                    name: "ddr=gdi".into(),
+                },
+            ]),
+        );
+    }
+    registry.register(Verb::new("winevdm=off", VerbCategory::Setting, "Disable winevdm (16-bit and DOS programs)", "Wine", "")
+        .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n\"winevdm.exe\"=\"\"\n".into() }]));
+    registry.register(Verb::new("winevdm=on", VerbCategory::Setting, "Enable winevdm (default)", "Wine", "")
+        .with_actions(vec![VerbAction::Registry { content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n\"winevdm.exe\"=-\n".into() }]));
+    let cpu_limits: [(&str, CustomAction); 4] = [
+        ("1", |wine_ctx, _, _| limit_cpu_cores(wine_ctx, Some(1))),
+        ("2", |wine_ctx, _, _| limit_cpu_cores(wine_ctx, Some(2))),
+        ("4", |wine_ctx, _, _| limit_cpu_cores(wine_ctx, Some(4))),
+        ("all", |wine_ctx, _, _| limit_cpu_cores(wine_ctx, None)),
+    ];
+    for (cores, action) in cpu_limits {
+        let title = match cores {
+            "all" => "Use all CPU cores (default)".to_string(),
+            "1" => "Limit to 1 CPU core".to_string(),
+            n => format!("Limit to {} CPU cores", n),
+        };
+        registry.register(
+            Verb::new(
+                &format!("cpu_cores={}", cores),
+                VerbCategory::Setting,
+                &title,
+                "Wine",
+                "",
+            )
+            .with_actions(vec![VerbAction::Custom(action)]),
+        );
+    }
+    registry.register(
+        Verb::new(
+            "legacy_apps",
+            VerbCategory::Setting,
+            "Common settings for 90s-era games (GDI DirectDraw, 800x600 desktop, 1 CPU core)",
+            "Wine",
+            "",
+        )
+        .with_actions(vec![
+            VerbAction::CallVerb {
+                name: "legacy_desktop=800x600".into(),
+            },
+            VerbAction::CallVerb {
+                name: "cpu_cores=1".into(),
+            },
+        ]),
+    );
+
     // Sandbox
     registry.register(
         Verb::new(
@@ -532,34 +1480,140 @@ fn register_settings(registry: &mut VerbRegistry) {
             "",
         )
         .with_actions(vec![VerbAction::Custom(|wine_ctx, _, _| {
-            let users = wine_ctx.prefix_path.join("drive_c/users");
-            if let Ok(entries) = std::fs::read_dir(&users) {
-                for entry in entries.flatten() {
-                    for subdir in [
-                        "My Documents",
-                        "Desktop",
-                        "Downloads",
-                        "My Music",
-                        "My Pictures",
-                        "My Videos",
-                    ] {
-                        let link = entry.path().join(subdir);
-                        if link.is_symlink() {
-                            std::fs::remove_file(&link).ok();
-                            std::fs::create_dir_all(&link).ok();
-                        }
-                    }
-                }
-            }
-            Ok(())
+            super::userdirs::set_policy(
+                &wine_ctx.prefix_path,
+                &super::userdirs::UserDirPolicy::none(),
+            )
+            .map(|_| ())
         })]),
     );
 }
 
+/// Settings verbs for 16-bit and 90s-era applications, offered together
+/// in the GUI's legacy apps settings group.
+pub const LEGACY_APP_VERBS: &[&str] = &[
+    "legacy_apps",
+    "ddr=gdi",
+    "ddr=opengl",
+    "legacy_desktop=640x480",
+    "legacy_desktop=800x600",
+    "winevdm=off",
+    "winevdm=on",
+    "cpu_cores=1",
+    "cpu_cores=2",
+    "cpu_cores=4",
+    "cpu_cores=all",
+];
+
+/// WINE_CPU_TOPOLOGY value exposing the first `cores` CPUs, e.g. `2:0,1`.
+///
+/// ```
+/// assert_eq!(protontool::wine::verbs::cpu_topology(2), "2:0,1");
+/// ```
+pub fn cpu_topology(cores: usize) -> String {
+    let cpus: Vec<String> = (0..cores).map(|cpu| cpu.to_string()).collect();
+    format!("{}:{}", cores, cpus.join(","))
+}
+
+/// Limit the CPU cores Wine reports to applications through the prefix's
+/// environment profile (None removes the limit). Old games often misbehave
+/// on many-core systems.
+fn limit_cpu_cores(wine_ctx: &WineContext, cores: Option<usize>) -> Result<(), String> {
+    let value = cores.map(cpu_topology);
+    crate::journal::set_profile_var(&wine_ctx.prefix_path, "WINE_CPU_TOPOLOGY", value.as_deref())
+}
+
+/// Register `NAME=on` / `NAME=off` settings verbs for the staging features
+/// a runner supports. Plain Proton runners get none.
+fn register_staging_settings(registry: &mut VerbRegistry, capabilities: &RunnerCapabilities) {
+    for feature in STAGING_FEATURES {
+        if !capabilities.supports(feature.name) {
+            continue;
+        }
+        let (on, off) = match feature.name {
+            "eax" => (
+                VerbAction::Registry {
+                    content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\DirectSound]\n\"EAXEnabled\"=\"Y\"\n".into(),
+                },
+                VerbAction::Registry {
+                    content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\DirectSound]\n\"EAXEnabled\"=\"N\"\n".into(),
+                },
+            ),
+            "large_address_aware" => (
+                VerbAction::Custom(|wine_ctx, _, _| {
+                    set_staging_var(wine_ctx, "WINE_LARGE_ADDRESS_AWARE", true)
+                }),
+                VerbAction::Custom(|wine_ctx, _, _| {
+                    set_staging_var(wine_ctx, "WINE_LARGE_ADDRESS_AWARE", false)
+                }),
+            ),
+            "staging_sharedmem" => (
+                VerbAction::Custom(|wine_ctx, _, _| {
+                    set_staging_var(wine_ctx, "STAGING_SHARED_MEMORY", true)
+                }),
+                VerbAction::Custom(|wine_ctx, _, _| {
+                    set_staging_var(wine_ctx, "STAGING_SHARED_MEMORY", false)
+                }),
+            ),
+            "staging_writecopy" => (
+                VerbAction::Custom(|wine_ctx, _, _| {
+                    set_staging_var(wine_ctx, "STAGING_WRITECOPY", true)
+                }),
+                VerbAction::Custom(|wine_ctx, _, _| {
+                    set_staging_var(wine_ctx, "STAGING_WRITECOPY", false)
+                }),
+            ),
+            _ => continue,
+        };
+        for (state, action) in [("on", on), ("off", off)] {
+            let title = format!(
+                "{} {} ({})",
+                if state == "on" { "Enable" } else { "Disable" },
+                feature.description,
+                capabilities.flavor.label()
+            );
+            registry.register(
+                Verb::new(
+                    &format!("{}={}", feature.name, state),
+                    VerbCategory::Setting,
+                    &title,
+                    "Wine-staging",
+                    "",
+                )
+                .with_actions(vec![action]),
+            );
+        }
+    }
+}
+
+/// Turn a staging environment toggle on (`1`) or off through the prefix's
+/// environment profile.
+fn set_staging_var(wine_ctx: &WineContext, name: &str, enabled: bool) -> Result<(), String> {
+    crate::journal::set_profile_var(&wine_ctx.prefix_path, name, enabled.then_some("1"))
+}
+
 // ============================================================================
 // FONT VERBS
 // ============================================================================
 
+/// License agreement of the Microsoft core fonts for the web.
+const COREFONTS_EULA: &str = "https://corefonts.sourceforge.net/eula.htm";
+
+/// Verbs installing the Microsoft core fonts.
+const COREFONTS_VERBS: &[&str] = &[
+    "corefonts",
+    "andale",
+    "arial",
+    "comicsans",
+    "courier",
+    "georgia",
+    "impact",
+    "times",
+    "trebuchet",
+    "verdana",
+    "webdings",
+];
+
 /// Register built-in font verbs (corefonts, tahoma, etc.).
 fn register_fonts(registry: &mut VerbRegistry) {
     registry.register(
@@ -885,12 +1939,101 @@ fn register_fonts(registry: &mut VerbRegistry) {
             },
         ]),
     );
+
+    for name in COREFONTS_VERBS {
+        if let Some(verb) = registry.verbs.get_mut(*name) {
+            verb.homepage = "https://corefonts.sourceforge.net/".to_string();
+            verb.license = "Microsoft core fonts EULA".to_string();
+            verb.eula = Some(COREFONTS_EULA.to_string());
+        }
+    }
 }
 
 // ============================================================================
 // DLL VERBS
 // ============================================================================
 
+/// DXVK version installed by each built-in DXVK verb.
+pub const DXVK_VERB_VERSIONS: &[(&str, &str)] = &[
+    ("dxvk", "2.5.3"),
+    ("dxvk2060", "2.6"),
+    ("dxvk2050", "2.5"),
+    ("dxvk2040", "2.4"),
+];
+
+impl Verb {
+    /// Wrap a .NET Framework installer with the steps it needs under Wine:
+    /// remove Wine Mono, run the installer with the given winver and make
+    /// the installed mscoree native. The prefix goes back to its previous
+    /// winver afterwards, also when the installer fails.
+    fn with_dotnet_framework(self, winver: &str, installer: VerbAction) -> Self {
+        self.with_actions(dotnet_framework_actions(winver, installer))
+            .with_cleanup(vec![VerbAction::Custom(restore_saved_winver)])
+    }
+}
+
+/// The actions of [`Verb::with_dotnet_framework`].
+fn dotnet_framework_actions(winver: &str, installer: VerbAction) -> Vec<VerbAction> {
+    vec![
+        VerbAction::Custom(super::mono::remove_mono_action),
+        VerbAction::Custom(save_winver),
+        VerbAction::Winecfg {
+            args: vec!["-v".into(), winver.into()],
+        },
+        installer,
+        VerbAction::Registry {
+            content: "Windows Registry Editor Version 5.00\n\n[HKEY_CURRENT_USER\\Software\\Wine\\DllOverrides]\n\"mscoree\"=\"native\"\n".into(),
+        },
+    ]
+}
+
+/// Key holding the prefix-wide winver (`Version`).
+const WINVER_KEY: &str = r"HKEY_CURRENT_USER\Software\Wine";
+
+/// File in a verb's temp directory holding the winver saved by
+/// [`save_winver`]; empty when none was set.
+const SAVED_WINVER_FILE: &str = "winver.saved";
+
+/// The prefix-wide winver in .reg syntax, None if Wine's default is used.
+fn current_winver(wine_ctx: &WineContext) -> Option<String> {
+    super::registry::read_prefix_value(&wine_ctx.prefix_path, WINVER_KEY, "Version")
+}
+
+/// Put back a winver read with [`current_winver`].
+fn set_winver(wine_ctx: &WineContext, winver: Option<&str>) -> Result<(), String> {
+    let editor = super::registry::RegistryEditor::new(wine_ctx);
+    match winver {
+        Some(data) => editor.set_raw_value(WINVER_KEY, "Version", data),
+        None => editor.delete_value(WINVER_KEY, "Version"),
+    }
+}
+
+/// Remember the prefix-wide winver before a step changes it.
+fn save_winver(
+    wine_ctx: &WineContext,
+    _downloader: &Downloader,
+    tmp_dir: &Path,
+) -> Result<(), String> {
+    let saved = current_winver(wine_ctx).unwrap_or_default();
+    std::fs::write(tmp_dir.join(SAVED_WINVER_FILE), saved)
+        .map_err(|e| format!("Failed to save the winver: {}", e))
+}
+
+/// Go back to the winver saved by [`save_winver`], if it ran.
+fn restore_saved_winver(
+    wine_ctx: &WineContext,
+    _downloader: &Downloader,
+    tmp_dir: &Path,
+) -> Result<(), String> {
+    let path = tmp_dir.join(SAVED_WINVER_FILE);
+    if !path.exists() {
+        return Ok(());
+    }
+    let saved = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read the saved winver: {}", e))?;
+    set_winver(wine_ctx, Some(saved.as_str()).filter(|s| !s.is_empty()))
+}
+
 /// Register built-in DLL verbs (vcrun, dotnet, dxvk, etc.).
 fn register_dlls(registry: &mut VerbRegistry) {
     // Visual C++ Runtimes
@@ -919,7 +2062,9 @@ fn register_dlls(registry: &mut VerbRegistry) {
                 ),
                 args: vec!["/install".into(), "/quiet".into(), "/norestart".into()],
             },
-        ]),
+        ])
+        // 1638: a newer version of the runtime is already installed
+        .with_success_codes(&[1638]),
     );
     registry.register(
         Verb::new(
@@ -960,20 +2105,20 @@ fn register_dlls(registry: &mut VerbRegistry) {
 
     // .NET Framework
     registry.register(Verb::new("dotnet48", VerbCategory::Dll, "MS .NET 4.8", "Microsoft", "2019")
-        .with_actions(vec![VerbAction::RunInstaller {
+        .with_dotnet_framework("win7", VerbAction::RunInstaller {
             file: DownloadFile::new("https://download.visualstudio.microsoft.com/download/pr/2d6bb6b2-226a-4baa-bdec-798822606ff1/8494001c276a4b96804cde7829c04d7f/ndp48-x86-x64-allos-enu.exe", "ndp48-x86-x64-allos-enu.exe", Some("68c9986a8dcc0214d909aa1f31bee9fb5461bb839edca996a75b08ddffc1483f")),
             args: vec!["/q".into(), "/norestart".into()],
-        }]));
+        }));
     registry.register(Verb::new("dotnet472", VerbCategory::Dll, "MS .NET 4.7.2", "Microsoft", "2018")
-        .with_actions(vec![VerbAction::RunInstaller {
+        .with_dotnet_framework("win7", VerbAction::RunInstaller {
             file: DownloadFile::new("https://download.microsoft.com/download/6/E/4/6E48E8AB-DC00-419E-9704-06DD46E5F81D/NDP472-KB4054530-x86-x64-AllOS-ENU.exe", "NDP472-KB4054530-x86-x64-AllOS-ENU.exe", Some("c908f0a5bea4be282e35acba307d0061b71b8b66ca9894943d3cbb53cad019bc")),
             args: vec!["/q".into(), "/norestart".into()],
-        }]));
+        }));
     registry.register(Verb::new("dotnet40", VerbCategory::Dll, "MS .NET 4.0", "Microsoft", "2011")
-        .with_actions(vec![VerbAction::RunInstaller {
+        .with_dotnet_framework("win7", VerbAction::RunInstaller {
             file: DownloadFile::new("https://download.microsoft.com/download/9/5/A/95A9616B-7A37-4AF6-BC36-D6EA96C8DAAE/dotNetFx40_Full_x86_x64.exe", "dotNetFx40_Full_x86_x64.exe", Some("65e064258f2e418816b304f646ff9e87af101e4c9552ab064bb74d281c38659f")),
             args: vec!["/q".into(), "/norestart".into()],
-        }]));
+        }));
 
     // DXVK
     registry.register(
@@ -991,27 +2136,26 @@ fn register_dlls(registry: &mut VerbRegistry) {
                 None,
             )?;
             crate::wine::util::extract_archive(&file, tmp_dir)?;
-            let dxvk = tmp_dir.join("dxvk-2.5.3");
-            let sys32 = wine_ctx.prefix_path.join("drive_c/windows/system32");
-            let syswow = wine_ctx.prefix_path.join("drive_c/windows/syswow64");
-            for dll in ["d3d9.dll", "d3d10core.dll", "d3d11.dll", "dxgi.dll"] {
-                if syswow.exists() {
-                    std::fs::copy(dxvk.join("x32").join(dll), syswow.join(dll)).ok();
-                    std::fs::copy(dxvk.join("x64").join(dll), sys32.join(dll)).ok();
-                } else {
-                    std::fs::copy(dxvk.join("x32").join(dll), sys32.join(dll)).ok();
-                }
-            }
-            let mut ctx = wine_ctx.clone();
-            for dll in ["d3d9", "d3d10core", "d3d11", "dxgi"] {
-                ctx.set_dll_override(dll, "native");
-            }
-            Ok(())
+            super::graphics::install_dlls(
+                wine_ctx,
+                "dxvk",
+                "2.5.3",
+                &tmp_dir.join("dxvk-2.5.3"),
+                ("x32", "x64"),
+                &["d3d9.dll", "d3d10core.dll", "d3d11.dll", "dxgi.dll"],
+            )?;
+            let overrides: Vec<(&str, &str)> = ["d3d9", "d3d10core", "d3d11", "dxgi"]
+                .iter()
+                .map(|dll| (*dll, "native"))
+                .collect();
+            persist_dll_overrides(wine_ctx, tmp_dir, &overrides)
         })]),
     );
 
     // PhysX
     registry.register(Verb::new("physx", VerbCategory::Dll, "PhysX", "Nvidia", "2021")
+        .with_homepage("https://www.nvidia.com/en-us/drivers/physx/physx-9-21-0713-driver/")
+        .with_license("NVIDIA Software License Agreement", Some("https://www.nvidia.com/en-us/drivers/nvidia-license/"))
         .with_actions(vec![VerbAction::RunInstaller {
             file: DownloadFile::new("https://us.download.nvidia.com/Windows/9.21.0713/PhysX-9.21.0713-SystemSoftware.exe", "PhysX-9.21.0713-SystemSoftware.exe", None),
             args: vec!["/s".into()],
@@ -1048,17 +2192,20 @@ fn register_dlls(registry: &mut VerbRegistry) {
         .with_actions(vec![
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/2/E/6/2E61CFA4-993B-4DD4-91DA-3737CD5CD6E3/vcredist_x86.exe", "vcredist_2013_x86.exe", None), args: vec!["/install".into(), "/quiet".into(), "/norestart".into()] },
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/2/E/6/2E61CFA4-993B-4DD4-91DA-3737CD5CD6E3/vcredist_x64.exe", "vcredist_2013_x64.exe", None), args: vec!["/install".into(), "/quiet".into(), "/norestart".into()] },
-        ]));
+        ])
+        .with_success_codes(&[1638]));
     registry.register(Verb::new("vcrun2012", VerbCategory::Dll, "Visual C++ 2012 Runtime", "Microsoft", "2012")
         .with_actions(vec![
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/1/6/B/16B06F60-3B20-4FF2-B699-5E9B7962F9AE/VSU_4/vcredist_x86.exe", "vcredist_2012_x86.exe", None), args: vec!["/install".into(), "/quiet".into(), "/norestart".into()] },
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/1/6/B/16B06F60-3B20-4FF2-B699-5E9B7962F9AE/VSU_4/vcredist_x64.exe", "vcredist_2012_x64.exe", None), args: vec!["/install".into(), "/quiet".into(), "/norestart".into()] },
-        ]));
+        ])
+        .with_success_codes(&[1638]));
     registry.register(Verb::new("vcrun2010", VerbCategory::Dll, "Visual C++ 2010 Runtime", "Microsoft", "2010")
         .with_actions(vec![
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/1/6/5/165255E7-1014-4D0A-B094-B6A430A6BFFC/vcredist_x86.exe", "vcredist_2010_x86.exe", None), args: vec!["/q".into(), "/norestart".into()] },
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/1/6/5/165255E7-1014-4D0A-B094-B6A430A6BFFC/vcredist_x64.exe", "vcredist_2010_x64.exe", None), args: vec!["/q".into(), "/norestart".into()] },
-        ]));
+        ])
+        .with_success_codes(&[1638]));
     registry.register(Verb::new("vcrun2008", VerbCategory::Dll, "Visual C++ 2008 Runtime", "Microsoft", "2008")
         .with_actions(vec![
             VerbAction::RunInstaller { file: DownloadFile::new("https://download.microsoft.com/download/5/D/8/5D8C65CB-C849-4025-8E95-C3966CAFD8AE/vcredist_x86.exe", "vcredist_2008_x86.exe", None), args: vec!["/q".into()] },
@@ -1072,20 +2219,20 @@ fn register_dlls(registry: &mut VerbRegistry) {
 
     // More .NET versions
     registry.register(Verb::new("dotnet46", VerbCategory::Dll, "MS .NET 4.6", "Microsoft", "2015")
-        .with_actions(vec![VerbAction::RunInstaller {
+        .with_dotnet_framework("win7", VerbAction::RunInstaller {
             file: DownloadFile::new("https://download.microsoft.com/download/6/F/9/6F9673B1-87D1-46C4-BF04-95F24C3EB9DA/enu_netfx/NDP46-KB3045557-x86-x64-AllOS-ENU_exe/NDP46-KB3045557-x86-x64-AllOS-ENU.exe", "NDP46-KB3045557-x86-x64-AllOS-ENU.exe", None),
             args: vec!["/q".into(), "/norestart".into()],
-        }]));
+        }));
     registry.register(Verb::new("dotnet462", VerbCategory::Dll, "MS .NET 4.6.2", "Microsoft", "2016")
-        .with_actions(vec![VerbAction::RunInstaller {
+        .with_dotnet_framework("win7", VerbAction::RunInstaller {
             file: DownloadFile::new("https://download.visualstudio.microsoft.com/download/pr/8e396c75-4d0d-41d3-aea8-848babc2736a/80b431456d8866ebe053eb8b81a168b3/ndp462-kb3151800-x86-x64-allos-enu.exe", "NDP462-KB3151800-x86-x64-AllOS-ENU.exe", None),
             args: vec!["/q".into(), "/norestart".into()],
-        }]));
+        }));
     registry.register(Verb::new("dotnet35sp1", VerbCategory::Dll, "MS .NET 3.5 SP1", "Microsoft", "2008")
-        .with_actions(vec![VerbAction::RunInstaller {
+        .with_dotnet_framework("winxp", VerbAction::RunInstaller {
             file: DownloadFile::new("https://download.microsoft.com/download/0/6/1/061F001C-8752-4600-A198-53214C69B51F/dotnetfx35setup.exe", "dotnetfx35setup.exe", None),
             args: vec!["/q".into()],
-        }]));
+        }));
 
     // .NET Core / .NET 6+
     registry.register(Verb::new("dotnet6", VerbCategory::Dll, "MS .NET Runtime 6.0", "Microsoft", "2023")
@@ -1114,18 +2261,7 @@ fn register_dlls(registry: &mut VerbRegistry) {
         .with_actions(vec![VerbAction::Custom(|wine_ctx, downloader, tmp_dir| {
             let file = downloader.download("https://github.com/HansKristian-Work/vkd3d-proton/releases/download/v2.13/vkd3d-proton-2.13.tar.zst", "vkd3d-proton-2.13.tar.zst", None)?;
             crate::wine::util::extract_archive(&file, tmp_dir)?;
-            let vkd3d = tmp_dir.join("vkd3d-proton-2.13");
-            let sys32 = wine_ctx.prefix_path.join("drive_c/windows/system32");
-            let syswow = wine_ctx.prefix_path.join("drive_c/windows/syswow64");
-            for dll in ["d3d12.dll", "d3d12core.dll"] {
-                if syswow.exists() {
-                    std::fs::copy(vkd3d.join("x86").join(dll), syswow.join(dll)).ok();
-                    std::fs::copy(vkd3d.join("x64").join(dll), sys32.join(dll)).ok();
-                } else {
-                    std::fs::copy(vkd3d.join("x86").join(dll), sys32.join(dll)).ok();
-                }
-            }
-            Ok(())
+            super::graphics::install_dlls(wine_ctx, "vkd3d", "2.13", &tmp_dir.join("vkd3d-proton-2.13"), ("x86", "x64"), &["d3d12.dll", "d3d12core.dll"])
         })]));
 
     // FAudio
@@ -1254,7 +2390,7 @@ fn register_dlls(registry: &mut VerbRegistry) {
             "Microsoft",
             "2011",
         )
-        .with_actions(vec![VerbAction::Custom(|wine_ctx, _, _| {
+        .with_actions(vec![VerbAction::Custom(|wine_ctx, _, tmp_dir| {
             // Enable Media Foundation DLLs via registry
             let reg_content = r#"Windows Registry Editor Version 5.00
 
@@ -1262,7 +2398,7 @@ fn register_dlls(registry: &mut VerbRegistry) {
 
 [HKEY_LOCAL_MACHINE\Software\Microsoft\Windows Media Foundation\HardwareMFT]
 "#;
-            let reg_file = wine_ctx.prefix_path.join("drive_c/mf.reg");
+            let reg_file = unique_file(tmp_dir, "mf", "reg");
             std::fs::write(&reg_file, reg_content).ok();
             wine_ctx.run_regedit(&reg_file).ok();
             std::fs::remove_file(&reg_file).ok();
@@ -1279,11 +2415,9 @@ fn register_dlls(registry: &mut VerbRegistry) {
             "Microsoft",
             "2011",
         )
-        .with_actions(vec![VerbAction::Custom(|wine_ctx, _, _| {
+        .with_actions(vec![VerbAction::Custom(|wine_ctx, _, tmp_dir| {
             // Just set native override - Wine has a builtin
-            let mut ctx = wine_ctx.clone();
-            ctx.set_dll_override("quartz", "native,builtin");
-            Ok(())
+            persist_dll_overrides(wine_ctx, tmp_dir, &[("quartz", "native,builtin")])
         })]),
     );
 
@@ -1306,24 +2440,21 @@ fn register_dlls(registry: &mut VerbRegistry) {
         wine_ctx: &crate::wine::WineContext,
         downloader: &crate::wine::download::Downloader,
         tmp_dir: &std::path::Path,
+        verb: &str,
         version: &str,
         url: &str,
     ) -> Result<(), String> {
         let filename = format!("dxvk-{}.tar.gz", version);
         let file = downloader.download(url, &filename, None)?;
         crate::wine::util::extract_archive(&file, tmp_dir)?;
-        let dxvk = tmp_dir.join(format!("dxvk-{}", version));
-        let sys32 = wine_ctx.prefix_path.join("drive_c/windows/system32");
-        let syswow = wine_ctx.prefix_path.join("drive_c/windows/syswow64");
-        for dll in ["d3d9.dll", "d3d10core.dll", "d3d11.dll", "dxgi.dll"] {
-            if syswow.exists() {
-                std::fs::copy(dxvk.join("x32").join(dll), syswow.join(dll)).ok();
-                std::fs::copy(dxvk.join("x64").join(dll), sys32.join(dll)).ok();
-            } else {
-                std::fs::copy(dxvk.join("x32").join(dll), sys32.join(dll)).ok();
-            }
-        }
-        Ok(())
+        super::graphics::install_dlls(
+            wine_ctx,
+            verb,
+            version,
+            &tmp_dir.join(format!("dxvk-{}", version)),
+            ("x32", "x64"),
+            &["d3d9.dll", "d3d10core.dll", "d3d11.dll", "dxgi.dll"],
+        )
     }
 
     registry.register(
@@ -1339,6 +2470,7 @@ fn register_dlls(registry: &mut VerbRegistry) {
                 wine_ctx,
                 downloader,
                 tmp_dir,
+                "dxvk2060",
                 "2.6",
                 "https://github.com/doitsujin/dxvk/releases/download/v2.6/dxvk-2.6.tar.gz",
             )
@@ -1357,6 +2489,7 @@ fn register_dlls(registry: &mut VerbRegistry) {
                 wine_ctx,
                 downloader,
                 tmp_dir,
+                "dxvk2050",
                 "2.5",
                 "https://github.com/doitsujin/dxvk/releases/download/v2.5/dxvk-2.5.tar.gz",
             )
@@ -1375,6 +2508,7 @@ fn register_dlls(registry: &mut VerbRegistry) {
                 wine_ctx,
                 downloader,
                 tmp_dir,
+                "dxvk2040",
                 "2.4",
                 "https://github.com/doitsujin/dxvk/releases/download/v2.4/dxvk-2.4.tar.gz",
             )
@@ -1387,6 +2521,54 @@ fn register_dlls(registry: &mut VerbRegistry) {
 // ============================================================================
 
 fn register_apps(registry: &mut VerbRegistry) {
+    /// Let MO2's nxmhandler.exe receive nxm:// links from the browser.
+    fn register_mo2_nxm_handler(
+        wine_ctx: &WineContext,
+        _downloader: &Downloader,
+        _tmp_dir: &Path,
+    ) -> Result<(), String> {
+        let handler = crate::desktop::NxmHandler {
+            prefix_path: wine_ctx.prefix_path.clone(),
+            proton_path: wine_ctx.proton_path.clone(),
+            arch: wine_ctx.arch.as_str().to_string(),
+            program: "C:\\Modding\\MO2\\nxmhandler.exe".to_string(),
+        };
+        // Mod Organizer still works without the handler; downloads are
+        // then added by hand
+        if let Err(e) = crate::desktop::register_nxm_handler(&handler) {
+            eprintln!("Warning: Failed to register the nxm:// handler: {}", e);
+        }
+        Ok(())
+    }
+
+    registry.register(
+        Verb::new(
+            "mo2",
+            VerbCategory::App,
+            "Mod Organizer 2",
+            "Mod Organizer 2 Team",
+            "2024",
+        )
+        .with_actions(vec![
+            VerbAction::CallVerb {
+                name: "vcrun2022".into(),
+            },
+            VerbAction::Extract {
+                file: DownloadFile::new(
+                    "https://github.com/ModOrganizer2/modorganizer/releases/download/v2.5.2/Mod.Organizer-2.5.2.7z",
+                    "Mod.Organizer-2.5.2.7z",
+                    None,
+                ),
+                dest: "drive_c/Modding/MO2".into(),
+            },
+            VerbAction::Custom(register_mo2_nxm_handler),
+        ])
+        .with_homepage("https://github.com/ModOrganizer2/modorganizer")
+        .with_license("GPL-3.0", None)
+        .with_install_marker(InstallMarker::File(
+            "C:\\Modding\\MO2\\ModOrganizer.exe".to_string(),
+        )),
+    );
     registry.register(
         Verb::new("7zip", VerbCategory::App, "7-Zip", "Igor Pavlov", "2024").with_actions(vec![
             VerbAction::RunInstaller {
@@ -1434,3 +2616,124 @@ fn register_apps(registry: &mut VerbRegistry) {
         ]),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_order_is_stable() {
+        let registry = |extra: &[&str]| {
+            let mut registry = VerbRegistry::new();
+            for namespace in extra {
+                let verb = Verb::new("a-fix", VerbCategory::Custom, "Fix", "", "");
+                registry.register(verb.in_namespace(namespace));
+            }
+            registry
+        };
+        let names = |registry: &VerbRegistry| -> Vec<String> {
+            registry
+                .list(None)
+                .iter()
+                .map(|v| v.qualified_name())
+                .collect()
+        };
+
+        // Registries are separate hash maps, so their iteration orders differ
+        let first = registry(&["user", "community"]);
+        let second = registry(&["community", "user"]);
+        assert_eq!(names(&first), names(&second));
+
+        let listed = first.list(None);
+        assert_eq!(listed[0].category, VerbCategory::App);
+        for pair in listed.windows(2) {
+            if pair[0].category == pair[1].category {
+                assert!(pair[0].name <= pair[1].name);
+            }
+        }
+        let custom: Vec<String> = first
+            .list(Some(VerbCategory::Custom))
+            .iter()
+            .filter(|v| v.name == "a-fix")
+            .map(|v| v.qualified_name())
+            .collect();
+        assert_eq!(custom, vec!["community/a-fix", "user/a-fix"]);
+    }
+
+    #[test]
+    fn test_skipped_step_is_remembered() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static RAN: AtomicUsize = AtomicUsize::new(0);
+
+        let prefix = std::env::temp_dir().join(format!("pt-steps-{}", std::process::id()));
+        std::fs::create_dir_all(&prefix).unwrap();
+        let proton = crate::steam::ProtonApp {
+            name: "Proton".into(),
+            appid: 0,
+            install_path: prefix.join("proton"),
+            is_proton_ready: true,
+        };
+        let wine_ctx = WineContext::from_proton(&proton, &prefix);
+        let verb = Verb::new("steps", VerbCategory::Custom, "Steps", "", "").with_actions(vec![
+            VerbAction::Custom(|_, _, _| Err("step failed".into())),
+            VerbAction::Custom(|_, _, _| {
+                RAN.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }),
+        ]);
+        let cache = prefix.join("cache");
+
+        set_action_failure_prompt(Some(|_| ActionResolution::Abort));
+        let error = verb.execute(&wine_ctx, &cache).unwrap_err();
+        assert!(error.starts_with(ABORTED_PREFIX));
+        assert_eq!(RAN.load(Ordering::SeqCst), 0);
+
+        set_action_failure_prompt(Some(|_| ActionResolution::Skip));
+        assert!(verb.execute(&wine_ctx, &cache).is_ok());
+        // Rerun without asking: the step is skipped as before
+        set_action_failure_prompt(None);
+        assert!(verb.execute(&wine_ctx, &cache).is_ok());
+        assert_eq!(RAN.load(Ordering::SeqCst), 2);
+        assert!(super::super::ledger::read_ledger(&prefix).is_empty());
+
+        std::fs::remove_dir_all(&prefix).ok();
+    }
+
+    #[test]
+    fn test_cleanup_runs_after_failed_installer() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static CLEANED: AtomicUsize = AtomicUsize::new(0);
+
+        let prefix = std::env::temp_dir().join(format!("pt-cleanup-{}", std::process::id()));
+        std::fs::create_dir_all(&prefix).unwrap();
+        let proton = crate::steam::ProtonApp {
+            name: "Proton".into(),
+            appid: 0,
+            install_path: prefix.join("proton"),
+            is_proton_ready: true,
+        };
+        let wine_ctx = WineContext::from_proton(&proton, &prefix);
+        let verb = Verb::new("failing", VerbCategory::Custom, "Failing", "", "")
+            .with_actions(vec![VerbAction::Custom(|_, _, _| {
+                Err("installer failed".into())
+            })])
+            .with_cleanup(vec![VerbAction::Custom(|_, _, _| {
+                CLEANED.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })]);
+
+        let error = verb.execute(&wine_ctx, &prefix.join("cache")).unwrap_err();
+        assert!(error.contains("installer failed"));
+        assert_eq!(CLEANED.load(Ordering::SeqCst), 1);
+
+        // The .NET verbs put the winver back this way, and there is nothing
+        // to put back when the installer failed before it was saved
+        let registry = VerbRegistry::new();
+        let dotnet = registry.get("dotnet48").unwrap();
+        assert!(matches!(dotnet.cleanup[..], [VerbAction::Custom(_)]));
+        let downloader = Downloader::new(&prefix.join("cache"));
+        assert!(restore_saved_winver(&wine_ctx, &downloader, &prefix).is_ok());
+
+        std::fs::remove_dir_all(&prefix).ok();
+    }
+}
@@ -0,0 +1,255 @@
+//! VKD3D-Proton tuning through the prefix environment profile.
+//!
+//! VKD3D-Proton (the Direct3D 12 implementation used by Proton) is tuned
+//! with environment variables:
+//!
+//! - `VKD3D_CONFIG`: comma-separated feature flags (`dxr`, `single_queue`, ...)
+//! - `VKD3D_SHADER_MODEL`: the shader model reported to games (`6_6`)
+//! - `VKD3D_DEBUG`: log level (`none`, `err`, `warn`, `fixme`, `info`, `trace`)
+//!
+//! Flags are checked against the vkd3d-proton version found in the prefix,
+//! as unknown flags are silently ignored by vkd3d-proton.
+
+use std::fs;
+use std::path::Path;
+
+use super::profile::EnvProfile;
+
+pub const VKD3D_CONFIG_VAR: &str = "VKD3D_CONFIG";
+pub const VKD3D_SHADER_MODEL_VAR: &str = "VKD3D_SHADER_MODEL";
+pub const VKD3D_DEBUG_VAR: &str = "VKD3D_DEBUG";
+
+/// A VKD3D_CONFIG flag.
+#[derive(Debug, Clone, Copy)]
+pub struct Vkd3dFlag {
+    pub name: &'static str,
+    /// First vkd3d-proton version (major, minor) supporting the flag.
+    pub since: (u32, u32),
+    pub description: &'static str,
+}
+
+/// VKD3D_CONFIG flags protontool knows about.
+pub const VKD3D_FLAGS: &[Vkd3dFlag] = &[
+    Vkd3dFlag {
+        name: "dxr",
+        since: (2, 0),
+        description: "Enable DirectX Raytracing",
+    },
+    Vkd3dFlag {
+        name: "dxr11",
+        since: (2, 3),
+        description: "Enable DirectX Raytracing 1.1",
+    },
+    Vkd3dFlag {
+        name: "force_static_cbv",
+        since: (2, 0),
+        description: "Faster constant buffers on NVIDIA (may break some games)",
+    },
+    Vkd3dFlag {
+        name: "single_queue",
+        since: (2, 0),
+        description: "Use a single Vulkan queue (works around driver hangs)",
+    },
+    Vkd3dFlag {
+        name: "no_upload_hvv",
+        since: (2, 6),
+        description: "Don't place upload heaps in video memory",
+    },
+    Vkd3dFlag {
+        name: "vk_debug",
+        since: (2, 0),
+        description: "Enable Vulkan validation layers (debugging, slow)",
+    },
+    Vkd3dFlag {
+        name: "breadcrumbs",
+        since: (2, 7),
+        description: "Record GPU breadcrumbs to debug GPU hangs",
+    },
+];
+
+/// Shader models accepted by VKD3D_SHADER_MODEL.
+pub const SHADER_MODELS: &[&str] = &[
+    "5_1", "6_0", "6_1", "6_2", "6_3", "6_4", "6_5", "6_6", "6_7", "6_8",
+];
+
+/// Levels accepted by VKD3D_DEBUG.
+pub const DEBUG_LEVELS: &[&str] = &["none", "err", "warn", "fixme", "info", "trace"];
+
+/// Parse a vkd3d-proton version like `v2.13`, `2.13.1` or `v2.13-5-gabcdef`.
+///
+/// ```
+/// use protontool::wine::vkd3d::parse_version;
+/// assert_eq!(parse_version("v2.13-5-gabcdef"), Some((2, 13)));
+/// assert_eq!(parse_version("2.8.1"), Some((2, 8)));
+/// assert_eq!(parse_version("latest"), None);
+/// ```
+pub fn parse_version(s: &str) -> Option<(u32, u32)> {
+    let s = s.trim().strip_prefix('v').unwrap_or(s.trim());
+    let mut parts = s.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Find an embedded `vX.Y` version string in a vkd3d-proton DLL.
+fn scan_dll_version(data: &[u8]) -> Option<(u32, u32)> {
+    let text: String = data
+        .iter()
+        .map(|&b| if b.is_ascii_graphic() { b as char } else { ' ' })
+        .collect();
+    text.split(' ')
+        .filter(|token| token.starts_with("v2.") || token.starts_with("v3."))
+        .find_map(parse_version)
+}
+
+/// Detect the vkd3d-proton version used by a prefix from the d3d12 DLLs
+/// Proton (or the vkd3d verb) placed in it. None if it can't be determined.
+pub fn detect_version(prefix_path: &Path) -> Option<(u32, u32)> {
+    let system32 = prefix_path.join("drive_c/windows/system32");
+    ["d3d12core.dll", "d3d12.dll"]
+        .iter()
+        .filter_map(|dll| fs::read(system32.join(dll)).ok())
+        .find_map(|data| scan_dll_version(&data))
+}
+
+/// VKD3D-Proton settings of a prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vkd3dSettings {
+    pub flags: Vec<String>,
+    pub shader_model: Option<String>,
+    pub debug: Option<String>,
+}
+
+impl Vkd3dSettings {
+    /// Read the settings from an environment profile.
+    pub fn from_profile(profile: &EnvProfile) -> Self {
+        Vkd3dSettings {
+            flags: profile
+                .get(VKD3D_CONFIG_VAR)
+                .unwrap_or_default()
+                .split(',')
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty())
+                .collect(),
+            shader_model: profile.get(VKD3D_SHADER_MODEL_VAR).map(|v| v.to_string()),
+            debug: profile.get(VKD3D_DEBUG_VAR).map(|v| v.to_string()),
+        }
+    }
+
+    pub fn load(prefix_path: &Path) -> Self {
+        Self::from_profile(&EnvProfile::load(prefix_path))
+    }
+
+    /// Values of the environment variables (None = unset).
+    pub fn env_vars(&self) -> [(&'static str, Option<String>); 3] {
+        [
+            (
+                VKD3D_CONFIG_VAR,
+                Some(self.flags.join(",")).filter(|f| !f.is_empty()),
+            ),
+            (VKD3D_SHADER_MODEL_VAR, self.shader_model.clone()),
+            (VKD3D_DEBUG_VAR, self.debug.clone()),
+        ]
+    }
+
+    /// Write changed variables to the prefix's environment profile. Each
+    /// change is journaled so it can be undone.
+    pub fn save(&self, prefix_path: &Path) -> Result<(), String> {
+        let current = EnvProfile::load(prefix_path);
+        for (name, value) in self.env_vars() {
+            if current.get(name) != value.as_deref() {
+                crate::journal::set_profile_var(prefix_path, name, value.as_deref())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Enable a flag after checking it's supported by `version` (when known).
+    pub fn enable_flag(&mut self, flag: &str, version: Option<(u32, u32)>) -> Result<(), String> {
+        let known = match VKD3D_FLAGS.iter().find(|f| f.name == flag) {
+            Some(f) => f,
+            None => return Err(format!("Unknown VKD3D_CONFIG flag: {}", flag)),
+        };
+        if let Some(version) = version {
+            if version < known.since {
+                return Err(format!(
+                    "{} requires vkd3d-proton {}.{} or newer (found {}.{})",
+                    flag, known.since.0, known.since.1, version.0, version.1
+                ));
+            }
+        }
+        if !self.flags.iter().any(|f| f == flag) {
+            self.flags.push(flag.to_string());
+        }
+        Ok(())
+    }
+
+    pub fn disable_flag(&mut self, flag: &str) -> bool {
+        let before = self.flags.len();
+        self.flags.retain(|f| f != flag);
+        self.flags.len() != before
+    }
+
+    /// Set the reported shader model (`6_6` or `6.6`), None for the default.
+    pub fn set_shader_model(&mut self, model: Option<&str>) -> Result<(), String> {
+        self.shader_model = match model {
+            None => None,
+            Some(model) => {
+                let model = model.replace('.', "_");
+                if !SHADER_MODELS.contains(&model.as_str()) {
+                    return Err(format!(
+                        "Unsupported shader model {} (expected one of {})",
+                        model,
+                        SHADER_MODELS.join(", ")
+                    ));
+                }
+                Some(model)
+            }
+        };
+        Ok(())
+    }
+
+    /// Set the debug log level, None for the default.
+    pub fn set_debug(&mut self, level: Option<&str>) -> Result<(), String> {
+        if let Some(level) = level {
+            if !DEBUG_LEVELS.contains(&level) {
+                return Err(format!(
+                    "Unknown VKD3D_DEBUG level {} (expected one of {})",
+                    level,
+                    DEBUG_LEVELS.join(", ")
+                ));
+            }
+        }
+        self.debug = level.map(|l| l.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vkd3d_settings() {
+        let profile = EnvProfile::parse("VKD3D_CONFIG=dxr,custom_flag\n");
+        let mut settings = Vkd3dSettings::from_profile(&profile);
+        assert_eq!(settings.flags, vec!["dxr", "custom_flag"]);
+
+        assert!(settings.enable_flag("breadcrumbs", Some((2, 6))).is_err());
+        assert!(settings.enable_flag("bogus", None).is_err());
+        settings.enable_flag("single_queue", Some((2, 13))).unwrap();
+        assert!(settings.disable_flag("dxr"));
+        settings.set_shader_model(Some("6.6")).unwrap();
+        assert!(settings.set_debug(Some("verbose")).is_err());
+
+        let vars = settings.env_vars();
+        assert_eq!(vars[0].1.as_deref(), Some("custom_flag,single_queue"));
+        assert_eq!(vars[1].1.as_deref(), Some("6_6"));
+        assert_eq!(vars[2].1, None);
+
+        assert_eq!(
+            scan_dll_version(b"MZ\0\0vkd3d-proton\0v2.12-3-g1234abc\0"),
+            Some((2, 12))
+        );
+    }
+}
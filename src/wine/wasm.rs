@@ -0,0 +1,433 @@
+//! Sandboxed (WASM) verb plugins.
+//!
+//! Executable plugins (see `plugin.rs`) run with the user's full privileges.
+//! A plugin file ending in `.wasm` instead runs in a wasmtime sandbox and only
+//! gets this narrow set of host calls, imported from the `protontool` module:
+//!
+//! | Import                                    | Effect                                     |
+//! |-------------------------------------------|--------------------------------------------|
+//! | `log(msg)`                                | Write to the protontool log                |
+//! | `args(buf, buf_len) -> len`               | Copy the verb's args (JSON array)          |
+//! | `download(url, name, sha256)`             | Download an https URL into the cache       |
+//! | `copy_to_prefix(source, dest)`            | Copy a cached/temp file into `drive_c`     |
+//! | `write_registry(key, name, value, dword)` | Write a `REG_SZ` or `REG_DWORD` value      |
+//! | `run_wine(program, args) -> exit code`    | Run a program with fixed args (JSON array) |
+//!
+//! Strings are `(ptr, len)` pairs in the plugin's exported `memory`; an empty
+//! `sha256` skips verification. A rejected host call traps, which fails the
+//! verb. The plugin exports `run() -> i32` and returns 0 on success.
+//!
+//! Every call validates its arguments so a plugin can't reach outside the
+//! cache, temporary directory and prefix. Plugins also run with a fuel budget
+//! and a memory cap. The runtime is behind the `wasm` cargo feature; other
+//! builds report that sandboxed plugins are unavailable.
+
+use std::path::{Component, Path, PathBuf};
+
+use super::download::Downloader;
+use super::registry::{RegType, RegistryEditor};
+use super::WineContext;
+
+/// Host functions exposed to sandboxed plugins.
+pub struct WasmHost {
+    wine_ctx: WineContext,
+    downloader: Downloader,
+    tmp_dir: PathBuf,
+}
+
+/// Reject empty, absolute and parent-escaping relative paths.
+fn safe_relative(path: &str) -> Result<PathBuf, String> {
+    let path = Path::new(path);
+    let safe = !path.as_os_str().is_empty()
+        && path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if safe {
+        Ok(path.to_path_buf())
+    } else {
+        Err(format!("Path not allowed in sandbox: {}", path.display()))
+    }
+}
+
+impl WasmHost {
+    pub fn new(wine_ctx: &WineContext, downloader: &Downloader, tmp_dir: &Path) -> Self {
+        Self {
+            wine_ctx: wine_ctx.clone(),
+            downloader: downloader.clone(),
+            tmp_dir: tmp_dir.to_path_buf(),
+        }
+    }
+
+    /// Download an https URL into the cache under a plain file name.
+    pub fn download(
+        &self,
+        url: &str,
+        filename: &str,
+        sha256: Option<&str>,
+    ) -> Result<PathBuf, String> {
+        if !url.starts_with("https://") {
+            return Err(format!("Only https downloads are allowed: {}", url));
+        }
+        if safe_relative(filename)?.components().count() != 1 {
+            return Err(format!("Invalid download file name: {}", filename));
+        }
+        self.downloader.download(url, filename, sha256)
+    }
+
+    /// Resolve a source file: cache first, then the temp directory.
+    fn source_path(&self, name: &str) -> Result<PathBuf, String> {
+        let relative = safe_relative(name)?;
+        [self.downloader.cache_dir(), self.tmp_dir.as_path()]
+            .iter()
+            .map(|dir| dir.join(&relative))
+            .find(|p| p.is_file())
+            .ok_or_else(|| format!("File not found in cache or temp dir: {}", name))
+    }
+
+    /// Copy a cached or temporary file to a path relative to `drive_c`.
+    pub fn copy_to_prefix(&self, source: &str, dest: &str) -> Result<(), String> {
+        let src = self.source_path(source)?;
+        let dest = self
+            .wine_ctx
+            .prefix_path
+            .join("drive_c")
+            .join(safe_relative(dest)?);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::copy(&src, &dest)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to copy {}: {}", src.display(), e))
+    }
+
+    /// Write a registry value (`REG_SZ` or `REG_DWORD`).
+    pub fn write_registry(
+        &self,
+        key: &str,
+        name: &str,
+        value: &str,
+        dword: bool,
+    ) -> Result<(), String> {
+        if key.contains(['[', ']', '\n']) || name.contains(['"', '\n']) || value.contains('\n') {
+            return Err(format!("Invalid registry value {}\\{}", key, name));
+        }
+        let value_type = if dword {
+            RegType::Dword
+        } else {
+            RegType::String
+        };
+        RegistryEditor::new(&self.wine_ctx).set_value(key, name, value, value_type)
+    }
+
+    /// Run a program from the cache, temp dir or `drive_c` with fixed arguments.
+    pub fn run_wine(&self, program: &str, args: &[String]) -> Result<i32, String> {
+        let path = match self.source_path(program) {
+            Ok(p) => p,
+            Err(_) => {
+                let in_prefix = self
+                    .wine_ctx
+                    .prefix_path
+                    .join("drive_c")
+                    .join(safe_relative(program)?);
+                if !in_prefix.is_file() {
+                    return Err(format!("Program not found: {}", program));
+                }
+                in_prefix
+            }
+        };
+
+        let mut cmd_args = vec![path.to_string_lossy().to_string()];
+        cmd_args.extend(args.iter().cloned());
+        let refs: Vec<&str> = cmd_args.iter().map(|s| s.as_str()).collect();
+        let output = self.wine_ctx.run_wine(&refs).map_err(|e| e.to_string())?;
+        self.wine_ctx.wait_for_wineserver().ok();
+        Ok(output.status.code().unwrap_or(-1))
+    }
+}
+
+/// Run a sandboxed WASM plugin module.
+#[cfg(not(feature = "wasm"))]
+pub fn run_wasm_plugin(module: &Path, _host: WasmHost, _args: &[String]) -> Result<(), String> {
+    Err(format!(
+        "Cannot run {}: protontool was built without the `wasm` feature",
+        module.display()
+    ))
+}
+
+#[cfg(feature = "wasm")]
+pub use runtime::run_wasm_plugin;
+
+#[cfg(feature = "wasm")]
+mod runtime {
+    use std::path::Path;
+
+    use wasmtime::{
+        Caller, Config, Engine, Error, Extern, Linker, Memory, Module, Store, StoreLimits,
+        StoreLimitsBuilder,
+    };
+
+    use super::WasmHost;
+
+    /// Instructions a plugin may execute. Host calls (downloads, Wine) are
+    /// not counted, so this only stops runaway guest code.
+    const FUEL: u64 = 10_000_000_000;
+    /// Largest linear memory a plugin may grow to.
+    const MAX_MEMORY: usize = 256 << 20;
+
+    struct State {
+        host: WasmHost,
+        args: String,
+        limits: StoreLimits,
+    }
+
+    fn memory(caller: &mut Caller<'_, State>) -> Result<Memory, Error> {
+        match caller.get_export("memory") {
+            Some(Extern::Memory(memory)) => Ok(memory),
+            _ => Err(Error::msg("plugin does not export its memory")),
+        }
+    }
+
+    fn read_str(caller: &mut Caller<'_, State>, ptr: u32, len: u32) -> Result<String, Error> {
+        let memory = memory(caller)?;
+        let (start, len) = (ptr as usize, len as usize);
+        let data = memory
+            .data(&caller)
+            .get(start..start.saturating_add(len))
+            .ok_or_else(|| Error::msg("string argument is out of bounds"))?;
+        String::from_utf8(data.to_vec()).map_err(|_| Error::msg("string argument is not UTF-8"))
+    }
+
+    fn read_args(caller: &mut Caller<'_, State>, ptr: u32, len: u32) -> Result<Vec<String>, Error> {
+        let json = read_str(caller, ptr, len)?;
+        crate::json::parse(&json)
+            .ok()
+            .and_then(|v| {
+                v.as_array()?
+                    .iter()
+                    .map(|a| a.as_str().map(String::from))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(|| Error::msg(format!("arguments are not a JSON string array: {}", json)))
+    }
+
+    fn link(linker: &mut Linker<State>) -> Result<(), Error> {
+        linker.func_wrap(
+            "protontool",
+            "log",
+            |mut caller: Caller<'_, State>, ptr: u32, len: u32| {
+                crate::log::info(&read_str(&mut caller, ptr, len)?);
+                Ok(())
+            },
+        )?;
+        linker.func_wrap(
+            "protontool",
+            "args",
+            |mut caller: Caller<'_, State>, ptr: u32, len: u32| {
+                let args = caller.data().args.clone();
+                if args.len() <= len as usize {
+                    memory(&mut caller)?.write(&mut caller, ptr as usize, args.as_bytes())?;
+                }
+                Ok(args.len() as u32)
+            },
+        )?;
+        linker.func_wrap(
+            "protontool",
+            "download",
+            |mut caller: Caller<'_, State>,
+             url_ptr: u32,
+             url_len: u32,
+             name_ptr: u32,
+             name_len: u32,
+             sha256_ptr: u32,
+             sha256_len: u32| {
+                let url = read_str(&mut caller, url_ptr, url_len)?;
+                let name = read_str(&mut caller, name_ptr, name_len)?;
+                let sha256 = read_str(&mut caller, sha256_ptr, sha256_len)?;
+                let sha256 = Some(sha256.as_str()).filter(|s| !s.is_empty());
+                caller
+                    .data()
+                    .host
+                    .download(&url, &name, sha256)
+                    .map(|_| ())
+                    .map_err(Error::msg)
+            },
+        )?;
+        linker.func_wrap(
+            "protontool",
+            "copy_to_prefix",
+            |mut caller: Caller<'_, State>,
+             source_ptr: u32,
+             source_len: u32,
+             dest_ptr: u32,
+             dest_len: u32| {
+                let source = read_str(&mut caller, source_ptr, source_len)?;
+                let dest = read_str(&mut caller, dest_ptr, dest_len)?;
+                caller
+                    .data()
+                    .host
+                    .copy_to_prefix(&source, &dest)
+                    .map_err(Error::msg)
+            },
+        )?;
+        linker.func_wrap(
+            "protontool",
+            "write_registry",
+            |mut caller: Caller<'_, State>,
+             key_ptr: u32,
+             key_len: u32,
+             name_ptr: u32,
+             name_len: u32,
+             value_ptr: u32,
+             value_len: u32,
+             dword: u32| {
+                let key = read_str(&mut caller, key_ptr, key_len)?;
+                let name = read_str(&mut caller, name_ptr, name_len)?;
+                let value = read_str(&mut caller, value_ptr, value_len)?;
+                caller
+                    .data()
+                    .host
+                    .write_registry(&key, &name, &value, dword != 0)
+                    .map_err(Error::msg)
+            },
+        )?;
+        linker.func_wrap(
+            "protontool",
+            "run_wine",
+            |mut caller: Caller<'_, State>,
+             program_ptr: u32,
+             program_len: u32,
+             args_ptr: u32,
+             args_len: u32| {
+                let program = read_str(&mut caller, program_ptr, program_len)?;
+                let args = read_args(&mut caller, args_ptr, args_len)?;
+                caller
+                    .data()
+                    .host
+                    .run_wine(&program, &args)
+                    .map_err(Error::msg)
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Report the underlying cause rather than wasmtime's backtrace context.
+    fn describe(error: Error) -> String {
+        error.root_cause().to_string()
+    }
+
+    /// Run a sandboxed WASM plugin module.
+    pub fn run_wasm_plugin(module: &Path, host: WasmHost, args: &[String]) -> Result<(), String> {
+        let name = module.display();
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(describe)?;
+        let compiled = Module::from_file(&engine, module)
+            .map_err(|e| format!("Failed to load plugin {}: {}", name, describe(e)))?;
+
+        let mut linker = Linker::new(&engine);
+        link(&mut linker).map_err(describe)?;
+
+        let args = crate::json::JsonValue::Array(
+            args.iter()
+                .map(|a| crate::json::JsonValue::String(a.clone()))
+                .collect(),
+        );
+        let state = State {
+            host,
+            args: args.to_string(),
+            limits: StoreLimitsBuilder::new().memory_size(MAX_MEMORY).build(),
+        };
+        let mut store = Store::new(&engine, state);
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(FUEL).map_err(describe)?;
+
+        let status = linker
+            .instantiate(&mut store, &compiled)
+            .and_then(|instance| instance.get_typed_func::<(), i32>(&mut store, "run"))
+            .and_then(|run| run.call(&mut store, ()))
+            .map_err(|e| format!("Plugin {} failed: {}", name, describe(e)))?;
+        if status != 0 {
+            return Err(format!(
+                "Plugin {} failed: exited with code {}",
+                name, status
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_host(name: &str) -> (WasmHost, PathBuf) {
+        let root = std::env::temp_dir().join(format!("pt-wasm-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(root.join("prefix/drive_c")).unwrap();
+        std::fs::create_dir_all(root.join("tmp")).unwrap();
+        let proton = crate::steam::ProtonApp {
+            name: "Proton".into(),
+            appid: 0,
+            install_path: root.join("proton"),
+            is_proton_ready: true,
+        };
+        let wine_ctx = WineContext::from_proton(&proton, &root.join("prefix"));
+        let downloader = Downloader::new(&root.join("cache"));
+        (
+            WasmHost::new(&wine_ctx, &downloader, &root.join("tmp")),
+            root,
+        )
+    }
+
+    #[test]
+    fn test_host_rejects_escapes() {
+        assert!(safe_relative("windows/system32/x.dll").is_ok());
+        assert!(safe_relative("../x").is_err());
+        assert!(safe_relative("/etc/passwd").is_err());
+        assert!(safe_relative("").is_err());
+
+        let (host, root) = test_host("escape");
+        assert!(host
+            .download("http://example.com/x.exe", "x.exe", None)
+            .is_err());
+        assert!(host
+            .download("https://example.com/x", "a/x.exe", None)
+            .is_err());
+        assert!(host.copy_to_prefix("missing.exe", "x.exe").is_err());
+        assert!(host.write_registry("HKCU\\]", "x", "1", true).is_err());
+        std::fs::remove_dir_all(root).ok();
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn test_run_wasm_plugin() {
+        let (host, root) = test_host("run");
+        std::fs::write(root.join("tmp/setup.dll"), "dll").unwrap();
+        let plugin = |name: &str, dest: &str| {
+            let module = root.join(name);
+            let wat = format!(
+                r#"(module
+                    (import "protontool" "copy_to_prefix" (func $copy (param i32 i32 i32 i32)))
+                    (memory (export "memory") 1)
+                    (data (i32.const 0) "setup.dll")
+                    (data (i32.const 16) "{}")
+                    (func (export "run") (result i32)
+                        (call $copy (i32.const 0) (i32.const 9) (i32.const 16) (i32.const {}))
+                        (i32.const 0)))"#,
+                dest,
+                dest.len()
+            );
+            std::fs::write(&module, wat).unwrap();
+            module
+        };
+
+        let ok = plugin("ok.wasm", "windows/setup.dll");
+        run_wasm_plugin(&ok, host, &[]).unwrap();
+        assert!(root.join("prefix/drive_c/windows/setup.dll").is_file());
+
+        let (host, _) = test_host("run");
+        let escape = plugin("escape.wasm", "../../setup.dll");
+        let error = run_wasm_plugin(&escape, host, &[]).unwrap_err();
+        assert!(error.contains("Path not allowed in sandbox"), "{}", error);
+        std::fs::remove_dir_all(root).ok();
+    }
+}
@@ -0,0 +1,117 @@
+//! Persistent per-prefix `WINEDEBUG`.
+//!
+//! A channel spec stored as `winedebug` in the prefix metadata is set as
+//! `WINEDEBUG` for everything protontool runs in the prefix (verbs,
+//! commands, `play`), so it doesn't have to be exported before every call.
+//! `--winedebug SPEC` overrides it for one invocation; otherwise an exported
+//! `WINEDEBUG` wins over the stored spec.
+//!
+//! Specs use Wine's syntax, comma-separated items of an optional class
+//! (`err`, `warn`, `fixme`, `trace`), `+` or `-` and a channel from
+//! [`WINE_DEBUG_CHANNELS`](crate::wine_data::WINE_DEBUG_CHANNELS), a channel
+//! Wine declares on the side (`loaddll`, `pid`, ...) or `all`:
+//! `-all,err+module,+loaddll`.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::metadata::PrefixMetadata;
+use crate::wine_data::is_valid_channel;
+
+/// Metadata key holding the stored spec.
+const WINEDEBUG_KEY: &str = "winedebug";
+
+/// Debug classes a spec item may start with.
+const CLASSES: &[&str] = &["err", "warn", "fixme", "trace"];
+
+/// Channels declared with `WINE_DECLARE_DEBUG_CHANNEL` (rather than as a
+/// file's default channel), which the bundled channel list predates.
+const DECLARED_CHANNELS: &[&str] = &[
+    "d3d_perf",
+    "fps",
+    "imports",
+    "key",
+    "loaddll",
+    "microsecs",
+    "pid",
+    "threadname",
+    "timestamp",
+    "winediag",
+];
+
+/// Spec given with `--winedebug` for this invocation.
+static OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Validate a `WINEDEBUG` spec and normalize its spacing.
+///
+/// ```
+/// use protontool::wine::winedebug::validate;
+/// assert_eq!(validate("-all, err+module ,+loaddll").unwrap(), "-all,err+module,+loaddll");
+/// assert_eq!(validate("relay").unwrap(), "relay");
+/// assert!(validate("+nosuchchannel").is_err());
+/// assert!(validate("info+d3d").is_err());
+/// ```
+pub fn validate(spec: &str) -> Result<String, String> {
+    let mut items = Vec::new();
+    for item in spec.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+        let (class, channel) = match item.find(['+', '-']) {
+            Some(sign) => (&item[..sign], &item[sign + 1..]),
+            None => ("", item),
+        };
+        if !class.is_empty() && !CLASSES.contains(&class) {
+            return Err(format!(
+                "Unknown debug class '{}' in '{}' (use {})",
+                class,
+                item,
+                CLASSES.join(", ")
+            ));
+        }
+        if channel != "all" && !is_valid_channel(channel) && !DECLARED_CHANNELS.contains(&channel) {
+            return Err(format!("Unknown debug channel '{}' in '{}'", channel, item));
+        }
+        items.push(item);
+    }
+    if items.is_empty() {
+        return Err("Empty WINEDEBUG spec".to_string());
+    }
+    Ok(items.join(","))
+}
+
+/// Use a validated spec for everything run by this invocation
+/// (`--winedebug`).
+pub fn set_override(spec: Option<String>) {
+    *OVERRIDE.lock().unwrap() = spec;
+}
+
+/// The spec stored for a prefix.
+pub fn stored(prefix_path: &Path) -> Option<String> {
+    PrefixMetadata::load(prefix_path)
+        .get(WINEDEBUG_KEY)
+        .filter(|spec| !spec.is_empty())
+        .map(str::to_string)
+}
+
+/// Store a spec for a prefix, or with None remove it.
+pub fn store(prefix_path: &Path, spec: Option<&str>) -> Result<(), String> {
+    let mut metadata = PrefixMetadata::load(prefix_path);
+    match spec {
+        Some(spec) => metadata.set(WINEDEBUG_KEY, &validate(spec)?),
+        None => {
+            metadata.unset(WINEDEBUG_KEY);
+        }
+    }
+    metadata.save(prefix_path)
+}
+
+/// `WINEDEBUG` to set for programs run in a prefix: the `--winedebug`
+/// override, else the stored spec unless `WINEDEBUG` is exported. None
+/// leaves the inherited value alone.
+pub fn for_prefix(prefix_path: &Path) -> Option<String> {
+    if let Some(spec) = OVERRIDE.lock().unwrap().clone() {
+        return Some(spec);
+    }
+    if std::env::var_os("WINEDEBUG").is_some() {
+        return None;
+    }
+    stored(prefix_path)
+}
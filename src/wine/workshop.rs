@@ -0,0 +1,147 @@
+//! Steam Workshop content for modding tools running inside a prefix.
+//!
+//! Steam keeps a game's subscribed Workshop items in
+//! `steamapps/workshop/content/APPID/ITEMID` of the library the game is
+//! installed in. Windows mod managers can't browse there unless the Z: drive
+//! is mapped, so the content directory is linked into the prefix as
+//! `drive_c/workshop`, where every item shows up as `C:\workshop\ITEMID`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Link inside the prefix pointing at the Workshop content directory.
+pub const WORKSHOP_LINK: &str = "drive_c/workshop";
+
+/// Windows path of `WORKSHOP_LINK`.
+pub const WORKSHOP_WINDOWS_PATH: &str = "C:\\workshop";
+
+/// A downloaded Workshop item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkshopItem {
+    pub id: u64,
+    pub path: PathBuf,
+}
+
+/// Workshop items in a content directory, sorted by id.
+pub fn items(content_dir: &Path) -> Vec<WorkshopItem> {
+    let mut items: Vec<WorkshopItem> = fs::read_dir(content_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| {
+                    let id = e.file_name().to_str()?.parse().ok()?;
+                    Some(WorkshopItem { id, path: e.path() })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    items.sort_by_key(|item| item.id);
+    items
+}
+
+/// App ID of a Steam prefix (`steamapps/compatdata/APPID/pfx`).
+pub fn prefix_appid(prefix_path: &Path) -> Option<u32> {
+    let compat_data = prefix_path.parent()?;
+    if compat_data.parent()?.file_name()? != "compatdata" {
+        return None;
+    }
+    compat_data.file_name()?.to_str()?.parse().ok()
+}
+
+/// Workshop content directory for a Steam prefix, looked up in the library
+/// holding the prefix. None for custom prefixes and games without Workshop
+/// content in that library.
+pub fn content_dir_for_prefix(prefix_path: &Path) -> Option<PathBuf> {
+    let appid = prefix_appid(prefix_path)?;
+    let steamapps = prefix_path.parent()?.parent()?.parent()?;
+    let content = steamapps.join("workshop/content").join(appid.to_string());
+    content.is_dir().then_some(content)
+}
+
+/// Host directory the prefix's Workshop link points at, if linked.
+pub fn linked_dir(prefix_path: &Path) -> Option<PathBuf> {
+    fs::read_link(prefix_path.join(WORKSHOP_LINK)).ok()
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(not(unix))]
+fn symlink(_target: &Path, _link: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "symlinks are not supported on this platform",
+    ))
+}
+
+/// Link a Workshop content directory into the prefix, replacing an earlier
+/// link. Returns the Windows path the content is reachable at.
+pub fn link(prefix_path: &Path, content_dir: &Path) -> Result<&'static str, String> {
+    let link = prefix_path.join(WORKSHOP_LINK);
+    if link.is_symlink() {
+        if linked_dir(prefix_path).as_deref() == Some(content_dir) {
+            return Ok(WORKSHOP_WINDOWS_PATH);
+        }
+        fs::remove_file(&link)
+            .map_err(|e| format!("Failed to remove {}: {}", link.display(), e))?;
+    } else if link.exists() {
+        return Err(format!(
+            "{} already exists and is not a link; move it away first",
+            link.display()
+        ));
+    }
+    symlink(content_dir, &link).map_err(|e| {
+        format!(
+            "Failed to link {} to {}: {}",
+            link.display(),
+            content_dir.display(),
+            e
+        )
+    })?;
+    Ok(WORKSHOP_WINDOWS_PATH)
+}
+
+/// Remove the prefix's Workshop link. Returns whether there was one.
+pub fn unlink(prefix_path: &Path) -> Result<bool, String> {
+    let link = prefix_path.join(WORKSHOP_LINK);
+    if !link.is_symlink() {
+        return Ok(false);
+    }
+    fs::remove_file(&link).map_err(|e| format!("Failed to remove {}: {}", link.display(), e))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_workshop_content() {
+        let root = std::env::temp_dir().join(format!("pt-workshop-{}", std::process::id()));
+        let steamapps = root.join("steamapps");
+        let prefix = steamapps.join("compatdata/489830/pfx");
+        let content = steamapps.join("workshop/content/489830");
+        fs::create_dir_all(prefix.join("drive_c")).unwrap();
+        fs::create_dir_all(content.join("2001")).unwrap();
+        fs::create_dir_all(content.join("1999")).unwrap();
+        fs::write(content.join("notes.txt"), "").unwrap();
+
+        assert_eq!(prefix_appid(&prefix), Some(489830));
+        assert_eq!(prefix_appid(&root.join("custom")), None);
+        assert_eq!(content_dir_for_prefix(&prefix), Some(content.clone()));
+        let ids: Vec<u64> = items(&content).iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![1999, 2001]);
+
+        assert_eq!(link(&prefix, &content).unwrap(), WORKSHOP_WINDOWS_PATH);
+        assert!(prefix.join(WORKSHOP_LINK).join("2001").is_dir());
+        assert_eq!(linked_dir(&prefix), Some(content.clone()));
+        assert!(link(&prefix, &content).is_ok());
+        assert!(unlink(&prefix).unwrap());
+        assert!(!unlink(&prefix).unwrap());
+
+        fs::remove_dir_all(&root).ok();
+    }
+}
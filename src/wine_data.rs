@@ -2,6 +2,10 @@
 //! Auto-generated by wine-extract tool
 //! Do not edit manually
 
+/// Wine version of the source tree the data was extracted from, if it was
+/// recorded
+pub const WINE_SOURCE_VERSION: Option<&str> = None;
+
 /// Wine debug channels extracted from Wine source code
 /// Use with WINEDEBUG=+channel to enable tracing
 pub const WINE_DEBUG_CHANNELS: &[&str] = &[
@@ -10948,6 +10952,7722 @@ pub const NTSTATUS_CODES: &[(u32, &str, &str)] = &[
     ),
 ];
 
+/// Typed codes from `NTSTATUS_CODES`, for matching without string comparisons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum NtStatus {
+    /// STATUS_GUARD_PAGE_VIOLATION
+    GuardPageViolation = 0x80000001,
+    /// STATUS_DATATYPE_MISALIGNMENT
+    DatatypeMisalignment = 0x80000002,
+    /// STATUS_BREAKPOINT
+    Breakpoint = 0x80000003,
+    /// STATUS_SINGLE_STEP
+    SingleStep = 0x80000004,
+    /// STATUS_BUFFER_OVERFLOW
+    BufferOverflow = 0x80000005,
+    /// STATUS_NO_MORE_FILES
+    NoMoreFiles = 0x80000006,
+    /// STATUS_WAKE_SYSTEM_DEBUGGER
+    WakeSystemDebugger = 0x80000007,
+    /// STATUS_HANDLES_CLOSED
+    HandlesClosed = 0x8000000A,
+    /// STATUS_NO_INHERITANCE
+    NoInheritance = 0x8000000B,
+    /// STATUS_GUID_SUBSTITUTION_MADE
+    GuidSubstitutionMade = 0x8000000C,
+    /// STATUS_PARTIAL_COPY
+    PartialCopy = 0x8000000D,
+    /// STATUS_DEVICE_PAPER_EMPTY
+    DevicePaperEmpty = 0x8000000E,
+    /// STATUS_DEVICE_POWERED_OFF
+    DevicePoweredOff = 0x8000000F,
+    /// STATUS_DEVICE_OFF_LINE
+    DeviceOffLine = 0x80000010,
+    /// STATUS_DEVICE_BUSY
+    DeviceBusy = 0x80000011,
+    /// STATUS_NO_MORE_EAS
+    NoMoreEas = 0x80000012,
+    /// STATUS_INVALID_EA_NAME
+    InvalidEaName = 0x80000013,
+    /// STATUS_EA_LIST_INCONSISTENT
+    EaListInconsistent = 0x80000014,
+    /// STATUS_INVALID_EA_FLAG
+    InvalidEaFlag = 0x80000015,
+    /// STATUS_VERIFY_REQUIRED
+    VerifyRequired = 0x80000016,
+    /// STATUS_EXTRANEOUS_INFORMATION
+    ExtraneousInformation = 0x80000017,
+    /// STATUS_RXACT_COMMIT_NECESSARY
+    RxactCommitNecessary = 0x80000018,
+    /// STATUS_NO_MORE_ENTRIES
+    NoMoreEntries = 0x8000001A,
+    /// STATUS_FILEMARK_DETECTED
+    FilemarkDetected = 0x8000001B,
+    /// STATUS_MEDIA_CHANGED
+    MediaChanged = 0x8000001C,
+    /// STATUS_BUS_RESET
+    BusReset = 0x8000001D,
+    /// STATUS_END_OF_MEDIA
+    EndOfMedia = 0x8000001E,
+    /// STATUS_BEGINNING_OF_MEDIA
+    BeginningOfMedia = 0x8000001F,
+    /// STATUS_MEDIA_CHECK
+    MediaCheck = 0x80000020,
+    /// STATUS_SETMARK_DETECTED
+    SetmarkDetected = 0x80000021,
+    /// STATUS_NO_DATA_DETECTED
+    NoDataDetected = 0x80000022,
+    /// STATUS_REDIRECTOR_HAS_OPEN_HANDLES
+    RedirectorHasOpenHandles = 0x80000023,
+    /// STATUS_SERVER_HAS_OPEN_HANDLES
+    ServerHasOpenHandles = 0x80000024,
+    /// STATUS_ALREADY_DISCONNECTED
+    AlreadyDisconnected = 0x80000025,
+    /// STATUS_LONGJUMP
+    Longjump = 0x80000026,
+    /// STATUS_CLEANER_CARTRIDGE_INSTALLED
+    CleanerCartridgeInstalled = 0x80000027,
+    /// STATUS_PLUGPLAY_QUERY_VETOED
+    PlugplayQueryVetoed = 0x80000028,
+    /// STATUS_UNWIND_CONSOLIDATE
+    UnwindConsolidate = 0x80000029,
+    /// STATUS_REGISTRY_HIVE_RECOVERED
+    RegistryHiveRecovered = 0x8000002A,
+    /// STATUS_DLL_MIGHT_BE_INSECURE
+    DllMightBeInsecure = 0x8000002B,
+    /// STATUS_DLL_MIGHT_BE_INCOMPATIBLE
+    DllMightBeIncompatible = 0x8000002C,
+    /// STATUS_STOPPED_ON_SYMLINK
+    StoppedOnSymlink = 0x8000002D,
+    /// STATUS_CANNOT_GRANT_REQUESTED_OPLOCK
+    CannotGrantRequestedOplock = 0x8000002E,
+    /// STATUS_NO_ACE_CONDITION
+    NoAceCondition = 0x8000002F,
+    /// STATUS_DEVICE_SUPPORT_IN_PROGRESS
+    DeviceSupportInProgress = 0x80000030,
+    /// STATUS_DEVICE_POWER_CYCLE_REQUIRED
+    DevicePowerCycleRequired = 0x80000031,
+    /// STATUS_NO_WORK_DONE
+    NoWorkDone = 0x80000032,
+    /// STATUS_RETURN_ADDRESS_HIJACK_ATTEMPT
+    ReturnAddressHijackAttempt = 0x80000033,
+    /// STATUS_RECOVERABLE_BUGCHECK
+    RecoverableBugcheck = 0x80000034,
+    /// STATUS_DEVICE_RESET_REQUIRED
+    DeviceResetRequired = 0x800001B6,
+    /// STATUS_DEVICE_REQUIRES_CLEANING
+    DeviceRequiresCleaning = 0x80000288,
+    /// STATUS_DEVICE_DOOR_OPEN
+    DeviceDoorOpen = 0x80000289,
+    /// STATUS_DATA_LOST_REPAIR
+    DataLostRepair = 0x80000803,
+    /// STATUS_GPIO_INTERRUPT_ALREADY_UNMASKED
+    GpioInterruptAlreadyUnmasked = 0x8000A127,
+    /// STATUS_CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH
+    CloudFilePropertyBlobChecksumMismatch = 0x8000CF00,
+    /// STATUS_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE
+    CloudFilePropertyBlobTooLarge = 0x8000CF04,
+    /// STATUS_CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS
+    CloudFileTooManyPropertyBlobs = 0x8000CF05,
+    /// STATUS_CLUSTER_NODE_ALREADY_UP
+    ClusterNodeAlreadyUp = 0x80130001,
+    /// STATUS_CLUSTER_NODE_ALREADY_DOWN
+    ClusterNodeAlreadyDown = 0x80130002,
+    /// STATUS_CLUSTER_NETWORK_ALREADY_ONLINE
+    ClusterNetworkAlreadyOnline = 0x80130003,
+    /// STATUS_CLUSTER_NETWORK_ALREADY_OFFLINE
+    ClusterNetworkAlreadyOffline = 0x80130004,
+    /// STATUS_CLUSTER_NODE_ALREADY_MEMBER
+    ClusterNodeAlreadyMember = 0x80130005,
+    /// STATUS_COULD_NOT_RESIZE_LOG
+    CouldNotResizeLog = 0x80190009,
+    /// STATUS_NO_TXF_METADATA
+    NoTxfMetadata = 0x80190029,
+    /// STATUS_CANT_RECOVER_WITH_HANDLE_OPEN
+    CantRecoverWithHandleOpen = 0x80190031,
+    /// STATUS_TXF_METADATA_ALREADY_PRESENT
+    TxfMetadataAlreadyPresent = 0x80190041,
+    /// STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET
+    TransactionScopeCallbacksNotSet = 0x80190042,
+    /// STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED
+    VideoHungDisplayDriverThreadRecovered = 0x801B00EB,
+    /// STATUS_FLT_BUFFER_TOO_SMALL
+    FltBufferTooSmall = 0x801C0001,
+    /// STATUS_GRAPHICS_LINK_CONFIGURATION_IN_PROGRESS
+    GraphicsLinkConfigurationInProgress = 0x801E0000,
+    /// STATUS_FVE_PARTIAL_METADATA
+    FvePartialMetadata = 0x80210001,
+    /// STATUS_FVE_TRANSIENT_STATE
+    FveTransientState = 0x80210002,
+    /// STATUS_VID_REMOTE_NODE_PARENT_GPA_PAGES_USED
+    VidRemoteNodeParentGpaPagesUsed = 0x80370001,
+    /// STATUS_VOLMGR_INCOMPLETE_REGENERATION
+    VolmgrIncompleteRegeneration = 0x80380001,
+    /// STATUS_VOLMGR_INCOMPLETE_DISK_MIGRATION
+    VolmgrIncompleteDiskMigration = 0x80380002,
+    /// STATUS_BCD_NOT_ALL_ENTRIES_IMPORTED
+    BcdNotAllEntriesImported = 0x80390001,
+    /// STATUS_BCD_NOT_ALL_ENTRIES_SYNCHRONIZED
+    BcdNotAllEntriesSynchronized = 0x80390003,
+    /// STATUS_QUERY_STORAGE_ERROR
+    QueryStorageError = 0x803A0001,
+    /// STATUS_GDI_HANDLE_LEAK
+    GdiHandleLeak = 0x803F0001,
+    /// STATUS_SECUREBOOT_NOT_ENABLED
+    SecurebootNotEnabled = 0x80430006,
+    /// STATUS_UNSUCCESSFUL
+    Unsuccessful = 0xC0000001,
+    /// STATUS_NOT_IMPLEMENTED
+    NotImplemented = 0xC0000002,
+    /// STATUS_INVALID_INFO_CLASS
+    InvalidInfoClass = 0xC0000003,
+    /// STATUS_INFO_LENGTH_MISMATCH
+    InfoLengthMismatch = 0xC0000004,
+    /// STATUS_ACCESS_VIOLATION
+    AccessViolation = 0xC0000005,
+    /// STATUS_IN_PAGE_ERROR
+    InPageError = 0xC0000006,
+    /// STATUS_PAGEFILE_QUOTA
+    PagefileQuota = 0xC0000007,
+    /// STATUS_INVALID_HANDLE
+    InvalidHandle = 0xC0000008,
+    /// STATUS_BAD_INITIAL_STACK
+    BadInitialStack = 0xC0000009,
+    /// STATUS_BAD_INITIAL_PC
+    BadInitialPc = 0xC000000A,
+    /// STATUS_INVALID_CID
+    InvalidCid = 0xC000000B,
+    /// STATUS_TIMER_NOT_CANCELED
+    TimerNotCanceled = 0xC000000C,
+    /// STATUS_INVALID_PARAMETER
+    InvalidParameter = 0xC000000D,
+    /// STATUS_NO_SUCH_DEVICE
+    NoSuchDevice = 0xC000000E,
+    /// STATUS_NO_SUCH_FILE
+    NoSuchFile = 0xC000000F,
+    /// STATUS_INVALID_DEVICE_REQUEST
+    InvalidDeviceRequest = 0xC0000010,
+    /// STATUS_END_OF_FILE
+    EndOfFile = 0xC0000011,
+    /// STATUS_WRONG_VOLUME
+    WrongVolume = 0xC0000012,
+    /// STATUS_NO_MEDIA_IN_DEVICE
+    NoMediaInDevice = 0xC0000013,
+    /// STATUS_UNRECOGNIZED_MEDIA
+    UnrecognizedMedia = 0xC0000014,
+    /// STATUS_NONEXISTENT_SECTOR
+    NonexistentSector = 0xC0000015,
+    /// STATUS_MORE_PROCESSING_REQUIRED
+    MoreProcessingRequired = 0xC0000016,
+    /// STATUS_NO_MEMORY
+    NoMemory = 0xC0000017,
+    /// STATUS_CONFLICTING_ADDRESSES
+    ConflictingAddresses = 0xC0000018,
+    /// STATUS_NOT_MAPPED_VIEW
+    NotMappedView = 0xC0000019,
+    /// STATUS_UNABLE_TO_FREE_VM
+    UnableToFreeVm = 0xC000001A,
+    /// STATUS_UNABLE_TO_DELETE_SECTION
+    UnableToDeleteSection = 0xC000001B,
+    /// STATUS_INVALID_SYSTEM_SERVICE
+    InvalidSystemService = 0xC000001C,
+    /// STATUS_ILLEGAL_INSTRUCTION
+    IllegalInstruction = 0xC000001D,
+    /// STATUS_INVALID_LOCK_SEQUENCE
+    InvalidLockSequence = 0xC000001E,
+    /// STATUS_INVALID_VIEW_SIZE
+    InvalidViewSize = 0xC000001F,
+    /// STATUS_INVALID_FILE_FOR_SECTION
+    InvalidFileForSection = 0xC0000020,
+    /// STATUS_ALREADY_COMMITTED
+    AlreadyCommitted = 0xC0000021,
+    /// STATUS_ACCESS_DENIED
+    AccessDenied = 0xC0000022,
+    /// STATUS_BUFFER_TOO_SMALL
+    BufferTooSmall = 0xC0000023,
+    /// STATUS_OBJECT_TYPE_MISMATCH
+    ObjectTypeMismatch = 0xC0000024,
+    /// STATUS_NONCONTINUABLE_EXCEPTION
+    NoncontinuableException = 0xC0000025,
+    /// STATUS_INVALID_DISPOSITION
+    InvalidDisposition = 0xC0000026,
+    /// STATUS_UNWIND
+    Unwind = 0xC0000027,
+    /// STATUS_BAD_STACK
+    BadStack = 0xC0000028,
+    /// STATUS_INVALID_UNWIND_TARGET
+    InvalidUnwindTarget = 0xC0000029,
+    /// STATUS_NOT_LOCKED
+    NotLocked = 0xC000002A,
+    /// STATUS_PARITY_ERROR
+    ParityError = 0xC000002B,
+    /// STATUS_UNABLE_TO_DECOMMIT_VM
+    UnableToDecommitVm = 0xC000002C,
+    /// STATUS_NOT_COMMITTED
+    NotCommitted = 0xC000002D,
+    /// STATUS_INVALID_PORT_ATTRIBUTES
+    InvalidPortAttributes = 0xC000002E,
+    /// STATUS_PORT_MESSAGE_TOO_LONG
+    PortMessageTooLong = 0xC000002F,
+    /// STATUS_INVALID_PARAMETER_MIX
+    InvalidParameterMix = 0xC0000030,
+    /// STATUS_INVALID_QUOTA_LOWER
+    InvalidQuotaLower = 0xC0000031,
+    /// STATUS_DISK_CORRUPT_ERROR
+    DiskCorruptError = 0xC0000032,
+    /// STATUS_OBJECT_NAME_INVALID
+    ObjectNameInvalid = 0xC0000033,
+    /// STATUS_OBJECT_NAME_NOT_FOUND
+    ObjectNameNotFound = 0xC0000034,
+    /// STATUS_OBJECT_NAME_COLLISION
+    ObjectNameCollision = 0xC0000035,
+    /// STATUS_PORT_DO_NOT_DISTURB
+    PortDoNotDisturb = 0xC0000036,
+    /// STATUS_PORT_DISCONNECTED
+    PortDisconnected = 0xC0000037,
+    /// STATUS_DEVICE_ALREADY_ATTACHED
+    DeviceAlreadyAttached = 0xC0000038,
+    /// STATUS_OBJECT_PATH_INVALID
+    ObjectPathInvalid = 0xC0000039,
+    /// STATUS_OBJECT_PATH_NOT_FOUND
+    ObjectPathNotFound = 0xC000003A,
+    /// STATUS_OBJECT_PATH_SYNTAX_BAD
+    ObjectPathSyntaxBad = 0xC000003B,
+    /// STATUS_DATA_OVERRUN
+    DataOverrun = 0xC000003C,
+    /// STATUS_DATA_LATE_ERROR
+    DataLateError = 0xC000003D,
+    /// STATUS_DATA_ERROR
+    DataError = 0xC000003E,
+    /// STATUS_CRC_ERROR
+    CrcError = 0xC000003F,
+    /// STATUS_SECTION_TOO_BIG
+    SectionTooBig = 0xC0000040,
+    /// STATUS_PORT_CONNECTION_REFUSED
+    PortConnectionRefused = 0xC0000041,
+    /// STATUS_INVALID_PORT_HANDLE
+    InvalidPortHandle = 0xC0000042,
+    /// STATUS_SHARING_VIOLATION
+    SharingViolation = 0xC0000043,
+    /// STATUS_QUOTA_EXCEEDED
+    QuotaExceeded = 0xC0000044,
+    /// STATUS_INVALID_PAGE_PROTECTION
+    InvalidPageProtection = 0xC0000045,
+    /// STATUS_MUTANT_NOT_OWNED
+    MutantNotOwned = 0xC0000046,
+    /// STATUS_SEMAPHORE_LIMIT_EXCEEDED
+    SemaphoreLimitExceeded = 0xC0000047,
+    /// STATUS_PORT_ALREADY_SET
+    PortAlreadySet = 0xC0000048,
+    /// STATUS_SECTION_NOT_IMAGE
+    SectionNotImage = 0xC0000049,
+    /// STATUS_SUSPEND_COUNT_EXCEEDED
+    SuspendCountExceeded = 0xC000004A,
+    /// STATUS_THREAD_IS_TERMINATING
+    ThreadIsTerminating = 0xC000004B,
+    /// STATUS_BAD_WORKING_SET_LIMIT
+    BadWorkingSetLimit = 0xC000004C,
+    /// STATUS_INCOMPATIBLE_FILE_MAP
+    IncompatibleFileMap = 0xC000004D,
+    /// STATUS_SECTION_PROTECTION
+    SectionProtection = 0xC000004E,
+    /// STATUS_EAS_NOT_SUPPORTED
+    EasNotSupported = 0xC000004F,
+    /// STATUS_EA_TOO_LARGE
+    EaTooLarge = 0xC0000050,
+    /// STATUS_NONEXISTENT_EA_ENTRY
+    NonexistentEaEntry = 0xC0000051,
+    /// STATUS_NO_EAS_ON_FILE
+    NoEasOnFile = 0xC0000052,
+    /// STATUS_EA_CORRUPT_ERROR
+    EaCorruptError = 0xC0000053,
+    /// STATUS_FILE_LOCK_CONFLICT
+    FileLockConflict = 0xC0000054,
+    /// STATUS_LOCK_NOT_GRANTED
+    LockNotGranted = 0xC0000055,
+    /// STATUS_DELETE_PENDING
+    DeletePending = 0xC0000056,
+    /// STATUS_CTL_FILE_NOT_SUPPORTED
+    CtlFileNotSupported = 0xC0000057,
+    /// STATUS_UNKNOWN_REVISION
+    UnknownRevision = 0xC0000058,
+    /// STATUS_REVISION_MISMATCH
+    RevisionMismatch = 0xC0000059,
+    /// STATUS_INVALID_OWNER
+    InvalidOwner = 0xC000005A,
+    /// STATUS_INVALID_PRIMARY_GROUP
+    InvalidPrimaryGroup = 0xC000005B,
+    /// STATUS_NO_IMPERSONATION_TOKEN
+    NoImpersonationToken = 0xC000005C,
+    /// STATUS_CANT_DISABLE_MANDATORY
+    CantDisableMandatory = 0xC000005D,
+    /// STATUS_NO_LOGON_SERVERS
+    NoLogonServers = 0xC000005E,
+    /// STATUS_NO_SUCH_LOGON_SESSION
+    NoSuchLogonSession = 0xC000005F,
+    /// STATUS_NO_SUCH_PRIVILEGE
+    NoSuchPrivilege = 0xC0000060,
+    /// STATUS_PRIVILEGE_NOT_HELD
+    PrivilegeNotHeld = 0xC0000061,
+    /// STATUS_INVALID_ACCOUNT_NAME
+    InvalidAccountName = 0xC0000062,
+    /// STATUS_USER_EXISTS
+    UserExists = 0xC0000063,
+    /// STATUS_NO_SUCH_USER
+    NoSuchUser = 0xC0000064,
+    /// STATUS_GROUP_EXISTS
+    GroupExists = 0xC0000065,
+    /// STATUS_NO_SUCH_GROUP
+    NoSuchGroup = 0xC0000066,
+    /// STATUS_MEMBER_IN_GROUP
+    MemberInGroup = 0xC0000067,
+    /// STATUS_MEMBER_NOT_IN_GROUP
+    MemberNotInGroup = 0xC0000068,
+    /// STATUS_LAST_ADMIN
+    LastAdmin = 0xC0000069,
+    /// STATUS_WRONG_PASSWORD
+    WrongPassword = 0xC000006A,
+    /// STATUS_ILL_FORMED_PASSWORD
+    IllFormedPassword = 0xC000006B,
+    /// STATUS_PASSWORD_RESTRICTION
+    PasswordRestriction = 0xC000006C,
+    /// STATUS_LOGON_FAILURE
+    LogonFailure = 0xC000006D,
+    /// STATUS_ACCOUNT_RESTRICTION
+    AccountRestriction = 0xC000006E,
+    /// STATUS_INVALID_LOGON_HOURS
+    InvalidLogonHours = 0xC000006F,
+    /// STATUS_INVALID_WORKSTATION
+    InvalidWorkstation = 0xC0000070,
+    /// STATUS_PASSWORD_EXPIRED
+    PasswordExpired = 0xC0000071,
+    /// STATUS_ACCOUNT_DISABLED
+    AccountDisabled = 0xC0000072,
+    /// STATUS_NONE_MAPPED
+    NoneMapped = 0xC0000073,
+    /// STATUS_TOO_MANY_LUIDS_REQUESTED
+    TooManyLuidsRequested = 0xC0000074,
+    /// STATUS_LUIDS_EXHAUSTED
+    LuidsExhausted = 0xC0000075,
+    /// STATUS_INVALID_SUB_AUTHORITY
+    InvalidSubAuthority = 0xC0000076,
+    /// STATUS_INVALID_ACL
+    InvalidAcl = 0xC0000077,
+    /// STATUS_INVALID_SID
+    InvalidSid = 0xC0000078,
+    /// STATUS_INVALID_SECURITY_DESCR
+    InvalidSecurityDescr = 0xC0000079,
+    /// STATUS_PROCEDURE_NOT_FOUND
+    ProcedureNotFound = 0xC000007A,
+    /// STATUS_INVALID_IMAGE_FORMAT
+    InvalidImageFormat = 0xC000007B,
+    /// STATUS_NO_TOKEN
+    NoToken = 0xC000007C,
+    /// STATUS_BAD_INHERITANCE_ACL
+    BadInheritanceAcl = 0xC000007D,
+    /// STATUS_RANGE_NOT_LOCKED
+    RangeNotLocked = 0xC000007E,
+    /// STATUS_DISK_FULL
+    DiskFull = 0xC000007F,
+    /// STATUS_SERVER_DISABLED
+    ServerDisabled = 0xC0000080,
+    /// STATUS_SERVER_NOT_DISABLED
+    ServerNotDisabled = 0xC0000081,
+    /// STATUS_TOO_MANY_GUIDS_REQUESTED
+    TooManyGuidsRequested = 0xC0000082,
+    /// STATUS_GUIDS_EXHAUSTED
+    GuidsExhausted = 0xC0000083,
+    /// STATUS_INVALID_ID_AUTHORITY
+    InvalidIdAuthority = 0xC0000084,
+    /// STATUS_AGENTS_EXHAUSTED
+    AgentsExhausted = 0xC0000085,
+    /// STATUS_INVALID_VOLUME_LABEL
+    InvalidVolumeLabel = 0xC0000086,
+    /// STATUS_SECTION_NOT_EXTENDED
+    SectionNotExtended = 0xC0000087,
+    /// STATUS_NOT_MAPPED_DATA
+    NotMappedData = 0xC0000088,
+    /// STATUS_RESOURCE_DATA_NOT_FOUND
+    ResourceDataNotFound = 0xC0000089,
+    /// STATUS_RESOURCE_TYPE_NOT_FOUND
+    ResourceTypeNotFound = 0xC000008A,
+    /// STATUS_RESOURCE_NAME_NOT_FOUND
+    ResourceNameNotFound = 0xC000008B,
+    /// STATUS_ARRAY_BOUNDS_EXCEEDED
+    ArrayBoundsExceeded = 0xC000008C,
+    /// STATUS_FLOAT_DENORMAL_OPERAND
+    FloatDenormalOperand = 0xC000008D,
+    /// STATUS_FLOAT_DIVIDE_BY_ZERO
+    FloatDivideByZero = 0xC000008E,
+    /// STATUS_FLOAT_INEXACT_RESULT
+    FloatInexactResult = 0xC000008F,
+    /// STATUS_FLOAT_INVALID_OPERATION
+    FloatInvalidOperation = 0xC0000090,
+    /// STATUS_FLOAT_OVERFLOW
+    FloatOverflow = 0xC0000091,
+    /// STATUS_FLOAT_STACK_CHECK
+    FloatStackCheck = 0xC0000092,
+    /// STATUS_FLOAT_UNDERFLOW
+    FloatUnderflow = 0xC0000093,
+    /// STATUS_INTEGER_DIVIDE_BY_ZERO
+    IntegerDivideByZero = 0xC0000094,
+    /// STATUS_INTEGER_OVERFLOW
+    IntegerOverflow = 0xC0000095,
+    /// STATUS_PRIVILEGED_INSTRUCTION
+    PrivilegedInstruction = 0xC0000096,
+    /// STATUS_TOO_MANY_PAGING_FILES
+    TooManyPagingFiles = 0xC0000097,
+    /// STATUS_FILE_INVALID
+    FileInvalid = 0xC0000098,
+    /// STATUS_ALLOTTED_SPACE_EXCEEDED
+    AllottedSpaceExceeded = 0xC0000099,
+    /// STATUS_INSUFFICIENT_RESOURCES
+    InsufficientResources = 0xC000009A,
+    /// STATUS_DFS_EXIT_PATH_FOUND
+    DfsExitPathFound = 0xC000009B,
+    /// STATUS_DEVICE_DATA_ERROR
+    DeviceDataError = 0xC000009C,
+    /// STATUS_DEVICE_NOT_CONNECTED
+    DeviceNotConnected = 0xC000009D,
+    /// STATUS_DEVICE_POWER_FAILURE
+    DevicePowerFailure = 0xC000009E,
+    /// STATUS_FREE_VM_NOT_AT_BASE
+    FreeVmNotAtBase = 0xC000009F,
+    /// STATUS_MEMORY_NOT_ALLOCATED
+    MemoryNotAllocated = 0xC00000A0,
+    /// STATUS_WORKING_SET_QUOTA
+    WorkingSetQuota = 0xC00000A1,
+    /// STATUS_MEDIA_WRITE_PROTECTED
+    MediaWriteProtected = 0xC00000A2,
+    /// STATUS_DEVICE_NOT_READY
+    DeviceNotReady = 0xC00000A3,
+    /// STATUS_INVALID_GROUP_ATTRIBUTES
+    InvalidGroupAttributes = 0xC00000A4,
+    /// STATUS_BAD_IMPERSONATION_LEVEL
+    BadImpersonationLevel = 0xC00000A5,
+    /// STATUS_CANT_OPEN_ANONYMOUS
+    CantOpenAnonymous = 0xC00000A6,
+    /// STATUS_BAD_VALIDATION_CLASS
+    BadValidationClass = 0xC00000A7,
+    /// STATUS_BAD_TOKEN_TYPE
+    BadTokenType = 0xC00000A8,
+    /// STATUS_BAD_MASTER_BOOT_RECORD
+    BadMasterBootRecord = 0xC00000A9,
+    /// STATUS_INSTRUCTION_MISALIGNMENT
+    InstructionMisalignment = 0xC00000AA,
+    /// STATUS_INSTANCE_NOT_AVAILABLE
+    InstanceNotAvailable = 0xC00000AB,
+    /// STATUS_PIPE_NOT_AVAILABLE
+    PipeNotAvailable = 0xC00000AC,
+    /// STATUS_INVALID_PIPE_STATE
+    InvalidPipeState = 0xC00000AD,
+    /// STATUS_PIPE_BUSY
+    PipeBusy = 0xC00000AE,
+    /// STATUS_ILLEGAL_FUNCTION
+    IllegalFunction = 0xC00000AF,
+    /// STATUS_PIPE_DISCONNECTED
+    PipeDisconnected = 0xC00000B0,
+    /// STATUS_PIPE_CLOSING
+    PipeClosing = 0xC00000B1,
+    /// STATUS_PIPE_CONNECTED
+    PipeConnected = 0xC00000B2,
+    /// STATUS_PIPE_LISTENING
+    PipeListening = 0xC00000B3,
+    /// STATUS_INVALID_READ_MODE
+    InvalidReadMode = 0xC00000B4,
+    /// STATUS_IO_TIMEOUT
+    IoTimeout = 0xC00000B5,
+    /// STATUS_FILE_FORCED_CLOSED
+    FileForcedClosed = 0xC00000B6,
+    /// STATUS_PROFILING_NOT_STARTED
+    ProfilingNotStarted = 0xC00000B7,
+    /// STATUS_PROFILING_NOT_STOPPED
+    ProfilingNotStopped = 0xC00000B8,
+    /// STATUS_COULD_NOT_INTERPRET
+    CouldNotInterpret = 0xC00000B9,
+    /// STATUS_FILE_IS_A_DIRECTORY
+    FileIsADirectory = 0xC00000BA,
+    /// STATUS_NOT_SUPPORTED
+    NotSupported = 0xC00000BB,
+    /// STATUS_REMOTE_NOT_LISTENING
+    RemoteNotListening = 0xC00000BC,
+    /// STATUS_DUPLICATE_NAME
+    DuplicateName = 0xC00000BD,
+    /// STATUS_BAD_NETWORK_PATH
+    BadNetworkPath = 0xC00000BE,
+    /// STATUS_NETWORK_BUSY
+    NetworkBusy = 0xC00000BF,
+    /// STATUS_DEVICE_DOES_NOT_EXIST
+    DeviceDoesNotExist = 0xC00000C0,
+    /// STATUS_TOO_MANY_COMMANDS
+    TooManyCommands = 0xC00000C1,
+    /// STATUS_ADAPTER_HARDWARE_ERROR
+    AdapterHardwareError = 0xC00000C2,
+    /// STATUS_INVALID_NETWORK_RESPONSE
+    InvalidNetworkResponse = 0xC00000C3,
+    /// STATUS_UNEXPECTED_NETWORK_ERROR
+    UnexpectedNetworkError = 0xC00000C4,
+    /// STATUS_BAD_REMOTE_ADAPTER
+    BadRemoteAdapter = 0xC00000C5,
+    /// STATUS_PRINT_QUEUE_FULL
+    PrintQueueFull = 0xC00000C6,
+    /// STATUS_NO_SPOOL_SPACE
+    NoSpoolSpace = 0xC00000C7,
+    /// STATUS_PRINT_CANCELLED
+    PrintCancelled = 0xC00000C8,
+    /// STATUS_NETWORK_NAME_DELETED
+    NetworkNameDeleted = 0xC00000C9,
+    /// STATUS_NETWORK_ACCESS_DENIED
+    NetworkAccessDenied = 0xC00000CA,
+    /// STATUS_BAD_DEVICE_TYPE
+    BadDeviceType = 0xC00000CB,
+    /// STATUS_BAD_NETWORK_NAME
+    BadNetworkName = 0xC00000CC,
+    /// STATUS_TOO_MANY_NAMES
+    TooManyNames = 0xC00000CD,
+    /// STATUS_TOO_MANY_SESSIONS
+    TooManySessions = 0xC00000CE,
+    /// STATUS_SHARING_PAUSED
+    SharingPaused = 0xC00000CF,
+    /// STATUS_REQUEST_NOT_ACCEPTED
+    RequestNotAccepted = 0xC00000D0,
+    /// STATUS_REDIRECTOR_PAUSED
+    RedirectorPaused = 0xC00000D1,
+    /// STATUS_NET_WRITE_FAULT
+    NetWriteFault = 0xC00000D2,
+    /// STATUS_PROFILING_AT_LIMIT
+    ProfilingAtLimit = 0xC00000D3,
+    /// STATUS_NOT_SAME_DEVICE
+    NotSameDevice = 0xC00000D4,
+    /// STATUS_FILE_RENAMED
+    FileRenamed = 0xC00000D5,
+    /// STATUS_VIRTUAL_CIRCUIT_CLOSED
+    VirtualCircuitClosed = 0xC00000D6,
+    /// STATUS_NO_SECURITY_ON_OBJECT
+    NoSecurityOnObject = 0xC00000D7,
+    /// STATUS_CANT_WAIT
+    CantWait = 0xC00000D8,
+    /// STATUS_PIPE_EMPTY
+    PipeEmpty = 0xC00000D9,
+    /// STATUS_CANT_ACCESS_DOMAIN_INFO
+    CantAccessDomainInfo = 0xC00000DA,
+    /// STATUS_CANT_TERMINATE_SELF
+    CantTerminateSelf = 0xC00000DB,
+    /// STATUS_INVALID_SERVER_STATE
+    InvalidServerState = 0xC00000DC,
+    /// STATUS_INVALID_DOMAIN_STATE
+    InvalidDomainState = 0xC00000DD,
+    /// STATUS_INVALID_DOMAIN_ROLE
+    InvalidDomainRole = 0xC00000DE,
+    /// STATUS_NO_SUCH_DOMAIN
+    NoSuchDomain = 0xC00000DF,
+    /// STATUS_DOMAIN_EXISTS
+    DomainExists = 0xC00000E0,
+    /// STATUS_DOMAIN_LIMIT_EXCEEDED
+    DomainLimitExceeded = 0xC00000E1,
+    /// STATUS_OPLOCK_NOT_GRANTED
+    OplockNotGranted = 0xC00000E2,
+    /// STATUS_INVALID_OPLOCK_PROTOCOL
+    InvalidOplockProtocol = 0xC00000E3,
+    /// STATUS_INTERNAL_DB_CORRUPTION
+    InternalDbCorruption = 0xC00000E4,
+    /// STATUS_INTERNAL_ERROR
+    InternalError = 0xC00000E5,
+    /// STATUS_GENERIC_NOT_MAPPED
+    GenericNotMapped = 0xC00000E6,
+    /// STATUS_BAD_DESCRIPTOR_FORMAT
+    BadDescriptorFormat = 0xC00000E7,
+    /// STATUS_INVALID_USER_BUFFER
+    InvalidUserBuffer = 0xC00000E8,
+    /// STATUS_UNEXPECTED_IO_ERROR
+    UnexpectedIoError = 0xC00000E9,
+    /// STATUS_UNEXPECTED_MM_CREATE_ERR
+    UnexpectedMmCreateErr = 0xC00000EA,
+    /// STATUS_UNEXPECTED_MM_MAP_ERROR
+    UnexpectedMmMapError = 0xC00000EB,
+    /// STATUS_UNEXPECTED_MM_EXTEND_ERR
+    UnexpectedMmExtendErr = 0xC00000EC,
+    /// STATUS_NOT_LOGON_PROCESS
+    NotLogonProcess = 0xC00000ED,
+    /// STATUS_LOGON_SESSION_EXISTS
+    LogonSessionExists = 0xC00000EE,
+    /// STATUS_INVALID_PARAMETER_1
+    InvalidParameter1 = 0xC00000EF,
+    /// STATUS_INVALID_PARAMETER_2
+    InvalidParameter2 = 0xC00000F0,
+    /// STATUS_INVALID_PARAMETER_3
+    InvalidParameter3 = 0xC00000F1,
+    /// STATUS_INVALID_PARAMETER_4
+    InvalidParameter4 = 0xC00000F2,
+    /// STATUS_INVALID_PARAMETER_5
+    InvalidParameter5 = 0xC00000F3,
+    /// STATUS_INVALID_PARAMETER_6
+    InvalidParameter6 = 0xC00000F4,
+    /// STATUS_INVALID_PARAMETER_7
+    InvalidParameter7 = 0xC00000F5,
+    /// STATUS_INVALID_PARAMETER_8
+    InvalidParameter8 = 0xC00000F6,
+    /// STATUS_INVALID_PARAMETER_9
+    InvalidParameter9 = 0xC00000F7,
+    /// STATUS_INVALID_PARAMETER_10
+    InvalidParameter10 = 0xC00000F8,
+    /// STATUS_INVALID_PARAMETER_11
+    InvalidParameter11 = 0xC00000F9,
+    /// STATUS_INVALID_PARAMETER_12
+    InvalidParameter12 = 0xC00000FA,
+    /// STATUS_REDIRECTOR_NOT_STARTED
+    RedirectorNotStarted = 0xC00000FB,
+    /// STATUS_REDIRECTOR_STARTED
+    RedirectorStarted = 0xC00000FC,
+    /// STATUS_STACK_OVERFLOW
+    StackOverflow = 0xC00000FD,
+    /// STATUS_NO_SUCH_PACKAGE
+    NoSuchPackage = 0xC00000FE,
+    /// STATUS_BAD_FUNCTION_TABLE
+    BadFunctionTable = 0xC00000FF,
+    /// STATUS_VARIABLE_NOT_FOUND
+    VariableNotFound = 0xC0000100,
+    /// STATUS_DIRECTORY_NOT_EMPTY
+    DirectoryNotEmpty = 0xC0000101,
+    /// STATUS_FILE_CORRUPT_ERROR
+    FileCorruptError = 0xC0000102,
+    /// STATUS_NOT_A_DIRECTORY
+    NotADirectory = 0xC0000103,
+    /// STATUS_BAD_LOGON_SESSION_STATE
+    BadLogonSessionState = 0xC0000104,
+    /// STATUS_LOGON_SESSION_COLLISION
+    LogonSessionCollision = 0xC0000105,
+    /// STATUS_NAME_TOO_LONG
+    NameTooLong = 0xC0000106,
+    /// STATUS_FILES_OPEN
+    FilesOpen = 0xC0000107,
+    /// STATUS_CONNECTION_IN_USE
+    ConnectionInUse = 0xC0000108,
+    /// STATUS_MESSAGE_NOT_FOUND
+    MessageNotFound = 0xC0000109,
+    /// STATUS_PROCESS_IS_TERMINATING
+    ProcessIsTerminating = 0xC000010A,
+    /// STATUS_INVALID_LOGON_TYPE
+    InvalidLogonType = 0xC000010B,
+    /// STATUS_NO_GUID_TRANSLATION
+    NoGuidTranslation = 0xC000010C,
+    /// STATUS_CANNOT_IMPERSONATE
+    CannotImpersonate = 0xC000010D,
+    /// STATUS_IMAGE_ALREADY_LOADED
+    ImageAlreadyLoaded = 0xC000010E,
+    /// STATUS_ABIOS_NOT_PRESENT
+    AbiosNotPresent = 0xC000010F,
+    /// STATUS_ABIOS_LID_NOT_EXIST
+    AbiosLidNotExist = 0xC0000110,
+    /// STATUS_ABIOS_LID_ALREADY_OWNED
+    AbiosLidAlreadyOwned = 0xC0000111,
+    /// STATUS_ABIOS_NOT_LID_OWNER
+    AbiosNotLidOwner = 0xC0000112,
+    /// STATUS_ABIOS_INVALID_COMMAND
+    AbiosInvalidCommand = 0xC0000113,
+    /// STATUS_ABIOS_INVALID_LID
+    AbiosInvalidLid = 0xC0000114,
+    /// STATUS_ABIOS_SELECTOR_NOT_AVAILABLE
+    AbiosSelectorNotAvailable = 0xC0000115,
+    /// STATUS_ABIOS_INVALID_SELECTOR
+    AbiosInvalidSelector = 0xC0000116,
+    /// STATUS_NO_LDT
+    NoLdt = 0xC0000117,
+    /// STATUS_INVALID_LDT_SIZE
+    InvalidLdtSize = 0xC0000118,
+    /// STATUS_INVALID_LDT_OFFSET
+    InvalidLdtOffset = 0xC0000119,
+    /// STATUS_INVALID_LDT_DESCRIPTOR
+    InvalidLdtDescriptor = 0xC000011A,
+    /// STATUS_INVALID_IMAGE_NE_FORMAT
+    InvalidImageNeFormat = 0xC000011B,
+    /// STATUS_RXACT_INVALID_STATE
+    RxactInvalidState = 0xC000011C,
+    /// STATUS_RXACT_COMMIT_FAILURE
+    RxactCommitFailure = 0xC000011D,
+    /// STATUS_MAPPED_FILE_SIZE_ZERO
+    MappedFileSizeZero = 0xC000011E,
+    /// STATUS_TOO_MANY_OPENED_FILES
+    TooManyOpenedFiles = 0xC000011F,
+    /// STATUS_CANCELLED
+    Cancelled = 0xC0000120,
+    /// STATUS_CANNOT_DELETE
+    CannotDelete = 0xC0000121,
+    /// STATUS_INVALID_COMPUTER_NAME
+    InvalidComputerName = 0xC0000122,
+    /// STATUS_FILE_DELETED
+    FileDeleted = 0xC0000123,
+    /// STATUS_SPECIAL_ACCOUNT
+    SpecialAccount = 0xC0000124,
+    /// STATUS_SPECIAL_GROUP
+    SpecialGroup = 0xC0000125,
+    /// STATUS_SPECIAL_USER
+    SpecialUser = 0xC0000126,
+    /// STATUS_MEMBERS_PRIMARY_GROUP
+    MembersPrimaryGroup = 0xC0000127,
+    /// STATUS_FILE_CLOSED
+    FileClosed = 0xC0000128,
+    /// STATUS_TOO_MANY_THREADS
+    TooManyThreads = 0xC0000129,
+    /// STATUS_THREAD_NOT_IN_PROCESS
+    ThreadNotInProcess = 0xC000012A,
+    /// STATUS_TOKEN_ALREADY_IN_USE
+    TokenAlreadyInUse = 0xC000012B,
+    /// STATUS_PAGEFILE_QUOTA_EXCEEDED
+    PagefileQuotaExceeded = 0xC000012C,
+    /// STATUS_COMMITMENT_LIMIT
+    CommitmentLimit = 0xC000012D,
+    /// STATUS_INVALID_IMAGE_LE_FORMAT
+    InvalidImageLeFormat = 0xC000012E,
+    /// STATUS_INVALID_IMAGE_NOT_MZ
+    InvalidImageNotMz = 0xC000012F,
+    /// STATUS_INVALID_IMAGE_PROTECT
+    InvalidImageProtect = 0xC0000130,
+    /// STATUS_INVALID_IMAGE_WIN_16
+    InvalidImageWin16 = 0xC0000131,
+    /// STATUS_LOGON_SERVER_CONFLICT
+    LogonServerConflict = 0xC0000132,
+    /// STATUS_TIME_DIFFERENCE_AT_DC
+    TimeDifferenceAtDc = 0xC0000133,
+    /// STATUS_SYNCHRONIZATION_REQUIRED
+    SynchronizationRequired = 0xC0000134,
+    /// STATUS_DLL_NOT_FOUND
+    DllNotFound = 0xC0000135,
+    /// STATUS_OPEN_FAILED
+    OpenFailed = 0xC0000136,
+    /// STATUS_IO_PRIVILEGE_FAILED
+    IoPrivilegeFailed = 0xC0000137,
+    /// STATUS_ORDINAL_NOT_FOUND
+    OrdinalNotFound = 0xC0000138,
+    /// STATUS_ENTRYPOINT_NOT_FOUND
+    EntrypointNotFound = 0xC0000139,
+    /// STATUS_CONTROL_C_EXIT
+    ControlCExit = 0xC000013A,
+    /// STATUS_LOCAL_DISCONNECT
+    LocalDisconnect = 0xC000013B,
+    /// STATUS_REMOTE_DISCONNECT
+    RemoteDisconnect = 0xC000013C,
+    /// STATUS_REMOTE_RESOURCES
+    RemoteResources = 0xC000013D,
+    /// STATUS_LINK_FAILED
+    LinkFailed = 0xC000013E,
+    /// STATUS_LINK_TIMEOUT
+    LinkTimeout = 0xC000013F,
+    /// STATUS_INVALID_CONNECTION
+    InvalidConnection = 0xC0000140,
+    /// STATUS_INVALID_ADDRESS
+    InvalidAddress = 0xC0000141,
+    /// STATUS_DLL_INIT_FAILED
+    DllInitFailed = 0xC0000142,
+    /// STATUS_MISSING_SYSTEMFILE
+    MissingSystemfile = 0xC0000143,
+    /// STATUS_UNHANDLED_EXCEPTION
+    UnhandledException = 0xC0000144,
+    /// STATUS_APP_INIT_FAILURE
+    AppInitFailure = 0xC0000145,
+    /// STATUS_PAGEFILE_CREATE_FAILED
+    PagefileCreateFailed = 0xC0000146,
+    /// STATUS_NO_PAGEFILE
+    NoPagefile = 0xC0000147,
+    /// STATUS_INVALID_LEVEL
+    InvalidLevel = 0xC0000148,
+    /// STATUS_WRONG_PASSWORD_CORE
+    WrongPasswordCore = 0xC0000149,
+    /// STATUS_ILLEGAL_FLOAT_CONTEXT
+    IllegalFloatContext = 0xC000014A,
+    /// STATUS_PIPE_BROKEN
+    PipeBroken = 0xC000014B,
+    /// STATUS_REGISTRY_CORRUPT
+    RegistryCorrupt = 0xC000014C,
+    /// STATUS_REGISTRY_IO_FAILED
+    RegistryIoFailed = 0xC000014D,
+    /// STATUS_NO_EVENT_PAIR
+    NoEventPair = 0xC000014E,
+    /// STATUS_UNRECOGNIZED_VOLUME
+    UnrecognizedVolume = 0xC000014F,
+    /// STATUS_SERIAL_NO_DEVICE_INITED
+    SerialNoDeviceInited = 0xC0000150,
+    /// STATUS_NO_SUCH_ALIAS
+    NoSuchAlias = 0xC0000151,
+    /// STATUS_MEMBER_NOT_IN_ALIAS
+    MemberNotInAlias = 0xC0000152,
+    /// STATUS_MEMBER_IN_ALIAS
+    MemberInAlias = 0xC0000153,
+    /// STATUS_ALIAS_EXISTS
+    AliasExists = 0xC0000154,
+    /// STATUS_LOGON_NOT_GRANTED
+    LogonNotGranted = 0xC0000155,
+    /// STATUS_TOO_MANY_SECRETS
+    TooManySecrets = 0xC0000156,
+    /// STATUS_SECRET_TOO_LONG
+    SecretTooLong = 0xC0000157,
+    /// STATUS_INTERNAL_DB_ERROR
+    InternalDbError = 0xC0000158,
+    /// STATUS_FULLSCREEN_MODE
+    FullscreenMode = 0xC0000159,
+    /// STATUS_TOO_MANY_CONTEXT_IDS
+    TooManyContextIds = 0xC000015A,
+    /// STATUS_LOGON_TYPE_NOT_GRANTED
+    LogonTypeNotGranted = 0xC000015B,
+    /// STATUS_NOT_REGISTRY_FILE
+    NotRegistryFile = 0xC000015C,
+    /// STATUS_NT_CROSS_ENCRYPTION_REQUIRED
+    NtCrossEncryptionRequired = 0xC000015D,
+    /// STATUS_DOMAIN_CTRLR_CONFIG_ERROR
+    DomainCtrlrConfigError = 0xC000015E,
+    /// STATUS_FT_MISSING_MEMBER
+    FtMissingMember = 0xC000015F,
+    /// STATUS_ILL_FORMED_SERVICE_ENTRY
+    IllFormedServiceEntry = 0xC0000160,
+    /// STATUS_ILLEGAL_CHARACTER
+    IllegalCharacter = 0xC0000161,
+    /// STATUS_UNMAPPABLE_CHARACTER
+    UnmappableCharacter = 0xC0000162,
+    /// STATUS_UNDEFINED_CHARACTER
+    UndefinedCharacter = 0xC0000163,
+    /// STATUS_FLOPPY_VOLUME
+    FloppyVolume = 0xC0000164,
+    /// STATUS_FLOPPY_ID_MARK_NOT_FOUND
+    FloppyIdMarkNotFound = 0xC0000165,
+    /// STATUS_FLOPPY_WRONG_CYLINDER
+    FloppyWrongCylinder = 0xC0000166,
+    /// STATUS_FLOPPY_UNKNOWN_ERROR
+    FloppyUnknownError = 0xC0000167,
+    /// STATUS_FLOPPY_BAD_REGISTERS
+    FloppyBadRegisters = 0xC0000168,
+    /// STATUS_DISK_RECALIBRATE_FAILED
+    DiskRecalibrateFailed = 0xC0000169,
+    /// STATUS_DISK_OPERATION_FAILED
+    DiskOperationFailed = 0xC000016A,
+    /// STATUS_DISK_RESET_FAILED
+    DiskResetFailed = 0xC000016B,
+    /// STATUS_SHARED_IRQ_BUSY
+    SharedIrqBusy = 0xC000016C,
+    /// STATUS_FT_ORPHANING
+    FtOrphaning = 0xC000016D,
+    /// STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT
+    BiosFailedToConnectInterrupt = 0xC000016E,
+    /// STATUS_PARTITION_FAILURE
+    PartitionFailure = 0xC0000172,
+    /// STATUS_INVALID_BLOCK_LENGTH
+    InvalidBlockLength = 0xC0000173,
+    /// STATUS_DEVICE_NOT_PARTITIONED
+    DeviceNotPartitioned = 0xC0000174,
+    /// STATUS_UNABLE_TO_LOCK_MEDIA
+    UnableToLockMedia = 0xC0000175,
+    /// STATUS_UNABLE_TO_UNLOAD_MEDIA
+    UnableToUnloadMedia = 0xC0000176,
+    /// STATUS_EOM_OVERFLOW
+    EomOverflow = 0xC0000177,
+    /// STATUS_NO_MEDIA
+    NoMedia = 0xC0000178,
+    /// STATUS_NO_SUCH_MEMBER
+    NoSuchMember = 0xC000017A,
+    /// STATUS_INVALID_MEMBER
+    InvalidMember = 0xC000017B,
+    /// STATUS_KEY_DELETED
+    KeyDeleted = 0xC000017C,
+    /// STATUS_NO_LOG_SPACE
+    NoLogSpace = 0xC000017D,
+    /// STATUS_TOO_MANY_SIDS
+    TooManySids = 0xC000017E,
+    /// STATUS_LM_CROSS_ENCRYPTION_REQUIRED
+    LmCrossEncryptionRequired = 0xC000017F,
+    /// STATUS_KEY_HAS_CHILDREN
+    KeyHasChildren = 0xC0000180,
+    /// STATUS_CHILD_MUST_BE_VOLATILE
+    ChildMustBeVolatile = 0xC0000181,
+    /// STATUS_DEVICE_CONFIGURATION_ERROR
+    DeviceConfigurationError = 0xC0000182,
+    /// STATUS_DRIVER_INTERNAL_ERROR
+    DriverInternalError = 0xC0000183,
+    /// STATUS_INVALID_DEVICE_STATE
+    InvalidDeviceState = 0xC0000184,
+    /// STATUS_IO_DEVICE_ERROR
+    IoDeviceError = 0xC0000185,
+    /// STATUS_DEVICE_PROTOCOL_ERROR
+    DeviceProtocolError = 0xC0000186,
+    /// STATUS_BACKUP_CONTROLLER
+    BackupController = 0xC0000187,
+    /// STATUS_LOG_FILE_FULL
+    LogFileFull = 0xC0000188,
+    /// STATUS_TOO_LATE
+    TooLate = 0xC0000189,
+    /// STATUS_NO_TRUST_LSA_SECRET
+    NoTrustLsaSecret = 0xC000018A,
+    /// STATUS_NO_TRUST_SAM_ACCOUNT
+    NoTrustSamAccount = 0xC000018B,
+    /// STATUS_TRUSTED_DOMAIN_FAILURE
+    TrustedDomainFailure = 0xC000018C,
+    /// STATUS_TRUSTED_RELATIONSHIP_FAILURE
+    TrustedRelationshipFailure = 0xC000018D,
+    /// STATUS_EVENTLOG_FILE_CORRUPT
+    EventlogFileCorrupt = 0xC000018E,
+    /// STATUS_EVENTLOG_CANT_START
+    EventlogCantStart = 0xC000018F,
+    /// STATUS_TRUST_FAILURE
+    TrustFailure = 0xC0000190,
+    /// STATUS_MUTANT_LIMIT_EXCEEDED
+    MutantLimitExceeded = 0xC0000191,
+    /// STATUS_NETLOGON_NOT_STARTED
+    NetlogonNotStarted = 0xC0000192,
+    /// STATUS_ACCOUNT_EXPIRED
+    AccountExpired = 0xC0000193,
+    /// STATUS_POSSIBLE_DEADLOCK
+    PossibleDeadlock = 0xC0000194,
+    /// STATUS_NETWORK_CREDENTIAL_CONFLICT
+    NetworkCredentialConflict = 0xC0000195,
+    /// STATUS_REMOTE_SESSION_LIMIT
+    RemoteSessionLimit = 0xC0000196,
+    /// STATUS_EVENTLOG_FILE_CHANGED
+    EventlogFileChanged = 0xC0000197,
+    /// STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT
+    NologonInterdomainTrustAccount = 0xC0000198,
+    /// STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT
+    NologonWorkstationTrustAccount = 0xC0000199,
+    /// STATUS_NOLOGON_SERVER_TRUST_ACCOUNT
+    NologonServerTrustAccount = 0xC000019A,
+    /// STATUS_DOMAIN_TRUST_INCONSISTENT
+    DomainTrustInconsistent = 0xC000019B,
+    /// STATUS_FS_DRIVER_REQUIRED
+    FsDriverRequired = 0xC000019C,
+    /// STATUS_IMAGE_ALREADY_LOADED_AS_DLL
+    ImageAlreadyLoadedAsDll = 0xC000019D,
+    /// STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING
+    IncompatibleWithGlobalShortNameRegistrySetting = 0xC000019E,
+    /// STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME
+    ShortNamesNotEnabledOnVolume = 0xC000019F,
+    /// STATUS_SECURITY_STREAM_IS_INCONSISTENT
+    SecurityStreamIsInconsistent = 0xC00001A0,
+    /// STATUS_INVALID_LOCK_RANGE
+    InvalidLockRange = 0xC00001A1,
+    /// STATUS_INVALID_ACE_CONDITION
+    InvalidAceCondition = 0xC00001A2,
+    /// STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT
+    ImageSubsystemNotPresent = 0xC00001A3,
+    /// STATUS_NOTIFICATION_GUID_ALREADY_DEFINED
+    NotificationGuidAlreadyDefined = 0xC00001A4,
+    /// STATUS_INVALID_EXCEPTION_HANDLER
+    InvalidExceptionHandler = 0xC00001A5,
+    /// STATUS_DUPLICATE_PRIVILEGES
+    DuplicatePrivileges = 0xC00001A6,
+    /// STATUS_NOT_ALLOWED_ON_SYSTEM_FILE
+    NotAllowedOnSystemFile = 0xC00001A7,
+    /// STATUS_REPAIR_NEEDED
+    RepairNeeded = 0xC00001A8,
+    /// STATUS_QUOTA_NOT_ENABLED
+    QuotaNotEnabled = 0xC00001A9,
+    /// STATUS_NO_APPLICATION_PACKAGE
+    NoApplicationPackage = 0xC00001AA,
+    /// STATUS_FILE_METADATA_OPTIMIZATION_IN_PROGRESS
+    FileMetadataOptimizationInProgress = 0xC00001AB,
+    /// STATUS_NOT_SAME_OBJECT
+    NotSameObject = 0xC00001AC,
+    /// STATUS_FATAL_MEMORY_EXHAUSTION
+    FatalMemoryExhaustion = 0xC00001AD,
+    /// STATUS_ERROR_PROCESS_NOT_IN_JOB
+    ErrorProcessNotInJob = 0xC00001AE,
+    /// STATUS_CPU_SET_INVALID
+    CpuSetInvalid = 0xC00001AF,
+    /// STATUS_IO_DEVICE_INVALID_DATA
+    IoDeviceInvalidData = 0xC00001B0,
+    /// STATUS_IO_UNALIGNED_WRITE
+    IoUnalignedWrite = 0xC00001B1,
+    /// STATUS_CONTROL_STACK_VIOLATION
+    ControlStackViolation = 0xC00001B2,
+    /// STATUS_WEAK_WHFBKEY_BLOCKED
+    WeakWhfbkeyBlocked = 0xC00001B3,
+    /// STATUS_SERVER_TRANSPORT_CONFLICT
+    ServerTransportConflict = 0xC00001B4,
+    /// STATUS_CERTIFICATE_VALIDATION_PREFERENCE_CONFLICT
+    CertificateValidationPreferenceConflict = 0xC00001B5,
+    /// STATUS_NETWORK_OPEN_RESTRICTION
+    NetworkOpenRestriction = 0xC0000201,
+    /// STATUS_NO_USER_SESSION_KEY
+    NoUserSessionKey = 0xC0000202,
+    /// STATUS_USER_SESSION_DELETED
+    UserSessionDeleted = 0xC0000203,
+    /// STATUS_RESOURCE_LANG_NOT_FOUND
+    ResourceLangNotFound = 0xC0000204,
+    /// STATUS_INSUFF_SERVER_RESOURCES
+    InsuffServerResources = 0xC0000205,
+    /// STATUS_INVALID_BUFFER_SIZE
+    InvalidBufferSize = 0xC0000206,
+    /// STATUS_INVALID_ADDRESS_COMPONENT
+    InvalidAddressComponent = 0xC0000207,
+    /// STATUS_INVALID_ADDRESS_WILDCARD
+    InvalidAddressWildcard = 0xC0000208,
+    /// STATUS_TOO_MANY_ADDRESSES
+    TooManyAddresses = 0xC0000209,
+    /// STATUS_ADDRESS_ALREADY_EXISTS
+    AddressAlreadyExists = 0xC000020A,
+    /// STATUS_ADDRESS_CLOSED
+    AddressClosed = 0xC000020B,
+    /// STATUS_CONNECTION_DISCONNECTED
+    ConnectionDisconnected = 0xC000020C,
+    /// STATUS_CONNECTION_RESET
+    ConnectionReset = 0xC000020D,
+    /// STATUS_TOO_MANY_NODES
+    TooManyNodes = 0xC000020E,
+    /// STATUS_TRANSACTION_ABORTED
+    TransactionAborted = 0xC000020F,
+    /// STATUS_TRANSACTION_TIMED_OUT
+    TransactionTimedOut = 0xC0000210,
+    /// STATUS_TRANSACTION_NO_RELEASE
+    TransactionNoRelease = 0xC0000211,
+    /// STATUS_TRANSACTION_NO_MATCH
+    TransactionNoMatch = 0xC0000212,
+    /// STATUS_TRANSACTION_RESPONDED
+    TransactionResponded = 0xC0000213,
+    /// STATUS_TRANSACTION_INVALID_ID
+    TransactionInvalidId = 0xC0000214,
+    /// STATUS_TRANSACTION_INVALID_TYPE
+    TransactionInvalidType = 0xC0000215,
+    /// STATUS_NOT_SERVER_SESSION
+    NotServerSession = 0xC0000216,
+    /// STATUS_NOT_CLIENT_SESSION
+    NotClientSession = 0xC0000217,
+    /// STATUS_CANNOT_LOAD_REGISTRY_FILE
+    CannotLoadRegistryFile = 0xC0000218,
+    /// STATUS_DEBUG_ATTACH_FAILED
+    DebugAttachFailed = 0xC0000219,
+    /// STATUS_SYSTEM_PROCESS_TERMINATED
+    SystemProcessTerminated = 0xC000021A,
+    /// STATUS_DATA_NOT_ACCEPTED
+    DataNotAccepted = 0xC000021B,
+    /// STATUS_NO_BROWSER_SERVERS_FOUND
+    NoBrowserServersFound = 0xC000021C,
+    /// STATUS_VDM_HARD_ERROR
+    VdmHardError = 0xC000021D,
+    /// STATUS_DRIVER_CANCEL_TIMEOUT
+    DriverCancelTimeout = 0xC000021E,
+    /// STATUS_REPLY_MESSAGE_MISMATCH
+    ReplyMessageMismatch = 0xC000021F,
+    /// STATUS_MAPPED_ALIGNMENT
+    MappedAlignment = 0xC0000220,
+    /// STATUS_IMAGE_CHECKSUM_MISMATCH
+    ImageChecksumMismatch = 0xC0000221,
+    /// STATUS_LOST_WRITEBEHIND_DATA
+    LostWritebehindData = 0xC0000222,
+    /// STATUS_CLIENT_SERVER_PARAMETERS_INVALID
+    ClientServerParametersInvalid = 0xC0000223,
+    /// STATUS_PASSWORD_MUST_CHANGE
+    PasswordMustChange = 0xC0000224,
+    /// STATUS_NOT_FOUND
+    NotFound = 0xC0000225,
+    /// STATUS_NOT_TINY_STREAM
+    NotTinyStream = 0xC0000226,
+    /// STATUS_RECOVERY_FAILURE
+    RecoveryFailure = 0xC0000227,
+    /// STATUS_STACK_OVERFLOW_READ
+    StackOverflowRead = 0xC0000228,
+    /// STATUS_FAIL_CHECK
+    FailCheck = 0xC0000229,
+    /// STATUS_DUPLICATE_OBJECTID
+    DuplicateObjectid = 0xC000022A,
+    /// STATUS_OBJECTID_EXISTS
+    ObjectidExists = 0xC000022B,
+    /// STATUS_CONVERT_TO_LARGE
+    ConvertToLarge = 0xC000022C,
+    /// STATUS_RETRY
+    Retry = 0xC000022D,
+    /// STATUS_FOUND_OUT_OF_SCOPE
+    FoundOutOfScope = 0xC000022E,
+    /// STATUS_ALLOCATE_BUCKET
+    AllocateBucket = 0xC000022F,
+    /// STATUS_PROPSET_NOT_FOUND
+    PropsetNotFound = 0xC0000230,
+    /// STATUS_MARSHALL_OVERFLOW
+    MarshallOverflow = 0xC0000231,
+    /// STATUS_INVALID_VARIANT
+    InvalidVariant = 0xC0000232,
+    /// STATUS_DOMAIN_CONTROLLER_NOT_FOUND
+    DomainControllerNotFound = 0xC0000233,
+    /// STATUS_ACCOUNT_LOCKED_OUT
+    AccountLockedOut = 0xC0000234,
+    /// STATUS_HANDLE_NOT_CLOSABLE
+    HandleNotClosable = 0xC0000235,
+    /// STATUS_CONNECTION_REFUSED
+    ConnectionRefused = 0xC0000236,
+    /// STATUS_GRACEFUL_DISCONNECT
+    GracefulDisconnect = 0xC0000237,
+    /// STATUS_ADDRESS_ALREADY_ASSOCIATED
+    AddressAlreadyAssociated = 0xC0000238,
+    /// STATUS_ADDRESS_NOT_ASSOCIATED
+    AddressNotAssociated = 0xC0000239,
+    /// STATUS_CONNECTION_INVALID
+    ConnectionInvalid = 0xC000023A,
+    /// STATUS_CONNECTION_ACTIVE
+    ConnectionActive = 0xC000023B,
+    /// STATUS_NETWORK_UNREACHABLE
+    NetworkUnreachable = 0xC000023C,
+    /// STATUS_HOST_UNREACHABLE
+    HostUnreachable = 0xC000023D,
+    /// STATUS_PROTOCOL_UNREACHABLE
+    ProtocolUnreachable = 0xC000023E,
+    /// STATUS_PORT_UNREACHABLE
+    PortUnreachable = 0xC000023F,
+    /// STATUS_REQUEST_ABORTED
+    RequestAborted = 0xC0000240,
+    /// STATUS_CONNECTION_ABORTED
+    ConnectionAborted = 0xC0000241,
+    /// STATUS_BAD_COMPRESSION_BUFFER
+    BadCompressionBuffer = 0xC0000242,
+    /// STATUS_USER_MAPPED_FILE
+    UserMappedFile = 0xC0000243,
+    /// STATUS_AUDIT_FAILED
+    AuditFailed = 0xC0000244,
+    /// STATUS_TIMER_RESOLUTION_NOT_SET
+    TimerResolutionNotSet = 0xC0000245,
+    /// STATUS_CONNECTION_COUNT_LIMIT
+    ConnectionCountLimit = 0xC0000246,
+    /// STATUS_LOGIN_TIME_RESTRICTION
+    LoginTimeRestriction = 0xC0000247,
+    /// STATUS_LOGIN_WKSTA_RESTRICTION
+    LoginWkstaRestriction = 0xC0000248,
+    /// STATUS_IMAGE_MP_UP_MISMATCH
+    ImageMpUpMismatch = 0xC0000249,
+    /// STATUS_INSUFFICIENT_LOGON_INFO
+    InsufficientLogonInfo = 0xC0000250,
+    /// STATUS_BAD_DLL_ENTRYPOINT
+    BadDllEntrypoint = 0xC0000251,
+    /// STATUS_BAD_SERVICE_ENTRYPOINT
+    BadServiceEntrypoint = 0xC0000252,
+    /// STATUS_LPC_REPLY_LOST
+    LpcReplyLost = 0xC0000253,
+    /// STATUS_IP_ADDRESS_CONFLICT1
+    IpAddressConflict1 = 0xC0000254,
+    /// STATUS_IP_ADDRESS_CONFLICT2
+    IpAddressConflict2 = 0xC0000255,
+    /// STATUS_REGISTRY_QUOTA_LIMIT
+    RegistryQuotaLimit = 0xC0000256,
+    /// STATUS_PATH_NOT_COVERED
+    PathNotCovered = 0xC0000257,
+    /// STATUS_NO_CALLBACK_ACTIVE
+    NoCallbackActive = 0xC0000258,
+    /// STATUS_LICENSE_QUOTA_EXCEEDED
+    LicenseQuotaExceeded = 0xC0000259,
+    /// STATUS_PWD_TOO_SHORT
+    PwdTooShort = 0xC000025A,
+    /// STATUS_PWD_TOO_RECENT
+    PwdTooRecent = 0xC000025B,
+    /// STATUS_PWD_HISTORY_CONFLICT
+    PwdHistoryConflict = 0xC000025C,
+    /// STATUS_PLUGPLAY_NO_DEVICE
+    PlugplayNoDevice = 0xC000025E,
+    /// STATUS_UNSUPPORTED_COMPRESSION
+    UnsupportedCompression = 0xC000025F,
+    /// STATUS_INVALID_HW_PROFILE
+    InvalidHwProfile = 0xC0000260,
+    /// STATUS_INVALID_PLUGPLAY_DEVICE_PATH
+    InvalidPlugplayDevicePath = 0xC0000261,
+    /// STATUS_DRIVER_ORDINAL_NOT_FOUND
+    DriverOrdinalNotFound = 0xC0000262,
+    /// STATUS_DRIVER_ENTRYPOINT_NOT_FOUND
+    DriverEntrypointNotFound = 0xC0000263,
+    /// STATUS_RESOURCE_NOT_OWNED
+    ResourceNotOwned = 0xC0000264,
+    /// STATUS_TOO_MANY_LINKS
+    TooManyLinks = 0xC0000265,
+    /// STATUS_QUOTA_LIST_INCONSISTENT
+    QuotaListInconsistent = 0xC0000266,
+    /// STATUS_FILE_IS_OFFLINE
+    FileIsOffline = 0xC0000267,
+    /// STATUS_EVALUATION_EXPIRATION
+    EvaluationExpiration = 0xC0000268,
+    /// STATUS_ILLEGAL_DLL_RELOCATION
+    IllegalDllRelocation = 0xC0000269,
+    /// STATUS_LICENSE_VIOLATION
+    LicenseViolation = 0xC000026A,
+    /// STATUS_DLL_INIT_FAILED_LOGOFF
+    DllInitFailedLogoff = 0xC000026B,
+    /// STATUS_DRIVER_UNABLE_TO_LOAD
+    DriverUnableToLoad = 0xC000026C,
+    /// STATUS_DFS_UNAVAILABLE
+    DfsUnavailable = 0xC000026D,
+    /// STATUS_VOLUME_DISMOUNTED
+    VolumeDismounted = 0xC000026E,
+    /// STATUS_WX86_INTERNAL_ERROR
+    Wx86InternalError = 0xC000026F,
+    /// STATUS_WX86_FLOAT_STACK_CHECK
+    Wx86FloatStackCheck = 0xC0000270,
+    /// STATUS_VALIDATE_CONTINUE
+    ValidateContinue = 0xC0000271,
+    /// STATUS_NO_MATCH
+    NoMatch = 0xC0000272,
+    /// STATUS_NO_MORE_MATCHES
+    NoMoreMatches = 0xC0000273,
+    /// STATUS_NOT_A_REPARSE_POINT
+    NotAReparsePoint = 0xC0000275,
+    /// STATUS_IO_REPARSE_TAG_INVALID
+    IoReparseTagInvalid = 0xC0000276,
+    /// STATUS_IO_REPARSE_TAG_MISMATCH
+    IoReparseTagMismatch = 0xC0000277,
+    /// STATUS_IO_REPARSE_DATA_INVALID
+    IoReparseDataInvalid = 0xC0000278,
+    /// STATUS_IO_REPARSE_TAG_NOT_HANDLED
+    IoReparseTagNotHandled = 0xC0000279,
+    /// STATUS_PWD_TOO_LONG
+    PwdTooLong = 0xC000027A,
+    /// STATUS_STOWED_EXCEPTION
+    StowedException = 0xC000027B,
+    /// STATUS_CONTEXT_STOWED_EXCEPTION
+    ContextStowedException = 0xC000027C,
+    /// STATUS_REPARSE_POINT_NOT_RESOLVED
+    ReparsePointNotResolved = 0xC0000280,
+    /// STATUS_DIRECTORY_IS_A_REPARSE_POINT
+    DirectoryIsAReparsePoint = 0xC0000281,
+    /// STATUS_RANGE_LIST_CONFLICT
+    RangeListConflict = 0xC0000282,
+    /// STATUS_SOURCE_ELEMENT_EMPTY
+    SourceElementEmpty = 0xC0000283,
+    /// STATUS_DESTINATION_ELEMENT_FULL
+    DestinationElementFull = 0xC0000284,
+    /// STATUS_ILLEGAL_ELEMENT_ADDRESS
+    IllegalElementAddress = 0xC0000285,
+    /// STATUS_MAGAZINE_NOT_PRESENT
+    MagazineNotPresent = 0xC0000286,
+    /// STATUS_REINITIALIZATION_NEEDED
+    ReinitializationNeeded = 0xC0000287,
+    /// STATUS_ENCRYPTION_FAILED
+    EncryptionFailed = 0xC000028A,
+    /// STATUS_DECRYPTION_FAILED
+    DecryptionFailed = 0xC000028B,
+    /// STATUS_RANGE_NOT_FOUND
+    RangeNotFound = 0xC000028C,
+    /// STATUS_NO_RECOVERY_POLICY
+    NoRecoveryPolicy = 0xC000028D,
+    /// STATUS_NO_EFS
+    NoEfs = 0xC000028E,
+    /// STATUS_WRONG_EFS
+    WrongEfs = 0xC000028F,
+    /// STATUS_NO_USER_KEYS
+    NoUserKeys = 0xC0000290,
+    /// STATUS_FILE_NOT_ENCRYPTED
+    FileNotEncrypted = 0xC0000291,
+    /// STATUS_NOT_EXPORT_FORMAT
+    NotExportFormat = 0xC0000292,
+    /// STATUS_FILE_ENCRYPTED
+    FileEncrypted = 0xC0000293,
+    /// STATUS_WMI_GUID_NOT_FOUND
+    WmiGuidNotFound = 0xC0000295,
+    /// STATUS_WMI_INSTANCE_NOT_FOUND
+    WmiInstanceNotFound = 0xC0000296,
+    /// STATUS_WMI_ITEMID_NOT_FOUND
+    WmiItemidNotFound = 0xC0000297,
+    /// STATUS_WMI_TRY_AGAIN
+    WmiTryAgain = 0xC0000298,
+    /// STATUS_SHARED_POLICY
+    SharedPolicy = 0xC0000299,
+    /// STATUS_POLICY_OBJECT_NOT_FOUND
+    PolicyObjectNotFound = 0xC000029A,
+    /// STATUS_POLICY_ONLY_IN_DS
+    PolicyOnlyInDs = 0xC000029B,
+    /// STATUS_VOLUME_NOT_UPGRADED
+    VolumeNotUpgraded = 0xC000029C,
+    /// STATUS_REMOTE_STORAGE_NOT_ACTIVE
+    RemoteStorageNotActive = 0xC000029D,
+    /// STATUS_REMOTE_STORAGE_MEDIA_ERROR
+    RemoteStorageMediaError = 0xC000029E,
+    /// STATUS_NO_TRACKING_SERVICE
+    NoTrackingService = 0xC000029F,
+    /// STATUS_SERVER_SID_MISMATCH
+    ServerSidMismatch = 0xC00002A0,
+    /// STATUS_DS_NO_ATTRIBUTE_OR_VALUE
+    DsNoAttributeOrValue = 0xC00002A1,
+    /// STATUS_DS_INVALID_ATTRIBUTE_SYNTAX
+    DsInvalidAttributeSyntax = 0xC00002A2,
+    /// STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED
+    DsAttributeTypeUndefined = 0xC00002A3,
+    /// STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS
+    DsAttributeOrValueExists = 0xC00002A4,
+    /// STATUS_DS_BUSY
+    DsBusy = 0xC00002A5,
+    /// STATUS_DS_UNAVAILABLE
+    DsUnavailable = 0xC00002A6,
+    /// STATUS_DS_NO_RIDS_ALLOCATED
+    DsNoRidsAllocated = 0xC00002A7,
+    /// STATUS_DS_NO_MORE_RIDS
+    DsNoMoreRids = 0xC00002A8,
+    /// STATUS_DS_INCORRECT_ROLE_OWNER
+    DsIncorrectRoleOwner = 0xC00002A9,
+    /// STATUS_DS_RIDMGR_INIT_ERROR
+    DsRidmgrInitError = 0xC00002AA,
+    /// STATUS_DS_OBJ_CLASS_VIOLATION
+    DsObjClassViolation = 0xC00002AB,
+    /// STATUS_DS_CANT_ON_NON_LEAF
+    DsCantOnNonLeaf = 0xC00002AC,
+    /// STATUS_DS_CANT_ON_RDN
+    DsCantOnRdn = 0xC00002AD,
+    /// STATUS_DS_CANT_MOD_OBJ_CLASS
+    DsCantModObjClass = 0xC00002AE,
+    /// STATUS_DS_CROSS_DOM_MOVE_FAILED
+    DsCrossDomMoveFailed = 0xC00002AF,
+    /// STATUS_DS_GC_NOT_AVAILABLE
+    DsGcNotAvailable = 0xC00002B0,
+    /// STATUS_DIRECTORY_SERVICE_REQUIRED
+    DirectoryServiceRequired = 0xC00002B1,
+    /// STATUS_REPARSE_ATTRIBUTE_CONFLICT
+    ReparseAttributeConflict = 0xC00002B2,
+    /// STATUS_CANT_ENABLE_DENY_ONLY
+    CantEnableDenyOnly = 0xC00002B3,
+    /// STATUS_FLOAT_MULTIPLE_FAULTS
+    FloatMultipleFaults = 0xC00002B4,
+    /// STATUS_FLOAT_MULTIPLE_TRAPS
+    FloatMultipleTraps = 0xC00002B5,
+    /// STATUS_DEVICE_REMOVED
+    DeviceRemoved = 0xC00002B6,
+    /// STATUS_JOURNAL_DELETE_IN_PROGRESS
+    JournalDeleteInProgress = 0xC00002B7,
+    /// STATUS_JOURNAL_NOT_ACTIVE
+    JournalNotActive = 0xC00002B8,
+    /// STATUS_NOINTERFACE
+    Nointerface = 0xC00002B9,
+    /// STATUS_DS_RIDMGR_DISABLED
+    DsRidmgrDisabled = 0xC00002BA,
+    /// STATUS_DS_ADMIN_LIMIT_EXCEEDED
+    DsAdminLimitExceeded = 0xC00002C1,
+    /// STATUS_DRIVER_FAILED_SLEEP
+    DriverFailedSleep = 0xC00002C2,
+    /// STATUS_MUTUAL_AUTHENTICATION_FAILED
+    MutualAuthenticationFailed = 0xC00002C3,
+    /// STATUS_CORRUPT_SYSTEM_FILE
+    CorruptSystemFile = 0xC00002C4,
+    /// STATUS_DATATYPE_MISALIGNMENT_ERROR
+    DatatypeMisalignmentError = 0xC00002C5,
+    /// STATUS_WMI_READ_ONLY
+    WmiReadOnly = 0xC00002C6,
+    /// STATUS_WMI_SET_FAILURE
+    WmiSetFailure = 0xC00002C7,
+    /// STATUS_COMMITMENT_MINIMUM
+    CommitmentMinimum = 0xC00002C8,
+    /// STATUS_REG_NAT_CONSUMPTION
+    RegNatConsumption = 0xC00002C9,
+    /// STATUS_TRANSPORT_FULL
+    TransportFull = 0xC00002CA,
+    /// STATUS_DS_SAM_INIT_FAILURE
+    DsSamInitFailure = 0xC00002CB,
+    /// STATUS_ONLY_IF_CONNECTED
+    OnlyIfConnected = 0xC00002CC,
+    /// STATUS_DS_SENSITIVE_GROUP_VIOLATION
+    DsSensitiveGroupViolation = 0xC00002CD,
+    /// STATUS_PNP_RESTART_ENUMERATION
+    PnpRestartEnumeration = 0xC00002CE,
+    /// STATUS_JOURNAL_ENTRY_DELETED
+    JournalEntryDeleted = 0xC00002CF,
+    /// STATUS_DS_CANT_MOD_PRIMARYGROUPID
+    DsCantModPrimarygroupid = 0xC00002D0,
+    /// STATUS_SYSTEM_IMAGE_BAD_SIGNATURE
+    SystemImageBadSignature = 0xC00002D1,
+    /// STATUS_PNP_REBOOT_REQUIRED
+    PnpRebootRequired = 0xC00002D2,
+    /// STATUS_POWER_STATE_INVALID
+    PowerStateInvalid = 0xC00002D3,
+    /// STATUS_DS_INVALID_GROUP_TYPE
+    DsInvalidGroupType = 0xC00002D4,
+    /// STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN
+    DsNoNestGlobalgroupInMixeddomain = 0xC00002D5,
+    /// STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN
+    DsNoNestLocalgroupInMixeddomain = 0xC00002D6,
+    /// STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER
+    DsGlobalCantHaveLocalMember = 0xC00002D7,
+    /// STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER
+    DsGlobalCantHaveUniversalMember = 0xC00002D8,
+    /// STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER
+    DsUniversalCantHaveLocalMember = 0xC00002D9,
+    /// STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER
+    DsGlobalCantHaveCrossdomainMember = 0xC00002DA,
+    /// STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER
+    DsLocalCantHaveCrossdomainLocalMember = 0xC00002DB,
+    /// STATUS_DS_HAVE_PRIMARY_MEMBERS
+    DsHavePrimaryMembers = 0xC00002DC,
+    /// STATUS_WMI_NOT_SUPPORTED
+    WmiNotSupported = 0xC00002DD,
+    /// STATUS_INSUFFICIENT_POWER
+    InsufficientPower = 0xC00002DE,
+    /// STATUS_SAM_NEED_BOOTKEY_PASSWORD
+    SamNeedBootkeyPassword = 0xC00002DF,
+    /// STATUS_SAM_NEED_BOOTKEY_FLOPPY
+    SamNeedBootkeyFloppy = 0xC00002E0,
+    /// STATUS_DS_CANT_START
+    DsCantStart = 0xC00002E1,
+    /// STATUS_DS_INIT_FAILURE
+    DsInitFailure = 0xC00002E2,
+    /// STATUS_SAM_INIT_FAILURE
+    SamInitFailure = 0xC00002E3,
+    /// STATUS_DS_GC_REQUIRED
+    DsGcRequired = 0xC00002E4,
+    /// STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY
+    DsLocalMemberOfLocalOnly = 0xC00002E5,
+    /// STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS
+    DsNoFpoInUniversalGroups = 0xC00002E6,
+    /// STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED
+    DsMachineAccountQuotaExceeded = 0xC00002E7,
+    /// STATUS_MULTIPLE_FAULT_VIOLATION
+    MultipleFaultViolation = 0xC00002E8,
+    /// STATUS_CURRENT_DOMAIN_NOT_ALLOWED
+    CurrentDomainNotAllowed = 0xC00002E9,
+    /// STATUS_CANNOT_MAKE
+    CannotMake = 0xC00002EA,
+    /// STATUS_SYSTEM_SHUTDOWN
+    SystemShutdown = 0xC00002EB,
+    /// STATUS_DS_INIT_FAILURE_CONSOLE
+    DsInitFailureConsole = 0xC00002EC,
+    /// STATUS_DS_SAM_INIT_FAILURE_CONSOLE
+    DsSamInitFailureConsole = 0xC00002ED,
+    /// STATUS_UNFINISHED_CONTEXT_DELETED
+    UnfinishedContextDeleted = 0xC00002EE,
+    /// STATUS_NO_TGT_REPLY
+    NoTgtReply = 0xC00002EF,
+    /// STATUS_OBJECTID_NOT_FOUND
+    ObjectidNotFound = 0xC00002F0,
+    /// STATUS_NO_IP_ADDRESSES
+    NoIpAddresses = 0xC00002F1,
+    /// STATUS_WRONG_CREDENTIAL_HANDLE
+    WrongCredentialHandle = 0xC00002F2,
+    /// STATUS_CRYPTO_SYSTEM_INVALID
+    CryptoSystemInvalid = 0xC00002F3,
+    /// STATUS_MAX_REFERRALS_EXCEEDED
+    MaxReferralsExceeded = 0xC00002F4,
+    /// STATUS_MUST_BE_KDC
+    MustBeKdc = 0xC00002F5,
+    /// STATUS_STRONG_CRYPTO_NOT_SUPPORTED
+    StrongCryptoNotSupported = 0xC00002F6,
+    /// STATUS_TOO_MANY_PRINCIPALS
+    TooManyPrincipals = 0xC00002F7,
+    /// STATUS_NO_PA_DATA
+    NoPaData = 0xC00002F8,
+    /// STATUS_PKINIT_NAME_MISMATCH
+    PkinitNameMismatch = 0xC00002F9,
+    /// STATUS_SMARTCARD_LOGON_REQUIRED
+    SmartcardLogonRequired = 0xC00002FA,
+    /// STATUS_KDC_INVALID_REQUEST
+    KdcInvalidRequest = 0xC00002FB,
+    /// STATUS_KDC_UNABLE_TO_REFER
+    KdcUnableToRefer = 0xC00002FC,
+    /// STATUS_KDC_UNKNOWN_ETYPE
+    KdcUnknownEtype = 0xC00002FD,
+    /// STATUS_SHUTDOWN_IN_PROGRESS
+    ShutdownInProgress = 0xC00002FE,
+    /// STATUS_SERVER_SHUTDOWN_IN_PROGRESS
+    ServerShutdownInProgress = 0xC00002FF,
+    /// STATUS_NOT_SUPPORTED_ON_SBS
+    NotSupportedOnSbs = 0xC0000300,
+    /// STATUS_WMI_GUID_DISCONNECTED
+    WmiGuidDisconnected = 0xC0000301,
+    /// STATUS_WMI_ALREADY_DISABLED
+    WmiAlreadyDisabled = 0xC0000302,
+    /// STATUS_WMI_ALREADY_ENABLED
+    WmiAlreadyEnabled = 0xC0000303,
+    /// STATUS_MFT_TOO_FRAGMENTED
+    MftTooFragmented = 0xC0000304,
+    /// STATUS_COPY_PROTECTION_FAILURE
+    CopyProtectionFailure = 0xC0000305,
+    /// STATUS_CSS_AUTHENTICATION_FAILURE
+    CssAuthenticationFailure = 0xC0000306,
+    /// STATUS_CSS_KEY_NOT_PRESENT
+    CssKeyNotPresent = 0xC0000307,
+    /// STATUS_CSS_KEY_NOT_ESTABLISHED
+    CssKeyNotEstablished = 0xC0000308,
+    /// STATUS_CSS_SCRAMBLED_SECTOR
+    CssScrambledSector = 0xC0000309,
+    /// STATUS_CSS_REGION_MISMATCH
+    CssRegionMismatch = 0xC000030A,
+    /// STATUS_CSS_RESETS_EXHAUSTED
+    CssResetsExhausted = 0xC000030B,
+    /// STATUS_PASSWORD_CHANGE_REQUIRED
+    PasswordChangeRequired = 0xC000030C,
+    /// STATUS_LOST_MODE_LOGON_RESTRICTION
+    LostModeLogonRestriction = 0xC000030D,
+    /// STATUS_PKINIT_FAILURE
+    PkinitFailure = 0xC0000320,
+    /// STATUS_SMARTCARD_SUBSYSTEM_FAILURE
+    SmartcardSubsystemFailure = 0xC0000321,
+    /// STATUS_NO_KERB_KEY
+    NoKerbKey = 0xC0000322,
+    /// STATUS_HOST_DOWN
+    HostDown = 0xC0000350,
+    /// STATUS_UNSUPPORTED_PREAUTH
+    UnsupportedPreauth = 0xC0000351,
+    /// STATUS_EFS_ALG_BLOB_TOO_BIG
+    EfsAlgBlobTooBig = 0xC0000352,
+    /// STATUS_PORT_NOT_SET
+    PortNotSet = 0xC0000353,
+    /// STATUS_DEBUGGER_INACTIVE
+    DebuggerInactive = 0xC0000354,
+    /// STATUS_DS_VERSION_CHECK_FAILURE
+    DsVersionCheckFailure = 0xC0000355,
+    /// STATUS_AUDITING_DISABLED
+    AuditingDisabled = 0xC0000356,
+    /// STATUS_PRENT4_MACHINE_ACCOUNT
+    Prent4MachineAccount = 0xC0000357,
+    /// STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER
+    DsAgCantHaveUniversalMember = 0xC0000358,
+    /// STATUS_INVALID_IMAGE_WIN_32
+    InvalidImageWin32 = 0xC0000359,
+    /// STATUS_INVALID_IMAGE_WIN_64
+    InvalidImageWin64 = 0xC000035A,
+    /// STATUS_BAD_BINDINGS
+    BadBindings = 0xC000035B,
+    /// STATUS_NETWORK_SESSION_EXPIRED
+    NetworkSessionExpired = 0xC000035C,
+    /// STATUS_APPHELP_BLOCK
+    ApphelpBlock = 0xC000035D,
+    /// STATUS_ALL_SIDS_FILTERED
+    AllSidsFiltered = 0xC000035E,
+    /// STATUS_NOT_SAFE_MODE_DRIVER
+    NotSafeModeDriver = 0xC000035F,
+    /// STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT
+    AccessDisabledByPolicyDefault = 0xC0000361,
+    /// STATUS_ACCESS_DISABLED_BY_POLICY_PATH
+    AccessDisabledByPolicyPath = 0xC0000362,
+    /// STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER
+    AccessDisabledByPolicyPublisher = 0xC0000363,
+    /// STATUS_ACCESS_DISABLED_BY_POLICY_OTHER
+    AccessDisabledByPolicyOther = 0xC0000364,
+    /// STATUS_FAILED_DRIVER_ENTRY
+    FailedDriverEntry = 0xC0000365,
+    /// STATUS_DEVICE_ENUMERATION_ERROR
+    DeviceEnumerationError = 0xC0000366,
+    /// STATUS_MOUNT_POINT_NOT_RESOLVED
+    MountPointNotResolved = 0xC0000368,
+    /// STATUS_INVALID_DEVICE_OBJECT_PARAMETER
+    InvalidDeviceObjectParameter = 0xC0000369,
+    /// STATUS_MCA_OCCURED
+    McaOccured = 0xC000036A,
+    /// STATUS_DRIVER_BLOCKED_CRITICAL
+    DriverBlockedCritical = 0xC000036B,
+    /// STATUS_DRIVER_BLOCKED
+    DriverBlocked = 0xC000036C,
+    /// STATUS_DRIVER_DATABASE_ERROR
+    DriverDatabaseError = 0xC000036D,
+    /// STATUS_SYSTEM_HIVE_TOO_LARGE
+    SystemHiveTooLarge = 0xC000036E,
+    /// STATUS_INVALID_IMPORT_OF_NON_DLL
+    InvalidImportOfNonDll = 0xC000036F,
+    /// STATUS_NO_SECRETS
+    NoSecrets = 0xC0000371,
+    /// STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY
+    AccessDisabledNoSaferUiByPolicy = 0xC0000372,
+    /// STATUS_FAILED_STACK_SWITCH
+    FailedStackSwitch = 0xC0000373,
+    /// STATUS_HEAP_CORRUPTION
+    HeapCorruption = 0xC0000374,
+    /// STATUS_SMARTCARD_WRONG_PIN
+    SmartcardWrongPin = 0xC0000380,
+    /// STATUS_SMARTCARD_CARD_BLOCKED
+    SmartcardCardBlocked = 0xC0000381,
+    /// STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED
+    SmartcardCardNotAuthenticated = 0xC0000382,
+    /// STATUS_SMARTCARD_NO_CARD
+    SmartcardNoCard = 0xC0000383,
+    /// STATUS_SMARTCARD_NO_KEY_CONTAINER
+    SmartcardNoKeyContainer = 0xC0000384,
+    /// STATUS_SMARTCARD_NO_CERTIFICATE
+    SmartcardNoCertificate = 0xC0000385,
+    /// STATUS_SMARTCARD_NO_KEYSET
+    SmartcardNoKeyset = 0xC0000386,
+    /// STATUS_SMARTCARD_IO_ERROR
+    SmartcardIoError = 0xC0000387,
+    /// STATUS_DOWNGRADE_DETECTED
+    DowngradeDetected = 0xC0000388,
+    /// STATUS_SMARTCARD_CERT_REVOKED
+    SmartcardCertRevoked = 0xC0000389,
+    /// STATUS_ISSUING_CA_UNTRUSTED
+    IssuingCaUntrusted = 0xC000038A,
+    /// STATUS_REVOCATION_OFFLINE_C
+    RevocationOfflineC = 0xC000038B,
+    /// STATUS_PKINIT_CLIENT_FAILURE
+    PkinitClientFailure = 0xC000038C,
+    /// STATUS_SMARTCARD_CERT_EXPIRED
+    SmartcardCertExpired = 0xC000038D,
+    /// STATUS_DRIVER_FAILED_PRIOR_UNLOAD
+    DriverFailedPriorUnload = 0xC000038E,
+    /// STATUS_SMARTCARD_SILENT_CONTEXT
+    SmartcardSilentContext = 0xC000038F,
+    /// STATUS_PER_USER_TRUST_QUOTA_EXCEEDED
+    PerUserTrustQuotaExceeded = 0xC0000401,
+    /// STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED
+    AllUserTrustQuotaExceeded = 0xC0000402,
+    /// STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED
+    UserDeleteTrustQuotaExceeded = 0xC0000403,
+    /// STATUS_DS_NAME_NOT_UNIQUE
+    DsNameNotUnique = 0xC0000404,
+    /// STATUS_DS_DUPLICATE_ID_FOUND
+    DsDuplicateIdFound = 0xC0000405,
+    /// STATUS_DS_GROUP_CONVERSION_ERROR
+    DsGroupConversionError = 0xC0000406,
+    /// STATUS_VOLSNAP_PREPARE_HIBERNATE
+    VolsnapPrepareHibernate = 0xC0000407,
+    /// STATUS_USER2USER_REQUIRED
+    User2userRequired = 0xC0000408,
+    /// STATUS_STACK_BUFFER_OVERRUN
+    StackBufferOverrun = 0xC0000409,
+    /// STATUS_NO_S4U_PROT_SUPPORT
+    NoS4uProtSupport = 0xC000040A,
+    /// STATUS_CROSSREALM_DELEGATION_FAILURE
+    CrossrealmDelegationFailure = 0xC000040B,
+    /// STATUS_REVOCATION_OFFLINE_KDC
+    RevocationOfflineKdc = 0xC000040C,
+    /// STATUS_ISSUING_CA_UNTRUSTED_KDC
+    IssuingCaUntrustedKdc = 0xC000040D,
+    /// STATUS_KDC_CERT_EXPIRED
+    KdcCertExpired = 0xC000040E,
+    /// STATUS_KDC_CERT_REVOKED
+    KdcCertRevoked = 0xC000040F,
+    /// STATUS_PARAMETER_QUOTA_EXCEEDED
+    ParameterQuotaExceeded = 0xC0000410,
+    /// STATUS_HIBERNATION_FAILURE
+    HibernationFailure = 0xC0000411,
+    /// STATUS_DELAY_LOAD_FAILED
+    DelayLoadFailed = 0xC0000412,
+    /// STATUS_AUTHENTICATION_FIREWALL_FAILED
+    AuthenticationFirewallFailed = 0xC0000413,
+    /// STATUS_VDM_DISALLOWED
+    VdmDisallowed = 0xC0000414,
+    /// STATUS_HUNG_DISPLAY_DRIVER_THREAD
+    HungDisplayDriverThread = 0xC0000415,
+    /// STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE
+    InsufficientResourceForSpecifiedSharedSectionSize = 0xC0000416,
+    /// STATUS_INVALID_CRUNTIME_PARAMETER
+    InvalidCruntimeParameter = 0xC0000417,
+    /// STATUS_NTLM_BLOCKED
+    NtlmBlocked = 0xC0000418,
+    /// STATUS_DS_SRC_SID_EXISTS_IN_FOREST
+    DsSrcSidExistsInForest = 0xC0000419,
+    /// STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST
+    DsDomainNameExistsInForest = 0xC000041A,
+    /// STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST
+    DsFlatNameExistsInForest = 0xC000041B,
+    /// STATUS_INVALID_USER_PRINCIPAL_NAME
+    InvalidUserPrincipalName = 0xC000041C,
+    /// STATUS_FATAL_USER_CALLBACK_EXCEPTION
+    FatalUserCallbackException = 0xC000041D,
+    /// STATUS_ASSERTION_FAILURE
+    AssertionFailure = 0xC0000420,
+    /// STATUS_VERIFIER_STOP
+    VerifierStop = 0xC0000421,
+    /// STATUS_CALLBACK_POP_STACK
+    CallbackPopStack = 0xC0000423,
+    /// STATUS_INCOMPATIBLE_DRIVER_BLOCKED
+    IncompatibleDriverBlocked = 0xC0000424,
+    /// STATUS_HIVE_UNLOADED
+    HiveUnloaded = 0xC0000425,
+    /// STATUS_COMPRESSION_DISABLED
+    CompressionDisabled = 0xC0000426,
+    /// STATUS_FILE_SYSTEM_LIMITATION
+    FileSystemLimitation = 0xC0000427,
+    /// STATUS_INVALID_IMAGE_HASH
+    InvalidImageHash = 0xC0000428,
+    /// STATUS_NOT_CAPABLE
+    NotCapable = 0xC0000429,
+    /// STATUS_REQUEST_OUT_OF_SEQUENCE
+    RequestOutOfSequence = 0xC000042A,
+    /// STATUS_IMPLEMENTATION_LIMIT
+    ImplementationLimit = 0xC000042B,
+    /// STATUS_ELEVATION_REQUIRED
+    ElevationRequired = 0xC000042C,
+    /// STATUS_NO_SECURITY_CONTEXT
+    NoSecurityContext = 0xC000042D,
+    /// STATUS_PKU2U_CERT_FAILURE
+    Pku2uCertFailure = 0xC000042F,
+    /// STATUS_BEYOND_VDL
+    BeyondVdl = 0xC0000432,
+    /// STATUS_ENCOUNTERED_WRITE_IN_PROGRESS
+    EncounteredWriteInProgress = 0xC0000433,
+    /// STATUS_PTE_CHANGED
+    PteChanged = 0xC0000434,
+    /// STATUS_PURGE_FAILED
+    PurgeFailed = 0xC0000435,
+    /// STATUS_CRED_REQUIRES_CONFIRMATION
+    CredRequiresConfirmation = 0xC0000440,
+    /// STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE
+    CsEncryptionInvalidServerResponse = 0xC0000441,
+    /// STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER
+    CsEncryptionUnsupportedServer = 0xC0000442,
+    /// STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE
+    CsEncryptionExistingEncryptedFile = 0xC0000443,
+    /// STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE
+    CsEncryptionNewEncryptedFile = 0xC0000444,
+    /// STATUS_CS_ENCRYPTION_FILE_NOT_CSE
+    CsEncryptionFileNotCse = 0xC0000445,
+    /// STATUS_INVALID_LABEL
+    InvalidLabel = 0xC0000446,
+    /// STATUS_DRIVER_PROCESS_TERMINATED
+    DriverProcessTerminated = 0xC0000450,
+    /// STATUS_AMBIGUOUS_SYSTEM_DEVICE
+    AmbiguousSystemDevice = 0xC0000451,
+    /// STATUS_SYSTEM_DEVICE_NOT_FOUND
+    SystemDeviceNotFound = 0xC0000452,
+    /// STATUS_RESTART_BOOT_APPLICATION
+    RestartBootApplication = 0xC0000453,
+    /// STATUS_INSUFFICIENT_NVRAM_RESOURCES
+    InsufficientNvramResources = 0xC0000454,
+    /// STATUS_INVALID_SESSION
+    InvalidSession = 0xC0000455,
+    /// STATUS_THREAD_ALREADY_IN_SESSION
+    ThreadAlreadyInSession = 0xC0000456,
+    /// STATUS_THREAD_NOT_IN_SESSION
+    ThreadNotInSession = 0xC0000457,
+    /// STATUS_INVALID_WEIGHT
+    InvalidWeight = 0xC0000458,
+    /// STATUS_REQUEST_PAUSED
+    RequestPaused = 0xC0000459,
+    /// STATUS_NO_RANGES_PROCESSED
+    NoRangesProcessed = 0xC0000460,
+    /// STATUS_DISK_RESOURCES_EXHAUSTED
+    DiskResourcesExhausted = 0xC0000461,
+    /// STATUS_NEEDS_REMEDIATION
+    NeedsRemediation = 0xC0000462,
+    /// STATUS_DEVICE_FEATURE_NOT_SUPPORTED
+    DeviceFeatureNotSupported = 0xC0000463,
+    /// STATUS_DEVICE_UNREACHABLE
+    DeviceUnreachable = 0xC0000464,
+    /// STATUS_INVALID_TOKEN
+    InvalidToken = 0xC0000465,
+    /// STATUS_SERVER_UNAVAILABLE
+    ServerUnavailable = 0xC0000466,
+    /// STATUS_FILE_NOT_AVAILABLE
+    FileNotAvailable = 0xC0000467,
+    /// STATUS_DEVICE_INSUFFICIENT_RESOURCES
+    DeviceInsufficientResources = 0xC0000468,
+    /// STATUS_PACKAGE_UPDATING
+    PackageUpdating = 0xC0000469,
+    /// STATUS_NOT_READ_FROM_COPY
+    NotReadFromCopy = 0xC000046A,
+    /// STATUS_FT_WRITE_FAILURE
+    FtWriteFailure = 0xC000046B,
+    /// STATUS_FT_DI_SCAN_REQUIRED
+    FtDiScanRequired = 0xC000046C,
+    /// STATUS_OBJECT_NOT_EXTERNALLY_BACKED
+    ObjectNotExternallyBacked = 0xC000046D,
+    /// STATUS_EXTERNAL_BACKING_PROVIDER_UNKNOWN
+    ExternalBackingProviderUnknown = 0xC000046E,
+    /// STATUS_COMPRESSION_NOT_BENEFICIAL
+    CompressionNotBeneficial = 0xC000046F,
+    /// STATUS_DATA_CHECKSUM_ERROR
+    DataChecksumError = 0xC0000470,
+    /// STATUS_INTERMIXED_KERNEL_EA_OPERATION
+    IntermixedKernelEaOperation = 0xC0000471,
+    /// STATUS_TRIM_READ_ZERO_NOT_SUPPORTED
+    TrimReadZeroNotSupported = 0xC0000472,
+    /// STATUS_TOO_MANY_SEGMENT_DESCRIPTORS
+    TooManySegmentDescriptors = 0xC0000473,
+    /// STATUS_INVALID_OFFSET_ALIGNMENT
+    InvalidOffsetAlignment = 0xC0000474,
+    /// STATUS_INVALID_FIELD_IN_PARAMETER_LIST
+    InvalidFieldInParameterList = 0xC0000475,
+    /// STATUS_OPERATION_IN_PROGRESS
+    OperationInProgress = 0xC0000476,
+    /// STATUS_INVALID_INITIATOR_TARGET_PATH
+    InvalidInitiatorTargetPath = 0xC0000477,
+    /// STATUS_SCRUB_DATA_DISABLED
+    ScrubDataDisabled = 0xC0000478,
+    /// STATUS_NOT_REDUNDANT_STORAGE
+    NotRedundantStorage = 0xC0000479,
+    /// STATUS_RESIDENT_FILE_NOT_SUPPORTED
+    ResidentFileNotSupported = 0xC000047A,
+    /// STATUS_COMPRESSED_FILE_NOT_SUPPORTED
+    CompressedFileNotSupported = 0xC000047B,
+    /// STATUS_DIRECTORY_NOT_SUPPORTED
+    DirectoryNotSupported = 0xC000047C,
+    /// STATUS_IO_OPERATION_TIMEOUT
+    IoOperationTimeout = 0xC000047D,
+    /// STATUS_SYSTEM_NEEDS_REMEDIATION
+    SystemNeedsRemediation = 0xC000047E,
+    /// STATUS_APPX_INTEGRITY_FAILURE_CLR_NGEN
+    AppxIntegrityFailureClrNgen = 0xC000047F,
+    /// STATUS_SHARE_UNAVAILABLE
+    ShareUnavailable = 0xC0000480,
+    /// STATUS_APISET_NOT_HOSTED
+    ApisetNotHosted = 0xC0000481,
+    /// STATUS_APISET_NOT_PRESENT
+    ApisetNotPresent = 0xC0000482,
+    /// STATUS_DEVICE_HARDWARE_ERROR
+    DeviceHardwareError = 0xC0000483,
+    /// STATUS_FIRMWARE_SLOT_INVALID
+    FirmwareSlotInvalid = 0xC0000484,
+    /// STATUS_FIRMWARE_IMAGE_INVALID
+    FirmwareImageInvalid = 0xC0000485,
+    /// STATUS_STORAGE_TOPOLOGY_ID_MISMATCH
+    StorageTopologyIdMismatch = 0xC0000486,
+    /// STATUS_WIM_NOT_BOOTABLE
+    WimNotBootable = 0xC0000487,
+    /// STATUS_BLOCKED_BY_PARENTAL_CONTROLS
+    BlockedByParentalControls = 0xC0000488,
+    /// STATUS_NEEDS_REGISTRATION
+    NeedsRegistration = 0xC0000489,
+    /// STATUS_QUOTA_ACTIVITY
+    QuotaActivity = 0xC000048A,
+    /// STATUS_CALLBACK_INVOKE_INLINE
+    CallbackInvokeInline = 0xC000048B,
+    /// STATUS_BLOCK_TOO_MANY_REFERENCES
+    BlockTooManyReferences = 0xC000048C,
+    /// STATUS_MARKED_TO_DISALLOW_WRITES
+    MarkedToDisallowWrites = 0xC000048D,
+    /// STATUS_NETWORK_ACCESS_DENIED_EDP
+    NetworkAccessDeniedEdp = 0xC000048E,
+    /// STATUS_ENCLAVE_FAILURE
+    EnclaveFailure = 0xC000048F,
+    /// STATUS_PNP_NO_COMPAT_DRIVERS
+    PnpNoCompatDrivers = 0xC0000490,
+    /// STATUS_PNP_DRIVER_PACKAGE_NOT_FOUND
+    PnpDriverPackageNotFound = 0xC0000491,
+    /// STATUS_PNP_DRIVER_CONFIGURATION_NOT_FOUND
+    PnpDriverConfigurationNotFound = 0xC0000492,
+    /// STATUS_PNP_DRIVER_CONFIGURATION_INCOMPLETE
+    PnpDriverConfigurationIncomplete = 0xC0000493,
+    /// STATUS_PNP_FUNCTION_DRIVER_REQUIRED
+    PnpFunctionDriverRequired = 0xC0000494,
+    /// STATUS_PNP_DEVICE_CONFIGURATION_PENDING
+    PnpDeviceConfigurationPending = 0xC0000495,
+    /// STATUS_DEVICE_HINT_NAME_BUFFER_TOO_SMALL
+    DeviceHintNameBufferTooSmall = 0xC0000496,
+    /// STATUS_PACKAGE_NOT_AVAILABLE
+    PackageNotAvailable = 0xC0000497,
+    /// STATUS_DEVICE_IN_MAINTENANCE
+    DeviceInMaintenance = 0xC0000499,
+    /// STATUS_NOT_SUPPORTED_ON_DAX
+    NotSupportedOnDax = 0xC000049A,
+    /// STATUS_FREE_SPACE_TOO_FRAGMENTED
+    FreeSpaceTooFragmented = 0xC000049B,
+    /// STATUS_DAX_MAPPING_EXISTS
+    DaxMappingExists = 0xC000049C,
+    /// STATUS_CHILD_PROCESS_BLOCKED
+    ChildProcessBlocked = 0xC000049D,
+    /// STATUS_STORAGE_LOST_DATA_PERSISTENCE
+    StorageLostDataPersistence = 0xC000049E,
+    /// STATUS_VRF_CFG_ENABLED
+    VrfCfgEnabled = 0xC000049F,
+    /// STATUS_PARTITION_TERMINATING
+    PartitionTerminating = 0xC00004A0,
+    /// STATUS_EXTERNAL_SYSKEY_NOT_SUPPORTED
+    ExternalSyskeyNotSupported = 0xC00004A1,
+    /// STATUS_ENCLAVE_VIOLATION
+    EnclaveViolation = 0xC00004A2,
+    /// STATUS_FILE_PROTECTED_UNDER_DPL
+    FileProtectedUnderDpl = 0xC00004A3,
+    /// STATUS_VOLUME_NOT_CLUSTER_ALIGNED
+    VolumeNotClusterAligned = 0xC00004A4,
+    /// STATUS_NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND
+    NoPhysicallyAlignedFreeSpaceFound = 0xC00004A5,
+    /// STATUS_APPX_FILE_NOT_ENCRYPTED
+    AppxFileNotEncrypted = 0xC00004A6,
+    /// STATUS_RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED
+    RwrawEncryptedFileNotEncrypted = 0xC00004A7,
+    /// STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET
+    RwrawEncryptedInvalidEdatainfoFileoffset = 0xC00004A8,
+    /// STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE
+    RwrawEncryptedInvalidEdatainfoFilerange = 0xC00004A9,
+    /// STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER
+    RwrawEncryptedInvalidEdatainfoParameter = 0xC00004AA,
+    /// STATUS_FT_READ_FAILURE
+    FtReadFailure = 0xC00004AB,
+    /// STATUS_PATCH_CONFLICT
+    PatchConflict = 0xC00004AC,
+    /// STATUS_STORAGE_RESERVE_ID_INVALID
+    StorageReserveIdInvalid = 0xC00004AD,
+    /// STATUS_STORAGE_RESERVE_DOES_NOT_EXIST
+    StorageReserveDoesNotExist = 0xC00004AE,
+    /// STATUS_STORAGE_RESERVE_ALREADY_EXISTS
+    StorageReserveAlreadyExists = 0xC00004AF,
+    /// STATUS_STORAGE_RESERVE_NOT_EMPTY
+    StorageReserveNotEmpty = 0xC00004B0,
+    /// STATUS_NOT_A_DAX_VOLUME
+    NotADaxVolume = 0xC00004B1,
+    /// STATUS_NOT_DAX_MAPPABLE
+    NotDaxMappable = 0xC00004B2,
+    /// STATUS_CASE_DIFFERING_NAMES_IN_DIR
+    CaseDifferingNamesInDir = 0xC00004B3,
+    /// STATUS_FILE_NOT_SUPPORTED
+    FileNotSupported = 0xC00004B4,
+    /// STATUS_NOT_SUPPORTED_WITH_BTT
+    NotSupportedWithBtt = 0xC00004B5,
+    /// STATUS_ENCRYPTION_DISABLED
+    EncryptionDisabled = 0xC00004B6,
+    /// STATUS_ENCRYPTING_METADATA_DISALLOWED
+    EncryptingMetadataDisallowed = 0xC00004B7,
+    /// STATUS_CANT_CLEAR_ENCRYPTION_FLAG
+    CantClearEncryptionFlag = 0xC00004B8,
+    /// STATUS_UNSATISFIED_DEPENDENCIES
+    UnsatisfiedDependencies = 0xC00004B9,
+    /// STATUS_CASE_SENSITIVE_PATH
+    CaseSensitivePath = 0xC00004BA,
+    /// STATUS_UNSUPPORTED_PAGING_MODE
+    UnsupportedPagingMode = 0xC00004BB,
+    /// STATUS_UNTRUSTED_MOUNT_POINT
+    UntrustedMountPoint = 0xC00004BC,
+    /// STATUS_HAS_SYSTEM_CRITICAL_FILES
+    HasSystemCriticalFiles = 0xC00004BD,
+    /// STATUS_OBJECT_IS_IMMUTABLE
+    ObjectIsImmutable = 0xC00004BE,
+    /// STATUS_FT_READ_FROM_COPY_FAILURE
+    FtReadFromCopyFailure = 0xC00004BF,
+    /// STATUS_IMAGE_LOADED_AS_PATCH_IMAGE
+    ImageLoadedAsPatchImage = 0xC00004C0,
+    /// STATUS_STORAGE_STACK_ACCESS_DENIED
+    StorageStackAccessDenied = 0xC00004C1,
+    /// STATUS_INSUFFICIENT_VIRTUAL_ADDR_RESOURCES
+    InsufficientVirtualAddrResources = 0xC00004C2,
+    /// STATUS_ENCRYPTED_FILE_NOT_SUPPORTED
+    EncryptedFileNotSupported = 0xC00004C3,
+    /// STATUS_SPARSE_FILE_NOT_SUPPORTED
+    SparseFileNotSupported = 0xC00004C4,
+    /// STATUS_PAGEFILE_NOT_SUPPORTED
+    PagefileNotSupported = 0xC00004C5,
+    /// STATUS_VOLUME_NOT_SUPPORTED
+    VolumeNotSupported = 0xC00004C6,
+    /// STATUS_NOT_SUPPORTED_WITH_BYPASSIO
+    NotSupportedWithBypassio = 0xC00004C7,
+    /// STATUS_NO_BYPASSIO_DRIVER_SUPPORT
+    NoBypassioDriverSupport = 0xC00004C8,
+    /// STATUS_NOT_SUPPORTED_WITH_ENCRYPTION
+    NotSupportedWithEncryption = 0xC00004C9,
+    /// STATUS_NOT_SUPPORTED_WITH_COMPRESSION
+    NotSupportedWithCompression = 0xC00004CA,
+    /// STATUS_NOT_SUPPORTED_WITH_REPLICATION
+    NotSupportedWithReplication = 0xC00004CB,
+    /// STATUS_NOT_SUPPORTED_WITH_DEDUPLICATION
+    NotSupportedWithDeduplication = 0xC00004CC,
+    /// STATUS_NOT_SUPPORTED_WITH_AUDITING
+    NotSupportedWithAuditing = 0xC00004CD,
+    /// STATUS_NOT_SUPPORTED_WITH_MONITORING
+    NotSupportedWithMonitoring = 0xC00004CE,
+    /// STATUS_NOT_SUPPORTED_WITH_SNAPSHOT
+    NotSupportedWithSnapshot = 0xC00004CF,
+    /// STATUS_NOT_SUPPORTED_WITH_VIRTUALIZATION
+    NotSupportedWithVirtualization = 0xC00004D0,
+    /// STATUS_INDEX_OUT_OF_BOUNDS
+    IndexOutOfBounds = 0xC00004D1,
+    /// STATUS_BYPASSIO_FLT_NOT_SUPPORTED
+    BypassioFltNotSupported = 0xC00004D2,
+    /// STATUS_VOLUME_WRITE_ACCESS_DENIED
+    VolumeWriteAccessDenied = 0xC00004D3,
+    /// STATUS_PATCH_NOT_REGISTERED
+    PatchNotRegistered = 0xC00004D4,
+    /// STATUS_NOT_SUPPORTED_WITH_CACHED_HANDLE
+    NotSupportedWithCachedHandle = 0xC00004D5,
+    /// STATUS_INVALID_TASK_NAME
+    InvalidTaskName = 0xC0000500,
+    /// STATUS_INVALID_TASK_INDEX
+    InvalidTaskIndex = 0xC0000501,
+    /// STATUS_THREAD_ALREADY_IN_TASK
+    ThreadAlreadyInTask = 0xC0000502,
+    /// STATUS_CALLBACK_BYPASS
+    CallbackBypass = 0xC0000503,
+    /// STATUS_UNDEFINED_SCOPE
+    UndefinedScope = 0xC0000504,
+    /// STATUS_INVALID_CAP
+    InvalidCap = 0xC0000505,
+    /// STATUS_NOT_GUI_PROCESS
+    NotGuiProcess = 0xC0000506,
+    /// STATUS_DEVICE_HUNG
+    DeviceHung = 0xC0000507,
+    /// STATUS_CONTAINER_ASSIGNED
+    ContainerAssigned = 0xC0000508,
+    /// STATUS_JOB_NO_CONTAINER
+    JobNoContainer = 0xC0000509,
+    /// STATUS_DEVICE_UNRESPONSIVE
+    DeviceUnresponsive = 0xC000050A,
+    /// STATUS_REPARSE_POINT_ENCOUNTERED
+    ReparsePointEncountered = 0xC000050B,
+    /// STATUS_ATTRIBUTE_NOT_PRESENT
+    AttributeNotPresent = 0xC000050C,
+    /// STATUS_NOT_A_TIERED_VOLUME
+    NotATieredVolume = 0xC000050D,
+    /// STATUS_ALREADY_HAS_STREAM_ID
+    AlreadyHasStreamId = 0xC000050E,
+    /// STATUS_JOB_NOT_EMPTY
+    JobNotEmpty = 0xC000050F,
+    /// STATUS_ALREADY_INITIALIZED
+    AlreadyInitialized = 0xC0000510,
+    /// STATUS_ENCLAVE_NOT_TERMINATED
+    EnclaveNotTerminated = 0xC0000511,
+    /// STATUS_ENCLAVE_IS_TERMINATING
+    EnclaveIsTerminating = 0xC0000512,
+    /// STATUS_SMB1_NOT_AVAILABLE
+    Smb1NotAvailable = 0xC0000513,
+    /// STATUS_SMR_GARBAGE_COLLECTION_REQUIRED
+    SmrGarbageCollectionRequired = 0xC0000514,
+    /// STATUS_INTERRUPTED
+    Interrupted = 0xC0000515,
+    /// STATUS_THREAD_NOT_RUNNING
+    ThreadNotRunning = 0xC0000516,
+    /// STATUS_SESSION_KEY_TOO_SHORT
+    SessionKeyTooShort = 0xC0000517,
+    /// STATUS_FS_METADATA_INCONSISTENT
+    FsMetadataInconsistent = 0xC0000518,
+    /// STATUS_FAIL_FAST_EXCEPTION
+    FailFastException = 0xC0000602,
+    /// STATUS_IMAGE_CERT_REVOKED
+    ImageCertRevoked = 0xC0000603,
+    /// STATUS_DYNAMIC_CODE_BLOCKED
+    DynamicCodeBlocked = 0xC0000604,
+    /// STATUS_IMAGE_CERT_EXPIRED
+    ImageCertExpired = 0xC0000605,
+    /// STATUS_STRICT_CFG_VIOLATION
+    StrictCfgViolation = 0xC0000606,
+    /// STATUS_SET_CONTEXT_DENIED
+    SetContextDenied = 0xC000060A,
+    /// STATUS_CROSS_PARTITION_VIOLATION
+    CrossPartitionViolation = 0xC000060B,
+    /// STATUS_PORT_CLOSED
+    PortClosed = 0xC0000700,
+    /// STATUS_MESSAGE_LOST
+    MessageLost = 0xC0000701,
+    /// STATUS_INVALID_MESSAGE
+    InvalidMessage = 0xC0000702,
+    /// STATUS_REQUEST_CANCELED
+    RequestCanceled = 0xC0000703,
+    /// STATUS_RECURSIVE_DISPATCH
+    RecursiveDispatch = 0xC0000704,
+    /// STATUS_LPC_RECEIVE_BUFFER_EXPECTED
+    LpcReceiveBufferExpected = 0xC0000705,
+    /// STATUS_LPC_INVALID_CONNECTION_USAGE
+    LpcInvalidConnectionUsage = 0xC0000706,
+    /// STATUS_LPC_REQUESTS_NOT_ALLOWED
+    LpcRequestsNotAllowed = 0xC0000707,
+    /// STATUS_RESOURCE_IN_USE
+    ResourceInUse = 0xC0000708,
+    /// STATUS_HARDWARE_MEMORY_ERROR
+    HardwareMemoryError = 0xC0000709,
+    /// STATUS_THREADPOOL_HANDLE_EXCEPTION
+    ThreadpoolHandleException = 0xC000070A,
+    /// STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED
+    ThreadpoolSetEventOnCompletionFailed = 0xC000070B,
+    /// STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED
+    ThreadpoolReleaseSemaphoreOnCompletionFailed = 0xC000070C,
+    /// STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED
+    ThreadpoolReleaseMutexOnCompletionFailed = 0xC000070D,
+    /// STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED
+    ThreadpoolFreeLibraryOnCompletionFailed = 0xC000070E,
+    /// STATUS_THREADPOOL_RELEASED_DURING_OPERATION
+    ThreadpoolReleasedDuringOperation = 0xC000070F,
+    /// STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING
+    CallbackReturnedWhileImpersonating = 0xC0000710,
+    /// STATUS_APC_RETURNED_WHILE_IMPERSONATING
+    ApcReturnedWhileImpersonating = 0xC0000711,
+    /// STATUS_PROCESS_IS_PROTECTED
+    ProcessIsProtected = 0xC0000712,
+    /// STATUS_MCA_EXCEPTION
+    McaException = 0xC0000713,
+    /// STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE
+    CertificateMappingNotUnique = 0xC0000714,
+    /// STATUS_SYMLINK_CLASS_DISABLED
+    SymlinkClassDisabled = 0xC0000715,
+    /// STATUS_INVALID_IDN_NORMALIZATION
+    InvalidIdnNormalization = 0xC0000716,
+    /// STATUS_NO_UNICODE_TRANSLATION
+    NoUnicodeTranslation = 0xC0000717,
+    /// STATUS_ALREADY_REGISTERED
+    AlreadyRegistered = 0xC0000718,
+    /// STATUS_CONTEXT_MISMATCH
+    ContextMismatch = 0xC0000719,
+    /// STATUS_PORT_ALREADY_HAS_COMPLETION_LIST
+    PortAlreadyHasCompletionList = 0xC000071A,
+    /// STATUS_CALLBACK_RETURNED_THREAD_PRIORITY
+    CallbackReturnedThreadPriority = 0xC000071B,
+    /// STATUS_INVALID_THREAD
+    InvalidThread = 0xC000071C,
+    /// STATUS_CALLBACK_RETURNED_TRANSACTION
+    CallbackReturnedTransaction = 0xC000071D,
+    /// STATUS_CALLBACK_RETURNED_LDR_LOCK
+    CallbackReturnedLdrLock = 0xC000071E,
+    /// STATUS_CALLBACK_RETURNED_LANG
+    CallbackReturnedLang = 0xC000071F,
+    /// STATUS_CALLBACK_RETURNED_PRI_BACK
+    CallbackReturnedPriBack = 0xC0000720,
+    /// STATUS_CALLBACK_RETURNED_THREAD_AFFINITY
+    CallbackReturnedThreadAffinity = 0xC0000721,
+    /// STATUS_LPC_HANDLE_COUNT_EXCEEDED
+    LpcHandleCountExceeded = 0xC0000722,
+    /// STATUS_EXECUTABLE_MEMORY_WRITE
+    ExecutableMemoryWrite = 0xC0000723,
+    /// STATUS_KERNEL_EXECUTABLE_MEMORY_WRITE
+    KernelExecutableMemoryWrite = 0xC0000724,
+    /// STATUS_ATTACHED_EXECUTABLE_MEMORY_WRITE
+    AttachedExecutableMemoryWrite = 0xC0000725,
+    /// STATUS_TRIGGERED_EXECUTABLE_MEMORY_WRITE
+    TriggeredExecutableMemoryWrite = 0xC0000726,
+    /// STATUS_DISK_REPAIR_DISABLED
+    DiskRepairDisabled = 0xC0000800,
+    /// STATUS_DS_DOMAIN_RENAME_IN_PROGRESS
+    DsDomainRenameInProgress = 0xC0000801,
+    /// STATUS_DISK_QUOTA_EXCEEDED
+    DiskQuotaExceeded = 0xC0000802,
+    /// STATUS_CONTENT_BLOCKED
+    ContentBlocked = 0xC0000804,
+    /// STATUS_BAD_CLUSTERS
+    BadClusters = 0xC0000805,
+    /// STATUS_VOLUME_DIRTY
+    VolumeDirty = 0xC0000806,
+    /// STATUS_DISK_REPAIR_UNSUCCESSFUL
+    DiskRepairUnsuccessful = 0xC0000808,
+    /// STATUS_CORRUPT_LOG_OVERFULL
+    CorruptLogOverfull = 0xC0000809,
+    /// STATUS_CORRUPT_LOG_CORRUPTED
+    CorruptLogCorrupted = 0xC000080A,
+    /// STATUS_CORRUPT_LOG_UNAVAILABLE
+    CorruptLogUnavailable = 0xC000080B,
+    /// STATUS_CORRUPT_LOG_DELETED_FULL
+    CorruptLogDeletedFull = 0xC000080C,
+    /// STATUS_CORRUPT_LOG_CLEARED
+    CorruptLogCleared = 0xC000080D,
+    /// STATUS_ORPHAN_NAME_EXHAUSTED
+    OrphanNameExhausted = 0xC000080E,
+    /// STATUS_PROACTIVE_SCAN_IN_PROGRESS
+    ProactiveScanInProgress = 0xC000080F,
+    /// STATUS_ENCRYPTED_IO_NOT_POSSIBLE
+    EncryptedIoNotPossible = 0xC0000810,
+    /// STATUS_CORRUPT_LOG_UPLEVEL_RECORDS
+    CorruptLogUplevelRecords = 0xC0000811,
+    /// STATUS_FILE_CHECKED_OUT
+    FileCheckedOut = 0xC0000901,
+    /// STATUS_CHECKOUT_REQUIRED
+    CheckoutRequired = 0xC0000902,
+    /// STATUS_BAD_FILE_TYPE
+    BadFileType = 0xC0000903,
+    /// STATUS_FILE_TOO_LARGE
+    FileTooLarge = 0xC0000904,
+    /// STATUS_FORMS_AUTH_REQUIRED
+    FormsAuthRequired = 0xC0000905,
+    /// STATUS_VIRUS_INFECTED
+    VirusInfected = 0xC0000906,
+    /// STATUS_VIRUS_DELETED
+    VirusDeleted = 0xC0000907,
+    /// STATUS_BAD_MCFG_TABLE
+    BadMcfgTable = 0xC0000908,
+    /// STATUS_CANNOT_BREAK_OPLOCK
+    CannotBreakOplock = 0xC0000909,
+    /// STATUS_BAD_KEY
+    BadKey = 0xC000090A,
+    /// STATUS_BAD_DATA
+    BadData = 0xC000090B,
+    /// STATUS_NO_KEY
+    NoKey = 0xC000090C,
+    /// STATUS_FILE_HANDLE_REVOKED
+    FileHandleRevoked = 0xC0000910,
+    /// STATUS_SECTION_DIRECT_MAP_ONLY
+    SectionDirectMapOnly = 0xC0000911,
+    /// STATUS_BLOCK_WEAK_REFERENCE_INVALID
+    BlockWeakReferenceInvalid = 0xC0000912,
+    /// STATUS_BLOCK_SOURCE_WEAK_REFERENCE_INVALID
+    BlockSourceWeakReferenceInvalid = 0xC0000913,
+    /// STATUS_BLOCK_TARGET_WEAK_REFERENCE_INVALID
+    BlockTargetWeakReferenceInvalid = 0xC0000914,
+    /// STATUS_BLOCK_SHARED
+    BlockShared = 0xC0000915,
+    /// STATUS_VRF_VOLATILE_CFG_AND_IO_ENABLED
+    VrfVolatileCfgAndIoEnabled = 0xC0000C08,
+    /// STATUS_VRF_VOLATILE_NOT_STOPPABLE
+    VrfVolatileNotStoppable = 0xC0000C09,
+    /// STATUS_VRF_VOLATILE_SAFE_MODE
+    VrfVolatileSafeMode = 0xC0000C0A,
+    /// STATUS_VRF_VOLATILE_NOT_RUNNABLE_SYSTEM
+    VrfVolatileNotRunnableSystem = 0xC0000C0B,
+    /// STATUS_VRF_VOLATILE_NOT_SUPPORTED_RULECLASS
+    VrfVolatileNotSupportedRuleclass = 0xC0000C0C,
+    /// STATUS_VRF_VOLATILE_PROTECTED_DRIVER
+    VrfVolatileProtectedDriver = 0xC0000C0D,
+    /// STATUS_VRF_VOLATILE_NMI_REGISTERED
+    VrfVolatileNmiRegistered = 0xC0000C0E,
+    /// STATUS_VRF_VOLATILE_SETTINGS_CONFLICT
+    VrfVolatileSettingsConflict = 0xC0000C0F,
+    /// STATUS_DIF_IOCALLBACK_NOT_REPLACED
+    DifIocallbackNotReplaced = 0xC0000C76,
+    /// STATUS_DIF_LIVEDUMP_LIMIT_EXCEEDED
+    DifLivedumpLimitExceeded = 0xC0000C77,
+    /// STATUS_DIF_VOLATILE_SECTION_NOT_LOCKED
+    DifVolatileSectionNotLocked = 0xC0000C78,
+    /// STATUS_DIF_VOLATILE_DRIVER_HOTPATCHED
+    DifVolatileDriverHotpatched = 0xC0000C79,
+    /// STATUS_DIF_VOLATILE_INVALID_INFO
+    DifVolatileInvalidInfo = 0xC0000C7A,
+    /// STATUS_DIF_VOLATILE_DRIVER_IS_NOT_RUNNING
+    DifVolatileDriverIsNotRunning = 0xC0000C7B,
+    /// STATUS_DIF_VOLATILE_PLUGIN_IS_NOT_RUNNING
+    DifVolatilePluginIsNotRunning = 0xC0000C7C,
+    /// STATUS_DIF_VOLATILE_PLUGIN_CHANGE_NOT_ALLOWED
+    DifVolatilePluginChangeNotAllowed = 0xC0000C7D,
+    /// STATUS_DIF_VOLATILE_NOT_ALLOWED
+    DifVolatileNotAllowed = 0xC0000C7E,
+    /// STATUS_DIF_BINDING_API_NOT_FOUND
+    DifBindingApiNotFound = 0xC0000C7F,
+    /// STATUS_WOW_ASSERTION
+    WowAssertion = 0xC0009898,
+    /// STATUS_INVALID_SIGNATURE
+    InvalidSignature = 0xC000A000,
+    /// STATUS_HMAC_NOT_SUPPORTED
+    HmacNotSupported = 0xC000A001,
+    /// STATUS_AUTH_TAG_MISMATCH
+    AuthTagMismatch = 0xC000A002,
+    /// STATUS_INVALID_STATE_TRANSITION
+    InvalidStateTransition = 0xC000A003,
+    /// STATUS_INVALID_KERNEL_INFO_VERSION
+    InvalidKernelInfoVersion = 0xC000A004,
+    /// STATUS_INVALID_PEP_INFO_VERSION
+    InvalidPepInfoVersion = 0xC000A005,
+    /// STATUS_HANDLE_REVOKED
+    HandleRevoked = 0xC000A006,
+    /// STATUS_EOF_ON_GHOSTED_RANGE
+    EofOnGhostedRange = 0xC000A007,
+    /// STATUS_CC_NEEDS_CALLBACK_SECTION_DRAIN
+    CcNeedsCallbackSectionDrain = 0xC000A008,
+    /// STATUS_IPSEC_QUEUE_OVERFLOW
+    IpsecQueueOverflow = 0xC000A010,
+    /// STATUS_ND_QUEUE_OVERFLOW
+    NdQueueOverflow = 0xC000A011,
+    /// STATUS_HOPLIMIT_EXCEEDED
+    HoplimitExceeded = 0xC000A012,
+    /// STATUS_PROTOCOL_NOT_SUPPORTED
+    ProtocolNotSupported = 0xC000A013,
+    /// STATUS_FASTPATH_REJECTED
+    FastpathRejected = 0xC000A014,
+    /// STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED
+    LostWritebehindDataNetworkDisconnected = 0xC000A080,
+    /// STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR
+    LostWritebehindDataNetworkServerError = 0xC000A081,
+    /// STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR
+    LostWritebehindDataLocalDiskError = 0xC000A082,
+    /// STATUS_XML_PARSE_ERROR
+    XmlParseError = 0xC000A083,
+    /// STATUS_XMLDSIG_ERROR
+    XmldsigError = 0xC000A084,
+    /// STATUS_WRONG_COMPARTMENT
+    WrongCompartment = 0xC000A085,
+    /// STATUS_AUTHIP_FAILURE
+    AuthipFailure = 0xC000A086,
+    /// STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS
+    DsOidMappedGroupCantHaveMembers = 0xC000A087,
+    /// STATUS_DS_OID_NOT_FOUND
+    DsOidNotFound = 0xC000A088,
+    /// STATUS_INCORRECT_ACCOUNT_TYPE
+    IncorrectAccountType = 0xC000A089,
+    /// STATUS_LOCAL_POLICY_MODIFICATION_NOT_SUPPORTED
+    LocalPolicyModificationNotSupported = 0xC000A08A,
+    /// STATUS_POLICY_CONTROLLED_ACCOUNT
+    PolicyControlledAccount = 0xC000A08B,
+    /// STATUS_LAPS_LEGACY_SCHEMA_MISSING
+    LapsLegacySchemaMissing = 0xC000A08C,
+    /// STATUS_LAPS_SCHEMA_MISSING
+    LapsSchemaMissing = 0xC000A08D,
+    /// STATUS_LAPS_ENCRYPTION_REQUIRES_2016_DFL
+    LapsEncryptionRequires2016Dfl = 0xC000A08E,
+    /// STATUS_HASH_NOT_SUPPORTED
+    HashNotSupported = 0xC000A100,
+    /// STATUS_HASH_NOT_PRESENT
+    HashNotPresent = 0xC000A101,
+    /// STATUS_SECONDARY_IC_PROVIDER_NOT_REGISTERED
+    SecondaryIcProviderNotRegistered = 0xC000A121,
+    /// STATUS_GPIO_CLIENT_INFORMATION_INVALID
+    GpioClientInformationInvalid = 0xC000A122,
+    /// STATUS_GPIO_VERSION_NOT_SUPPORTED
+    GpioVersionNotSupported = 0xC000A123,
+    /// STATUS_GPIO_INVALID_REGISTRATION_PACKET
+    GpioInvalidRegistrationPacket = 0xC000A124,
+    /// STATUS_GPIO_OPERATION_DENIED
+    GpioOperationDenied = 0xC000A125,
+    /// STATUS_GPIO_INCOMPATIBLE_CONNECT_MODE
+    GpioIncompatibleConnectMode = 0xC000A126,
+    /// STATUS_CANNOT_SWITCH_RUNLEVEL
+    CannotSwitchRunlevel = 0xC000A141,
+    /// STATUS_INVALID_RUNLEVEL_SETTING
+    InvalidRunlevelSetting = 0xC000A142,
+    /// STATUS_RUNLEVEL_SWITCH_TIMEOUT
+    RunlevelSwitchTimeout = 0xC000A143,
+    /// STATUS_RUNLEVEL_SWITCH_AGENT_TIMEOUT
+    RunlevelSwitchAgentTimeout = 0xC000A145,
+    /// STATUS_RUNLEVEL_SWITCH_IN_PROGRESS
+    RunlevelSwitchInProgress = 0xC000A146,
+    /// STATUS_NOT_APPCONTAINER
+    NotAppcontainer = 0xC000A200,
+    /// STATUS_NOT_SUPPORTED_IN_APPCONTAINER
+    NotSupportedInAppcontainer = 0xC000A201,
+    /// STATUS_INVALID_PACKAGE_SID_LENGTH
+    InvalidPackageSidLength = 0xC000A202,
+    /// STATUS_LPAC_ACCESS_DENIED
+    LpacAccessDenied = 0xC000A203,
+    /// STATUS_ADMINLESS_ACCESS_DENIED
+    AdminlessAccessDenied = 0xC000A204,
+    /// STATUS_APP_DATA_NOT_FOUND
+    AppDataNotFound = 0xC000A281,
+    /// STATUS_APP_DATA_EXPIRED
+    AppDataExpired = 0xC000A282,
+    /// STATUS_APP_DATA_CORRUPT
+    AppDataCorrupt = 0xC000A283,
+    /// STATUS_APP_DATA_LIMIT_EXCEEDED
+    AppDataLimitExceeded = 0xC000A284,
+    /// STATUS_APP_DATA_REBOOT_REQUIRED
+    AppDataRebootRequired = 0xC000A285,
+    /// STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED
+    OffloadReadFltNotSupported = 0xC000A2A1,
+    /// STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED
+    OffloadWriteFltNotSupported = 0xC000A2A2,
+    /// STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED
+    OffloadReadFileNotSupported = 0xC000A2A3,
+    /// STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED
+    OffloadWriteFileNotSupported = 0xC000A2A4,
+    /// STATUS_WOF_WIM_HEADER_CORRUPT
+    WofWimHeaderCorrupt = 0xC000A2A5,
+    /// STATUS_WOF_WIM_RESOURCE_TABLE_CORRUPT
+    WofWimResourceTableCorrupt = 0xC000A2A6,
+    /// STATUS_WOF_FILE_RESOURCE_TABLE_CORRUPT
+    WofFileResourceTableCorrupt = 0xC000A2A7,
+    /// STATUS_CIMFS_IMAGE_CORRUPT
+    CimfsImageCorrupt = 0xC000C001,
+    /// STATUS_CIMFS_IMAGE_VERSION_NOT_SUPPORTED
+    CimfsImageVersionNotSupported = 0xC000C002,
+    /// STATUS_FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE
+    FileSystemVirtualizationUnavailable = 0xC000CE01,
+    /// STATUS_FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT
+    FileSystemVirtualizationMetadataCorrupt = 0xC000CE02,
+    /// STATUS_FILE_SYSTEM_VIRTUALIZATION_BUSY
+    FileSystemVirtualizationBusy = 0xC000CE03,
+    /// STATUS_FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN
+    FileSystemVirtualizationProviderUnknown = 0xC000CE04,
+    /// STATUS_FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION
+    FileSystemVirtualizationInvalidOperation = 0xC000CE05,
+    /// STATUS_CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT
+    CloudFileSyncRootMetadataCorrupt = 0xC000CF00,
+    /// STATUS_CLOUD_FILE_PROVIDER_NOT_RUNNING
+    CloudFileProviderNotRunning = 0xC000CF01,
+    /// STATUS_CLOUD_FILE_METADATA_CORRUPT
+    CloudFileMetadataCorrupt = 0xC000CF02,
+    /// STATUS_CLOUD_FILE_METADATA_TOO_LARGE
+    CloudFileMetadataTooLarge = 0xC000CF03,
+    /// STATUS_CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED
+    CloudFilePropertyVersionNotSupported = 0xC000CF06,
+    /// STATUS_NOT_A_CLOUD_FILE
+    NotACloudFile = 0xC000CF07,
+    /// STATUS_CLOUD_FILE_NOT_IN_SYNC
+    CloudFileNotInSync = 0xC000CF08,
+    /// STATUS_CLOUD_FILE_ALREADY_CONNECTED
+    CloudFileAlreadyConnected = 0xC000CF09,
+    /// STATUS_CLOUD_FILE_NOT_SUPPORTED
+    CloudFileNotSupported = 0xC000CF0A,
+    /// STATUS_CLOUD_FILE_INVALID_REQUEST
+    CloudFileInvalidRequest = 0xC000CF0B,
+    /// STATUS_CLOUD_FILE_READ_ONLY_VOLUME
+    CloudFileReadOnlyVolume = 0xC000CF0C,
+    /// STATUS_CLOUD_FILE_CONNECTED_PROVIDER_ONLY
+    CloudFileConnectedProviderOnly = 0xC000CF0D,
+    /// STATUS_CLOUD_FILE_VALIDATION_FAILED
+    CloudFileValidationFailed = 0xC000CF0E,
+    /// STATUS_CLOUD_FILE_AUTHENTICATION_FAILED
+    CloudFileAuthenticationFailed = 0xC000CF0F,
+    /// STATUS_CLOUD_FILE_INSUFFICIENT_RESOURCES
+    CloudFileInsufficientResources = 0xC000CF10,
+    /// STATUS_CLOUD_FILE_NETWORK_UNAVAILABLE
+    CloudFileNetworkUnavailable = 0xC000CF11,
+    /// STATUS_CLOUD_FILE_UNSUCCESSFUL
+    CloudFileUnsuccessful = 0xC000CF12,
+    /// STATUS_CLOUD_FILE_NOT_UNDER_SYNC_ROOT
+    CloudFileNotUnderSyncRoot = 0xC000CF13,
+    /// STATUS_CLOUD_FILE_IN_USE
+    CloudFileInUse = 0xC000CF14,
+    /// STATUS_CLOUD_FILE_PINNED
+    CloudFilePinned = 0xC000CF15,
+    /// STATUS_CLOUD_FILE_REQUEST_ABORTED
+    CloudFileRequestAborted = 0xC000CF16,
+    /// STATUS_CLOUD_FILE_PROPERTY_CORRUPT
+    CloudFilePropertyCorrupt = 0xC000CF17,
+    /// STATUS_CLOUD_FILE_ACCESS_DENIED
+    CloudFileAccessDenied = 0xC000CF18,
+    /// STATUS_CLOUD_FILE_INCOMPATIBLE_HARDLINKS
+    CloudFileIncompatibleHardlinks = 0xC000CF19,
+    /// STATUS_CLOUD_FILE_PROPERTY_LOCK_CONFLICT
+    CloudFilePropertyLockConflict = 0xC000CF1A,
+    /// STATUS_CLOUD_FILE_REQUEST_CANCELED
+    CloudFileRequestCanceled = 0xC000CF1B,
+    /// STATUS_CLOUD_FILE_PROVIDER_TERMINATED
+    CloudFileProviderTerminated = 0xC000CF1D,
+    /// STATUS_NOT_A_CLOUD_SYNC_ROOT
+    NotACloudSyncRoot = 0xC000CF1E,
+    /// STATUS_CLOUD_FILE_REQUEST_TIMEOUT
+    CloudFileRequestTimeout = 0xC000CF1F,
+    /// STATUS_CLOUD_FILE_DEHYDRATION_DISALLOWED
+    CloudFileDehydrationDisallowed = 0xC000CF20,
+    /// STATUS_CLOUD_FILE_US_MESSAGE_TIMEOUT
+    CloudFileUsMessageTimeout = 0xC000CF21,
+    /// STATUS_FILE_SNAP_IN_PROGRESS
+    FileSnapInProgress = 0xC000F500,
+    /// STATUS_FILE_SNAP_USER_SECTION_NOT_SUPPORTED
+    FileSnapUserSectionNotSupported = 0xC000F501,
+    /// STATUS_FILE_SNAP_MODIFY_NOT_SUPPORTED
+    FileSnapModifyNotSupported = 0xC000F502,
+    /// STATUS_FILE_SNAP_IO_NOT_COORDINATED
+    FileSnapIoNotCoordinated = 0xC000F503,
+    /// STATUS_FILE_SNAP_UNEXPECTED_ERROR
+    FileSnapUnexpectedError = 0xC000F504,
+    /// STATUS_FILE_SNAP_INVALID_PARAMETER
+    FileSnapInvalidParameter = 0xC000F505,
+    /// STATUS_PNP_BAD_MPS_TABLE
+    PnpBadMpsTable = 0xC0040035,
+    /// STATUS_PNP_TRANSLATION_FAILED
+    PnpTranslationFailed = 0xC0040036,
+    /// STATUS_PNP_IRQ_TRANSLATION_FAILED
+    PnpIrqTranslationFailed = 0xC0040037,
+    /// STATUS_PNP_INVALID_ID
+    PnpInvalidId = 0xC0040038,
+    /// STATUS_IO_REISSUE_AS_CACHED
+    IoReissueAsCached = 0xC0040039,
+    /// STATUS_CTX_WINSTATION_NAME_INVALID
+    CtxWinstationNameInvalid = 0xC00A0001,
+    /// STATUS_CTX_INVALID_PD
+    CtxInvalidPd = 0xC00A0002,
+    /// STATUS_CTX_PD_NOT_FOUND
+    CtxPdNotFound = 0xC00A0003,
+    /// STATUS_CTX_CLOSE_PENDING
+    CtxClosePending = 0xC00A0006,
+    /// STATUS_CTX_NO_OUTBUF
+    CtxNoOutbuf = 0xC00A0007,
+    /// STATUS_CTX_MODEM_INF_NOT_FOUND
+    CtxModemInfNotFound = 0xC00A0008,
+    /// STATUS_CTX_INVALID_MODEMNAME
+    CtxInvalidModemname = 0xC00A0009,
+    /// STATUS_CTX_RESPONSE_ERROR
+    CtxResponseError = 0xC00A000A,
+    /// STATUS_CTX_MODEM_RESPONSE_TIMEOUT
+    CtxModemResponseTimeout = 0xC00A000B,
+    /// STATUS_CTX_MODEM_RESPONSE_NO_CARRIER
+    CtxModemResponseNoCarrier = 0xC00A000C,
+    /// STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE
+    CtxModemResponseNoDialtone = 0xC00A000D,
+    /// STATUS_CTX_MODEM_RESPONSE_BUSY
+    CtxModemResponseBusy = 0xC00A000E,
+    /// STATUS_CTX_MODEM_RESPONSE_VOICE
+    CtxModemResponseVoice = 0xC00A000F,
+    /// STATUS_CTX_TD_ERROR
+    CtxTdError = 0xC00A0010,
+    /// STATUS_CTX_LICENSE_CLIENT_INVALID
+    CtxLicenseClientInvalid = 0xC00A0012,
+    /// STATUS_CTX_LICENSE_NOT_AVAILABLE
+    CtxLicenseNotAvailable = 0xC00A0013,
+    /// STATUS_CTX_LICENSE_EXPIRED
+    CtxLicenseExpired = 0xC00A0014,
+    /// STATUS_CTX_WINSTATION_NOT_FOUND
+    CtxWinstationNotFound = 0xC00A0015,
+    /// STATUS_CTX_WINSTATION_NAME_COLLISION
+    CtxWinstationNameCollision = 0xC00A0016,
+    /// STATUS_CTX_WINSTATION_BUSY
+    CtxWinstationBusy = 0xC00A0017,
+    /// STATUS_CTX_BAD_VIDEO_MODE
+    CtxBadVideoMode = 0xC00A0018,
+    /// STATUS_CTX_GRAPHICS_INVALID
+    CtxGraphicsInvalid = 0xC00A0022,
+    /// STATUS_CTX_NOT_CONSOLE
+    CtxNotConsole = 0xC00A0024,
+    /// STATUS_CTX_CLIENT_QUERY_TIMEOUT
+    CtxClientQueryTimeout = 0xC00A0026,
+    /// STATUS_CTX_CONSOLE_DISCONNECT
+    CtxConsoleDisconnect = 0xC00A0027,
+    /// STATUS_CTX_CONSOLE_CONNECT
+    CtxConsoleConnect = 0xC00A0028,
+    /// STATUS_CTX_SHADOW_DENIED
+    CtxShadowDenied = 0xC00A002A,
+    /// STATUS_CTX_WINSTATION_ACCESS_DENIED
+    CtxWinstationAccessDenied = 0xC00A002B,
+    /// STATUS_CTX_INVALID_WD
+    CtxInvalidWd = 0xC00A002E,
+    /// STATUS_CTX_WD_NOT_FOUND
+    CtxWdNotFound = 0xC00A002F,
+    /// STATUS_CTX_SHADOW_INVALID
+    CtxShadowInvalid = 0xC00A0030,
+    /// STATUS_CTX_SHADOW_DISABLED
+    CtxShadowDisabled = 0xC00A0031,
+    /// STATUS_RDP_PROTOCOL_ERROR
+    RdpProtocolError = 0xC00A0032,
+    /// STATUS_CTX_CLIENT_LICENSE_NOT_SET
+    CtxClientLicenseNotSet = 0xC00A0033,
+    /// STATUS_CTX_CLIENT_LICENSE_IN_USE
+    CtxClientLicenseInUse = 0xC00A0034,
+    /// STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE
+    CtxShadowEndedByModeChange = 0xC00A0035,
+    /// STATUS_CTX_SHADOW_NOT_RUNNING
+    CtxShadowNotRunning = 0xC00A0036,
+    /// STATUS_CTX_LOGON_DISABLED
+    CtxLogonDisabled = 0xC00A0037,
+    /// STATUS_CTX_SECURITY_LAYER_ERROR
+    CtxSecurityLayerError = 0xC00A0038,
+    /// STATUS_TS_INCOMPATIBLE_SESSIONS
+    TsIncompatibleSessions = 0xC00A0039,
+    /// STATUS_TS_VIDEO_SUBSYSTEM_ERROR
+    TsVideoSubsystemError = 0xC00A003A,
+    /// STATUS_MUI_FILE_NOT_FOUND
+    MuiFileNotFound = 0xC00B0001,
+    /// STATUS_MUI_INVALID_FILE
+    MuiInvalidFile = 0xC00B0002,
+    /// STATUS_MUI_INVALID_RC_CONFIG
+    MuiInvalidRcConfig = 0xC00B0003,
+    /// STATUS_MUI_INVALID_LOCALE_NAME
+    MuiInvalidLocaleName = 0xC00B0004,
+    /// STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME
+    MuiInvalidUltimatefallbackName = 0xC00B0005,
+    /// STATUS_MUI_FILE_NOT_LOADED
+    MuiFileNotLoaded = 0xC00B0006,
+    /// STATUS_RESOURCE_ENUM_USER_STOP
+    ResourceEnumUserStop = 0xC00B0007,
+    /// STATUS_CLUSTER_INVALID_NODE
+    ClusterInvalidNode = 0xC0130001,
+    /// STATUS_CLUSTER_NODE_EXISTS
+    ClusterNodeExists = 0xC0130002,
+    /// STATUS_CLUSTER_JOIN_IN_PROGRESS
+    ClusterJoinInProgress = 0xC0130003,
+    /// STATUS_CLUSTER_NODE_NOT_FOUND
+    ClusterNodeNotFound = 0xC0130004,
+    /// STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND
+    ClusterLocalNodeNotFound = 0xC0130005,
+    /// STATUS_CLUSTER_NETWORK_EXISTS
+    ClusterNetworkExists = 0xC0130006,
+    /// STATUS_CLUSTER_NETWORK_NOT_FOUND
+    ClusterNetworkNotFound = 0xC0130007,
+    /// STATUS_CLUSTER_NETINTERFACE_EXISTS
+    ClusterNetinterfaceExists = 0xC0130008,
+    /// STATUS_CLUSTER_NETINTERFACE_NOT_FOUND
+    ClusterNetinterfaceNotFound = 0xC0130009,
+    /// STATUS_CLUSTER_INVALID_REQUEST
+    ClusterInvalidRequest = 0xC013000A,
+    /// STATUS_CLUSTER_INVALID_NETWORK_PROVIDER
+    ClusterInvalidNetworkProvider = 0xC013000B,
+    /// STATUS_CLUSTER_NODE_DOWN
+    ClusterNodeDown = 0xC013000C,
+    /// STATUS_CLUSTER_NODE_UNREACHABLE
+    ClusterNodeUnreachable = 0xC013000D,
+    /// STATUS_CLUSTER_NODE_NOT_MEMBER
+    ClusterNodeNotMember = 0xC013000E,
+    /// STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS
+    ClusterJoinNotInProgress = 0xC013000F,
+    /// STATUS_CLUSTER_INVALID_NETWORK
+    ClusterInvalidNetwork = 0xC0130010,
+    /// STATUS_CLUSTER_NO_NET_ADAPTERS
+    ClusterNoNetAdapters = 0xC0130011,
+    /// STATUS_CLUSTER_NODE_UP
+    ClusterNodeUp = 0xC0130012,
+    /// STATUS_CLUSTER_NODE_PAUSED
+    ClusterNodePaused = 0xC0130013,
+    /// STATUS_CLUSTER_NODE_NOT_PAUSED
+    ClusterNodeNotPaused = 0xC0130014,
+    /// STATUS_CLUSTER_NO_SECURITY_CONTEXT
+    ClusterNoSecurityContext = 0xC0130015,
+    /// STATUS_CLUSTER_NETWORK_NOT_INTERNAL
+    ClusterNetworkNotInternal = 0xC0130016,
+    /// STATUS_CLUSTER_POISONED
+    ClusterPoisoned = 0xC0130017,
+    /// STATUS_CLUSTER_NON_CSV_PATH
+    ClusterNonCsvPath = 0xC0130018,
+    /// STATUS_CLUSTER_CSV_VOLUME_NOT_LOCAL
+    ClusterCsvVolumeNotLocal = 0xC0130019,
+    /// STATUS_CLUSTER_CSV_READ_OPLOCK_BREAK_IN_PROGRESS
+    ClusterCsvReadOplockBreakInProgress = 0xC0130020,
+    /// STATUS_CLUSTER_CSV_AUTO_PAUSE_ERROR
+    ClusterCsvAutoPauseError = 0xC0130021,
+    /// STATUS_CLUSTER_CSV_REDIRECTED
+    ClusterCsvRedirected = 0xC0130022,
+    /// STATUS_CLUSTER_CSV_NOT_REDIRECTED
+    ClusterCsvNotRedirected = 0xC0130023,
+    /// STATUS_CLUSTER_CSV_VOLUME_DRAINING
+    ClusterCsvVolumeDraining = 0xC0130024,
+    /// STATUS_CLUSTER_CSV_SNAPSHOT_CREATION_IN_PROGRESS
+    ClusterCsvSnapshotCreationInProgress = 0xC0130025,
+    /// STATUS_CLUSTER_CSV_VOLUME_DRAINING_SUCCEEDED_DOWNLEVEL
+    ClusterCsvVolumeDrainingSucceededDownlevel = 0xC0130026,
+    /// STATUS_CLUSTER_CSV_NO_SNAPSHOTS
+    ClusterCsvNoSnapshots = 0xC0130027,
+    /// STATUS_CSV_IO_PAUSE_TIMEOUT
+    CsvIoPauseTimeout = 0xC0130028,
+    /// STATUS_CLUSTER_CSV_INVALID_HANDLE
+    ClusterCsvInvalidHandle = 0xC0130029,
+    /// STATUS_CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR
+    ClusterCsvSupportedOnlyOnCoordinator = 0xC0130030,
+    /// STATUS_CLUSTER_CAM_TICKET_REPLAY_DETECTED
+    ClusterCamTicketReplayDetected = 0xC0130031,
+    /// STATUS_ACPI_INVALID_OPCODE
+    AcpiInvalidOpcode = 0xC0140001,
+    /// STATUS_ACPI_STACK_OVERFLOW
+    AcpiStackOverflow = 0xC0140002,
+    /// STATUS_ACPI_ASSERT_FAILED
+    AcpiAssertFailed = 0xC0140003,
+    /// STATUS_ACPI_INVALID_INDEX
+    AcpiInvalidIndex = 0xC0140004,
+    /// STATUS_ACPI_INVALID_ARGUMENT
+    AcpiInvalidArgument = 0xC0140005,
+    /// STATUS_ACPI_FATAL
+    AcpiFatal = 0xC0140006,
+    /// STATUS_ACPI_INVALID_SUPERNAME
+    AcpiInvalidSupername = 0xC0140007,
+    /// STATUS_ACPI_INVALID_ARGTYPE
+    AcpiInvalidArgtype = 0xC0140008,
+    /// STATUS_ACPI_INVALID_OBJTYPE
+    AcpiInvalidObjtype = 0xC0140009,
+    /// STATUS_ACPI_INVALID_TARGETTYPE
+    AcpiInvalidTargettype = 0xC014000A,
+    /// STATUS_ACPI_INCORRECT_ARGUMENT_COUNT
+    AcpiIncorrectArgumentCount = 0xC014000B,
+    /// STATUS_ACPI_ADDRESS_NOT_MAPPED
+    AcpiAddressNotMapped = 0xC014000C,
+    /// STATUS_ACPI_INVALID_EVENTTYPE
+    AcpiInvalidEventtype = 0xC014000D,
+    /// STATUS_ACPI_HANDLER_COLLISION
+    AcpiHandlerCollision = 0xC014000E,
+    /// STATUS_ACPI_INVALID_DATA
+    AcpiInvalidData = 0xC014000F,
+    /// STATUS_ACPI_INVALID_REGION
+    AcpiInvalidRegion = 0xC0140010,
+    /// STATUS_ACPI_INVALID_ACCESS_SIZE
+    AcpiInvalidAccessSize = 0xC0140011,
+    /// STATUS_ACPI_ACQUIRE_GLOBAL_LOCK
+    AcpiAcquireGlobalLock = 0xC0140012,
+    /// STATUS_ACPI_ALREADY_INITIALIZED
+    AcpiAlreadyInitialized = 0xC0140013,
+    /// STATUS_ACPI_NOT_INITIALIZED
+    AcpiNotInitialized = 0xC0140014,
+    /// STATUS_ACPI_INVALID_MUTEX_LEVEL
+    AcpiInvalidMutexLevel = 0xC0140015,
+    /// STATUS_ACPI_MUTEX_NOT_OWNED
+    AcpiMutexNotOwned = 0xC0140016,
+    /// STATUS_ACPI_MUTEX_NOT_OWNER
+    AcpiMutexNotOwner = 0xC0140017,
+    /// STATUS_ACPI_RS_ACCESS
+    AcpiRsAccess = 0xC0140018,
+    /// STATUS_ACPI_INVALID_TABLE
+    AcpiInvalidTable = 0xC0140019,
+    /// STATUS_ACPI_REG_HANDLER_FAILED
+    AcpiRegHandlerFailed = 0xC0140020,
+    /// STATUS_ACPI_POWER_REQUEST_FAILED
+    AcpiPowerRequestFailed = 0xC0140021,
+    /// STATUS_SXS_SECTION_NOT_FOUND
+    SxsSectionNotFound = 0xC0150001,
+    /// STATUS_SXS_CANT_GEN_ACTCTX
+    SxsCantGenActctx = 0xC0150002,
+    /// STATUS_SXS_INVALID_ACTCTXDATA_FORMAT
+    SxsInvalidActctxdataFormat = 0xC0150003,
+    /// STATUS_SXS_ASSEMBLY_NOT_FOUND
+    SxsAssemblyNotFound = 0xC0150004,
+    /// STATUS_SXS_MANIFEST_FORMAT_ERROR
+    SxsManifestFormatError = 0xC0150005,
+    /// STATUS_SXS_MANIFEST_PARSE_ERROR
+    SxsManifestParseError = 0xC0150006,
+    /// STATUS_SXS_ACTIVATION_CONTEXT_DISABLED
+    SxsActivationContextDisabled = 0xC0150007,
+    /// STATUS_SXS_KEY_NOT_FOUND
+    SxsKeyNotFound = 0xC0150008,
+    /// STATUS_SXS_VERSION_CONFLICT
+    SxsVersionConflict = 0xC0150009,
+    /// STATUS_SXS_WRONG_SECTION_TYPE
+    SxsWrongSectionType = 0xC015000A,
+    /// STATUS_SXS_THREAD_QUERIES_DISABLED
+    SxsThreadQueriesDisabled = 0xC015000B,
+    /// STATUS_SXS_ASSEMBLY_MISSING
+    SxsAssemblyMissing = 0xC015000C,
+    /// STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET
+    SxsProcessDefaultAlreadySet = 0xC015000E,
+    /// STATUS_SXS_EARLY_DEACTIVATION
+    SxsEarlyDeactivation = 0xC015000F,
+    /// STATUS_SXS_INVALID_DEACTIVATION
+    SxsInvalidDeactivation = 0xC0150010,
+    /// STATUS_SXS_MULTIPLE_DEACTIVATION
+    SxsMultipleDeactivation = 0xC0150011,
+    /// STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY
+    SxsSystemDefaultActivationContextEmpty = 0xC0150012,
+    /// STATUS_SXS_PROCESS_TERMINATION_REQUESTED
+    SxsProcessTerminationRequested = 0xC0150013,
+    /// STATUS_SXS_CORRUPT_ACTIVATION_STACK
+    SxsCorruptActivationStack = 0xC0150014,
+    /// STATUS_SXS_CORRUPTION
+    SxsCorruption = 0xC0150015,
+    /// STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE
+    SxsInvalidIdentityAttributeValue = 0xC0150016,
+    /// STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME
+    SxsInvalidIdentityAttributeName = 0xC0150017,
+    /// STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE
+    SxsIdentityDuplicateAttribute = 0xC0150018,
+    /// STATUS_SXS_IDENTITY_PARSE_ERROR
+    SxsIdentityParseError = 0xC0150019,
+    /// STATUS_SXS_COMPONENT_STORE_CORRUPT
+    SxsComponentStoreCorrupt = 0xC015001A,
+    /// STATUS_SXS_FILE_HASH_MISMATCH
+    SxsFileHashMismatch = 0xC015001B,
+    /// STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT
+    SxsManifestIdentitySameButContentsDifferent = 0xC015001C,
+    /// STATUS_SXS_IDENTITIES_DIFFERENT
+    SxsIdentitiesDifferent = 0xC015001D,
+    /// STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT
+    SxsAssemblyIsNotADeployment = 0xC015001E,
+    /// STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY
+    SxsFileNotPartOfAssembly = 0xC015001F,
+    /// STATUS_ADVANCED_INSTALLER_FAILED
+    AdvancedInstallerFailed = 0xC0150020,
+    /// STATUS_XML_ENCODING_MISMATCH
+    XmlEncodingMismatch = 0xC0150021,
+    /// STATUS_SXS_MANIFEST_TOO_BIG
+    SxsManifestTooBig = 0xC0150022,
+    /// STATUS_SXS_SETTING_NOT_REGISTERED
+    SxsSettingNotRegistered = 0xC0150023,
+    /// STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE
+    SxsTransactionClosureIncomplete = 0xC0150024,
+    /// STATUS_SMI_PRIMITIVE_INSTALLER_FAILED
+    SmiPrimitiveInstallerFailed = 0xC0150025,
+    /// STATUS_GENERIC_COMMAND_FAILED
+    GenericCommandFailed = 0xC0150026,
+    /// STATUS_SXS_FILE_HASH_MISSING
+    SxsFileHashMissing = 0xC0150027,
+    /// STATUS_TRANSACTIONAL_CONFLICT
+    TransactionalConflict = 0xC0190001,
+    /// STATUS_INVALID_TRANSACTION
+    InvalidTransaction = 0xC0190002,
+    /// STATUS_TRANSACTION_NOT_ACTIVE
+    TransactionNotActive = 0xC0190003,
+    /// STATUS_TM_INITIALIZATION_FAILED
+    TmInitializationFailed = 0xC0190004,
+    /// STATUS_RM_NOT_ACTIVE
+    RmNotActive = 0xC0190005,
+    /// STATUS_RM_METADATA_CORRUPT
+    RmMetadataCorrupt = 0xC0190006,
+    /// STATUS_TRANSACTION_NOT_JOINED
+    TransactionNotJoined = 0xC0190007,
+    /// STATUS_DIRECTORY_NOT_RM
+    DirectoryNotRm = 0xC0190008,
+    /// STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE
+    TransactionsUnsupportedRemote = 0xC019000A,
+    /// STATUS_LOG_RESIZE_INVALID_SIZE
+    LogResizeInvalidSize = 0xC019000B,
+    /// STATUS_REMOTE_FILE_VERSION_MISMATCH
+    RemoteFileVersionMismatch = 0xC019000C,
+    /// STATUS_CRM_PROTOCOL_ALREADY_EXISTS
+    CrmProtocolAlreadyExists = 0xC019000F,
+    /// STATUS_TRANSACTION_PROPAGATION_FAILED
+    TransactionPropagationFailed = 0xC0190010,
+    /// STATUS_CRM_PROTOCOL_NOT_FOUND
+    CrmProtocolNotFound = 0xC0190011,
+    /// STATUS_TRANSACTION_SUPERIOR_EXISTS
+    TransactionSuperiorExists = 0xC0190012,
+    /// STATUS_TRANSACTION_REQUEST_NOT_VALID
+    TransactionRequestNotValid = 0xC0190013,
+    /// STATUS_TRANSACTION_NOT_REQUESTED
+    TransactionNotRequested = 0xC0190014,
+    /// STATUS_TRANSACTION_ALREADY_ABORTED
+    TransactionAlreadyAborted = 0xC0190015,
+    /// STATUS_TRANSACTION_ALREADY_COMMITTED
+    TransactionAlreadyCommitted = 0xC0190016,
+    /// STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER
+    TransactionInvalidMarshallBuffer = 0xC0190017,
+    /// STATUS_CURRENT_TRANSACTION_NOT_VALID
+    CurrentTransactionNotValid = 0xC0190018,
+    /// STATUS_LOG_GROWTH_FAILED
+    LogGrowthFailed = 0xC0190019,
+    /// STATUS_OBJECT_NO_LONGER_EXISTS
+    ObjectNoLongerExists = 0xC0190021,
+    /// STATUS_STREAM_MINIVERSION_NOT_FOUND
+    StreamMiniversionNotFound = 0xC0190022,
+    /// STATUS_STREAM_MINIVERSION_NOT_VALID
+    StreamMiniversionNotValid = 0xC0190023,
+    /// STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION
+    MiniversionInaccessibleFromSpecifiedTransaction = 0xC0190024,
+    /// STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT
+    CantOpenMiniversionWithModifyIntent = 0xC0190025,
+    /// STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS
+    CantCreateMoreStreamMiniversions = 0xC0190026,
+    /// STATUS_HANDLE_NO_LONGER_VALID
+    HandleNoLongerValid = 0xC0190028,
+    /// STATUS_LOG_CORRUPTION_DETECTED
+    LogCorruptionDetected = 0xC0190030,
+    /// STATUS_RM_DISCONNECTED
+    RmDisconnected = 0xC0190032,
+    /// STATUS_ENLISTMENT_NOT_SUPERIOR
+    EnlistmentNotSuperior = 0xC0190033,
+    /// STATUS_FILE_IDENTITY_NOT_PERSISTENT
+    FileIdentityNotPersistent = 0xC0190036,
+    /// STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY
+    CantBreakTransactionalDependency = 0xC0190037,
+    /// STATUS_CANT_CROSS_RM_BOUNDARY
+    CantCrossRmBoundary = 0xC0190038,
+    /// STATUS_TXF_DIR_NOT_EMPTY
+    TxfDirNotEmpty = 0xC0190039,
+    /// STATUS_INDOUBT_TRANSACTIONS_EXIST
+    IndoubtTransactionsExist = 0xC019003A,
+    /// STATUS_TM_VOLATILE
+    TmVolatile = 0xC019003B,
+    /// STATUS_ROLLBACK_TIMER_EXPIRED
+    RollbackTimerExpired = 0xC019003C,
+    /// STATUS_TXF_ATTRIBUTE_CORRUPT
+    TxfAttributeCorrupt = 0xC019003D,
+    /// STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION
+    EfsNotAllowedInTransaction = 0xC019003E,
+    /// STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED
+    TransactionalOpenNotAllowed = 0xC019003F,
+    /// STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE
+    TransactedMappingUnsupportedRemote = 0xC0190040,
+    /// STATUS_TRANSACTION_REQUIRED_PROMOTION
+    TransactionRequiredPromotion = 0xC0190043,
+    /// STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION
+    CannotExecuteFileInTransaction = 0xC0190044,
+    /// STATUS_TRANSACTIONS_NOT_FROZEN
+    TransactionsNotFrozen = 0xC0190045,
+    /// STATUS_TRANSACTION_FREEZE_IN_PROGRESS
+    TransactionFreezeInProgress = 0xC0190046,
+    /// STATUS_NOT_SNAPSHOT_VOLUME
+    NotSnapshotVolume = 0xC0190047,
+    /// STATUS_NO_SAVEPOINT_WITH_OPEN_FILES
+    NoSavepointWithOpenFiles = 0xC0190048,
+    /// STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION
+    SparseNotAllowedInTransaction = 0xC0190049,
+    /// STATUS_TM_IDENTITY_MISMATCH
+    TmIdentityMismatch = 0xC019004A,
+    /// STATUS_FLOATED_SECTION
+    FloatedSection = 0xC019004B,
+    /// STATUS_CANNOT_ACCEPT_TRANSACTED_WORK
+    CannotAcceptTransactedWork = 0xC019004C,
+    /// STATUS_CANNOT_ABORT_TRANSACTIONS
+    CannotAbortTransactions = 0xC019004D,
+    /// STATUS_TRANSACTION_NOT_FOUND
+    TransactionNotFound = 0xC019004E,
+    /// STATUS_RESOURCEMANAGER_NOT_FOUND
+    ResourcemanagerNotFound = 0xC019004F,
+    /// STATUS_ENLISTMENT_NOT_FOUND
+    EnlistmentNotFound = 0xC0190050,
+    /// STATUS_TRANSACTIONMANAGER_NOT_FOUND
+    TransactionmanagerNotFound = 0xC0190051,
+    /// STATUS_TRANSACTIONMANAGER_NOT_ONLINE
+    TransactionmanagerNotOnline = 0xC0190052,
+    /// STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION
+    TransactionmanagerRecoveryNameCollision = 0xC0190053,
+    /// STATUS_TRANSACTION_NOT_ROOT
+    TransactionNotRoot = 0xC0190054,
+    /// STATUS_TRANSACTION_OBJECT_EXPIRED
+    TransactionObjectExpired = 0xC0190055,
+    /// STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION
+    CompressionNotAllowedInTransaction = 0xC0190056,
+    /// STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED
+    TransactionResponseNotEnlisted = 0xC0190057,
+    /// STATUS_TRANSACTION_RECORD_TOO_LONG
+    TransactionRecordTooLong = 0xC0190058,
+    /// STATUS_NO_LINK_TRACKING_IN_TRANSACTION
+    NoLinkTrackingInTransaction = 0xC0190059,
+    /// STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION
+    OperationNotSupportedInTransaction = 0xC019005A,
+    /// STATUS_TRANSACTION_INTEGRITY_VIOLATED
+    TransactionIntegrityViolated = 0xC019005B,
+    /// STATUS_TRANSACTIONMANAGER_IDENTITY_MISMATCH
+    TransactionmanagerIdentityMismatch = 0xC019005C,
+    /// STATUS_RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT
+    RmCannotBeFrozenForSnapshot = 0xC019005D,
+    /// STATUS_TRANSACTION_MUST_WRITETHROUGH
+    TransactionMustWritethrough = 0xC019005E,
+    /// STATUS_TRANSACTION_NO_SUPERIOR
+    TransactionNoSuperior = 0xC019005F,
+    /// STATUS_EXPIRED_HANDLE
+    ExpiredHandle = 0xC0190060,
+    /// STATUS_TRANSACTION_NOT_ENLISTED
+    TransactionNotEnlisted = 0xC0190061,
+    /// STATUS_LOG_SECTOR_INVALID
+    LogSectorInvalid = 0xC01A0001,
+    /// STATUS_LOG_SECTOR_PARITY_INVALID
+    LogSectorParityInvalid = 0xC01A0002,
+    /// STATUS_LOG_SECTOR_REMAPPED
+    LogSectorRemapped = 0xC01A0003,
+    /// STATUS_LOG_BLOCK_INCOMPLETE
+    LogBlockIncomplete = 0xC01A0004,
+    /// STATUS_LOG_INVALID_RANGE
+    LogInvalidRange = 0xC01A0005,
+    /// STATUS_LOG_BLOCKS_EXHAUSTED
+    LogBlocksExhausted = 0xC01A0006,
+    /// STATUS_LOG_READ_CONTEXT_INVALID
+    LogReadContextInvalid = 0xC01A0007,
+    /// STATUS_LOG_RESTART_INVALID
+    LogRestartInvalid = 0xC01A0008,
+    /// STATUS_LOG_BLOCK_VERSION
+    LogBlockVersion = 0xC01A0009,
+    /// STATUS_LOG_BLOCK_INVALID
+    LogBlockInvalid = 0xC01A000A,
+    /// STATUS_LOG_READ_MODE_INVALID
+    LogReadModeInvalid = 0xC01A000B,
+    /// STATUS_LOG_METADATA_CORRUPT
+    LogMetadataCorrupt = 0xC01A000D,
+    /// STATUS_LOG_METADATA_INVALID
+    LogMetadataInvalid = 0xC01A000E,
+    /// STATUS_LOG_METADATA_INCONSISTENT
+    LogMetadataInconsistent = 0xC01A000F,
+    /// STATUS_LOG_RESERVATION_INVALID
+    LogReservationInvalid = 0xC01A0010,
+    /// STATUS_LOG_CANT_DELETE
+    LogCantDelete = 0xC01A0011,
+    /// STATUS_LOG_CONTAINER_LIMIT_EXCEEDED
+    LogContainerLimitExceeded = 0xC01A0012,
+    /// STATUS_LOG_START_OF_LOG
+    LogStartOfLog = 0xC01A0013,
+    /// STATUS_LOG_POLICY_ALREADY_INSTALLED
+    LogPolicyAlreadyInstalled = 0xC01A0014,
+    /// STATUS_LOG_POLICY_NOT_INSTALLED
+    LogPolicyNotInstalled = 0xC01A0015,
+    /// STATUS_LOG_POLICY_INVALID
+    LogPolicyInvalid = 0xC01A0016,
+    /// STATUS_LOG_POLICY_CONFLICT
+    LogPolicyConflict = 0xC01A0017,
+    /// STATUS_LOG_PINNED_ARCHIVE_TAIL
+    LogPinnedArchiveTail = 0xC01A0018,
+    /// STATUS_LOG_RECORD_NONEXISTENT
+    LogRecordNonexistent = 0xC01A0019,
+    /// STATUS_LOG_RECORDS_RESERVED_INVALID
+    LogRecordsReservedInvalid = 0xC01A001A,
+    /// STATUS_LOG_SPACE_RESERVED_INVALID
+    LogSpaceReservedInvalid = 0xC01A001B,
+    /// STATUS_LOG_TAIL_INVALID
+    LogTailInvalid = 0xC01A001C,
+    /// STATUS_LOG_FULL
+    LogFull = 0xC01A001D,
+    /// STATUS_LOG_MULTIPLEXED
+    LogMultiplexed = 0xC01A001E,
+    /// STATUS_LOG_DEDICATED
+    LogDedicated = 0xC01A001F,
+    /// STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS
+    LogArchiveNotInProgress = 0xC01A0020,
+    /// STATUS_LOG_ARCHIVE_IN_PROGRESS
+    LogArchiveInProgress = 0xC01A0021,
+    /// STATUS_LOG_EPHEMERAL
+    LogEphemeral = 0xC01A0022,
+    /// STATUS_LOG_NOT_ENOUGH_CONTAINERS
+    LogNotEnoughContainers = 0xC01A0023,
+    /// STATUS_LOG_CLIENT_ALREADY_REGISTERED
+    LogClientAlreadyRegistered = 0xC01A0024,
+    /// STATUS_LOG_CLIENT_NOT_REGISTERED
+    LogClientNotRegistered = 0xC01A0025,
+    /// STATUS_LOG_FULL_HANDLER_IN_PROGRESS
+    LogFullHandlerInProgress = 0xC01A0026,
+    /// STATUS_LOG_CONTAINER_READ_FAILED
+    LogContainerReadFailed = 0xC01A0027,
+    /// STATUS_LOG_CONTAINER_WRITE_FAILED
+    LogContainerWriteFailed = 0xC01A0028,
+    /// STATUS_LOG_CONTAINER_OPEN_FAILED
+    LogContainerOpenFailed = 0xC01A0029,
+    /// STATUS_LOG_CONTAINER_STATE_INVALID
+    LogContainerStateInvalid = 0xC01A002A,
+    /// STATUS_LOG_STATE_INVALID
+    LogStateInvalid = 0xC01A002B,
+    /// STATUS_LOG_PINNED
+    LogPinned = 0xC01A002C,
+    /// STATUS_LOG_METADATA_FLUSH_FAILED
+    LogMetadataFlushFailed = 0xC01A002D,
+    /// STATUS_LOG_INCONSISTENT_SECURITY
+    LogInconsistentSecurity = 0xC01A002E,
+    /// STATUS_LOG_APPENDED_FLUSH_FAILED
+    LogAppendedFlushFailed = 0xC01A002F,
+    /// STATUS_LOG_PINNED_RESERVATION
+    LogPinnedReservation = 0xC01A0030,
+    /// STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD
+    VideoHungDisplayDriverThread = 0xC01B00EA,
+    /// STATUS_FLT_NO_HANDLER_DEFINED
+    FltNoHandlerDefined = 0xC01C0001,
+    /// STATUS_FLT_CONTEXT_ALREADY_DEFINED
+    FltContextAlreadyDefined = 0xC01C0002,
+    /// STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST
+    FltInvalidAsynchronousRequest = 0xC01C0003,
+    /// STATUS_FLT_DISALLOW_FAST_IO
+    FltDisallowFastIo = 0xC01C0004,
+    /// STATUS_FLT_INVALID_NAME_REQUEST
+    FltInvalidNameRequest = 0xC01C0005,
+    /// STATUS_FLT_NOT_SAFE_TO_POST_OPERATION
+    FltNotSafeToPostOperation = 0xC01C0006,
+    /// STATUS_FLT_NOT_INITIALIZED
+    FltNotInitialized = 0xC01C0007,
+    /// STATUS_FLT_FILTER_NOT_READY
+    FltFilterNotReady = 0xC01C0008,
+    /// STATUS_FLT_POST_OPERATION_CLEANUP
+    FltPostOperationCleanup = 0xC01C0009,
+    /// STATUS_FLT_INTERNAL_ERROR
+    FltInternalError = 0xC01C000A,
+    /// STATUS_FLT_DELETING_OBJECT
+    FltDeletingObject = 0xC01C000B,
+    /// STATUS_FLT_MUST_BE_NONPAGED_POOL
+    FltMustBeNonpagedPool = 0xC01C000C,
+    /// STATUS_FLT_DUPLICATE_ENTRY
+    FltDuplicateEntry = 0xC01C000D,
+    /// STATUS_FLT_CBDQ_DISABLED
+    FltCbdqDisabled = 0xC01C000E,
+    /// STATUS_FLT_DO_NOT_ATTACH
+    FltDoNotAttach = 0xC01C000F,
+    /// STATUS_FLT_DO_NOT_DETACH
+    FltDoNotDetach = 0xC01C0010,
+    /// STATUS_FLT_INSTANCE_ALTITUDE_COLLISION
+    FltInstanceAltitudeCollision = 0xC01C0011,
+    /// STATUS_FLT_INSTANCE_NAME_COLLISION
+    FltInstanceNameCollision = 0xC01C0012,
+    /// STATUS_FLT_FILTER_NOT_FOUND
+    FltFilterNotFound = 0xC01C0013,
+    /// STATUS_FLT_VOLUME_NOT_FOUND
+    FltVolumeNotFound = 0xC01C0014,
+    /// STATUS_FLT_INSTANCE_NOT_FOUND
+    FltInstanceNotFound = 0xC01C0015,
+    /// STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND
+    FltContextAllocationNotFound = 0xC01C0016,
+    /// STATUS_FLT_INVALID_CONTEXT_REGISTRATION
+    FltInvalidContextRegistration = 0xC01C0017,
+    /// STATUS_FLT_NAME_CACHE_MISS
+    FltNameCacheMiss = 0xC01C0018,
+    /// STATUS_FLT_NO_DEVICE_OBJECT
+    FltNoDeviceObject = 0xC01C0019,
+    /// STATUS_FLT_VOLUME_ALREADY_MOUNTED
+    FltVolumeAlreadyMounted = 0xC01C001A,
+    /// STATUS_FLT_ALREADY_ENLISTED
+    FltAlreadyEnlisted = 0xC01C001B,
+    /// STATUS_FLT_CONTEXT_ALREADY_LINKED
+    FltContextAlreadyLinked = 0xC01C001C,
+    /// STATUS_FLT_NO_WAITER_FOR_REPLY
+    FltNoWaiterForReply = 0xC01C0020,
+    /// STATUS_FLT_REGISTRATION_BUSY
+    FltRegistrationBusy = 0xC01C0023,
+    /// STATUS_FLT_WCOS_NOT_SUPPORTED
+    FltWcosNotSupported = 0xC01C0024,
+    /// STATUS_MONITOR_NO_DESCRIPTOR
+    MonitorNoDescriptor = 0xC01D0001,
+    /// STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT
+    MonitorUnknownDescriptorFormat = 0xC01D0002,
+    /// STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM
+    MonitorInvalidDescriptorChecksum = 0xC01D0003,
+    /// STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK
+    MonitorInvalidStandardTimingBlock = 0xC01D0004,
+    /// STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED
+    MonitorWmiDatablockRegistrationFailed = 0xC01D0005,
+    /// STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK
+    MonitorInvalidSerialNumberMondscBlock = 0xC01D0006,
+    /// STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK
+    MonitorInvalidUserFriendlyMondscBlock = 0xC01D0007,
+    /// STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA
+    MonitorNoMoreDescriptorData = 0xC01D0008,
+    /// STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK
+    MonitorInvalidDetailedTimingBlock = 0xC01D0009,
+    /// STATUS_MONITOR_INVALID_MANUFACTURE_DATE
+    MonitorInvalidManufactureDate = 0xC01D000A,
+    /// STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER
+    GraphicsNotExclusiveModeOwner = 0xC01E0000,
+    /// STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER
+    GraphicsInsufficientDmaBuffer = 0xC01E0001,
+    /// STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER
+    GraphicsInvalidDisplayAdapter = 0xC01E0002,
+    /// STATUS_GRAPHICS_ADAPTER_WAS_RESET
+    GraphicsAdapterWasReset = 0xC01E0003,
+    /// STATUS_GRAPHICS_INVALID_DRIVER_MODEL
+    GraphicsInvalidDriverModel = 0xC01E0004,
+    /// STATUS_GRAPHICS_PRESENT_MODE_CHANGED
+    GraphicsPresentModeChanged = 0xC01E0005,
+    /// STATUS_GRAPHICS_PRESENT_OCCLUDED
+    GraphicsPresentOccluded = 0xC01E0006,
+    /// STATUS_GRAPHICS_PRESENT_DENIED
+    GraphicsPresentDenied = 0xC01E0007,
+    /// STATUS_GRAPHICS_CANNOTCOLORCONVERT
+    GraphicsCannotcolorconvert = 0xC01E0008,
+    /// STATUS_GRAPHICS_DRIVER_MISMATCH
+    GraphicsDriverMismatch = 0xC01E0009,
+    /// STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED
+    GraphicsPresentRedirectionDisabled = 0xC01E000B,
+    /// STATUS_GRAPHICS_PRESENT_UNOCCLUDED
+    GraphicsPresentUnoccluded = 0xC01E000C,
+    /// STATUS_GRAPHICS_WINDOWDC_NOT_AVAILABLE
+    GraphicsWindowdcNotAvailable = 0xC01E000D,
+    /// STATUS_GRAPHICS_WINDOWLESS_PRESENT_DISABLED
+    GraphicsWindowlessPresentDisabled = 0xC01E000E,
+    /// STATUS_GRAPHICS_PRESENT_INVALID_WINDOW
+    GraphicsPresentInvalidWindow = 0xC01E000F,
+    /// STATUS_GRAPHICS_PRESENT_BUFFER_NOT_BOUND
+    GraphicsPresentBufferNotBound = 0xC01E0010,
+    /// STATUS_GRAPHICS_VAIL_STATE_CHANGED
+    GraphicsVailStateChanged = 0xC01E0011,
+    /// STATUS_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN
+    GraphicsIndirectDisplayAbandonSwapchain = 0xC01E0012,
+    /// STATUS_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED
+    GraphicsIndirectDisplayDeviceStopped = 0xC01E0013,
+    /// STATUS_GRAPHICS_MPO_ALLOCATION_UNPINNED
+    GraphicsMpoAllocationUnpinned = 0xC01E0018,
+    /// STATUS_GRAPHICS_NO_VIDEO_MEMORY
+    GraphicsNoVideoMemory = 0xC01E0100,
+    /// STATUS_GRAPHICS_CANT_LOCK_MEMORY
+    GraphicsCantLockMemory = 0xC01E0101,
+    /// STATUS_GRAPHICS_ALLOCATION_BUSY
+    GraphicsAllocationBusy = 0xC01E0102,
+    /// STATUS_GRAPHICS_TOO_MANY_REFERENCES
+    GraphicsTooManyReferences = 0xC01E0103,
+    /// STATUS_GRAPHICS_TRY_AGAIN_LATER
+    GraphicsTryAgainLater = 0xC01E0104,
+    /// STATUS_GRAPHICS_TRY_AGAIN_NOW
+    GraphicsTryAgainNow = 0xC01E0105,
+    /// STATUS_GRAPHICS_ALLOCATION_INVALID
+    GraphicsAllocationInvalid = 0xC01E0106,
+    /// STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE
+    GraphicsUnswizzlingApertureUnavailable = 0xC01E0107,
+    /// STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED
+    GraphicsUnswizzlingApertureUnsupported = 0xC01E0108,
+    /// STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION
+    GraphicsCantEvictPinnedAllocation = 0xC01E0109,
+    /// STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE
+    GraphicsInvalidAllocationUsage = 0xC01E0110,
+    /// STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION
+    GraphicsCantRenderLockedAllocation = 0xC01E0111,
+    /// STATUS_GRAPHICS_ALLOCATION_CLOSED
+    GraphicsAllocationClosed = 0xC01E0112,
+    /// STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE
+    GraphicsInvalidAllocationInstance = 0xC01E0113,
+    /// STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE
+    GraphicsInvalidAllocationHandle = 0xC01E0114,
+    /// STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE
+    GraphicsWrongAllocationDevice = 0xC01E0115,
+    /// STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST
+    GraphicsAllocationContentLost = 0xC01E0116,
+    /// STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE
+    GraphicsGpuExceptionOnDevice = 0xC01E0200,
+    /// STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY
+    GraphicsInvalidVidpnTopology = 0xC01E0300,
+    /// STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED
+    GraphicsVidpnTopologyNotSupported = 0xC01E0301,
+    /// STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED
+    GraphicsVidpnTopologyCurrentlyNotSupported = 0xC01E0302,
+    /// STATUS_GRAPHICS_INVALID_VIDPN
+    GraphicsInvalidVidpn = 0xC01E0303,
+    /// STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE
+    GraphicsInvalidVideoPresentSource = 0xC01E0304,
+    /// STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET
+    GraphicsInvalidVideoPresentTarget = 0xC01E0305,
+    /// STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED
+    GraphicsVidpnModalityNotSupported = 0xC01E0306,
+    /// STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET
+    GraphicsInvalidVidpnSourcemodeset = 0xC01E0308,
+    /// STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET
+    GraphicsInvalidVidpnTargetmodeset = 0xC01E0309,
+    /// STATUS_GRAPHICS_INVALID_FREQUENCY
+    GraphicsInvalidFrequency = 0xC01E030A,
+    /// STATUS_GRAPHICS_INVALID_ACTIVE_REGION
+    GraphicsInvalidActiveRegion = 0xC01E030B,
+    /// STATUS_GRAPHICS_INVALID_TOTAL_REGION
+    GraphicsInvalidTotalRegion = 0xC01E030C,
+    /// STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE
+    GraphicsInvalidVideoPresentSourceMode = 0xC01E0310,
+    /// STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE
+    GraphicsInvalidVideoPresentTargetMode = 0xC01E0311,
+    /// STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET
+    GraphicsPinnedModeMustRemainInSet = 0xC01E0312,
+    /// STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY
+    GraphicsPathAlreadyInTopology = 0xC01E0313,
+    /// STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET
+    GraphicsModeAlreadyInModeset = 0xC01E0314,
+    /// STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET
+    GraphicsInvalidVideopresentsourceset = 0xC01E0315,
+    /// STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET
+    GraphicsInvalidVideopresenttargetset = 0xC01E0316,
+    /// STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET
+    GraphicsSourceAlreadyInSet = 0xC01E0317,
+    /// STATUS_GRAPHICS_TARGET_ALREADY_IN_SET
+    GraphicsTargetAlreadyInSet = 0xC01E0318,
+    /// STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH
+    GraphicsInvalidVidpnPresentPath = 0xC01E0319,
+    /// STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY
+    GraphicsNoRecommendedVidpnTopology = 0xC01E031A,
+    /// STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET
+    GraphicsInvalidMonitorFrequencyrangeset = 0xC01E031B,
+    /// STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE
+    GraphicsInvalidMonitorFrequencyrange = 0xC01E031C,
+    /// STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET
+    GraphicsFrequencyrangeNotInSet = 0xC01E031D,
+    /// STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET
+    GraphicsFrequencyrangeAlreadyInSet = 0xC01E031F,
+    /// STATUS_GRAPHICS_STALE_MODESET
+    GraphicsStaleModeset = 0xC01E0320,
+    /// STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET
+    GraphicsInvalidMonitorSourcemodeset = 0xC01E0321,
+    /// STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE
+    GraphicsInvalidMonitorSourceMode = 0xC01E0322,
+    /// STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN
+    GraphicsNoRecommendedFunctionalVidpn = 0xC01E0323,
+    /// STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE
+    GraphicsModeIdMustBeUnique = 0xC01E0324,
+    /// STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION
+    GraphicsEmptyAdapterMonitorModeSupportIntersection = 0xC01E0325,
+    /// STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES
+    GraphicsVideoPresentTargetsLessThanSources = 0xC01E0326,
+    /// STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY
+    GraphicsPathNotInTopology = 0xC01E0327,
+    /// STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE
+    GraphicsAdapterMustHaveAtLeastOneSource = 0xC01E0328,
+    /// STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET
+    GraphicsAdapterMustHaveAtLeastOneTarget = 0xC01E0329,
+    /// STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET
+    GraphicsInvalidMonitordescriptorset = 0xC01E032A,
+    /// STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR
+    GraphicsInvalidMonitordescriptor = 0xC01E032B,
+    /// STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET
+    GraphicsMonitordescriptorNotInSet = 0xC01E032C,
+    /// STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET
+    GraphicsMonitordescriptorAlreadyInSet = 0xC01E032D,
+    /// STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE
+    GraphicsMonitordescriptorIdMustBeUnique = 0xC01E032E,
+    /// STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE
+    GraphicsInvalidVidpnTargetSubsetType = 0xC01E032F,
+    /// STATUS_GRAPHICS_RESOURCES_NOT_RELATED
+    GraphicsResourcesNotRelated = 0xC01E0330,
+    /// STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE
+    GraphicsSourceIdMustBeUnique = 0xC01E0331,
+    /// STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE
+    GraphicsTargetIdMustBeUnique = 0xC01E0332,
+    /// STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET
+    GraphicsNoAvailableVidpnTarget = 0xC01E0333,
+    /// STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER
+    GraphicsMonitorCouldNotBeAssociatedWithAdapter = 0xC01E0334,
+    /// STATUS_GRAPHICS_NO_VIDPNMGR
+    GraphicsNoVidpnmgr = 0xC01E0335,
+    /// STATUS_GRAPHICS_NO_ACTIVE_VIDPN
+    GraphicsNoActiveVidpn = 0xC01E0336,
+    /// STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY
+    GraphicsStaleVidpnTopology = 0xC01E0337,
+    /// STATUS_GRAPHICS_MONITOR_NOT_CONNECTED
+    GraphicsMonitorNotConnected = 0xC01E0338,
+    /// STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY
+    GraphicsSourceNotInTopology = 0xC01E0339,
+    /// STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE
+    GraphicsInvalidPrimarysurfaceSize = 0xC01E033A,
+    /// STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE
+    GraphicsInvalidVisibleregionSize = 0xC01E033B,
+    /// STATUS_GRAPHICS_INVALID_STRIDE
+    GraphicsInvalidStride = 0xC01E033C,
+    /// STATUS_GRAPHICS_INVALID_PIXELFORMAT
+    GraphicsInvalidPixelformat = 0xC01E033D,
+    /// STATUS_GRAPHICS_INVALID_COLORBASIS
+    GraphicsInvalidColorbasis = 0xC01E033E,
+    /// STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE
+    GraphicsInvalidPixelvalueaccessmode = 0xC01E033F,
+    /// STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY
+    GraphicsTargetNotInTopology = 0xC01E0340,
+    /// STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT
+    GraphicsNoDisplayModeManagementSupport = 0xC01E0341,
+    /// STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE
+    GraphicsVidpnSourceInUse = 0xC01E0342,
+    /// STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN
+    GraphicsCantAccessActiveVidpn = 0xC01E0343,
+    /// STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL
+    GraphicsInvalidPathImportanceOrdinal = 0xC01E0344,
+    /// STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION
+    GraphicsInvalidPathContentGeometryTransformation = 0xC01E0345,
+    /// STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED
+    GraphicsPathContentGeometryTransformationNotSupported = 0xC01E0346,
+    /// STATUS_GRAPHICS_INVALID_GAMMA_RAMP
+    GraphicsInvalidGammaRamp = 0xC01E0347,
+    /// STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED
+    GraphicsGammaRampNotSupported = 0xC01E0348,
+    /// STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED
+    GraphicsMultisamplingNotSupported = 0xC01E0349,
+    /// STATUS_GRAPHICS_MODE_NOT_IN_MODESET
+    GraphicsModeNotInModeset = 0xC01E034A,
+    /// STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON
+    GraphicsInvalidVidpnTopologyRecommendationReason = 0xC01E034D,
+    /// STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE
+    GraphicsInvalidPathContentType = 0xC01E034E,
+    /// STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE
+    GraphicsInvalidCopyprotectionType = 0xC01E034F,
+    /// STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS
+    GraphicsUnassignedModesetAlreadyExists = 0xC01E0350,
+    /// STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING
+    GraphicsInvalidScanlineOrdering = 0xC01E0352,
+    /// STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED
+    GraphicsTopologyChangesNotAllowed = 0xC01E0353,
+    /// STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS
+    GraphicsNoAvailableImportanceOrdinals = 0xC01E0354,
+    /// STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT
+    GraphicsIncompatiblePrivateFormat = 0xC01E0355,
+    /// STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM
+    GraphicsInvalidModePruningAlgorithm = 0xC01E0356,
+    /// STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN
+    GraphicsInvalidMonitorCapabilityOrigin = 0xC01E0357,
+    /// STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT
+    GraphicsInvalidMonitorFrequencyrangeConstraint = 0xC01E0358,
+    /// STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED
+    GraphicsMaxNumPathsReached = 0xC01E0359,
+    /// STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION
+    GraphicsCancelVidpnTopologyAugmentation = 0xC01E035A,
+    /// STATUS_GRAPHICS_INVALID_CLIENT_TYPE
+    GraphicsInvalidClientType = 0xC01E035B,
+    /// STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET
+    GraphicsClientvidpnNotSet = 0xC01E035C,
+    /// STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED
+    GraphicsSpecifiedChildAlreadyConnected = 0xC01E0400,
+    /// STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED
+    GraphicsChildDescriptorNotSupported = 0xC01E0401,
+    /// STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER
+    GraphicsNotALinkedAdapter = 0xC01E0430,
+    /// STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED
+    GraphicsLeadlinkNotEnumerated = 0xC01E0431,
+    /// STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED
+    GraphicsChainlinksNotEnumerated = 0xC01E0432,
+    /// STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY
+    GraphicsAdapterChainNotReady = 0xC01E0433,
+    /// STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED
+    GraphicsChainlinksNotStarted = 0xC01E0434,
+    /// STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON
+    GraphicsChainlinksNotPoweredOn = 0xC01E0435,
+    /// STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE
+    GraphicsInconsistentDeviceLinkState = 0xC01E0436,
+    /// STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER
+    GraphicsNotPostDeviceDriver = 0xC01E0438,
+    /// STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED
+    GraphicsAdapterAccessNotExcluded = 0xC01E043B,
+    /// STATUS_GRAPHICS_OPM_NOT_SUPPORTED
+    GraphicsOpmNotSupported = 0xC01E0500,
+    /// STATUS_GRAPHICS_COPP_NOT_SUPPORTED
+    GraphicsCoppNotSupported = 0xC01E0501,
+    /// STATUS_GRAPHICS_UAB_NOT_SUPPORTED
+    GraphicsUabNotSupported = 0xC01E0502,
+    /// STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS
+    GraphicsOpmInvalidEncryptedParameters = 0xC01E0503,
+    /// STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST
+    GraphicsOpmNoProtectedOutputsExist = 0xC01E0505,
+    /// STATUS_GRAPHICS_OPM_INTERNAL_ERROR
+    GraphicsOpmInternalError = 0xC01E050B,
+    /// STATUS_GRAPHICS_OPM_INVALID_HANDLE
+    GraphicsOpmInvalidHandle = 0xC01E050C,
+    /// STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH
+    GraphicsPvpInvalidCertificateLength = 0xC01E050E,
+    /// STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED
+    GraphicsOpmSpanningModeEnabled = 0xC01E050F,
+    /// STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED
+    GraphicsOpmTheaterModeEnabled = 0xC01E0510,
+    /// STATUS_GRAPHICS_PVP_HFS_FAILED
+    GraphicsPvpHfsFailed = 0xC01E0511,
+    /// STATUS_GRAPHICS_OPM_INVALID_SRM
+    GraphicsOpmInvalidSrm = 0xC01E0512,
+    /// STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP
+    GraphicsOpmOutputDoesNotSupportHdcp = 0xC01E0513,
+    /// STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP
+    GraphicsOpmOutputDoesNotSupportAcp = 0xC01E0514,
+    /// STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA
+    GraphicsOpmOutputDoesNotSupportCgmsa = 0xC01E0515,
+    /// STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET
+    GraphicsOpmHdcpSrmNeverSet = 0xC01E0516,
+    /// STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH
+    GraphicsOpmResolutionTooHigh = 0xC01E0517,
+    /// STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE
+    GraphicsOpmAllHdcpHardwareAlreadyInUse = 0xC01E0518,
+    /// STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS
+    GraphicsOpmProtectedOutputNoLongerExists = 0xC01E051A,
+    /// STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS
+    GraphicsOpmProtectedOutputDoesNotHaveCoppSemantics = 0xC01E051C,
+    /// STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST
+    GraphicsOpmInvalidInformationRequest = 0xC01E051D,
+    /// STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR
+    GraphicsOpmDriverInternalError = 0xC01E051E,
+    /// STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS
+    GraphicsOpmProtectedOutputDoesNotHaveOpmSemantics = 0xC01E051F,
+    /// STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED
+    GraphicsOpmSignalingNotSupported = 0xC01E0520,
+    /// STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST
+    GraphicsOpmInvalidConfigurationRequest = 0xC01E0521,
+    /// STATUS_GRAPHICS_I2C_NOT_SUPPORTED
+    GraphicsI2cNotSupported = 0xC01E0580,
+    /// STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST
+    GraphicsI2cDeviceDoesNotExist = 0xC01E0581,
+    /// STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA
+    GraphicsI2cErrorTransmittingData = 0xC01E0582,
+    /// STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA
+    GraphicsI2cErrorReceivingData = 0xC01E0583,
+    /// STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED
+    GraphicsDdcciVcpNotSupported = 0xC01E0584,
+    /// STATUS_GRAPHICS_DDCCI_INVALID_DATA
+    GraphicsDdcciInvalidData = 0xC01E0585,
+    /// STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE
+    GraphicsDdcciMonitorReturnedInvalidTimingStatusByte = 0xC01E0586,
+    /// STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING
+    GraphicsDdcciInvalidCapabilitiesString = 0xC01E0587,
+    /// STATUS_GRAPHICS_MCA_INTERNAL_ERROR
+    GraphicsMcaInternalError = 0xC01E0588,
+    /// STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND
+    GraphicsDdcciInvalidMessageCommand = 0xC01E0589,
+    /// STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH
+    GraphicsDdcciInvalidMessageLength = 0xC01E058A,
+    /// STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM
+    GraphicsDdcciInvalidMessageChecksum = 0xC01E058B,
+    /// STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE
+    GraphicsInvalidPhysicalMonitorHandle = 0xC01E058C,
+    /// STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS
+    GraphicsMonitorNoLongerExists = 0xC01E058D,
+    /// STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED
+    GraphicsOnlyConsoleSessionSupported = 0xC01E05E0,
+    /// STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME
+    GraphicsNoDisplayDeviceCorrespondsToName = 0xC01E05E1,
+    /// STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP
+    GraphicsDisplayDeviceNotAttachedToDesktop = 0xC01E05E2,
+    /// STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED
+    GraphicsMirroringDevicesNotSupported = 0xC01E05E3,
+    /// STATUS_GRAPHICS_INVALID_POINTER
+    GraphicsInvalidPointer = 0xC01E05E4,
+    /// STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE
+    GraphicsNoMonitorsCorrespondToDisplayDevice = 0xC01E05E5,
+    /// STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL
+    GraphicsParameterArrayTooSmall = 0xC01E05E6,
+    /// STATUS_GRAPHICS_INTERNAL_ERROR
+    GraphicsInternalError = 0xC01E05E7,
+    /// STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS
+    GraphicsSessionTypeChangeInProgress = 0xC01E05E8,
+    /// STATUS_FVE_LOCKED_VOLUME
+    FveLockedVolume = 0xC0210000,
+    /// STATUS_FVE_NOT_ENCRYPTED
+    FveNotEncrypted = 0xC0210001,
+    /// STATUS_FVE_BAD_INFORMATION
+    FveBadInformation = 0xC0210002,
+    /// STATUS_FVE_TOO_SMALL
+    FveTooSmall = 0xC0210003,
+    /// STATUS_FVE_FAILED_WRONG_FS
+    FveFailedWrongFs = 0xC0210004,
+    /// STATUS_FVE_BAD_PARTITION_SIZE
+    FveBadPartitionSize = 0xC0210005,
+    /// STATUS_FVE_FS_NOT_EXTENDED
+    FveFsNotExtended = 0xC0210006,
+    /// STATUS_FVE_FS_MOUNTED
+    FveFsMounted = 0xC0210007,
+    /// STATUS_FVE_NO_LICENSE
+    FveNoLicense = 0xC0210008,
+    /// STATUS_FVE_ACTION_NOT_ALLOWED
+    FveActionNotAllowed = 0xC0210009,
+    /// STATUS_FVE_BAD_DATA
+    FveBadData = 0xC021000A,
+    /// STATUS_FVE_VOLUME_NOT_BOUND
+    FveVolumeNotBound = 0xC021000B,
+    /// STATUS_FVE_NOT_DATA_VOLUME
+    FveNotDataVolume = 0xC021000C,
+    /// STATUS_FVE_CONV_READ_ERROR
+    FveConvReadError = 0xC021000D,
+    /// STATUS_FVE_CONV_WRITE_ERROR
+    FveConvWriteError = 0xC021000E,
+    /// STATUS_FVE_OVERLAPPED_UPDATE
+    FveOverlappedUpdate = 0xC021000F,
+    /// STATUS_FVE_FAILED_SECTOR_SIZE
+    FveFailedSectorSize = 0xC0210010,
+    /// STATUS_FVE_FAILED_AUTHENTICATION
+    FveFailedAuthentication = 0xC0210011,
+    /// STATUS_FVE_NOT_OS_VOLUME
+    FveNotOsVolume = 0xC0210012,
+    /// STATUS_FVE_KEYFILE_NOT_FOUND
+    FveKeyfileNotFound = 0xC0210013,
+    /// STATUS_FVE_KEYFILE_INVALID
+    FveKeyfileInvalid = 0xC0210014,
+    /// STATUS_FVE_KEYFILE_NO_VMK
+    FveKeyfileNoVmk = 0xC0210015,
+    /// STATUS_FVE_TPM_DISABLED
+    FveTpmDisabled = 0xC0210016,
+    /// STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO
+    FveTpmSrkAuthNotZero = 0xC0210017,
+    /// STATUS_FVE_TPM_INVALID_PCR
+    FveTpmInvalidPcr = 0xC0210018,
+    /// STATUS_FVE_TPM_NO_VMK
+    FveTpmNoVmk = 0xC0210019,
+    /// STATUS_FVE_PIN_INVALID
+    FvePinInvalid = 0xC021001A,
+    /// STATUS_FVE_AUTH_INVALID_APPLICATION
+    FveAuthInvalidApplication = 0xC021001B,
+    /// STATUS_FVE_AUTH_INVALID_CONFIG
+    FveAuthInvalidConfig = 0xC021001C,
+    /// STATUS_FVE_DEBUGGER_ENABLED
+    FveDebuggerEnabled = 0xC021001D,
+    /// STATUS_FVE_DRY_RUN_FAILED
+    FveDryRunFailed = 0xC021001E,
+    /// STATUS_FVE_BAD_METADATA_POINTER
+    FveBadMetadataPointer = 0xC021001F,
+    /// STATUS_FVE_OLD_METADATA_COPY
+    FveOldMetadataCopy = 0xC0210020,
+    /// STATUS_FVE_REBOOT_REQUIRED
+    FveRebootRequired = 0xC0210021,
+    /// STATUS_FVE_RAW_ACCESS
+    FveRawAccess = 0xC0210022,
+    /// STATUS_FVE_RAW_BLOCKED
+    FveRawBlocked = 0xC0210023,
+    /// STATUS_FVE_NO_AUTOUNLOCK_MASTER_KEY
+    FveNoAutounlockMasterKey = 0xC0210024,
+    /// STATUS_FVE_MOR_FAILED
+    FveMorFailed = 0xC0210025,
+    /// STATUS_FVE_NO_FEATURE_LICENSE
+    FveNoFeatureLicense = 0xC0210026,
+    /// STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED
+    FvePolicyUserDisableRdvNotAllowed = 0xC0210027,
+    /// STATUS_FVE_CONV_RECOVERY_FAILED
+    FveConvRecoveryFailed = 0xC0210028,
+    /// STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG
+    FveVirtualizedSpaceTooBig = 0xC0210029,
+    /// STATUS_FVE_INVALID_DATUM_TYPE
+    FveInvalidDatumType = 0xC021002A,
+    /// STATUS_FVE_VOLUME_TOO_SMALL
+    FveVolumeTooSmall = 0xC0210030,
+    /// STATUS_FVE_ENH_PIN_INVALID
+    FveEnhPinInvalid = 0xC0210031,
+    /// STATUS_FVE_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE
+    FveFullEncryptionNotAllowedOnTpStorage = 0xC0210032,
+    /// STATUS_FVE_WIPE_NOT_ALLOWED_ON_TP_STORAGE
+    FveWipeNotAllowedOnTpStorage = 0xC0210033,
+    /// STATUS_FVE_NOT_ALLOWED_ON_CSV_STACK
+    FveNotAllowedOnCsvStack = 0xC0210034,
+    /// STATUS_FVE_NOT_ALLOWED_ON_CLUSTER
+    FveNotAllowedOnCluster = 0xC0210035,
+    /// STATUS_FVE_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING
+    FveNotAllowedToUpgradeWhileConverting = 0xC0210036,
+    /// STATUS_FVE_WIPE_CANCEL_NOT_APPLICABLE
+    FveWipeCancelNotApplicable = 0xC0210037,
+    /// STATUS_FVE_EDRIVE_DRY_RUN_FAILED
+    FveEdriveDryRunFailed = 0xC0210038,
+    /// STATUS_FVE_SECUREBOOT_DISABLED
+    FveSecurebootDisabled = 0xC0210039,
+    /// STATUS_FVE_SECUREBOOT_CONFIG_CHANGE
+    FveSecurebootConfigChange = 0xC021003A,
+    /// STATUS_FVE_DEVICE_LOCKEDOUT
+    FveDeviceLockedout = 0xC021003B,
+    /// STATUS_FVE_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT
+    FveVolumeExtendPreventsEowDecrypt = 0xC021003C,
+    /// STATUS_FVE_NOT_DE_VOLUME
+    FveNotDeVolume = 0xC021003D,
+    /// STATUS_FVE_PROTECTION_DISABLED
+    FveProtectionDisabled = 0xC021003E,
+    /// STATUS_FVE_PROTECTION_CANNOT_BE_DISABLED
+    FveProtectionCannotBeDisabled = 0xC021003F,
+    /// STATUS_FVE_OSV_KSR_NOT_ALLOWED
+    FveOsvKsrNotAllowed = 0xC0210040,
+    /// STATUS_FVE_EDRIVE_BAND_ENUMERATION_FAILED
+    FveEdriveBandEnumerationFailed = 0xC0210041,
+    /// STATUS_FVE_POLICY_ON_RDV_EXCLUSION_LIST
+    FvePolicyOnRdvExclusionList = 0xC0210042,
+    /// STATUS_FVE_DATASET_FULL
+    FveDatasetFull = 0xC0210043,
+    /// STATUS_FVE_METADATA_FULL
+    FveMetadataFull = 0xC0210044,
+    /// STATUS_FWP_CALLOUT_NOT_FOUND
+    FwpCalloutNotFound = 0xC0220001,
+    /// STATUS_FWP_CONDITION_NOT_FOUND
+    FwpConditionNotFound = 0xC0220002,
+    /// STATUS_FWP_FILTER_NOT_FOUND
+    FwpFilterNotFound = 0xC0220003,
+    /// STATUS_FWP_LAYER_NOT_FOUND
+    FwpLayerNotFound = 0xC0220004,
+    /// STATUS_FWP_PROVIDER_NOT_FOUND
+    FwpProviderNotFound = 0xC0220005,
+    /// STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND
+    FwpProviderContextNotFound = 0xC0220006,
+    /// STATUS_FWP_SUBLAYER_NOT_FOUND
+    FwpSublayerNotFound = 0xC0220007,
+    /// STATUS_FWP_NOT_FOUND
+    FwpNotFound = 0xC0220008,
+    /// STATUS_FWP_ALREADY_EXISTS
+    FwpAlreadyExists = 0xC0220009,
+    /// STATUS_FWP_IN_USE
+    FwpInUse = 0xC022000A,
+    /// STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS
+    FwpDynamicSessionInProgress = 0xC022000B,
+    /// STATUS_FWP_WRONG_SESSION
+    FwpWrongSession = 0xC022000C,
+    /// STATUS_FWP_NO_TXN_IN_PROGRESS
+    FwpNoTxnInProgress = 0xC022000D,
+    /// STATUS_FWP_TXN_IN_PROGRESS
+    FwpTxnInProgress = 0xC022000E,
+    /// STATUS_FWP_TXN_ABORTED
+    FwpTxnAborted = 0xC022000F,
+    /// STATUS_FWP_SESSION_ABORTED
+    FwpSessionAborted = 0xC0220010,
+    /// STATUS_FWP_INCOMPATIBLE_TXN
+    FwpIncompatibleTxn = 0xC0220011,
+    /// STATUS_FWP_TIMEOUT
+    FwpTimeout = 0xC0220012,
+    /// STATUS_FWP_NET_EVENTS_DISABLED
+    FwpNetEventsDisabled = 0xC0220013,
+    /// STATUS_FWP_INCOMPATIBLE_LAYER
+    FwpIncompatibleLayer = 0xC0220014,
+    /// STATUS_FWP_KM_CLIENTS_ONLY
+    FwpKmClientsOnly = 0xC0220015,
+    /// STATUS_FWP_LIFETIME_MISMATCH
+    FwpLifetimeMismatch = 0xC0220016,
+    /// STATUS_FWP_BUILTIN_OBJECT
+    FwpBuiltinObject = 0xC0220017,
+    /// STATUS_FWP_TOO_MANY_CALLOUTS
+    FwpTooManyCallouts = 0xC0220018,
+    /// STATUS_FWP_NOTIFICATION_DROPPED
+    FwpNotificationDropped = 0xC0220019,
+    /// STATUS_FWP_TRAFFIC_MISMATCH
+    FwpTrafficMismatch = 0xC022001A,
+    /// STATUS_FWP_INCOMPATIBLE_SA_STATE
+    FwpIncompatibleSaState = 0xC022001B,
+    /// STATUS_FWP_NULL_POINTER
+    FwpNullPointer = 0xC022001C,
+    /// STATUS_FWP_INVALID_ENUMERATOR
+    FwpInvalidEnumerator = 0xC022001D,
+    /// STATUS_FWP_INVALID_FLAGS
+    FwpInvalidFlags = 0xC022001E,
+    /// STATUS_FWP_INVALID_NET_MASK
+    FwpInvalidNetMask = 0xC022001F,
+    /// STATUS_FWP_INVALID_RANGE
+    FwpInvalidRange = 0xC0220020,
+    /// STATUS_FWP_INVALID_INTERVAL
+    FwpInvalidInterval = 0xC0220021,
+    /// STATUS_FWP_ZERO_LENGTH_ARRAY
+    FwpZeroLengthArray = 0xC0220022,
+    /// STATUS_FWP_NULL_DISPLAY_NAME
+    FwpNullDisplayName = 0xC0220023,
+    /// STATUS_FWP_INVALID_ACTION_TYPE
+    FwpInvalidActionType = 0xC0220024,
+    /// STATUS_FWP_INVALID_WEIGHT
+    FwpInvalidWeight = 0xC0220025,
+    /// STATUS_FWP_MATCH_TYPE_MISMATCH
+    FwpMatchTypeMismatch = 0xC0220026,
+    /// STATUS_FWP_TYPE_MISMATCH
+    FwpTypeMismatch = 0xC0220027,
+    /// STATUS_FWP_OUT_OF_BOUNDS
+    FwpOutOfBounds = 0xC0220028,
+    /// STATUS_FWP_RESERVED
+    FwpReserved = 0xC0220029,
+    /// STATUS_FWP_DUPLICATE_CONDITION
+    FwpDuplicateCondition = 0xC022002A,
+    /// STATUS_FWP_DUPLICATE_KEYMOD
+    FwpDuplicateKeymod = 0xC022002B,
+    /// STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER
+    FwpActionIncompatibleWithLayer = 0xC022002C,
+    /// STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER
+    FwpActionIncompatibleWithSublayer = 0xC022002D,
+    /// STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER
+    FwpContextIncompatibleWithLayer = 0xC022002E,
+    /// STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT
+    FwpContextIncompatibleWithCallout = 0xC022002F,
+    /// STATUS_FWP_INCOMPATIBLE_AUTH_METHOD
+    FwpIncompatibleAuthMethod = 0xC0220030,
+    /// STATUS_FWP_INCOMPATIBLE_DH_GROUP
+    FwpIncompatibleDhGroup = 0xC0220031,
+    /// STATUS_FWP_EM_NOT_SUPPORTED
+    FwpEmNotSupported = 0xC0220032,
+    /// STATUS_FWP_NEVER_MATCH
+    FwpNeverMatch = 0xC0220033,
+    /// STATUS_FWP_PROVIDER_CONTEXT_MISMATCH
+    FwpProviderContextMismatch = 0xC0220034,
+    /// STATUS_FWP_INVALID_PARAMETER
+    FwpInvalidParameter = 0xC0220035,
+    /// STATUS_FWP_TOO_MANY_SUBLAYERS
+    FwpTooManySublayers = 0xC0220036,
+    /// STATUS_FWP_CALLOUT_NOTIFICATION_FAILED
+    FwpCalloutNotificationFailed = 0xC0220037,
+    /// STATUS_FWP_INVALID_AUTH_TRANSFORM
+    FwpInvalidAuthTransform = 0xC0220038,
+    /// STATUS_FWP_INVALID_CIPHER_TRANSFORM
+    FwpInvalidCipherTransform = 0xC0220039,
+    /// STATUS_FWP_INCOMPATIBLE_CIPHER_TRANSFORM
+    FwpIncompatibleCipherTransform = 0xC022003A,
+    /// STATUS_FWP_INVALID_TRANSFORM_COMBINATION
+    FwpInvalidTransformCombination = 0xC022003B,
+    /// STATUS_FWP_DUPLICATE_AUTH_METHOD
+    FwpDuplicateAuthMethod = 0xC022003C,
+    /// STATUS_FWP_INVALID_TUNNEL_ENDPOINT
+    FwpInvalidTunnelEndpoint = 0xC022003D,
+    /// STATUS_FWP_L2_DRIVER_NOT_READY
+    FwpL2DriverNotReady = 0xC022003E,
+    /// STATUS_FWP_KEY_DICTATOR_ALREADY_REGISTERED
+    FwpKeyDictatorAlreadyRegistered = 0xC022003F,
+    /// STATUS_FWP_KEY_DICTATION_INVALID_KEYING_MATERIAL
+    FwpKeyDictationInvalidKeyingMaterial = 0xC0220040,
+    /// STATUS_FWP_CONNECTIONS_DISABLED
+    FwpConnectionsDisabled = 0xC0220041,
+    /// STATUS_FWP_INVALID_DNS_NAME
+    FwpInvalidDnsName = 0xC0220042,
+    /// STATUS_FWP_STILL_ON
+    FwpStillOn = 0xC0220043,
+    /// STATUS_FWP_IKEEXT_NOT_RUNNING
+    FwpIkeextNotRunning = 0xC0220044,
+    /// STATUS_FWP_TCPIP_NOT_READY
+    FwpTcpipNotReady = 0xC0220100,
+    /// STATUS_FWP_INJECT_HANDLE_CLOSING
+    FwpInjectHandleClosing = 0xC0220101,
+    /// STATUS_FWP_INJECT_HANDLE_STALE
+    FwpInjectHandleStale = 0xC0220102,
+    /// STATUS_FWP_CANNOT_PEND
+    FwpCannotPend = 0xC0220103,
+    /// STATUS_FWP_DROP_NOICMP
+    FwpDropNoicmp = 0xC0220104,
+    /// STATUS_NDIS_CLOSING
+    NdisClosing = 0xC0230002,
+    /// STATUS_NDIS_BAD_VERSION
+    NdisBadVersion = 0xC0230004,
+    /// STATUS_NDIS_BAD_CHARACTERISTICS
+    NdisBadCharacteristics = 0xC0230005,
+    /// STATUS_NDIS_ADAPTER_NOT_FOUND
+    NdisAdapterNotFound = 0xC0230006,
+    /// STATUS_NDIS_OPEN_FAILED
+    NdisOpenFailed = 0xC0230007,
+    /// STATUS_NDIS_DEVICE_FAILED
+    NdisDeviceFailed = 0xC0230008,
+    /// STATUS_NDIS_MULTICAST_FULL
+    NdisMulticastFull = 0xC0230009,
+    /// STATUS_NDIS_MULTICAST_EXISTS
+    NdisMulticastExists = 0xC023000A,
+    /// STATUS_NDIS_MULTICAST_NOT_FOUND
+    NdisMulticastNotFound = 0xC023000B,
+    /// STATUS_NDIS_REQUEST_ABORTED
+    NdisRequestAborted = 0xC023000C,
+    /// STATUS_NDIS_RESET_IN_PROGRESS
+    NdisResetInProgress = 0xC023000D,
+    /// STATUS_NDIS_INVALID_PACKET
+    NdisInvalidPacket = 0xC023000F,
+    /// STATUS_NDIS_INVALID_DEVICE_REQUEST
+    NdisInvalidDeviceRequest = 0xC0230010,
+    /// STATUS_NDIS_ADAPTER_NOT_READY
+    NdisAdapterNotReady = 0xC0230011,
+    /// STATUS_NDIS_INVALID_LENGTH
+    NdisInvalidLength = 0xC0230014,
+    /// STATUS_NDIS_INVALID_DATA
+    NdisInvalidData = 0xC0230015,
+    /// STATUS_NDIS_BUFFER_TOO_SHORT
+    NdisBufferTooShort = 0xC0230016,
+    /// STATUS_NDIS_INVALID_OID
+    NdisInvalidOid = 0xC0230017,
+    /// STATUS_NDIS_ADAPTER_REMOVED
+    NdisAdapterRemoved = 0xC0230018,
+    /// STATUS_NDIS_UNSUPPORTED_MEDIA
+    NdisUnsupportedMedia = 0xC0230019,
+    /// STATUS_NDIS_GROUP_ADDRESS_IN_USE
+    NdisGroupAddressInUse = 0xC023001A,
+    /// STATUS_NDIS_FILE_NOT_FOUND
+    NdisFileNotFound = 0xC023001B,
+    /// STATUS_NDIS_ERROR_READING_FILE
+    NdisErrorReadingFile = 0xC023001C,
+    /// STATUS_NDIS_ALREADY_MAPPED
+    NdisAlreadyMapped = 0xC023001D,
+    /// STATUS_NDIS_RESOURCE_CONFLICT
+    NdisResourceConflict = 0xC023001E,
+    /// STATUS_NDIS_MEDIA_DISCONNECTED
+    NdisMediaDisconnected = 0xC023001F,
+    /// STATUS_NDIS_INVALID_ADDRESS
+    NdisInvalidAddress = 0xC0230022,
+    /// STATUS_NDIS_PAUSED
+    NdisPaused = 0xC023002A,
+    /// STATUS_NDIS_INTERFACE_NOT_FOUND
+    NdisInterfaceNotFound = 0xC023002B,
+    /// STATUS_NDIS_UNSUPPORTED_REVISION
+    NdisUnsupportedRevision = 0xC023002C,
+    /// STATUS_NDIS_INVALID_PORT
+    NdisInvalidPort = 0xC023002D,
+    /// STATUS_NDIS_INVALID_PORT_STATE
+    NdisInvalidPortState = 0xC023002E,
+    /// STATUS_NDIS_LOW_POWER_STATE
+    NdisLowPowerState = 0xC023002F,
+    /// STATUS_NDIS_REINIT_REQUIRED
+    NdisReinitRequired = 0xC0230030,
+    /// STATUS_NDIS_NO_QUEUES
+    NdisNoQueues = 0xC0230031,
+    /// STATUS_NDIS_NOT_SUPPORTED
+    NdisNotSupported = 0xC02300BB,
+    /// STATUS_NDIS_OFFLOAD_POLICY
+    NdisOffloadPolicy = 0xC023100F,
+    /// STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED
+    NdisOffloadConnectionRejected = 0xC0231012,
+    /// STATUS_NDIS_OFFLOAD_PATH_REJECTED
+    NdisOffloadPathRejected = 0xC0231013,
+    /// STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED
+    NdisDot11AutoConfigEnabled = 0xC0232000,
+    /// STATUS_NDIS_DOT11_MEDIA_IN_USE
+    NdisDot11MediaInUse = 0xC0232001,
+    /// STATUS_NDIS_DOT11_POWER_STATE_INVALID
+    NdisDot11PowerStateInvalid = 0xC0232002,
+    /// STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL
+    NdisPmWolPatternListFull = 0xC0232003,
+    /// STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL
+    NdisPmProtocolOffloadListFull = 0xC0232004,
+    /// STATUS_NDIS_DOT11_AP_CHANNEL_CURRENTLY_NOT_AVAILABLE
+    NdisDot11ApChannelCurrentlyNotAvailable = 0xC0232005,
+    /// STATUS_NDIS_DOT11_AP_BAND_CURRENTLY_NOT_AVAILABLE
+    NdisDot11ApBandCurrentlyNotAvailable = 0xC0232006,
+    /// STATUS_NDIS_DOT11_AP_CHANNEL_NOT_ALLOWED
+    NdisDot11ApChannelNotAllowed = 0xC0232007,
+    /// STATUS_NDIS_DOT11_AP_BAND_NOT_ALLOWED
+    NdisDot11ApBandNotAllowed = 0xC0232008,
+    /// STATUS_QUIC_HANDSHAKE_FAILURE
+    QuicHandshakeFailure = 0xC0240000,
+    /// STATUS_QUIC_VER_NEG_FAILURE
+    QuicVerNegFailure = 0xC0240001,
+    /// STATUS_QUIC_USER_CANCELED
+    QuicUserCanceled = 0xC0240002,
+    /// STATUS_QUIC_INTERNAL_ERROR
+    QuicInternalError = 0xC0240003,
+    /// STATUS_QUIC_PROTOCOL_VIOLATION
+    QuicProtocolViolation = 0xC0240004,
+    /// STATUS_QUIC_CONNECTION_IDLE
+    QuicConnectionIdle = 0xC0240005,
+    /// STATUS_QUIC_CONNECTION_TIMEOUT
+    QuicConnectionTimeout = 0xC0240006,
+    /// STATUS_QUIC_ALPN_NEG_FAILURE
+    QuicAlpnNegFailure = 0xC0240007,
+    /// STATUS_TPM_ERROR_MASK
+    TpmErrorMask = 0xC0290000,
+    /// STATUS_TPM_AUTHFAIL
+    TpmAuthfail = 0xC0290001,
+    /// STATUS_TPM_BADINDEX
+    TpmBadindex = 0xC0290002,
+    /// STATUS_TPM_BAD_PARAMETER
+    TpmBadParameter = 0xC0290003,
+    /// STATUS_TPM_AUDITFAILURE
+    TpmAuditfailure = 0xC0290004,
+    /// STATUS_TPM_CLEAR_DISABLED
+    TpmClearDisabled = 0xC0290005,
+    /// STATUS_TPM_DEACTIVATED
+    TpmDeactivated = 0xC0290006,
+    /// STATUS_TPM_DISABLED
+    TpmDisabled = 0xC0290007,
+    /// STATUS_TPM_DISABLED_CMD
+    TpmDisabledCmd = 0xC0290008,
+    /// STATUS_TPM_FAIL
+    TpmFail = 0xC0290009,
+    /// STATUS_TPM_BAD_ORDINAL
+    TpmBadOrdinal = 0xC029000A,
+    /// STATUS_TPM_INSTALL_DISABLED
+    TpmInstallDisabled = 0xC029000B,
+    /// STATUS_TPM_INVALID_KEYHANDLE
+    TpmInvalidKeyhandle = 0xC029000C,
+    /// STATUS_TPM_KEYNOTFOUND
+    TpmKeynotfound = 0xC029000D,
+    /// STATUS_TPM_INAPPROPRIATE_ENC
+    TpmInappropriateEnc = 0xC029000E,
+    /// STATUS_TPM_MIGRATEFAIL
+    TpmMigratefail = 0xC029000F,
+    /// STATUS_TPM_INVALID_PCR_INFO
+    TpmInvalidPcrInfo = 0xC0290010,
+    /// STATUS_TPM_NOSPACE
+    TpmNospace = 0xC0290011,
+    /// STATUS_TPM_NOSRK
+    TpmNosrk = 0xC0290012,
+    /// STATUS_TPM_NOTSEALED_BLOB
+    TpmNotsealedBlob = 0xC0290013,
+    /// STATUS_TPM_OWNER_SET
+    TpmOwnerSet = 0xC0290014,
+    /// STATUS_TPM_RESOURCES
+    TpmResources = 0xC0290015,
+    /// STATUS_TPM_SHORTRANDOM
+    TpmShortrandom = 0xC0290016,
+    /// STATUS_TPM_SIZE
+    TpmSize = 0xC0290017,
+    /// STATUS_TPM_WRONGPCRVAL
+    TpmWrongpcrval = 0xC0290018,
+    /// STATUS_TPM_BAD_PARAM_SIZE
+    TpmBadParamSize = 0xC0290019,
+    /// STATUS_TPM_SHA_THREAD
+    TpmShaThread = 0xC029001A,
+    /// STATUS_TPM_SHA_ERROR
+    TpmShaError = 0xC029001B,
+    /// STATUS_TPM_FAILEDSELFTEST
+    TpmFailedselftest = 0xC029001C,
+    /// STATUS_TPM_AUTH2FAIL
+    TpmAuth2fail = 0xC029001D,
+    /// STATUS_TPM_BADTAG
+    TpmBadtag = 0xC029001E,
+    /// STATUS_TPM_IOERROR
+    TpmIoerror = 0xC029001F,
+    /// STATUS_TPM_ENCRYPT_ERROR
+    TpmEncryptError = 0xC0290020,
+    /// STATUS_TPM_DECRYPT_ERROR
+    TpmDecryptError = 0xC0290021,
+    /// STATUS_TPM_INVALID_AUTHHANDLE
+    TpmInvalidAuthhandle = 0xC0290022,
+    /// STATUS_TPM_NO_ENDORSEMENT
+    TpmNoEndorsement = 0xC0290023,
+    /// STATUS_TPM_INVALID_KEYUSAGE
+    TpmInvalidKeyusage = 0xC0290024,
+    /// STATUS_TPM_WRONG_ENTITYTYPE
+    TpmWrongEntitytype = 0xC0290025,
+    /// STATUS_TPM_INVALID_POSTINIT
+    TpmInvalidPostinit = 0xC0290026,
+    /// STATUS_TPM_INAPPROPRIATE_SIG
+    TpmInappropriateSig = 0xC0290027,
+    /// STATUS_TPM_BAD_KEY_PROPERTY
+    TpmBadKeyProperty = 0xC0290028,
+    /// STATUS_TPM_BAD_MIGRATION
+    TpmBadMigration = 0xC0290029,
+    /// STATUS_TPM_BAD_SCHEME
+    TpmBadScheme = 0xC029002A,
+    /// STATUS_TPM_BAD_DATASIZE
+    TpmBadDatasize = 0xC029002B,
+    /// STATUS_TPM_BAD_MODE
+    TpmBadMode = 0xC029002C,
+    /// STATUS_TPM_BAD_PRESENCE
+    TpmBadPresence = 0xC029002D,
+    /// STATUS_TPM_BAD_VERSION
+    TpmBadVersion = 0xC029002E,
+    /// STATUS_TPM_NO_WRAP_TRANSPORT
+    TpmNoWrapTransport = 0xC029002F,
+    /// STATUS_TPM_AUDITFAIL_UNSUCCESSFUL
+    TpmAuditfailUnsuccessful = 0xC0290030,
+    /// STATUS_TPM_AUDITFAIL_SUCCESSFUL
+    TpmAuditfailSuccessful = 0xC0290031,
+    /// STATUS_TPM_NOTRESETABLE
+    TpmNotresetable = 0xC0290032,
+    /// STATUS_TPM_NOTLOCAL
+    TpmNotlocal = 0xC0290033,
+    /// STATUS_TPM_BAD_TYPE
+    TpmBadType = 0xC0290034,
+    /// STATUS_TPM_INVALID_RESOURCE
+    TpmInvalidResource = 0xC0290035,
+    /// STATUS_TPM_NOTFIPS
+    TpmNotfips = 0xC0290036,
+    /// STATUS_TPM_INVALID_FAMILY
+    TpmInvalidFamily = 0xC0290037,
+    /// STATUS_TPM_NO_NV_PERMISSION
+    TpmNoNvPermission = 0xC0290038,
+    /// STATUS_TPM_REQUIRES_SIGN
+    TpmRequiresSign = 0xC0290039,
+    /// STATUS_TPM_KEY_NOTSUPPORTED
+    TpmKeyNotsupported = 0xC029003A,
+    /// STATUS_TPM_AUTH_CONFLICT
+    TpmAuthConflict = 0xC029003B,
+    /// STATUS_TPM_AREA_LOCKED
+    TpmAreaLocked = 0xC029003C,
+    /// STATUS_TPM_BAD_LOCALITY
+    TpmBadLocality = 0xC029003D,
+    /// STATUS_TPM_READ_ONLY
+    TpmReadOnly = 0xC029003E,
+    /// STATUS_TPM_PER_NOWRITE
+    TpmPerNowrite = 0xC029003F,
+    /// STATUS_TPM_FAMILYCOUNT
+    TpmFamilycount = 0xC0290040,
+    /// STATUS_TPM_WRITE_LOCKED
+    TpmWriteLocked = 0xC0290041,
+    /// STATUS_TPM_BAD_ATTRIBUTES
+    TpmBadAttributes = 0xC0290042,
+    /// STATUS_TPM_INVALID_STRUCTURE
+    TpmInvalidStructure = 0xC0290043,
+    /// STATUS_TPM_KEY_OWNER_CONTROL
+    TpmKeyOwnerControl = 0xC0290044,
+    /// STATUS_TPM_BAD_COUNTER
+    TpmBadCounter = 0xC0290045,
+    /// STATUS_TPM_NOT_FULLWRITE
+    TpmNotFullwrite = 0xC0290046,
+    /// STATUS_TPM_CONTEXT_GAP
+    TpmContextGap = 0xC0290047,
+    /// STATUS_TPM_MAXNVWRITES
+    TpmMaxnvwrites = 0xC0290048,
+    /// STATUS_TPM_NOOPERATOR
+    TpmNooperator = 0xC0290049,
+    /// STATUS_TPM_RESOURCEMISSING
+    TpmResourcemissing = 0xC029004A,
+    /// STATUS_TPM_DELEGATE_LOCK
+    TpmDelegateLock = 0xC029004B,
+    /// STATUS_TPM_DELEGATE_FAMILY
+    TpmDelegateFamily = 0xC029004C,
+    /// STATUS_TPM_DELEGATE_ADMIN
+    TpmDelegateAdmin = 0xC029004D,
+    /// STATUS_TPM_TRANSPORT_NOTEXCLUSIVE
+    TpmTransportNotexclusive = 0xC029004E,
+    /// STATUS_TPM_OWNER_CONTROL
+    TpmOwnerControl = 0xC029004F,
+    /// STATUS_TPM_DAA_RESOURCES
+    TpmDaaResources = 0xC0290050,
+    /// STATUS_TPM_DAA_INPUT_DATA0
+    TpmDaaInputData0 = 0xC0290051,
+    /// STATUS_TPM_DAA_INPUT_DATA1
+    TpmDaaInputData1 = 0xC0290052,
+    /// STATUS_TPM_DAA_ISSUER_SETTINGS
+    TpmDaaIssuerSettings = 0xC0290053,
+    /// STATUS_TPM_DAA_TPM_SETTINGS
+    TpmDaaTpmSettings = 0xC0290054,
+    /// STATUS_TPM_DAA_STAGE
+    TpmDaaStage = 0xC0290055,
+    /// STATUS_TPM_DAA_ISSUER_VALIDITY
+    TpmDaaIssuerValidity = 0xC0290056,
+    /// STATUS_TPM_DAA_WRONG_W
+    TpmDaaWrongW = 0xC0290057,
+    /// STATUS_TPM_BAD_HANDLE
+    TpmBadHandle = 0xC0290058,
+    /// STATUS_TPM_BAD_DELEGATE
+    TpmBadDelegate = 0xC0290059,
+    /// STATUS_TPM_BADCONTEXT
+    TpmBadcontext = 0xC029005A,
+    /// STATUS_TPM_TOOMANYCONTEXTS
+    TpmToomanycontexts = 0xC029005B,
+    /// STATUS_TPM_MA_TICKET_SIGNATURE
+    TpmMaTicketSignature = 0xC029005C,
+    /// STATUS_TPM_MA_DESTINATION
+    TpmMaDestination = 0xC029005D,
+    /// STATUS_TPM_MA_SOURCE
+    TpmMaSource = 0xC029005E,
+    /// STATUS_TPM_MA_AUTHORITY
+    TpmMaAuthority = 0xC029005F,
+    /// STATUS_TPM_PERMANENTEK
+    TpmPermanentek = 0xC0290061,
+    /// STATUS_TPM_BAD_SIGNATURE
+    TpmBadSignature = 0xC0290062,
+    /// STATUS_TPM_NOCONTEXTSPACE
+    TpmNocontextspace = 0xC0290063,
+    /// STATUS_TPM_20_E_ASYMMETRIC
+    Tpm20EAsymmetric = 0xC0290081,
+    /// STATUS_TPM_20_E_ATTRIBUTES
+    Tpm20EAttributes = 0xC0290082,
+    /// STATUS_TPM_20_E_HASH
+    Tpm20EHash = 0xC0290083,
+    /// STATUS_TPM_20_E_VALUE
+    Tpm20EValue = 0xC0290084,
+    /// STATUS_TPM_20_E_HIERARCHY
+    Tpm20EHierarchy = 0xC0290085,
+    /// STATUS_TPM_20_E_KEY_SIZE
+    Tpm20EKeySize = 0xC0290087,
+    /// STATUS_TPM_20_E_MGF
+    Tpm20EMgf = 0xC0290088,
+    /// STATUS_TPM_20_E_MODE
+    Tpm20EMode = 0xC0290089,
+    /// STATUS_TPM_20_E_TYPE
+    Tpm20EType = 0xC029008A,
+    /// STATUS_TPM_20_E_HANDLE
+    Tpm20EHandle = 0xC029008B,
+    /// STATUS_TPM_20_E_KDF
+    Tpm20EKdf = 0xC029008C,
+    /// STATUS_TPM_20_E_RANGE
+    Tpm20ERange = 0xC029008D,
+    /// STATUS_TPM_20_E_AUTH_FAIL
+    Tpm20EAuthFail = 0xC029008E,
+    /// STATUS_TPM_20_E_NONCE
+    Tpm20ENonce = 0xC029008F,
+    /// STATUS_TPM_20_E_PP
+    Tpm20EPp = 0xC0290090,
+    /// STATUS_TPM_20_E_SCHEME
+    Tpm20EScheme = 0xC0290092,
+    /// STATUS_TPM_20_E_SIZE
+    Tpm20ESize = 0xC0290095,
+    /// STATUS_TPM_20_E_SYMMETRIC
+    Tpm20ESymmetric = 0xC0290096,
+    /// STATUS_TPM_20_E_TAG
+    Tpm20ETag = 0xC0290097,
+    /// STATUS_TPM_20_E_SELECTOR
+    Tpm20ESelector = 0xC0290098,
+    /// STATUS_TPM_20_E_INSUFFICIENT
+    Tpm20EInsufficient = 0xC029009A,
+    /// STATUS_TPM_20_E_SIGNATURE
+    Tpm20ESignature = 0xC029009B,
+    /// STATUS_TPM_20_E_KEY
+    Tpm20EKey = 0xC029009C,
+    /// STATUS_TPM_20_E_POLICY_FAIL
+    Tpm20EPolicyFail = 0xC029009D,
+    /// STATUS_TPM_20_E_INTEGRITY
+    Tpm20EIntegrity = 0xC029009F,
+    /// STATUS_TPM_20_E_TICKET
+    Tpm20ETicket = 0xC02900A0,
+    /// STATUS_TPM_20_E_RESERVED_BITS
+    Tpm20EReservedBits = 0xC02900A1,
+    /// STATUS_TPM_20_E_BAD_AUTH
+    Tpm20EBadAuth = 0xC02900A2,
+    /// STATUS_TPM_20_E_EXPIRED
+    Tpm20EExpired = 0xC02900A3,
+    /// STATUS_TPM_20_E_POLICY_CC
+    Tpm20EPolicyCc = 0xC02900A4,
+    /// STATUS_TPM_20_E_BINDING
+    Tpm20EBinding = 0xC02900A5,
+    /// STATUS_TPM_20_E_CURVE
+    Tpm20ECurve = 0xC02900A6,
+    /// STATUS_TPM_20_E_ECC_POINT
+    Tpm20EEccPoint = 0xC02900A7,
+    /// STATUS_TPM_20_E_INITIALIZE
+    Tpm20EInitialize = 0xC0290100,
+    /// STATUS_TPM_20_E_FAILURE
+    Tpm20EFailure = 0xC0290101,
+    /// STATUS_TPM_20_E_SEQUENCE
+    Tpm20ESequence = 0xC0290103,
+    /// STATUS_TPM_20_E_PRIVATE
+    Tpm20EPrivate = 0xC029010B,
+    /// STATUS_TPM_20_E_HMAC
+    Tpm20EHmac = 0xC0290119,
+    /// STATUS_TPM_20_E_DISABLED
+    Tpm20EDisabled = 0xC0290120,
+    /// STATUS_TPM_20_E_EXCLUSIVE
+    Tpm20EExclusive = 0xC0290121,
+    /// STATUS_TPM_20_E_ECC_CURVE
+    Tpm20EEccCurve = 0xC0290123,
+    /// STATUS_TPM_20_E_AUTH_TYPE
+    Tpm20EAuthType = 0xC0290124,
+    /// STATUS_TPM_20_E_AUTH_MISSING
+    Tpm20EAuthMissing = 0xC0290125,
+    /// STATUS_TPM_20_E_POLICY
+    Tpm20EPolicy = 0xC0290126,
+    /// STATUS_TPM_20_E_PCR
+    Tpm20EPcr = 0xC0290127,
+    /// STATUS_TPM_20_E_PCR_CHANGED
+    Tpm20EPcrChanged = 0xC0290128,
+    /// STATUS_TPM_20_E_UPGRADE
+    Tpm20EUpgrade = 0xC029012D,
+    /// STATUS_TPM_20_E_TOO_MANY_CONTEXTS
+    Tpm20ETooManyContexts = 0xC029012E,
+    /// STATUS_TPM_20_E_AUTH_UNAVAILABLE
+    Tpm20EAuthUnavailable = 0xC029012F,
+    /// STATUS_TPM_20_E_REBOOT
+    Tpm20EReboot = 0xC0290130,
+    /// STATUS_TPM_20_E_UNBALANCED
+    Tpm20EUnbalanced = 0xC0290131,
+    /// STATUS_TPM_20_E_COMMAND_SIZE
+    Tpm20ECommandSize = 0xC0290142,
+    /// STATUS_TPM_20_E_COMMAND_CODE
+    Tpm20ECommandCode = 0xC0290143,
+    /// STATUS_TPM_20_E_AUTHSIZE
+    Tpm20EAuthsize = 0xC0290144,
+    /// STATUS_TPM_20_E_AUTH_CONTEXT
+    Tpm20EAuthContext = 0xC0290145,
+    /// STATUS_TPM_20_E_NV_RANGE
+    Tpm20ENvRange = 0xC0290146,
+    /// STATUS_TPM_20_E_NV_SIZE
+    Tpm20ENvSize = 0xC0290147,
+    /// STATUS_TPM_20_E_NV_LOCKED
+    Tpm20ENvLocked = 0xC0290148,
+    /// STATUS_TPM_20_E_NV_AUTHORIZATION
+    Tpm20ENvAuthorization = 0xC0290149,
+    /// STATUS_TPM_20_E_NV_UNINITIALIZED
+    Tpm20ENvUninitialized = 0xC029014A,
+    /// STATUS_TPM_20_E_NV_SPACE
+    Tpm20ENvSpace = 0xC029014B,
+    /// STATUS_TPM_20_E_NV_DEFINED
+    Tpm20ENvDefined = 0xC029014C,
+    /// STATUS_TPM_20_E_BAD_CONTEXT
+    Tpm20EBadContext = 0xC0290150,
+    /// STATUS_TPM_20_E_CPHASH
+    Tpm20ECphash = 0xC0290151,
+    /// STATUS_TPM_20_E_PARENT
+    Tpm20EParent = 0xC0290152,
+    /// STATUS_TPM_20_E_NEEDS_TEST
+    Tpm20ENeedsTest = 0xC0290153,
+    /// STATUS_TPM_20_E_NO_RESULT
+    Tpm20ENoResult = 0xC0290154,
+    /// STATUS_TPM_20_E_SENSITIVE
+    Tpm20ESensitive = 0xC0290155,
+    /// STATUS_TPM_COMMAND_BLOCKED
+    TpmCommandBlocked = 0xC0290400,
+    /// STATUS_TPM_INVALID_HANDLE
+    TpmInvalidHandle = 0xC0290401,
+    /// STATUS_TPM_DUPLICATE_VHANDLE
+    TpmDuplicateVhandle = 0xC0290402,
+    /// STATUS_TPM_EMBEDDED_COMMAND_BLOCKED
+    TpmEmbeddedCommandBlocked = 0xC0290403,
+    /// STATUS_TPM_EMBEDDED_COMMAND_UNSUPPORTED
+    TpmEmbeddedCommandUnsupported = 0xC0290404,
+    /// STATUS_TPM_RETRY
+    TpmRetry = 0xC0290800,
+    /// STATUS_TPM_NEEDS_SELFTEST
+    TpmNeedsSelftest = 0xC0290801,
+    /// STATUS_TPM_DOING_SELFTEST
+    TpmDoingSelftest = 0xC0290802,
+    /// STATUS_TPM_DEFEND_LOCK_RUNNING
+    TpmDefendLockRunning = 0xC0290803,
+    /// STATUS_TPM_COMMAND_CANCELED
+    TpmCommandCanceled = 0xC0291001,
+    /// STATUS_TPM_TOO_MANY_CONTEXTS
+    TpmTooManyContexts = 0xC0291002,
+    /// STATUS_TPM_NOT_FOUND
+    TpmNotFound = 0xC0291003,
+    /// STATUS_TPM_ACCESS_DENIED
+    TpmAccessDenied = 0xC0291004,
+    /// STATUS_TPM_INSUFFICIENT_BUFFER
+    TpmInsufficientBuffer = 0xC0291005,
+    /// STATUS_TPM_PPI_FUNCTION_UNSUPPORTED
+    TpmPpiFunctionUnsupported = 0xC0291006,
+    /// STATUS_PCP_ERROR_MASK
+    PcpErrorMask = 0xC0292000,
+    /// STATUS_PCP_DEVICE_NOT_READY
+    PcpDeviceNotReady = 0xC0292001,
+    /// STATUS_PCP_INVALID_HANDLE
+    PcpInvalidHandle = 0xC0292002,
+    /// STATUS_PCP_INVALID_PARAMETER
+    PcpInvalidParameter = 0xC0292003,
+    /// STATUS_PCP_FLAG_NOT_SUPPORTED
+    PcpFlagNotSupported = 0xC0292004,
+    /// STATUS_PCP_NOT_SUPPORTED
+    PcpNotSupported = 0xC0292005,
+    /// STATUS_PCP_BUFFER_TOO_SMALL
+    PcpBufferTooSmall = 0xC0292006,
+    /// STATUS_PCP_INTERNAL_ERROR
+    PcpInternalError = 0xC0292007,
+    /// STATUS_PCP_AUTHENTICATION_FAILED
+    PcpAuthenticationFailed = 0xC0292008,
+    /// STATUS_PCP_AUTHENTICATION_IGNORED
+    PcpAuthenticationIgnored = 0xC0292009,
+    /// STATUS_PCP_POLICY_NOT_FOUND
+    PcpPolicyNotFound = 0xC029200A,
+    /// STATUS_PCP_PROFILE_NOT_FOUND
+    PcpProfileNotFound = 0xC029200B,
+    /// STATUS_PCP_VALIDATION_FAILED
+    PcpValidationFailed = 0xC029200C,
+    /// STATUS_PCP_DEVICE_NOT_FOUND
+    PcpDeviceNotFound = 0xC029200D,
+    /// STATUS_PCP_WRONG_PARENT
+    PcpWrongParent = 0xC029200E,
+    /// STATUS_PCP_KEY_NOT_LOADED
+    PcpKeyNotLoaded = 0xC029200F,
+    /// STATUS_PCP_NO_KEY_CERTIFICATION
+    PcpNoKeyCertification = 0xC0292010,
+    /// STATUS_PCP_KEY_NOT_FINALIZED
+    PcpKeyNotFinalized = 0xC0292011,
+    /// STATUS_PCP_ATTESTATION_CHALLENGE_NOT_SET
+    PcpAttestationChallengeNotSet = 0xC0292012,
+    /// STATUS_PCP_NOT_PCR_BOUND
+    PcpNotPcrBound = 0xC0292013,
+    /// STATUS_PCP_KEY_ALREADY_FINALIZED
+    PcpKeyAlreadyFinalized = 0xC0292014,
+    /// STATUS_PCP_KEY_USAGE_POLICY_NOT_SUPPORTED
+    PcpKeyUsagePolicyNotSupported = 0xC0292015,
+    /// STATUS_PCP_KEY_USAGE_POLICY_INVALID
+    PcpKeyUsagePolicyInvalid = 0xC0292016,
+    /// STATUS_PCP_SOFT_KEY_ERROR
+    PcpSoftKeyError = 0xC0292017,
+    /// STATUS_PCP_KEY_NOT_AUTHENTICATED
+    PcpKeyNotAuthenticated = 0xC0292018,
+    /// STATUS_PCP_KEY_NOT_AIK
+    PcpKeyNotAik = 0xC0292019,
+    /// STATUS_PCP_KEY_NOT_SIGNING_KEY
+    PcpKeyNotSigningKey = 0xC029201A,
+    /// STATUS_PCP_LOCKED_OUT
+    PcpLockedOut = 0xC029201B,
+    /// STATUS_PCP_CLAIM_TYPE_NOT_SUPPORTED
+    PcpClaimTypeNotSupported = 0xC029201C,
+    /// STATUS_PCP_TPM_VERSION_NOT_SUPPORTED
+    PcpTpmVersionNotSupported = 0xC029201D,
+    /// STATUS_PCP_BUFFER_LENGTH_MISMATCH
+    PcpBufferLengthMismatch = 0xC029201E,
+    /// STATUS_PCP_IFX_RSA_KEY_CREATION_BLOCKED
+    PcpIfxRsaKeyCreationBlocked = 0xC029201F,
+    /// STATUS_PCP_TICKET_MISSING
+    PcpTicketMissing = 0xC0292020,
+    /// STATUS_PCP_RAW_POLICY_NOT_SUPPORTED
+    PcpRawPolicyNotSupported = 0xC0292021,
+    /// STATUS_PCP_KEY_HANDLE_INVALIDATED
+    PcpKeyHandleInvalidated = 0xC0292022,
+    /// STATUS_RTPM_NO_RESULT
+    RtpmNoResult = 0xC0293002,
+    /// STATUS_RTPM_PCR_READ_INCOMPLETE
+    RtpmPcrReadIncomplete = 0xC0293003,
+    /// STATUS_RTPM_INVALID_CONTEXT
+    RtpmInvalidContext = 0xC0293004,
+    /// STATUS_RTPM_UNSUPPORTED_CMD
+    RtpmUnsupportedCmd = 0xC0293005,
+    /// STATUS_TPM_ZERO_EXHAUST_ENABLED
+    TpmZeroExhaustEnabled = 0xC0294000,
+    /// STATUS_HV_INVALID_HYPERCALL_CODE
+    HvInvalidHypercallCode = 0xC0350002,
+    /// STATUS_HV_INVALID_HYPERCALL_INPUT
+    HvInvalidHypercallInput = 0xC0350003,
+    /// STATUS_HV_INVALID_ALIGNMENT
+    HvInvalidAlignment = 0xC0350004,
+    /// STATUS_HV_INVALID_PARAMETER
+    HvInvalidParameter = 0xC0350005,
+    /// STATUS_HV_ACCESS_DENIED
+    HvAccessDenied = 0xC0350006,
+    /// STATUS_HV_INVALID_PARTITION_STATE
+    HvInvalidPartitionState = 0xC0350007,
+    /// STATUS_HV_OPERATION_DENIED
+    HvOperationDenied = 0xC0350008,
+    /// STATUS_HV_UNKNOWN_PROPERTY
+    HvUnknownProperty = 0xC0350009,
+    /// STATUS_HV_PROPERTY_VALUE_OUT_OF_RANGE
+    HvPropertyValueOutOfRange = 0xC035000A,
+    /// STATUS_HV_INSUFFICIENT_MEMORY
+    HvInsufficientMemory = 0xC035000B,
+    /// STATUS_HV_PARTITION_TOO_DEEP
+    HvPartitionTooDeep = 0xC035000C,
+    /// STATUS_HV_INVALID_PARTITION_ID
+    HvInvalidPartitionId = 0xC035000D,
+    /// STATUS_HV_INVALID_VP_INDEX
+    HvInvalidVpIndex = 0xC035000E,
+    /// STATUS_HV_INVALID_PORT_ID
+    HvInvalidPortId = 0xC0350011,
+    /// STATUS_HV_INVALID_CONNECTION_ID
+    HvInvalidConnectionId = 0xC0350012,
+    /// STATUS_HV_INSUFFICIENT_BUFFERS
+    HvInsufficientBuffers = 0xC0350013,
+    /// STATUS_HV_NOT_ACKNOWLEDGED
+    HvNotAcknowledged = 0xC0350014,
+    /// STATUS_HV_INVALID_VP_STATE
+    HvInvalidVpState = 0xC0350015,
+    /// STATUS_HV_ACKNOWLEDGED
+    HvAcknowledged = 0xC0350016,
+    /// STATUS_HV_INVALID_SAVE_RESTORE_STATE
+    HvInvalidSaveRestoreState = 0xC0350017,
+    /// STATUS_HV_INVALID_SYNIC_STATE
+    HvInvalidSynicState = 0xC0350018,
+    /// STATUS_HV_OBJECT_IN_USE
+    HvObjectInUse = 0xC0350019,
+    /// STATUS_HV_INVALID_PROXIMITY_DOMAIN_INFO
+    HvInvalidProximityDomainInfo = 0xC035001A,
+    /// STATUS_HV_NO_DATA
+    HvNoData = 0xC035001B,
+    /// STATUS_HV_INACTIVE
+    HvInactive = 0xC035001C,
+    /// STATUS_HV_NO_RESOURCES
+    HvNoResources = 0xC035001D,
+    /// STATUS_HV_FEATURE_UNAVAILABLE
+    HvFeatureUnavailable = 0xC035001E,
+    /// STATUS_HV_INSUFFICIENT_BUFFER
+    HvInsufficientBuffer = 0xC0350033,
+    /// STATUS_HV_INSUFFICIENT_DEVICE_DOMAINS
+    HvInsufficientDeviceDomains = 0xC0350038,
+    /// STATUS_HV_CPUID_FEATURE_VALIDATION_ERROR
+    HvCpuidFeatureValidationError = 0xC035003C,
+    /// STATUS_HV_CPUID_XSAVE_FEATURE_VALIDATION_ERROR
+    HvCpuidXsaveFeatureValidationError = 0xC035003D,
+    /// STATUS_HV_PROCESSOR_STARTUP_TIMEOUT
+    HvProcessorStartupTimeout = 0xC035003E,
+    /// STATUS_HV_SMX_ENABLED
+    HvSmxEnabled = 0xC035003F,
+    /// STATUS_HV_INVALID_LP_INDEX
+    HvInvalidLpIndex = 0xC0350041,
+    /// STATUS_HV_INVALID_REGISTER_VALUE
+    HvInvalidRegisterValue = 0xC0350050,
+    /// STATUS_HV_INVALID_VTL_STATE
+    HvInvalidVtlState = 0xC0350051,
+    /// STATUS_HV_NX_NOT_DETECTED
+    HvNxNotDetected = 0xC0350055,
+    /// STATUS_HV_INVALID_DEVICE_ID
+    HvInvalidDeviceId = 0xC0350057,
+    /// STATUS_HV_INVALID_DEVICE_STATE
+    HvInvalidDeviceState = 0xC0350058,
+    /// STATUS_HV_PAGE_REQUEST_INVALID
+    HvPageRequestInvalid = 0xC0350060,
+    /// STATUS_HV_INVALID_CPU_GROUP_ID
+    HvInvalidCpuGroupId = 0xC035006F,
+    /// STATUS_HV_INVALID_CPU_GROUP_STATE
+    HvInvalidCpuGroupState = 0xC0350070,
+    /// STATUS_HV_OPERATION_FAILED
+    HvOperationFailed = 0xC0350071,
+    /// STATUS_HV_NOT_ALLOWED_WITH_NESTED_VIRT_ACTIVE
+    HvNotAllowedWithNestedVirtActive = 0xC0350072,
+    /// STATUS_HV_INSUFFICIENT_ROOT_MEMORY
+    HvInsufficientRootMemory = 0xC0350073,
+    /// STATUS_HV_EVENT_BUFFER_ALREADY_FREED
+    HvEventBufferAlreadyFreed = 0xC0350074,
+    /// STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY
+    HvInsufficientContiguousMemory = 0xC0350075,
+    /// STATUS_HV_DEVICE_NOT_IN_DOMAIN
+    HvDeviceNotInDomain = 0xC0350076,
+    /// STATUS_HV_NESTED_VM_EXIT
+    HvNestedVmExit = 0xC0350077,
+    /// STATUS_HV_CALL_PENDING
+    HvCallPending = 0xC0350079,
+    /// STATUS_HV_MSR_ACCESS_FAILED
+    HvMsrAccessFailed = 0xC0350080,
+    /// STATUS_HV_INSUFFICIENT_MEMORY_MIRRORING
+    HvInsufficientMemoryMirroring = 0xC0350081,
+    /// STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY_MIRRORING
+    HvInsufficientContiguousMemoryMirroring = 0xC0350082,
+    /// STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY
+    HvInsufficientContiguousRootMemory = 0xC0350083,
+    /// STATUS_HV_INSUFFICIENT_ROOT_MEMORY_MIRRORING
+    HvInsufficientRootMemoryMirroring = 0xC0350084,
+    /// STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY_MIRRORING
+    HvInsufficientContiguousRootMemoryMirroring = 0xC0350085,
+    /// STATUS_HV_NOT_PRESENT
+    HvNotPresent = 0xC0351000,
+    /// STATUS_IPSEC_BAD_SPI
+    IpsecBadSpi = 0xC0360001,
+    /// STATUS_IPSEC_SA_LIFETIME_EXPIRED
+    IpsecSaLifetimeExpired = 0xC0360002,
+    /// STATUS_IPSEC_WRONG_SA
+    IpsecWrongSa = 0xC0360003,
+    /// STATUS_IPSEC_REPLAY_CHECK_FAILED
+    IpsecReplayCheckFailed = 0xC0360004,
+    /// STATUS_IPSEC_INVALID_PACKET
+    IpsecInvalidPacket = 0xC0360005,
+    /// STATUS_IPSEC_INTEGRITY_CHECK_FAILED
+    IpsecIntegrityCheckFailed = 0xC0360006,
+    /// STATUS_IPSEC_CLEAR_TEXT_DROP
+    IpsecClearTextDrop = 0xC0360007,
+    /// STATUS_IPSEC_AUTH_FIREWALL_DROP
+    IpsecAuthFirewallDrop = 0xC0360008,
+    /// STATUS_IPSEC_THROTTLE_DROP
+    IpsecThrottleDrop = 0xC0360009,
+    /// STATUS_IPSEC_DOSP_BLOCK
+    IpsecDospBlock = 0xC0368000,
+    /// STATUS_IPSEC_DOSP_RECEIVED_MULTICAST
+    IpsecDospReceivedMulticast = 0xC0368001,
+    /// STATUS_IPSEC_DOSP_INVALID_PACKET
+    IpsecDospInvalidPacket = 0xC0368002,
+    /// STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED
+    IpsecDospStateLookupFailed = 0xC0368003,
+    /// STATUS_IPSEC_DOSP_MAX_ENTRIES
+    IpsecDospMaxEntries = 0xC0368004,
+    /// STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED
+    IpsecDospKeymodNotAllowed = 0xC0368005,
+    /// STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES
+    IpsecDospMaxPerIpRatelimitQueues = 0xC0368006,
+    /// STATUS_VID_DUPLICATE_HANDLER
+    VidDuplicateHandler = 0xC0370001,
+    /// STATUS_VID_TOO_MANY_HANDLERS
+    VidTooManyHandlers = 0xC0370002,
+    /// STATUS_VID_QUEUE_FULL
+    VidQueueFull = 0xC0370003,
+    /// STATUS_VID_HANDLER_NOT_PRESENT
+    VidHandlerNotPresent = 0xC0370004,
+    /// STATUS_VID_INVALID_OBJECT_NAME
+    VidInvalidObjectName = 0xC0370005,
+    /// STATUS_VID_PARTITION_NAME_TOO_LONG
+    VidPartitionNameTooLong = 0xC0370006,
+    /// STATUS_VID_MESSAGE_QUEUE_NAME_TOO_LONG
+    VidMessageQueueNameTooLong = 0xC0370007,
+    /// STATUS_VID_PARTITION_ALREADY_EXISTS
+    VidPartitionAlreadyExists = 0xC0370008,
+    /// STATUS_VID_PARTITION_DOES_NOT_EXIST
+    VidPartitionDoesNotExist = 0xC0370009,
+    /// STATUS_VID_PARTITION_NAME_NOT_FOUND
+    VidPartitionNameNotFound = 0xC037000A,
+    /// STATUS_VID_MESSAGE_QUEUE_ALREADY_EXISTS
+    VidMessageQueueAlreadyExists = 0xC037000B,
+    /// STATUS_VID_EXCEEDED_MBP_ENTRY_MAP_LIMIT
+    VidExceededMbpEntryMapLimit = 0xC037000C,
+    /// STATUS_VID_MB_STILL_REFERENCED
+    VidMbStillReferenced = 0xC037000D,
+    /// STATUS_VID_CHILD_GPA_PAGE_SET_CORRUPTED
+    VidChildGpaPageSetCorrupted = 0xC037000E,
+    /// STATUS_VID_INVALID_NUMA_SETTINGS
+    VidInvalidNumaSettings = 0xC037000F,
+    /// STATUS_VID_INVALID_NUMA_NODE_INDEX
+    VidInvalidNumaNodeIndex = 0xC0370010,
+    /// STATUS_VID_NOTIFICATION_QUEUE_ALREADY_ASSOCIATED
+    VidNotificationQueueAlreadyAssociated = 0xC0370011,
+    /// STATUS_VID_INVALID_MEMORY_BLOCK_HANDLE
+    VidInvalidMemoryBlockHandle = 0xC0370012,
+    /// STATUS_VID_PAGE_RANGE_OVERFLOW
+    VidPageRangeOverflow = 0xC0370013,
+    /// STATUS_VID_INVALID_MESSAGE_QUEUE_HANDLE
+    VidInvalidMessageQueueHandle = 0xC0370014,
+    /// STATUS_VID_INVALID_GPA_RANGE_HANDLE
+    VidInvalidGpaRangeHandle = 0xC0370015,
+    /// STATUS_VID_NO_MEMORY_BLOCK_NOTIFICATION_QUEUE
+    VidNoMemoryBlockNotificationQueue = 0xC0370016,
+    /// STATUS_VID_MEMORY_BLOCK_LOCK_COUNT_EXCEEDED
+    VidMemoryBlockLockCountExceeded = 0xC0370017,
+    /// STATUS_VID_INVALID_PPM_HANDLE
+    VidInvalidPpmHandle = 0xC0370018,
+    /// STATUS_VID_MBPS_ARE_LOCKED
+    VidMbpsAreLocked = 0xC0370019,
+    /// STATUS_VID_MESSAGE_QUEUE_CLOSED
+    VidMessageQueueClosed = 0xC037001A,
+    /// STATUS_VID_VIRTUAL_PROCESSOR_LIMIT_EXCEEDED
+    VidVirtualProcessorLimitExceeded = 0xC037001B,
+    /// STATUS_VID_STOP_PENDING
+    VidStopPending = 0xC037001C,
+    /// STATUS_VID_INVALID_PROCESSOR_STATE
+    VidInvalidProcessorState = 0xC037001D,
+    /// STATUS_VID_EXCEEDED_KM_CONTEXT_COUNT_LIMIT
+    VidExceededKmContextCountLimit = 0xC037001E,
+    /// STATUS_VID_KM_INTERFACE_ALREADY_INITIALIZED
+    VidKmInterfaceAlreadyInitialized = 0xC037001F,
+    /// STATUS_VID_MB_PROPERTY_ALREADY_SET_RESET
+    VidMbPropertyAlreadySetReset = 0xC0370020,
+    /// STATUS_VID_MMIO_RANGE_DESTROYED
+    VidMmioRangeDestroyed = 0xC0370021,
+    /// STATUS_VID_INVALID_CHILD_GPA_PAGE_SET
+    VidInvalidChildGpaPageSet = 0xC0370022,
+    /// STATUS_VID_RESERVE_PAGE_SET_IS_BEING_USED
+    VidReservePageSetIsBeingUsed = 0xC0370023,
+    /// STATUS_VID_RESERVE_PAGE_SET_TOO_SMALL
+    VidReservePageSetTooSmall = 0xC0370024,
+    /// STATUS_VID_MBP_ALREADY_LOCKED_USING_RESERVED_PAGE
+    VidMbpAlreadyLockedUsingReservedPage = 0xC0370025,
+    /// STATUS_VID_MBP_COUNT_EXCEEDED_LIMIT
+    VidMbpCountExceededLimit = 0xC0370026,
+    /// STATUS_VID_SAVED_STATE_CORRUPT
+    VidSavedStateCorrupt = 0xC0370027,
+    /// STATUS_VID_SAVED_STATE_UNRECOGNIZED_ITEM
+    VidSavedStateUnrecognizedItem = 0xC0370028,
+    /// STATUS_VID_SAVED_STATE_INCOMPATIBLE
+    VidSavedStateIncompatible = 0xC0370029,
+    /// STATUS_VID_VTL_ACCESS_DENIED
+    VidVtlAccessDenied = 0xC037002A,
+    /// STATUS_VID_INSUFFICIENT_RESOURCES_RESERVE
+    VidInsufficientResourcesReserve = 0xC037002B,
+    /// STATUS_VID_INSUFFICIENT_RESOURCES_PHYSICAL_BUFFER
+    VidInsufficientResourcesPhysicalBuffer = 0xC037002C,
+    /// STATUS_VID_INSUFFICIENT_RESOURCES_HV_DEPOSIT
+    VidInsufficientResourcesHvDeposit = 0xC037002D,
+    /// STATUS_VID_MEMORY_TYPE_NOT_SUPPORTED
+    VidMemoryTypeNotSupported = 0xC037002E,
+    /// STATUS_VID_INSUFFICIENT_RESOURCES_WITHDRAW
+    VidInsufficientResourcesWithdraw = 0xC037002F,
+    /// STATUS_VID_PROCESS_ALREADY_SET
+    VidProcessAlreadySet = 0xC0370030,
+    /// STATUS_DM_OPERATION_LIMIT_EXCEEDED
+    DmOperationLimitExceeded = 0xC0370600,
+    /// STATUS_VOLMGR_DATABASE_FULL
+    VolmgrDatabaseFull = 0xC0380001,
+    /// STATUS_VOLMGR_DISK_CONFIGURATION_CORRUPTED
+    VolmgrDiskConfigurationCorrupted = 0xC0380002,
+    /// STATUS_VOLMGR_DISK_CONFIGURATION_NOT_IN_SYNC
+    VolmgrDiskConfigurationNotInSync = 0xC0380003,
+    /// STATUS_VOLMGR_PACK_CONFIG_UPDATE_FAILED
+    VolmgrPackConfigUpdateFailed = 0xC0380004,
+    /// STATUS_VOLMGR_DISK_CONTAINS_NON_SIMPLE_VOLUME
+    VolmgrDiskContainsNonSimpleVolume = 0xC0380005,
+    /// STATUS_VOLMGR_DISK_DUPLICATE
+    VolmgrDiskDuplicate = 0xC0380006,
+    /// STATUS_VOLMGR_DISK_DYNAMIC
+    VolmgrDiskDynamic = 0xC0380007,
+    /// STATUS_VOLMGR_DISK_ID_INVALID
+    VolmgrDiskIdInvalid = 0xC0380008,
+    /// STATUS_VOLMGR_DISK_INVALID
+    VolmgrDiskInvalid = 0xC0380009,
+    /// STATUS_VOLMGR_DISK_LAST_VOTER
+    VolmgrDiskLastVoter = 0xC038000A,
+    /// STATUS_VOLMGR_DISK_LAYOUT_INVALID
+    VolmgrDiskLayoutInvalid = 0xC038000B,
+    /// STATUS_VOLMGR_DISK_LAYOUT_NON_BASIC_BETWEEN_BASIC_PARTITIONS
+    VolmgrDiskLayoutNonBasicBetweenBasicPartitions = 0xC038000C,
+    /// STATUS_VOLMGR_DISK_LAYOUT_NOT_CYLINDER_ALIGNED
+    VolmgrDiskLayoutNotCylinderAligned = 0xC038000D,
+    /// STATUS_VOLMGR_DISK_LAYOUT_PARTITIONS_TOO_SMALL
+    VolmgrDiskLayoutPartitionsTooSmall = 0xC038000E,
+    /// STATUS_VOLMGR_DISK_LAYOUT_PRIMARY_BETWEEN_LOGICAL_PARTITIONS
+    VolmgrDiskLayoutPrimaryBetweenLogicalPartitions = 0xC038000F,
+    /// STATUS_VOLMGR_DISK_LAYOUT_TOO_MANY_PARTITIONS
+    VolmgrDiskLayoutTooManyPartitions = 0xC0380010,
+    /// STATUS_VOLMGR_DISK_MISSING
+    VolmgrDiskMissing = 0xC0380011,
+    /// STATUS_VOLMGR_DISK_NOT_EMPTY
+    VolmgrDiskNotEmpty = 0xC0380012,
+    /// STATUS_VOLMGR_DISK_NOT_ENOUGH_SPACE
+    VolmgrDiskNotEnoughSpace = 0xC0380013,
+    /// STATUS_VOLMGR_DISK_REVECTORING_FAILED
+    VolmgrDiskRevectoringFailed = 0xC0380014,
+    /// STATUS_VOLMGR_DISK_SECTOR_SIZE_INVALID
+    VolmgrDiskSectorSizeInvalid = 0xC0380015,
+    /// STATUS_VOLMGR_DISK_SET_NOT_CONTAINED
+    VolmgrDiskSetNotContained = 0xC0380016,
+    /// STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_MEMBERS
+    VolmgrDiskUsedByMultipleMembers = 0xC0380017,
+    /// STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_PLEXES
+    VolmgrDiskUsedByMultiplePlexes = 0xC0380018,
+    /// STATUS_VOLMGR_DYNAMIC_DISK_NOT_SUPPORTED
+    VolmgrDynamicDiskNotSupported = 0xC0380019,
+    /// STATUS_VOLMGR_EXTENT_ALREADY_USED
+    VolmgrExtentAlreadyUsed = 0xC038001A,
+    /// STATUS_VOLMGR_EXTENT_NOT_CONTIGUOUS
+    VolmgrExtentNotContiguous = 0xC038001B,
+    /// STATUS_VOLMGR_EXTENT_NOT_IN_PUBLIC_REGION
+    VolmgrExtentNotInPublicRegion = 0xC038001C,
+    /// STATUS_VOLMGR_EXTENT_NOT_SECTOR_ALIGNED
+    VolmgrExtentNotSectorAligned = 0xC038001D,
+    /// STATUS_VOLMGR_EXTENT_OVERLAPS_EBR_PARTITION
+    VolmgrExtentOverlapsEbrPartition = 0xC038001E,
+    /// STATUS_VOLMGR_EXTENT_VOLUME_LENGTHS_DO_NOT_MATCH
+    VolmgrExtentVolumeLengthsDoNotMatch = 0xC038001F,
+    /// STATUS_VOLMGR_FAULT_TOLERANT_NOT_SUPPORTED
+    VolmgrFaultTolerantNotSupported = 0xC0380020,
+    /// STATUS_VOLMGR_INTERLEAVE_LENGTH_INVALID
+    VolmgrInterleaveLengthInvalid = 0xC0380021,
+    /// STATUS_VOLMGR_MAXIMUM_REGISTERED_USERS
+    VolmgrMaximumRegisteredUsers = 0xC0380022,
+    /// STATUS_VOLMGR_MEMBER_IN_SYNC
+    VolmgrMemberInSync = 0xC0380023,
+    /// STATUS_VOLMGR_MEMBER_INDEX_DUPLICATE
+    VolmgrMemberIndexDuplicate = 0xC0380024,
+    /// STATUS_VOLMGR_MEMBER_INDEX_INVALID
+    VolmgrMemberIndexInvalid = 0xC0380025,
+    /// STATUS_VOLMGR_MEMBER_MISSING
+    VolmgrMemberMissing = 0xC0380026,
+    /// STATUS_VOLMGR_MEMBER_NOT_DETACHED
+    VolmgrMemberNotDetached = 0xC0380027,
+    /// STATUS_VOLMGR_MEMBER_REGENERATING
+    VolmgrMemberRegenerating = 0xC0380028,
+    /// STATUS_VOLMGR_ALL_DISKS_FAILED
+    VolmgrAllDisksFailed = 0xC0380029,
+    /// STATUS_VOLMGR_NO_REGISTERED_USERS
+    VolmgrNoRegisteredUsers = 0xC038002A,
+    /// STATUS_VOLMGR_NO_SUCH_USER
+    VolmgrNoSuchUser = 0xC038002B,
+    /// STATUS_VOLMGR_NOTIFICATION_RESET
+    VolmgrNotificationReset = 0xC038002C,
+    /// STATUS_VOLMGR_NUMBER_OF_MEMBERS_INVALID
+    VolmgrNumberOfMembersInvalid = 0xC038002D,
+    /// STATUS_VOLMGR_NUMBER_OF_PLEXES_INVALID
+    VolmgrNumberOfPlexesInvalid = 0xC038002E,
+    /// STATUS_VOLMGR_PACK_DUPLICATE
+    VolmgrPackDuplicate = 0xC038002F,
+    /// STATUS_VOLMGR_PACK_ID_INVALID
+    VolmgrPackIdInvalid = 0xC0380030,
+    /// STATUS_VOLMGR_PACK_INVALID
+    VolmgrPackInvalid = 0xC0380031,
+    /// STATUS_VOLMGR_PACK_NAME_INVALID
+    VolmgrPackNameInvalid = 0xC0380032,
+    /// STATUS_VOLMGR_PACK_OFFLINE
+    VolmgrPackOffline = 0xC0380033,
+    /// STATUS_VOLMGR_PACK_HAS_QUORUM
+    VolmgrPackHasQuorum = 0xC0380034,
+    /// STATUS_VOLMGR_PACK_WITHOUT_QUORUM
+    VolmgrPackWithoutQuorum = 0xC0380035,
+    /// STATUS_VOLMGR_PARTITION_STYLE_INVALID
+    VolmgrPartitionStyleInvalid = 0xC0380036,
+    /// STATUS_VOLMGR_PARTITION_UPDATE_FAILED
+    VolmgrPartitionUpdateFailed = 0xC0380037,
+    /// STATUS_VOLMGR_PLEX_IN_SYNC
+    VolmgrPlexInSync = 0xC0380038,
+    /// STATUS_VOLMGR_PLEX_INDEX_DUPLICATE
+    VolmgrPlexIndexDuplicate = 0xC0380039,
+    /// STATUS_VOLMGR_PLEX_INDEX_INVALID
+    VolmgrPlexIndexInvalid = 0xC038003A,
+    /// STATUS_VOLMGR_PLEX_LAST_ACTIVE
+    VolmgrPlexLastActive = 0xC038003B,
+    /// STATUS_VOLMGR_PLEX_MISSING
+    VolmgrPlexMissing = 0xC038003C,
+    /// STATUS_VOLMGR_PLEX_REGENERATING
+    VolmgrPlexRegenerating = 0xC038003D,
+    /// STATUS_VOLMGR_PLEX_TYPE_INVALID
+    VolmgrPlexTypeInvalid = 0xC038003E,
+    /// STATUS_VOLMGR_PLEX_NOT_RAID5
+    VolmgrPlexNotRaid5 = 0xC038003F,
+    /// STATUS_VOLMGR_PLEX_NOT_SIMPLE
+    VolmgrPlexNotSimple = 0xC0380040,
+    /// STATUS_VOLMGR_STRUCTURE_SIZE_INVALID
+    VolmgrStructureSizeInvalid = 0xC0380041,
+    /// STATUS_VOLMGR_TOO_MANY_NOTIFICATION_REQUESTS
+    VolmgrTooManyNotificationRequests = 0xC0380042,
+    /// STATUS_VOLMGR_TRANSACTION_IN_PROGRESS
+    VolmgrTransactionInProgress = 0xC0380043,
+    /// STATUS_VOLMGR_UNEXPECTED_DISK_LAYOUT_CHANGE
+    VolmgrUnexpectedDiskLayoutChange = 0xC0380044,
+    /// STATUS_VOLMGR_VOLUME_CONTAINS_MISSING_DISK
+    VolmgrVolumeContainsMissingDisk = 0xC0380045,
+    /// STATUS_VOLMGR_VOLUME_ID_INVALID
+    VolmgrVolumeIdInvalid = 0xC0380046,
+    /// STATUS_VOLMGR_VOLUME_LENGTH_INVALID
+    VolmgrVolumeLengthInvalid = 0xC0380047,
+    /// STATUS_VOLMGR_VOLUME_LENGTH_NOT_SECTOR_SIZE_MULTIPLE
+    VolmgrVolumeLengthNotSectorSizeMultiple = 0xC0380048,
+    /// STATUS_VOLMGR_VOLUME_NOT_MIRRORED
+    VolmgrVolumeNotMirrored = 0xC0380049,
+    /// STATUS_VOLMGR_VOLUME_NOT_RETAINED
+    VolmgrVolumeNotRetained = 0xC038004A,
+    /// STATUS_VOLMGR_VOLUME_OFFLINE
+    VolmgrVolumeOffline = 0xC038004B,
+    /// STATUS_VOLMGR_VOLUME_RETAINED
+    VolmgrVolumeRetained = 0xC038004C,
+    /// STATUS_VOLMGR_NUMBER_OF_EXTENTS_INVALID
+    VolmgrNumberOfExtentsInvalid = 0xC038004D,
+    /// STATUS_VOLMGR_DIFFERENT_SECTOR_SIZE
+    VolmgrDifferentSectorSize = 0xC038004E,
+    /// STATUS_VOLMGR_BAD_BOOT_DISK
+    VolmgrBadBootDisk = 0xC038004F,
+    /// STATUS_VOLMGR_PACK_CONFIG_OFFLINE
+    VolmgrPackConfigOffline = 0xC0380050,
+    /// STATUS_VOLMGR_PACK_CONFIG_ONLINE
+    VolmgrPackConfigOnline = 0xC0380051,
+    /// STATUS_VOLMGR_NOT_PRIMARY_PACK
+    VolmgrNotPrimaryPack = 0xC0380052,
+    /// STATUS_VOLMGR_PACK_LOG_UPDATE_FAILED
+    VolmgrPackLogUpdateFailed = 0xC0380053,
+    /// STATUS_VOLMGR_NUMBER_OF_DISKS_IN_PLEX_INVALID
+    VolmgrNumberOfDisksInPlexInvalid = 0xC0380054,
+    /// STATUS_VOLMGR_NUMBER_OF_DISKS_IN_MEMBER_INVALID
+    VolmgrNumberOfDisksInMemberInvalid = 0xC0380055,
+    /// STATUS_VOLMGR_VOLUME_MIRRORED
+    VolmgrVolumeMirrored = 0xC0380056,
+    /// STATUS_VOLMGR_PLEX_NOT_SIMPLE_SPANNED
+    VolmgrPlexNotSimpleSpanned = 0xC0380057,
+    /// STATUS_VOLMGR_NO_VALID_LOG_COPIES
+    VolmgrNoValidLogCopies = 0xC0380058,
+    /// STATUS_VOLMGR_PRIMARY_PACK_PRESENT
+    VolmgrPrimaryPackPresent = 0xC0380059,
+    /// STATUS_VOLMGR_NUMBER_OF_DISKS_INVALID
+    VolmgrNumberOfDisksInvalid = 0xC038005A,
+    /// STATUS_VOLMGR_MIRROR_NOT_SUPPORTED
+    VolmgrMirrorNotSupported = 0xC038005B,
+    /// STATUS_VOLMGR_RAID5_NOT_SUPPORTED
+    VolmgrRaid5NotSupported = 0xC038005C,
+    /// STATUS_BCD_TOO_MANY_ELEMENTS
+    BcdTooManyElements = 0xC0390002,
+    /// STATUS_VHD_DRIVE_FOOTER_MISSING
+    VhdDriveFooterMissing = 0xC03A0001,
+    /// STATUS_VHD_DRIVE_FOOTER_CHECKSUM_MISMATCH
+    VhdDriveFooterChecksumMismatch = 0xC03A0002,
+    /// STATUS_VHD_DRIVE_FOOTER_CORRUPT
+    VhdDriveFooterCorrupt = 0xC03A0003,
+    /// STATUS_VHD_FORMAT_UNKNOWN
+    VhdFormatUnknown = 0xC03A0004,
+    /// STATUS_VHD_FORMAT_UNSUPPORTED_VERSION
+    VhdFormatUnsupportedVersion = 0xC03A0005,
+    /// STATUS_VHD_SPARSE_HEADER_CHECKSUM_MISMATCH
+    VhdSparseHeaderChecksumMismatch = 0xC03A0006,
+    /// STATUS_VHD_SPARSE_HEADER_UNSUPPORTED_VERSION
+    VhdSparseHeaderUnsupportedVersion = 0xC03A0007,
+    /// STATUS_VHD_SPARSE_HEADER_CORRUPT
+    VhdSparseHeaderCorrupt = 0xC03A0008,
+    /// STATUS_VHD_BLOCK_ALLOCATION_FAILURE
+    VhdBlockAllocationFailure = 0xC03A0009,
+    /// STATUS_VHD_BLOCK_ALLOCATION_TABLE_CORRUPT
+    VhdBlockAllocationTableCorrupt = 0xC03A000A,
+    /// STATUS_VHD_INVALID_BLOCK_SIZE
+    VhdInvalidBlockSize = 0xC03A000B,
+    /// STATUS_VHD_BITMAP_MISMATCH
+    VhdBitmapMismatch = 0xC03A000C,
+    /// STATUS_VHD_PARENT_VHD_NOT_FOUND
+    VhdParentVhdNotFound = 0xC03A000D,
+    /// STATUS_VHD_CHILD_PARENT_ID_MISMATCH
+    VhdChildParentIdMismatch = 0xC03A000E,
+    /// STATUS_VHD_CHILD_PARENT_TIMESTAMP_MISMATCH
+    VhdChildParentTimestampMismatch = 0xC03A000F,
+    /// STATUS_VHD_METADATA_READ_FAILURE
+    VhdMetadataReadFailure = 0xC03A0010,
+    /// STATUS_VHD_METADATA_WRITE_FAILURE
+    VhdMetadataWriteFailure = 0xC03A0011,
+    /// STATUS_VHD_INVALID_SIZE
+    VhdInvalidSize = 0xC03A0012,
+    /// STATUS_VHD_INVALID_FILE_SIZE
+    VhdInvalidFileSize = 0xC03A0013,
+    /// STATUS_VIRTDISK_PROVIDER_NOT_FOUND
+    VirtdiskProviderNotFound = 0xC03A0014,
+    /// STATUS_VIRTDISK_NOT_VIRTUAL_DISK
+    VirtdiskNotVirtualDisk = 0xC03A0015,
+    /// STATUS_VHD_PARENT_VHD_ACCESS_DENIED
+    VhdParentVhdAccessDenied = 0xC03A0016,
+    /// STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH
+    VhdChildParentSizeMismatch = 0xC03A0017,
+    /// STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED
+    VhdDifferencingChainCycleDetected = 0xC03A0018,
+    /// STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT
+    VhdDifferencingChainErrorInParent = 0xC03A0019,
+    /// STATUS_VIRTUAL_DISK_LIMITATION
+    VirtualDiskLimitation = 0xC03A001A,
+    /// STATUS_VHD_INVALID_TYPE
+    VhdInvalidType = 0xC03A001B,
+    /// STATUS_VHD_INVALID_STATE
+    VhdInvalidState = 0xC03A001C,
+    /// STATUS_VIRTDISK_UNSUPPORTED_DISK_SECTOR_SIZE
+    VirtdiskUnsupportedDiskSectorSize = 0xC03A001D,
+    /// STATUS_VIRTDISK_DISK_ALREADY_OWNED
+    VirtdiskDiskAlreadyOwned = 0xC03A001E,
+    /// STATUS_VIRTDISK_DISK_ONLINE_AND_WRITABLE
+    VirtdiskDiskOnlineAndWritable = 0xC03A001F,
+    /// STATUS_CTLOG_TRACKING_NOT_INITIALIZED
+    CtlogTrackingNotInitialized = 0xC03A0020,
+    /// STATUS_CTLOG_LOGFILE_SIZE_EXCEEDED_MAXSIZE
+    CtlogLogfileSizeExceededMaxsize = 0xC03A0021,
+    /// STATUS_CTLOG_VHD_CHANGED_OFFLINE
+    CtlogVhdChangedOffline = 0xC03A0022,
+    /// STATUS_CTLOG_INVALID_TRACKING_STATE
+    CtlogInvalidTrackingState = 0xC03A0023,
+    /// STATUS_CTLOG_INCONSISTENT_TRACKING_FILE
+    CtlogInconsistentTrackingFile = 0xC03A0024,
+    /// STATUS_VHD_METADATA_FULL
+    VhdMetadataFull = 0xC03A0028,
+    /// STATUS_VHD_INVALID_CHANGE_TRACKING_ID
+    VhdInvalidChangeTrackingId = 0xC03A0029,
+    /// STATUS_VHD_CHANGE_TRACKING_DISABLED
+    VhdChangeTrackingDisabled = 0xC03A002A,
+    /// STATUS_VHD_MISSING_CHANGE_TRACKING_INFORMATION
+    VhdMissingChangeTrackingInformation = 0xC03A0030,
+    /// STATUS_VHD_RESIZE_WOULD_TRUNCATE_DATA
+    VhdResizeWouldTruncateData = 0xC03A0031,
+    /// STATUS_VHD_COULD_NOT_COMPUTE_MINIMUM_VIRTUAL_SIZE
+    VhdCouldNotComputeMinimumVirtualSize = 0xC03A0032,
+    /// STATUS_VHD_ALREADY_AT_OR_BELOW_MINIMUM_VIRTUAL_SIZE
+    VhdAlreadyAtOrBelowMinimumVirtualSize = 0xC03A0033,
+    /// STATUS_RKF_KEY_NOT_FOUND
+    RkfKeyNotFound = 0xC0400001,
+    /// STATUS_RKF_DUPLICATE_KEY
+    RkfDuplicateKey = 0xC0400002,
+    /// STATUS_RKF_BLOB_FULL
+    RkfBlobFull = 0xC0400003,
+    /// STATUS_RKF_STORE_FULL
+    RkfStoreFull = 0xC0400004,
+    /// STATUS_RKF_FILE_BLOCKED
+    RkfFileBlocked = 0xC0400005,
+    /// STATUS_RKF_ACTIVE_KEY
+    RkfActiveKey = 0xC0400006,
+    /// STATUS_RDBSS_RESTART_OPERATION
+    RdbssRestartOperation = 0xC0410001,
+    /// STATUS_RDBSS_CONTINUE_OPERATION
+    RdbssContinueOperation = 0xC0410002,
+    /// STATUS_RDBSS_POST_OPERATION
+    RdbssPostOperation = 0xC0410003,
+    /// STATUS_RDBSS_RETRY_LOOKUP
+    RdbssRetryLookup = 0xC0410004,
+    /// STATUS_BTH_ATT_INVALID_HANDLE
+    BthAttInvalidHandle = 0xC0420001,
+    /// STATUS_BTH_ATT_READ_NOT_PERMITTED
+    BthAttReadNotPermitted = 0xC0420002,
+    /// STATUS_BTH_ATT_WRITE_NOT_PERMITTED
+    BthAttWriteNotPermitted = 0xC0420003,
+    /// STATUS_BTH_ATT_INVALID_PDU
+    BthAttInvalidPdu = 0xC0420004,
+    /// STATUS_BTH_ATT_INSUFFICIENT_AUTHENTICATION
+    BthAttInsufficientAuthentication = 0xC0420005,
+    /// STATUS_BTH_ATT_REQUEST_NOT_SUPPORTED
+    BthAttRequestNotSupported = 0xC0420006,
+    /// STATUS_BTH_ATT_INVALID_OFFSET
+    BthAttInvalidOffset = 0xC0420007,
+    /// STATUS_BTH_ATT_INSUFFICIENT_AUTHORIZATION
+    BthAttInsufficientAuthorization = 0xC0420008,
+    /// STATUS_BTH_ATT_PREPARE_QUEUE_FULL
+    BthAttPrepareQueueFull = 0xC0420009,
+    /// STATUS_BTH_ATT_ATTRIBUTE_NOT_FOUND
+    BthAttAttributeNotFound = 0xC042000A,
+    /// STATUS_BTH_ATT_ATTRIBUTE_NOT_LONG
+    BthAttAttributeNotLong = 0xC042000B,
+    /// STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE
+    BthAttInsufficientEncryptionKeySize = 0xC042000C,
+    /// STATUS_BTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH
+    BthAttInvalidAttributeValueLength = 0xC042000D,
+    /// STATUS_BTH_ATT_UNLIKELY
+    BthAttUnlikely = 0xC042000E,
+    /// STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION
+    BthAttInsufficientEncryption = 0xC042000F,
+    /// STATUS_BTH_ATT_UNSUPPORTED_GROUP_TYPE
+    BthAttUnsupportedGroupType = 0xC0420010,
+    /// STATUS_BTH_ATT_INSUFFICIENT_RESOURCES
+    BthAttInsufficientResources = 0xC0420011,
+    /// STATUS_BTH_ATT_UNKNOWN_ERROR
+    BthAttUnknownError = 0xC0421000,
+    /// STATUS_SECUREBOOT_ROLLBACK_DETECTED
+    SecurebootRollbackDetected = 0xC0430001,
+    /// STATUS_SECUREBOOT_POLICY_VIOLATION
+    SecurebootPolicyViolation = 0xC0430002,
+    /// STATUS_SECUREBOOT_INVALID_POLICY
+    SecurebootInvalidPolicy = 0xC0430003,
+    /// STATUS_SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND
+    SecurebootPolicyPublisherNotFound = 0xC0430004,
+    /// STATUS_SECUREBOOT_POLICY_NOT_SIGNED
+    SecurebootPolicyNotSigned = 0xC0430005,
+    /// STATUS_SECUREBOOT_FILE_REPLACED
+    SecurebootFileReplaced = 0xC0430007,
+    /// STATUS_SECUREBOOT_POLICY_NOT_AUTHORIZED
+    SecurebootPolicyNotAuthorized = 0xC0430008,
+    /// STATUS_SECUREBOOT_POLICY_UNKNOWN
+    SecurebootPolicyUnknown = 0xC0430009,
+    /// STATUS_SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION
+    SecurebootPolicyMissingAntirollbackversion = 0xC043000A,
+    /// STATUS_SECUREBOOT_PLATFORM_ID_MISMATCH
+    SecurebootPlatformIdMismatch = 0xC043000B,
+    /// STATUS_SECUREBOOT_POLICY_ROLLBACK_DETECTED
+    SecurebootPolicyRollbackDetected = 0xC043000C,
+    /// STATUS_SECUREBOOT_POLICY_UPGRADE_MISMATCH
+    SecurebootPolicyUpgradeMismatch = 0xC043000D,
+    /// STATUS_SECUREBOOT_REQUIRED_POLICY_FILE_MISSING
+    SecurebootRequiredPolicyFileMissing = 0xC043000E,
+    /// STATUS_SECUREBOOT_NOT_BASE_POLICY
+    SecurebootNotBasePolicy = 0xC043000F,
+    /// STATUS_SECUREBOOT_NOT_SUPPLEMENTAL_POLICY
+    SecurebootNotSupplementalPolicy = 0xC0430010,
+    /// STATUS_AUDIO_ENGINE_NODE_NOT_FOUND
+    AudioEngineNodeNotFound = 0xC0440001,
+    /// STATUS_HDAUDIO_EMPTY_CONNECTION_LIST
+    HdaudioEmptyConnectionList = 0xC0440002,
+    /// STATUS_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED
+    HdaudioConnectionListNotSupported = 0xC0440003,
+    /// STATUS_HDAUDIO_NO_LOGICAL_DEVICES_CREATED
+    HdaudioNoLogicalDevicesCreated = 0xC0440004,
+    /// STATUS_HDAUDIO_NULL_LINKED_LIST_ENTRY
+    HdaudioNullLinkedListEntry = 0xC0440005,
+    /// STATUS_VSM_NOT_INITIALIZED
+    VsmNotInitialized = 0xC0450000,
+    /// STATUS_VSM_DMA_PROTECTION_NOT_IN_USE
+    VsmDmaProtectionNotInUse = 0xC0450001,
+    /// STATUS_IORING_REQUIRED_FLAG_NOT_SUPPORTED
+    IoringRequiredFlagNotSupported = 0xC0460001,
+    /// STATUS_IORING_SUBMISSION_QUEUE_FULL
+    IoringSubmissionQueueFull = 0xC0460002,
+    /// STATUS_IORING_VERSION_NOT_SUPPORTED
+    IoringVersionNotSupported = 0xC0460003,
+    /// STATUS_IORING_SUBMISSION_QUEUE_TOO_BIG
+    IoringSubmissionQueueTooBig = 0xC0460004,
+    /// STATUS_IORING_COMPLETION_QUEUE_TOO_BIG
+    IoringCompletionQueueTooBig = 0xC0460005,
+    /// STATUS_IORING_SUBMIT_IN_PROGRESS
+    IoringSubmitInProgress = 0xC0460006,
+    /// STATUS_IORING_CORRUPT
+    IoringCorrupt = 0xC0460007,
+    /// STATUS_IORING_COMPLETION_QUEUE_TOO_FULL
+    IoringCompletionQueueTooFull = 0xC0460008,
+    /// STATUS_VOLSNAP_BOOTFILE_NOT_VALID
+    VolsnapBootfileNotValid = 0xC0500003,
+    /// STATUS_VOLSNAP_ACTIVATION_TIMEOUT
+    VolsnapActivationTimeout = 0xC0500004,
+    /// STATUS_VOLSNAP_NO_BYPASSIO_WITH_SNAPSHOT
+    VolsnapNoBypassioWithSnapshot = 0xC0500005,
+    /// STATUS_IO_PREEMPTED
+    IoPreempted = 0xC0510001,
+    /// STATUS_SVHDX_ERROR_STORED
+    SvhdxErrorStored = 0xC05C0000,
+    /// STATUS_SVHDX_ERROR_NOT_AVAILABLE
+    SvhdxErrorNotAvailable = 0xC05CFF00,
+    /// STATUS_SVHDX_UNIT_ATTENTION_AVAILABLE
+    SvhdxUnitAttentionAvailable = 0xC05CFF01,
+    /// STATUS_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED
+    SvhdxUnitAttentionCapacityDataChanged = 0xC05CFF02,
+    /// STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED
+    SvhdxUnitAttentionReservationsPreempted = 0xC05CFF03,
+    /// STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED
+    SvhdxUnitAttentionReservationsReleased = 0xC05CFF04,
+    /// STATUS_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED
+    SvhdxUnitAttentionRegistrationsPreempted = 0xC05CFF05,
+    /// STATUS_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED
+    SvhdxUnitAttentionOperatingDefinitionChanged = 0xC05CFF06,
+    /// STATUS_SVHDX_RESERVATION_CONFLICT
+    SvhdxReservationConflict = 0xC05CFF07,
+    /// STATUS_SVHDX_WRONG_FILE_TYPE
+    SvhdxWrongFileType = 0xC05CFF08,
+    /// STATUS_SVHDX_VERSION_MISMATCH
+    SvhdxVersionMismatch = 0xC05CFF09,
+    /// STATUS_VHD_SHARED
+    VhdShared = 0xC05CFF0A,
+    /// STATUS_SVHDX_NO_INITIATOR
+    SvhdxNoInitiator = 0xC05CFF0B,
+    /// STATUS_VHDSET_BACKING_STORAGE_NOT_FOUND
+    VhdsetBackingStorageNotFound = 0xC05CFF0C,
+    /// STATUS_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP
+    SmbNoPreauthIntegrityHashOverlap = 0xC05D0000,
+    /// STATUS_SMB_BAD_CLUSTER_DIALECT
+    SmbBadClusterDialect = 0xC05D0001,
+    /// STATUS_SMB_GUEST_LOGON_BLOCKED
+    SmbGuestLogonBlocked = 0xC05D0002,
+    /// STATUS_SMB_NO_SIGNING_ALGORITHM_OVERLAP
+    SmbNoSigningAlgorithmOverlap = 0xC05D0003,
+    /// STATUS_NETWORK_AUTHENTICATION_PROMPT_CANCELED
+    NetworkAuthenticationPromptCanceled = 0xC05D0004,
+    /// STATUS_SPACES_FAULT_DOMAIN_TYPE_INVALID
+    SpacesFaultDomainTypeInvalid = 0xC0E70001,
+    /// STATUS_SPACES_RESILIENCY_TYPE_INVALID
+    SpacesResiliencyTypeInvalid = 0xC0E70003,
+    /// STATUS_SPACES_DRIVE_SECTOR_SIZE_INVALID
+    SpacesDriveSectorSizeInvalid = 0xC0E70004,
+    /// STATUS_SPACES_DRIVE_REDUNDANCY_INVALID
+    SpacesDriveRedundancyInvalid = 0xC0E70006,
+    /// STATUS_SPACES_NUMBER_OF_DATA_COPIES_INVALID
+    SpacesNumberOfDataCopiesInvalid = 0xC0E70007,
+    /// STATUS_SPACES_INTERLEAVE_LENGTH_INVALID
+    SpacesInterleaveLengthInvalid = 0xC0E70009,
+    /// STATUS_SPACES_NUMBER_OF_COLUMNS_INVALID
+    SpacesNumberOfColumnsInvalid = 0xC0E7000A,
+    /// STATUS_SPACES_NOT_ENOUGH_DRIVES
+    SpacesNotEnoughDrives = 0xC0E7000B,
+    /// STATUS_SPACES_EXTENDED_ERROR
+    SpacesExtendedError = 0xC0E7000C,
+    /// STATUS_SPACES_PROVISIONING_TYPE_INVALID
+    SpacesProvisioningTypeInvalid = 0xC0E7000D,
+    /// STATUS_SPACES_ALLOCATION_SIZE_INVALID
+    SpacesAllocationSizeInvalid = 0xC0E7000E,
+    /// STATUS_SPACES_ENCLOSURE_AWARE_INVALID
+    SpacesEnclosureAwareInvalid = 0xC0E7000F,
+    /// STATUS_SPACES_WRITE_CACHE_SIZE_INVALID
+    SpacesWriteCacheSizeInvalid = 0xC0E70010,
+    /// STATUS_SPACES_NUMBER_OF_GROUPS_INVALID
+    SpacesNumberOfGroupsInvalid = 0xC0E70011,
+    /// STATUS_SPACES_DRIVE_OPERATIONAL_STATE_INVALID
+    SpacesDriveOperationalStateInvalid = 0xC0E70012,
+    /// STATUS_SPACES_UPDATE_COLUMN_STATE
+    SpacesUpdateColumnState = 0xC0E70013,
+    /// STATUS_SPACES_MAP_REQUIRED
+    SpacesMapRequired = 0xC0E70014,
+    /// STATUS_SPACES_UNSUPPORTED_VERSION
+    SpacesUnsupportedVersion = 0xC0E70015,
+    /// STATUS_SPACES_CORRUPT_METADATA
+    SpacesCorruptMetadata = 0xC0E70016,
+    /// STATUS_SPACES_DRT_FULL
+    SpacesDrtFull = 0xC0E70017,
+    /// STATUS_SPACES_INCONSISTENCY
+    SpacesInconsistency = 0xC0E70018,
+    /// STATUS_SPACES_LOG_NOT_READY
+    SpacesLogNotReady = 0xC0E70019,
+    /// STATUS_SPACES_NO_REDUNDANCY
+    SpacesNoRedundancy = 0xC0E7001A,
+    /// STATUS_SPACES_DRIVE_NOT_READY
+    SpacesDriveNotReady = 0xC0E7001B,
+    /// STATUS_SPACES_DRIVE_SPLIT
+    SpacesDriveSplit = 0xC0E7001C,
+    /// STATUS_SPACES_DRIVE_LOST_DATA
+    SpacesDriveLostData = 0xC0E7001D,
+    /// STATUS_SPACES_ENTRY_INCOMPLETE
+    SpacesEntryIncomplete = 0xC0E7001E,
+    /// STATUS_SPACES_ENTRY_INVALID
+    SpacesEntryInvalid = 0xC0E7001F,
+    /// STATUS_SPACES_MARK_DIRTY
+    SpacesMarkDirty = 0xC0E70020,
+    /// STATUS_SPACES_PD_NOT_FOUND
+    SpacesPdNotFound = 0xC0E70021,
+    /// STATUS_SPACES_PD_LENGTH_MISMATCH
+    SpacesPdLengthMismatch = 0xC0E70022,
+    /// STATUS_SPACES_PD_UNSUPPORTED_VERSION
+    SpacesPdUnsupportedVersion = 0xC0E70023,
+    /// STATUS_SPACES_PD_INVALID_DATA
+    SpacesPdInvalidData = 0xC0E70024,
+    /// STATUS_SPACES_FLUSH_METADATA
+    SpacesFlushMetadata = 0xC0E70025,
+    /// STATUS_SPACES_CACHE_FULL
+    SpacesCacheFull = 0xC0E70026,
+    /// STATUS_SPACES_REPAIR_IN_PROGRESS
+    SpacesRepairInProgress = 0xC0E70027,
+    /// STATUS_SECCORE_INVALID_COMMAND
+    SeccoreInvalidCommand = 0xC0E80000,
+    /// STATUS_SYSTEM_INTEGRITY_ROLLBACK_DETECTED
+    SystemIntegrityRollbackDetected = 0xC0E90001,
+    /// STATUS_SYSTEM_INTEGRITY_POLICY_VIOLATION
+    SystemIntegrityPolicyViolation = 0xC0E90002,
+    /// STATUS_SYSTEM_INTEGRITY_INVALID_POLICY
+    SystemIntegrityInvalidPolicy = 0xC0E90003,
+    /// STATUS_SYSTEM_INTEGRITY_POLICY_NOT_SIGNED
+    SystemIntegrityPolicyNotSigned = 0xC0E90004,
+    /// STATUS_SYSTEM_INTEGRITY_TOO_MANY_POLICIES
+    SystemIntegrityTooManyPolicies = 0xC0E90005,
+    /// STATUS_SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED
+    SystemIntegritySupplementalPolicyNotAuthorized = 0xC0E90006,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_MALICIOUS
+    SystemIntegrityReputationMalicious = 0xC0E90007,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_PUA
+    SystemIntegrityReputationPua = 0xC0E90008,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_DANGEROUS_EXT
+    SystemIntegrityReputationDangerousExt = 0xC0E90009,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_OFFLINE
+    SystemIntegrityReputationOffline = 0xC0E9000A,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_UNFRIENDLY_FILE
+    SystemIntegrityReputationUnfriendlyFile = 0xC0E9000B,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_UNATTAINABLE
+    SystemIntegrityReputationUnattainable = 0xC0E9000C,
+    /// STATUS_SYSTEM_INTEGRITY_REPUTATION_EXPLICIT_DENY_FILE
+    SystemIntegrityReputationExplicitDenyFile = 0xC0E9000D,
+    /// STATUS_NO_APPLICABLE_APP_LICENSES_FOUND
+    NoApplicableAppLicensesFound = 0xC0EA0001,
+    /// STATUS_CLIP_LICENSE_NOT_FOUND
+    ClipLicenseNotFound = 0xC0EA0002,
+    /// STATUS_CLIP_DEVICE_LICENSE_MISSING
+    ClipDeviceLicenseMissing = 0xC0EA0003,
+    /// STATUS_CLIP_LICENSE_INVALID_SIGNATURE
+    ClipLicenseInvalidSignature = 0xC0EA0004,
+    /// STATUS_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID
+    ClipKeyholderLicenseMissingOrInvalid = 0xC0EA0005,
+    /// STATUS_CLIP_LICENSE_EXPIRED
+    ClipLicenseExpired = 0xC0EA0006,
+    /// STATUS_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE
+    ClipLicenseSignedByUnknownSource = 0xC0EA0007,
+    /// STATUS_CLIP_LICENSE_NOT_SIGNED
+    ClipLicenseNotSigned = 0xC0EA0008,
+    /// STATUS_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE
+    ClipLicenseHardwareIdOutOfTolerance = 0xC0EA0009,
+    /// STATUS_CLIP_LICENSE_DEVICE_ID_MISMATCH
+    ClipLicenseDeviceIdMismatch = 0xC0EA000A,
+    /// STATUS_PLATFORM_MANIFEST_NOT_AUTHORIZED
+    PlatformManifestNotAuthorized = 0xC0EB0001,
+    /// STATUS_PLATFORM_MANIFEST_INVALID
+    PlatformManifestInvalid = 0xC0EB0002,
+    /// STATUS_PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED
+    PlatformManifestFileNotAuthorized = 0xC0EB0003,
+    /// STATUS_PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED
+    PlatformManifestCatalogNotAuthorized = 0xC0EB0004,
+    /// STATUS_PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND
+    PlatformManifestBinaryIdNotFound = 0xC0EB0005,
+    /// STATUS_PLATFORM_MANIFEST_NOT_ACTIVE
+    PlatformManifestNotActive = 0xC0EB0006,
+    /// STATUS_PLATFORM_MANIFEST_NOT_SIGNED
+    PlatformManifestNotSigned = 0xC0EB0007,
+    /// STATUS_APPEXEC_CONDITION_NOT_SATISFIED
+    AppexecConditionNotSatisfied = 0xC0EC0000,
+    /// STATUS_APPEXEC_HANDLE_INVALIDATED
+    AppexecHandleInvalidated = 0xC0EC0001,
+    /// STATUS_APPEXEC_INVALID_HOST_GENERATION
+    AppexecInvalidHostGeneration = 0xC0EC0002,
+    /// STATUS_APPEXEC_UNEXPECTED_PROCESS_REGISTRATION
+    AppexecUnexpectedProcessRegistration = 0xC0EC0003,
+    /// STATUS_APPEXEC_INVALID_HOST_STATE
+    AppexecInvalidHostState = 0xC0EC0004,
+    /// STATUS_APPEXEC_NO_DONOR
+    AppexecNoDonor = 0xC0EC0005,
+    /// STATUS_APPEXEC_HOST_ID_MISMATCH
+    AppexecHostIdMismatch = 0xC0EC0006,
+    /// STATUS_APPEXEC_UNKNOWN_USER
+    AppexecUnknownUser = 0xC0EC0007,
+    /// STATUS_APPEXEC_APP_COMPAT_BLOCK
+    AppexecAppCompatBlock = 0xC0EC0008,
+    /// STATUS_APPEXEC_CALLER_WAIT_TIMEOUT
+    AppexecCallerWaitTimeout = 0xC0EC0009,
+    /// STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_TERMINATION
+    AppexecCallerWaitTimeoutTermination = 0xC0EC000A,
+    /// STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_LICENSING
+    AppexecCallerWaitTimeoutLicensing = 0xC0EC000B,
+    /// STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_RESOURCES
+    AppexecCallerWaitTimeoutResources = 0xC0EC000C,
+}
+
+impl NtStatus {
+    /// Every code, in the same order as `NTSTATUS_CODES`
+    pub const ALL: &'static [Self] = &[
+        Self::GuardPageViolation,
+        Self::DatatypeMisalignment,
+        Self::Breakpoint,
+        Self::SingleStep,
+        Self::BufferOverflow,
+        Self::NoMoreFiles,
+        Self::WakeSystemDebugger,
+        Self::HandlesClosed,
+        Self::NoInheritance,
+        Self::GuidSubstitutionMade,
+        Self::PartialCopy,
+        Self::DevicePaperEmpty,
+        Self::DevicePoweredOff,
+        Self::DeviceOffLine,
+        Self::DeviceBusy,
+        Self::NoMoreEas,
+        Self::InvalidEaName,
+        Self::EaListInconsistent,
+        Self::InvalidEaFlag,
+        Self::VerifyRequired,
+        Self::ExtraneousInformation,
+        Self::RxactCommitNecessary,
+        Self::NoMoreEntries,
+        Self::FilemarkDetected,
+        Self::MediaChanged,
+        Self::BusReset,
+        Self::EndOfMedia,
+        Self::BeginningOfMedia,
+        Self::MediaCheck,
+        Self::SetmarkDetected,
+        Self::NoDataDetected,
+        Self::RedirectorHasOpenHandles,
+        Self::ServerHasOpenHandles,
+        Self::AlreadyDisconnected,
+        Self::Longjump,
+        Self::CleanerCartridgeInstalled,
+        Self::PlugplayQueryVetoed,
+        Self::UnwindConsolidate,
+        Self::RegistryHiveRecovered,
+        Self::DllMightBeInsecure,
+        Self::DllMightBeIncompatible,
+        Self::StoppedOnSymlink,
+        Self::CannotGrantRequestedOplock,
+        Self::NoAceCondition,
+        Self::DeviceSupportInProgress,
+        Self::DevicePowerCycleRequired,
+        Self::NoWorkDone,
+        Self::ReturnAddressHijackAttempt,
+        Self::RecoverableBugcheck,
+        Self::DeviceResetRequired,
+        Self::DeviceRequiresCleaning,
+        Self::DeviceDoorOpen,
+        Self::DataLostRepair,
+        Self::GpioInterruptAlreadyUnmasked,
+        Self::CloudFilePropertyBlobChecksumMismatch,
+        Self::CloudFilePropertyBlobTooLarge,
+        Self::CloudFileTooManyPropertyBlobs,
+        Self::ClusterNodeAlreadyUp,
+        Self::ClusterNodeAlreadyDown,
+        Self::ClusterNetworkAlreadyOnline,
+        Self::ClusterNetworkAlreadyOffline,
+        Self::ClusterNodeAlreadyMember,
+        Self::CouldNotResizeLog,
+        Self::NoTxfMetadata,
+        Self::CantRecoverWithHandleOpen,
+        Self::TxfMetadataAlreadyPresent,
+        Self::TransactionScopeCallbacksNotSet,
+        Self::VideoHungDisplayDriverThreadRecovered,
+        Self::FltBufferTooSmall,
+        Self::GraphicsLinkConfigurationInProgress,
+        Self::FvePartialMetadata,
+        Self::FveTransientState,
+        Self::VidRemoteNodeParentGpaPagesUsed,
+        Self::VolmgrIncompleteRegeneration,
+        Self::VolmgrIncompleteDiskMigration,
+        Self::BcdNotAllEntriesImported,
+        Self::BcdNotAllEntriesSynchronized,
+        Self::QueryStorageError,
+        Self::GdiHandleLeak,
+        Self::SecurebootNotEnabled,
+        Self::Unsuccessful,
+        Self::NotImplemented,
+        Self::InvalidInfoClass,
+        Self::InfoLengthMismatch,
+        Self::AccessViolation,
+        Self::InPageError,
+        Self::PagefileQuota,
+        Self::InvalidHandle,
+        Self::BadInitialStack,
+        Self::BadInitialPc,
+        Self::InvalidCid,
+        Self::TimerNotCanceled,
+        Self::InvalidParameter,
+        Self::NoSuchDevice,
+        Self::NoSuchFile,
+        Self::InvalidDeviceRequest,
+        Self::EndOfFile,
+        Self::WrongVolume,
+        Self::NoMediaInDevice,
+        Self::UnrecognizedMedia,
+        Self::NonexistentSector,
+        Self::MoreProcessingRequired,
+        Self::NoMemory,
+        Self::ConflictingAddresses,
+        Self::NotMappedView,
+        Self::UnableToFreeVm,
+        Self::UnableToDeleteSection,
+        Self::InvalidSystemService,
+        Self::IllegalInstruction,
+        Self::InvalidLockSequence,
+        Self::InvalidViewSize,
+        Self::InvalidFileForSection,
+        Self::AlreadyCommitted,
+        Self::AccessDenied,
+        Self::BufferTooSmall,
+        Self::ObjectTypeMismatch,
+        Self::NoncontinuableException,
+        Self::InvalidDisposition,
+        Self::Unwind,
+        Self::BadStack,
+        Self::InvalidUnwindTarget,
+        Self::NotLocked,
+        Self::ParityError,
+        Self::UnableToDecommitVm,
+        Self::NotCommitted,
+        Self::InvalidPortAttributes,
+        Self::PortMessageTooLong,
+        Self::InvalidParameterMix,
+        Self::InvalidQuotaLower,
+        Self::DiskCorruptError,
+        Self::ObjectNameInvalid,
+        Self::ObjectNameNotFound,
+        Self::ObjectNameCollision,
+        Self::PortDoNotDisturb,
+        Self::PortDisconnected,
+        Self::DeviceAlreadyAttached,
+        Self::ObjectPathInvalid,
+        Self::ObjectPathNotFound,
+        Self::ObjectPathSyntaxBad,
+        Self::DataOverrun,
+        Self::DataLateError,
+        Self::DataError,
+        Self::CrcError,
+        Self::SectionTooBig,
+        Self::PortConnectionRefused,
+        Self::InvalidPortHandle,
+        Self::SharingViolation,
+        Self::QuotaExceeded,
+        Self::InvalidPageProtection,
+        Self::MutantNotOwned,
+        Self::SemaphoreLimitExceeded,
+        Self::PortAlreadySet,
+        Self::SectionNotImage,
+        Self::SuspendCountExceeded,
+        Self::ThreadIsTerminating,
+        Self::BadWorkingSetLimit,
+        Self::IncompatibleFileMap,
+        Self::SectionProtection,
+        Self::EasNotSupported,
+        Self::EaTooLarge,
+        Self::NonexistentEaEntry,
+        Self::NoEasOnFile,
+        Self::EaCorruptError,
+        Self::FileLockConflict,
+        Self::LockNotGranted,
+        Self::DeletePending,
+        Self::CtlFileNotSupported,
+        Self::UnknownRevision,
+        Self::RevisionMismatch,
+        Self::InvalidOwner,
+        Self::InvalidPrimaryGroup,
+        Self::NoImpersonationToken,
+        Self::CantDisableMandatory,
+        Self::NoLogonServers,
+        Self::NoSuchLogonSession,
+        Self::NoSuchPrivilege,
+        Self::PrivilegeNotHeld,
+        Self::InvalidAccountName,
+        Self::UserExists,
+        Self::NoSuchUser,
+        Self::GroupExists,
+        Self::NoSuchGroup,
+        Self::MemberInGroup,
+        Self::MemberNotInGroup,
+        Self::LastAdmin,
+        Self::WrongPassword,
+        Self::IllFormedPassword,
+        Self::PasswordRestriction,
+        Self::LogonFailure,
+        Self::AccountRestriction,
+        Self::InvalidLogonHours,
+        Self::InvalidWorkstation,
+        Self::PasswordExpired,
+        Self::AccountDisabled,
+        Self::NoneMapped,
+        Self::TooManyLuidsRequested,
+        Self::LuidsExhausted,
+        Self::InvalidSubAuthority,
+        Self::InvalidAcl,
+        Self::InvalidSid,
+        Self::InvalidSecurityDescr,
+        Self::ProcedureNotFound,
+        Self::InvalidImageFormat,
+        Self::NoToken,
+        Self::BadInheritanceAcl,
+        Self::RangeNotLocked,
+        Self::DiskFull,
+        Self::ServerDisabled,
+        Self::ServerNotDisabled,
+        Self::TooManyGuidsRequested,
+        Self::GuidsExhausted,
+        Self::InvalidIdAuthority,
+        Self::AgentsExhausted,
+        Self::InvalidVolumeLabel,
+        Self::SectionNotExtended,
+        Self::NotMappedData,
+        Self::ResourceDataNotFound,
+        Self::ResourceTypeNotFound,
+        Self::ResourceNameNotFound,
+        Self::ArrayBoundsExceeded,
+        Self::FloatDenormalOperand,
+        Self::FloatDivideByZero,
+        Self::FloatInexactResult,
+        Self::FloatInvalidOperation,
+        Self::FloatOverflow,
+        Self::FloatStackCheck,
+        Self::FloatUnderflow,
+        Self::IntegerDivideByZero,
+        Self::IntegerOverflow,
+        Self::PrivilegedInstruction,
+        Self::TooManyPagingFiles,
+        Self::FileInvalid,
+        Self::AllottedSpaceExceeded,
+        Self::InsufficientResources,
+        Self::DfsExitPathFound,
+        Self::DeviceDataError,
+        Self::DeviceNotConnected,
+        Self::DevicePowerFailure,
+        Self::FreeVmNotAtBase,
+        Self::MemoryNotAllocated,
+        Self::WorkingSetQuota,
+        Self::MediaWriteProtected,
+        Self::DeviceNotReady,
+        Self::InvalidGroupAttributes,
+        Self::BadImpersonationLevel,
+        Self::CantOpenAnonymous,
+        Self::BadValidationClass,
+        Self::BadTokenType,
+        Self::BadMasterBootRecord,
+        Self::InstructionMisalignment,
+        Self::InstanceNotAvailable,
+        Self::PipeNotAvailable,
+        Self::InvalidPipeState,
+        Self::PipeBusy,
+        Self::IllegalFunction,
+        Self::PipeDisconnected,
+        Self::PipeClosing,
+        Self::PipeConnected,
+        Self::PipeListening,
+        Self::InvalidReadMode,
+        Self::IoTimeout,
+        Self::FileForcedClosed,
+        Self::ProfilingNotStarted,
+        Self::ProfilingNotStopped,
+        Self::CouldNotInterpret,
+        Self::FileIsADirectory,
+        Self::NotSupported,
+        Self::RemoteNotListening,
+        Self::DuplicateName,
+        Self::BadNetworkPath,
+        Self::NetworkBusy,
+        Self::DeviceDoesNotExist,
+        Self::TooManyCommands,
+        Self::AdapterHardwareError,
+        Self::InvalidNetworkResponse,
+        Self::UnexpectedNetworkError,
+        Self::BadRemoteAdapter,
+        Self::PrintQueueFull,
+        Self::NoSpoolSpace,
+        Self::PrintCancelled,
+        Self::NetworkNameDeleted,
+        Self::NetworkAccessDenied,
+        Self::BadDeviceType,
+        Self::BadNetworkName,
+        Self::TooManyNames,
+        Self::TooManySessions,
+        Self::SharingPaused,
+        Self::RequestNotAccepted,
+        Self::RedirectorPaused,
+        Self::NetWriteFault,
+        Self::ProfilingAtLimit,
+        Self::NotSameDevice,
+        Self::FileRenamed,
+        Self::VirtualCircuitClosed,
+        Self::NoSecurityOnObject,
+        Self::CantWait,
+        Self::PipeEmpty,
+        Self::CantAccessDomainInfo,
+        Self::CantTerminateSelf,
+        Self::InvalidServerState,
+        Self::InvalidDomainState,
+        Self::InvalidDomainRole,
+        Self::NoSuchDomain,
+        Self::DomainExists,
+        Self::DomainLimitExceeded,
+        Self::OplockNotGranted,
+        Self::InvalidOplockProtocol,
+        Self::InternalDbCorruption,
+        Self::InternalError,
+        Self::GenericNotMapped,
+        Self::BadDescriptorFormat,
+        Self::InvalidUserBuffer,
+        Self::UnexpectedIoError,
+        Self::UnexpectedMmCreateErr,
+        Self::UnexpectedMmMapError,
+        Self::UnexpectedMmExtendErr,
+        Self::NotLogonProcess,
+        Self::LogonSessionExists,
+        Self::InvalidParameter1,
+        Self::InvalidParameter2,
+        Self::InvalidParameter3,
+        Self::InvalidParameter4,
+        Self::InvalidParameter5,
+        Self::InvalidParameter6,
+        Self::InvalidParameter7,
+        Self::InvalidParameter8,
+        Self::InvalidParameter9,
+        Self::InvalidParameter10,
+        Self::InvalidParameter11,
+        Self::InvalidParameter12,
+        Self::RedirectorNotStarted,
+        Self::RedirectorStarted,
+        Self::StackOverflow,
+        Self::NoSuchPackage,
+        Self::BadFunctionTable,
+        Self::VariableNotFound,
+        Self::DirectoryNotEmpty,
+        Self::FileCorruptError,
+        Self::NotADirectory,
+        Self::BadLogonSessionState,
+        Self::LogonSessionCollision,
+        Self::NameTooLong,
+        Self::FilesOpen,
+        Self::ConnectionInUse,
+        Self::MessageNotFound,
+        Self::ProcessIsTerminating,
+        Self::InvalidLogonType,
+        Self::NoGuidTranslation,
+        Self::CannotImpersonate,
+        Self::ImageAlreadyLoaded,
+        Self::AbiosNotPresent,
+        Self::AbiosLidNotExist,
+        Self::AbiosLidAlreadyOwned,
+        Self::AbiosNotLidOwner,
+        Self::AbiosInvalidCommand,
+        Self::AbiosInvalidLid,
+        Self::AbiosSelectorNotAvailable,
+        Self::AbiosInvalidSelector,
+        Self::NoLdt,
+        Self::InvalidLdtSize,
+        Self::InvalidLdtOffset,
+        Self::InvalidLdtDescriptor,
+        Self::InvalidImageNeFormat,
+        Self::RxactInvalidState,
+        Self::RxactCommitFailure,
+        Self::MappedFileSizeZero,
+        Self::TooManyOpenedFiles,
+        Self::Cancelled,
+        Self::CannotDelete,
+        Self::InvalidComputerName,
+        Self::FileDeleted,
+        Self::SpecialAccount,
+        Self::SpecialGroup,
+        Self::SpecialUser,
+        Self::MembersPrimaryGroup,
+        Self::FileClosed,
+        Self::TooManyThreads,
+        Self::ThreadNotInProcess,
+        Self::TokenAlreadyInUse,
+        Self::PagefileQuotaExceeded,
+        Self::CommitmentLimit,
+        Self::InvalidImageLeFormat,
+        Self::InvalidImageNotMz,
+        Self::InvalidImageProtect,
+        Self::InvalidImageWin16,
+        Self::LogonServerConflict,
+        Self::TimeDifferenceAtDc,
+        Self::SynchronizationRequired,
+        Self::DllNotFound,
+        Self::OpenFailed,
+        Self::IoPrivilegeFailed,
+        Self::OrdinalNotFound,
+        Self::EntrypointNotFound,
+        Self::ControlCExit,
+        Self::LocalDisconnect,
+        Self::RemoteDisconnect,
+        Self::RemoteResources,
+        Self::LinkFailed,
+        Self::LinkTimeout,
+        Self::InvalidConnection,
+        Self::InvalidAddress,
+        Self::DllInitFailed,
+        Self::MissingSystemfile,
+        Self::UnhandledException,
+        Self::AppInitFailure,
+        Self::PagefileCreateFailed,
+        Self::NoPagefile,
+        Self::InvalidLevel,
+        Self::WrongPasswordCore,
+        Self::IllegalFloatContext,
+        Self::PipeBroken,
+        Self::RegistryCorrupt,
+        Self::RegistryIoFailed,
+        Self::NoEventPair,
+        Self::UnrecognizedVolume,
+        Self::SerialNoDeviceInited,
+        Self::NoSuchAlias,
+        Self::MemberNotInAlias,
+        Self::MemberInAlias,
+        Self::AliasExists,
+        Self::LogonNotGranted,
+        Self::TooManySecrets,
+        Self::SecretTooLong,
+        Self::InternalDbError,
+        Self::FullscreenMode,
+        Self::TooManyContextIds,
+        Self::LogonTypeNotGranted,
+        Self::NotRegistryFile,
+        Self::NtCrossEncryptionRequired,
+        Self::DomainCtrlrConfigError,
+        Self::FtMissingMember,
+        Self::IllFormedServiceEntry,
+        Self::IllegalCharacter,
+        Self::UnmappableCharacter,
+        Self::UndefinedCharacter,
+        Self::FloppyVolume,
+        Self::FloppyIdMarkNotFound,
+        Self::FloppyWrongCylinder,
+        Self::FloppyUnknownError,
+        Self::FloppyBadRegisters,
+        Self::DiskRecalibrateFailed,
+        Self::DiskOperationFailed,
+        Self::DiskResetFailed,
+        Self::SharedIrqBusy,
+        Self::FtOrphaning,
+        Self::BiosFailedToConnectInterrupt,
+        Self::PartitionFailure,
+        Self::InvalidBlockLength,
+        Self::DeviceNotPartitioned,
+        Self::UnableToLockMedia,
+        Self::UnableToUnloadMedia,
+        Self::EomOverflow,
+        Self::NoMedia,
+        Self::NoSuchMember,
+        Self::InvalidMember,
+        Self::KeyDeleted,
+        Self::NoLogSpace,
+        Self::TooManySids,
+        Self::LmCrossEncryptionRequired,
+        Self::KeyHasChildren,
+        Self::ChildMustBeVolatile,
+        Self::DeviceConfigurationError,
+        Self::DriverInternalError,
+        Self::InvalidDeviceState,
+        Self::IoDeviceError,
+        Self::DeviceProtocolError,
+        Self::BackupController,
+        Self::LogFileFull,
+        Self::TooLate,
+        Self::NoTrustLsaSecret,
+        Self::NoTrustSamAccount,
+        Self::TrustedDomainFailure,
+        Self::TrustedRelationshipFailure,
+        Self::EventlogFileCorrupt,
+        Self::EventlogCantStart,
+        Self::TrustFailure,
+        Self::MutantLimitExceeded,
+        Self::NetlogonNotStarted,
+        Self::AccountExpired,
+        Self::PossibleDeadlock,
+        Self::NetworkCredentialConflict,
+        Self::RemoteSessionLimit,
+        Self::EventlogFileChanged,
+        Self::NologonInterdomainTrustAccount,
+        Self::NologonWorkstationTrustAccount,
+        Self::NologonServerTrustAccount,
+        Self::DomainTrustInconsistent,
+        Self::FsDriverRequired,
+        Self::ImageAlreadyLoadedAsDll,
+        Self::IncompatibleWithGlobalShortNameRegistrySetting,
+        Self::ShortNamesNotEnabledOnVolume,
+        Self::SecurityStreamIsInconsistent,
+        Self::InvalidLockRange,
+        Self::InvalidAceCondition,
+        Self::ImageSubsystemNotPresent,
+        Self::NotificationGuidAlreadyDefined,
+        Self::InvalidExceptionHandler,
+        Self::DuplicatePrivileges,
+        Self::NotAllowedOnSystemFile,
+        Self::RepairNeeded,
+        Self::QuotaNotEnabled,
+        Self::NoApplicationPackage,
+        Self::FileMetadataOptimizationInProgress,
+        Self::NotSameObject,
+        Self::FatalMemoryExhaustion,
+        Self::ErrorProcessNotInJob,
+        Self::CpuSetInvalid,
+        Self::IoDeviceInvalidData,
+        Self::IoUnalignedWrite,
+        Self::ControlStackViolation,
+        Self::WeakWhfbkeyBlocked,
+        Self::ServerTransportConflict,
+        Self::CertificateValidationPreferenceConflict,
+        Self::NetworkOpenRestriction,
+        Self::NoUserSessionKey,
+        Self::UserSessionDeleted,
+        Self::ResourceLangNotFound,
+        Self::InsuffServerResources,
+        Self::InvalidBufferSize,
+        Self::InvalidAddressComponent,
+        Self::InvalidAddressWildcard,
+        Self::TooManyAddresses,
+        Self::AddressAlreadyExists,
+        Self::AddressClosed,
+        Self::ConnectionDisconnected,
+        Self::ConnectionReset,
+        Self::TooManyNodes,
+        Self::TransactionAborted,
+        Self::TransactionTimedOut,
+        Self::TransactionNoRelease,
+        Self::TransactionNoMatch,
+        Self::TransactionResponded,
+        Self::TransactionInvalidId,
+        Self::TransactionInvalidType,
+        Self::NotServerSession,
+        Self::NotClientSession,
+        Self::CannotLoadRegistryFile,
+        Self::DebugAttachFailed,
+        Self::SystemProcessTerminated,
+        Self::DataNotAccepted,
+        Self::NoBrowserServersFound,
+        Self::VdmHardError,
+        Self::DriverCancelTimeout,
+        Self::ReplyMessageMismatch,
+        Self::MappedAlignment,
+        Self::ImageChecksumMismatch,
+        Self::LostWritebehindData,
+        Self::ClientServerParametersInvalid,
+        Self::PasswordMustChange,
+        Self::NotFound,
+        Self::NotTinyStream,
+        Self::RecoveryFailure,
+        Self::StackOverflowRead,
+        Self::FailCheck,
+        Self::DuplicateObjectid,
+        Self::ObjectidExists,
+        Self::ConvertToLarge,
+        Self::Retry,
+        Self::FoundOutOfScope,
+        Self::AllocateBucket,
+        Self::PropsetNotFound,
+        Self::MarshallOverflow,
+        Self::InvalidVariant,
+        Self::DomainControllerNotFound,
+        Self::AccountLockedOut,
+        Self::HandleNotClosable,
+        Self::ConnectionRefused,
+        Self::GracefulDisconnect,
+        Self::AddressAlreadyAssociated,
+        Self::AddressNotAssociated,
+        Self::ConnectionInvalid,
+        Self::ConnectionActive,
+        Self::NetworkUnreachable,
+        Self::HostUnreachable,
+        Self::ProtocolUnreachable,
+        Self::PortUnreachable,
+        Self::RequestAborted,
+        Self::ConnectionAborted,
+        Self::BadCompressionBuffer,
+        Self::UserMappedFile,
+        Self::AuditFailed,
+        Self::TimerResolutionNotSet,
+        Self::ConnectionCountLimit,
+        Self::LoginTimeRestriction,
+        Self::LoginWkstaRestriction,
+        Self::ImageMpUpMismatch,
+        Self::InsufficientLogonInfo,
+        Self::BadDllEntrypoint,
+        Self::BadServiceEntrypoint,
+        Self::LpcReplyLost,
+        Self::IpAddressConflict1,
+        Self::IpAddressConflict2,
+        Self::RegistryQuotaLimit,
+        Self::PathNotCovered,
+        Self::NoCallbackActive,
+        Self::LicenseQuotaExceeded,
+        Self::PwdTooShort,
+        Self::PwdTooRecent,
+        Self::PwdHistoryConflict,
+        Self::PlugplayNoDevice,
+        Self::UnsupportedCompression,
+        Self::InvalidHwProfile,
+        Self::InvalidPlugplayDevicePath,
+        Self::DriverOrdinalNotFound,
+        Self::DriverEntrypointNotFound,
+        Self::ResourceNotOwned,
+        Self::TooManyLinks,
+        Self::QuotaListInconsistent,
+        Self::FileIsOffline,
+        Self::EvaluationExpiration,
+        Self::IllegalDllRelocation,
+        Self::LicenseViolation,
+        Self::DllInitFailedLogoff,
+        Self::DriverUnableToLoad,
+        Self::DfsUnavailable,
+        Self::VolumeDismounted,
+        Self::Wx86InternalError,
+        Self::Wx86FloatStackCheck,
+        Self::ValidateContinue,
+        Self::NoMatch,
+        Self::NoMoreMatches,
+        Self::NotAReparsePoint,
+        Self::IoReparseTagInvalid,
+        Self::IoReparseTagMismatch,
+        Self::IoReparseDataInvalid,
+        Self::IoReparseTagNotHandled,
+        Self::PwdTooLong,
+        Self::StowedException,
+        Self::ContextStowedException,
+        Self::ReparsePointNotResolved,
+        Self::DirectoryIsAReparsePoint,
+        Self::RangeListConflict,
+        Self::SourceElementEmpty,
+        Self::DestinationElementFull,
+        Self::IllegalElementAddress,
+        Self::MagazineNotPresent,
+        Self::ReinitializationNeeded,
+        Self::EncryptionFailed,
+        Self::DecryptionFailed,
+        Self::RangeNotFound,
+        Self::NoRecoveryPolicy,
+        Self::NoEfs,
+        Self::WrongEfs,
+        Self::NoUserKeys,
+        Self::FileNotEncrypted,
+        Self::NotExportFormat,
+        Self::FileEncrypted,
+        Self::WmiGuidNotFound,
+        Self::WmiInstanceNotFound,
+        Self::WmiItemidNotFound,
+        Self::WmiTryAgain,
+        Self::SharedPolicy,
+        Self::PolicyObjectNotFound,
+        Self::PolicyOnlyInDs,
+        Self::VolumeNotUpgraded,
+        Self::RemoteStorageNotActive,
+        Self::RemoteStorageMediaError,
+        Self::NoTrackingService,
+        Self::ServerSidMismatch,
+        Self::DsNoAttributeOrValue,
+        Self::DsInvalidAttributeSyntax,
+        Self::DsAttributeTypeUndefined,
+        Self::DsAttributeOrValueExists,
+        Self::DsBusy,
+        Self::DsUnavailable,
+        Self::DsNoRidsAllocated,
+        Self::DsNoMoreRids,
+        Self::DsIncorrectRoleOwner,
+        Self::DsRidmgrInitError,
+        Self::DsObjClassViolation,
+        Self::DsCantOnNonLeaf,
+        Self::DsCantOnRdn,
+        Self::DsCantModObjClass,
+        Self::DsCrossDomMoveFailed,
+        Self::DsGcNotAvailable,
+        Self::DirectoryServiceRequired,
+        Self::ReparseAttributeConflict,
+        Self::CantEnableDenyOnly,
+        Self::FloatMultipleFaults,
+        Self::FloatMultipleTraps,
+        Self::DeviceRemoved,
+        Self::JournalDeleteInProgress,
+        Self::JournalNotActive,
+        Self::Nointerface,
+        Self::DsRidmgrDisabled,
+        Self::DsAdminLimitExceeded,
+        Self::DriverFailedSleep,
+        Self::MutualAuthenticationFailed,
+        Self::CorruptSystemFile,
+        Self::DatatypeMisalignmentError,
+        Self::WmiReadOnly,
+        Self::WmiSetFailure,
+        Self::CommitmentMinimum,
+        Self::RegNatConsumption,
+        Self::TransportFull,
+        Self::DsSamInitFailure,
+        Self::OnlyIfConnected,
+        Self::DsSensitiveGroupViolation,
+        Self::PnpRestartEnumeration,
+        Self::JournalEntryDeleted,
+        Self::DsCantModPrimarygroupid,
+        Self::SystemImageBadSignature,
+        Self::PnpRebootRequired,
+        Self::PowerStateInvalid,
+        Self::DsInvalidGroupType,
+        Self::DsNoNestGlobalgroupInMixeddomain,
+        Self::DsNoNestLocalgroupInMixeddomain,
+        Self::DsGlobalCantHaveLocalMember,
+        Self::DsGlobalCantHaveUniversalMember,
+        Self::DsUniversalCantHaveLocalMember,
+        Self::DsGlobalCantHaveCrossdomainMember,
+        Self::DsLocalCantHaveCrossdomainLocalMember,
+        Self::DsHavePrimaryMembers,
+        Self::WmiNotSupported,
+        Self::InsufficientPower,
+        Self::SamNeedBootkeyPassword,
+        Self::SamNeedBootkeyFloppy,
+        Self::DsCantStart,
+        Self::DsInitFailure,
+        Self::SamInitFailure,
+        Self::DsGcRequired,
+        Self::DsLocalMemberOfLocalOnly,
+        Self::DsNoFpoInUniversalGroups,
+        Self::DsMachineAccountQuotaExceeded,
+        Self::MultipleFaultViolation,
+        Self::CurrentDomainNotAllowed,
+        Self::CannotMake,
+        Self::SystemShutdown,
+        Self::DsInitFailureConsole,
+        Self::DsSamInitFailureConsole,
+        Self::UnfinishedContextDeleted,
+        Self::NoTgtReply,
+        Self::ObjectidNotFound,
+        Self::NoIpAddresses,
+        Self::WrongCredentialHandle,
+        Self::CryptoSystemInvalid,
+        Self::MaxReferralsExceeded,
+        Self::MustBeKdc,
+        Self::StrongCryptoNotSupported,
+        Self::TooManyPrincipals,
+        Self::NoPaData,
+        Self::PkinitNameMismatch,
+        Self::SmartcardLogonRequired,
+        Self::KdcInvalidRequest,
+        Self::KdcUnableToRefer,
+        Self::KdcUnknownEtype,
+        Self::ShutdownInProgress,
+        Self::ServerShutdownInProgress,
+        Self::NotSupportedOnSbs,
+        Self::WmiGuidDisconnected,
+        Self::WmiAlreadyDisabled,
+        Self::WmiAlreadyEnabled,
+        Self::MftTooFragmented,
+        Self::CopyProtectionFailure,
+        Self::CssAuthenticationFailure,
+        Self::CssKeyNotPresent,
+        Self::CssKeyNotEstablished,
+        Self::CssScrambledSector,
+        Self::CssRegionMismatch,
+        Self::CssResetsExhausted,
+        Self::PasswordChangeRequired,
+        Self::LostModeLogonRestriction,
+        Self::PkinitFailure,
+        Self::SmartcardSubsystemFailure,
+        Self::NoKerbKey,
+        Self::HostDown,
+        Self::UnsupportedPreauth,
+        Self::EfsAlgBlobTooBig,
+        Self::PortNotSet,
+        Self::DebuggerInactive,
+        Self::DsVersionCheckFailure,
+        Self::AuditingDisabled,
+        Self::Prent4MachineAccount,
+        Self::DsAgCantHaveUniversalMember,
+        Self::InvalidImageWin32,
+        Self::InvalidImageWin64,
+        Self::BadBindings,
+        Self::NetworkSessionExpired,
+        Self::ApphelpBlock,
+        Self::AllSidsFiltered,
+        Self::NotSafeModeDriver,
+        Self::AccessDisabledByPolicyDefault,
+        Self::AccessDisabledByPolicyPath,
+        Self::AccessDisabledByPolicyPublisher,
+        Self::AccessDisabledByPolicyOther,
+        Self::FailedDriverEntry,
+        Self::DeviceEnumerationError,
+        Self::MountPointNotResolved,
+        Self::InvalidDeviceObjectParameter,
+        Self::McaOccured,
+        Self::DriverBlockedCritical,
+        Self::DriverBlocked,
+        Self::DriverDatabaseError,
+        Self::SystemHiveTooLarge,
+        Self::InvalidImportOfNonDll,
+        Self::NoSecrets,
+        Self::AccessDisabledNoSaferUiByPolicy,
+        Self::FailedStackSwitch,
+        Self::HeapCorruption,
+        Self::SmartcardWrongPin,
+        Self::SmartcardCardBlocked,
+        Self::SmartcardCardNotAuthenticated,
+        Self::SmartcardNoCard,
+        Self::SmartcardNoKeyContainer,
+        Self::SmartcardNoCertificate,
+        Self::SmartcardNoKeyset,
+        Self::SmartcardIoError,
+        Self::DowngradeDetected,
+        Self::SmartcardCertRevoked,
+        Self::IssuingCaUntrusted,
+        Self::RevocationOfflineC,
+        Self::PkinitClientFailure,
+        Self::SmartcardCertExpired,
+        Self::DriverFailedPriorUnload,
+        Self::SmartcardSilentContext,
+        Self::PerUserTrustQuotaExceeded,
+        Self::AllUserTrustQuotaExceeded,
+        Self::UserDeleteTrustQuotaExceeded,
+        Self::DsNameNotUnique,
+        Self::DsDuplicateIdFound,
+        Self::DsGroupConversionError,
+        Self::VolsnapPrepareHibernate,
+        Self::User2userRequired,
+        Self::StackBufferOverrun,
+        Self::NoS4uProtSupport,
+        Self::CrossrealmDelegationFailure,
+        Self::RevocationOfflineKdc,
+        Self::IssuingCaUntrustedKdc,
+        Self::KdcCertExpired,
+        Self::KdcCertRevoked,
+        Self::ParameterQuotaExceeded,
+        Self::HibernationFailure,
+        Self::DelayLoadFailed,
+        Self::AuthenticationFirewallFailed,
+        Self::VdmDisallowed,
+        Self::HungDisplayDriverThread,
+        Self::InsufficientResourceForSpecifiedSharedSectionSize,
+        Self::InvalidCruntimeParameter,
+        Self::NtlmBlocked,
+        Self::DsSrcSidExistsInForest,
+        Self::DsDomainNameExistsInForest,
+        Self::DsFlatNameExistsInForest,
+        Self::InvalidUserPrincipalName,
+        Self::FatalUserCallbackException,
+        Self::AssertionFailure,
+        Self::VerifierStop,
+        Self::CallbackPopStack,
+        Self::IncompatibleDriverBlocked,
+        Self::HiveUnloaded,
+        Self::CompressionDisabled,
+        Self::FileSystemLimitation,
+        Self::InvalidImageHash,
+        Self::NotCapable,
+        Self::RequestOutOfSequence,
+        Self::ImplementationLimit,
+        Self::ElevationRequired,
+        Self::NoSecurityContext,
+        Self::Pku2uCertFailure,
+        Self::BeyondVdl,
+        Self::EncounteredWriteInProgress,
+        Self::PteChanged,
+        Self::PurgeFailed,
+        Self::CredRequiresConfirmation,
+        Self::CsEncryptionInvalidServerResponse,
+        Self::CsEncryptionUnsupportedServer,
+        Self::CsEncryptionExistingEncryptedFile,
+        Self::CsEncryptionNewEncryptedFile,
+        Self::CsEncryptionFileNotCse,
+        Self::InvalidLabel,
+        Self::DriverProcessTerminated,
+        Self::AmbiguousSystemDevice,
+        Self::SystemDeviceNotFound,
+        Self::RestartBootApplication,
+        Self::InsufficientNvramResources,
+        Self::InvalidSession,
+        Self::ThreadAlreadyInSession,
+        Self::ThreadNotInSession,
+        Self::InvalidWeight,
+        Self::RequestPaused,
+        Self::NoRangesProcessed,
+        Self::DiskResourcesExhausted,
+        Self::NeedsRemediation,
+        Self::DeviceFeatureNotSupported,
+        Self::DeviceUnreachable,
+        Self::InvalidToken,
+        Self::ServerUnavailable,
+        Self::FileNotAvailable,
+        Self::DeviceInsufficientResources,
+        Self::PackageUpdating,
+        Self::NotReadFromCopy,
+        Self::FtWriteFailure,
+        Self::FtDiScanRequired,
+        Self::ObjectNotExternallyBacked,
+        Self::ExternalBackingProviderUnknown,
+        Self::CompressionNotBeneficial,
+        Self::DataChecksumError,
+        Self::IntermixedKernelEaOperation,
+        Self::TrimReadZeroNotSupported,
+        Self::TooManySegmentDescriptors,
+        Self::InvalidOffsetAlignment,
+        Self::InvalidFieldInParameterList,
+        Self::OperationInProgress,
+        Self::InvalidInitiatorTargetPath,
+        Self::ScrubDataDisabled,
+        Self::NotRedundantStorage,
+        Self::ResidentFileNotSupported,
+        Self::CompressedFileNotSupported,
+        Self::DirectoryNotSupported,
+        Self::IoOperationTimeout,
+        Self::SystemNeedsRemediation,
+        Self::AppxIntegrityFailureClrNgen,
+        Self::ShareUnavailable,
+        Self::ApisetNotHosted,
+        Self::ApisetNotPresent,
+        Self::DeviceHardwareError,
+        Self::FirmwareSlotInvalid,
+        Self::FirmwareImageInvalid,
+        Self::StorageTopologyIdMismatch,
+        Self::WimNotBootable,
+        Self::BlockedByParentalControls,
+        Self::NeedsRegistration,
+        Self::QuotaActivity,
+        Self::CallbackInvokeInline,
+        Self::BlockTooManyReferences,
+        Self::MarkedToDisallowWrites,
+        Self::NetworkAccessDeniedEdp,
+        Self::EnclaveFailure,
+        Self::PnpNoCompatDrivers,
+        Self::PnpDriverPackageNotFound,
+        Self::PnpDriverConfigurationNotFound,
+        Self::PnpDriverConfigurationIncomplete,
+        Self::PnpFunctionDriverRequired,
+        Self::PnpDeviceConfigurationPending,
+        Self::DeviceHintNameBufferTooSmall,
+        Self::PackageNotAvailable,
+        Self::DeviceInMaintenance,
+        Self::NotSupportedOnDax,
+        Self::FreeSpaceTooFragmented,
+        Self::DaxMappingExists,
+        Self::ChildProcessBlocked,
+        Self::StorageLostDataPersistence,
+        Self::VrfCfgEnabled,
+        Self::PartitionTerminating,
+        Self::ExternalSyskeyNotSupported,
+        Self::EnclaveViolation,
+        Self::FileProtectedUnderDpl,
+        Self::VolumeNotClusterAligned,
+        Self::NoPhysicallyAlignedFreeSpaceFound,
+        Self::AppxFileNotEncrypted,
+        Self::RwrawEncryptedFileNotEncrypted,
+        Self::RwrawEncryptedInvalidEdatainfoFileoffset,
+        Self::RwrawEncryptedInvalidEdatainfoFilerange,
+        Self::RwrawEncryptedInvalidEdatainfoParameter,
+        Self::FtReadFailure,
+        Self::PatchConflict,
+        Self::StorageReserveIdInvalid,
+        Self::StorageReserveDoesNotExist,
+        Self::StorageReserveAlreadyExists,
+        Self::StorageReserveNotEmpty,
+        Self::NotADaxVolume,
+        Self::NotDaxMappable,
+        Self::CaseDifferingNamesInDir,
+        Self::FileNotSupported,
+        Self::NotSupportedWithBtt,
+        Self::EncryptionDisabled,
+        Self::EncryptingMetadataDisallowed,
+        Self::CantClearEncryptionFlag,
+        Self::UnsatisfiedDependencies,
+        Self::CaseSensitivePath,
+        Self::UnsupportedPagingMode,
+        Self::UntrustedMountPoint,
+        Self::HasSystemCriticalFiles,
+        Self::ObjectIsImmutable,
+        Self::FtReadFromCopyFailure,
+        Self::ImageLoadedAsPatchImage,
+        Self::StorageStackAccessDenied,
+        Self::InsufficientVirtualAddrResources,
+        Self::EncryptedFileNotSupported,
+        Self::SparseFileNotSupported,
+        Self::PagefileNotSupported,
+        Self::VolumeNotSupported,
+        Self::NotSupportedWithBypassio,
+        Self::NoBypassioDriverSupport,
+        Self::NotSupportedWithEncryption,
+        Self::NotSupportedWithCompression,
+        Self::NotSupportedWithReplication,
+        Self::NotSupportedWithDeduplication,
+        Self::NotSupportedWithAuditing,
+        Self::NotSupportedWithMonitoring,
+        Self::NotSupportedWithSnapshot,
+        Self::NotSupportedWithVirtualization,
+        Self::IndexOutOfBounds,
+        Self::BypassioFltNotSupported,
+        Self::VolumeWriteAccessDenied,
+        Self::PatchNotRegistered,
+        Self::NotSupportedWithCachedHandle,
+        Self::InvalidTaskName,
+        Self::InvalidTaskIndex,
+        Self::ThreadAlreadyInTask,
+        Self::CallbackBypass,
+        Self::UndefinedScope,
+        Self::InvalidCap,
+        Self::NotGuiProcess,
+        Self::DeviceHung,
+        Self::ContainerAssigned,
+        Self::JobNoContainer,
+        Self::DeviceUnresponsive,
+        Self::ReparsePointEncountered,
+        Self::AttributeNotPresent,
+        Self::NotATieredVolume,
+        Self::AlreadyHasStreamId,
+        Self::JobNotEmpty,
+        Self::AlreadyInitialized,
+        Self::EnclaveNotTerminated,
+        Self::EnclaveIsTerminating,
+        Self::Smb1NotAvailable,
+        Self::SmrGarbageCollectionRequired,
+        Self::Interrupted,
+        Self::ThreadNotRunning,
+        Self::SessionKeyTooShort,
+        Self::FsMetadataInconsistent,
+        Self::FailFastException,
+        Self::ImageCertRevoked,
+        Self::DynamicCodeBlocked,
+        Self::ImageCertExpired,
+        Self::StrictCfgViolation,
+        Self::SetContextDenied,
+        Self::CrossPartitionViolation,
+        Self::PortClosed,
+        Self::MessageLost,
+        Self::InvalidMessage,
+        Self::RequestCanceled,
+        Self::RecursiveDispatch,
+        Self::LpcReceiveBufferExpected,
+        Self::LpcInvalidConnectionUsage,
+        Self::LpcRequestsNotAllowed,
+        Self::ResourceInUse,
+        Self::HardwareMemoryError,
+        Self::ThreadpoolHandleException,
+        Self::ThreadpoolSetEventOnCompletionFailed,
+        Self::ThreadpoolReleaseSemaphoreOnCompletionFailed,
+        Self::ThreadpoolReleaseMutexOnCompletionFailed,
+        Self::ThreadpoolFreeLibraryOnCompletionFailed,
+        Self::ThreadpoolReleasedDuringOperation,
+        Self::CallbackReturnedWhileImpersonating,
+        Self::ApcReturnedWhileImpersonating,
+        Self::ProcessIsProtected,
+        Self::McaException,
+        Self::CertificateMappingNotUnique,
+        Self::SymlinkClassDisabled,
+        Self::InvalidIdnNormalization,
+        Self::NoUnicodeTranslation,
+        Self::AlreadyRegistered,
+        Self::ContextMismatch,
+        Self::PortAlreadyHasCompletionList,
+        Self::CallbackReturnedThreadPriority,
+        Self::InvalidThread,
+        Self::CallbackReturnedTransaction,
+        Self::CallbackReturnedLdrLock,
+        Self::CallbackReturnedLang,
+        Self::CallbackReturnedPriBack,
+        Self::CallbackReturnedThreadAffinity,
+        Self::LpcHandleCountExceeded,
+        Self::ExecutableMemoryWrite,
+        Self::KernelExecutableMemoryWrite,
+        Self::AttachedExecutableMemoryWrite,
+        Self::TriggeredExecutableMemoryWrite,
+        Self::DiskRepairDisabled,
+        Self::DsDomainRenameInProgress,
+        Self::DiskQuotaExceeded,
+        Self::ContentBlocked,
+        Self::BadClusters,
+        Self::VolumeDirty,
+        Self::DiskRepairUnsuccessful,
+        Self::CorruptLogOverfull,
+        Self::CorruptLogCorrupted,
+        Self::CorruptLogUnavailable,
+        Self::CorruptLogDeletedFull,
+        Self::CorruptLogCleared,
+        Self::OrphanNameExhausted,
+        Self::ProactiveScanInProgress,
+        Self::EncryptedIoNotPossible,
+        Self::CorruptLogUplevelRecords,
+        Self::FileCheckedOut,
+        Self::CheckoutRequired,
+        Self::BadFileType,
+        Self::FileTooLarge,
+        Self::FormsAuthRequired,
+        Self::VirusInfected,
+        Self::VirusDeleted,
+        Self::BadMcfgTable,
+        Self::CannotBreakOplock,
+        Self::BadKey,
+        Self::BadData,
+        Self::NoKey,
+        Self::FileHandleRevoked,
+        Self::SectionDirectMapOnly,
+        Self::BlockWeakReferenceInvalid,
+        Self::BlockSourceWeakReferenceInvalid,
+        Self::BlockTargetWeakReferenceInvalid,
+        Self::BlockShared,
+        Self::VrfVolatileCfgAndIoEnabled,
+        Self::VrfVolatileNotStoppable,
+        Self::VrfVolatileSafeMode,
+        Self::VrfVolatileNotRunnableSystem,
+        Self::VrfVolatileNotSupportedRuleclass,
+        Self::VrfVolatileProtectedDriver,
+        Self::VrfVolatileNmiRegistered,
+        Self::VrfVolatileSettingsConflict,
+        Self::DifIocallbackNotReplaced,
+        Self::DifLivedumpLimitExceeded,
+        Self::DifVolatileSectionNotLocked,
+        Self::DifVolatileDriverHotpatched,
+        Self::DifVolatileInvalidInfo,
+        Self::DifVolatileDriverIsNotRunning,
+        Self::DifVolatilePluginIsNotRunning,
+        Self::DifVolatilePluginChangeNotAllowed,
+        Self::DifVolatileNotAllowed,
+        Self::DifBindingApiNotFound,
+        Self::WowAssertion,
+        Self::InvalidSignature,
+        Self::HmacNotSupported,
+        Self::AuthTagMismatch,
+        Self::InvalidStateTransition,
+        Self::InvalidKernelInfoVersion,
+        Self::InvalidPepInfoVersion,
+        Self::HandleRevoked,
+        Self::EofOnGhostedRange,
+        Self::CcNeedsCallbackSectionDrain,
+        Self::IpsecQueueOverflow,
+        Self::NdQueueOverflow,
+        Self::HoplimitExceeded,
+        Self::ProtocolNotSupported,
+        Self::FastpathRejected,
+        Self::LostWritebehindDataNetworkDisconnected,
+        Self::LostWritebehindDataNetworkServerError,
+        Self::LostWritebehindDataLocalDiskError,
+        Self::XmlParseError,
+        Self::XmldsigError,
+        Self::WrongCompartment,
+        Self::AuthipFailure,
+        Self::DsOidMappedGroupCantHaveMembers,
+        Self::DsOidNotFound,
+        Self::IncorrectAccountType,
+        Self::LocalPolicyModificationNotSupported,
+        Self::PolicyControlledAccount,
+        Self::LapsLegacySchemaMissing,
+        Self::LapsSchemaMissing,
+        Self::LapsEncryptionRequires2016Dfl,
+        Self::HashNotSupported,
+        Self::HashNotPresent,
+        Self::SecondaryIcProviderNotRegistered,
+        Self::GpioClientInformationInvalid,
+        Self::GpioVersionNotSupported,
+        Self::GpioInvalidRegistrationPacket,
+        Self::GpioOperationDenied,
+        Self::GpioIncompatibleConnectMode,
+        Self::CannotSwitchRunlevel,
+        Self::InvalidRunlevelSetting,
+        Self::RunlevelSwitchTimeout,
+        Self::RunlevelSwitchAgentTimeout,
+        Self::RunlevelSwitchInProgress,
+        Self::NotAppcontainer,
+        Self::NotSupportedInAppcontainer,
+        Self::InvalidPackageSidLength,
+        Self::LpacAccessDenied,
+        Self::AdminlessAccessDenied,
+        Self::AppDataNotFound,
+        Self::AppDataExpired,
+        Self::AppDataCorrupt,
+        Self::AppDataLimitExceeded,
+        Self::AppDataRebootRequired,
+        Self::OffloadReadFltNotSupported,
+        Self::OffloadWriteFltNotSupported,
+        Self::OffloadReadFileNotSupported,
+        Self::OffloadWriteFileNotSupported,
+        Self::WofWimHeaderCorrupt,
+        Self::WofWimResourceTableCorrupt,
+        Self::WofFileResourceTableCorrupt,
+        Self::CimfsImageCorrupt,
+        Self::CimfsImageVersionNotSupported,
+        Self::FileSystemVirtualizationUnavailable,
+        Self::FileSystemVirtualizationMetadataCorrupt,
+        Self::FileSystemVirtualizationBusy,
+        Self::FileSystemVirtualizationProviderUnknown,
+        Self::FileSystemVirtualizationInvalidOperation,
+        Self::CloudFileSyncRootMetadataCorrupt,
+        Self::CloudFileProviderNotRunning,
+        Self::CloudFileMetadataCorrupt,
+        Self::CloudFileMetadataTooLarge,
+        Self::CloudFilePropertyVersionNotSupported,
+        Self::NotACloudFile,
+        Self::CloudFileNotInSync,
+        Self::CloudFileAlreadyConnected,
+        Self::CloudFileNotSupported,
+        Self::CloudFileInvalidRequest,
+        Self::CloudFileReadOnlyVolume,
+        Self::CloudFileConnectedProviderOnly,
+        Self::CloudFileValidationFailed,
+        Self::CloudFileAuthenticationFailed,
+        Self::CloudFileInsufficientResources,
+        Self::CloudFileNetworkUnavailable,
+        Self::CloudFileUnsuccessful,
+        Self::CloudFileNotUnderSyncRoot,
+        Self::CloudFileInUse,
+        Self::CloudFilePinned,
+        Self::CloudFileRequestAborted,
+        Self::CloudFilePropertyCorrupt,
+        Self::CloudFileAccessDenied,
+        Self::CloudFileIncompatibleHardlinks,
+        Self::CloudFilePropertyLockConflict,
+        Self::CloudFileRequestCanceled,
+        Self::CloudFileProviderTerminated,
+        Self::NotACloudSyncRoot,
+        Self::CloudFileRequestTimeout,
+        Self::CloudFileDehydrationDisallowed,
+        Self::CloudFileUsMessageTimeout,
+        Self::FileSnapInProgress,
+        Self::FileSnapUserSectionNotSupported,
+        Self::FileSnapModifyNotSupported,
+        Self::FileSnapIoNotCoordinated,
+        Self::FileSnapUnexpectedError,
+        Self::FileSnapInvalidParameter,
+        Self::PnpBadMpsTable,
+        Self::PnpTranslationFailed,
+        Self::PnpIrqTranslationFailed,
+        Self::PnpInvalidId,
+        Self::IoReissueAsCached,
+        Self::CtxWinstationNameInvalid,
+        Self::CtxInvalidPd,
+        Self::CtxPdNotFound,
+        Self::CtxClosePending,
+        Self::CtxNoOutbuf,
+        Self::CtxModemInfNotFound,
+        Self::CtxInvalidModemname,
+        Self::CtxResponseError,
+        Self::CtxModemResponseTimeout,
+        Self::CtxModemResponseNoCarrier,
+        Self::CtxModemResponseNoDialtone,
+        Self::CtxModemResponseBusy,
+        Self::CtxModemResponseVoice,
+        Self::CtxTdError,
+        Self::CtxLicenseClientInvalid,
+        Self::CtxLicenseNotAvailable,
+        Self::CtxLicenseExpired,
+        Self::CtxWinstationNotFound,
+        Self::CtxWinstationNameCollision,
+        Self::CtxWinstationBusy,
+        Self::CtxBadVideoMode,
+        Self::CtxGraphicsInvalid,
+        Self::CtxNotConsole,
+        Self::CtxClientQueryTimeout,
+        Self::CtxConsoleDisconnect,
+        Self::CtxConsoleConnect,
+        Self::CtxShadowDenied,
+        Self::CtxWinstationAccessDenied,
+        Self::CtxInvalidWd,
+        Self::CtxWdNotFound,
+        Self::CtxShadowInvalid,
+        Self::CtxShadowDisabled,
+        Self::RdpProtocolError,
+        Self::CtxClientLicenseNotSet,
+        Self::CtxClientLicenseInUse,
+        Self::CtxShadowEndedByModeChange,
+        Self::CtxShadowNotRunning,
+        Self::CtxLogonDisabled,
+        Self::CtxSecurityLayerError,
+        Self::TsIncompatibleSessions,
+        Self::TsVideoSubsystemError,
+        Self::MuiFileNotFound,
+        Self::MuiInvalidFile,
+        Self::MuiInvalidRcConfig,
+        Self::MuiInvalidLocaleName,
+        Self::MuiInvalidUltimatefallbackName,
+        Self::MuiFileNotLoaded,
+        Self::ResourceEnumUserStop,
+        Self::ClusterInvalidNode,
+        Self::ClusterNodeExists,
+        Self::ClusterJoinInProgress,
+        Self::ClusterNodeNotFound,
+        Self::ClusterLocalNodeNotFound,
+        Self::ClusterNetworkExists,
+        Self::ClusterNetworkNotFound,
+        Self::ClusterNetinterfaceExists,
+        Self::ClusterNetinterfaceNotFound,
+        Self::ClusterInvalidRequest,
+        Self::ClusterInvalidNetworkProvider,
+        Self::ClusterNodeDown,
+        Self::ClusterNodeUnreachable,
+        Self::ClusterNodeNotMember,
+        Self::ClusterJoinNotInProgress,
+        Self::ClusterInvalidNetwork,
+        Self::ClusterNoNetAdapters,
+        Self::ClusterNodeUp,
+        Self::ClusterNodePaused,
+        Self::ClusterNodeNotPaused,
+        Self::ClusterNoSecurityContext,
+        Self::ClusterNetworkNotInternal,
+        Self::ClusterPoisoned,
+        Self::ClusterNonCsvPath,
+        Self::ClusterCsvVolumeNotLocal,
+        Self::ClusterCsvReadOplockBreakInProgress,
+        Self::ClusterCsvAutoPauseError,
+        Self::ClusterCsvRedirected,
+        Self::ClusterCsvNotRedirected,
+        Self::ClusterCsvVolumeDraining,
+        Self::ClusterCsvSnapshotCreationInProgress,
+        Self::ClusterCsvVolumeDrainingSucceededDownlevel,
+        Self::ClusterCsvNoSnapshots,
+        Self::CsvIoPauseTimeout,
+        Self::ClusterCsvInvalidHandle,
+        Self::ClusterCsvSupportedOnlyOnCoordinator,
+        Self::ClusterCamTicketReplayDetected,
+        Self::AcpiInvalidOpcode,
+        Self::AcpiStackOverflow,
+        Self::AcpiAssertFailed,
+        Self::AcpiInvalidIndex,
+        Self::AcpiInvalidArgument,
+        Self::AcpiFatal,
+        Self::AcpiInvalidSupername,
+        Self::AcpiInvalidArgtype,
+        Self::AcpiInvalidObjtype,
+        Self::AcpiInvalidTargettype,
+        Self::AcpiIncorrectArgumentCount,
+        Self::AcpiAddressNotMapped,
+        Self::AcpiInvalidEventtype,
+        Self::AcpiHandlerCollision,
+        Self::AcpiInvalidData,
+        Self::AcpiInvalidRegion,
+        Self::AcpiInvalidAccessSize,
+        Self::AcpiAcquireGlobalLock,
+        Self::AcpiAlreadyInitialized,
+        Self::AcpiNotInitialized,
+        Self::AcpiInvalidMutexLevel,
+        Self::AcpiMutexNotOwned,
+        Self::AcpiMutexNotOwner,
+        Self::AcpiRsAccess,
+        Self::AcpiInvalidTable,
+        Self::AcpiRegHandlerFailed,
+        Self::AcpiPowerRequestFailed,
+        Self::SxsSectionNotFound,
+        Self::SxsCantGenActctx,
+        Self::SxsInvalidActctxdataFormat,
+        Self::SxsAssemblyNotFound,
+        Self::SxsManifestFormatError,
+        Self::SxsManifestParseError,
+        Self::SxsActivationContextDisabled,
+        Self::SxsKeyNotFound,
+        Self::SxsVersionConflict,
+        Self::SxsWrongSectionType,
+        Self::SxsThreadQueriesDisabled,
+        Self::SxsAssemblyMissing,
+        Self::SxsProcessDefaultAlreadySet,
+        Self::SxsEarlyDeactivation,
+        Self::SxsInvalidDeactivation,
+        Self::SxsMultipleDeactivation,
+        Self::SxsSystemDefaultActivationContextEmpty,
+        Self::SxsProcessTerminationRequested,
+        Self::SxsCorruptActivationStack,
+        Self::SxsCorruption,
+        Self::SxsInvalidIdentityAttributeValue,
+        Self::SxsInvalidIdentityAttributeName,
+        Self::SxsIdentityDuplicateAttribute,
+        Self::SxsIdentityParseError,
+        Self::SxsComponentStoreCorrupt,
+        Self::SxsFileHashMismatch,
+        Self::SxsManifestIdentitySameButContentsDifferent,
+        Self::SxsIdentitiesDifferent,
+        Self::SxsAssemblyIsNotADeployment,
+        Self::SxsFileNotPartOfAssembly,
+        Self::AdvancedInstallerFailed,
+        Self::XmlEncodingMismatch,
+        Self::SxsManifestTooBig,
+        Self::SxsSettingNotRegistered,
+        Self::SxsTransactionClosureIncomplete,
+        Self::SmiPrimitiveInstallerFailed,
+        Self::GenericCommandFailed,
+        Self::SxsFileHashMissing,
+        Self::TransactionalConflict,
+        Self::InvalidTransaction,
+        Self::TransactionNotActive,
+        Self::TmInitializationFailed,
+        Self::RmNotActive,
+        Self::RmMetadataCorrupt,
+        Self::TransactionNotJoined,
+        Self::DirectoryNotRm,
+        Self::TransactionsUnsupportedRemote,
+        Self::LogResizeInvalidSize,
+        Self::RemoteFileVersionMismatch,
+        Self::CrmProtocolAlreadyExists,
+        Self::TransactionPropagationFailed,
+        Self::CrmProtocolNotFound,
+        Self::TransactionSuperiorExists,
+        Self::TransactionRequestNotValid,
+        Self::TransactionNotRequested,
+        Self::TransactionAlreadyAborted,
+        Self::TransactionAlreadyCommitted,
+        Self::TransactionInvalidMarshallBuffer,
+        Self::CurrentTransactionNotValid,
+        Self::LogGrowthFailed,
+        Self::ObjectNoLongerExists,
+        Self::StreamMiniversionNotFound,
+        Self::StreamMiniversionNotValid,
+        Self::MiniversionInaccessibleFromSpecifiedTransaction,
+        Self::CantOpenMiniversionWithModifyIntent,
+        Self::CantCreateMoreStreamMiniversions,
+        Self::HandleNoLongerValid,
+        Self::LogCorruptionDetected,
+        Self::RmDisconnected,
+        Self::EnlistmentNotSuperior,
+        Self::FileIdentityNotPersistent,
+        Self::CantBreakTransactionalDependency,
+        Self::CantCrossRmBoundary,
+        Self::TxfDirNotEmpty,
+        Self::IndoubtTransactionsExist,
+        Self::TmVolatile,
+        Self::RollbackTimerExpired,
+        Self::TxfAttributeCorrupt,
+        Self::EfsNotAllowedInTransaction,
+        Self::TransactionalOpenNotAllowed,
+        Self::TransactedMappingUnsupportedRemote,
+        Self::TransactionRequiredPromotion,
+        Self::CannotExecuteFileInTransaction,
+        Self::TransactionsNotFrozen,
+        Self::TransactionFreezeInProgress,
+        Self::NotSnapshotVolume,
+        Self::NoSavepointWithOpenFiles,
+        Self::SparseNotAllowedInTransaction,
+        Self::TmIdentityMismatch,
+        Self::FloatedSection,
+        Self::CannotAcceptTransactedWork,
+        Self::CannotAbortTransactions,
+        Self::TransactionNotFound,
+        Self::ResourcemanagerNotFound,
+        Self::EnlistmentNotFound,
+        Self::TransactionmanagerNotFound,
+        Self::TransactionmanagerNotOnline,
+        Self::TransactionmanagerRecoveryNameCollision,
+        Self::TransactionNotRoot,
+        Self::TransactionObjectExpired,
+        Self::CompressionNotAllowedInTransaction,
+        Self::TransactionResponseNotEnlisted,
+        Self::TransactionRecordTooLong,
+        Self::NoLinkTrackingInTransaction,
+        Self::OperationNotSupportedInTransaction,
+        Self::TransactionIntegrityViolated,
+        Self::TransactionmanagerIdentityMismatch,
+        Self::RmCannotBeFrozenForSnapshot,
+        Self::TransactionMustWritethrough,
+        Self::TransactionNoSuperior,
+        Self::ExpiredHandle,
+        Self::TransactionNotEnlisted,
+        Self::LogSectorInvalid,
+        Self::LogSectorParityInvalid,
+        Self::LogSectorRemapped,
+        Self::LogBlockIncomplete,
+        Self::LogInvalidRange,
+        Self::LogBlocksExhausted,
+        Self::LogReadContextInvalid,
+        Self::LogRestartInvalid,
+        Self::LogBlockVersion,
+        Self::LogBlockInvalid,
+        Self::LogReadModeInvalid,
+        Self::LogMetadataCorrupt,
+        Self::LogMetadataInvalid,
+        Self::LogMetadataInconsistent,
+        Self::LogReservationInvalid,
+        Self::LogCantDelete,
+        Self::LogContainerLimitExceeded,
+        Self::LogStartOfLog,
+        Self::LogPolicyAlreadyInstalled,
+        Self::LogPolicyNotInstalled,
+        Self::LogPolicyInvalid,
+        Self::LogPolicyConflict,
+        Self::LogPinnedArchiveTail,
+        Self::LogRecordNonexistent,
+        Self::LogRecordsReservedInvalid,
+        Self::LogSpaceReservedInvalid,
+        Self::LogTailInvalid,
+        Self::LogFull,
+        Self::LogMultiplexed,
+        Self::LogDedicated,
+        Self::LogArchiveNotInProgress,
+        Self::LogArchiveInProgress,
+        Self::LogEphemeral,
+        Self::LogNotEnoughContainers,
+        Self::LogClientAlreadyRegistered,
+        Self::LogClientNotRegistered,
+        Self::LogFullHandlerInProgress,
+        Self::LogContainerReadFailed,
+        Self::LogContainerWriteFailed,
+        Self::LogContainerOpenFailed,
+        Self::LogContainerStateInvalid,
+        Self::LogStateInvalid,
+        Self::LogPinned,
+        Self::LogMetadataFlushFailed,
+        Self::LogInconsistentSecurity,
+        Self::LogAppendedFlushFailed,
+        Self::LogPinnedReservation,
+        Self::VideoHungDisplayDriverThread,
+        Self::FltNoHandlerDefined,
+        Self::FltContextAlreadyDefined,
+        Self::FltInvalidAsynchronousRequest,
+        Self::FltDisallowFastIo,
+        Self::FltInvalidNameRequest,
+        Self::FltNotSafeToPostOperation,
+        Self::FltNotInitialized,
+        Self::FltFilterNotReady,
+        Self::FltPostOperationCleanup,
+        Self::FltInternalError,
+        Self::FltDeletingObject,
+        Self::FltMustBeNonpagedPool,
+        Self::FltDuplicateEntry,
+        Self::FltCbdqDisabled,
+        Self::FltDoNotAttach,
+        Self::FltDoNotDetach,
+        Self::FltInstanceAltitudeCollision,
+        Self::FltInstanceNameCollision,
+        Self::FltFilterNotFound,
+        Self::FltVolumeNotFound,
+        Self::FltInstanceNotFound,
+        Self::FltContextAllocationNotFound,
+        Self::FltInvalidContextRegistration,
+        Self::FltNameCacheMiss,
+        Self::FltNoDeviceObject,
+        Self::FltVolumeAlreadyMounted,
+        Self::FltAlreadyEnlisted,
+        Self::FltContextAlreadyLinked,
+        Self::FltNoWaiterForReply,
+        Self::FltRegistrationBusy,
+        Self::FltWcosNotSupported,
+        Self::MonitorNoDescriptor,
+        Self::MonitorUnknownDescriptorFormat,
+        Self::MonitorInvalidDescriptorChecksum,
+        Self::MonitorInvalidStandardTimingBlock,
+        Self::MonitorWmiDatablockRegistrationFailed,
+        Self::MonitorInvalidSerialNumberMondscBlock,
+        Self::MonitorInvalidUserFriendlyMondscBlock,
+        Self::MonitorNoMoreDescriptorData,
+        Self::MonitorInvalidDetailedTimingBlock,
+        Self::MonitorInvalidManufactureDate,
+        Self::GraphicsNotExclusiveModeOwner,
+        Self::GraphicsInsufficientDmaBuffer,
+        Self::GraphicsInvalidDisplayAdapter,
+        Self::GraphicsAdapterWasReset,
+        Self::GraphicsInvalidDriverModel,
+        Self::GraphicsPresentModeChanged,
+        Self::GraphicsPresentOccluded,
+        Self::GraphicsPresentDenied,
+        Self::GraphicsCannotcolorconvert,
+        Self::GraphicsDriverMismatch,
+        Self::GraphicsPresentRedirectionDisabled,
+        Self::GraphicsPresentUnoccluded,
+        Self::GraphicsWindowdcNotAvailable,
+        Self::GraphicsWindowlessPresentDisabled,
+        Self::GraphicsPresentInvalidWindow,
+        Self::GraphicsPresentBufferNotBound,
+        Self::GraphicsVailStateChanged,
+        Self::GraphicsIndirectDisplayAbandonSwapchain,
+        Self::GraphicsIndirectDisplayDeviceStopped,
+        Self::GraphicsMpoAllocationUnpinned,
+        Self::GraphicsNoVideoMemory,
+        Self::GraphicsCantLockMemory,
+        Self::GraphicsAllocationBusy,
+        Self::GraphicsTooManyReferences,
+        Self::GraphicsTryAgainLater,
+        Self::GraphicsTryAgainNow,
+        Self::GraphicsAllocationInvalid,
+        Self::GraphicsUnswizzlingApertureUnavailable,
+        Self::GraphicsUnswizzlingApertureUnsupported,
+        Self::GraphicsCantEvictPinnedAllocation,
+        Self::GraphicsInvalidAllocationUsage,
+        Self::GraphicsCantRenderLockedAllocation,
+        Self::GraphicsAllocationClosed,
+        Self::GraphicsInvalidAllocationInstance,
+        Self::GraphicsInvalidAllocationHandle,
+        Self::GraphicsWrongAllocationDevice,
+        Self::GraphicsAllocationContentLost,
+        Self::GraphicsGpuExceptionOnDevice,
+        Self::GraphicsInvalidVidpnTopology,
+        Self::GraphicsVidpnTopologyNotSupported,
+        Self::GraphicsVidpnTopologyCurrentlyNotSupported,
+        Self::GraphicsInvalidVidpn,
+        Self::GraphicsInvalidVideoPresentSource,
+        Self::GraphicsInvalidVideoPresentTarget,
+        Self::GraphicsVidpnModalityNotSupported,
+        Self::GraphicsInvalidVidpnSourcemodeset,
+        Self::GraphicsInvalidVidpnTargetmodeset,
+        Self::GraphicsInvalidFrequency,
+        Self::GraphicsInvalidActiveRegion,
+        Self::GraphicsInvalidTotalRegion,
+        Self::GraphicsInvalidVideoPresentSourceMode,
+        Self::GraphicsInvalidVideoPresentTargetMode,
+        Self::GraphicsPinnedModeMustRemainInSet,
+        Self::GraphicsPathAlreadyInTopology,
+        Self::GraphicsModeAlreadyInModeset,
+        Self::GraphicsInvalidVideopresentsourceset,
+        Self::GraphicsInvalidVideopresenttargetset,
+        Self::GraphicsSourceAlreadyInSet,
+        Self::GraphicsTargetAlreadyInSet,
+        Self::GraphicsInvalidVidpnPresentPath,
+        Self::GraphicsNoRecommendedVidpnTopology,
+        Self::GraphicsInvalidMonitorFrequencyrangeset,
+        Self::GraphicsInvalidMonitorFrequencyrange,
+        Self::GraphicsFrequencyrangeNotInSet,
+        Self::GraphicsFrequencyrangeAlreadyInSet,
+        Self::GraphicsStaleModeset,
+        Self::GraphicsInvalidMonitorSourcemodeset,
+        Self::GraphicsInvalidMonitorSourceMode,
+        Self::GraphicsNoRecommendedFunctionalVidpn,
+        Self::GraphicsModeIdMustBeUnique,
+        Self::GraphicsEmptyAdapterMonitorModeSupportIntersection,
+        Self::GraphicsVideoPresentTargetsLessThanSources,
+        Self::GraphicsPathNotInTopology,
+        Self::GraphicsAdapterMustHaveAtLeastOneSource,
+        Self::GraphicsAdapterMustHaveAtLeastOneTarget,
+        Self::GraphicsInvalidMonitordescriptorset,
+        Self::GraphicsInvalidMonitordescriptor,
+        Self::GraphicsMonitordescriptorNotInSet,
+        Self::GraphicsMonitordescriptorAlreadyInSet,
+        Self::GraphicsMonitordescriptorIdMustBeUnique,
+        Self::GraphicsInvalidVidpnTargetSubsetType,
+        Self::GraphicsResourcesNotRelated,
+        Self::GraphicsSourceIdMustBeUnique,
+        Self::GraphicsTargetIdMustBeUnique,
+        Self::GraphicsNoAvailableVidpnTarget,
+        Self::GraphicsMonitorCouldNotBeAssociatedWithAdapter,
+        Self::GraphicsNoVidpnmgr,
+        Self::GraphicsNoActiveVidpn,
+        Self::GraphicsStaleVidpnTopology,
+        Self::GraphicsMonitorNotConnected,
+        Self::GraphicsSourceNotInTopology,
+        Self::GraphicsInvalidPrimarysurfaceSize,
+        Self::GraphicsInvalidVisibleregionSize,
+        Self::GraphicsInvalidStride,
+        Self::GraphicsInvalidPixelformat,
+        Self::GraphicsInvalidColorbasis,
+        Self::GraphicsInvalidPixelvalueaccessmode,
+        Self::GraphicsTargetNotInTopology,
+        Self::GraphicsNoDisplayModeManagementSupport,
+        Self::GraphicsVidpnSourceInUse,
+        Self::GraphicsCantAccessActiveVidpn,
+        Self::GraphicsInvalidPathImportanceOrdinal,
+        Self::GraphicsInvalidPathContentGeometryTransformation,
+        Self::GraphicsPathContentGeometryTransformationNotSupported,
+        Self::GraphicsInvalidGammaRamp,
+        Self::GraphicsGammaRampNotSupported,
+        Self::GraphicsMultisamplingNotSupported,
+        Self::GraphicsModeNotInModeset,
+        Self::GraphicsInvalidVidpnTopologyRecommendationReason,
+        Self::GraphicsInvalidPathContentType,
+        Self::GraphicsInvalidCopyprotectionType,
+        Self::GraphicsUnassignedModesetAlreadyExists,
+        Self::GraphicsInvalidScanlineOrdering,
+        Self::GraphicsTopologyChangesNotAllowed,
+        Self::GraphicsNoAvailableImportanceOrdinals,
+        Self::GraphicsIncompatiblePrivateFormat,
+        Self::GraphicsInvalidModePruningAlgorithm,
+        Self::GraphicsInvalidMonitorCapabilityOrigin,
+        Self::GraphicsInvalidMonitorFrequencyrangeConstraint,
+        Self::GraphicsMaxNumPathsReached,
+        Self::GraphicsCancelVidpnTopologyAugmentation,
+        Self::GraphicsInvalidClientType,
+        Self::GraphicsClientvidpnNotSet,
+        Self::GraphicsSpecifiedChildAlreadyConnected,
+        Self::GraphicsChildDescriptorNotSupported,
+        Self::GraphicsNotALinkedAdapter,
+        Self::GraphicsLeadlinkNotEnumerated,
+        Self::GraphicsChainlinksNotEnumerated,
+        Self::GraphicsAdapterChainNotReady,
+        Self::GraphicsChainlinksNotStarted,
+        Self::GraphicsChainlinksNotPoweredOn,
+        Self::GraphicsInconsistentDeviceLinkState,
+        Self::GraphicsNotPostDeviceDriver,
+        Self::GraphicsAdapterAccessNotExcluded,
+        Self::GraphicsOpmNotSupported,
+        Self::GraphicsCoppNotSupported,
+        Self::GraphicsUabNotSupported,
+        Self::GraphicsOpmInvalidEncryptedParameters,
+        Self::GraphicsOpmNoProtectedOutputsExist,
+        Self::GraphicsOpmInternalError,
+        Self::GraphicsOpmInvalidHandle,
+        Self::GraphicsPvpInvalidCertificateLength,
+        Self::GraphicsOpmSpanningModeEnabled,
+        Self::GraphicsOpmTheaterModeEnabled,
+        Self::GraphicsPvpHfsFailed,
+        Self::GraphicsOpmInvalidSrm,
+        Self::GraphicsOpmOutputDoesNotSupportHdcp,
+        Self::GraphicsOpmOutputDoesNotSupportAcp,
+        Self::GraphicsOpmOutputDoesNotSupportCgmsa,
+        Self::GraphicsOpmHdcpSrmNeverSet,
+        Self::GraphicsOpmResolutionTooHigh,
+        Self::GraphicsOpmAllHdcpHardwareAlreadyInUse,
+        Self::GraphicsOpmProtectedOutputNoLongerExists,
+        Self::GraphicsOpmProtectedOutputDoesNotHaveCoppSemantics,
+        Self::GraphicsOpmInvalidInformationRequest,
+        Self::GraphicsOpmDriverInternalError,
+        Self::GraphicsOpmProtectedOutputDoesNotHaveOpmSemantics,
+        Self::GraphicsOpmSignalingNotSupported,
+        Self::GraphicsOpmInvalidConfigurationRequest,
+        Self::GraphicsI2cNotSupported,
+        Self::GraphicsI2cDeviceDoesNotExist,
+        Self::GraphicsI2cErrorTransmittingData,
+        Self::GraphicsI2cErrorReceivingData,
+        Self::GraphicsDdcciVcpNotSupported,
+        Self::GraphicsDdcciInvalidData,
+        Self::GraphicsDdcciMonitorReturnedInvalidTimingStatusByte,
+        Self::GraphicsDdcciInvalidCapabilitiesString,
+        Self::GraphicsMcaInternalError,
+        Self::GraphicsDdcciInvalidMessageCommand,
+        Self::GraphicsDdcciInvalidMessageLength,
+        Self::GraphicsDdcciInvalidMessageChecksum,
+        Self::GraphicsInvalidPhysicalMonitorHandle,
+        Self::GraphicsMonitorNoLongerExists,
+        Self::GraphicsOnlyConsoleSessionSupported,
+        Self::GraphicsNoDisplayDeviceCorrespondsToName,
+        Self::GraphicsDisplayDeviceNotAttachedToDesktop,
+        Self::GraphicsMirroringDevicesNotSupported,
+        Self::GraphicsInvalidPointer,
+        Self::GraphicsNoMonitorsCorrespondToDisplayDevice,
+        Self::GraphicsParameterArrayTooSmall,
+        Self::GraphicsInternalError,
+        Self::GraphicsSessionTypeChangeInProgress,
+        Self::FveLockedVolume,
+        Self::FveNotEncrypted,
+        Self::FveBadInformation,
+        Self::FveTooSmall,
+        Self::FveFailedWrongFs,
+        Self::FveBadPartitionSize,
+        Self::FveFsNotExtended,
+        Self::FveFsMounted,
+        Self::FveNoLicense,
+        Self::FveActionNotAllowed,
+        Self::FveBadData,
+        Self::FveVolumeNotBound,
+        Self::FveNotDataVolume,
+        Self::FveConvReadError,
+        Self::FveConvWriteError,
+        Self::FveOverlappedUpdate,
+        Self::FveFailedSectorSize,
+        Self::FveFailedAuthentication,
+        Self::FveNotOsVolume,
+        Self::FveKeyfileNotFound,
+        Self::FveKeyfileInvalid,
+        Self::FveKeyfileNoVmk,
+        Self::FveTpmDisabled,
+        Self::FveTpmSrkAuthNotZero,
+        Self::FveTpmInvalidPcr,
+        Self::FveTpmNoVmk,
+        Self::FvePinInvalid,
+        Self::FveAuthInvalidApplication,
+        Self::FveAuthInvalidConfig,
+        Self::FveDebuggerEnabled,
+        Self::FveDryRunFailed,
+        Self::FveBadMetadataPointer,
+        Self::FveOldMetadataCopy,
+        Self::FveRebootRequired,
+        Self::FveRawAccess,
+        Self::FveRawBlocked,
+        Self::FveNoAutounlockMasterKey,
+        Self::FveMorFailed,
+        Self::FveNoFeatureLicense,
+        Self::FvePolicyUserDisableRdvNotAllowed,
+        Self::FveConvRecoveryFailed,
+        Self::FveVirtualizedSpaceTooBig,
+        Self::FveInvalidDatumType,
+        Self::FveVolumeTooSmall,
+        Self::FveEnhPinInvalid,
+        Self::FveFullEncryptionNotAllowedOnTpStorage,
+        Self::FveWipeNotAllowedOnTpStorage,
+        Self::FveNotAllowedOnCsvStack,
+        Self::FveNotAllowedOnCluster,
+        Self::FveNotAllowedToUpgradeWhileConverting,
+        Self::FveWipeCancelNotApplicable,
+        Self::FveEdriveDryRunFailed,
+        Self::FveSecurebootDisabled,
+        Self::FveSecurebootConfigChange,
+        Self::FveDeviceLockedout,
+        Self::FveVolumeExtendPreventsEowDecrypt,
+        Self::FveNotDeVolume,
+        Self::FveProtectionDisabled,
+        Self::FveProtectionCannotBeDisabled,
+        Self::FveOsvKsrNotAllowed,
+        Self::FveEdriveBandEnumerationFailed,
+        Self::FvePolicyOnRdvExclusionList,
+        Self::FveDatasetFull,
+        Self::FveMetadataFull,
+        Self::FwpCalloutNotFound,
+        Self::FwpConditionNotFound,
+        Self::FwpFilterNotFound,
+        Self::FwpLayerNotFound,
+        Self::FwpProviderNotFound,
+        Self::FwpProviderContextNotFound,
+        Self::FwpSublayerNotFound,
+        Self::FwpNotFound,
+        Self::FwpAlreadyExists,
+        Self::FwpInUse,
+        Self::FwpDynamicSessionInProgress,
+        Self::FwpWrongSession,
+        Self::FwpNoTxnInProgress,
+        Self::FwpTxnInProgress,
+        Self::FwpTxnAborted,
+        Self::FwpSessionAborted,
+        Self::FwpIncompatibleTxn,
+        Self::FwpTimeout,
+        Self::FwpNetEventsDisabled,
+        Self::FwpIncompatibleLayer,
+        Self::FwpKmClientsOnly,
+        Self::FwpLifetimeMismatch,
+        Self::FwpBuiltinObject,
+        Self::FwpTooManyCallouts,
+        Self::FwpNotificationDropped,
+        Self::FwpTrafficMismatch,
+        Self::FwpIncompatibleSaState,
+        Self::FwpNullPointer,
+        Self::FwpInvalidEnumerator,
+        Self::FwpInvalidFlags,
+        Self::FwpInvalidNetMask,
+        Self::FwpInvalidRange,
+        Self::FwpInvalidInterval,
+        Self::FwpZeroLengthArray,
+        Self::FwpNullDisplayName,
+        Self::FwpInvalidActionType,
+        Self::FwpInvalidWeight,
+        Self::FwpMatchTypeMismatch,
+        Self::FwpTypeMismatch,
+        Self::FwpOutOfBounds,
+        Self::FwpReserved,
+        Self::FwpDuplicateCondition,
+        Self::FwpDuplicateKeymod,
+        Self::FwpActionIncompatibleWithLayer,
+        Self::FwpActionIncompatibleWithSublayer,
+        Self::FwpContextIncompatibleWithLayer,
+        Self::FwpContextIncompatibleWithCallout,
+        Self::FwpIncompatibleAuthMethod,
+        Self::FwpIncompatibleDhGroup,
+        Self::FwpEmNotSupported,
+        Self::FwpNeverMatch,
+        Self::FwpProviderContextMismatch,
+        Self::FwpInvalidParameter,
+        Self::FwpTooManySublayers,
+        Self::FwpCalloutNotificationFailed,
+        Self::FwpInvalidAuthTransform,
+        Self::FwpInvalidCipherTransform,
+        Self::FwpIncompatibleCipherTransform,
+        Self::FwpInvalidTransformCombination,
+        Self::FwpDuplicateAuthMethod,
+        Self::FwpInvalidTunnelEndpoint,
+        Self::FwpL2DriverNotReady,
+        Self::FwpKeyDictatorAlreadyRegistered,
+        Self::FwpKeyDictationInvalidKeyingMaterial,
+        Self::FwpConnectionsDisabled,
+        Self::FwpInvalidDnsName,
+        Self::FwpStillOn,
+        Self::FwpIkeextNotRunning,
+        Self::FwpTcpipNotReady,
+        Self::FwpInjectHandleClosing,
+        Self::FwpInjectHandleStale,
+        Self::FwpCannotPend,
+        Self::FwpDropNoicmp,
+        Self::NdisClosing,
+        Self::NdisBadVersion,
+        Self::NdisBadCharacteristics,
+        Self::NdisAdapterNotFound,
+        Self::NdisOpenFailed,
+        Self::NdisDeviceFailed,
+        Self::NdisMulticastFull,
+        Self::NdisMulticastExists,
+        Self::NdisMulticastNotFound,
+        Self::NdisRequestAborted,
+        Self::NdisResetInProgress,
+        Self::NdisInvalidPacket,
+        Self::NdisInvalidDeviceRequest,
+        Self::NdisAdapterNotReady,
+        Self::NdisInvalidLength,
+        Self::NdisInvalidData,
+        Self::NdisBufferTooShort,
+        Self::NdisInvalidOid,
+        Self::NdisAdapterRemoved,
+        Self::NdisUnsupportedMedia,
+        Self::NdisGroupAddressInUse,
+        Self::NdisFileNotFound,
+        Self::NdisErrorReadingFile,
+        Self::NdisAlreadyMapped,
+        Self::NdisResourceConflict,
+        Self::NdisMediaDisconnected,
+        Self::NdisInvalidAddress,
+        Self::NdisPaused,
+        Self::NdisInterfaceNotFound,
+        Self::NdisUnsupportedRevision,
+        Self::NdisInvalidPort,
+        Self::NdisInvalidPortState,
+        Self::NdisLowPowerState,
+        Self::NdisReinitRequired,
+        Self::NdisNoQueues,
+        Self::NdisNotSupported,
+        Self::NdisOffloadPolicy,
+        Self::NdisOffloadConnectionRejected,
+        Self::NdisOffloadPathRejected,
+        Self::NdisDot11AutoConfigEnabled,
+        Self::NdisDot11MediaInUse,
+        Self::NdisDot11PowerStateInvalid,
+        Self::NdisPmWolPatternListFull,
+        Self::NdisPmProtocolOffloadListFull,
+        Self::NdisDot11ApChannelCurrentlyNotAvailable,
+        Self::NdisDot11ApBandCurrentlyNotAvailable,
+        Self::NdisDot11ApChannelNotAllowed,
+        Self::NdisDot11ApBandNotAllowed,
+        Self::QuicHandshakeFailure,
+        Self::QuicVerNegFailure,
+        Self::QuicUserCanceled,
+        Self::QuicInternalError,
+        Self::QuicProtocolViolation,
+        Self::QuicConnectionIdle,
+        Self::QuicConnectionTimeout,
+        Self::QuicAlpnNegFailure,
+        Self::TpmErrorMask,
+        Self::TpmAuthfail,
+        Self::TpmBadindex,
+        Self::TpmBadParameter,
+        Self::TpmAuditfailure,
+        Self::TpmClearDisabled,
+        Self::TpmDeactivated,
+        Self::TpmDisabled,
+        Self::TpmDisabledCmd,
+        Self::TpmFail,
+        Self::TpmBadOrdinal,
+        Self::TpmInstallDisabled,
+        Self::TpmInvalidKeyhandle,
+        Self::TpmKeynotfound,
+        Self::TpmInappropriateEnc,
+        Self::TpmMigratefail,
+        Self::TpmInvalidPcrInfo,
+        Self::TpmNospace,
+        Self::TpmNosrk,
+        Self::TpmNotsealedBlob,
+        Self::TpmOwnerSet,
+        Self::TpmResources,
+        Self::TpmShortrandom,
+        Self::TpmSize,
+        Self::TpmWrongpcrval,
+        Self::TpmBadParamSize,
+        Self::TpmShaThread,
+        Self::TpmShaError,
+        Self::TpmFailedselftest,
+        Self::TpmAuth2fail,
+        Self::TpmBadtag,
+        Self::TpmIoerror,
+        Self::TpmEncryptError,
+        Self::TpmDecryptError,
+        Self::TpmInvalidAuthhandle,
+        Self::TpmNoEndorsement,
+        Self::TpmInvalidKeyusage,
+        Self::TpmWrongEntitytype,
+        Self::TpmInvalidPostinit,
+        Self::TpmInappropriateSig,
+        Self::TpmBadKeyProperty,
+        Self::TpmBadMigration,
+        Self::TpmBadScheme,
+        Self::TpmBadDatasize,
+        Self::TpmBadMode,
+        Self::TpmBadPresence,
+        Self::TpmBadVersion,
+        Self::TpmNoWrapTransport,
+        Self::TpmAuditfailUnsuccessful,
+        Self::TpmAuditfailSuccessful,
+        Self::TpmNotresetable,
+        Self::TpmNotlocal,
+        Self::TpmBadType,
+        Self::TpmInvalidResource,
+        Self::TpmNotfips,
+        Self::TpmInvalidFamily,
+        Self::TpmNoNvPermission,
+        Self::TpmRequiresSign,
+        Self::TpmKeyNotsupported,
+        Self::TpmAuthConflict,
+        Self::TpmAreaLocked,
+        Self::TpmBadLocality,
+        Self::TpmReadOnly,
+        Self::TpmPerNowrite,
+        Self::TpmFamilycount,
+        Self::TpmWriteLocked,
+        Self::TpmBadAttributes,
+        Self::TpmInvalidStructure,
+        Self::TpmKeyOwnerControl,
+        Self::TpmBadCounter,
+        Self::TpmNotFullwrite,
+        Self::TpmContextGap,
+        Self::TpmMaxnvwrites,
+        Self::TpmNooperator,
+        Self::TpmResourcemissing,
+        Self::TpmDelegateLock,
+        Self::TpmDelegateFamily,
+        Self::TpmDelegateAdmin,
+        Self::TpmTransportNotexclusive,
+        Self::TpmOwnerControl,
+        Self::TpmDaaResources,
+        Self::TpmDaaInputData0,
+        Self::TpmDaaInputData1,
+        Self::TpmDaaIssuerSettings,
+        Self::TpmDaaTpmSettings,
+        Self::TpmDaaStage,
+        Self::TpmDaaIssuerValidity,
+        Self::TpmDaaWrongW,
+        Self::TpmBadHandle,
+        Self::TpmBadDelegate,
+        Self::TpmBadcontext,
+        Self::TpmToomanycontexts,
+        Self::TpmMaTicketSignature,
+        Self::TpmMaDestination,
+        Self::TpmMaSource,
+        Self::TpmMaAuthority,
+        Self::TpmPermanentek,
+        Self::TpmBadSignature,
+        Self::TpmNocontextspace,
+        Self::Tpm20EAsymmetric,
+        Self::Tpm20EAttributes,
+        Self::Tpm20EHash,
+        Self::Tpm20EValue,
+        Self::Tpm20EHierarchy,
+        Self::Tpm20EKeySize,
+        Self::Tpm20EMgf,
+        Self::Tpm20EMode,
+        Self::Tpm20EType,
+        Self::Tpm20EHandle,
+        Self::Tpm20EKdf,
+        Self::Tpm20ERange,
+        Self::Tpm20EAuthFail,
+        Self::Tpm20ENonce,
+        Self::Tpm20EPp,
+        Self::Tpm20EScheme,
+        Self::Tpm20ESize,
+        Self::Tpm20ESymmetric,
+        Self::Tpm20ETag,
+        Self::Tpm20ESelector,
+        Self::Tpm20EInsufficient,
+        Self::Tpm20ESignature,
+        Self::Tpm20EKey,
+        Self::Tpm20EPolicyFail,
+        Self::Tpm20EIntegrity,
+        Self::Tpm20ETicket,
+        Self::Tpm20EReservedBits,
+        Self::Tpm20EBadAuth,
+        Self::Tpm20EExpired,
+        Self::Tpm20EPolicyCc,
+        Self::Tpm20EBinding,
+        Self::Tpm20ECurve,
+        Self::Tpm20EEccPoint,
+        Self::Tpm20EInitialize,
+        Self::Tpm20EFailure,
+        Self::Tpm20ESequence,
+        Self::Tpm20EPrivate,
+        Self::Tpm20EHmac,
+        Self::Tpm20EDisabled,
+        Self::Tpm20EExclusive,
+        Self::Tpm20EEccCurve,
+        Self::Tpm20EAuthType,
+        Self::Tpm20EAuthMissing,
+        Self::Tpm20EPolicy,
+        Self::Tpm20EPcr,
+        Self::Tpm20EPcrChanged,
+        Self::Tpm20EUpgrade,
+        Self::Tpm20ETooManyContexts,
+        Self::Tpm20EAuthUnavailable,
+        Self::Tpm20EReboot,
+        Self::Tpm20EUnbalanced,
+        Self::Tpm20ECommandSize,
+        Self::Tpm20ECommandCode,
+        Self::Tpm20EAuthsize,
+        Self::Tpm20EAuthContext,
+        Self::Tpm20ENvRange,
+        Self::Tpm20ENvSize,
+        Self::Tpm20ENvLocked,
+        Self::Tpm20ENvAuthorization,
+        Self::Tpm20ENvUninitialized,
+        Self::Tpm20ENvSpace,
+        Self::Tpm20ENvDefined,
+        Self::Tpm20EBadContext,
+        Self::Tpm20ECphash,
+        Self::Tpm20EParent,
+        Self::Tpm20ENeedsTest,
+        Self::Tpm20ENoResult,
+        Self::Tpm20ESensitive,
+        Self::TpmCommandBlocked,
+        Self::TpmInvalidHandle,
+        Self::TpmDuplicateVhandle,
+        Self::TpmEmbeddedCommandBlocked,
+        Self::TpmEmbeddedCommandUnsupported,
+        Self::TpmRetry,
+        Self::TpmNeedsSelftest,
+        Self::TpmDoingSelftest,
+        Self::TpmDefendLockRunning,
+        Self::TpmCommandCanceled,
+        Self::TpmTooManyContexts,
+        Self::TpmNotFound,
+        Self::TpmAccessDenied,
+        Self::TpmInsufficientBuffer,
+        Self::TpmPpiFunctionUnsupported,
+        Self::PcpErrorMask,
+        Self::PcpDeviceNotReady,
+        Self::PcpInvalidHandle,
+        Self::PcpInvalidParameter,
+        Self::PcpFlagNotSupported,
+        Self::PcpNotSupported,
+        Self::PcpBufferTooSmall,
+        Self::PcpInternalError,
+        Self::PcpAuthenticationFailed,
+        Self::PcpAuthenticationIgnored,
+        Self::PcpPolicyNotFound,
+        Self::PcpProfileNotFound,
+        Self::PcpValidationFailed,
+        Self::PcpDeviceNotFound,
+        Self::PcpWrongParent,
+        Self::PcpKeyNotLoaded,
+        Self::PcpNoKeyCertification,
+        Self::PcpKeyNotFinalized,
+        Self::PcpAttestationChallengeNotSet,
+        Self::PcpNotPcrBound,
+        Self::PcpKeyAlreadyFinalized,
+        Self::PcpKeyUsagePolicyNotSupported,
+        Self::PcpKeyUsagePolicyInvalid,
+        Self::PcpSoftKeyError,
+        Self::PcpKeyNotAuthenticated,
+        Self::PcpKeyNotAik,
+        Self::PcpKeyNotSigningKey,
+        Self::PcpLockedOut,
+        Self::PcpClaimTypeNotSupported,
+        Self::PcpTpmVersionNotSupported,
+        Self::PcpBufferLengthMismatch,
+        Self::PcpIfxRsaKeyCreationBlocked,
+        Self::PcpTicketMissing,
+        Self::PcpRawPolicyNotSupported,
+        Self::PcpKeyHandleInvalidated,
+        Self::RtpmNoResult,
+        Self::RtpmPcrReadIncomplete,
+        Self::RtpmInvalidContext,
+        Self::RtpmUnsupportedCmd,
+        Self::TpmZeroExhaustEnabled,
+        Self::HvInvalidHypercallCode,
+        Self::HvInvalidHypercallInput,
+        Self::HvInvalidAlignment,
+        Self::HvInvalidParameter,
+        Self::HvAccessDenied,
+        Self::HvInvalidPartitionState,
+        Self::HvOperationDenied,
+        Self::HvUnknownProperty,
+        Self::HvPropertyValueOutOfRange,
+        Self::HvInsufficientMemory,
+        Self::HvPartitionTooDeep,
+        Self::HvInvalidPartitionId,
+        Self::HvInvalidVpIndex,
+        Self::HvInvalidPortId,
+        Self::HvInvalidConnectionId,
+        Self::HvInsufficientBuffers,
+        Self::HvNotAcknowledged,
+        Self::HvInvalidVpState,
+        Self::HvAcknowledged,
+        Self::HvInvalidSaveRestoreState,
+        Self::HvInvalidSynicState,
+        Self::HvObjectInUse,
+        Self::HvInvalidProximityDomainInfo,
+        Self::HvNoData,
+        Self::HvInactive,
+        Self::HvNoResources,
+        Self::HvFeatureUnavailable,
+        Self::HvInsufficientBuffer,
+        Self::HvInsufficientDeviceDomains,
+        Self::HvCpuidFeatureValidationError,
+        Self::HvCpuidXsaveFeatureValidationError,
+        Self::HvProcessorStartupTimeout,
+        Self::HvSmxEnabled,
+        Self::HvInvalidLpIndex,
+        Self::HvInvalidRegisterValue,
+        Self::HvInvalidVtlState,
+        Self::HvNxNotDetected,
+        Self::HvInvalidDeviceId,
+        Self::HvInvalidDeviceState,
+        Self::HvPageRequestInvalid,
+        Self::HvInvalidCpuGroupId,
+        Self::HvInvalidCpuGroupState,
+        Self::HvOperationFailed,
+        Self::HvNotAllowedWithNestedVirtActive,
+        Self::HvInsufficientRootMemory,
+        Self::HvEventBufferAlreadyFreed,
+        Self::HvInsufficientContiguousMemory,
+        Self::HvDeviceNotInDomain,
+        Self::HvNestedVmExit,
+        Self::HvCallPending,
+        Self::HvMsrAccessFailed,
+        Self::HvInsufficientMemoryMirroring,
+        Self::HvInsufficientContiguousMemoryMirroring,
+        Self::HvInsufficientContiguousRootMemory,
+        Self::HvInsufficientRootMemoryMirroring,
+        Self::HvInsufficientContiguousRootMemoryMirroring,
+        Self::HvNotPresent,
+        Self::IpsecBadSpi,
+        Self::IpsecSaLifetimeExpired,
+        Self::IpsecWrongSa,
+        Self::IpsecReplayCheckFailed,
+        Self::IpsecInvalidPacket,
+        Self::IpsecIntegrityCheckFailed,
+        Self::IpsecClearTextDrop,
+        Self::IpsecAuthFirewallDrop,
+        Self::IpsecThrottleDrop,
+        Self::IpsecDospBlock,
+        Self::IpsecDospReceivedMulticast,
+        Self::IpsecDospInvalidPacket,
+        Self::IpsecDospStateLookupFailed,
+        Self::IpsecDospMaxEntries,
+        Self::IpsecDospKeymodNotAllowed,
+        Self::IpsecDospMaxPerIpRatelimitQueues,
+        Self::VidDuplicateHandler,
+        Self::VidTooManyHandlers,
+        Self::VidQueueFull,
+        Self::VidHandlerNotPresent,
+        Self::VidInvalidObjectName,
+        Self::VidPartitionNameTooLong,
+        Self::VidMessageQueueNameTooLong,
+        Self::VidPartitionAlreadyExists,
+        Self::VidPartitionDoesNotExist,
+        Self::VidPartitionNameNotFound,
+        Self::VidMessageQueueAlreadyExists,
+        Self::VidExceededMbpEntryMapLimit,
+        Self::VidMbStillReferenced,
+        Self::VidChildGpaPageSetCorrupted,
+        Self::VidInvalidNumaSettings,
+        Self::VidInvalidNumaNodeIndex,
+        Self::VidNotificationQueueAlreadyAssociated,
+        Self::VidInvalidMemoryBlockHandle,
+        Self::VidPageRangeOverflow,
+        Self::VidInvalidMessageQueueHandle,
+        Self::VidInvalidGpaRangeHandle,
+        Self::VidNoMemoryBlockNotificationQueue,
+        Self::VidMemoryBlockLockCountExceeded,
+        Self::VidInvalidPpmHandle,
+        Self::VidMbpsAreLocked,
+        Self::VidMessageQueueClosed,
+        Self::VidVirtualProcessorLimitExceeded,
+        Self::VidStopPending,
+        Self::VidInvalidProcessorState,
+        Self::VidExceededKmContextCountLimit,
+        Self::VidKmInterfaceAlreadyInitialized,
+        Self::VidMbPropertyAlreadySetReset,
+        Self::VidMmioRangeDestroyed,
+        Self::VidInvalidChildGpaPageSet,
+        Self::VidReservePageSetIsBeingUsed,
+        Self::VidReservePageSetTooSmall,
+        Self::VidMbpAlreadyLockedUsingReservedPage,
+        Self::VidMbpCountExceededLimit,
+        Self::VidSavedStateCorrupt,
+        Self::VidSavedStateUnrecognizedItem,
+        Self::VidSavedStateIncompatible,
+        Self::VidVtlAccessDenied,
+        Self::VidInsufficientResourcesReserve,
+        Self::VidInsufficientResourcesPhysicalBuffer,
+        Self::VidInsufficientResourcesHvDeposit,
+        Self::VidMemoryTypeNotSupported,
+        Self::VidInsufficientResourcesWithdraw,
+        Self::VidProcessAlreadySet,
+        Self::DmOperationLimitExceeded,
+        Self::VolmgrDatabaseFull,
+        Self::VolmgrDiskConfigurationCorrupted,
+        Self::VolmgrDiskConfigurationNotInSync,
+        Self::VolmgrPackConfigUpdateFailed,
+        Self::VolmgrDiskContainsNonSimpleVolume,
+        Self::VolmgrDiskDuplicate,
+        Self::VolmgrDiskDynamic,
+        Self::VolmgrDiskIdInvalid,
+        Self::VolmgrDiskInvalid,
+        Self::VolmgrDiskLastVoter,
+        Self::VolmgrDiskLayoutInvalid,
+        Self::VolmgrDiskLayoutNonBasicBetweenBasicPartitions,
+        Self::VolmgrDiskLayoutNotCylinderAligned,
+        Self::VolmgrDiskLayoutPartitionsTooSmall,
+        Self::VolmgrDiskLayoutPrimaryBetweenLogicalPartitions,
+        Self::VolmgrDiskLayoutTooManyPartitions,
+        Self::VolmgrDiskMissing,
+        Self::VolmgrDiskNotEmpty,
+        Self::VolmgrDiskNotEnoughSpace,
+        Self::VolmgrDiskRevectoringFailed,
+        Self::VolmgrDiskSectorSizeInvalid,
+        Self::VolmgrDiskSetNotContained,
+        Self::VolmgrDiskUsedByMultipleMembers,
+        Self::VolmgrDiskUsedByMultiplePlexes,
+        Self::VolmgrDynamicDiskNotSupported,
+        Self::VolmgrExtentAlreadyUsed,
+        Self::VolmgrExtentNotContiguous,
+        Self::VolmgrExtentNotInPublicRegion,
+        Self::VolmgrExtentNotSectorAligned,
+        Self::VolmgrExtentOverlapsEbrPartition,
+        Self::VolmgrExtentVolumeLengthsDoNotMatch,
+        Self::VolmgrFaultTolerantNotSupported,
+        Self::VolmgrInterleaveLengthInvalid,
+        Self::VolmgrMaximumRegisteredUsers,
+        Self::VolmgrMemberInSync,
+        Self::VolmgrMemberIndexDuplicate,
+        Self::VolmgrMemberIndexInvalid,
+        Self::VolmgrMemberMissing,
+        Self::VolmgrMemberNotDetached,
+        Self::VolmgrMemberRegenerating,
+        Self::VolmgrAllDisksFailed,
+        Self::VolmgrNoRegisteredUsers,
+        Self::VolmgrNoSuchUser,
+        Self::VolmgrNotificationReset,
+        Self::VolmgrNumberOfMembersInvalid,
+        Self::VolmgrNumberOfPlexesInvalid,
+        Self::VolmgrPackDuplicate,
+        Self::VolmgrPackIdInvalid,
+        Self::VolmgrPackInvalid,
+        Self::VolmgrPackNameInvalid,
+        Self::VolmgrPackOffline,
+        Self::VolmgrPackHasQuorum,
+        Self::VolmgrPackWithoutQuorum,
+        Self::VolmgrPartitionStyleInvalid,
+        Self::VolmgrPartitionUpdateFailed,
+        Self::VolmgrPlexInSync,
+        Self::VolmgrPlexIndexDuplicate,
+        Self::VolmgrPlexIndexInvalid,
+        Self::VolmgrPlexLastActive,
+        Self::VolmgrPlexMissing,
+        Self::VolmgrPlexRegenerating,
+        Self::VolmgrPlexTypeInvalid,
+        Self::VolmgrPlexNotRaid5,
+        Self::VolmgrPlexNotSimple,
+        Self::VolmgrStructureSizeInvalid,
+        Self::VolmgrTooManyNotificationRequests,
+        Self::VolmgrTransactionInProgress,
+        Self::VolmgrUnexpectedDiskLayoutChange,
+        Self::VolmgrVolumeContainsMissingDisk,
+        Self::VolmgrVolumeIdInvalid,
+        Self::VolmgrVolumeLengthInvalid,
+        Self::VolmgrVolumeLengthNotSectorSizeMultiple,
+        Self::VolmgrVolumeNotMirrored,
+        Self::VolmgrVolumeNotRetained,
+        Self::VolmgrVolumeOffline,
+        Self::VolmgrVolumeRetained,
+        Self::VolmgrNumberOfExtentsInvalid,
+        Self::VolmgrDifferentSectorSize,
+        Self::VolmgrBadBootDisk,
+        Self::VolmgrPackConfigOffline,
+        Self::VolmgrPackConfigOnline,
+        Self::VolmgrNotPrimaryPack,
+        Self::VolmgrPackLogUpdateFailed,
+        Self::VolmgrNumberOfDisksInPlexInvalid,
+        Self::VolmgrNumberOfDisksInMemberInvalid,
+        Self::VolmgrVolumeMirrored,
+        Self::VolmgrPlexNotSimpleSpanned,
+        Self::VolmgrNoValidLogCopies,
+        Self::VolmgrPrimaryPackPresent,
+        Self::VolmgrNumberOfDisksInvalid,
+        Self::VolmgrMirrorNotSupported,
+        Self::VolmgrRaid5NotSupported,
+        Self::BcdTooManyElements,
+        Self::VhdDriveFooterMissing,
+        Self::VhdDriveFooterChecksumMismatch,
+        Self::VhdDriveFooterCorrupt,
+        Self::VhdFormatUnknown,
+        Self::VhdFormatUnsupportedVersion,
+        Self::VhdSparseHeaderChecksumMismatch,
+        Self::VhdSparseHeaderUnsupportedVersion,
+        Self::VhdSparseHeaderCorrupt,
+        Self::VhdBlockAllocationFailure,
+        Self::VhdBlockAllocationTableCorrupt,
+        Self::VhdInvalidBlockSize,
+        Self::VhdBitmapMismatch,
+        Self::VhdParentVhdNotFound,
+        Self::VhdChildParentIdMismatch,
+        Self::VhdChildParentTimestampMismatch,
+        Self::VhdMetadataReadFailure,
+        Self::VhdMetadataWriteFailure,
+        Self::VhdInvalidSize,
+        Self::VhdInvalidFileSize,
+        Self::VirtdiskProviderNotFound,
+        Self::VirtdiskNotVirtualDisk,
+        Self::VhdParentVhdAccessDenied,
+        Self::VhdChildParentSizeMismatch,
+        Self::VhdDifferencingChainCycleDetected,
+        Self::VhdDifferencingChainErrorInParent,
+        Self::VirtualDiskLimitation,
+        Self::VhdInvalidType,
+        Self::VhdInvalidState,
+        Self::VirtdiskUnsupportedDiskSectorSize,
+        Self::VirtdiskDiskAlreadyOwned,
+        Self::VirtdiskDiskOnlineAndWritable,
+        Self::CtlogTrackingNotInitialized,
+        Self::CtlogLogfileSizeExceededMaxsize,
+        Self::CtlogVhdChangedOffline,
+        Self::CtlogInvalidTrackingState,
+        Self::CtlogInconsistentTrackingFile,
+        Self::VhdMetadataFull,
+        Self::VhdInvalidChangeTrackingId,
+        Self::VhdChangeTrackingDisabled,
+        Self::VhdMissingChangeTrackingInformation,
+        Self::VhdResizeWouldTruncateData,
+        Self::VhdCouldNotComputeMinimumVirtualSize,
+        Self::VhdAlreadyAtOrBelowMinimumVirtualSize,
+        Self::RkfKeyNotFound,
+        Self::RkfDuplicateKey,
+        Self::RkfBlobFull,
+        Self::RkfStoreFull,
+        Self::RkfFileBlocked,
+        Self::RkfActiveKey,
+        Self::RdbssRestartOperation,
+        Self::RdbssContinueOperation,
+        Self::RdbssPostOperation,
+        Self::RdbssRetryLookup,
+        Self::BthAttInvalidHandle,
+        Self::BthAttReadNotPermitted,
+        Self::BthAttWriteNotPermitted,
+        Self::BthAttInvalidPdu,
+        Self::BthAttInsufficientAuthentication,
+        Self::BthAttRequestNotSupported,
+        Self::BthAttInvalidOffset,
+        Self::BthAttInsufficientAuthorization,
+        Self::BthAttPrepareQueueFull,
+        Self::BthAttAttributeNotFound,
+        Self::BthAttAttributeNotLong,
+        Self::BthAttInsufficientEncryptionKeySize,
+        Self::BthAttInvalidAttributeValueLength,
+        Self::BthAttUnlikely,
+        Self::BthAttInsufficientEncryption,
+        Self::BthAttUnsupportedGroupType,
+        Self::BthAttInsufficientResources,
+        Self::BthAttUnknownError,
+        Self::SecurebootRollbackDetected,
+        Self::SecurebootPolicyViolation,
+        Self::SecurebootInvalidPolicy,
+        Self::SecurebootPolicyPublisherNotFound,
+        Self::SecurebootPolicyNotSigned,
+        Self::SecurebootFileReplaced,
+        Self::SecurebootPolicyNotAuthorized,
+        Self::SecurebootPolicyUnknown,
+        Self::SecurebootPolicyMissingAntirollbackversion,
+        Self::SecurebootPlatformIdMismatch,
+        Self::SecurebootPolicyRollbackDetected,
+        Self::SecurebootPolicyUpgradeMismatch,
+        Self::SecurebootRequiredPolicyFileMissing,
+        Self::SecurebootNotBasePolicy,
+        Self::SecurebootNotSupplementalPolicy,
+        Self::AudioEngineNodeNotFound,
+        Self::HdaudioEmptyConnectionList,
+        Self::HdaudioConnectionListNotSupported,
+        Self::HdaudioNoLogicalDevicesCreated,
+        Self::HdaudioNullLinkedListEntry,
+        Self::VsmNotInitialized,
+        Self::VsmDmaProtectionNotInUse,
+        Self::IoringRequiredFlagNotSupported,
+        Self::IoringSubmissionQueueFull,
+        Self::IoringVersionNotSupported,
+        Self::IoringSubmissionQueueTooBig,
+        Self::IoringCompletionQueueTooBig,
+        Self::IoringSubmitInProgress,
+        Self::IoringCorrupt,
+        Self::IoringCompletionQueueTooFull,
+        Self::VolsnapBootfileNotValid,
+        Self::VolsnapActivationTimeout,
+        Self::VolsnapNoBypassioWithSnapshot,
+        Self::IoPreempted,
+        Self::SvhdxErrorStored,
+        Self::SvhdxErrorNotAvailable,
+        Self::SvhdxUnitAttentionAvailable,
+        Self::SvhdxUnitAttentionCapacityDataChanged,
+        Self::SvhdxUnitAttentionReservationsPreempted,
+        Self::SvhdxUnitAttentionReservationsReleased,
+        Self::SvhdxUnitAttentionRegistrationsPreempted,
+        Self::SvhdxUnitAttentionOperatingDefinitionChanged,
+        Self::SvhdxReservationConflict,
+        Self::SvhdxWrongFileType,
+        Self::SvhdxVersionMismatch,
+        Self::VhdShared,
+        Self::SvhdxNoInitiator,
+        Self::VhdsetBackingStorageNotFound,
+        Self::SmbNoPreauthIntegrityHashOverlap,
+        Self::SmbBadClusterDialect,
+        Self::SmbGuestLogonBlocked,
+        Self::SmbNoSigningAlgorithmOverlap,
+        Self::NetworkAuthenticationPromptCanceled,
+        Self::SpacesFaultDomainTypeInvalid,
+        Self::SpacesResiliencyTypeInvalid,
+        Self::SpacesDriveSectorSizeInvalid,
+        Self::SpacesDriveRedundancyInvalid,
+        Self::SpacesNumberOfDataCopiesInvalid,
+        Self::SpacesInterleaveLengthInvalid,
+        Self::SpacesNumberOfColumnsInvalid,
+        Self::SpacesNotEnoughDrives,
+        Self::SpacesExtendedError,
+        Self::SpacesProvisioningTypeInvalid,
+        Self::SpacesAllocationSizeInvalid,
+        Self::SpacesEnclosureAwareInvalid,
+        Self::SpacesWriteCacheSizeInvalid,
+        Self::SpacesNumberOfGroupsInvalid,
+        Self::SpacesDriveOperationalStateInvalid,
+        Self::SpacesUpdateColumnState,
+        Self::SpacesMapRequired,
+        Self::SpacesUnsupportedVersion,
+        Self::SpacesCorruptMetadata,
+        Self::SpacesDrtFull,
+        Self::SpacesInconsistency,
+        Self::SpacesLogNotReady,
+        Self::SpacesNoRedundancy,
+        Self::SpacesDriveNotReady,
+        Self::SpacesDriveSplit,
+        Self::SpacesDriveLostData,
+        Self::SpacesEntryIncomplete,
+        Self::SpacesEntryInvalid,
+        Self::SpacesMarkDirty,
+        Self::SpacesPdNotFound,
+        Self::SpacesPdLengthMismatch,
+        Self::SpacesPdUnsupportedVersion,
+        Self::SpacesPdInvalidData,
+        Self::SpacesFlushMetadata,
+        Self::SpacesCacheFull,
+        Self::SpacesRepairInProgress,
+        Self::SeccoreInvalidCommand,
+        Self::SystemIntegrityRollbackDetected,
+        Self::SystemIntegrityPolicyViolation,
+        Self::SystemIntegrityInvalidPolicy,
+        Self::SystemIntegrityPolicyNotSigned,
+        Self::SystemIntegrityTooManyPolicies,
+        Self::SystemIntegritySupplementalPolicyNotAuthorized,
+        Self::SystemIntegrityReputationMalicious,
+        Self::SystemIntegrityReputationPua,
+        Self::SystemIntegrityReputationDangerousExt,
+        Self::SystemIntegrityReputationOffline,
+        Self::SystemIntegrityReputationUnfriendlyFile,
+        Self::SystemIntegrityReputationUnattainable,
+        Self::SystemIntegrityReputationExplicitDenyFile,
+        Self::NoApplicableAppLicensesFound,
+        Self::ClipLicenseNotFound,
+        Self::ClipDeviceLicenseMissing,
+        Self::ClipLicenseInvalidSignature,
+        Self::ClipKeyholderLicenseMissingOrInvalid,
+        Self::ClipLicenseExpired,
+        Self::ClipLicenseSignedByUnknownSource,
+        Self::ClipLicenseNotSigned,
+        Self::ClipLicenseHardwareIdOutOfTolerance,
+        Self::ClipLicenseDeviceIdMismatch,
+        Self::PlatformManifestNotAuthorized,
+        Self::PlatformManifestInvalid,
+        Self::PlatformManifestFileNotAuthorized,
+        Self::PlatformManifestCatalogNotAuthorized,
+        Self::PlatformManifestBinaryIdNotFound,
+        Self::PlatformManifestNotActive,
+        Self::PlatformManifestNotSigned,
+        Self::AppexecConditionNotSatisfied,
+        Self::AppexecHandleInvalidated,
+        Self::AppexecInvalidHostGeneration,
+        Self::AppexecUnexpectedProcessRegistration,
+        Self::AppexecInvalidHostState,
+        Self::AppexecNoDonor,
+        Self::AppexecHostIdMismatch,
+        Self::AppexecUnknownUser,
+        Self::AppexecAppCompatBlock,
+        Self::AppexecCallerWaitTimeout,
+        Self::AppexecCallerWaitTimeoutTermination,
+        Self::AppexecCallerWaitTimeoutLicensing,
+        Self::AppexecCallerWaitTimeoutResources,
+    ];
+
+    /// Convert a raw code, None if it isn't in `NTSTATUS_CODES`
+    pub fn from_u32(code: u32) -> Option<Self> {
+        NTSTATUS_CODES
+            .binary_search_by_key(&code, |(c, _, _)| *c)
+            .ok()
+            .map(|index| Self::ALL[index])
+    }
+
+    /// Raw code value
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// Name as written in the Wine headers
+    pub fn name(self) -> &'static str {
+        NTSTATUS_CODES[self.index()].1
+    }
+
+    /// Human-readable description
+    pub fn description(self) -> &'static str {
+        NTSTATUS_CODES[self.index()].2
+    }
+
+    fn index(self) -> usize {
+        NTSTATUS_CODES.partition_point(|(c, _, _)| *c < self.code())
+    }
+}
+
+impl std::fmt::Display for NtStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (0x{:08X})", self.name(), self.code())
+    }
+}
+
 /// HRESULT codes extracted from Wine winerror.h
 pub const HRESULT_CODES: &[(u32, &str, &str)] = &[
     (0x8000000A, "E_PENDING", "pending"),
@@ -11528,6 +19248,651 @@ pub const HRESULT_CODES: &[(u32, &str, &str)] = &[
     (0x80030001, "STG_E_INVALIDFUNCTION", "stg e invalidfunction"),
 ];
 
+/// Typed codes from `HRESULT_CODES`, for matching without string comparisons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum HResult {
+    /// E_PENDING
+    Pending = 0x8000000A,
+    /// E_BOUNDS
+    Bounds = 0x8000000B,
+    /// E_CHANGED_STATE
+    ChangedState = 0x8000000C,
+    /// E_ILLEGAL_STATE_CHANGE
+    IllegalStateChange = 0x8000000D,
+    /// E_ILLEGAL_METHOD_CALL
+    IllegalMethodCall = 0x8000000E,
+    /// RO_E_METADATA_NAME_NOT_FOUND
+    RoEMetadataNameNotFound = 0x8000000F,
+    /// RO_E_METADATA_NAME_IS_NAMESPACE
+    RoEMetadataNameIsNamespace = 0x80000010,
+    /// RO_E_METADATA_INVALID_TYPE_FORMAT
+    RoEMetadataInvalidTypeFormat = 0x80000011,
+    /// RO_E_INVALID_METADATA_FILE
+    RoEInvalidMetadataFile = 0x80000012,
+    /// RO_E_CLOSED
+    RoEClosed = 0x80000013,
+    /// RO_E_EXCLUSIVE_WRITE
+    RoEExclusiveWrite = 0x80000014,
+    /// RO_E_CHANGE_NOTIFICATION_IN_PROGRESS
+    RoEChangeNotificationInProgress = 0x80000015,
+    /// RO_E_ERROR_STRING_NOT_FOUND
+    RoEErrorStringNotFound = 0x80000016,
+    /// E_STRING_NOT_NULL_TERMINATED
+    StringNotNullTerminated = 0x80000017,
+    /// E_ILLEGAL_DELEGATE_ASSIGNMENT
+    IllegalDelegateAssignment = 0x80000018,
+    /// E_ASYNC_OPERATION_NOT_STARTED
+    AsyncOperationNotStarted = 0x80000019,
+    /// E_APPLICATION_EXITING
+    ApplicationExiting = 0x8000001A,
+    /// E_APPLICATION_VIEW_EXITING
+    ApplicationViewExiting = 0x8000001B,
+    /// RO_E_MUST_BE_AGILE
+    RoEMustBeAgile = 0x8000001C,
+    /// RO_E_UNSUPPORTED_FROM_MTA
+    RoEUnsupportedFromMta = 0x8000001D,
+    /// RO_E_COMMITTED
+    RoECommitted = 0x8000001E,
+    /// RO_E_BLOCKED_CROSS_ASTA_CALL
+    RoEBlockedCrossAstaCall = 0x8000001F,
+    /// RO_E_CANNOT_ACTIVATE_FULL_TRUST_SERVER
+    RoECannotActivateFullTrustServer = 0x80000020,
+    /// RO_E_CANNOT_ACTIVATE_UNIVERSAL_APPLICATION_SERVER
+    RoECannotActivateUniversalApplicationServer = 0x80000021,
+    /// E_NOTIMPL
+    Notimpl = 0x80004001,
+    /// E_NOINTERFACE
+    Nointerface = 0x80004002,
+    /// E_POINTER
+    Pointer = 0x80004003,
+    /// E_ABORT
+    Abort = 0x80004004,
+    /// E_FAIL
+    Fail = 0x80004005,
+    /// CO_E_INIT_TLS
+    CoEInitTls = 0x80004006,
+    /// CO_E_INIT_SHARED_ALLOCATOR
+    CoEInitSharedAllocator = 0x80004007,
+    /// CO_E_INIT_MEMORY_ALLOCATOR
+    CoEInitMemoryAllocator = 0x80004008,
+    /// CO_E_INIT_CLASS_CACHE
+    CoEInitClassCache = 0x80004009,
+    /// CO_E_INIT_RPC_CHANNEL
+    CoEInitRpcChannel = 0x8000400A,
+    /// CO_E_INIT_TLS_SET_CHANNEL_CONTROL
+    CoEInitTlsSetChannelControl = 0x8000400B,
+    /// CO_E_INIT_TLS_CHANNEL_CONTROL
+    CoEInitTlsChannelControl = 0x8000400C,
+    /// CO_E_INIT_UNACCEPTED_USER_ALLOCATOR
+    CoEInitUnacceptedUserAllocator = 0x8000400D,
+    /// CO_E_INIT_SCM_MUTEX_EXISTS
+    CoEInitScmMutexExists = 0x8000400E,
+    /// CO_E_INIT_SCM_FILE_MAPPING_EXISTS
+    CoEInitScmFileMappingExists = 0x8000400F,
+    /// CO_E_INIT_SCM_MAP_VIEW_OF_FILE
+    CoEInitScmMapViewOfFile = 0x80004010,
+    /// CO_E_INIT_SCM_EXEC_FAILURE
+    CoEInitScmExecFailure = 0x80004011,
+    /// CO_E_INIT_ONLY_SINGLE_THREADED
+    CoEInitOnlySingleThreaded = 0x80004012,
+    /// CO_E_CANT_REMOTE
+    CoECantRemote = 0x80004013,
+    /// CO_E_BAD_SERVER_NAME
+    CoEBadServerName = 0x80004014,
+    /// CO_E_WRONG_SERVER_IDENTITY
+    CoEWrongServerIdentity = 0x80004015,
+    /// CO_E_OLE1DDE_DISABLED
+    CoEOle1ddeDisabled = 0x80004016,
+    /// CO_E_RUNAS_SYNTAX
+    CoERunasSyntax = 0x80004017,
+    /// CO_E_CREATEPROCESS_FAILURE
+    CoECreateprocessFailure = 0x80004018,
+    /// CO_E_RUNAS_CREATEPROCESS_FAILURE
+    CoERunasCreateprocessFailure = 0x80004019,
+    /// CO_E_RUNAS_LOGON_FAILURE
+    CoERunasLogonFailure = 0x8000401A,
+    /// CO_E_LAUNCH_PERMISSION_DENIED
+    CoELaunchPermissionDenied = 0x8000401B,
+    /// CO_E_START_SERVICE_FAILURE
+    CoEStartServiceFailure = 0x8000401C,
+    /// CO_E_REMOTE_COMMUNICATION_FAILURE
+    CoERemoteCommunicationFailure = 0x8000401D,
+    /// CO_E_SERVER_START_TIMEOUT
+    CoEServerStartTimeout = 0x8000401E,
+    /// CO_E_CLSREG_INCONSISTENT
+    CoEClsregInconsistent = 0x8000401F,
+    /// CO_E_IIDREG_INCONSISTENT
+    CoEIidregInconsistent = 0x80004020,
+    /// CO_E_NOT_SUPPORTED
+    CoENotSupported = 0x80004021,
+    /// CO_E_RELOAD_DLL
+    CoEReloadDll = 0x80004022,
+    /// CO_E_MSI_ERROR
+    CoEMsiError = 0x80004023,
+    /// CO_E_ATTEMPT_TO_CREATE_OUTSIDE_CLIENT_CONTEXT
+    CoEAttemptToCreateOutsideClientContext = 0x80004024,
+    /// CO_E_SERVER_PAUSED
+    CoEServerPaused = 0x80004025,
+    /// CO_E_SERVER_NOT_PAUSED
+    CoEServerNotPaused = 0x80004026,
+    /// CO_E_CLASS_DISABLED
+    CoEClassDisabled = 0x80004027,
+    /// CO_E_CLRNOTAVAILABLE
+    CoEClrnotavailable = 0x80004028,
+    /// CO_E_ASYNC_WORK_REJECTED
+    CoEAsyncWorkRejected = 0x80004029,
+    /// CO_E_SERVER_INIT_TIMEOUT
+    CoEServerInitTimeout = 0x8000402A,
+    /// CO_E_NO_SECCTX_IN_ACTIVATE
+    CoENoSecctxInActivate = 0x8000402B,
+    /// CO_E_TRACKER_CONFIG
+    CoETrackerConfig = 0x80004030,
+    /// CO_E_THREADPOOL_CONFIG
+    CoEThreadpoolConfig = 0x80004031,
+    /// CO_E_SXS_CONFIG
+    CoESxsConfig = 0x80004032,
+    /// CO_E_MALFORMED_SPN
+    CoEMalformedSpn = 0x80004033,
+    /// CO_E_UNREVOKED_REGISTRATION_ON_APARTMENT_SHUTDOWN
+    CoEUnrevokedRegistrationOnApartmentShutdown = 0x80004034,
+    /// CO_E_PREMATURE_STUB_RUNDOWN
+    CoEPrematureStubRundown = 0x80004035,
+    /// E_UNEXPECTED
+    Unexpected = 0x8000FFFF,
+    /// RPC_E_CALL_REJECTED
+    RpcECallRejected = 0x80010001,
+    /// RPC_E_CALL_CANCELED
+    RpcECallCanceled = 0x80010002,
+    /// RPC_E_CANTPOST_INSENDCALL
+    RpcECantpostInsendcall = 0x80010003,
+    /// RPC_E_CANTCALLOUT_INASYNCCALL
+    RpcECantcalloutInasynccall = 0x80010004,
+    /// RPC_E_CANTCALLOUT_INEXTERNALCALL
+    RpcECantcalloutInexternalcall = 0x80010005,
+    /// RPC_E_CONNECTION_TERMINATED
+    RpcEConnectionTerminated = 0x80010006,
+    /// RPC_E_SERVER_DIED
+    RpcEServerDied = 0x80010007,
+    /// RPC_E_CLIENT_DIED
+    RpcEClientDied = 0x80010008,
+    /// RPC_E_INVALID_DATAPACKET
+    RpcEInvalidDatapacket = 0x80010009,
+    /// RPC_E_CANTTRANSMIT_CALL
+    RpcECanttransmitCall = 0x8001000A,
+    /// RPC_E_CLIENT_CANTMARSHAL_DATA
+    RpcEClientCantmarshalData = 0x8001000B,
+    /// RPC_E_CLIENT_CANTUNMARSHAL_DATA
+    RpcEClientCantunmarshalData = 0x8001000C,
+    /// RPC_E_SERVER_CANTMARSHAL_DATA
+    RpcEServerCantmarshalData = 0x8001000D,
+    /// RPC_E_SERVER_CANTUNMARSHAL_DATA
+    RpcEServerCantunmarshalData = 0x8001000E,
+    /// RPC_E_INVALID_DATA
+    RpcEInvalidData = 0x8001000F,
+    /// RPC_E_INVALID_PARAMETER
+    RpcEInvalidParameter = 0x80010010,
+    /// RPC_E_CANTCALLOUT_AGAIN
+    RpcECantcalloutAgain = 0x80010011,
+    /// RPC_E_SERVER_DIED_DNE
+    RpcEServerDiedDne = 0x80010012,
+    /// RPC_E_SYS_CALL_FAILED
+    RpcESysCallFailed = 0x80010100,
+    /// RPC_E_OUT_OF_RESOURCES
+    RpcEOutOfResources = 0x80010101,
+    /// RPC_E_ATTEMPTED_MULTITHREAD
+    RpcEAttemptedMultithread = 0x80010102,
+    /// RPC_E_NOT_REGISTERED
+    RpcENotRegistered = 0x80010103,
+    /// RPC_E_FAULT
+    RpcEFault = 0x80010104,
+    /// RPC_E_SERVERFAULT
+    RpcEServerfault = 0x80010105,
+    /// RPC_E_CHANGED_MODE
+    RpcEChangedMode = 0x80010106,
+    /// RPC_E_INVALIDMETHOD
+    RpcEInvalidmethod = 0x80010107,
+    /// RPC_E_DISCONNECTED
+    RpcEDisconnected = 0x80010108,
+    /// RPC_E_RETRY
+    RpcERetry = 0x80010109,
+    /// RPC_E_SERVERCALL_RETRYLATER
+    RpcEServercallRetrylater = 0x8001010A,
+    /// RPC_E_SERVERCALL_REJECTED
+    RpcEServercallRejected = 0x8001010B,
+    /// RPC_E_INVALID_CALLDATA
+    RpcEInvalidCalldata = 0x8001010C,
+    /// RPC_E_CANTCALLOUT_ININPUTSYNCCALL
+    RpcECantcalloutIninputsynccall = 0x8001010D,
+    /// RPC_E_WRONG_THREAD
+    RpcEWrongThread = 0x8001010E,
+    /// RPC_E_THREAD_NOT_INIT
+    RpcEThreadNotInit = 0x8001010F,
+    /// RPC_E_VERSION_MISMATCH
+    RpcEVersionMismatch = 0x80010110,
+    /// RPC_E_INVALID_HEADER
+    RpcEInvalidHeader = 0x80010111,
+    /// RPC_E_INVALID_EXTENSION
+    RpcEInvalidExtension = 0x80010112,
+    /// RPC_E_INVALID_IPID
+    RpcEInvalidIpid = 0x80010113,
+    /// RPC_E_INVALID_OBJECT
+    RpcEInvalidObject = 0x80010114,
+    /// RPC_E_CALL_COMPLETE
+    RpcECallComplete = 0x80010117,
+    /// RPC_E_UNSECURE_CALL
+    RpcEUnsecureCall = 0x80010118,
+    /// RPC_E_TOO_LATE
+    RpcETooLate = 0x80010119,
+    /// RPC_E_NO_GOOD_SECURITY_PACKAGES
+    RpcENoGoodSecurityPackages = 0x8001011A,
+    /// RPC_E_ACCESS_DENIED
+    RpcEAccessDenied = 0x8001011B,
+    /// RPC_E_REMOTE_DISABLED
+    RpcERemoteDisabled = 0x8001011C,
+    /// RPC_E_INVALID_OBJREF
+    RpcEInvalidObjref = 0x8001011D,
+    /// RPC_E_NO_CONTEXT
+    RpcENoContext = 0x8001011E,
+    /// RPC_E_TIMEOUT
+    RpcETimeout = 0x8001011F,
+    /// RPC_E_NO_SYNC
+    RpcENoSync = 0x80010120,
+    /// RPC_E_FULLSIC_REQUIRED
+    RpcEFullsicRequired = 0x80010121,
+    /// RPC_E_INVALID_STD_NAME
+    RpcEInvalidStdName = 0x80010122,
+    /// CO_E_FAILEDTOIMPERSONATE
+    CoEFailedtoimpersonate = 0x80010123,
+    /// CO_E_FAILEDTOGETSECCTX
+    CoEFailedtogetsecctx = 0x80010124,
+    /// CO_E_FAILEDTOOPENTHREADTOKEN
+    CoEFailedtoopenthreadtoken = 0x80010125,
+    /// CO_E_FAILEDTOGETTOKENINFO
+    CoEFailedtogettokeninfo = 0x80010126,
+    /// CO_E_TRUSTEEDOESNTMATCHCLIENT
+    CoETrusteedoesntmatchclient = 0x80010127,
+    /// CO_E_FAILEDTOQUERYCLIENTBLANKET
+    CoEFailedtoqueryclientblanket = 0x80010128,
+    /// CO_E_FAILEDTOSETDACL
+    CoEFailedtosetdacl = 0x80010129,
+    /// CO_E_ACCESSCHECKFAILED
+    CoEAccesscheckfailed = 0x8001012A,
+    /// CO_E_NETACCESSAPIFAILED
+    CoENetaccessapifailed = 0x8001012B,
+    /// CO_E_WRONGTRUSTEENAMESYNTAX
+    CoEWrongtrusteenamesyntax = 0x8001012C,
+    /// CO_E_INVALIDSID
+    CoEInvalidsid = 0x8001012D,
+    /// CO_E_CONVERSIONFAILED
+    CoEConversionfailed = 0x8001012E,
+    /// CO_E_NOMATCHINGSIDFOUND
+    CoENomatchingsidfound = 0x8001012F,
+    /// CO_E_LOOKUPACCSIDFAILED
+    CoELookupaccsidfailed = 0x80010130,
+    /// CO_E_NOMATCHINGNAMEFOUND
+    CoENomatchingnamefound = 0x80010131,
+    /// CO_E_LOOKUPACCNAMEFAILED
+    CoELookupaccnamefailed = 0x80010132,
+    /// CO_E_SETSERLHNDLFAILED
+    CoESetserlhndlfailed = 0x80010133,
+    /// CO_E_FAILEDTOGETWINDIR
+    CoEFailedtogetwindir = 0x80010134,
+    /// CO_E_PATHTOOLONG
+    CoEPathtoolong = 0x80010135,
+    /// CO_E_FAILEDTOGENUUID
+    CoEFailedtogenuuid = 0x80010136,
+    /// CO_E_FAILEDTOCREATEFILE
+    CoEFailedtocreatefile = 0x80010137,
+    /// CO_E_FAILEDTOCLOSEHANDLE
+    CoEFailedtoclosehandle = 0x80010138,
+    /// CO_E_EXCEEDSYSACLLIMIT
+    CoEExceedsysacllimit = 0x80010139,
+    /// CO_E_ACESINWRONGORDER
+    CoEAcesinwrongorder = 0x8001013A,
+    /// CO_E_INCOMPATIBLESTREAMVERSION
+    CoEIncompatiblestreamversion = 0x8001013B,
+    /// CO_E_FAILEDTOOPENPROCESSTOKEN
+    CoEFailedtoopenprocesstoken = 0x8001013C,
+    /// CO_E_DECODEFAILED
+    CoEDecodefailed = 0x8001013D,
+    /// CO_E_ACNOTINITIALIZED
+    CoEAcnotinitialized = 0x8001013F,
+    /// CO_E_CANCEL_DISABLED
+    CoECancelDisabled = 0x80010140,
+    /// RPC_E_UNEXPECTED
+    RpcEUnexpected = 0x8001FFFF,
+    /// DISP_E_UNKNOWNINTERFACE
+    DispEUnknowninterface = 0x80020001,
+    /// DISP_E_MEMBERNOTFOUND
+    DispEMembernotfound = 0x80020003,
+    /// DISP_E_PARAMNOTFOUND
+    DispEParamnotfound = 0x80020004,
+    /// DISP_E_TYPEMISMATCH
+    DispETypemismatch = 0x80020005,
+    /// DISP_E_UNKNOWNNAME
+    DispEUnknownname = 0x80020006,
+    /// DISP_E_NONAMEDARGS
+    DispENonamedargs = 0x80020007,
+    /// DISP_E_BADVARTYPE
+    DispEBadvartype = 0x80020008,
+    /// DISP_E_EXCEPTION
+    DispEException = 0x80020009,
+    /// DISP_E_OVERFLOW
+    DispEOverflow = 0x8002000A,
+    /// DISP_E_BADINDEX
+    DispEBadindex = 0x8002000B,
+    /// DISP_E_UNKNOWNLCID
+    DispEUnknownlcid = 0x8002000C,
+    /// DISP_E_ARRAYISLOCKED
+    DispEArrayislocked = 0x8002000D,
+    /// DISP_E_BADPARAMCOUNT
+    DispEBadparamcount = 0x8002000E,
+    /// DISP_E_PARAMNOTOPTIONAL
+    DispEParamnotoptional = 0x8002000F,
+    /// DISP_E_BADCALLEE
+    DispEBadcallee = 0x80020010,
+    /// DISP_E_NOTACOLLECTION
+    DispENotacollection = 0x80020011,
+    /// DISP_E_DIVBYZERO
+    DispEDivbyzero = 0x80020012,
+    /// DISP_E_BUFFERTOOSMALL
+    DispEBuffertoosmall = 0x80020013,
+    /// TYPE_E_BUFFERTOOSMALL
+    TypeEBuffertoosmall = 0x80028016,
+    /// TYPE_E_FIELDNOTFOUND
+    TypeEFieldnotfound = 0x80028017,
+    /// TYPE_E_INVDATAREAD
+    TypeEInvdataread = 0x80028018,
+    /// TYPE_E_UNSUPFORMAT
+    TypeEUnsupformat = 0x80028019,
+    /// TYPE_E_REGISTRYACCESS
+    TypeERegistryaccess = 0x8002801C,
+    /// TYPE_E_LIBNOTREGISTERED
+    TypeELibnotregistered = 0x8002801D,
+    /// TYPE_E_UNDEFINEDTYPE
+    TypeEUndefinedtype = 0x80028027,
+    /// TYPE_E_QUALIFIEDNAMEDISALLOWED
+    TypeEQualifiednamedisallowed = 0x80028028,
+    /// TYPE_E_INVALIDSTATE
+    TypeEInvalidstate = 0x80028029,
+    /// TYPE_E_WRONGTYPEKIND
+    TypeEWrongtypekind = 0x8002802A,
+    /// TYPE_E_ELEMENTNOTFOUND
+    TypeEElementnotfound = 0x8002802B,
+    /// TYPE_E_AMBIGUOUSNAME
+    TypeEAmbiguousname = 0x8002802C,
+    /// TYPE_E_NAMECONFLICT
+    TypeENameconflict = 0x8002802D,
+    /// TYPE_E_UNKNOWNLCID
+    TypeEUnknownlcid = 0x8002802E,
+    /// TYPE_E_DLLFUNCTIONNOTFOUND
+    TypeEDllfunctionnotfound = 0x8002802F,
+    /// TYPE_E_BADMODULEKIND
+    TypeEBadmodulekind = 0x800288BD,
+    /// TYPE_E_SIZETOOBIG
+    TypeESizetoobig = 0x800288C5,
+    /// TYPE_E_DUPLICATEID
+    TypeEDuplicateid = 0x800288C6,
+    /// TYPE_E_INVALIDID
+    TypeEInvalidid = 0x800288CF,
+    /// TYPE_E_TYPEMISMATCH
+    TypeETypemismatch = 0x80028CA0,
+    /// TYPE_E_OUTOFBOUNDS
+    TypeEOutofbounds = 0x80028CA1,
+    /// TYPE_E_IOERROR
+    TypeEIoerror = 0x80028CA2,
+    /// TYPE_E_CANTCREATETMPFILE
+    TypeECantcreatetmpfile = 0x80028CA3,
+    /// TYPE_E_CANTLOADLIBRARY
+    TypeECantloadlibrary = 0x80029C4A,
+    /// TYPE_E_INCONSISTENTPROPFUNCS
+    TypeEInconsistentpropfuncs = 0x80029C83,
+    /// TYPE_E_CIRCULARTYPE
+    TypeECirculartype = 0x80029C84,
+    /// STG_E_INVALIDFUNCTION
+    StgEInvalidfunction = 0x80030001,
+}
+
+impl HResult {
+    /// Every code, in the same order as `HRESULT_CODES`
+    pub const ALL: &'static [Self] = &[
+        Self::Pending,
+        Self::Bounds,
+        Self::ChangedState,
+        Self::IllegalStateChange,
+        Self::IllegalMethodCall,
+        Self::RoEMetadataNameNotFound,
+        Self::RoEMetadataNameIsNamespace,
+        Self::RoEMetadataInvalidTypeFormat,
+        Self::RoEInvalidMetadataFile,
+        Self::RoEClosed,
+        Self::RoEExclusiveWrite,
+        Self::RoEChangeNotificationInProgress,
+        Self::RoEErrorStringNotFound,
+        Self::StringNotNullTerminated,
+        Self::IllegalDelegateAssignment,
+        Self::AsyncOperationNotStarted,
+        Self::ApplicationExiting,
+        Self::ApplicationViewExiting,
+        Self::RoEMustBeAgile,
+        Self::RoEUnsupportedFromMta,
+        Self::RoECommitted,
+        Self::RoEBlockedCrossAstaCall,
+        Self::RoECannotActivateFullTrustServer,
+        Self::RoECannotActivateUniversalApplicationServer,
+        Self::Notimpl,
+        Self::Nointerface,
+        Self::Pointer,
+        Self::Abort,
+        Self::Fail,
+        Self::CoEInitTls,
+        Self::CoEInitSharedAllocator,
+        Self::CoEInitMemoryAllocator,
+        Self::CoEInitClassCache,
+        Self::CoEInitRpcChannel,
+        Self::CoEInitTlsSetChannelControl,
+        Self::CoEInitTlsChannelControl,
+        Self::CoEInitUnacceptedUserAllocator,
+        Self::CoEInitScmMutexExists,
+        Self::CoEInitScmFileMappingExists,
+        Self::CoEInitScmMapViewOfFile,
+        Self::CoEInitScmExecFailure,
+        Self::CoEInitOnlySingleThreaded,
+        Self::CoECantRemote,
+        Self::CoEBadServerName,
+        Self::CoEWrongServerIdentity,
+        Self::CoEOle1ddeDisabled,
+        Self::CoERunasSyntax,
+        Self::CoECreateprocessFailure,
+        Self::CoERunasCreateprocessFailure,
+        Self::CoERunasLogonFailure,
+        Self::CoELaunchPermissionDenied,
+        Self::CoEStartServiceFailure,
+        Self::CoERemoteCommunicationFailure,
+        Self::CoEServerStartTimeout,
+        Self::CoEClsregInconsistent,
+        Self::CoEIidregInconsistent,
+        Self::CoENotSupported,
+        Self::CoEReloadDll,
+        Self::CoEMsiError,
+        Self::CoEAttemptToCreateOutsideClientContext,
+        Self::CoEServerPaused,
+        Self::CoEServerNotPaused,
+        Self::CoEClassDisabled,
+        Self::CoEClrnotavailable,
+        Self::CoEAsyncWorkRejected,
+        Self::CoEServerInitTimeout,
+        Self::CoENoSecctxInActivate,
+        Self::CoETrackerConfig,
+        Self::CoEThreadpoolConfig,
+        Self::CoESxsConfig,
+        Self::CoEMalformedSpn,
+        Self::CoEUnrevokedRegistrationOnApartmentShutdown,
+        Self::CoEPrematureStubRundown,
+        Self::Unexpected,
+        Self::RpcECallRejected,
+        Self::RpcECallCanceled,
+        Self::RpcECantpostInsendcall,
+        Self::RpcECantcalloutInasynccall,
+        Self::RpcECantcalloutInexternalcall,
+        Self::RpcEConnectionTerminated,
+        Self::RpcEServerDied,
+        Self::RpcEClientDied,
+        Self::RpcEInvalidDatapacket,
+        Self::RpcECanttransmitCall,
+        Self::RpcEClientCantmarshalData,
+        Self::RpcEClientCantunmarshalData,
+        Self::RpcEServerCantmarshalData,
+        Self::RpcEServerCantunmarshalData,
+        Self::RpcEInvalidData,
+        Self::RpcEInvalidParameter,
+        Self::RpcECantcalloutAgain,
+        Self::RpcEServerDiedDne,
+        Self::RpcESysCallFailed,
+        Self::RpcEOutOfResources,
+        Self::RpcEAttemptedMultithread,
+        Self::RpcENotRegistered,
+        Self::RpcEFault,
+        Self::RpcEServerfault,
+        Self::RpcEChangedMode,
+        Self::RpcEInvalidmethod,
+        Self::RpcEDisconnected,
+        Self::RpcERetry,
+        Self::RpcEServercallRetrylater,
+        Self::RpcEServercallRejected,
+        Self::RpcEInvalidCalldata,
+        Self::RpcECantcalloutIninputsynccall,
+        Self::RpcEWrongThread,
+        Self::RpcEThreadNotInit,
+        Self::RpcEVersionMismatch,
+        Self::RpcEInvalidHeader,
+        Self::RpcEInvalidExtension,
+        Self::RpcEInvalidIpid,
+        Self::RpcEInvalidObject,
+        Self::RpcECallComplete,
+        Self::RpcEUnsecureCall,
+        Self::RpcETooLate,
+        Self::RpcENoGoodSecurityPackages,
+        Self::RpcEAccessDenied,
+        Self::RpcERemoteDisabled,
+        Self::RpcEInvalidObjref,
+        Self::RpcENoContext,
+        Self::RpcETimeout,
+        Self::RpcENoSync,
+        Self::RpcEFullsicRequired,
+        Self::RpcEInvalidStdName,
+        Self::CoEFailedtoimpersonate,
+        Self::CoEFailedtogetsecctx,
+        Self::CoEFailedtoopenthreadtoken,
+        Self::CoEFailedtogettokeninfo,
+        Self::CoETrusteedoesntmatchclient,
+        Self::CoEFailedtoqueryclientblanket,
+        Self::CoEFailedtosetdacl,
+        Self::CoEAccesscheckfailed,
+        Self::CoENetaccessapifailed,
+        Self::CoEWrongtrusteenamesyntax,
+        Self::CoEInvalidsid,
+        Self::CoEConversionfailed,
+        Self::CoENomatchingsidfound,
+        Self::CoELookupaccsidfailed,
+        Self::CoENomatchingnamefound,
+        Self::CoELookupaccnamefailed,
+        Self::CoESetserlhndlfailed,
+        Self::CoEFailedtogetwindir,
+        Self::CoEPathtoolong,
+        Self::CoEFailedtogenuuid,
+        Self::CoEFailedtocreatefile,
+        Self::CoEFailedtoclosehandle,
+        Self::CoEExceedsysacllimit,
+        Self::CoEAcesinwrongorder,
+        Self::CoEIncompatiblestreamversion,
+        Self::CoEFailedtoopenprocesstoken,
+        Self::CoEDecodefailed,
+        Self::CoEAcnotinitialized,
+        Self::CoECancelDisabled,
+        Self::RpcEUnexpected,
+        Self::DispEUnknowninterface,
+        Self::DispEMembernotfound,
+        Self::DispEParamnotfound,
+        Self::DispETypemismatch,
+        Self::DispEUnknownname,
+        Self::DispENonamedargs,
+        Self::DispEBadvartype,
+        Self::DispEException,
+        Self::DispEOverflow,
+        Self::DispEBadindex,
+        Self::DispEUnknownlcid,
+        Self::DispEArrayislocked,
+        Self::DispEBadparamcount,
+        Self::DispEParamnotoptional,
+        Self::DispEBadcallee,
+        Self::DispENotacollection,
+        Self::DispEDivbyzero,
+        Self::DispEBuffertoosmall,
+        Self::TypeEBuffertoosmall,
+        Self::TypeEFieldnotfound,
+        Self::TypeEInvdataread,
+        Self::TypeEUnsupformat,
+        Self::TypeERegistryaccess,
+        Self::TypeELibnotregistered,
+        Self::TypeEUndefinedtype,
+        Self::TypeEQualifiednamedisallowed,
+        Self::TypeEInvalidstate,
+        Self::TypeEWrongtypekind,
+        Self::TypeEElementnotfound,
+        Self::TypeEAmbiguousname,
+        Self::TypeENameconflict,
+        Self::TypeEUnknownlcid,
+        Self::TypeEDllfunctionnotfound,
+        Self::TypeEBadmodulekind,
+        Self::TypeESizetoobig,
+        Self::TypeEDuplicateid,
+        Self::TypeEInvalidid,
+        Self::TypeETypemismatch,
+        Self::TypeEOutofbounds,
+        Self::TypeEIoerror,
+        Self::TypeECantcreatetmpfile,
+        Self::TypeECantloadlibrary,
+        Self::TypeEInconsistentpropfuncs,
+        Self::TypeECirculartype,
+        Self::StgEInvalidfunction,
+    ];
+
+    /// Convert a raw code, None if it isn't in `HRESULT_CODES`
+    pub fn from_u32(code: u32) -> Option<Self> {
+        HRESULT_CODES
+            .binary_search_by_key(&code, |(c, _, _)| *c)
+            .ok()
+            .map(|index| Self::ALL[index])
+    }
+
+    /// Raw code value
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// Name as written in the Wine headers
+    pub fn name(self) -> &'static str {
+        HRESULT_CODES[self.index()].1
+    }
+
+    /// Human-readable description
+    pub fn description(self) -> &'static str {
+        HRESULT_CODES[self.index()].2
+    }
+
+    fn index(self) -> usize {
+        HRESULT_CODES.partition_point(|(c, _, _)| *c < self.code())
+    }
+}
+
+impl std::fmt::Display for HResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (0x{:08X})", self.name(), self.code())
+    }
+}
+
 /// Win32 error codes extracted from Wine winerror.h
 pub const WIN32_ERROR_CODES: &[(u32, &str, &str)] = &[
     (1, "ERROR_INVALID_FUNCTION", "invalid function"),
@@ -13255,4 +21620,20 @@ pub const KNOWN_ERRORS: &[(&str, &str, &str)] = &[
         "Network connection timed out",
     ),
     ("certificate", "NET-CERT", "SSL/TLS certificate issue"),
+    // Media playback (winegstreamer)
+    (
+        "missing a plug-in",
+        "MEDIA-GST-PLUGIN",
+        "GStreamer plugin missing - a container or codec can't be played",
+    ),
+    (
+        "no decoder available for type",
+        "MEDIA-GST-DECODER",
+        "No GStreamer decoder for a media type - codec plugin missing",
+    ),
+    (
+        "failed to initialize gstreamer",
+        "MEDIA-GST-INIT",
+        "GStreamer failed to initialize - media playback unavailable",
+    ),
 ];
@@ -0,0 +1,157 @@
+//! Per-operation temporary directories.
+//!
+//! Temporary files (registry imports, extracted archives, plugin scratch
+//! space) go into a directory of their own under the work directory (see
+//! [`crate::config::get_work_dir`]), so concurrent protontool runs never
+//! share files. The directory is removed when the operation finishes;
+//! directories left behind by a crashed or killed protontool are removed by
+//! [`sweep_stale`] on a later start.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// Work directories younger than this are never swept. A protontool in
+/// another PID namespace (e.g. a Flatpak) has a PID that looks dead from
+/// here, so the owner check alone isn't enough.
+const STALE_MIN_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Sequence number making directory names unique within a process.
+static SEQUENCE: AtomicU32 = AtomicU32::new(0);
+
+/// A temporary directory removed when dropped.
+#[derive(Debug)]
+pub struct WorkDir {
+    path: PathBuf,
+}
+
+impl WorkDir {
+    /// Create a fresh directory named `LABEL-PID-SEQ` in the work directory.
+    pub fn create(label: &str) -> Result<Self, String> {
+        Self::create_in(&crate::config::get_work_dir(), label)
+    }
+
+    /// Create a fresh directory in `base`.
+    pub fn create_in(base: &Path, label: &str) -> Result<Self, String> {
+        let label: String = label
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        loop {
+            let seq = SEQUENCE.fetch_add(1, Ordering::SeqCst);
+            let path = base.join(format!("{}-{}-{}", label, std::process::id(), seq));
+            fs::create_dir_all(base)
+                .map_err(|e| format!("Failed to create {}: {}", base.display(), e))?;
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(WorkDir { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(format!("Failed to create {}: {}", path.display(), e)),
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// A unique file name in this directory (see [`unique_file`]).
+    pub fn file(&self, stem: &str, ext: &str) -> PathBuf {
+        unique_file(&self.path, stem, ext)
+    }
+}
+
+/// A file name in `dir` unique to this process and call, like
+/// `patch-1234-7.reg`, so operations sharing a directory never overwrite
+/// each other's pending files.
+pub fn unique_file(dir: &Path, stem: &str, ext: &str) -> PathBuf {
+    let seq = SEQUENCE.fetch_add(1, Ordering::SeqCst);
+    dir.join(format!("{}-{}-{}.{}", stem, std::process::id(), seq, ext))
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.path).ok();
+    }
+}
+
+/// PID of the process owning a work directory, from its `LABEL-PID-SEQ` name.
+fn owner_pid(name: &str) -> Option<u32> {
+    let mut parts = name.rsplitn(3, '-');
+    parts.next()?.parse::<u32>().ok()?;
+    let pid = parts.next()?.parse().ok()?;
+    parts.next()?;
+    Some(pid)
+}
+
+/// Remove work directories whose process is no longer running and that
+/// haven't changed for a day. Returns the number of directories removed.
+pub fn sweep_stale() -> usize {
+    sweep_stale_in(&crate::config::get_work_dir())
+}
+
+fn sweep_stale_in(base: &Path) -> usize {
+    let entries = match fs::read_dir(base) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+    let now = SystemTime::now();
+    entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            match owner_pid(&name.to_string_lossy()) {
+                Some(pid) => !Path::new("/proc").join(pid.to_string()).exists(),
+                None => false,
+            }
+        })
+        .filter(|entry| {
+            entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .is_ok_and(|modified| {
+                    now.duration_since(modified).unwrap_or_default() >= STALE_MIN_AGE
+                })
+        })
+        .filter(|entry| fs::remove_dir_all(entry.path()).is_ok())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_dir_is_unique_and_removed() {
+        let base = std::env::temp_dir().join(format!("protontool-work-{}", std::process::id()));
+        let a = WorkDir::create_in(&base, "verb-d3dx9").unwrap();
+        let b = WorkDir::create_in(&base, "verb-d3dx9").unwrap();
+        assert_ne!(a.path(), b.path());
+        assert_ne!(a.file("patch", "reg"), a.file("patch", "reg"));
+        assert!(a.file("patch", "reg").starts_with(a.path()));
+        let kept = a.path().to_path_buf();
+        drop(b);
+        assert_eq!(fs::read_dir(&base).unwrap().count(), 1);
+
+        // A directory of a process that no longer exists is swept once it
+        // is old enough.
+        let stale = base.join("verb_x-4294967295-0");
+        fs::create_dir(&stale).unwrap();
+        assert_eq!(sweep_stale_in(&base), 0);
+        fs::File::open(&stale)
+            .unwrap()
+            .set_modified(SystemTime::now() - STALE_MIN_AGE * 2)
+            .unwrap();
+        assert_eq!(sweep_stale_in(&base), 1);
+        assert!(kept.exists());
+        drop(a);
+        assert!(!kept.exists());
+        fs::remove_dir_all(&base).ok();
+    }
+}